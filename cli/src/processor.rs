@@ -1,4 +1,5 @@
 use clap::Subcommand;
+use std::path::PathBuf;
 use std::process::Command;
 use termcolor::Color;
 
@@ -13,6 +14,69 @@ pub enum NautilusCommand {
     Deploy,
     /// ⛴️  Ships (deploys) the Nautilus program
     Ship,
+    /// 📜  Works with the Nautilus program's IDL
+    #[command(subcommand)]
+    Idl(IdlCommand),
+}
+
+#[derive(Subcommand)]
+pub enum IdlCommand {
+    /// 📜  Builds the IDL, its Anchor counterpart and its TypeScript, Python and Rust bindings
+    Build {
+        /// Directory to write the IDL and bindings to
+        #[arg(short, long, default_value = "target/idl")]
+        out_dir: String,
+        /// Path to the program's `Cargo.toml`, instead of the one in the current directory
+        #[arg(long)]
+        manifest_path: Option<String>,
+    },
+    /// 📜  Builds the IDL's Go bindings
+    Go {
+        /// Directory to write the Go bindings to
+        #[arg(short, long, default_value = "target/idl/go")]
+        out_dir: String,
+        /// Path to the program's `Cargo.toml`, instead of the one in the current directory
+        #[arg(long)]
+        manifest_path: Option<String>,
+    },
+    /// 📜  Builds SQLite and Postgres `CREATE TABLE` statements for the program's tables
+    Sql {
+        /// Directory to write the SQL files to
+        #[arg(short, long, default_value = "target/idl/sql")]
+        out_dir: String,
+        /// Path to the program's `Cargo.toml`, instead of the one in the current directory
+        #[arg(long)]
+        manifest_path: Option<String>,
+    },
+    /// 📜  Builds a JSON Schema for each of the program's accounts and types
+    Schema {
+        /// Directory to write the schemas to
+        #[arg(short, long, default_value = "target/idl/schema")]
+        out_dir: String,
+        /// Path to the program's `Cargo.toml`, instead of the one in the current directory
+        #[arg(long)]
+        manifest_path: Option<String>,
+    },
+    /// 📜  Builds Markdown and HTML reference documentation from the IDL
+    Docs {
+        /// Directory to write the documentation to
+        #[arg(short, long, default_value = "target/idl/docs")]
+        out_dir: String,
+        /// Path to the program's `Cargo.toml`, instead of the one in the current directory
+        #[arg(long)]
+        manifest_path: Option<String>,
+    },
+    /// 📜  Compares two IDLs and reports breaking changes as JSON, exiting with
+    /// status 1 if there are any
+    Diff {
+        /// The previous version of the IDL
+        old: String,
+        /// The new version of the IDL
+        new: String,
+        /// File to write the report to, instead of stdout
+        #[arg(short, long)]
+        out: Option<String>,
+    },
 }
 
 /// Util function for running commands on the operating system.
@@ -41,6 +105,76 @@ fn deploy() -> std::io::Result<()> {
     Ok(())
 }
 
+/// Resolves the directory of the program's `Cargo.toml` - the one at
+/// `manifest_path` if it's provided, otherwise the current directory.
+fn manifest_dir(manifest_path: Option<&str>) -> std::io::Result<PathBuf> {
+    let current_dir = std::env::current_dir()?;
+    match manifest_path.map(|path| current_dir.join(path)) {
+        Some(path) if path.ends_with("Cargo.toml") => {
+            Ok(path.parent().unwrap_or(&current_dir).to_path_buf())
+        }
+        Some(_) => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "`--manifest-path` must be a path to a `Cargo.toml` file",
+        )),
+        None => Ok(current_dir),
+    }
+}
+
+/// Build the IDL for the Nautilus program whose manifest is at
+/// `manifest_path`, or in the current directory.
+fn idl_build(out_dir: &str, manifest_path: Option<&str>) -> std::io::Result<()> {
+    let manifest_dir = manifest_dir(manifest_path)?;
+    nautilus_syn::entry::idl::write_idl(&manifest_dir, out_dir)?;
+    Ok(())
+}
+
+/// Build the Go bindings for the Nautilus program whose manifest is at
+/// `manifest_path`, or in the current directory.
+fn idl_go(out_dir: &str, manifest_path: Option<&str>) -> std::io::Result<()> {
+    let manifest_dir = manifest_dir(manifest_path)?;
+    nautilus_syn::entry::idl::write_go_bindings(&manifest_dir, out_dir)?;
+    Ok(())
+}
+
+/// Build the SQL tables for the Nautilus program whose manifest is at
+/// `manifest_path`, or in the current directory.
+fn idl_sql(out_dir: &str, manifest_path: Option<&str>) -> std::io::Result<()> {
+    let manifest_dir = manifest_dir(manifest_path)?;
+    nautilus_syn::entry::idl::write_sql(&manifest_dir, out_dir)?;
+    Ok(())
+}
+
+/// Build the JSON Schemas for the Nautilus program whose manifest is at
+/// `manifest_path`, or in the current directory.
+fn idl_schema(out_dir: &str, manifest_path: Option<&str>) -> std::io::Result<()> {
+    let manifest_dir = manifest_dir(manifest_path)?;
+    nautilus_syn::entry::idl::write_json_schemas(&manifest_dir, out_dir)?;
+    Ok(())
+}
+
+/// Build the reference documentation for the Nautilus program whose manifest is at
+/// `manifest_path`, or in the current directory.
+fn idl_docs(out_dir: &str, manifest_path: Option<&str>) -> std::io::Result<()> {
+    let manifest_dir = manifest_dir(manifest_path)?;
+    nautilus_syn::entry::idl::write_docs(&manifest_dir, out_dir)?;
+    Ok(())
+}
+
+/// Compare two IDLs and write the JSON report to `out`, or stdout if it's not
+/// provided. Returns whether any change is breaking.
+fn idl_diff(old: &str, new: &str, out: Option<&str>) -> std::io::Result<bool> {
+    let old_idl = nautilus_idl::util::load_idl_from_json(old)?;
+    let new_idl = nautilus_idl::util::load_idl_from_json(new)?;
+    let diff = old_idl.diff(&new_idl);
+    let report = diff.to_json()?;
+    match out {
+        Some(out) => std::fs::write(out, report)?,
+        None => println!("{}", report),
+    };
+    Ok(diff.breaking)
+}
+
 /// Process incoming commands to the Nautilus CLI.
 pub fn processor(cli: Cli) -> std::io::Result<()> {
     match &cli.command {
@@ -74,6 +208,89 @@ pub fn processor(cli: Cli) -> std::io::Result<()> {
                 Err(_) => terminal.end_output(Color::Red, "   ❌  Deploy failed."),
             };
         }
+        NautilusCommand::Idl(IdlCommand::Build {
+            out_dir,
+            manifest_path,
+        }) => {
+            let mut terminal = NautilusTerminal::new(Color::Cyan, " 📜  Building IDL...");
+            match idl_build(out_dir, manifest_path.as_deref()) {
+                Ok(()) => terminal
+                    .end_output(Color::Green, &format!("   ✅  IDL written to: {}", out_dir)),
+                Err(e) => {
+                    terminal.end_output(Color::Red, &format!("   ❌  IDL build failed: {}", e))
+                }
+            };
+        }
+        NautilusCommand::Idl(IdlCommand::Go {
+            out_dir,
+            manifest_path,
+        }) => {
+            let mut terminal = NautilusTerminal::new(Color::Cyan, " 📜  Building Go bindings...");
+            match idl_go(out_dir, manifest_path.as_deref()) {
+                Ok(()) => terminal.end_output(
+                    Color::Green,
+                    &format!("   ✅  Go bindings written to: {}", out_dir),
+                ),
+                Err(e) => terminal.end_output(
+                    Color::Red,
+                    &format!("   ❌  Go bindings build failed: {}", e),
+                ),
+            };
+        }
+        NautilusCommand::Idl(IdlCommand::Sql {
+            out_dir,
+            manifest_path,
+        }) => {
+            let mut terminal = NautilusTerminal::new(Color::Cyan, " 📜  Building SQL tables...");
+            match idl_sql(out_dir, manifest_path.as_deref()) {
+                Ok(()) => terminal.end_output(
+                    Color::Green,
+                    &format!("   ✅  SQL tables written to: {}", out_dir),
+                ),
+                Err(e) => terminal.end_output(
+                    Color::Red,
+                    &format!("   ❌  SQL tables build failed: {}", e),
+                ),
+            };
+        }
+        NautilusCommand::Idl(IdlCommand::Schema {
+            out_dir,
+            manifest_path,
+        }) => {
+            let mut terminal = NautilusTerminal::new(Color::Cyan, " 📜  Building JSON Schemas...");
+            match idl_schema(out_dir, manifest_path.as_deref()) {
+                Ok(()) => terminal.end_output(
+                    Color::Green,
+                    &format!("   ✅  JSON Schemas written to: {}", out_dir),
+                ),
+                Err(e) => terminal.end_output(
+                    Color::Red,
+                    &format!("   ❌  JSON Schemas build failed: {}", e),
+                ),
+            };
+        }
+        NautilusCommand::Idl(IdlCommand::Docs {
+            out_dir,
+            manifest_path,
+        }) => {
+            let mut terminal = NautilusTerminal::new(Color::Cyan, " 📜  Building docs...");
+            match idl_docs(out_dir, manifest_path.as_deref()) {
+                Ok(()) => terminal.end_output(
+                    Color::Green,
+                    &format!("   ✅  Docs written to: {}", out_dir),
+                ),
+                Err(e) => {
+                    terminal.end_output(Color::Red, &format!("   ❌  Docs build failed: {}", e))
+                }
+            };
+        }
+        // The report is meant to be read by other tools, so it's written without
+        // any terminal decoration.
+        NautilusCommand::Idl(IdlCommand::Diff { old, new, out }) => {
+            if idl_diff(old, new, out.as_deref())? {
+                std::process::exit(1);
+            }
+        }
     };
     Ok(())
 }
@@ -2,7 +2,7 @@
 use nautilus_syn::{entry::NautilusEntrypoint, object::NautilusObject};
 use proc_macro::TokenStream;
 use quote::ToTokens;
-use syn::{parse_macro_input, ItemStruct};
+use syn::{parse_macro_input, Item};
 
 extern crate proc_macro;
 
@@ -12,8 +12,11 @@ extern crate proc_macro;
 /// module.
 ///
 /// Parses the annotated module into a `syn::ItemMod` and converts that to a
-/// `nautilus_syn::NautilusEntrypoint` to build the program's entrypoint,
-/// processor, and IDL.
+/// `nautilus_syn::NautilusEntrypoint` to build the program's entrypoint and
+/// processor.
+///
+/// The IDL is not generated here - see `nautilus idl build` or
+/// `nautilus_syn::entry::idl::build_script` for use in a `build.rs`.
 #[proc_macro_attribute]
 pub fn nautilus(_: TokenStream, input: TokenStream) -> TokenStream {
     parse_macro_input!(input as NautilusEntrypoint)
@@ -24,27 +27,48 @@ pub fn nautilus(_: TokenStream, input: TokenStream) -> TokenStream {
 /// The derive macro to implement the required traits to allow for the annotated
 /// struct to serve as the data type for a Nautilus record - allowing it to be
 /// used as `T` inside of `Record<'_, T>`.
+///
+/// Named and tuple structs are supported, including generic ones. Each
+/// instantiation of a generic struct used in the program's instructions gets
+/// its own table, named after its type arguments - ie. `vault<u64>` for
+/// `Vault<u64>`.
 #[proc_macro_derive(Table, attributes(default_instructions, primary_key, authority))]
 pub fn nautilus_table(input: TokenStream) -> TokenStream {
-    let item_struct = parse_macro_input!(input as ItemStruct);
-    NautilusObject::from_item_struct(
-        item_struct,
-        nautilus_syn::object::NautilusObjectType::Record,
-    )
-    .to_token_stream()
-    .into()
+    derive_nautilus_object(input, nautilus_syn::object::NautilusObjectType::Record)
 }
 
 /// The derive macro to implement the required traits to allow for the annotated
-/// struct to serve as the data type for a Nautilus account - allowing it to be
-/// used as `T` inside of `Account<'_, T>`.
-#[proc_macro_derive(State, attributes(seeds, authority))]
+/// struct or enum to serve as the data type for a Nautilus account - allowing
+/// it to be used as `T` inside of `Account<'_, T>`.
+///
+/// Named and tuple structs are supported, as well as enums (stored using
+/// Borsh's enum layout), including generic ones. Each instantiation of a
+/// generic type used in the program's instructions gets its own
+/// discriminator, from its type arguments - ie. `vault<u64>` for `Vault<u64>`.
+///
+/// A non-generic `#[repr(C)]` struct of `bytemuck::Pod` fields without padding
+/// can be marked `#[zero_copy]` instead, to be used as `T` inside of
+/// `ZeroCopyAccount<'_, T>` - which reads and writes its data in place.
+#[proc_macro_derive(State, attributes(seeds, authority, zero_copy))]
 pub fn nautilus_account(input: TokenStream) -> TokenStream {
-    let item_struct = parse_macro_input!(input as ItemStruct);
-    NautilusObject::from_item_struct(
-        item_struct,
-        nautilus_syn::object::NautilusObjectType::Account,
-    )
+    derive_nautilus_object(input, nautilus_syn::object::NautilusObjectType::Account)
+}
+
+/// Converts the annotated struct or enum into a `NautilusObject` and spawns its
+/// tokens.
+fn derive_nautilus_object(
+    input: TokenStream,
+    nautilus_ty: nautilus_syn::object::NautilusObjectType,
+) -> TokenStream {
+    match parse_macro_input!(input as Item) {
+        Item::Struct(item_struct) => NautilusObject::from_item_struct(item_struct, nautilus_ty),
+        Item::Enum(item_enum) => NautilusObject::from_item_enum(item_enum, nautilus_ty),
+        item => {
+            return syn::Error::new_spanned(item, "Expected a struct or an enum.")
+                .to_compile_error()
+                .into()
+        }
+    }
     .to_token_stream()
     .into()
 }
@@ -0,0 +1,393 @@
+//! Lays out an instruction's accounts - the ones its IDL says to pass - and
+//! swaps them for adversarial ones.
+use std::{collections::HashMap, str::FromStr};
+
+use arbitrary::{Result, Unstructured};
+use nautilus::{
+    borsh::BorshDeserialize,
+    mpl_token_metadata,
+    solana_program::instruction::AccountMeta,
+    spl_associated_token_account, spl_token, system_program, sysvar,
+    testing::{TestAccount, TestHarness},
+    Pubkey,
+};
+use nautilus_idl::{
+    idl_instruction::{IdlInstruction, IdlInstructionAccount},
+    idl_type::IdlType,
+    idl_type_def::{discriminator, IdlTypeDef, IdlTypeDefType},
+    Idl,
+};
+
+use crate::args::Arg;
+
+/// The table name of the `NautilusIndex`, which counts each table's records to
+/// autoincrement their primary keys.
+const INDEX_TABLE_NAME: &str = "nautilus_index";
+
+/// An adversarial account passed in place of the one an instruction expects.
+/// Accounts are referred to by their position in the instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fault {
+    /// The account is swapped for a copy of it - same lamports and data - owned
+    /// by another program.
+    WrongOwner { account: usize },
+    /// The account should sign, but doesn't.
+    MissingSigner { account: usize },
+    /// Another of the instruction's accounts, `of`, is passed again in the
+    /// account's place.
+    Duplicate { account: usize, of: usize },
+    /// The account is a program-derived address, derived from the wrong seeds.
+    WrongPda { account: usize },
+}
+
+impl Fault {
+    /// The position of the account the fault replaced.
+    pub fn account(&self) -> usize {
+        match self {
+            Self::WrongOwner { account }
+            | Self::MissingSigner { account }
+            | Self::Duplicate { account, .. }
+            | Self::WrongPda { account } => *account,
+        }
+    }
+}
+
+/// An instruction's accounts, and any accounts the harness needs to hold for
+/// it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Layout {
+    pub metas: Vec<AccountMeta>,
+    /// Accounts to add to the harness before processing the instruction, ie.
+    /// the copy a `Fault::WrongOwner` passes.
+    pub accounts: Vec<(Pubkey, TestAccount)>,
+    /// For each account that's a program-derived address, the address derived
+    /// from the wrong seeds.
+    wrong_pdas: Vec<Option<Pubkey>>,
+}
+
+/// Where the accounts an instruction is called with come from.
+pub(crate) struct AccountResolver<'a> {
+    pub idl: &'a Idl,
+    pub program_id: Pubkey,
+    pub harness: &'a TestHarness,
+    pub payer: Pubkey,
+    /// Funded wallets that can sign - the harness doesn't check signatures.
+    pub users: &'a [Pubkey],
+}
+
+impl<'a> AccountResolver<'a> {
+    /// The accounts `instruction` expects, given the arguments it's called
+    /// with.
+    ///
+    /// Records are found by matching the account's name - less any `new_`
+    /// prefix - to their type. Their address is derived from the primary key
+    /// in `args`, or the index's next one if the instruction creates an
+    /// autoincremented record, or else picked from the records the harness
+    /// holds. Their authorities are the addresses the record holds. Other
+    /// accounts are the usual sysvars and programs, or a user or a new address.
+    pub fn layout(
+        &self,
+        instruction: &IdlInstruction,
+        args: &[Arg],
+        u: &mut Unstructured,
+        fresh: &mut impl FnMut() -> Pubkey,
+    ) -> Result<Layout> {
+        let creating = instruction
+            .accounts
+            .iter()
+            .any(|a| a.account_type == "index" && a.is_mut);
+        let mut layout = Layout::default();
+        // The records the instruction is called with, to find their
+        // authorities.
+        let mut records: Vec<(&IdlTypeDef, Pubkey)> = vec![];
+        let mut authorities = vec![];
+        for (i, account) in instruction.accounts.iter().enumerate() {
+            let (pubkey, wrong_pda) = match account.account_type.as_str() {
+                "feePayer" => (self.payer, None),
+                "sysvar" => (
+                    match account.name.as_str() {
+                        "clock" => sysvar::clock::ID,
+                        "epochSchedule" => sysvar::epoch_schedule::ID,
+                        _ => sysvar::rent::ID,
+                    },
+                    None,
+                ),
+                "systemProgram" => (system_program::ID, None),
+                "tokenProgram" => (spl_token::ID, None),
+                "associatedTokenProgram" => (spl_associated_token_account::ID, None),
+                "tokenMetadataProgram" => (mpl_token_metadata::ID, None),
+                "program" => (self.program_id, None),
+                "index" => (
+                    self.pda(INDEX_TABLE_NAME, &[0]),
+                    Some(self.pda(INDEX_TABLE_NAME, &[1])),
+                ),
+                _ => match table(self.idl, &account.name) {
+                    Some(type_def) => {
+                        let (pubkey, wrong_pda) =
+                            self.record(type_def, account, args, creating, u, fresh)?;
+                        records.push((type_def, pubkey));
+                        (pubkey, wrong_pda)
+                    }
+                    None if is_authority(self.idl, &account.name) => {
+                        authorities.push(i);
+                        (Pubkey::default(), None)
+                    }
+                    None if account.is_signer => (fresh(), None),
+                    None => (self.existing(u)?, None),
+                },
+            };
+            layout.metas.push(AccountMeta {
+                pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_mut,
+            });
+            layout.wrong_pdas.push(wrong_pda);
+        }
+        for i in authorities {
+            let name = &instruction.accounts[i].name;
+            layout.metas[i].pubkey = match self.authority(name, args, &records) {
+                Some(pubkey) => pubkey,
+                None => *u.choose(self.users)?,
+            };
+        }
+        Ok(layout)
+    }
+
+    /// Swaps one of the layout's accounts for an adversarial one, or returns
+    /// `None` if none of its accounts can be.
+    pub fn fault(
+        &self,
+        instruction: &IdlInstruction,
+        layout: &mut Layout,
+        u: &mut Unstructured,
+        fresh: &mut impl FnMut() -> Pubkey,
+    ) -> Result<Option<Fault>> {
+        let metas = &layout.metas;
+        let holds_data = |i: usize| {
+            matches!(
+                instruction.accounts[i].account_type.as_str(),
+                "account" | "index" | "feePayer"
+            )
+        };
+        let mut faults = vec![];
+        for (i, meta) in metas.iter().enumerate() {
+            if meta.is_signer {
+                faults.push(Fault::MissingSigner { account: i });
+            }
+            let exists = self
+                .harness
+                .account(&meta.pubkey)
+                .is_some_and(|a| !a.executable);
+            if holds_data(i) && exists {
+                faults.push(Fault::WrongOwner { account: i });
+            }
+            if layout.wrong_pdas[i].is_some() {
+                faults.push(Fault::WrongPda { account: i });
+            }
+            for (of, other) in metas.iter().enumerate() {
+                if of != i
+                    && holds_data(i)
+                    && holds_data(of)
+                    && other.pubkey != meta.pubkey
+                    && (meta.is_writable || other.is_writable)
+                {
+                    faults.push(Fault::Duplicate { account: i, of });
+                }
+            }
+        }
+        if faults.is_empty() {
+            return Ok(None);
+        }
+        let fault = *u.choose(&faults)?;
+        match fault {
+            Fault::WrongOwner { account } => {
+                let original = &layout.metas[account].pubkey;
+                let copy = TestAccount {
+                    owner: fresh(),
+                    ..self.harness.account(original).cloned().unwrap_or_default()
+                };
+                let pubkey = fresh();
+                layout.accounts.push((pubkey, copy));
+                layout.metas[account].pubkey = pubkey;
+            }
+            Fault::MissingSigner { account } => {
+                // An account passed more than once signs if any of its uses do.
+                let pubkey = layout.metas[account].pubkey;
+                for meta in layout.metas.iter_mut().filter(|m| m.pubkey == pubkey) {
+                    meta.is_signer = false;
+                }
+            }
+            Fault::Duplicate { account, of } => {
+                layout.metas[account].pubkey = layout.metas[of].pubkey;
+            }
+            Fault::WrongPda { account } => {
+                layout.metas[account].pubkey = layout.wrong_pdas[account].expect("A PDA");
+            }
+        }
+        Ok(Some(fault))
+    }
+
+    /// A record's address, and the address derived from the wrong seeds if
+    /// its primary key is known.
+    fn record(
+        &self,
+        type_def: &IdlTypeDef,
+        account: &IdlInstructionAccount,
+        args: &[Arg],
+        creating: bool,
+        u: &mut Unstructured,
+        fresh: &mut impl FnMut() -> Pubkey,
+    ) -> Result<(Pubkey, Option<Pubkey>)> {
+        let config = type_def.config.as_ref().expect("Tables have a config");
+        let table_name = config.table_name.as_deref().unwrap_or_default();
+        let primary_key = config.primary_key.as_deref().unwrap_or_default();
+        let primary_key_arg = args
+            .iter()
+            .find(|a| a.name == primary_key)
+            .and_then(|a| seed(&a.ty, &a.bytes));
+        let next_key = match config.autoincrement == Some(true) && creating && account.is_mut {
+            true => column_type(type_def, primary_key)
+                .and_then(|ty| encode_key(ty, self.count(table_name) as u64 + 1)),
+            false => None,
+        };
+        if let Some(seed) = primary_key_arg.or(next_key) {
+            let mut wrong_seed = seed.clone();
+            match wrong_seed.first_mut() {
+                Some(byte) => *byte ^= 1,
+                None => wrong_seed.push(0),
+            }
+            return Ok((
+                self.pda(table_name, &seed),
+                Some(self.pda(table_name, &wrong_seed)),
+            ));
+        }
+        let discriminator = discriminator(table_name);
+        let mut existing: Vec<&Pubkey> = self
+            .harness
+            .accounts()
+            .filter(|(_, a)| a.owner == self.program_id && a.data.starts_with(&discriminator))
+            .map(|(pubkey, _)| pubkey)
+            .collect();
+        existing.sort();
+        match existing.is_empty() {
+            true => Ok((fresh(), None)),
+            false => Ok((**u.choose(&existing)?, None)),
+        }
+    }
+
+    /// The address an authority account should be: the argument of the same
+    /// name, or the address a record it guards holds.
+    fn authority(
+        &self,
+        name: &str,
+        args: &[Arg],
+        records: &[(&IdlTypeDef, Pubkey)],
+    ) -> Option<Pubkey> {
+        if let Some(arg) = args
+            .iter()
+            .find(|a| a.name == name && a.ty == IdlType::PublicKey)
+        {
+            return Pubkey::try_from(arg.bytes.as_slice()).ok();
+        }
+        records.iter().find_map(|(type_def, pubkey)| {
+            let config = type_def.config.as_ref()?;
+            if !config.authorities.iter().any(|a| a == name) {
+                return None;
+            }
+            let decoded = self
+                .idl
+                .decode_account(&self.harness.account(pubkey)?.data)
+                .ok()?;
+            Pubkey::from_str(decoded.get("data")?.get(name)?.as_str()?).ok()
+        })
+    }
+
+    /// An address for an account the IDL says little about: a user, the payer
+    /// or any other account the harness holds.
+    fn existing(&self, u: &mut Unstructured) -> Result<Pubkey> {
+        let mut candidates: Vec<Pubkey> = self
+            .harness
+            .accounts()
+            .filter(|(_, a)| !a.executable && a.owner != sysvar::ID)
+            .map(|(pubkey, _)| *pubkey)
+            .collect();
+        candidates.sort();
+        Ok(*u.choose(&candidates)?)
+    }
+
+    /// The number of records the program's index has counted for a table.
+    fn count(&self, table_name: &str) -> u32 {
+        self.harness
+            .account(&self.pda(INDEX_TABLE_NAME, &[0]))
+            .and_then(|index| HashMap::<String, u32>::deserialize(&mut index.data.get(8..)?).ok())
+            .and_then(|index| index.get(table_name).copied())
+            .unwrap_or(0)
+    }
+
+    fn pda(&self, table_name: &str, seed: &[u8]) -> Pubkey {
+        Pubkey::find_program_address(&[table_name.as_bytes(), seed], &self.program_id).0
+    }
+}
+
+/// The record whose type matches an account's name, ie. `Person` for
+/// `new_person`.
+fn table<'a>(idl: &'a Idl, account_name: &str) -> Option<&'a IdlTypeDef> {
+    let name = account_name.strip_prefix("new_").unwrap_or(account_name);
+    idl.accounts.iter().find(|a| {
+        a.config.as_ref().is_some_and(|c| c.table_name.is_some())
+            && matches!(a.idl_type, IdlTypeDefType::Struct { .. })
+            && normalize(&a.name) == normalize(name)
+    })
+}
+
+fn is_authority(idl: &Idl, account_name: &str) -> bool {
+    idl.accounts.iter().any(|a| {
+        a.config
+            .as_ref()
+            .is_some_and(|c| c.authorities.iter().any(|n| n == account_name))
+    })
+}
+
+/// A name without case or underscores, so `HomeAddress` matches
+/// `home_address`.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn column_type<'a>(type_def: &'a IdlTypeDef, column: &str) -> Option<&'a IdlType> {
+    match &type_def.idl_type {
+        IdlTypeDefType::Struct { fields } => fields
+            .iter()
+            .find(|f| f.name == column)
+            .map(|f| &f.field_data_type),
+        IdlTypeDefType::Enum { .. } => None,
+    }
+}
+
+/// The seed a primary key adds to its record's address, from its Borsh
+/// encoding.
+fn seed(ty: &IdlType, bytes: &[u8]) -> Option<Vec<u8>> {
+    match ty {
+        // The string's bytes, without Borsh's length prefix.
+        IdlType::String => Some(bytes.get(4..)?.to_vec()),
+        IdlType::U8 | IdlType::U16 | IdlType::U32 | IdlType::U64 | IdlType::PublicKey => {
+            Some(bytes.to_vec())
+        }
+        _ => None,
+    }
+}
+
+/// An autoincremented primary key's seed.
+fn encode_key(ty: &IdlType, key: u64) -> Option<Vec<u8>> {
+    let bytes = key.to_le_bytes();
+    match ty {
+        IdlType::U8 => Some(bytes[..1].to_vec()),
+        IdlType::U16 => Some(bytes[..2].to_vec()),
+        IdlType::U32 => Some(bytes[..4].to_vec()),
+        IdlType::U64 => Some(bytes.to_vec()),
+        _ => None,
+    }
+}
@@ -0,0 +1,229 @@
+//! Generates instruction arguments - Borsh-encoded values of the IDL's types.
+use std::fmt;
+
+use arbitrary::{Error, Result, Unstructured};
+use nautilus::Pubkey;
+use nautilus_idl::{
+    idl_instruction::IdlInstruction,
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeStructField},
+    Idl,
+};
+
+/// The longest string generated. Strings are often used as seeds, which can't
+/// be longer than 32 bytes.
+const MAX_STRING_LEN: usize = 32;
+/// The most elements generated for a `Vec`, set or map.
+const MAX_ELEMENTS: usize = 4;
+/// How deeply defined types may nest before generation gives up - ie. on a
+/// recursive type.
+const MAX_DEPTH: usize = 16;
+
+/// One of an instruction's generated arguments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arg {
+    pub name: String,
+    pub ty: IdlType,
+    /// The value, as Borsh encodes it.
+    pub bytes: Vec<u8>,
+}
+
+impl fmt::Display for Arg {
+    /// Strings, public keys and numbers as their values, and anything else as
+    /// its bytes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = &self.bytes[..];
+        macro_rules! int {
+            ($int:ty) => {
+                match bytes.try_into() {
+                    Ok(bytes) => write!(f, "{}", <$int>::from_le_bytes(bytes)),
+                    Err(_) => write!(f, "{:?}", bytes),
+                }
+            };
+        }
+        match &self.ty {
+            IdlType::Bool => write!(f, "{}", bytes != [0]),
+            IdlType::U8 => int!(u8),
+            IdlType::U16 => int!(u16),
+            IdlType::U32 => int!(u32),
+            IdlType::U64 => int!(u64),
+            IdlType::I8 => int!(i8),
+            IdlType::I16 => int!(i16),
+            IdlType::I32 => int!(i32),
+            IdlType::I64 => int!(i64),
+            IdlType::PublicKey => match Pubkey::try_from(bytes) {
+                Ok(pubkey) => write!(f, "{}", pubkey),
+                Err(_) => write!(f, "{:?}", bytes),
+            },
+            IdlType::String => match bytes.get(4..).map(std::str::from_utf8) {
+                Some(Ok(string)) => write!(f, "{:?}", string),
+                _ => write!(f, "{:?}", bytes),
+            },
+            _ => write!(f, "{:?}", bytes),
+        }
+    }
+}
+
+/// Generates values of an IDL's types.
+pub struct ArgGenerator<'a> {
+    idl: &'a Idl,
+    /// The addresses `PublicKey` values are usually picked from, so they refer
+    /// to accounts the program can be called with.
+    addresses: &'a [Pubkey],
+}
+
+impl<'a> ArgGenerator<'a> {
+    pub fn new(idl: &'a Idl, addresses: &'a [Pubkey]) -> Self {
+        Self { idl, addresses }
+    }
+
+    /// An instruction's data: its 1-byte discriminant - its variant of the
+    /// program's `NautilusEntrypoint` - followed by its arguments.
+    pub fn instruction(
+        &self,
+        instruction: &IdlInstruction,
+        u: &mut Unstructured,
+    ) -> Result<(Vec<u8>, Vec<Arg>)> {
+        let mut data = vec![instruction.discriminant.value];
+        let mut args = Vec::with_capacity(instruction.args.len());
+        for arg in instruction.args.iter() {
+            let mut bytes = vec![];
+            self.value(&arg.arg_type, u, &mut bytes, 0)?;
+            data.extend(&bytes);
+            args.push(Arg {
+                name: arg.name.clone(),
+                ty: arg.arg_type.clone(),
+                bytes,
+            });
+        }
+        Ok((data, args))
+    }
+
+    /// Appends an arbitrary value of `ty`, as Borsh encodes it.
+    ///
+    /// Fails with `Error::IncorrectFormat` on types the IDL can't resolve,
+    /// like generics and undefined types.
+    pub fn value(
+        &self,
+        ty: &IdlType,
+        u: &mut Unstructured,
+        out: &mut Vec<u8>,
+        depth: usize,
+    ) -> Result<()> {
+        if depth > MAX_DEPTH {
+            return Err(Error::IncorrectFormat);
+        }
+        match ty {
+            IdlType::Bool => out.push(u.arbitrary::<bool>()? as u8),
+            IdlType::U8 => out.push(u.arbitrary()?),
+            IdlType::I8 => out.extend(u.arbitrary::<i8>()?.to_le_bytes()),
+            IdlType::U16 => out.extend(u.arbitrary::<u16>()?.to_le_bytes()),
+            IdlType::I16 => out.extend(u.arbitrary::<i16>()?.to_le_bytes()),
+            IdlType::U32 => out.extend(u.arbitrary::<u32>()?.to_le_bytes()),
+            IdlType::I32 => out.extend(u.arbitrary::<i32>()?.to_le_bytes()),
+            IdlType::U64 => out.extend(u.arbitrary::<u64>()?.to_le_bytes()),
+            IdlType::I64 => out.extend(u.arbitrary::<i64>()?.to_le_bytes()),
+            IdlType::U128 => out.extend(u.arbitrary::<u128>()?.to_le_bytes()),
+            IdlType::I128 => out.extend(u.arbitrary::<i128>()?.to_le_bytes()),
+            IdlType::String => {
+                // Printable ASCII, so it's valid UTF-8 and readable in logs.
+                let len = u.int_in_range(0..=MAX_STRING_LEN)?;
+                out.extend((len as u32).to_le_bytes());
+                for _ in 0..len {
+                    out.push(u.int_in_range(b' '..=b'~')?);
+                }
+            }
+            IdlType::Bytes => {
+                let len = u.int_in_range(0..=MAX_STRING_LEN)?;
+                out.extend((len as u32).to_le_bytes());
+                out.extend(u.bytes(len)?);
+            }
+            IdlType::PublicKey => {
+                let pubkey = match self.addresses.is_empty() || u.ratio(1, 8)? {
+                    true => Pubkey::new_from_array(u.arbitrary()?),
+                    false => *u.choose(self.addresses)?,
+                };
+                out.extend(pubkey.as_ref());
+            }
+            IdlType::Option(inner) => match u.arbitrary::<bool>()? {
+                true => {
+                    out.push(1);
+                    self.value(inner, u, out, depth + 1)?;
+                }
+                false => out.push(0),
+            },
+            IdlType::Vec(inner) | IdlType::HashSet(inner) | IdlType::BTreeSet(inner) => {
+                let len = u.int_in_range(0..=MAX_ELEMENTS)?;
+                out.extend((len as u32).to_le_bytes());
+                for _ in 0..len {
+                    self.value(inner, u, out, depth + 1)?;
+                }
+            }
+            IdlType::HashMap(key, value) | IdlType::BTreeMap(key, value) => {
+                let len = u.int_in_range(0..=MAX_ELEMENTS)?;
+                out.extend((len as u32).to_le_bytes());
+                for _ in 0..len {
+                    self.value(key, u, out, depth + 1)?;
+                    self.value(value, u, out, depth + 1)?;
+                }
+            }
+            IdlType::Array(inner, len) => {
+                for _ in 0..*len {
+                    self.value(inner, u, out, depth + 1)?;
+                }
+            }
+            IdlType::Tuple(tys) => {
+                for ty in tys.iter() {
+                    self.value(ty, u, out, depth + 1)?;
+                }
+            }
+            IdlType::Defined(name) => {
+                let type_def = self.type_def(name).ok_or(Error::IncorrectFormat)?;
+                self.type_def_value(type_def, u, out, depth + 1)?;
+            }
+            IdlType::Generic(_) => return Err(Error::IncorrectFormat),
+        }
+        Ok(())
+    }
+
+    fn type_def_value(
+        &self,
+        type_def: &IdlTypeDef,
+        u: &mut Unstructured,
+        out: &mut Vec<u8>,
+        depth: usize,
+    ) -> Result<()> {
+        if !type_def.generics.is_empty() {
+            return Err(Error::IncorrectFormat);
+        }
+        let fields = |fields: &[IdlTypeStructField], u: &mut Unstructured, out: &mut Vec<u8>| {
+            fields
+                .iter()
+                .try_for_each(|f| self.value(&f.field_data_type, u, out, depth))
+        };
+        match &type_def.idl_type {
+            IdlTypeDefType::Struct {
+                fields: struct_fields,
+            } => fields(struct_fields, u, out),
+            IdlTypeDefType::Enum { variants } => {
+                let index = u.choose_index(variants.len())?;
+                out.push(index as u8);
+                match &variants[index].fields {
+                    Some(IdlTypeEnumFields::Named(named)) => fields(named, u, out),
+                    Some(IdlTypeEnumFields::Tuple(tys)) => {
+                        tys.iter().try_for_each(|ty| self.value(ty, u, out, depth))
+                    }
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    fn type_def(&self, name: &str) -> Option<&'a IdlTypeDef> {
+        self.idl
+            .types
+            .iter()
+            .chain(self.idl.accounts.iter())
+            .find(|t| t.name == name)
+    }
+}
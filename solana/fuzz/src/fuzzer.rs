@@ -0,0 +1,340 @@
+//! Runs generated instructions against a `TestHarness`, checking invariants
+//! after each one.
+use std::fmt;
+
+use arbitrary::{Result, Unstructured};
+use nautilus::{
+    solana_program::instruction::Instruction,
+    testing::{Failed, Processed, TestAccount, TestHarness},
+    Pubkey,
+};
+use nautilus_idl::Idl;
+
+use crate::{
+    accounts::{AccountResolver, Fault},
+    args::{Arg, ArgGenerator},
+    invariants,
+};
+
+/// Lamports each of the fuzzer's wallets starts with.
+const WALLET_LAMPORTS: u64 = 1_000_000_000_000;
+/// How many wallets besides the payer the fuzzer can sign for.
+const USERS: usize = 3;
+/// How many bytes of input `Fuzzer::run` generates for each run.
+const INPUT_LEN: usize = 1024;
+
+/// Checks the state a processed case left behind. Returns why the invariant
+/// doesn't hold, if it doesn't.
+pub type Invariant = fn(&Step) -> std::result::Result<(), String>;
+
+/// A case that was just processed, for invariants to check.
+pub struct Step<'a> {
+    pub idl: &'a Idl,
+    pub program_id: &'a Pubkey,
+    /// The harness, after the case was processed.
+    pub harness: &'a TestHarness,
+    pub case: &'a FuzzCase,
+    pub result: &'a std::result::Result<Processed, Failed>,
+}
+
+/// A generated call to one of the IDL's instructions.
+#[derive(Clone, Debug)]
+pub struct FuzzCase {
+    /// The IDL's name for the instruction, ie. `createPerson`.
+    pub name: String,
+    pub instruction: Instruction,
+    pub args: Vec<Arg>,
+    /// The IDL's name for each of the instruction's accounts.
+    pub account_names: Vec<String>,
+    /// The adversarial account passed, if any.
+    pub fault: Option<Fault>,
+    /// Accounts added to the harness for the case, ie. the copy a
+    /// `Fault::WrongOwner` passes.
+    pub accounts: Vec<(Pubkey, TestAccount)>,
+}
+
+impl FuzzCase {
+    /// Describes the case's fault with the accounts' names, ie. `` `person`
+    /// owned by another program ``.
+    pub fn describe_fault(&self) -> Option<String> {
+        let name = |i: usize| self.account_names.get(i).map_or("?", String::as_str);
+        Some(match self.fault? {
+            Fault::WrongOwner { account } => {
+                format!("`{}` owned by another program", name(account))
+            }
+            Fault::MissingSigner { account } => {
+                format!("`{}` without its signature", name(account))
+            }
+            Fault::Duplicate { account, of } => {
+                format!("`{}` passed again as `{}`", name(of), name(account))
+            }
+            Fault::WrongPda { account } => {
+                format!("`{}` derived from the wrong seeds", name(account))
+            }
+        })
+    }
+}
+
+impl fmt::Display for FuzzCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(fault) = self.describe_fault() {
+            write!(f, " with {}", fault)?;
+        }
+        writeln!(f)?;
+        for arg in self.args.iter() {
+            writeln!(f, "    {}: {}", arg.name, arg)?;
+        }
+        for (name, meta) in self.account_names.iter().zip(&self.instruction.accounts) {
+            write!(f, "    {}: {}", name, meta.pubkey)?;
+            match (meta.is_signer, meta.is_writable) {
+                (true, true) => writeln!(f, " (signer, writable)")?,
+                (true, false) => writeln!(f, " (signer)")?,
+                (false, true) => writeln!(f, " (writable)")?,
+                (false, false) => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An input that broke an invariant.
+#[derive(Clone, Debug)]
+pub struct Violation {
+    pub invariant: &'static str,
+    pub message: String,
+    /// The cases the input ran, the last being the one that broke the
+    /// invariant.
+    pub cases: Vec<FuzzCase>,
+    /// The last case's logs.
+    pub logs: Vec<String>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Invariant `{}` broken: {}", self.invariant, self.message)?;
+        for (i, case) in self.cases.iter().enumerate() {
+            write!(f, "{}. {}", i + 1, case)?;
+        }
+        for log in self.logs.iter() {
+            writeln!(f, "    {}", log)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Violation {}
+
+/// What a fuzzing run did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FuzzStats {
+    pub inputs: usize,
+    /// Cases processed.
+    pub cases: usize,
+    /// Cases the program processed successfully.
+    pub succeeded: usize,
+    /// Cases with an adversarial account.
+    pub faulty: usize,
+}
+
+impl std::ops::AddAssign for FuzzStats {
+    fn add_assign(&mut self, other: Self) {
+        self.inputs += other.inputs;
+        self.cases += other.cases;
+        self.succeeded += other.succeeded;
+        self.faulty += other.faulty;
+    }
+}
+
+/// Fuzzes a program with calls to its IDL's instructions.
+///
+/// Each input - ie. from `cargo fuzz`, or generated by `Fuzzer::run` - drives
+/// a sequence of cases against a fork of the harness, starting from the state
+/// it had when it was given to the fuzzer. So set up any state the program
+/// needs first, ie. by initializing its index.
+pub struct Fuzzer {
+    idl: Idl,
+    program_id: Pubkey,
+    harness: TestHarness,
+    payer: Pubkey,
+    users: Vec<Pubkey>,
+    invariants: Vec<(&'static str, Invariant)>,
+    max_cases: usize,
+}
+
+impl Fuzzer {
+    /// Creates a fuzzer for the program `idl` describes, loaded into `harness`
+    /// as `program_id`, checking the default invariants.
+    ///
+    /// Funds a payer and a few other wallets in the harness to sign with.
+    pub fn new(idl: Idl, program_id: Pubkey, mut harness: TestHarness) -> Self {
+        let payer = Pubkey::new_unique();
+        let users: Vec<Pubkey> = (0..USERS).map(|_| Pubkey::new_unique()).collect();
+        for wallet in std::iter::once(&payer).chain(users.iter()) {
+            harness.add_account(*wallet, TestAccount::wallet(WALLET_LAMPORTS));
+        }
+        Self {
+            idl,
+            program_id,
+            harness,
+            payer,
+            users,
+            invariants: invariants::DEFAULT.to_vec(),
+            max_cases: 8,
+        }
+    }
+
+    /// Checks another invariant after each case.
+    pub fn add_invariant(&mut self, name: &'static str, invariant: Invariant) {
+        self.invariants.push((name, invariant));
+    }
+
+    /// Sets the most cases an input can drive.
+    pub fn set_max_cases(&mut self, max_cases: usize) {
+        self.max_cases = max_cases;
+    }
+
+    /// The wallet paying for every case.
+    pub fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
+    /// The other wallets the fuzzer signs with.
+    pub fn users(&self) -> &[Pubkey] {
+        &self.users
+    }
+
+    /// The harness each input starts from.
+    pub fn harness(&self) -> &TestHarness {
+        &self.harness
+    }
+
+    /// Drives cases generated from `data` until it runs out, or an invariant
+    /// is broken.
+    ///
+    /// ```ignore
+    /// // fuzz/fuzz_targets/program.rs
+    /// fuzz_target!(|data: &[u8]| {
+    ///     if let Err(violation) = FUZZER.run_input(data) {
+    ///         panic!("{}", violation);
+    ///     }
+    /// });
+    /// ```
+    pub fn run_input(&self, data: &[u8]) -> std::result::Result<FuzzStats, Violation> {
+        let mut u = Unstructured::new(data);
+        let mut harness = self.harness.clone();
+        let mut stats = FuzzStats {
+            inputs: 1,
+            ..FuzzStats::default()
+        };
+        let mut cases: Vec<FuzzCase> = vec![];
+        // New addresses are numbered, so an input always generates the same
+        // cases.
+        let mut next_address = 0u64;
+        let mut fresh = || {
+            next_address += 1;
+            let mut bytes = [0u8; 32];
+            bytes[..4].copy_from_slice(b"fuzz");
+            bytes[24..].copy_from_slice(&next_address.to_le_bytes());
+            Pubkey::new_from_array(bytes)
+        };
+        while cases.len() < self.max_cases && !u.is_empty() {
+            let case = match self.generate(&harness, &mut u, &mut fresh) {
+                Ok(case) => case,
+                Err(_) => break,
+            };
+            for (pubkey, account) in case.accounts.iter() {
+                harness.add_account(*pubkey, account.clone());
+            }
+            let result = harness.process(&case.instruction);
+            stats.cases += 1;
+            stats.succeeded += result.is_ok() as usize;
+            stats.faulty += case.fault.is_some() as usize;
+            let step = Step {
+                idl: &self.idl,
+                program_id: &self.program_id,
+                harness: &harness,
+                case: &case,
+                result: &result,
+            };
+            let broken = self
+                .invariants
+                .iter()
+                .find_map(|(name, invariant)| invariant(&step).err().map(|e| (*name, e)));
+            cases.push(case);
+            if let Some((invariant, message)) = broken {
+                let logs = match result {
+                    Ok(processed) => processed.logs,
+                    Err(failed) => failed.logs,
+                };
+                return Err(Violation {
+                    invariant,
+                    message,
+                    cases,
+                    logs,
+                });
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Runs `inputs` pseudo-random inputs derived from `seed`, ie. to fuzz
+    /// from `cargo test` without `cargo fuzz`.
+    pub fn run(&self, inputs: usize, seed: u64) -> std::result::Result<FuzzStats, Violation> {
+        let mut state = seed;
+        let mut stats = FuzzStats::default();
+        for _ in 0..inputs {
+            let data: Vec<u8> = (0..INPUT_LEN / 8)
+                .flat_map(|_| splitmix64(&mut state).to_le_bytes())
+                .collect();
+            stats += self.run_input(&data)?;
+        }
+        Ok(stats)
+    }
+
+    /// Generates a call to one of the IDL's instructions, adversarial a third
+    /// of the time.
+    fn generate(
+        &self,
+        harness: &TestHarness,
+        u: &mut Unstructured,
+        fresh: &mut impl FnMut() -> Pubkey,
+    ) -> Result<FuzzCase> {
+        let instruction = u.choose(&self.idl.instructions)?;
+        let mut addresses = vec![self.payer];
+        addresses.extend(&self.users);
+        let (data, args) = ArgGenerator::new(&self.idl, &addresses).instruction(instruction, u)?;
+        let resolver = AccountResolver {
+            idl: &self.idl,
+            program_id: self.program_id,
+            harness,
+            payer: self.payer,
+            users: &self.users,
+        };
+        let mut layout = resolver.layout(instruction, &args, u, fresh)?;
+        let fault = match u.ratio(1, 3)? {
+            true => resolver.fault(instruction, &mut layout, u, fresh)?,
+            false => None,
+        };
+        Ok(FuzzCase {
+            name: instruction.name.clone(),
+            instruction: Instruction::new_with_bytes(self.program_id, &data, layout.metas),
+            args,
+            account_names: instruction
+                .accounts
+                .iter()
+                .map(|a| a.name.clone())
+                .collect(),
+            fault,
+            accounts: layout.accounts,
+        })
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
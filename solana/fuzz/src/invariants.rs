@@ -0,0 +1,62 @@
+//! Invariants every Nautilus program should hold, checked by default.
+use nautilus::testing::{Failed, TestError};
+
+use crate::fuzzer::{Invariant, Step};
+
+/// The invariants a `Fuzzer` checks unless told otherwise.
+pub const DEFAULT: &[(&str, Invariant)] = &[
+    ("never_panics", never_panics),
+    ("rejects_faults", rejects_faults),
+    ("records_decode", records_decode),
+];
+
+/// The program returns errors rather than panicking.
+pub fn never_panics(step: &Step) -> Result<(), String> {
+    match step.result {
+        Err(Failed {
+            error: TestError::Panicked(message),
+            ..
+        }) => Err(format!("the program panicked: {}", message)),
+        _ => Ok(()),
+    }
+}
+
+/// The program fails any instruction called with an adversarial account - so
+/// it checks owners, signers and program-derived addresses.
+pub fn rejects_faults(step: &Step) -> Result<(), String> {
+    match (step.case.describe_fault(), step.result) {
+        (Some(fault), Ok(_)) => Err(format!("`{}` succeeded with {}", step.case.name, fault)),
+        _ => Ok(()),
+    }
+}
+
+/// Every account the program writes to that holds one of the IDL's accounts
+/// still decodes as one - so the program and its IDL agree.
+pub fn records_decode(step: &Step) -> Result<(), String> {
+    if step.result.is_err() {
+        return Ok(());
+    }
+    let discriminators: Vec<[u8; 8]> = step
+        .idl
+        .accounts
+        .iter()
+        .filter_map(|a| a.discriminator())
+        .collect();
+    for meta in step.case.instruction.accounts.iter() {
+        let account = match step.harness.account(&meta.pubkey) {
+            Some(account) if meta.is_writable && account.owner == *step.program_id => account,
+            _ => continue,
+        };
+        let holds_account = account
+            .data
+            .get(..8)
+            .is_some_and(|d| discriminators.iter().any(|x| x == d));
+        if !holds_account {
+            continue;
+        }
+        if let Err(e) = step.idl.decode_account(&account.data) {
+            return Err(format!("{} doesn't decode: {}", meta.pubkey, e));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//
+//
+// ----------------------------------------------------------------
+//                          Nautilus Fuzz
+// ----------------------------------------------------------------
+//
+// Fuzzes a Nautilus program with calls to its instructions, generated from
+// its IDL with `arbitrary`.
+//
+// * Arguments are valid Borsh encodings of each instruction's argument types,
+//   following its `NautilusEntrypoint` discriminant.
+// * Accounts are laid out the way the IDL says to pass them - deriving
+//   records' addresses from their primary keys - or, a third of the time,
+//   with an adversarial account in place of one: owned by another program,
+//   missing its signature, passed twice or derived from the wrong seeds.
+//
+// Cases run natively against a `nautilus::testing::TestHarness`, and
+// invariants are checked after each one - by default, that the program never
+// panics, rejects every adversarial account and leaves its accounts decodable
+// with its IDL.
+//
+//
+pub use self::{
+    accounts::Fault,
+    args::{Arg, ArgGenerator},
+    fuzzer::{FuzzCase, FuzzStats, Fuzzer, Invariant, Step, Violation},
+};
+
+pub mod accounts;
+pub mod args;
+pub mod fuzzer;
+pub mod invariants;
+
+pub use arbitrary;
@@ -0,0 +1,242 @@
+use nautilus::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    invoke_signed,
+    solana_program::{program::set_return_data, rent::Rent, sysvar::Sysvar},
+    system_instruction,
+    testing::TestHarness,
+    AccountInfo, ProgramError, ProgramResult, Pubkey,
+};
+use nautilus_fuzz::{arbitrary::Unstructured, ArgGenerator, Fault, Fuzzer};
+use nautilus_idl::{idl_type_def::discriminator, Idl};
+
+/// A notes program, written by hand the way `#[nautilus]` would generate it:
+/// `createNote` creates a `Note` record at `["note", id]`, `updateNote` lets
+/// its authority change its text and `readNote` returns its text.
+fn idl() -> Idl {
+    serde_json::from_value(serde_json::json!({
+        "version": "0.1.0",
+        "name": "notes",
+        "instructions": [
+            {
+                "name": "createNote",
+                "accounts": [
+                    { "name": "new_note", "isMut": true, "isSigner": false, "type": "account", "desc": "new_note" },
+                    { "name": "feePayer", "isMut": true, "isSigner": true, "type": "feePayer", "desc": "The transaction fee payer" },
+                    { "name": "systemProgram", "isMut": false, "isSigner": false, "type": "systemProgram", "desc": "The System Program" }
+                ],
+                "args": [
+                    { "name": "id", "type": "u8" },
+                    { "name": "text", "type": "string" },
+                    { "name": "authority", "type": "publicKey" }
+                ],
+                "discriminant": { "type": "u8", "value": 0 }
+            },
+            {
+                "name": "updateNote",
+                "accounts": [
+                    { "name": "note", "isMut": true, "isSigner": false, "type": "account", "desc": "note" },
+                    { "name": "authority", "isMut": false, "isSigner": true, "type": "account", "desc": "authority" }
+                ],
+                "args": [{ "name": "text", "type": "string" }],
+                "discriminant": { "type": "u8", "value": 1 }
+            },
+            {
+                "name": "readNote",
+                "accounts": [
+                    { "name": "note", "isMut": false, "isSigner": false, "type": "account", "desc": "note" }
+                ],
+                "args": [],
+                "discriminant": { "type": "u8", "value": 2 }
+            },
+            {
+                "name": "tagNote",
+                "accounts": [],
+                "args": [
+                    { "name": "mood", "type": { "defined": "Mood" } },
+                    { "name": "tags", "type": { "vec": "string" } },
+                    { "name": "pinned", "type": { "option": "u64" } },
+                    { "name": "colors", "type": { "array": ["u8", 3] } },
+                    { "name": "votes", "type": { "hashMap": ["publicKey", "i32"] } }
+                ],
+                "discriminant": { "type": "u8", "value": 3 }
+            }
+        ],
+        "accounts": [
+            {
+                "name": "Note",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "id", "type": "u8" },
+                        { "name": "text", "type": "string" },
+                        { "name": "authority", "type": "publicKey" }
+                    ]
+                },
+                "config": { "tableName": "note", "primaryKey": "id", "autoincrement": false, "authorities": ["authority"] }
+            }
+        ],
+        "types": [
+            {
+                "name": "Mood",
+                "type": {
+                    "kind": "enum",
+                    "variants": [
+                        { "name": "Happy" },
+                        { "name": "Sad", "fields": ["u8"] },
+                        { "name": "Tired", "fields": [{ "name": "hours", "type": "u16" }] }
+                    ]
+                }
+            }
+        ],
+        "metadata": { "origin": "nautilus" }
+    }))
+    .unwrap()
+}
+
+/// A `Note`'s `id`, `text` and `authority`.
+type Note = (u8, String, Pubkey);
+
+fn note_data(note: &Note) -> Vec<u8> {
+    let mut data = discriminator("note").to_vec();
+    data.extend(note.try_to_vec().unwrap());
+    data
+}
+
+fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+    check_signer: bool,
+) -> ProgramResult {
+    let (discriminant, mut args) = data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    match (discriminant, accounts) {
+        (0, [new_note, payer, system_program, ..]) => {
+            let (id, text, authority) = <(u8, String, Pubkey)>::deserialize(&mut args)?;
+            let (pda, bump) = Pubkey::find_program_address(&[b"note", &[id]], program_id);
+            if new_note.key != &pda {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            let data = note_data(&(id, text, authority));
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer.key,
+                    new_note.key,
+                    Rent::get()?.minimum_balance(data.len()),
+                    data.len() as u64,
+                    program_id,
+                ),
+                &[payer.clone(), new_note.clone(), system_program.clone()],
+                &[&[b"note", &[id], &[bump]]],
+            )?;
+            new_note.data.borrow_mut().copy_from_slice(&data);
+            Ok(())
+        }
+        (1, [note, authority, ..]) => {
+            let text = String::deserialize(&mut args)?;
+            let mut stored = load(program_id, note)?;
+            if stored.2 != *authority.key || (check_signer && !authority.is_signer) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            stored.1 = text;
+            let data = note_data(&stored);
+            note.realloc(data.len(), false)?;
+            note.data.borrow_mut().copy_from_slice(&data);
+            Ok(())
+        }
+        (2, [note, ..]) => {
+            set_return_data(load(program_id, note)?.1.as_bytes());
+            Ok(())
+        }
+        (0..=2, _) => Err(ProgramError::NotEnoughAccountKeys),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn load(program_id: &Pubkey, note: &AccountInfo) -> Result<Note, ProgramError> {
+    if note.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let data = note.data.borrow();
+    match data.strip_prefix(&discriminator("note")[..]) {
+        Some(mut data) => Ok(Note::deserialize(&mut data)?),
+        None => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    process(program_id, accounts, data, true)
+}
+
+/// Lets anyone update a note - it never checks its authority signed.
+fn process_instruction_unchecked(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    process(program_id, accounts, data, false)
+}
+
+#[test]
+fn generates_args_the_idl_decodes() {
+    let idl = idl();
+    let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let generator = ArgGenerator::new(&idl, &addresses);
+    let bytes: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+    let mut u = Unstructured::new(&bytes);
+    for _ in 0..20 {
+        for instruction in idl.instructions.iter() {
+            let (data, args) = generator.instruction(instruction, &mut u).unwrap();
+            assert_eq!(data[0], instruction.discriminant.value);
+            assert_eq!(args.len(), instruction.args.len());
+            let accounts = vec!["11111111111111111111111111111111"; instruction.accounts.len()];
+            let decoded = idl
+                .decode_instruction(&data, &accounts)
+                .unwrap_or_else(|e| {
+                    panic!(
+                        "`{}` data {:?} doesn't decode: {}",
+                        instruction.name, data, e
+                    )
+                });
+            assert_eq!(decoded["name"], instruction.name.as_str());
+        }
+    }
+}
+
+#[test]
+fn a_program_checking_its_accounts_holds_every_invariant() {
+    let program_id = Pubkey::new_unique();
+    let harness = TestHarness::new(program_id, process_instruction);
+    let fuzzer = Fuzzer::new(idl(), program_id, harness);
+
+    let stats = fuzzer.run(300, 42).unwrap_or_else(|v| panic!("{}", v));
+    assert_eq!(stats.inputs, 300);
+    assert!(stats.succeeded > 0, "{:?}", stats);
+    assert!(stats.faulty > 0, "{:?}", stats);
+    // The same seed runs the same cases.
+    assert_eq!(fuzzer.run(300, 42).unwrap(), stats);
+}
+
+#[test]
+fn finds_a_missing_signer_check() {
+    let program_id = Pubkey::new_unique();
+    let harness = TestHarness::new(program_id, process_instruction_unchecked);
+    let fuzzer = Fuzzer::new(idl(), program_id, harness);
+
+    let violation = fuzzer.run(1000, 42).unwrap_err();
+    assert_eq!(violation.invariant, "rejects_faults", "{}", violation);
+    let case = violation.cases.last().unwrap();
+    assert_eq!(case.name, "updateNote");
+    assert_eq!(case.fault, Some(Fault::MissingSigner { account: 1 }));
+    assert_eq!(
+        violation.message,
+        "`updateNote` succeeded with `authority` without its signature"
+    );
+    // The note was created earlier in the same input.
+    assert!(violation.cases.iter().any(|c| c.name == "createNote"));
+}
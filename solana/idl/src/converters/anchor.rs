@@ -0,0 +1,1170 @@
+//! Converts between the Nautilus IDL and the Anchor IDL (spec `0.1.0`, as
+//! emitted by Anchor 0.30 onwards), so Anchor-aware explorers, wallets and
+//! tooling can work with Nautilus programs and vice versa.
+//!
+//! Anchor has no notion of tuples, maps or sets, so on export:
+//! * Tuples become synthetic tuple structs, which have the same Borsh layout.
+//! * Maps become a `vec` of their `(key, value)` tuples and sets a `vec` of
+//!   their items - again the same Borsh layout.
+//!
+//! Everything the Anchor IDL can't express - table names, primary keys,
+//! autoincrement, authorities, default instructions, account seeds, instruction
+//! account types, account sizes, enum variant docs and the original type of
+//! any converted field - is kept in a `nautilus` block in the Anchor IDL's
+//! `metadata`, which is used to restore the Nautilus IDL exactly on import.
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    idl_constant::IdlConstant,
+    idl_error::IdlError,
+    idl_instruction::{
+        IdlInstruction, IdlInstructionAccount, IdlInstructionArg, IdlInstructionDiscriminant,
+    },
+    idl_metadata::IdlMetadata,
+    idl_nautilus_config::IdlTypeDefNautilusConfig,
+    idl_type::IdlType,
+    idl_type_def::{
+        IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeEnumVariant, IdlTypeStructField,
+    },
+    Idl,
+};
+
+use super::pascal_case;
+
+/// The Anchor IDL spec version this module reads and writes.
+pub const ANCHOR_IDL_SPEC: &str = "0.1.0";
+
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+const TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+const SYSVAR_CLOCK_ID: &str = "SysvarC1ock11111111111111111111111111111111";
+const SYSVAR_INSTRUCTIONS_ID: &str = "Sysvar1nstructions1111111111111111111111111";
+const SYSVAR_RENT_ID: &str = "SysvarRent111111111111111111111111111111111";
+
+/// An Anchor IDL.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdl {
+    pub address: String,
+    pub metadata: AnchorIdlMetadata,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub instructions: Vec<AnchorIdlInstruction>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<AnchorIdlAccount>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<IdlError>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<AnchorIdlTypeDef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constants: Vec<AnchorIdlConst>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlMetadata {
+    pub name: String,
+    pub version: String,
+    pub spec: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The Nautilus extension block, holding everything the Anchor IDL can't
+    /// express.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nautilus: Option<NautilusExtension>,
+}
+
+/// Nautilus-only information carried in an Anchor IDL's metadata.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NautilusExtension {
+    pub origin: String,
+    /// The Nautilus configuration of every account, by type name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub configs: BTreeMap<String, IdlTypeDefNautilusConfig>,
+    /// The Nautilus account type (`index`, `feePayer`, `account`, ...) of each
+    /// of an instruction's accounts, by instruction name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub account_types: BTreeMap<String, Vec<String>>,
+    /// The original type of any field or argument whose type was converted,
+    /// by path (ie. `types.<Type>.<field>`, `types.<Type>.<Variant>.<field>`
+    /// or `instructions.<instruction>.<arg>`).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub type_overrides: BTreeMap<String, IdlType>,
+    /// The types added to stand in for tuples.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub synthetic_types: Vec<String>,
+    /// The docs of every documented enum variant, by path (ie.
+    /// `types.<Type>.<Variant>`), since Anchor variants have none.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variant_docs: BTreeMap<String, Vec<String>>,
+    /// The size of every fixed-size account, by type name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub sizes: BTreeMap<String, usize>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlInstruction {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub discriminator: Vec<u8>,
+    pub accounts: Vec<AnchorIdlInstructionAccountItem>,
+    pub args: Vec<AnchorIdlField>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AnchorIdlInstructionAccountItem {
+    Composite(AnchorIdlInstructionAccounts),
+    Single(AnchorIdlInstructionAccount),
+}
+
+/// A group of accounts, ie. a nested `Accounts` struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlInstructionAccounts {
+    pub name: String,
+    pub accounts: Vec<AnchorIdlInstructionAccountItem>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlInstructionAccount {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub writable: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub signer: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pda: Option<AnchorIdlPda>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlPda {
+    pub seeds: Vec<AnchorIdlSeed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program: Option<AnchorIdlSeed>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase", tag = "kind")]
+pub enum AnchorIdlSeed {
+    Const {
+        value: Vec<u8>,
+    },
+    Arg {
+        path: String,
+    },
+    Account {
+        path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        account: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlAccount {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlConst {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(rename = "type")]
+    pub ty: AnchorIdlType,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlField {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(rename = "type")]
+    pub ty: AnchorIdlType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlTypeDef {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serialization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repr: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<AnchorIdlTypeDefGeneric>,
+    #[serde(rename = "type")]
+    pub ty: AnchorIdlTypeDefTy,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase", tag = "kind")]
+pub enum AnchorIdlTypeDefGeneric {
+    Type {
+        name: String,
+    },
+    Const {
+        name: String,
+        #[serde(rename = "type")]
+        ty: String,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase", tag = "kind")]
+pub enum AnchorIdlTypeDefTy {
+    Struct {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fields: Option<AnchorIdlDefinedFields>,
+    },
+    Enum {
+        variants: Vec<AnchorIdlEnumVariant>,
+    },
+    Type {
+        alias: AnchorIdlType,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnchorIdlEnumVariant {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<AnchorIdlDefinedFields>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AnchorIdlDefinedFields {
+    Named(Vec<AnchorIdlField>),
+    Tuple(Vec<AnchorIdlType>),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnchorIdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    U256,
+    I256,
+    Bytes,
+    String,
+    Pubkey,
+    Option(Box<AnchorIdlType>),
+    COption(Box<AnchorIdlType>),
+    Vec(Box<AnchorIdlType>),
+    Array(Box<AnchorIdlType>, AnchorIdlArrayLen),
+    Defined {
+        name: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        generics: Vec<AnchorIdlGenericArg>,
+    },
+    Generic(String),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AnchorIdlArrayLen {
+    Value(usize),
+    Generic(String),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase", tag = "kind")]
+pub enum AnchorIdlGenericArg {
+    Type {
+        #[serde(rename = "type")]
+        ty: AnchorIdlType,
+    },
+    Const {
+        value: String,
+    },
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+pub trait AnchorIdlWrite {
+    fn write_to_anchor_json(&self, dir_path: &str) -> std::io::Result<()>;
+}
+
+impl AnchorIdlWrite for Idl {
+    fn write_to_anchor_json(&self, dir_path: &str) -> std::io::Result<()> {
+        if dir_path != "." {
+            fs::create_dir_all(dir_path)?;
+        }
+        let anchor_idl_path =
+            Path::join(Path::new(dir_path), format!("{}.anchor.json", &self.name));
+        let mut file = File::create(anchor_idl_path)?;
+        let json_string = self.to_anchor_json()?;
+        file.write_all(json_string.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl Idl {
+    /// Converts this IDL to an Anchor IDL.
+    pub fn to_anchor(&self) -> AnchorIdl {
+        AnchorIdl::from(self)
+    }
+
+    /// Converts this IDL to an Anchor IDL's JSON.
+    pub fn to_anchor_json(&self) -> std::io::Result<String> {
+        Ok(serde_json::to_string(&self.to_anchor())?)
+    }
+
+    /// Reads a Nautilus IDL from an Anchor IDL's JSON.
+    ///
+    /// An IDL exported by [`Idl::to_anchor`] is restored exactly. Any other
+    /// Anchor IDL must describe a program Nautilus clients can talk to: 1-byte
+    /// instruction discriminators, Borsh-serialized types Nautilus can
+    /// represent and accounts discriminated the way Nautilus discriminates
+    /// them.
+    pub fn from_anchor_json(json: &str) -> std::io::Result<Self> {
+        let anchor_idl: AnchorIdl = serde_json::from_str(json)?;
+        Self::try_from(anchor_idl)
+    }
+}
+
+impl From<&Idl> for AnchorIdl {
+    fn from(idl: &Idl) -> Self {
+        let mut export = AnchorExport::default();
+
+        let accounts = idl
+            .accounts
+            .iter()
+            .filter_map(|a| {
+                a.discriminator().map(|discriminator| AnchorIdlAccount {
+                    name: a.name.clone(),
+                    discriminator: discriminator.to_vec(),
+                })
+            })
+            .collect();
+        let configs = idl
+            .accounts
+            .iter()
+            .chain(idl.types.iter())
+            .filter_map(|t| t.config.clone().map(|c| (t.name.clone(), c)))
+            .collect();
+
+        let address = idl.metadata.address.clone().unwrap_or_default();
+        let instructions = idl
+            .instructions
+            .iter()
+            .map(|i| export.instruction(i, &address))
+            .collect();
+        let account_types = idl
+            .instructions
+            .iter()
+            .map(|i| {
+                let types = i.accounts.iter().map(|a| a.account_type.clone()).collect();
+                (i.name.clone(), types)
+            })
+            .collect();
+
+        let sizes = idl
+            .accounts
+            .iter()
+            .filter_map(|a| a.size.map(|size| (a.name.clone(), size)))
+            .collect();
+
+        let constants = idl
+            .constants
+            .iter()
+            .map(|c| AnchorIdlConst {
+                name: c.name.clone(),
+                docs: c.docs.clone(),
+                ty: export.overridden_type(&c.constant_type, format!("constants.{}", c.name)),
+                value: c.value.clone(),
+            })
+            .collect();
+        let mut types: Vec<AnchorIdlTypeDef> = idl
+            .accounts
+            .iter()
+            .chain(idl.types.iter())
+            .map(|t| export.type_def(t))
+            .collect();
+        types.append(&mut export.synthetic_types);
+
+        AnchorIdl {
+            address,
+            metadata: AnchorIdlMetadata {
+                name: idl.name.clone(),
+                version: idl.version.clone(),
+                spec: ANCHOR_IDL_SPEC.to_string(),
+                description: None,
+                nautilus: Some(NautilusExtension {
+                    origin: idl.metadata.origin.clone(),
+                    configs,
+                    account_types,
+                    type_overrides: export.type_overrides,
+                    synthetic_types: export.synthetic_type_names,
+                    variant_docs: export.variant_docs,
+                    sizes,
+                }),
+            },
+            docs: vec![],
+            instructions,
+            accounts,
+            errors: idl.errors.clone(),
+            types,
+            constants,
+        }
+    }
+}
+
+/// The state built up while exporting an IDL: the synthetic types standing in
+/// for tuples, the original types of converted fields and the docs Anchor
+/// can't hold.
+#[derive(Default)]
+struct AnchorExport {
+    synthetic_types: Vec<AnchorIdlTypeDef>,
+    synthetic_type_names: Vec<String>,
+    type_overrides: BTreeMap<String, IdlType>,
+    variant_docs: BTreeMap<String, Vec<String>>,
+}
+
+impl AnchorExport {
+    fn instruction(&mut self, instruction: &IdlInstruction, address: &str) -> AnchorIdlInstruction {
+        AnchorIdlInstruction {
+            name: instruction.name.clone(),
+            docs: instruction.docs.clone(),
+            discriminator: vec![instruction.discriminant.value],
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|a| AnchorIdlInstructionAccountItem::Single(instruction_account(a, address)))
+                .collect(),
+            args: instruction
+                .args
+                .iter()
+                .map(|a| {
+                    let path = format!("instructions.{}.{}", instruction.name, a.name);
+                    self.field(&a.name, &a.docs, &a.arg_type, path)
+                })
+                .collect(),
+        }
+    }
+
+    fn type_def(&mut self, type_def: &IdlTypeDef) -> AnchorIdlTypeDef {
+        let path = format!("types.{}", type_def.name);
+        let ty = match &type_def.idl_type {
+            IdlTypeDefType::Struct { fields } => AnchorIdlTypeDefTy::Struct {
+                fields: self.struct_fields(fields, &path),
+            },
+            IdlTypeDefType::Enum { variants } => AnchorIdlTypeDefTy::Enum {
+                variants: variants
+                    .iter()
+                    .map(|v| {
+                        let path = format!("{}.{}", path, v.name);
+                        if !v.docs.is_empty() {
+                            self.variant_docs.insert(path.clone(), v.docs.clone());
+                        }
+                        let fields = match &v.fields {
+                            Some(IdlTypeEnumFields::Named(fields)) => {
+                                self.struct_fields(fields, &path)
+                            }
+                            Some(IdlTypeEnumFields::Tuple(types)) => {
+                                Some(AnchorIdlDefinedFields::Tuple(
+                                    types
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(i, t)| {
+                                            self.overridden_type(t, format!("{}.{}", path, i))
+                                        })
+                                        .collect(),
+                                ))
+                            }
+                            None => None,
+                        };
+                        AnchorIdlEnumVariant {
+                            name: v.name.clone(),
+                            fields,
+                        }
+                    })
+                    .collect(),
+            },
+        };
+        AnchorIdlTypeDef {
+            name: type_def.name.clone(),
+            docs: type_def.docs.clone(),
+            serialization: None,
+            repr: None,
+            generics: type_def
+                .generics
+                .iter()
+                .map(|name| AnchorIdlTypeDefGeneric::Type { name: name.clone() })
+                .collect(),
+            ty,
+        }
+    }
+
+    /// Converts a struct's fields, where a tuple struct's fields are named by
+    /// their index.
+    fn struct_fields(
+        &mut self,
+        fields: &[IdlTypeStructField],
+        path: &str,
+    ) -> Option<AnchorIdlDefinedFields> {
+        if fields.is_empty() {
+            return None;
+        }
+        let is_tuple = fields
+            .iter()
+            .all(|f| f.name.chars().all(|c| c.is_ascii_digit()));
+        Some(match is_tuple {
+            true => AnchorIdlDefinedFields::Tuple(
+                fields
+                    .iter()
+                    .map(|f| {
+                        self.overridden_type(&f.field_data_type, format!("{}.{}", path, f.name))
+                    })
+                    .collect(),
+            ),
+            false => AnchorIdlDefinedFields::Named(
+                fields
+                    .iter()
+                    .map(|f| {
+                        let field_path = format!("{}.{}", path, f.name);
+                        self.field(&f.name, &f.docs, &f.field_data_type, field_path)
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    fn field(
+        &mut self,
+        name: &str,
+        docs: &[String],
+        idl_type: &IdlType,
+        path: String,
+    ) -> AnchorIdlField {
+        AnchorIdlField {
+            name: name.to_string(),
+            docs: docs.to_vec(),
+            ty: self.overridden_type(idl_type, path),
+        }
+    }
+
+    /// Converts a type, recording the original type under `path` if the
+    /// Anchor IDL can't express it.
+    fn overridden_type(&mut self, idl_type: &IdlType, path: String) -> AnchorIdlType {
+        if !is_anchor_type(idl_type) {
+            self.type_overrides.insert(path, idl_type.clone());
+        }
+        self.anchor_type(idl_type)
+    }
+
+    fn anchor_type(&mut self, idl_type: &IdlType) -> AnchorIdlType {
+        match idl_type {
+            IdlType::Bool => AnchorIdlType::Bool,
+            IdlType::U8 => AnchorIdlType::U8,
+            IdlType::U16 => AnchorIdlType::U16,
+            IdlType::U32 => AnchorIdlType::U32,
+            IdlType::U64 => AnchorIdlType::U64,
+            IdlType::U128 => AnchorIdlType::U128,
+            IdlType::I8 => AnchorIdlType::I8,
+            IdlType::I16 => AnchorIdlType::I16,
+            IdlType::I32 => AnchorIdlType::I32,
+            IdlType::I64 => AnchorIdlType::I64,
+            IdlType::I128 => AnchorIdlType::I128,
+            IdlType::Bytes => AnchorIdlType::Bytes,
+            IdlType::String => AnchorIdlType::String,
+            IdlType::PublicKey => AnchorIdlType::Pubkey,
+            IdlType::Array(t, len) => AnchorIdlType::Array(
+                Box::new(self.anchor_type(t)),
+                AnchorIdlArrayLen::Value(*len),
+            ),
+            IdlType::Option(t) => AnchorIdlType::Option(Box::new(self.anchor_type(t))),
+            IdlType::Vec(t) | IdlType::HashSet(t) | IdlType::BTreeSet(t) => {
+                AnchorIdlType::Vec(Box::new(self.anchor_type(t)))
+            }
+            IdlType::HashMap(k, v) | IdlType::BTreeMap(k, v) => {
+                let entry = IdlType::Tuple(vec![*k.clone(), *v.clone()]);
+                AnchorIdlType::Vec(Box::new(self.anchor_type(&entry)))
+            }
+            IdlType::Tuple(types) => self.tuple_type(types),
+            IdlType::Defined(name) => AnchorIdlType::Defined {
+                name: name.clone(),
+                generics: vec![],
+            },
+            IdlType::Generic(name) => AnchorIdlType::Generic(name.clone()),
+        }
+    }
+
+    /// Refers to the synthetic tuple struct standing in for a tuple, adding it
+    /// if it hasn't been yet.
+    ///
+    /// Any generic parameters used in the tuple become the tuple struct's own.
+    fn tuple_type(&mut self, types: &[IdlType]) -> AnchorIdlType {
+        let name = format!(
+            "Tuple{}",
+            types.iter().map(type_name_fragment).collect::<String>()
+        );
+        let mut generics = vec![];
+        types.iter().for_each(|t| generic_names(t, &mut generics));
+        if !self.synthetic_type_names.contains(&name) {
+            self.synthetic_type_names.push(name.clone());
+            let fields = types.iter().map(|t| self.anchor_type(t)).collect();
+            self.synthetic_types.push(AnchorIdlTypeDef {
+                name: name.clone(),
+                docs: vec![],
+                serialization: None,
+                repr: None,
+                generics: generics
+                    .iter()
+                    .map(|name| AnchorIdlTypeDefGeneric::Type { name: name.clone() })
+                    .collect(),
+                ty: AnchorIdlTypeDefTy::Struct {
+                    fields: Some(AnchorIdlDefinedFields::Tuple(fields)),
+                },
+            });
+        }
+        AnchorIdlType::Defined {
+            name,
+            generics: generics
+                .into_iter()
+                .map(|name| AnchorIdlGenericArg::Type {
+                    ty: AnchorIdlType::Generic(name),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts an instruction account, filling in its address when it's a
+/// well-known one and its seeds when it's the Nautilus Index.
+fn instruction_account(
+    account: &IdlInstructionAccount,
+    program_address: &str,
+) -> AnchorIdlInstructionAccount {
+    let pda = match account.account_type.as_str() {
+        "index" => Some(AnchorIdlPda {
+            seeds: index_seeds(),
+            program: None,
+        }),
+        _ => None,
+    };
+    AnchorIdlInstructionAccount {
+        name: account.name.clone(),
+        docs: match account.desc.is_empty() {
+            true => vec![],
+            false => vec![account.desc.clone()],
+        },
+        writable: account.is_mut,
+        signer: account.is_signer,
+        optional: false,
+        address: known_address(account, program_address),
+        pda,
+        relations: vec![],
+    }
+}
+
+fn index_seeds() -> Vec<AnchorIdlSeed> {
+    vec![
+        AnchorIdlSeed::Const {
+            value: b"nautilus_index".to_vec(),
+        },
+        AnchorIdlSeed::Const { value: vec![0] },
+    ]
+}
+
+/// The address of an instruction account, if it's always the same one.
+fn known_address(account: &IdlInstructionAccount, program_address: &str) -> Option<String> {
+    let address = match account.account_type.as_str() {
+        "systemProgram" => SYSTEM_PROGRAM_ID,
+        "tokenProgram" => TOKEN_PROGRAM_ID,
+        "associatedTokenProgram" => ASSOCIATED_TOKEN_PROGRAM_ID,
+        "tokenMetadataProgram" => TOKEN_METADATA_PROGRAM_ID,
+        "program" if !program_address.is_empty() => program_address,
+        "sysvar" => match account.name.as_str() {
+            "clock" => SYSVAR_CLOCK_ID,
+            "instructions" => SYSVAR_INSTRUCTIONS_ID,
+            "rent" => SYSVAR_RENT_ID,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(address.to_string())
+}
+
+/// The Nautilus account type of an instruction account, inferred from its
+/// address or seeds.
+fn infer_account_type(account: &AnchorIdlInstructionAccount, program_address: &str) -> String {
+    let account_type = match account.address.as_deref() {
+        Some(SYSTEM_PROGRAM_ID) => "systemProgram",
+        Some(TOKEN_PROGRAM_ID) => "tokenProgram",
+        Some(ASSOCIATED_TOKEN_PROGRAM_ID) => "associatedTokenProgram",
+        Some(TOKEN_METADATA_PROGRAM_ID) => "tokenMetadataProgram",
+        Some(SYSVAR_CLOCK_ID | SYSVAR_INSTRUCTIONS_ID | SYSVAR_RENT_ID) => "sysvar",
+        Some(address) if address == program_address => "program",
+        _ => match &account.pda {
+            Some(pda) if pda.seeds == index_seeds() => "index",
+            _ => "account",
+        },
+    };
+    account_type.to_string()
+}
+
+/// Whether the Anchor IDL can express a type as-is.
+fn is_anchor_type(idl_type: &IdlType) -> bool {
+    match idl_type {
+        IdlType::Tuple(_)
+        | IdlType::HashMap(..)
+        | IdlType::BTreeMap(..)
+        | IdlType::HashSet(_)
+        | IdlType::BTreeSet(_) => false,
+        IdlType::Array(t, _) | IdlType::Option(t) | IdlType::Vec(t) => is_anchor_type(t),
+        _ => true,
+    }
+}
+
+/// Names a type within a synthetic tuple struct's name, ie. `(u8, Vec<String>)`
+/// is named `TupleU8VecString`.
+fn type_name_fragment(idl_type: &IdlType) -> String {
+    match idl_type {
+        IdlType::Array(t, len) => format!("Array{}{}", type_name_fragment(t), len),
+        IdlType::Option(t) => format!("Option{}", type_name_fragment(t)),
+        IdlType::Vec(t) => format!("Vec{}", type_name_fragment(t)),
+        IdlType::HashSet(t) => format!("HashSet{}", type_name_fragment(t)),
+        IdlType::BTreeSet(t) => format!("BTreeSet{}", type_name_fragment(t)),
+        IdlType::HashMap(k, v) => {
+            format!("HashMap{}{}", type_name_fragment(k), type_name_fragment(v))
+        }
+        IdlType::BTreeMap(k, v) => {
+            format!("BTreeMap{}{}", type_name_fragment(k), type_name_fragment(v))
+        }
+        IdlType::Tuple(types) => format!(
+            "Tuple{}End",
+            types.iter().map(type_name_fragment).collect::<String>()
+        ),
+        IdlType::Defined(name) | IdlType::Generic(name) => pascal_case(name),
+        _ => pascal_case(
+            serde_json::to_value(idl_type)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default()
+                .as_str(),
+        ),
+    }
+}
+
+/// Collects the names of the generic parameters used in a type, in order.
+fn generic_names(idl_type: &IdlType, names: &mut Vec<String>) {
+    match idl_type {
+        IdlType::Generic(name) if !names.contains(name) => names.push(name.clone()),
+        IdlType::Array(t, _)
+        | IdlType::Option(t)
+        | IdlType::Vec(t)
+        | IdlType::HashSet(t)
+        | IdlType::BTreeSet(t) => generic_names(t, names),
+        IdlType::HashMap(k, v) | IdlType::BTreeMap(k, v) => {
+            generic_names(k, names);
+            generic_names(v, names);
+        }
+        IdlType::Tuple(types) => types.iter().for_each(|t| generic_names(t, names)),
+        _ => (),
+    }
+}
+
+impl TryFrom<AnchorIdl> for Idl {
+    type Error = io::Error;
+
+    fn try_from(anchor_idl: AnchorIdl) -> Result<Self, Self::Error> {
+        let extension = anchor_idl.metadata.nautilus.clone().unwrap_or_default();
+        let import = AnchorImport {
+            extension: &extension,
+        };
+
+        let mut accounts = vec![];
+        let mut types = vec![];
+        for anchor_type_def in anchor_idl
+            .types
+            .iter()
+            .filter(|t| !extension.synthetic_types.contains(&t.name))
+        {
+            let mut type_def = import.type_def(anchor_type_def)?;
+            let anchor_account = anchor_idl
+                .accounts
+                .iter()
+                .find(|a| a.name == anchor_type_def.name);
+            match anchor_account {
+                Some(anchor_account) => {
+                    if type_def.config.is_none() {
+                        type_def.config = Some(state_config(&type_def.name));
+                    }
+                    if type_def.discriminator().map(|d| d.to_vec())
+                        != Some(anchor_account.discriminator.clone())
+                    {
+                        return Err(invalid_data(format!(
+                            "The discriminator of account `{}` isn't one Nautilus derives",
+                            anchor_account.name
+                        )));
+                    }
+                    accounts.push(type_def);
+                }
+                None if type_def.config.is_some() => accounts.push(type_def),
+                None => types.push(type_def),
+            }
+        }
+        if let Some(missing) = anchor_idl
+            .accounts
+            .iter()
+            .find(|a| !accounts.iter().any(|t: &IdlTypeDef| t.name == a.name))
+        {
+            return Err(invalid_data(format!(
+                "Account `{}` has no type definition",
+                missing.name
+            )));
+        }
+
+        // Only Nautilus' extension records which accounts are fixed-size, so
+        // any other IDL's account sizes are computed.
+        let all_types: Vec<IdlTypeDef> = accounts.iter().chain(types.iter()).cloned().collect();
+        for account in accounts.iter_mut() {
+            account.size = match &anchor_idl.metadata.nautilus {
+                Some(_) => extension.sizes.get(&account.name).copied(),
+                None => account.account_size(&all_types),
+            };
+        }
+
+        let instructions = anchor_idl
+            .instructions
+            .iter()
+            .map(|i| import.instruction(i, &anchor_idl.address))
+            .collect::<io::Result<Vec<_>>>()?;
+        let constants = anchor_idl
+            .constants
+            .iter()
+            .map(|c| {
+                let path = format!("constants.{}", c.name);
+                let mut constant = IdlConstant::new(
+                    c.name.clone(),
+                    import.overridden_type(&c.ty, &path)?,
+                    c.value.clone(),
+                );
+                constant.docs = c.docs.clone();
+                Ok(constant)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let origin = match extension.origin.is_empty() {
+            true => "anchor".to_string(),
+            false => extension.origin.clone(),
+        };
+        let address = match anchor_idl.address.is_empty() {
+            true => None,
+            false => Some(anchor_idl.address),
+        };
+
+        let mut idl = Idl::new(
+            anchor_idl.metadata.version,
+            anchor_idl.metadata.name,
+            instructions,
+            accounts,
+            types,
+            IdlMetadata { origin, address },
+        );
+        idl.constants = constants;
+        idl.errors = anchor_idl.errors;
+        Ok(idl)
+    }
+}
+
+/// The configuration of a state account described by an Anchor IDL without
+/// Nautilus' extension, discriminated by its lowercased name as Nautilus does.
+fn state_config(type_name: &str) -> IdlTypeDefNautilusConfig {
+    IdlTypeDefNautilusConfig {
+        discrminator_str: Some(type_name.to_lowercase()),
+        table_name: None,
+        primary_key: None,
+        autoincrement: None,
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    }
+}
+
+/// Imports an Anchor IDL's components, restoring what Nautilus' extension
+/// block holds.
+struct AnchorImport<'a> {
+    extension: &'a NautilusExtension,
+}
+
+impl AnchorImport<'_> {
+    fn instruction(
+        &self,
+        instruction: &AnchorIdlInstruction,
+        program_address: &str,
+    ) -> io::Result<IdlInstruction> {
+        let discriminant = match instruction.discriminator.as_slice() {
+            [value] => *value,
+            _ => {
+                return Err(invalid_data(format!(
+                    "Instruction `{}` doesn't have a 1-byte discriminator",
+                    instruction.name
+                )))
+            }
+        };
+
+        let mut anchor_accounts = vec![];
+        flatten_accounts(&instruction.accounts, &mut anchor_accounts);
+        let account_types = self
+            .extension
+            .account_types
+            .get(&instruction.name)
+            .filter(|types| types.len() == anchor_accounts.len());
+        let accounts = anchor_accounts
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                let account_type = match account_types {
+                    Some(types) => types[i].clone(),
+                    None => infer_account_type(a, program_address),
+                };
+                IdlInstructionAccount::new(
+                    a.name.clone(),
+                    a.writable,
+                    a.signer,
+                    account_type,
+                    a.docs.join(" "),
+                )
+            })
+            .collect();
+
+        let args = instruction
+            .args
+            .iter()
+            .map(|a| {
+                let path = format!("instructions.{}.{}", instruction.name, a.name);
+                let mut arg =
+                    IdlInstructionArg::new(a.name.clone(), self.overridden_type(&a.ty, &path)?);
+                arg.docs = a.docs.clone();
+                Ok(arg)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let mut idl_instruction = IdlInstruction::new(
+            &instruction.name,
+            accounts,
+            args,
+            IdlInstructionDiscriminant::new(discriminant),
+        );
+        idl_instruction.docs = instruction.docs.clone();
+        Ok(idl_instruction)
+    }
+
+    fn type_def(&self, type_def: &AnchorIdlTypeDef) -> io::Result<IdlTypeDef> {
+        if let Some(serialization) = type_def.serialization.as_deref() {
+            if serialization != "borsh" {
+                return Err(invalid_data(format!(
+                    "Type `{}` uses `{}` serialization, but only Borsh is supported",
+                    type_def.name, serialization
+                )));
+            }
+        }
+        let generics = type_def
+            .generics
+            .iter()
+            .map(|g| match g {
+                AnchorIdlTypeDefGeneric::Type { name } => Ok(name.clone()),
+                AnchorIdlTypeDefGeneric::Const { name, .. } => Err(invalid_data(format!(
+                    "Type `{}` has const generic `{}`, which isn't supported",
+                    type_def.name, name
+                ))),
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let path = format!("types.{}", type_def.name);
+        let idl_type = match &type_def.ty {
+            AnchorIdlTypeDefTy::Struct { fields } => IdlTypeDefType::Struct {
+                fields: self.struct_fields(fields.as_ref(), &path)?,
+            },
+            AnchorIdlTypeDefTy::Enum { variants } => IdlTypeDefType::Enum {
+                variants: variants
+                    .iter()
+                    .map(|v| {
+                        let path = format!("{}.{}", path, v.name);
+                        let fields = match &v.fields {
+                            Some(AnchorIdlDefinedFields::Named(_)) => {
+                                Some(IdlTypeEnumFields::Named(
+                                    self.struct_fields(v.fields.as_ref(), &path)?,
+                                ))
+                            }
+                            Some(AnchorIdlDefinedFields::Tuple(types)) => {
+                                Some(IdlTypeEnumFields::Tuple(
+                                    types
+                                        .iter()
+                                        .enumerate()
+                                        .map(|(i, t)| {
+                                            self.overridden_type(t, &format!("{}.{}", path, i))
+                                        })
+                                        .collect::<io::Result<Vec<_>>>()?,
+                                ))
+                            }
+                            None => None,
+                        };
+                        Ok(IdlTypeEnumVariant {
+                            name: v.name.clone(),
+                            docs: self
+                                .extension
+                                .variant_docs
+                                .get(&path)
+                                .cloned()
+                                .unwrap_or_default(),
+                            fields,
+                        })
+                    })
+                    .collect::<io::Result<Vec<_>>>()?,
+            },
+            AnchorIdlTypeDefTy::Type { .. } => {
+                return Err(invalid_data(format!(
+                    "Type `{}` is a type alias, which isn't supported",
+                    type_def.name
+                )))
+            }
+        };
+
+        Ok(IdlTypeDef {
+            name: type_def.name.clone(),
+            docs: type_def.docs.clone(),
+            generics,
+            idl_type,
+            config: self.extension.configs.get(&type_def.name).cloned(),
+            size: None,
+        })
+    }
+
+    /// Converts a struct's fields, naming a tuple struct's fields by their
+    /// index.
+    fn struct_fields(
+        &self,
+        fields: Option<&AnchorIdlDefinedFields>,
+        path: &str,
+    ) -> io::Result<Vec<IdlTypeStructField>> {
+        match fields {
+            Some(AnchorIdlDefinedFields::Named(fields)) => fields
+                .iter()
+                .map(|f| {
+                    let field_path = format!("{}.{}", path, f.name);
+                    let mut field = IdlTypeStructField::new(
+                        f.name.clone(),
+                        self.overridden_type(&f.ty, &field_path)?,
+                    );
+                    field.docs = f.docs.clone();
+                    Ok(field)
+                })
+                .collect(),
+            Some(AnchorIdlDefinedFields::Tuple(types)) => types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| {
+                    let field_path = format!("{}.{}", path, i);
+                    Ok(IdlTypeStructField::new(
+                        i.to_string(),
+                        self.overridden_type(t, &field_path)?,
+                    ))
+                })
+                .collect(),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Converts a type, unless the extension block holds its original type
+    /// under `path`.
+    fn overridden_type(&self, ty: &AnchorIdlType, path: &str) -> io::Result<IdlType> {
+        match self.extension.type_overrides.get(path) {
+            Some(idl_type) => Ok(idl_type.clone()),
+            None => idl_type(ty).map_err(|e| invalid_data(format!("{} (in `{}`)", e, path))),
+        }
+    }
+}
+
+/// Flattens an instruction's accounts, expanding any composite accounts in
+/// place.
+fn flatten_accounts<'a>(
+    items: &'a [AnchorIdlInstructionAccountItem],
+    accounts: &mut Vec<&'a AnchorIdlInstructionAccount>,
+) {
+    for item in items {
+        match item {
+            AnchorIdlInstructionAccountItem::Single(account) => accounts.push(account),
+            AnchorIdlInstructionAccountItem::Composite(composite) => {
+                flatten_accounts(&composite.accounts, accounts)
+            }
+        }
+    }
+}
+
+/// Converts an Anchor type, failing if Nautilus can't represent it.
+///
+/// Nautilus doesn't track the type arguments of a defined type, so they're
+/// dropped.
+fn idl_type(ty: &AnchorIdlType) -> Result<IdlType, String> {
+    Ok(match ty {
+        AnchorIdlType::Bool => IdlType::Bool,
+        AnchorIdlType::U8 => IdlType::U8,
+        AnchorIdlType::U16 => IdlType::U16,
+        AnchorIdlType::U32 => IdlType::U32,
+        AnchorIdlType::U64 => IdlType::U64,
+        AnchorIdlType::U128 => IdlType::U128,
+        AnchorIdlType::I8 => IdlType::I8,
+        AnchorIdlType::I16 => IdlType::I16,
+        AnchorIdlType::I32 => IdlType::I32,
+        AnchorIdlType::I64 => IdlType::I64,
+        AnchorIdlType::I128 => IdlType::I128,
+        AnchorIdlType::Bytes => IdlType::Bytes,
+        AnchorIdlType::String => IdlType::String,
+        AnchorIdlType::Pubkey => IdlType::PublicKey,
+        AnchorIdlType::Option(t) => IdlType::Option(Box::new(idl_type(t)?)),
+        AnchorIdlType::Vec(t) => IdlType::Vec(Box::new(idl_type(t)?)),
+        AnchorIdlType::Array(t, AnchorIdlArrayLen::Value(len)) => {
+            IdlType::Array(Box::new(idl_type(t)?), *len)
+        }
+        AnchorIdlType::Array(_, AnchorIdlArrayLen::Generic(len)) => {
+            return Err(format!("Array length `{}` is generic", len))
+        }
+        AnchorIdlType::Defined { name, .. } => IdlType::Defined(name.clone()),
+        AnchorIdlType::Generic(name) => IdlType::Generic(name.clone()),
+        unsupported => {
+            return Err(format!(
+                "Type `{}` isn't supported",
+                serde_json::to_string(unsupported).unwrap_or_default()
+            ))
+        }
+    })
+}
@@ -0,0 +1,641 @@
+//! Converts a JSON IDL to static reference documentation, in Markdown and in
+//! HTML.
+//!
+//! The documentation covers:
+//! * Every instruction, with its discriminant, its accounts (and whether each
+//!   is writable or a signer) and the byte offset of each argument.
+//! * Every account, with its discriminator, size, table configuration and
+//!   program-derived address seeds, and the byte offset of each field.
+//! * Every type, constant and error.
+//!
+//! Offsets are only listed up to the first field whose size varies, since
+//! everything after it moves with its length. Seeds are Borsh-serialized
+//! before being hashed, except string literals, which are used as is.
+//!
+//! Both formats are rendered from the same document, so they never disagree.
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use crate::{
+    idl_instruction::IdlInstruction,
+    idl_nautilus_config::IdlSeed,
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeStructField},
+    Idl,
+};
+
+use super::{field_type, string_literal};
+
+const STYLE: &str = "body { font-family: system-ui, sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; }
+table { border-collapse: collapse; margin: 1rem 0; }
+th, td { border: 1px solid #ccc; padding: 0.25rem 0.5rem; text-align: left; vertical-align: top; }
+th { background: #f4f4f4; }
+code { background: #f4f4f4; padding: 0 0.2rem; border-radius: 3px; }";
+
+pub trait DocsIdlWrite {
+    /// Writes `<name>.md` and `<name>.html`.
+    fn write_to_docs(&self, dir_path: &str) -> std::io::Result<()>;
+}
+
+pub trait DocsConverter {
+    fn to_markdown_string(&self) -> String;
+    fn to_html_string(&self) -> String;
+}
+
+impl DocsIdlWrite for Idl {
+    fn write_to_docs(&self, dir_path: &str) -> std::io::Result<()> {
+        if dir_path != "." {
+            fs::create_dir_all(dir_path)?;
+        }
+        let md_path = Path::join(Path::new(dir_path), format!("{}.md", &self.name));
+        let mut file = File::create(md_path)?;
+        file.write_all(self.to_markdown_string().as_bytes())?;
+        let html_path = Path::join(Path::new(dir_path), format!("{}.html", &self.name));
+        let mut file = File::create(html_path)?;
+        file.write_all(self.to_html_string().as_bytes())?;
+        Ok(())
+    }
+}
+
+impl DocsConverter for Idl {
+    fn to_markdown_string(&self) -> String {
+        let blocks: Vec<String> = document(self).iter().map(Block::to_markdown).collect();
+        blocks.join("\n\n") + "\n"
+    }
+
+    fn to_html_string(&self) -> String {
+        let blocks: Vec<String> = document(self).iter().map(Block::to_html).collect();
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{} reference</title>\n<style>\n{}\n</style>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+            escape_html(&self.name),
+            STYLE,
+            blocks.join("\n")
+        )
+    }
+}
+
+/// A run of text within a block.
+enum Inline {
+    Text(String),
+    Code(String),
+    /// A link to the heading with the given text, shown as code.
+    Link(String),
+}
+
+type Cell = Vec<Inline>;
+
+enum Block {
+    Heading(usize, String),
+    Paragraph(Vec<Inline>),
+    List(Vec<Vec<Inline>>),
+    Table {
+        headers: Vec<&'static str>,
+        rows: Vec<Vec<Cell>>,
+    },
+}
+
+fn text(text: &str) -> Inline {
+    Inline::Text(text.to_string())
+}
+
+fn code(code: &str) -> Inline {
+    Inline::Code(code.to_string())
+}
+
+/// The anchor of a heading, which matches the one GitHub generates for it
+/// as long as the heading is a single identifier.
+fn anchor(heading: &str) -> String {
+    heading.to_lowercase()
+}
+
+impl Block {
+    fn to_markdown(&self) -> String {
+        match self {
+            Block::Heading(level, heading) => format!("{} {}", "#".repeat(*level), heading),
+            Block::Paragraph(inlines) => markdown_inlines(inlines),
+            Block::List(items) => items
+                .iter()
+                .map(|item| format!("- {}", markdown_inlines(item)))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            Block::Table { headers, rows } => {
+                let mut lines = vec![
+                    format!("| {} |", headers.join(" | ")),
+                    format!("|{}", " --- |".repeat(headers.len())),
+                ];
+                lines.extend(rows.iter().map(|row| {
+                    let cells: Vec<String> = row
+                        .iter()
+                        .map(|cell| markdown_inlines(cell).replace('|', "\\|"))
+                        .collect();
+                    format!("| {} |", cells.join(" | "))
+                }));
+                lines.join("\n")
+            }
+        }
+    }
+
+    fn to_html(&self) -> String {
+        match self {
+            Block::Heading(level, heading) => format!(
+                "<h{level} id=\"{}\">{}</h{level}>",
+                escape_html(&anchor(heading)),
+                escape_html(heading),
+                level = level
+            ),
+            Block::Paragraph(inlines) => format!("<p>{}</p>", html_inlines(inlines)),
+            Block::List(items) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| format!("<li>{}</li>", html_inlines(item)))
+                    .collect();
+                format!("<ul>\n{}\n</ul>", items.join("\n"))
+            }
+            Block::Table { headers, rows } => {
+                let headers: Vec<String> = headers
+                    .iter()
+                    .map(|h| format!("<th>{}</th>", escape_html(h)))
+                    .collect();
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        let cells: Vec<String> = row
+                            .iter()
+                            .map(|cell| format!("<td>{}</td>", html_inlines(cell)))
+                            .collect();
+                        format!("<tr>{}</tr>", cells.join(""))
+                    })
+                    .collect();
+                format!(
+                    "<table>\n<thead><tr>{}</tr></thead>\n<tbody>\n{}\n</tbody>\n</table>",
+                    headers.join(""),
+                    rows.join("\n")
+                )
+            }
+        }
+    }
+}
+
+fn markdown_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.replace('\n', " "),
+            Inline::Code(code) => format!("`{}`", code),
+            Inline::Link(heading) => format!("[`{}`](#{})", heading, anchor(heading)),
+        })
+        .collect()
+}
+
+fn html_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape_html(text),
+            Inline::Code(code) => format!("<code>{}</code>", escape_html(code)),
+            Inline::Link(heading) => format!(
+                "<a href=\"#{}\"><code>{}</code></a>",
+                escape_html(&anchor(heading)),
+                escape_html(heading)
+            ),
+        })
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Splits a line of documentation into text and its backticked code spans.
+fn doc_inlines(doc: &str) -> Vec<Inline> {
+    let parts: Vec<&str> = doc.split('`').collect();
+    // An unclosed backtick is just a backtick.
+    if parts.len() % 2 == 0 {
+        return vec![text(doc)];
+    }
+    parts
+        .iter()
+        .enumerate()
+        .filter(|(_, part)| !part.is_empty())
+        .map(|(i, part)| match i % 2 {
+            0 => text(part),
+            _ => code(part),
+        })
+        .collect()
+}
+
+/// An item's doc comments as paragraphs, which are separated by blank lines.
+fn doc_paragraphs(docs: &[String]) -> Vec<Block> {
+    docs.split(|line| line.is_empty())
+        .filter(|lines| !lines.is_empty())
+        .map(|lines| Block::Paragraph(doc_inlines(&lines.join(" "))))
+        .collect()
+}
+
+/// An item's doc comments squeezed into a table cell.
+fn doc_cell(docs: &[String]) -> Cell {
+    doc_inlines(&docs.join(" "))
+}
+
+/// A type as it's written in Rust.
+fn type_name(idl_type: &IdlType) -> String {
+    match idl_type {
+        IdlType::Bool => "bool".to_string(),
+        IdlType::U8 => "u8".to_string(),
+        IdlType::U16 => "u16".to_string(),
+        IdlType::U32 => "u32".to_string(),
+        IdlType::U64 => "u64".to_string(),
+        IdlType::U128 => "u128".to_string(),
+        IdlType::I8 => "i8".to_string(),
+        IdlType::I16 => "i16".to_string(),
+        IdlType::I32 => "i32".to_string(),
+        IdlType::I64 => "i64".to_string(),
+        IdlType::I128 => "i128".to_string(),
+        IdlType::String => "String".to_string(),
+        IdlType::PublicKey => "Pubkey".to_string(),
+        IdlType::Bytes => "Vec<u8>".to_string(),
+        IdlType::Array(ty, len) => format!("[{}; {}]", type_name(ty), len),
+        IdlType::Vec(ty) => format!("Vec<{}>", type_name(ty)),
+        IdlType::HashSet(ty) => format!("HashSet<{}>", type_name(ty)),
+        IdlType::BTreeSet(ty) => format!("BTreeSet<{}>", type_name(ty)),
+        IdlType::HashMap(key, value) => {
+            format!("HashMap<{}, {}>", type_name(key), type_name(value))
+        }
+        IdlType::BTreeMap(key, value) => {
+            format!("BTreeMap<{}, {}>", type_name(key), type_name(value))
+        }
+        IdlType::Option(ty) => format!("Option<{}>", type_name(ty)),
+        IdlType::Tuple(types) => match types.len() {
+            1 => format!("({},)", type_name(&types[0])),
+            _ => format!(
+                "({})",
+                types
+                    .iter()
+                    .map(type_name)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        },
+        IdlType::Defined(name) | IdlType::Generic(name) => name.clone(),
+    }
+}
+
+/// A type's cell, linking to the type if the IDL defines it.
+fn type_cell(idl_type: &IdlType) -> Cell {
+    match idl_type {
+        IdlType::Defined(name) => vec![Inline::Link(name.clone())],
+        ty => vec![code(&type_name(ty))],
+    }
+}
+
+fn size_cell(size: Option<usize>) -> Cell {
+    match size {
+        Some(size) => vec![text(&size.to_string())],
+        None => vec![text("variable")],
+    }
+}
+
+/// Lists each value's offset and size, starting at `offset`. Offsets stop
+/// being known after the first value whose size varies.
+fn layout<'a>(
+    types: &[IdlTypeDef],
+    mut offset: Option<usize>,
+    values: impl Iterator<Item = &'a IdlType>,
+) -> Vec<(Cell, Cell)> {
+    values
+        .map(|ty| {
+            let size = ty.borsh_size(types);
+            let offset_cell = match offset {
+                Some(offset) => vec![text(&offset.to_string())],
+                None => vec![text("—")],
+            };
+            offset = offset.zip(size).map(|(offset, size)| offset + size);
+            (offset_cell, size_cell(size))
+        })
+        .collect()
+}
+
+fn document(idl: &Idl) -> Vec<Block> {
+    // Every defined type, for working out sizes and offsets.
+    let types: Vec<IdlTypeDef> = idl
+        .accounts
+        .iter()
+        .chain(idl.types.iter())
+        .cloned()
+        .collect();
+
+    let mut intro = vec![
+        text("Reference for version "),
+        code(&idl.version),
+        text(" of the "),
+        code(&idl.name),
+        text(" program"),
+    ];
+    if let Some(address) = &idl.metadata.address {
+        intro.extend([text(", deployed at "), code(address)]);
+    }
+    intro.push(text(". Generated by Nautilus from its IDL."));
+    let mut blocks = vec![Block::Heading(1, idl.name.clone()), Block::Paragraph(intro)];
+
+    if !idl.instructions.is_empty() {
+        blocks.push(Block::Heading(2, "Instructions".to_string()));
+        blocks.push(Block::Table {
+            headers: vec!["Discriminant", "Instruction", "Accounts", "Args"],
+            rows: idl
+                .instructions
+                .iter()
+                .map(|i| {
+                    vec![
+                        vec![code(&i.discriminant.value.to_string())],
+                        vec![Inline::Link(i.name.clone())],
+                        vec![text(&i.accounts.len().to_string())],
+                        vec![text(&i.args.len().to_string())],
+                    ]
+                })
+                .collect(),
+        });
+        for instruction in idl.instructions.iter() {
+            blocks.extend(instruction_blocks(instruction, &types));
+        }
+    }
+
+    if !idl.accounts.is_empty() {
+        blocks.push(Block::Heading(2, "Accounts".to_string()));
+        for account in idl.accounts.iter() {
+            blocks.extend(type_def_blocks(account, &types));
+        }
+    }
+
+    if !idl.types.is_empty() {
+        blocks.push(Block::Heading(2, "Types".to_string()));
+        for type_def in idl.types.iter() {
+            blocks.extend(type_def_blocks(type_def, &types));
+        }
+    }
+
+    if !idl.constants.is_empty() {
+        blocks.push(Block::Heading(2, "Constants".to_string()));
+        blocks.push(Block::Table {
+            headers: vec!["Constant", "Type", "Value", "Description"],
+            rows: idl
+                .constants
+                .iter()
+                .map(|c| {
+                    vec![
+                        vec![code(&c.name)],
+                        type_cell(&c.constant_type),
+                        vec![code(&c.value)],
+                        doc_cell(&c.docs),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    if !idl.errors.is_empty() {
+        blocks.push(Block::Heading(2, "Errors".to_string()));
+        blocks.push(Block::Table {
+            headers: vec!["Code", "Error", "Message"],
+            rows: idl
+                .errors
+                .iter()
+                .map(|e| {
+                    vec![
+                        vec![code(&format!("{} (0x{:x})", e.code, e.code))],
+                        vec![code(&e.name)],
+                        doc_inlines(e.msg.as_deref().unwrap_or_default()),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    blocks
+}
+
+/// Documents an instruction's accounts and its arguments, which follow its
+/// 1-byte discriminant.
+fn instruction_blocks(instruction: &IdlInstruction, types: &[IdlTypeDef]) -> Vec<Block> {
+    let mut blocks = vec![Block::Heading(3, instruction.name.clone())];
+    blocks.extend(doc_paragraphs(&instruction.docs));
+    blocks.push(Block::Paragraph(vec![
+        text("Discriminant: "),
+        code(&instruction.discriminant.value.to_string()),
+    ]));
+
+    let yes_no = |flag: bool| vec![text(if flag { "yes" } else { "no" })];
+    if !instruction.accounts.is_empty() {
+        blocks.push(Block::Table {
+            headers: vec!["#", "Account", "Writable", "Signer", "Description"],
+            rows: instruction
+                .accounts
+                .iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    vec![
+                        vec![text(&i.to_string())],
+                        vec![code(&a.name)],
+                        yes_no(a.is_mut),
+                        yes_no(a.is_signer),
+                        doc_inlines(&a.desc),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    if !instruction.args.is_empty() {
+        let offsets = layout(types, Some(1), instruction.args.iter().map(|a| &a.arg_type));
+        blocks.push(Block::Table {
+            headers: vec!["Offset", "Arg", "Type", "Size", "Description"],
+            rows: instruction
+                .args
+                .iter()
+                .zip(offsets)
+                .map(|(a, (offset, size))| {
+                    vec![
+                        offset,
+                        vec![code(&a.name)],
+                        type_cell(&a.arg_type),
+                        size,
+                        doc_cell(&a.docs),
+                    ]
+                })
+                .collect(),
+        });
+    }
+    blocks
+}
+
+/// Documents an account or a type: its configuration, and the layout of its
+/// fields or variants, after any discriminator.
+fn type_def_blocks(type_def: &IdlTypeDef, types: &[IdlTypeDef]) -> Vec<Block> {
+    let mut blocks = vec![Block::Heading(3, type_def.name.clone())];
+    blocks.extend(doc_paragraphs(&type_def.docs));
+
+    let discriminator = type_def.discriminator();
+    let mut facts: Vec<Vec<Inline>> = vec![];
+    if !type_def.generics.is_empty() {
+        facts.push(vec![
+            text("Generic over "),
+            code(&type_def.generics.join(", ")),
+        ]);
+    }
+    if let Some(discriminator) = discriminator {
+        facts.push(vec![
+            text("Discriminator: "),
+            code(&format!("{:?}", discriminator)),
+        ]);
+        let size = type_def.size.or_else(|| type_def.account_size(types));
+        facts.push(match size {
+            Some(size) => vec![text(&format!("Size: {} bytes", size))],
+            None => vec![text("Size: variable")],
+        });
+    }
+    if let Some(config) = &type_def.config {
+        if let Some(table_name) = &config.table_name {
+            facts.push(vec![text("Table: "), code(table_name)]);
+        }
+        if let Some(primary_key) = &config.primary_key {
+            let mut fact = vec![text("Primary key: "), code(primary_key)];
+            if config.autoincrement == Some(true) {
+                fact.push(text(" (autoincrement)"));
+            }
+            facts.push(fact);
+        }
+        if !config.authorities.is_empty() {
+            let mut fact = vec![text("Authorities: ")];
+            for (i, authority) in config.authorities.iter().enumerate() {
+                if i > 0 {
+                    fact.push(text(", "));
+                }
+                fact.push(code(authority));
+            }
+            facts.push(fact);
+        }
+        if let Some(seeds) = seeds(type_def) {
+            facts.push(vec![text("Address: program-derived from "), code(&seeds)]);
+        }
+    }
+    if !facts.is_empty() {
+        blocks.push(Block::List(facts));
+    }
+
+    let start = match discriminator {
+        Some(_) => 8,
+        None => 0,
+    };
+    match &type_def.idl_type {
+        IdlTypeDefType::Struct { fields } => {
+            let mut rows = vec![];
+            if discriminator.is_some() {
+                rows.push(vec![
+                    vec![text("0")],
+                    vec![text("discriminator")],
+                    vec![code("[u8; 8]")],
+                    vec![text("8")],
+                    vec![text("Identifies the account's type.")],
+                ]);
+            }
+            rows.extend(field_rows(fields, types, start));
+            blocks.push(Block::Table {
+                headers: vec!["Offset", "Field", "Type", "Size", "Description"],
+                rows,
+            });
+        }
+        IdlTypeDefType::Enum { variants } => {
+            blocks.push(Block::Paragraph(vec![text(&format!(
+                "The variant's index is the byte at offset {}, followed by its fields.",
+                start
+            ))]));
+            blocks.push(Block::Table {
+                headers: vec!["Index", "Variant", "Fields", "Description"],
+                rows: variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        vec![
+                            vec![text(&i.to_string())],
+                            vec![code(&v.name)],
+                            variant_fields_cell(&v.fields),
+                            doc_cell(&v.docs),
+                        ]
+                    })
+                    .collect(),
+            });
+        }
+    }
+    blocks
+}
+
+fn field_rows(fields: &[IdlTypeStructField], types: &[IdlTypeDef], start: usize) -> Vec<Vec<Cell>> {
+    let offsets = layout(
+        types,
+        Some(start),
+        fields.iter().map(|f| &f.field_data_type),
+    );
+    fields
+        .iter()
+        .zip(offsets)
+        .map(|(f, (offset, size))| {
+            vec![
+                offset,
+                vec![code(&f.name)],
+                type_cell(&f.field_data_type),
+                size,
+                doc_cell(&f.docs),
+            ]
+        })
+        .collect()
+}
+
+fn variant_fields_cell(fields: &Option<IdlTypeEnumFields>) -> Cell {
+    match fields {
+        Some(IdlTypeEnumFields::Named(fields)) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|f| format!("{}: {}", f.name, type_name(&f.field_data_type)))
+                .collect();
+            vec![code(&format!("{{ {} }}", fields.join(", ")))]
+        }
+        Some(IdlTypeEnumFields::Tuple(types)) => {
+            let types: Vec<String> = types.iter().map(type_name).collect();
+            vec![code(&format!("({})", types.join(", ")))]
+        }
+        None => vec![],
+    }
+}
+
+/// The seeds an account's address is derived from, ie. `["person", id: u32]`.
+///
+/// A record's seeds are its table name and primary key.
+fn seeds(type_def: &IdlTypeDef) -> Option<String> {
+    let config = type_def.config.as_ref()?;
+    let seed = |key: &str, ty: Option<&IdlType>| match ty {
+        Some(ty) => format!("{}: {}", key, type_name(ty)),
+        None => key.to_string(),
+    };
+    let seeds: Vec<String> = match (&config.table_name, &config.primary_key) {
+        (Some(table_name), Some(primary_key)) => vec![
+            string_literal(table_name),
+            seed(primary_key, field_type(type_def, primary_key)),
+        ],
+        _ if config.seeds.is_empty() => return None,
+        _ => config
+            .seeds
+            .iter()
+            .map(|s| match s {
+                IdlSeed::Lit { value } => string_literal(value),
+                IdlSeed::Field { key } => seed(key, field_type(type_def, key)),
+                IdlSeed::Param { key, value } => seed(key, Some(value)),
+            })
+            .collect(),
+    };
+    Some(format!("[{}]", seeds.join(", ")))
+}
@@ -0,0 +1,1238 @@
+//! Converts a JSON IDL to a Go client package.
+//!
+//! The generated package only depends on the Go standard library and
+//! `github.com/gagliardetto/solana-go`, and contains:
+//! * A Go type for every account and type, along with `Encode<Type>` and
+//!   `Decode<Type>` functions (de)serializing it with Borsh. Accounts
+//!   (de)serialize their discriminator the same way the Nautilus runtime does.
+//! * Account decoders, including one identifying an account by its
+//!   discriminator.
+//! * Program-derived address helpers for every record and state account.
+//! * A `New<Instruction>Instruction` builder for every instruction, returning a
+//!   `*solana.GenericInstruction`.
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use crate::{
+    idl_instruction::{IdlInstruction, IdlInstructionAccount},
+    idl_nautilus_config::IdlSeed,
+    idl_type::IdlType,
+    idl_type_def::{discriminator, IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields},
+    Idl,
+};
+
+use super::{
+    camel_case, field_type, instruction_struct_name, is_generic, is_record, pascal_case,
+    string_literal, type_names, uses_index,
+};
+
+const IMPORTS: &str = r#"import (
+	"bytes"
+	"encoding/binary"
+	"fmt"
+	"io"
+	"math/big"
+	"sort"
+
+	"github.com/gagliardetto/solana-go"
+)"#;
+
+const HELPERS: &str = r#"// Uint128 is a Borsh `u128`, split into its low and high 64 bits.
+type Uint128 struct {
+	Lo uint64
+	Hi uint64
+}
+
+// BigInt converts the value to a big.Int.
+func (v Uint128) BigInt() *big.Int {
+	hi := new(big.Int).SetUint64(v.Hi)
+	return hi.Lsh(hi, 64).Add(hi, new(big.Int).SetUint64(v.Lo))
+}
+
+// Int128 is a Borsh `i128`, split into its low and high 64 bits.
+type Int128 struct {
+	Lo uint64
+	Hi int64
+}
+
+// BigInt converts the value to a big.Int.
+func (v Int128) BigInt() *big.Int {
+	hi := big.NewInt(v.Hi)
+	return hi.Lsh(hi, 64).Add(hi, new(big.Int).SetUint64(v.Lo))
+}
+
+// BorshEncoder serializes values with Borsh.
+type BorshEncoder struct {
+	buf bytes.Buffer
+}
+
+func NewBorshEncoder() *BorshEncoder {
+	return &BorshEncoder{}
+}
+
+// Bytes returns the data written so far.
+func (e *BorshEncoder) Bytes() []byte {
+	return e.buf.Bytes()
+}
+
+// WriteRaw writes data as is, without a length prefix.
+func (e *BorshEncoder) WriteRaw(data []byte) error {
+	_, err := e.buf.Write(data)
+	return err
+}
+
+func (e *BorshEncoder) writeFixed(v any) error {
+	return binary.Write(&e.buf, binary.LittleEndian, v)
+}
+
+func (e *BorshEncoder) WriteBool(v bool) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU8(v uint8) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU16(v uint16) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU32(v uint32) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU64(v uint64) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU128(v Uint128) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI8(v int8) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI16(v int16) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI32(v int32) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI64(v int64) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI128(v Int128) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteString(v string) error { return e.WriteBytes([]byte(v)) }
+func (e *BorshEncoder) WritePublicKey(v solana.PublicKey) error { return e.WriteRaw(v[:]) }
+
+func (e *BorshEncoder) WriteBytes(v []byte) error {
+	if err := e.WriteU32(uint32(len(v))); err != nil {
+		return err
+	}
+	return e.WriteRaw(v)
+}
+
+// BorshDecoder deserializes values with Borsh.
+type BorshDecoder struct {
+	data []byte
+}
+
+func NewBorshDecoder(data []byte) *BorshDecoder {
+	return &BorshDecoder{data: data}
+}
+
+// Remaining returns the data that hasn't been read yet.
+func (d *BorshDecoder) Remaining() []byte {
+	return d.data
+}
+
+// ReadRaw reads the next n bytes as is.
+func (d *BorshDecoder) ReadRaw(n int) ([]byte, error) {
+	if n < 0 || n > len(d.data) {
+		return nil, io.ErrUnexpectedEOF
+	}
+	data := d.data[:n]
+	d.data = d.data[n:]
+	return data, nil
+}
+
+func readFixed[T any](d *BorshDecoder) (T, error) {
+	var v T
+	data, err := d.ReadRaw(binary.Size(v))
+	if err != nil {
+		return v, err
+	}
+	err = binary.Read(bytes.NewReader(data), binary.LittleEndian, &v)
+	return v, err
+}
+
+func (d *BorshDecoder) ReadU8() (uint8, error) { return readFixed[uint8](d) }
+func (d *BorshDecoder) ReadU16() (uint16, error) { return readFixed[uint16](d) }
+func (d *BorshDecoder) ReadU32() (uint32, error) { return readFixed[uint32](d) }
+func (d *BorshDecoder) ReadU64() (uint64, error) { return readFixed[uint64](d) }
+func (d *BorshDecoder) ReadU128() (Uint128, error) { return readFixed[Uint128](d) }
+func (d *BorshDecoder) ReadI8() (int8, error) { return readFixed[int8](d) }
+func (d *BorshDecoder) ReadI16() (int16, error) { return readFixed[int16](d) }
+func (d *BorshDecoder) ReadI32() (int32, error) { return readFixed[int32](d) }
+func (d *BorshDecoder) ReadI64() (int64, error) { return readFixed[int64](d) }
+func (d *BorshDecoder) ReadI128() (Int128, error) { return readFixed[Int128](d) }
+
+func (d *BorshDecoder) ReadBool() (bool, error) {
+	v, err := d.ReadU8()
+	if err != nil {
+		return false, err
+	}
+	if v > 1 {
+		return false, fmt.Errorf("invalid bool: %d", v)
+	}
+	return v == 1, nil
+}
+
+func (d *BorshDecoder) ReadBytes() ([]byte, error) {
+	n, err := d.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	data, err := d.ReadRaw(int(n))
+	if err != nil {
+		return nil, err
+	}
+	return append([]byte{}, data...), nil
+}
+
+func (d *BorshDecoder) ReadString() (string, error) {
+	data, err := d.ReadBytes()
+	return string(data), err
+}
+
+func (d *BorshDecoder) ReadPublicKey() (solana.PublicKey, error) {
+	var v solana.PublicKey
+	data, err := d.ReadRaw(len(v))
+	if err != nil {
+		return v, err
+	}
+	copy(v[:], data)
+	return v, nil
+}
+
+// checkDiscriminator reads and checks an account's 8-byte discriminator.
+func (d *BorshDecoder) checkDiscriminator(discriminator [8]byte, name string) error {
+	data, err := d.ReadRaw(len(discriminator))
+	if err != nil {
+		return err
+	}
+	if !bytes.Equal(data, discriminator[:]) {
+		return fmt.Errorf("account data is not a `%s`", name)
+	}
+	return nil
+}
+
+func writeItems[T any](e *BorshEncoder, items []T, write func(*BorshEncoder, T) error) error {
+	for _, item := range items {
+		if err := write(e, item); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func readItems[T any](d *BorshDecoder, items []T, read func(*BorshDecoder) (T, error)) error {
+	for i := range items {
+		var err error
+		if items[i], err = read(d); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func vecEncoder[T any](write func(*BorshEncoder, T) error) func(*BorshEncoder, []T) error {
+	return func(e *BorshEncoder, v []T) error {
+		if err := e.WriteU32(uint32(len(v))); err != nil {
+			return err
+		}
+		return writeItems(e, v, write)
+	}
+}
+
+func vecDecoder[T any](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) ([]T, error) {
+	return func(d *BorshDecoder) ([]T, error) {
+		n, err := d.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		v := []T{}
+		for i := uint32(0); i < n; i++ {
+			item, err := read(d)
+			if err != nil {
+				return nil, err
+			}
+			v = append(v, item)
+		}
+		return v, nil
+	}
+}
+
+func optionEncoder[T any](write func(*BorshEncoder, T) error) func(*BorshEncoder, *T) error {
+	return func(e *BorshEncoder, v *T) error {
+		if v == nil {
+			return e.WriteU8(0)
+		}
+		if err := e.WriteU8(1); err != nil {
+			return err
+		}
+		return write(e, *v)
+	}
+}
+
+func optionDecoder[T any](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) (*T, error) {
+	return func(d *BorshDecoder) (*T, error) {
+		tag, err := d.ReadU8()
+		if err != nil {
+			return nil, err
+		}
+		switch tag {
+		case 0:
+			return nil, nil
+		case 1:
+			v, err := read(d)
+			if err != nil {
+				return nil, err
+			}
+			return &v, nil
+		default:
+			return nil, fmt.Errorf("invalid option tag: %d", tag)
+		}
+	}
+}
+
+// mapEncoder writes a map's entries sorted by key, like Borsh does.
+func mapEncoder[K comparable, V any](
+	writeKey func(*BorshEncoder, K) error,
+	writeValue func(*BorshEncoder, V) error,
+	less func(K, K) bool,
+) func(*BorshEncoder, map[K]V) error {
+	return func(e *BorshEncoder, v map[K]V) error {
+		keys := make([]K, 0, len(v))
+		for key := range v {
+			keys = append(keys, key)
+		}
+		sort.Slice(keys, func(i, j int) bool { return less(keys[i], keys[j]) })
+		if err := e.WriteU32(uint32(len(keys))); err != nil {
+			return err
+		}
+		for _, key := range keys {
+			if err := writeKey(e, key); err != nil {
+				return err
+			}
+			if err := writeValue(e, v[key]); err != nil {
+				return err
+			}
+		}
+		return nil
+	}
+}
+
+func mapDecoder[K comparable, V any](
+	readKey func(*BorshDecoder) (K, error),
+	readValue func(*BorshDecoder) (V, error),
+) func(*BorshDecoder) (map[K]V, error) {
+	return func(d *BorshDecoder) (map[K]V, error) {
+		n, err := d.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		v := map[K]V{}
+		for i := uint32(0); i < n; i++ {
+			key, err := readKey(d)
+			if err != nil {
+				return nil, err
+			}
+			if v[key], err = readValue(d); err != nil {
+				return nil, err
+			}
+		}
+		return v, nil
+	}
+}
+
+func setEncoder[T comparable](write func(*BorshEncoder, T) error, less func(T, T) bool) func(*BorshEncoder, map[T]struct{}) error {
+	return mapEncoder(write, func(*BorshEncoder, struct{}) error { return nil }, less)
+}
+
+func setDecoder[T comparable](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) (map[T]struct{}, error) {
+	return mapDecoder(read, func(*BorshDecoder) (struct{}, error) { return struct{}{}, nil })
+}
+
+type ordered interface {
+	~int8 | ~int16 | ~int32 | ~int64 | ~uint8 | ~uint16 | ~uint32 | ~uint64 | ~string
+}
+
+func lessOrdered[T ordered](a, b T) bool { return a < b }
+func lessBool(a, b bool) bool { return !a && b }
+func lessUint128(a, b Uint128) bool { return a.Hi < b.Hi || (a.Hi == b.Hi && a.Lo < b.Lo) }
+func lessInt128(a, b Int128) bool { return a.Hi < b.Hi || (a.Hi == b.Hi && a.Lo < b.Lo) }
+func lessPublicKey(a, b solana.PublicKey) bool { return bytes.Compare(a[:], b[:]) < 0 }
+
+// lessEncoded orders keys without a natural order by their encoded bytes.
+func lessEncoded[T any](write func(*BorshEncoder, T) error) func(T, T) bool {
+	return func(a, b T) bool {
+		encodedA, encodedB := NewBorshEncoder(), NewBorshEncoder()
+		_ = write(encodedA, a)
+		_ = write(encodedB, b)
+		return bytes.Compare(encodedA.Bytes(), encodedB.Bytes()) < 0
+	}
+}
+
+// borshSeed serializes a value to be used as a seed.
+func borshSeed[T any](v T, write func(*BorshEncoder, T) error) ([]byte, error) {
+	e := NewBorshEncoder()
+	err := write(e, v)
+	return e.Bytes(), err
+}"#;
+
+pub trait GoIdlWrite {
+    fn write_to_go(&self, dir_path: &str) -> std::io::Result<()>;
+}
+
+pub trait GoConverter {
+    fn to_go_string(&self) -> String;
+}
+
+impl GoIdlWrite for Idl {
+    fn write_to_go(&self, dir_path: &str) -> std::io::Result<()> {
+        if dir_path != "." {
+            fs::create_dir_all(dir_path)?;
+        }
+        let go_idl_path = Path::join(
+            Path::new(dir_path),
+            format!("{}.go", package_name(&self.name)),
+        );
+
+        let mut file = File::create(go_idl_path)?;
+        let go_string = self.to_go_string();
+        file.write_all(go_string.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl GoConverter for Idl {
+    fn to_go_string(&self) -> String {
+        let mut sections = vec![
+            format!(
+                "// Code generated by Nautilus from the `{}` IDL (v{}). DO NOT EDIT.\n\npackage {}",
+                self.name,
+                self.version,
+                package_name(&self.name)
+            ),
+            IMPORTS.to_string(),
+        ];
+        if let Some(address) = &self.metadata.address {
+            sections.push(format!(
+                "var ProgramID = solana.MustPublicKeyFromBase58({})",
+                string_literal(address)
+            ));
+        }
+        sections.push(HELPERS.to_string());
+
+        let all_types = self.accounts.iter().chain(self.types.iter());
+        sections.extend(all_types.map(|t| t.to_go_string()));
+        sections.extend(self.accounts.iter().map(account_helpers));
+        if let Some(decode_account) = decode_account_helpers(&self.accounts) {
+            sections.push(decode_account);
+        }
+        if self.accounts.iter().any(is_record) || uses_index(self) {
+            sections.push(index_helpers());
+        }
+        let type_names = type_names(self);
+        sections.extend(
+            self.instructions
+                .iter()
+                .map(|i| instruction_to_go_string(i, &type_names)),
+        );
+
+        sections.join("\n\n") + "\n"
+    }
+}
+
+/// Builds a `New<Name>Instruction(..)` function, along with structs for its
+/// accounts and arguments.
+///
+/// Accounts with well-known addresses (programs, sysvars and the Nautilus
+/// Index) are filled in automatically. The instruction data is the
+/// instruction's discriminant followed by its arguments.
+impl GoConverter for IdlInstruction {
+    fn to_go_string(&self) -> String {
+        instruction_to_go_string(self, &[])
+    }
+}
+
+/// Renders an instruction, naming its accounts and arguments structs so they
+/// don't clash with any of `type_names`.
+fn instruction_to_go_string(instruction: &IdlInstruction, type_names: &[&str]) -> String {
+    let name = pascal_case(&instruction.name);
+    let accounts_name = instruction_struct_name(&instruction.name, "Accounts", type_names);
+    let args_name = instruction_struct_name(&instruction.name, "Args", type_names);
+    let mut sections = vec![];
+    let mut params = vec!["programID solana.PublicKey".to_string()];
+
+    let account_fields: Vec<String> = instruction
+        .accounts
+        .iter()
+        .filter(|a| known_address(a).is_none())
+        .map(|a| format!("\t{} solana.PublicKey", pascal_case(&a.name)))
+        .collect();
+    if !account_fields.is_empty() {
+        sections.push(format!(
+            "// {name} are the accounts of a `{}` instruction that need to be provided.\ntype {name} struct {{\n{}\n}}",
+            instruction.name,
+            account_fields.join("\n"),
+            name = accounts_name,
+        ));
+        params.push(format!("accounts {}", accounts_name));
+    }
+    if !instruction.args.is_empty() {
+        sections.push(format!(
+            "// {name} are the arguments of a `{}` instruction.\ntype {name} struct {{\n{}\n}}",
+            instruction.name,
+            instruction
+                .args
+                .iter()
+                .map(|a| format!("\t{} {}", pascal_case(&a.name), a.arg_type.to_go_string()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            name = args_name,
+        ));
+        params.push(format!("args {}", args_name));
+    }
+
+    let mut body = vec![];
+    if instruction
+        .accounts
+        .iter()
+        .any(|a| a.account_type == "index")
+    {
+        body.push(
+            "indexAddress, _, err := FindNautilusIndexAddress(programID)\nif err != nil {\n\treturn nil, err\n}"
+                .to_string(),
+        );
+    }
+    body.push("e := NewBorshEncoder()".to_string());
+    let mut writes = vec![format!("e.WriteU8({})", instruction.discriminant.value)];
+    writes.extend(
+        instruction
+            .args
+            .iter()
+            .map(|a| encode_call(&a.arg_type, &format!("args.{}", pascal_case(&a.name)))),
+    );
+    body.extend(
+        writes
+            .iter()
+            .map(|w| format!("if err := {}; err != nil {{\n\treturn nil, err\n}}", w)),
+    );
+    let metas = instruction
+        .accounts
+        .iter()
+        .map(|a| {
+            let pubkey =
+                known_address(a).unwrap_or_else(|| format!("accounts.{}", pascal_case(&a.name)));
+            format!(
+                "\tsolana.NewAccountMeta({}, {}, {}),",
+                pubkey, a.is_mut, a.is_signer
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    body.push(format!(
+        "return solana.NewInstruction(programID, solana.AccountMetaSlice{{\n{}\n}}, e.Bytes()), nil",
+        metas
+    ));
+
+    sections.push(format!(
+        "// New{name}Instruction creates a `{}` instruction.\nfunc New{name}Instruction({}) (*solana.GenericInstruction, error) {{\n{}\n}}",
+        instruction.name,
+        params.join(", "),
+        indent(&body.join("\n")),
+        name = name,
+    ));
+    sections.join("\n\n")
+}
+
+/// Builds a type's Go type along with its `Encode<Type>` and `Decode<Type>`
+/// functions.
+///
+/// Enums are represented as an interface implemented by a struct for each of
+/// their variants.
+impl GoConverter for IdlTypeDef {
+    fn to_go_string(&self) -> String {
+        let name = &self.name;
+        let type_params = go_type_params(&self.generics);
+        let type_args = go_type_args(&self.generics);
+        let discriminator = self.discriminator();
+        let mut sections = vec![];
+        if let Some(discriminator) = discriminator {
+            sections.push(format!(
+                "var {} = [8]byte{{{}}}",
+                discriminator_var(name),
+                discriminator
+                    .iter()
+                    .map(|b| b.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ));
+        }
+
+        let mut encode_body = vec![];
+        let mut decode_body = vec![];
+        if discriminator.is_some() {
+            encode_body.push(format!(
+                "if err := e.WriteRaw({}[:]); err != nil {{\n\treturn err\n}}",
+                discriminator_var(name)
+            ));
+        }
+        let decode_result = match &self.idl_type {
+            IdlTypeDefType::Struct { fields } => {
+                sections.push(format!(
+                    "type {}{} struct {{\n{}\n}}",
+                    name,
+                    type_params,
+                    fields
+                        .iter()
+                        .map(|f| format!(
+                            "\t{} {}",
+                            go_field_name(&f.name),
+                            f.field_data_type.to_go_string()
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ));
+                if discriminator.is_some() {
+                    decode_body.push(format!(
+                        "if err = d.checkDiscriminator({}, {}); err != nil {{\n\treturn\n}}",
+                        discriminator_var(name),
+                        string_literal(name)
+                    ));
+                }
+                for f in fields {
+                    let member = format!("v.{}", go_field_name(&f.name));
+                    encode_body.push(format!(
+                        "if err := {}; err != nil {{\n\treturn err\n}}",
+                        encode_call(&f.field_data_type, &member)
+                    ));
+                    decode_body.push(format!(
+                        "if {}, err = {}; err != nil {{\n\treturn\n}}",
+                        member,
+                        decode_call(&f.field_data_type)
+                    ));
+                }
+                encode_body.push("return nil".to_string());
+                decode_body.push("return".to_string());
+                format!("(v {}{}, err error)", name, type_args)
+            }
+            IdlTypeDefType::Enum { variants } => {
+                let marker = format!("is{}", name);
+                sections.push(format!(
+                    "// {} is one of {}.\ntype {}{} interface {{\n\t{}()\n}}",
+                    name,
+                    variants
+                        .iter()
+                        .map(|v| format!("{}{}", name, v.name))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    name,
+                    type_params,
+                    marker
+                ));
+                if discriminator.is_some() {
+                    decode_body.push(format!(
+                        "if err := d.checkDiscriminator({}, {}); err != nil {{\n\treturn nil, err\n}}",
+                        discriminator_var(name),
+                        string_literal(name)
+                    ));
+                }
+                decode_body.push(
+                    "variant, err := d.ReadU8()\nif err != nil {\n\treturn nil, err\n}".to_string(),
+                );
+                let mut encode_cases = vec![];
+                let mut decode_cases = vec![];
+                for (i, v) in variants.iter().enumerate() {
+                    let variant_type = format!("{}{}", name, v.name);
+                    let fields: Vec<(String, &IdlType)> = match &v.fields {
+                        Some(IdlTypeEnumFields::Named(fields)) => fields
+                            .iter()
+                            .map(|f| (go_field_name(&f.name), &f.field_data_type))
+                            .collect(),
+                        Some(IdlTypeEnumFields::Tuple(types)) => types
+                            .iter()
+                            .enumerate()
+                            .map(|(i, t)| (go_field_name(&i.to_string()), t))
+                            .collect(),
+                        None => vec![],
+                    };
+                    sections.push(format!(
+                        "type {}{} {}\n\nfunc ({}{}) {}() {{}}",
+                        variant_type,
+                        type_params,
+                        match fields.is_empty() {
+                            true => "struct{}".to_string(),
+                            false => format!(
+                                "struct {{\n{}\n}}",
+                                fields
+                                    .iter()
+                                    .map(|(n, t)| format!("\t{} {}", n, t.to_go_string()))
+                                    .collect::<Vec<String>>()
+                                    .join("\n")
+                            ),
+                        },
+                        variant_type,
+                        type_args,
+                        marker
+                    ));
+
+                    let mut encode_case = vec![];
+                    let mut decode_case = vec![];
+                    match fields.is_empty() {
+                        true => {
+                            encode_case.push(format!("return e.WriteU8({})", i));
+                            decode_case
+                                .push(format!("return {}{}{{}}, nil", variant_type, type_args));
+                        }
+                        false => {
+                            encode_case.push(format!(
+                                "if err := e.WriteU8({}); err != nil {{\n\treturn err\n}}",
+                                i
+                            ));
+                            decode_case.push(format!("var v {}{}", variant_type, type_args));
+                            for (field, t) in &fields {
+                                encode_case.push(format!(
+                                    "if err := {}; err != nil {{\n\treturn err\n}}",
+                                    encode_call(t, &format!("v.{}", field))
+                                ));
+                                decode_case.push(format!(
+                                    "if v.{}, err = {}; err != nil {{\n\treturn nil, err\n}}",
+                                    field,
+                                    decode_call(t)
+                                ));
+                            }
+                            encode_case.push("return nil".to_string());
+                            decode_case.push("return v, nil".to_string());
+                        }
+                    }
+                    encode_cases.push(format!(
+                        "case {}{}:\n{}",
+                        variant_type,
+                        type_args,
+                        indent(&encode_case.join("\n"))
+                    ));
+                    decode_cases.push(format!("case {}:\n{}", i, indent(&decode_case.join("\n"))));
+                }
+                encode_cases.push(format!(
+                    "default:\n\treturn fmt.Errorf(\"invalid variant for `{}`: %T\", v)",
+                    name
+                ));
+                decode_cases.push(format!(
+                    "default:\n\treturn nil, fmt.Errorf(\"invalid variant index for `{}`: %d\", variant)",
+                    name
+                ));
+                encode_body.push(format!(
+                    "switch v := v.(type) {{\n{}\n}}",
+                    encode_cases.join("\n")
+                ));
+                decode_body.push(format!(
+                    "switch variant {{\n{}\n}}",
+                    decode_cases.join("\n")
+                ));
+                format!("({}{}, error)", name, type_args)
+            }
+        };
+
+        let generic_encoders: String = self
+            .generics
+            .iter()
+            .map(|g| format!(", {} func(*BorshEncoder, {}) error", encoder_param(g), g))
+            .collect();
+        let generic_decoders: String = self
+            .generics
+            .iter()
+            .map(|g| format!(", {} func(*BorshDecoder) ({}, error)", decoder_param(g), g))
+            .collect();
+        sections.push(format!(
+            "func Encode{name}{type_params}(e *BorshEncoder, v {name}{type_args}{generic_encoders}) error {{\n{}\n}}",
+            indent(&encode_body.join("\n")),
+            name = name,
+            type_params = type_params,
+            type_args = type_args,
+            generic_encoders = generic_encoders,
+        ));
+        sections.push(format!(
+            "func Decode{name}{type_params}(d *BorshDecoder{generic_decoders}) {decode_result} {{\n{}\n}}",
+            indent(&decode_body.join("\n")),
+            name = name,
+            type_params = type_params,
+            generic_decoders = generic_decoders,
+            decode_result = decode_result,
+        ));
+        sections.join("\n\n")
+    }
+}
+
+impl GoConverter for IdlType {
+    fn to_go_string(&self) -> String {
+        match self {
+            IdlType::Array(idl_type, size) => format!("[{}]{}", size, idl_type.to_go_string()),
+            IdlType::Bool => "bool".to_string(),
+            IdlType::Bytes => "[]byte".to_string(),
+            IdlType::Defined(name) => name.clone(),
+            IdlType::I128 => "Int128".to_string(),
+            IdlType::I16 => "int16".to_string(),
+            IdlType::I32 => "int32".to_string(),
+            IdlType::I64 => "int64".to_string(),
+            IdlType::I8 => "int8".to_string(),
+            IdlType::Option(idl_type) => format!("*{}", idl_type.to_go_string()),
+            IdlType::Tuple(idl_types) => format!(
+                "struct{{ {} }}",
+                idl_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| format!("{} {}", go_field_name(&i.to_string()), t.to_go_string()))
+                    .collect::<Vec<String>>()
+                    .join("; ")
+            ),
+            IdlType::PublicKey => "solana.PublicKey".to_string(),
+            IdlType::String => "string".to_string(),
+            IdlType::U128 => "Uint128".to_string(),
+            IdlType::U16 => "uint16".to_string(),
+            IdlType::U32 => "uint32".to_string(),
+            IdlType::U64 => "uint64".to_string(),
+            IdlType::U8 => "uint8".to_string(),
+            IdlType::Vec(idl_type) => format!("[]{}", idl_type.to_go_string()),
+            IdlType::HashMap(key_type, value_type) | IdlType::BTreeMap(key_type, value_type) => {
+                format!(
+                    "map[{}]{}",
+                    key_type.to_go_string(),
+                    value_type.to_go_string()
+                )
+            }
+            IdlType::HashSet(idl_type) | IdlType::BTreeSet(idl_type) => {
+                format!("map[{}]struct{{}}", idl_type.to_go_string())
+            }
+            IdlType::Generic(name) => name.clone(),
+        }
+    }
+}
+
+/// Renders a function of type `func(*BorshEncoder, T) error` writing a value
+/// of the given type.
+fn encoder(idl_type: &IdlType) -> String {
+    let method = |name: &str| format!("(*BorshEncoder).Write{}", name);
+    match idl_type {
+        IdlType::Array(item_type, _) => format!(
+            "func(e *BorshEncoder, v {}) error {{ return writeItems(e, v[:], {}) }}",
+            idl_type.to_go_string(),
+            encoder(item_type)
+        ),
+        IdlType::Bool => method("Bool"),
+        IdlType::Bytes => method("Bytes"),
+        IdlType::Defined(name) => format!("Encode{}", name),
+        IdlType::I128 => method("I128"),
+        IdlType::I16 => method("I16"),
+        IdlType::I32 => method("I32"),
+        IdlType::I64 => method("I64"),
+        IdlType::I8 => method("I8"),
+        IdlType::Option(idl_type) => format!("optionEncoder({})", encoder(idl_type)),
+        IdlType::Tuple(idl_types) => format!(
+            "func(e *BorshEncoder, v {}) error {{ {}; return nil }}",
+            idl_type.to_go_string(),
+            idl_types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| format!(
+                    "if err := {}; err != nil {{ return err }}",
+                    encode_call(t, &format!("v.{}", go_field_name(&i.to_string())))
+                ))
+                .collect::<Vec<String>>()
+                .join("; ")
+        ),
+        IdlType::PublicKey => method("PublicKey"),
+        IdlType::String => method("String"),
+        IdlType::U128 => method("U128"),
+        IdlType::U16 => method("U16"),
+        IdlType::U32 => method("U32"),
+        IdlType::U64 => method("U64"),
+        IdlType::U8 => method("U8"),
+        IdlType::Vec(idl_type) => format!("vecEncoder({})", encoder(idl_type)),
+        IdlType::HashMap(key_type, value_type) | IdlType::BTreeMap(key_type, value_type) => {
+            format!(
+                "mapEncoder({}, {}, {})",
+                encoder(key_type),
+                encoder(value_type),
+                less(key_type)
+            )
+        }
+        IdlType::HashSet(idl_type) | IdlType::BTreeSet(idl_type) => {
+            format!("setEncoder({}, {})", encoder(idl_type), less(idl_type))
+        }
+        IdlType::Generic(name) => encoder_param(name),
+    }
+}
+
+/// Renders a function of type `func(*BorshDecoder) (T, error)` reading a value
+/// of the given type.
+fn decoder(idl_type: &IdlType) -> String {
+    let method = |name: &str| format!("(*BorshDecoder).Read{}", name);
+    match idl_type {
+        IdlType::Array(item_type, _) => format!(
+            "func(d *BorshDecoder) (v {}, err error) {{ err = readItems(d, v[:], {}); return }}",
+            idl_type.to_go_string(),
+            decoder(item_type)
+        ),
+        IdlType::Bool => method("Bool"),
+        IdlType::Bytes => method("Bytes"),
+        IdlType::Defined(name) => format!("Decode{}", name),
+        IdlType::I128 => method("I128"),
+        IdlType::I16 => method("I16"),
+        IdlType::I32 => method("I32"),
+        IdlType::I64 => method("I64"),
+        IdlType::I8 => method("I8"),
+        IdlType::Option(idl_type) => format!("optionDecoder({})", decoder(idl_type)),
+        IdlType::Tuple(idl_types) => format!(
+            "func(d *BorshDecoder) (v {}, err error) {{ {}; return }}",
+            idl_type.to_go_string(),
+            idl_types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| format!(
+                    "if v.{}, err = {}; err != nil {{ return }}",
+                    go_field_name(&i.to_string()),
+                    decode_call(t)
+                ))
+                .collect::<Vec<String>>()
+                .join("; ")
+        ),
+        IdlType::PublicKey => method("PublicKey"),
+        IdlType::String => method("String"),
+        IdlType::U128 => method("U128"),
+        IdlType::U16 => method("U16"),
+        IdlType::U32 => method("U32"),
+        IdlType::U64 => method("U64"),
+        IdlType::U8 => method("U8"),
+        IdlType::Vec(idl_type) => format!("vecDecoder({})", decoder(idl_type)),
+        IdlType::HashMap(key_type, value_type) | IdlType::BTreeMap(key_type, value_type) => {
+            format!("mapDecoder({}, {})", decoder(key_type), decoder(value_type))
+        }
+        IdlType::HashSet(idl_type) | IdlType::BTreeSet(idl_type) => {
+            format!("setDecoder({})", decoder(idl_type))
+        }
+        IdlType::Generic(name) => decoder_param(name),
+    }
+}
+
+/// Renders the function ordering a map's keys the way Borsh sorts them.
+fn less(key_type: &IdlType) -> String {
+    match key_type {
+        IdlType::I8
+        | IdlType::I16
+        | IdlType::I32
+        | IdlType::I64
+        | IdlType::U8
+        | IdlType::U16
+        | IdlType::U32
+        | IdlType::U64
+        | IdlType::String => format!("lessOrdered[{}]", key_type.to_go_string()),
+        IdlType::Bool => "lessBool".to_string(),
+        IdlType::U128 => "lessUint128".to_string(),
+        IdlType::I128 => "lessInt128".to_string(),
+        IdlType::PublicKey => "lessPublicKey".to_string(),
+        _ => format!("lessEncoded({})", encoder(key_type)),
+    }
+}
+
+/// Renders a call writing a value to the encoder `e`.
+fn encode_call(idl_type: &IdlType, value: &str) -> String {
+    let encoder = encoder(idl_type);
+    match encoder.strip_prefix("(*BorshEncoder).") {
+        Some(method) => format!("e.{}({})", method, value),
+        None => format!("{}(e, {})", encoder, value),
+    }
+}
+
+/// Renders a call reading a value from the decoder `d`.
+fn decode_call(idl_type: &IdlType) -> String {
+    let decoder = decoder(idl_type);
+    match decoder.strip_prefix("(*BorshDecoder).") {
+        Some(method) => format!("d.{}()", method),
+        None => format!("{}(d)", decoder),
+    }
+}
+
+/// Builds the decoder and the address helpers for an account.
+fn account_helpers(account: &IdlTypeDef) -> String {
+    let name = &account.name;
+    let mut helpers = vec![];
+    if account.discriminator().is_some() {
+        let type_params = go_type_params(&account.generics);
+        let generic_decoders: String = account
+            .generics
+            .iter()
+            .map(|g| format!(", {} func(*BorshDecoder) ({}, error)", decoder_param(g), g))
+            .collect();
+        let decoder_args: String = account
+            .generics
+            .iter()
+            .map(|g| format!(", {}", decoder_param(g)))
+            .collect();
+        helpers.push(format!(
+            "// Decode{name}Account decodes a `{name}` account's data, checking its discriminator.\nfunc Decode{name}Account{type_params}(data []byte{generic_decoders}) ({name}{type_args}, error) {{\n\treturn Decode{name}(NewBorshDecoder(data){decoder_args})\n}}",
+            name = name,
+            type_params = type_params,
+            type_args = go_type_args(&account.generics),
+            generic_decoders = generic_decoders,
+            decoder_args = decoder_args,
+        ));
+    }
+    if let Some(find_address) = find_address_helpers(account) {
+        helpers.push(find_address);
+    }
+    helpers.join("\n\n")
+}
+
+/// Builds the helpers deriving an account's program-derived address, returning
+/// the address and its bump.
+///
+/// Records are derived from their table name and primary key, while state
+/// accounts are derived from their declared seeds.
+///
+/// An IDL loaded from JSON may name a primary key or seed that isn't one of
+/// the account's fields. The address can't be derived then, so the helpers
+/// are left out rather than failing to generate the rest of the client.
+fn find_address_helpers(account: &IdlTypeDef) -> Option<String> {
+    let config = account.config.as_ref()?;
+    let name = &account.name;
+    let mut params = vec!["programID solana.PublicKey".to_string()];
+    let mut type_params = String::new();
+    let mut body = vec![];
+    let mut seeds = vec![];
+
+    if let (Some(table_name), Some(primary_key)) = (&config.table_name, &config.primary_key) {
+        let primary_key_ty = field_type(account, primary_key)?;
+        params.push(format!("primaryKey {}", primary_key_ty.to_go_string()));
+        seeds.push(format!("[]byte({})", string_literal(table_name)));
+        seeds.push(match primary_key_ty {
+            IdlType::String => "[]byte(primaryKey)".to_string(),
+            IdlType::U8 => "{primaryKey}".to_string(),
+            IdlType::PublicKey => "primaryKey[:]".to_string(),
+            ty => borsh_seed("primaryKey", ty, &mut body),
+        });
+        body.push(format!(
+            "return solana.FindProgramAddress([][]byte{{{}}}, programID)",
+            seeds.join(", ")
+        ));
+        let mut helpers = vec![format!(
+            "// Find{name}Address derives the address of the `{name}` record with the given primary key.\nfunc Find{name}Address({}) (solana.PublicKey, uint8, error) {{\n{}\n}}",
+            params.join(", "),
+            indent(&body.join("\n")),
+            name = name,
+        )];
+        if config.autoincrement == Some(true) {
+            let ty = primary_key_ty.to_go_string();
+            helpers.push(format!(
+                "// FindNext{name}Address derives the address of the next `{name}` record from the\n// Nautilus Index, returning it along with the record's primary key.\nfunc FindNext{name}Address(programID solana.PublicKey, index NautilusIndex) (solana.PublicKey, {ty}, error) {{\n\tprimaryKey := {ty}(index.NextCount({table}))\n\taddress, _, err := Find{name}Address(programID, primaryKey)\n\treturn address, primaryKey, err\n}}",
+                name = name,
+                ty = ty,
+                table = string_literal(table_name),
+            ));
+        }
+        return Some(helpers.join("\n\n"));
+    }
+
+    for seed in &config.seeds {
+        let (key, ty) = match seed {
+            IdlSeed::Lit { value } => {
+                seeds.push(format!("[]byte({})", string_literal(value)));
+                continue;
+            }
+            IdlSeed::Field { key } => (key, field_type(account, key)?),
+            IdlSeed::Param { key, value } => (key, value),
+        };
+        let param = go_ident(&camel_case(key));
+        params.push(format!("{} {}", param, ty.to_go_string()));
+        seeds.push(borsh_seed(&param, ty, &mut body));
+    }
+    // Field seeds of a generic type need the type argument's encoder.
+    if config.seeds.iter().any(|seed| match seed {
+        IdlSeed::Lit { .. } => false,
+        IdlSeed::Field { key } => field_type(account, key).map_or(false, is_generic),
+        IdlSeed::Param { value, .. } => is_generic(value),
+    }) {
+        type_params = go_type_params(&account.generics);
+        params.extend(
+            account
+                .generics
+                .iter()
+                .map(|g| format!("{} func(*BorshEncoder, {}) error", encoder_param(g), g)),
+        );
+    }
+    body.push(format!(
+        "return solana.FindProgramAddress([][]byte{{{}}}, programID)",
+        seeds.join(", ")
+    ));
+    Some(format!(
+        "// Find{name}Address derives the address of the `{name}` account.\nfunc Find{name}Address{}({}) (solana.PublicKey, uint8, error) {{\n{}\n}}",
+        type_params,
+        params.join(", "),
+        indent(&body.join("\n")),
+        name = name,
+    ))
+}
+
+/// Adds a statement Borsh-serializing a parameter to use it as a seed, and
+/// returns the seed's variable.
+fn borsh_seed(param: &str, idl_type: &IdlType, body: &mut Vec<String>) -> String {
+    body.push(format!(
+        "{param}Seed, err := borshSeed({param}, {})\nif err != nil {{\n\treturn solana.PublicKey{{}}, 0, err\n}}",
+        encoder(idl_type),
+        param = param,
+    ));
+    format!("{}Seed", param)
+}
+
+/// Builds `DecodeAccount(..)`, which identifies an account by its
+/// discriminator before decoding it.
+///
+/// Generic accounts are left out, since they can't be decoded without knowing
+/// their type arguments.
+fn decode_account_helpers(accounts: &[IdlTypeDef]) -> Option<String> {
+    let accounts: Vec<&IdlTypeDef> = accounts
+        .iter()
+        .filter(|a| a.generics.is_empty() && a.discriminator().is_some())
+        .collect();
+    if accounts.is_empty() {
+        return None;
+    }
+    let cases = accounts
+        .iter()
+        .map(|a| {
+            format!(
+                "case {}:\n\treturn Decode{}Account(data)",
+                discriminator_var(&a.name),
+                a.name
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    Some(format!(
+        "// DecodeAccount decodes an account's data based on its discriminator, returning\n// nil if it doesn't belong to any of this program's accounts.\nfunc DecodeAccount(data []byte) (any, error) {{\n\tvar discriminator [8]byte\n\tif len(data) < len(discriminator) {{\n\t\treturn nil, nil\n\t}}\n\tcopy(discriminator[:], data)\n\tswitch discriminator {{\n{}\n\tdefault:\n\t\treturn nil, nil\n\t}}\n}}",
+        indent(&cases)
+    ))
+}
+
+/// Builds the helpers for the Nautilus Index, which holds the current record
+/// count of every table.
+fn index_helpers() -> String {
+    format!(
+        "var NautilusIndexDiscriminator = [8]byte{{{}}}\n\n// NautilusIndex holds the current record count of every table.\ntype NautilusIndex map[string]uint32\n\n// NextCount returns the count the next record added to a table will have.\nfunc (index NautilusIndex) NextCount(tableName string) uint32 {{\n\treturn index[tableName] + 1\n}}\n\nfunc FindNautilusIndexAddress(programID solana.PublicKey) (solana.PublicKey, uint8, error) {{\n\treturn solana.FindProgramAddress([][]byte{{[]byte(\"nautilus_index\"), {{0}}}}, programID)\n}}\n\nfunc DecodeNautilusIndexAccount(data []byte) (NautilusIndex, error) {{\n\td := NewBorshDecoder(data)\n\tif err := d.checkDiscriminator(NautilusIndexDiscriminator, \"NautilusIndex\"); err != nil {{\n\t\treturn nil, err\n\t}}\n\tindex, err := mapDecoder((*BorshDecoder).ReadString, (*BorshDecoder).ReadU32)(d)\n\treturn NautilusIndex(index), err\n}}",
+        discriminator("nautilus_index")
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// The address of an instruction account that doesn't need to be provided by
+/// the caller, if there is one.
+fn known_address(account: &IdlInstructionAccount) -> Option<String> {
+    let address = match account.account_type.as_str() {
+        "index" => "indexAddress",
+        "systemProgram" => "solana.SystemProgramID",
+        "tokenProgram" => "solana.TokenProgramID",
+        "associatedTokenProgram" => "solana.SPLAssociatedTokenAccountProgramID",
+        "tokenMetadataProgram" => "solana.TokenMetadataProgramID",
+        "sysvar" => match account.name.as_str() {
+            "clock" => "solana.SysVarClockPubkey",
+            "instructions" => "solana.SysVarInstructionsPubkey",
+            "rent" => "solana.SysVarRentPubkey",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(address.to_string())
+}
+
+/// The Go package name for a program, ie. `programnautilus`.
+fn package_name(idl_name: &str) -> String {
+    idl_name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// The name of the variable holding an account's discriminator.
+fn discriminator_var(type_name: &str) -> String {
+    format!("{}Discriminator", type_name)
+}
+
+/// The exported Go name of a field, ie. `Field0` for a tuple's first field.
+fn go_field_name(name: &str) -> String {
+    match name.chars().all(|c| c.is_ascii_digit()) {
+        true => format!("Field{}", name),
+        false => pascal_case(name),
+    }
+}
+
+/// Suffixes names clashing with Go's keywords with an underscore.
+fn go_ident(name: &str) -> String {
+    const KEYWORDS: [&str; 25] = [
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "fallthrough",
+        "for",
+        "func",
+        "go",
+        "goto",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "type",
+        "var",
+    ];
+    match KEYWORDS.contains(&name) {
+        true => format!("{}_", name),
+        false => name.to_string(),
+    }
+}
+
+/// The name of the parameter holding a generic type parameter's encoder.
+fn encoder_param(generic: &str) -> String {
+    format!("encode{}", generic)
+}
+
+/// The name of the parameter holding a generic type parameter's decoder.
+fn decoder_param(generic: &str) -> String {
+    format!("decode{}", generic)
+}
+
+/// Renders a type's type parameters, ie. `[T any, U any]`.
+fn go_type_params(generics: &[String]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!(
+            "[{}]",
+            generics
+                .iter()
+                .map(|g| format!("{} any", g))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Renders a type's type arguments, ie. `[T, U]`.
+fn go_type_args(generics: &[String]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!("[{}]", generics.join(", ")),
+    }
+}
+
+fn indent(code: &str) -> String {
+    code.lines()
+        .map(|line| match line.is_empty() {
+            true => String::new(),
+            false => format!("\t{}", line),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,218 @@
+//! Converts a JSON IDL to JSON Schema (draft 2020-12) documents, so services
+//! that don't speak Borsh can validate a program's data.
+//!
+//! One schema is written per account and per type, describing values in the
+//! format `nautilus_idl::decode` writes them:
+//! * Integers are numbers bounded by their type's range, except `u128` and
+//!   `i128`, which are strings of digits.
+//! * Public keys are base58 strings.
+//! * Maps are arrays of `[key, value]` pairs.
+//! * Enums are externally tagged.
+//!
+//! Each schema is self-contained: every type the IDL defines is included
+//! under `$defs`.
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeStructField},
+    Idl,
+};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub trait JsonSchemaIdlWrite {
+    /// Writes `<type>.schema.json` for each account and type.
+    fn write_to_json_schema(&self, dir_path: &str) -> std::io::Result<()>;
+}
+
+pub trait JsonSchemaConverter {
+    /// The schema validating values of the named account or type, or `None`
+    /// if the IDL doesn't define it.
+    fn to_json_schema(&self, type_name: &str) -> Option<Value>;
+}
+
+impl JsonSchemaIdlWrite for Idl {
+    fn write_to_json_schema(&self, dir_path: &str) -> std::io::Result<()> {
+        if dir_path != "." {
+            fs::create_dir_all(dir_path)?;
+        }
+        for type_def in self.accounts.iter().chain(self.types.iter()) {
+            let schema = self
+                .to_json_schema(&type_def.name)
+                .expect("The IDL defines its own types");
+            let schema_path = Path::join(
+                Path::new(dir_path),
+                format!("{}.schema.json", type_def.name),
+            );
+            let mut file = File::create(schema_path)?;
+            file.write_all(serde_json::to_string_pretty(&schema)?.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl JsonSchemaConverter for Idl {
+    fn to_json_schema(&self, type_name: &str) -> Option<Value> {
+        let type_def = self
+            .accounts
+            .iter()
+            .chain(self.types.iter())
+            .find(|t| t.name == type_name)?;
+        let defs: Map<String, Value> = self
+            .accounts
+            .iter()
+            .chain(self.types.iter())
+            .map(|t| (t.name.clone(), type_def_schema(t)))
+            .collect();
+
+        let mut schema = Map::new();
+        schema.insert("$schema".to_string(), json!(DRAFT));
+        schema.insert(
+            "$id".to_string(),
+            json!(format!("{}/{}.schema.json", self.name, type_def.name)),
+        );
+        schema.insert("title".to_string(), json!(type_def.name));
+        schema.insert("$ref".to_string(), json!(def_ref(&type_def.name)));
+        schema.insert("$defs".to_string(), Value::Object(defs));
+        Some(Value::Object(schema))
+    }
+}
+
+fn def_ref(name: &str) -> String {
+    format!("#/$defs/{}", name)
+}
+
+/// Adds a `description` from doc comments, if there are any.
+fn describe(mut schema: Value, docs: &[String]) -> Value {
+    if let (Value::Object(object), false) = (&mut schema, docs.is_empty()) {
+        object.insert("description".to_string(), json!(docs.join("\n")));
+    }
+    schema
+}
+
+/// The schema of a defined type's values. A generic type's parameters are
+/// unconstrained, since they aren't known.
+fn type_def_schema(type_def: &IdlTypeDef) -> Value {
+    let schema = match &type_def.idl_type {
+        IdlTypeDefType::Struct { fields } => fields_schema(fields),
+        IdlTypeDefType::Enum { variants } => {
+            let variants: Vec<Value> = variants
+                .iter()
+                .map(|v| {
+                    let schema = match &v.fields {
+                        Some(IdlTypeEnumFields::Named(fields)) => fields_schema(fields),
+                        Some(IdlTypeEnumFields::Tuple(types)) => tuple_schema(types.iter()),
+                        None => return describe(json!({ "const": v.name }), &v.docs),
+                    };
+                    describe(
+                        json!({
+                            "type": "object",
+                            "properties": { &v.name: schema },
+                            "required": [v.name],
+                            "additionalProperties": false,
+                        }),
+                        &v.docs,
+                    )
+                })
+                .collect();
+            json!({ "oneOf": variants })
+        }
+    };
+    let mut schema = describe(schema, &type_def.docs);
+    if let Value::Object(object) = &mut schema {
+        object.insert("title".to_string(), json!(type_def.name));
+    }
+    schema
+}
+
+/// An object of a struct's fields, or an array if they're a tuple struct's.
+fn fields_schema(fields: &[IdlTypeStructField]) -> Value {
+    let is_tuple = !fields.is_empty()
+        && fields
+            .iter()
+            .all(|f| f.name.chars().all(|c| c.is_ascii_digit()));
+    if is_tuple {
+        return tuple_schema(fields.iter().map(|f| &f.field_data_type));
+    }
+    let properties: Map<String, Value> = fields
+        .iter()
+        .map(|f| {
+            (
+                f.name.clone(),
+                describe(idl_type_schema(&f.field_data_type), &f.docs),
+            )
+        })
+        .collect();
+    let required: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn tuple_schema<'a>(types: impl Iterator<Item = &'a IdlType>) -> Value {
+    let items: Vec<Value> = types.map(idl_type_schema).collect();
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": len,
+    })
+}
+
+fn integer_schema(min: i64, max: u64) -> Value {
+    json!({ "type": "integer", "minimum": min, "maximum": max })
+}
+
+/// The schema of a value of an IDL type.
+fn idl_type_schema(idl_type: &IdlType) -> Value {
+    match idl_type {
+        IdlType::Bool => json!({ "type": "boolean" }),
+        IdlType::U8 => integer_schema(0, u8::MAX as u64),
+        IdlType::U16 => integer_schema(0, u16::MAX as u64),
+        IdlType::U32 => integer_schema(0, u32::MAX as u64),
+        IdlType::U64 => integer_schema(0, u64::MAX),
+        IdlType::I8 => integer_schema(i8::MIN as i64, i8::MAX as u64),
+        IdlType::I16 => integer_schema(i16::MIN as i64, i16::MAX as u64),
+        IdlType::I32 => integer_schema(i32::MIN as i64, i32::MAX as u64),
+        IdlType::I64 => integer_schema(i64::MIN, i64::MAX as u64),
+        IdlType::U128 => json!({ "type": "string", "pattern": "^[0-9]+$" }),
+        IdlType::I128 => json!({ "type": "string", "pattern": "^-?[0-9]+$" }),
+        IdlType::String => json!({ "type": "string" }),
+        IdlType::PublicKey => json!({
+            "type": "string",
+            "pattern": "^[1-9A-HJ-NP-Za-km-z]{32,44}$",
+        }),
+        IdlType::Bytes => json!({ "type": "array", "items": idl_type_schema(&IdlType::U8) }),
+        IdlType::Array(ty, len) => json!({
+            "type": "array",
+            "items": idl_type_schema(ty),
+            "minItems": len,
+            "maxItems": len,
+        }),
+        IdlType::Vec(ty) => json!({ "type": "array", "items": idl_type_schema(ty) }),
+        IdlType::HashSet(ty) | IdlType::BTreeSet(ty) => json!({
+            "type": "array",
+            "items": idl_type_schema(ty),
+            "uniqueItems": true,
+        }),
+        IdlType::HashMap(key, value) | IdlType::BTreeMap(key, value) => json!({
+            "type": "array",
+            "items": tuple_schema([key.as_ref(), value.as_ref()].into_iter()),
+        }),
+        IdlType::Option(ty) => json!({ "anyOf": [{ "type": "null" }, idl_type_schema(ty)] }),
+        IdlType::Tuple(types) => tuple_schema(types.iter()),
+        IdlType::Defined(name) => json!({ "$ref": def_ref(name) }),
+        IdlType::Generic(_) => json!({}),
+    }
+}
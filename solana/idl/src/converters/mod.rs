@@ -1,2 +1,124 @@
+use crate::{
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType},
+    Idl,
+};
+
+pub mod anchor;
+pub mod docs;
+pub mod go;
+pub mod json_schema;
 pub mod py;
+pub mod rust;
+pub mod shank;
+pub mod sql;
 pub mod ts;
+
+/// Converts a `snake_case` or `camelCase` name to `PascalCase`.
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts a `snake_case` or `PascalCase` name to `camelCase`.
+pub(crate) fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Converts a `camelCase` or `PascalCase` name to `snake_case`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    let mut prev_is_lower = false;
+    for c in name.chars() {
+        if c.is_uppercase() && prev_is_lower {
+            snake.push('_');
+        }
+        prev_is_lower = c.is_lowercase() || c.is_ascii_digit();
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Whether an account is a record, rather than a state account.
+pub(crate) fn is_record(account: &IdlTypeDef) -> bool {
+    account
+        .config
+        .as_ref()
+        .map_or(false, |c| c.table_name.is_some())
+}
+
+/// Whether any instruction uses the Nautilus Index.
+pub(crate) fn uses_index(idl: &Idl) -> bool {
+    idl.instructions
+        .iter()
+        .flat_map(|i| i.accounts.iter())
+        .any(|a| a.account_type == "index")
+}
+
+/// The type of one of a struct's fields.
+pub(crate) fn field_type<'a>(type_def: &'a IdlTypeDef, field_name: &str) -> Option<&'a IdlType> {
+    match &type_def.idl_type {
+        IdlTypeDefType::Struct { fields } => fields
+            .iter()
+            .find(|f| f.name == field_name)
+            .map(|f| &f.field_data_type),
+        IdlTypeDefType::Enum { .. } => None,
+    }
+}
+
+/// Whether a type contains a generic parameter.
+pub(crate) fn is_generic(idl_type: &IdlType) -> bool {
+    match idl_type {
+        IdlType::Generic(_) => true,
+        IdlType::Array(t, _)
+        | IdlType::Option(t)
+        | IdlType::Vec(t)
+        | IdlType::HashSet(t)
+        | IdlType::BTreeSet(t) => is_generic(t),
+        IdlType::HashMap(k, v) | IdlType::BTreeMap(k, v) => is_generic(k) || is_generic(v),
+        IdlType::Tuple(types) => types.iter().any(is_generic),
+        _ => false,
+    }
+}
+
+/// Renders a double-quoted string literal, valid in TypeScript, Python, Rust and Go.
+pub(crate) fn string_literal(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// The name of a struct generated for an instruction's accounts or arguments,
+/// ie. `CreateHeroArgs`, or `CreateHeroInstructionArgs` if that would clash
+/// with one of `type_names`.
+pub(crate) fn instruction_struct_name(
+    instruction_name: &str,
+    suffix: &str,
+    type_names: &[&str],
+) -> String {
+    let name = format!("{}{}", pascal_case(instruction_name), suffix);
+    match type_names.contains(&name.as_str()) {
+        true => format!("{}Instruction{}", pascal_case(instruction_name), suffix),
+        false => name,
+    }
+}
+
+/// The names of every account and type defined in an IDL.
+pub(crate) fn type_names(idl: &Idl) -> Vec<&str> {
+    idl.accounts
+        .iter()
+        .chain(idl.types.iter())
+        .map(|t| t.name.as_str())
+        .collect()
+}
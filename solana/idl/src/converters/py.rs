@@ -1,4 +1,13 @@
-//! Converts a JSON IDL to Python bindings.
+//! Converts a JSON IDL to a Python client.
+//!
+//! The generated client uses `borsh-construct` for (de)serialization and
+//! `solders` for addresses and instructions, and contains:
+//! * A dataclass and a Borsh layout for every account and type.
+//! * Discriminator-checked decoders and async fetch helpers for every account,
+//!   which take any RPC client implementing `get_account_info` (such as
+//!   `solana-py`'s `AsyncClient`).
+//! * Program-derived address helpers for every record and state account.
+//! * A builder for every instruction returning a `solders` `Instruction`.
 use std::{
     fs::{self, File},
     io::Write,
@@ -6,12 +15,117 @@ use std::{
 };
 
 use crate::{
-    idl_instruction::IdlInstruction,
+    idl_instruction::{IdlInstruction, IdlInstructionAccount},
+    idl_nautilus_config::IdlSeed,
     idl_type::IdlType,
-    idl_type_def::{IdlTypeDef, IdlTypeDefType},
+    idl_type_def::{
+        discriminator, IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeStructField,
+    },
     Idl,
 };
 
+use super::{
+    field_type, instruction_struct_name, is_record, snake_case, string_literal, type_names,
+    uses_index,
+};
+
+const IMPORTS: &str = r#"from __future__ import annotations
+
+import typing
+from dataclasses import dataclass, fields
+
+import borsh_construct as borsh
+from construct import Adapter, Array, Construct
+from construct import Bytes as FixedBytes
+from solders.instruction import AccountMeta, Instruction
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.sysvar import CLOCK, INSTRUCTIONS, RENT"#;
+
+const HELPERS: &str = r#"TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+ASSOCIATED_TOKEN_PROGRAM_ID = Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+TOKEN_METADATA_PROGRAM_ID = Pubkey.from_string("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+
+
+class Rpc(typing.Protocol):
+    """Any RPC client that can fetch accounts, such as `solana.rpc.async_api.AsyncClient`."""
+
+    async def get_account_info(self, pubkey: Pubkey) -> typing.Any:
+        ...
+
+
+async def _fetch_account_data(rpc: Rpc, address: Pubkey) -> typing.Optional[bytes]:
+    response = await rpc.get_account_info(address)
+    return None if response.value is None else bytes(response.value.data)
+
+
+class _PublicKey(Adapter):
+    def _decode(self, obj, context, path):
+        return Pubkey.from_bytes(obj)
+
+    def _encode(self, obj, context, path):
+        return bytes(obj)
+
+
+class _Tuple(Adapter):
+    def _decode(self, obj, context, path):
+        return tuple(obj)
+
+    def _encode(self, obj, context, path):
+        return list(obj)
+
+
+class _Dataclass(Adapter):
+    """Converts between a `CStruct` container and a dataclass."""
+
+    def __init__(self, cls, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+
+    def _decode(self, obj, context, path):
+        return self.cls(**{f.name: obj[f.name] for f in fields(self.cls)})
+
+    def _encode(self, obj, context, path):
+        return {f.name: getattr(obj, f.name) for f in fields(self.cls)}
+
+
+class _Enum(Construct):
+    """A `u8` variant index followed by the variant's fields."""
+
+    def __init__(self, variants: typing.List[_Dataclass]):
+        super().__init__()
+        self.variants = variants
+
+    def _parse(self, stream, context, path):
+        index = borsh.U8._parsereport(stream, context, path)
+        return self.variants[index]._parsereport(stream, context, path)
+
+    def _build(self, obj, stream, context, path):
+        index = next(i for i, v in enumerate(self.variants) if isinstance(obj, v.cls))
+        borsh.U8._build(index, stream, context, path)
+        return self.variants[index]._build(obj, stream, context, path)
+
+
+class _Discriminated(Construct):
+    """Prefixes a layout with an account's 8-byte discriminator, the way the Nautilus runtime serializes accounts."""
+
+    def __init__(self, discriminator: bytes, subcon: Construct):
+        super().__init__()
+        self.discriminator = discriminator
+        self.subcon = subcon
+
+    def _parse(self, stream, context, path):
+        if FixedBytes(8)._parsereport(stream, context, path) != self.discriminator:
+            raise ValueError("Invalid account discriminator")
+        return self.subcon._parsereport(stream, context, path)
+
+    def _build(self, obj, stream, context, path):
+        FixedBytes(8)._build(self.discriminator, stream, context, path)
+        return self.subcon._build(obj, stream, context, path)
+
+
+PUBLIC_KEY = _PublicKey(FixedBytes(32))"#;
+
 pub trait PythonIdlWrite {
     fn write_to_py(&self, dir_path: &str) -> std::io::Result<()>;
 }
@@ -26,7 +140,7 @@ impl PythonIdlWrite for Idl {
             fs::create_dir_all(dir_path)?;
         }
 
-        let py_idl_path = Path::join(Path::new(dir_path), &format!("{}.py", &self.name));
+        let py_idl_path = Path::join(Path::new(dir_path), format!("{}.py", &self.name));
 
         let mut file = File::create(py_idl_path)?;
         let python_string = self.to_python_string();
@@ -38,50 +152,257 @@ impl PythonIdlWrite for Idl {
 
 impl PythonConverter for Idl {
     fn to_python_string(&self) -> String {
-        // TODO: Lay down schema and add instructions/configs:
-        let mut all_types = self.accounts.clone();
-        all_types.extend(self.types.clone());
-        let all_types_strings: Vec<String> =
-            all_types.iter().map(|t| t.to_python_string()).collect();
-        let res = all_types_strings.join("\n");
-        res
+        let mut sections = vec![
+            format!(
+                "# Generated by Nautilus from the `{}` IDL (v{}). Do not edit by hand.\n{}",
+                self.name, self.version, IMPORTS
+            ),
+            HELPERS.to_string(),
+        ];
+        if let Some(address) = &self.metadata.address {
+            sections.push(format!(
+                "PROGRAM_ID = Pubkey.from_string({})",
+                string_literal(address)
+            ));
+        }
+
+        let all_types: Vec<&IdlTypeDef> = self.accounts.iter().chain(self.types.iter()).collect();
+        let mut generics: Vec<&String> = all_types.iter().flat_map(|t| &t.generics).collect();
+        generics.sort();
+        generics.dedup();
+        if !generics.is_empty() {
+            sections.push(
+                generics
+                    .iter()
+                    .map(|g| format!("{} = typing.TypeVar({})", g, string_literal(g)))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            );
+        }
+        sections.extend(all_types.iter().map(|t| t.to_python_string()));
+        sections.extend(self.accounts.iter().map(account_helpers));
+        if let Some(decode_account) = decode_account_helpers(&self.accounts) {
+            sections.push(decode_account);
+        }
+        if self.accounts.iter().any(is_record) || uses_index(self) {
+            sections.push(index_helpers());
+        }
+        let type_names = type_names(self);
+        sections.extend(
+            self.instructions
+                .iter()
+                .map(|i| instruction_to_python_string(i, &type_names)),
+        );
+
+        sections.join("\n\n\n") + "\n"
     }
 }
 
+/// Builds a `create_<name>_instruction(..)` function, along with `TypedDict`s
+/// for its accounts and arguments.
+///
+/// Accounts with well-known addresses (programs, sysvars and the Nautilus
+/// Index) are filled in automatically. The instruction data is the
+/// instruction's discriminant followed by its arguments.
 impl PythonConverter for IdlInstruction {
     fn to_python_string(&self) -> String {
-        todo!()
+        instruction_to_python_string(self, &[])
     }
 }
 
+/// Renders an instruction, naming its accounts and arguments structs so they
+/// don't clash with any of `type_names`.
+fn instruction_to_python_string(instruction: &IdlInstruction, type_names: &[&str]) -> String {
+    let accounts_name = instruction_struct_name(&instruction.name, "Accounts", type_names);
+    let args_name = instruction_struct_name(&instruction.name, "Args", type_names);
+    let mut sections = vec![];
+    let mut params = vec!["program_id: Pubkey".to_string()];
+
+    let account_fields: Vec<String> = instruction
+        .accounts
+        .iter()
+        .filter(|a| known_address(a).is_none())
+        .map(|a| format!("    {}: Pubkey", py_ident(&snake_case(&a.name))))
+        .collect();
+    if !account_fields.is_empty() {
+        sections.push(format!(
+            "class {}(typing.TypedDict):\n{}",
+            accounts_name,
+            account_fields.join("\n")
+        ));
+        params.push(format!("accounts: {}", accounts_name));
+    }
+    let arg_fields: Vec<String> = instruction
+        .args
+        .iter()
+        .map(|a| {
+            format!(
+                "    {}: {}",
+                py_ident(&snake_case(&a.name)),
+                a.arg_type.to_python_string()
+            )
+        })
+        .collect();
+    if !arg_fields.is_empty() {
+        sections.push(format!(
+            "class {}(typing.TypedDict):\n{}",
+            args_name,
+            arg_fields.join("\n")
+        ));
+        params.push(format!("args: {}", args_name));
+    }
+
+    let keys = instruction
+        .accounts
+        .iter()
+        .map(|a| {
+            let pubkey = known_address(a).unwrap_or_else(|| {
+                format!(
+                    "accounts[{}]",
+                    string_literal(&py_ident(&snake_case(&a.name)))
+                )
+            });
+            format!(
+                "        AccountMeta({}, is_signer={}, is_writable={}),",
+                pubkey,
+                py_bool(a.is_signer),
+                py_bool(a.is_mut)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let mut data_layouts = vec!["\"instruction\" / borsh.U8".to_string()];
+    data_layouts.extend(instruction.args.iter().map(|a| {
+        format!(
+            "{} / {}",
+            string_literal(&py_ident(&snake_case(&a.name))),
+            layout(&a.arg_type)
+        )
+    }));
+    let data_value = match instruction.args.is_empty() {
+        true => format!("{{\"instruction\": {}}}", instruction.discriminant.value),
+        false => format!(
+            "{{\"instruction\": {}, **args}}",
+            instruction.discriminant.value
+        ),
+    };
+
+    sections.push(format!(
+        "def create_{}_instruction({}) -> Instruction:\n    keys = [\n{}\n    ]\n    data = borsh.CStruct({}).build({})\n    return Instruction(program_id, data, keys)",
+        snake_case(&instruction.name),
+        params.join(", "),
+        keys,
+        data_layouts.join(", "),
+        data_value,
+    ));
+    sections.join("\n\n\n")
+}
+
+/// Builds a type's dataclass along with a function returning its Borsh layout.
+///
+/// Enums become one dataclass per variant, named `<Enum><Variant>`, and a
+/// `Union` of those dataclasses.
 impl PythonConverter for IdlTypeDef {
     fn to_python_string(&self) -> String {
-        match &self.idl_type {
-            IdlTypeDefType::Struct { fields } => {
-                let fields_str = fields
-                    .iter()
-                    .map(|field| {
-                        format!(
-                            "   {}: {}",
-                            field.name,
-                            field.field_data_type.to_python_string()
-                        )
-                    })
-                    .collect::<Vec<String>>()
-                    .join("\n");
-
-                format!("class {}:\n{}\n", self.name, fields_str)
+        let generic_base = python_generic_base(&self.generics);
+        let (classes, layout_str) = match &self.idl_type {
+            IdlTypeDefType::Struct { fields } => (
+                dataclass(&self.name, &generic_base, &struct_fields(fields)),
+                format!("_Dataclass({}, {})", self.name, cstruct(fields)),
+            ),
+            IdlTypeDefType::Enum { variants } => {
+                let mut classes = vec![];
+                let mut variant_layouts = vec![];
+                let mut variant_names = vec![];
+                for variant in variants {
+                    let class_name = format!("{}{}", self.name, variant.name);
+                    let fields = match &variant.fields {
+                        Some(IdlTypeEnumFields::Named(fields)) => fields.clone(),
+                        Some(IdlTypeEnumFields::Tuple(types)) => types
+                            .iter()
+                            .enumerate()
+                            .map(|(i, ty)| IdlTypeStructField::new(i.to_string(), ty.clone()))
+                            .collect(),
+                        None => vec![],
+                    };
+                    // Only variants using one of the generic parameters are generic.
+                    let variant_generics: Vec<String> = self
+                        .generics
+                        .iter()
+                        .filter(|g| {
+                            fields
+                                .iter()
+                                .any(|f| contains_generic(&f.field_data_type, g))
+                        })
+                        .cloned()
+                        .collect();
+                    classes.push(dataclass(
+                        &class_name,
+                        &python_generic_base(&variant_generics),
+                        &struct_fields(&fields),
+                    ));
+                    variant_layouts.push(format!(
+                        "        _Dataclass({}, {}),",
+                        class_name,
+                        cstruct(&fields)
+                    ));
+                    variant_names.push(format!(
+                        "{}{}",
+                        class_name,
+                        python_generic_args(&variant_generics)
+                    ));
+                }
+                classes.push(format!(
+                    "{} = typing.Union[{}]",
+                    self.name,
+                    variant_names.join(", ")
+                ));
+                (
+                    classes.join("\n\n\n"),
+                    format!("_Enum([\n{}\n    ])", variant_layouts.join("\n")),
+                )
             }
-            IdlTypeDefType::Enum { .. } => String::new(), // TODO: Python enums not supported yet
-        }
+        };
+
+        let layout_params: Vec<String> = self
+            .generics
+            .iter()
+            .map(|g| format!("{}: Construct", generic_layout(g)))
+            .collect();
+        // Accounts are always prefixed with their discriminator, even when
+        // nested in another type or passed as an instruction argument.
+        let (discriminator_str, layout_str) = match self.discriminator() {
+            Some(discriminator) => (
+                format!(
+                    "{} = {}\n\n\n",
+                    discriminator_const(&self.name),
+                    bytes_literal(&discriminator)
+                ),
+                format!(
+                    "_Discriminated({}, {})",
+                    discriminator_const(&self.name),
+                    layout_str
+                ),
+            ),
+            None => (String::new(), layout_str),
+        };
+        format!(
+            "{}{}\n\n\ndef {}({}) -> Construct:\n    return {}",
+            discriminator_str,
+            classes,
+            layout_fn(&self.name),
+            layout_params.join(", "),
+            layout_str
+        )
     }
 }
 
 impl PythonConverter for IdlType {
     fn to_python_string(&self) -> String {
         match self {
-            IdlType::Array(inner_type, size) => {
-                format!("[{}; {}]", inner_type.to_python_string(), size)
+            IdlType::Array(inner_type, _) => {
+                format!("typing.List[{}]", inner_type.to_python_string())
             }
             IdlType::Bool => "bool".to_string(),
             IdlType::Bytes => "bytes".to_string(),
@@ -91,35 +412,396 @@ impl PythonConverter for IdlType {
             IdlType::I32 => "int".to_string(),
             IdlType::I64 => "int".to_string(),
             IdlType::I8 => "int".to_string(),
-            IdlType::Option(inner_type) => format!("Optional[{}]", inner_type.to_python_string()),
+            IdlType::Option(inner_type) => {
+                format!("typing.Optional[{}]", inner_type.to_python_string())
+            }
             IdlType::Tuple(types) => format!(
-                "Tuple[{}]",
+                "typing.Tuple[{}]",
                 types
                     .iter()
                     .map(|t| t.to_python_string())
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
-            IdlType::PublicKey => "PublicKey".to_string(),
+            IdlType::PublicKey => "Pubkey".to_string(),
             IdlType::String => "str".to_string(),
             IdlType::U128 => "int".to_string(),
             IdlType::U16 => "int".to_string(),
             IdlType::U32 => "int".to_string(),
             IdlType::U64 => "int".to_string(),
             IdlType::U8 => "int".to_string(),
-            IdlType::Vec(inner_type) => format!("List[{}]", inner_type.to_python_string()),
+            IdlType::Vec(inner_type) => format!("typing.List[{}]", inner_type.to_python_string()),
             IdlType::HashMap(key_type, value_type) => format!(
-                "Dict[{}, {}]",
+                "typing.Dict[{}, {}]",
                 key_type.to_python_string(),
                 value_type.to_python_string()
             ),
             IdlType::BTreeMap(key_type, value_type) => format!(
-                "Dict[{}, {}]",
+                "typing.Dict[{}, {}]",
                 key_type.to_python_string(),
                 value_type.to_python_string()
             ),
-            IdlType::HashSet(value_type) => format!("Set[{}]", value_type.to_python_string()),
-            IdlType::BTreeSet(value_type) => format!("Set[{}]", value_type.to_python_string()),
+            IdlType::HashSet(value_type) => {
+                format!("typing.Set[{}]", value_type.to_python_string())
+            }
+            IdlType::BTreeSet(value_type) => {
+                format!("typing.Set[{}]", value_type.to_python_string())
+            }
+            IdlType::Generic(name) => name.clone(),
+        }
+    }
+}
+
+/// Renders the Borsh layout for a type.
+fn layout(idl_type: &IdlType) -> String {
+    match idl_type {
+        IdlType::Array(inner_type, size) => format!("Array({}, {})", size, layout(inner_type)),
+        IdlType::Bool => "borsh.Bool".to_string(),
+        IdlType::Bytes => "borsh.Bytes".to_string(),
+        IdlType::Defined(name) => format!("{}()", layout_fn(name)),
+        IdlType::I128 => "borsh.I128".to_string(),
+        IdlType::I16 => "borsh.I16".to_string(),
+        IdlType::I32 => "borsh.I32".to_string(),
+        IdlType::I64 => "borsh.I64".to_string(),
+        IdlType::I8 => "borsh.I8".to_string(),
+        IdlType::Option(inner_type) => format!("borsh.Option({})", layout(inner_type)),
+        IdlType::Tuple(types) => format!(
+            "_Tuple(borsh.TupleStruct({}))",
+            types.iter().map(layout).collect::<Vec<String>>().join(", ")
+        ),
+        IdlType::PublicKey => "PUBLIC_KEY".to_string(),
+        IdlType::String => "borsh.String".to_string(),
+        IdlType::U128 => "borsh.U128".to_string(),
+        IdlType::U16 => "borsh.U16".to_string(),
+        IdlType::U32 => "borsh.U32".to_string(),
+        IdlType::U64 => "borsh.U64".to_string(),
+        IdlType::U8 => "borsh.U8".to_string(),
+        IdlType::Vec(inner_type) => format!("borsh.Vec({})", layout(inner_type)),
+        IdlType::HashMap(key_type, value_type) | IdlType::BTreeMap(key_type, value_type) => {
+            format!(
+                "borsh.HashMap({}, {})",
+                layout(key_type),
+                layout(value_type)
+            )
         }
+        IdlType::HashSet(inner_type) | IdlType::BTreeSet(inner_type) => {
+            format!("borsh.HashSet({})", layout(inner_type))
+        }
+        IdlType::Generic(name) => generic_layout(name),
+    }
+}
+
+/// Whether a type refers to the given generic type parameter.
+fn contains_generic(idl_type: &IdlType, generic: &str) -> bool {
+    match idl_type {
+        IdlType::Generic(name) => name == generic,
+        IdlType::Array(inner, _)
+        | IdlType::Option(inner)
+        | IdlType::Vec(inner)
+        | IdlType::HashSet(inner)
+        | IdlType::BTreeSet(inner) => contains_generic(inner, generic),
+        IdlType::HashMap(key, value) | IdlType::BTreeMap(key, value) => {
+            contains_generic(key, generic) || contains_generic(value, generic)
+        }
+        IdlType::Tuple(types) => types.iter().any(|t| contains_generic(t, generic)),
+        _ => false,
+    }
+}
+
+/// Python's reserved words, which get a trailing underscore when used as a
+/// name.
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Converts a field name into a valid Python identifier. Tuple fields, which are
+/// named by their index, become `field_<index>`.
+fn py_ident(name: &str) -> String {
+    if name.chars().next().map_or(false, |c| c.is_ascii_digit()) {
+        format!("field_{}", name)
+    } else if PYTHON_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn py_bool(value: bool) -> &'static str {
+    match value {
+        true => "True",
+        false => "False",
+    }
+}
+
+/// The name of the function returning a type's layout, ie. `person_layout`.
+fn layout_fn(type_name: &str) -> String {
+    format!("{}_layout", snake_case(type_name))
+}
+
+/// The name of the parameter holding a generic type parameter's layout.
+fn generic_layout(generic: &str) -> String {
+    format!("{}_layout", snake_case(generic))
+}
+
+/// The name of the constant holding an account's discriminator.
+fn discriminator_const(type_name: &str) -> String {
+    format!("{}_DISCRIMINATOR", snake_case(type_name).to_uppercase())
+}
+
+/// Renders a dataclass's generic base class, ie. `(typing.Generic[T])`.
+fn python_generic_base(generics: &[String]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!("(typing.Generic[{}])", generics.join(", ")),
+    }
+}
+
+/// Renders a type's generic arguments, ie. `[T]`.
+fn python_generic_args(generics: &[String]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!("[{}]", generics.join(", ")),
     }
 }
+
+/// Renders the fields of a dataclass.
+fn struct_fields(fields: &[IdlTypeStructField]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|f| {
+            format!(
+                "{}: {}",
+                py_ident(&f.name),
+                f.field_data_type.to_python_string()
+            )
+        })
+        .collect()
+}
+
+fn dataclass(name: &str, generic_base: &str, fields: &[String]) -> String {
+    let body = match fields.is_empty() {
+        true => "    pass".to_string(),
+        false => fields
+            .iter()
+            .map(|f| format!("    {}", f))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    };
+    format!("@dataclass\nclass {}{}:\n{}", name, generic_base, body)
+}
+
+fn cstruct(fields: &[IdlTypeStructField]) -> String {
+    format!(
+        "borsh.CStruct({})",
+        fields
+            .iter()
+            .map(|f| format!(
+                "{} / {}",
+                string_literal(&py_ident(&f.name)),
+                layout(&f.field_data_type)
+            ))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// Builds the decoder and async fetch helper, and the address helpers for an
+/// account.
+fn account_helpers(account: &IdlTypeDef) -> String {
+    let name = &account.name;
+    let snake_name = snake_case(name);
+    let generic_params: String = account
+        .generics
+        .iter()
+        .map(|g| format!(", {}: Construct", generic_layout(g)))
+        .collect();
+    let generic_args: String = account
+        .generics
+        .iter()
+        .map(|g| format!(", {}", generic_layout(g)))
+        .collect();
+    let layout_args: Vec<String> = account.generics.iter().map(|g| generic_layout(g)).collect();
+
+    let mut helpers = vec![];
+    if account.discriminator().is_some() {
+        helpers.push(format!(
+            "def decode_{snake_name}(data: bytes{generic_params}) -> {name}:\n    if data[:8] != {discrim}:\n        raise ValueError(\"Account data is not a `{name}`\")\n    return {layout_fn}({layout_args}).parse(data)",
+            snake_name = snake_name,
+            name = name,
+            generic_params = generic_params,
+            discrim = discriminator_const(name),
+            layout_fn = layout_fn(name),
+            layout_args = layout_args.join(", "),
+        ));
+        helpers.push(format!(
+            "async def fetch_{snake_name}(rpc: Rpc, address: Pubkey{generic_params}) -> typing.Optional[{name}]:\n    data = await _fetch_account_data(rpc, address)\n    return None if data is None else decode_{snake_name}(data{generic_args})",
+            snake_name = snake_name,
+            name = name,
+            generic_params = generic_params,
+            generic_args = generic_args,
+        ));
+    }
+    if let Some(find_address) = find_address_helpers(account) {
+        helpers.push(find_address);
+    }
+    helpers.join("\n\n\n")
+}
+
+/// Builds the helpers deriving an account's program-derived address.
+///
+/// Records are derived from their table name and primary key, while state
+/// accounts are derived from their declared seeds.
+///
+/// An IDL loaded from JSON may name a primary key or seed that isn't one of
+/// the account's fields. The address can't be derived then, so the helpers
+/// are left out rather than failing to generate the rest of the client.
+fn find_address_helpers(account: &IdlTypeDef) -> Option<String> {
+    let config = account.config.as_ref()?;
+    let name = &account.name;
+    let snake_name = snake_case(name);
+    if let (Some(table_name), Some(primary_key)) = (&config.table_name, &config.primary_key) {
+        let primary_key_ty = field_type(account, primary_key)?;
+        let primary_key_seed = match primary_key_ty {
+            IdlType::String => "primary_key.encode()".to_string(),
+            ty => format!("{}.build(primary_key)", layout(ty)),
+        };
+        let mut helpers = vec![format!(
+            "def find_{snake_name}_address(program_id: Pubkey, primary_key: {ty}) -> Pubkey:\n    return Pubkey.find_program_address([{table}, {seed}], program_id)[0]",
+            snake_name = snake_name,
+            ty = primary_key_ty.to_python_string(),
+            table = bytes_str_literal(table_name),
+            seed = primary_key_seed,
+        )];
+        if config.autoincrement == Some(true) {
+            helpers.push(format!(
+                "async def find_next_{snake_name}_address(rpc: Rpc, program_id: Pubkey) -> typing.Tuple[Pubkey, int]:\n    \"\"\"Derives the address of the next `{name}` record, based on the record count in the Nautilus Index.\"\"\"\n    index = await fetch_nautilus_index(rpc, program_id)\n    primary_key = (index or {{}}).get({table}, 0) + 1\n    return find_{snake_name}_address(program_id, primary_key), primary_key",
+                snake_name = snake_name,
+                name = name,
+                table = string_literal(table_name),
+            ));
+        }
+        return Some(helpers.join("\n\n\n"));
+    }
+
+    let mut seed_params: Vec<String> = vec![];
+    let mut uses_generics = false;
+    let seeds: Vec<String> = config
+        .seeds
+        .iter()
+        .map(|seed| {
+            let (key, ty) = match seed {
+                IdlSeed::Lit { value } => return Some(bytes_str_literal(value)),
+                IdlSeed::Field { key } => (key, field_type(account, key)?),
+                IdlSeed::Param { key, value } => (key, value),
+            };
+            uses_generics |= account.generics.iter().any(|g| contains_generic(ty, g));
+            let param = py_ident(&snake_case(key));
+            seed_params.push(format!("{}: {}", param, ty.to_python_string()));
+            Some(format!("{}.build({})", layout(ty), param))
+        })
+        .collect::<Option<_>>()?;
+    let mut params = vec!["program_id: Pubkey".to_string()];
+    if !seed_params.is_empty() {
+        params.push("*".to_string());
+        params.extend(seed_params);
+    }
+    // Field seeds of a generic type need the layout of the type argument.
+    if uses_generics {
+        params.extend(
+            account
+                .generics
+                .iter()
+                .map(|g| format!("{}: Construct", generic_layout(g))),
+        );
+    }
+    Some(format!(
+        "def find_{}_address({}) -> Pubkey:\n    return Pubkey.find_program_address([{}], program_id)[0]",
+        snake_name,
+        params.join(", "),
+        seeds.join(", "),
+    ))
+}
+
+/// Builds `decode_account(..)` and `fetch_account(..)`, which identify an
+/// account by its discriminator before decoding it.
+///
+/// Generic accounts are left out, since they can't be decoded without knowing
+/// their type arguments.
+fn decode_account_helpers(accounts: &[IdlTypeDef]) -> Option<String> {
+    let accounts: Vec<&IdlTypeDef> = accounts
+        .iter()
+        .filter(|a| a.generics.is_empty() && a.discriminator().is_some())
+        .collect();
+    if accounts.is_empty() {
+        return None;
+    }
+    let decoders = accounts
+        .iter()
+        .map(|a| {
+            format!(
+                "    {}: decode_{},",
+                discriminator_const(&a.name),
+                snake_case(&a.name)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let names = accounts
+        .iter()
+        .map(|a| a.name.clone())
+        .collect::<Vec<String>>()
+        .join(", ");
+    Some(format!(
+        "DecodedAccount = typing.Union[{names}]\n\n_ACCOUNT_DECODERS = {{\n{decoders}\n}}\n\n\ndef decode_account(data: bytes) -> typing.Optional[DecodedAccount]:\n    decoder = _ACCOUNT_DECODERS.get(data[:8])\n    return None if decoder is None else decoder(data)\n\n\nasync def fetch_account(rpc: Rpc, address: Pubkey) -> typing.Optional[DecodedAccount]:\n    data = await _fetch_account_data(rpc, address)\n    return None if data is None else decode_account(data)",
+        names = names,
+        decoders = decoders,
+    ))
+}
+
+/// Builds the helpers for the Nautilus Index, which holds the current record
+/// count of every table.
+fn index_helpers() -> String {
+    format!(
+        "NAUTILUS_INDEX_DISCRIMINATOR = {}\n\n\ndef find_nautilus_index_address(program_id: Pubkey) -> Pubkey:\n    return Pubkey.find_program_address([b\"nautilus_index\", bytes([0])], program_id)[0]\n\n\nasync def fetch_nautilus_index(rpc: Rpc, program_id: Pubkey) -> typing.Optional[typing.Dict[str, int]]:\n    data = await _fetch_account_data(rpc, find_nautilus_index_address(program_id))\n    if data is None:\n        return None\n    if data[:8] != NAUTILUS_INDEX_DISCRIMINATOR:\n        raise ValueError(\"Account data is not the Nautilus Index\")\n    return borsh.HashMap(borsh.String, borsh.U32).parse(data[8:])",
+        bytes_literal(&discriminator("nautilus_index"))
+    )
+}
+
+/// The address of an instruction account that doesn't need to be provided by
+/// the caller, if there is one.
+fn known_address(account: &IdlInstructionAccount) -> Option<String> {
+    let address = match account.account_type.as_str() {
+        "index" => "find_nautilus_index_address(program_id)",
+        "systemProgram" => "SYSTEM_PROGRAM_ID",
+        "tokenProgram" => "TOKEN_PROGRAM_ID",
+        "associatedTokenProgram" => "ASSOCIATED_TOKEN_PROGRAM_ID",
+        "tokenMetadataProgram" => "TOKEN_METADATA_PROGRAM_ID",
+        "sysvar" => match account.name.as_str() {
+            "clock" => "CLOCK",
+            "instructions" => "INSTRUCTIONS",
+            "rent" => "RENT",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(address.to_string())
+}
+
+fn bytes_literal(bytes: &[u8]) -> String {
+    format!(
+        "bytes([{}])",
+        bytes
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// Renders a string's UTF-8 bytes, ie. `"person".encode()`.
+fn bytes_str_literal(value: &str) -> String {
+    format!("{}.encode()", string_literal(value))
+}
@@ -0,0 +1,662 @@
+//! Converts a JSON IDL to a standalone Rust client module.
+//!
+//! The generated module only depends on `borsh` (0.9, like the Nautilus
+//! runtime) and `solana-program` - not on the program's crate - and contains:
+//! * A Borsh type for every account and type, with accounts (de)serializing
+//!   their discriminator the same way the Nautilus runtime does.
+//! * Account decoders, including one identifying an account by its
+//!   discriminator.
+//! * Program-derived address helpers for every record and state account.
+//! * An `instruction` module with a builder for every instruction returning a
+//!   `solana_program::instruction::Instruction`.
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use crate::{
+    idl_instruction::{IdlInstruction, IdlInstructionAccount},
+    idl_nautilus_config::IdlSeed,
+    idl_type::IdlType,
+    idl_type_def::{discriminator, IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields},
+    Idl,
+};
+
+use super::{
+    field_type, instruction_struct_name, is_generic, is_record, snake_case, string_literal,
+    type_names, uses_index,
+};
+
+const IMPORTS: &str = r#"#![allow(dead_code, unused_imports)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{pubkey, pubkey::Pubkey};"#;
+
+const HELPERS: &str = r#"pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Reads and checks an account's 8-byte discriminator.
+fn check_discriminator(buf: &mut &[u8], discriminator: [u8; 8], name: &str) -> std::io::Result<()> {
+    let data_discriminator: [u8; 8] = BorshDeserialize::deserialize(buf)?;
+    match data_discriminator == discriminator {
+        true => Ok(()),
+        false => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Account data is not a `{}`", name),
+        )),
+    }
+}"#;
+
+pub trait RustIdlWrite {
+    fn write_to_rs(&self, dir_path: &str) -> std::io::Result<()>;
+}
+
+pub trait RustConverter {
+    fn to_rust_string(&self) -> String;
+}
+
+impl RustIdlWrite for Idl {
+    fn write_to_rs(&self, dir_path: &str) -> std::io::Result<()> {
+        if dir_path != "." {
+            fs::create_dir_all(dir_path)?;
+        }
+
+        // The file is meant to be used as a module, so its name must be a valid
+        // identifier.
+        let module_name = snake_case(&self.name.replace('-', "_"));
+        let rs_idl_path = Path::join(Path::new(dir_path), format!("{}.rs", module_name));
+
+        let mut file = File::create(rs_idl_path)?;
+        let rust_string = self.to_rust_string();
+        file.write_all(rust_string.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl RustConverter for Idl {
+    fn to_rust_string(&self) -> String {
+        let mut sections = vec![
+            format!(
+                "//! Generated by Nautilus from the `{}` IDL (v{}). Do not edit by hand.\n{}",
+                self.name, self.version, IMPORTS
+            ),
+            HELPERS.to_string(),
+        ];
+        if let Some(address) = &self.metadata.address {
+            sections.push(format!(
+                "pub const PROGRAM_ID: Pubkey = pubkey!({});",
+                string_literal(address)
+            ));
+        }
+
+        let all_types = self.accounts.iter().chain(self.types.iter());
+        sections.extend(all_types.map(|t| t.to_rust_string()));
+        sections.extend(self.accounts.iter().map(account_helpers));
+        if let Some(decode_account) = decode_account_helpers(&self.accounts) {
+            sections.push(decode_account);
+        }
+        if self.accounts.iter().any(is_record) || uses_index(self) {
+            sections.push(index_helpers());
+        }
+        if !self.instructions.is_empty() {
+            let type_names = type_names(self);
+            let instructions = self
+                .instructions
+                .iter()
+                .map(|i| instruction_to_rust_string(i, &type_names))
+                .collect::<Vec<String>>()
+                .join("\n\n");
+            sections.push(format!(
+                "pub mod instruction {{\n    use solana_program::{{\n        instruction::{{AccountMeta, Instruction}},\n        system_program, sysvar,\n    }};\n\n    use super::*;\n\n{}\n}}",
+                indent(&instructions)
+            ));
+        }
+
+        sections.join("\n\n") + "\n"
+    }
+}
+
+/// Builds an instruction's builder function, along with structs for its
+/// accounts and arguments.
+///
+/// Accounts with well-known addresses (programs, sysvars and the Nautilus
+/// Index) are filled in automatically. The instruction data is the
+/// instruction's discriminant followed by its arguments.
+impl RustConverter for IdlInstruction {
+    fn to_rust_string(&self) -> String {
+        instruction_to_rust_string(self, &[])
+    }
+}
+
+/// Renders an instruction, naming its accounts and arguments structs so they
+/// don't clash with any of `type_names`.
+fn instruction_to_rust_string(instruction: &IdlInstruction, type_names: &[&str]) -> String {
+    let accounts_name = instruction_struct_name(&instruction.name, "Accounts", type_names);
+    let args_name = instruction_struct_name(&instruction.name, "Args", type_names);
+    let mut sections = vec![];
+    let mut params = vec!["program_id: &Pubkey".to_string()];
+
+    let account_fields: Vec<String> = instruction
+        .accounts
+        .iter()
+        .filter(|a| known_address(a).is_none())
+        .map(|a| format!("    pub {}: Pubkey,", snake_case(&a.name)))
+        .collect();
+    if !account_fields.is_empty() {
+        sections.push(format!(
+            "/// The accounts for `{}` that need to be provided.\n#[derive(Clone, Debug, PartialEq)]\npub struct {} {{\n{}\n}}",
+            instruction.name,
+            accounts_name,
+            account_fields.join("\n")
+        ));
+        params.push(format!("accounts: &{}", accounts_name));
+    }
+    let arg_fields: Vec<String> = instruction
+        .args
+        .iter()
+        .map(|a| {
+            format!(
+                "    pub {}: {},",
+                snake_case(&a.name),
+                a.arg_type.to_rust_string()
+            )
+        })
+        .collect();
+    if !arg_fields.is_empty() {
+        sections.push(format!(
+            "/// The arguments for `{}`.\n#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]\npub struct {} {{\n{}\n}}",
+            instruction.name,
+            args_name,
+            arg_fields.join("\n")
+        ));
+        params.push(format!("args: &{}", args_name));
+    }
+
+    let keys = instruction
+        .accounts
+        .iter()
+        .map(|a| {
+            let pubkey =
+                known_address(a).unwrap_or_else(|| format!("accounts.{}", snake_case(&a.name)));
+            let constructor = match a.is_mut {
+                true => "new",
+                false => "new_readonly",
+            };
+            format!(
+                "        AccountMeta::{}({}, {}),",
+                constructor, pubkey, a.is_signer
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let data = match instruction.args.is_empty() {
+        true => format!("vec![{}]", instruction.discriminant.value),
+        false => format!(
+            "{{\n        let mut data = vec![{}];\n        args.serialize(&mut data).unwrap();\n        data\n    }}",
+            instruction.discriminant.value
+        ),
+    };
+
+    sections.push(format!(
+        "/// Creates a `{}` instruction.\npub fn {}({}) -> Instruction {{\n    let account_metas = vec![\n{}\n    ];\n    let data = {};\n    Instruction::new_with_bytes(*program_id, &data, account_metas)\n}}",
+        instruction.name,
+        snake_case(&instruction.name),
+        params.join(", "),
+        keys,
+        data,
+    ));
+    sections.join("\n\n")
+}
+
+/// Builds a type's definition.
+///
+/// Plain types derive Borsh, while accounts implement it by hand to prefix
+/// their data with their discriminator.
+impl RustConverter for IdlTypeDef {
+    fn to_rust_string(&self) -> String {
+        let generics = rust_generics(&self.generics, None);
+        let definition = match &self.idl_type {
+            IdlTypeDefType::Struct { fields } => match is_tuple(fields.iter().map(|f| &f.name)) {
+                true => format!(
+                    "pub struct {}{}({});",
+                    self.name,
+                    generics,
+                    fields
+                        .iter()
+                        .map(|f| format!("pub {}", f.field_data_type.to_rust_string()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                false => format!(
+                    "pub struct {}{} {{\n{}\n}}",
+                    self.name,
+                    generics,
+                    fields
+                        .iter()
+                        .map(|f| format!(
+                            "    pub {}: {},",
+                            f.name,
+                            f.field_data_type.to_rust_string()
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            },
+            IdlTypeDefType::Enum { variants } => format!(
+                "pub enum {}{} {{\n{}\n}}",
+                self.name,
+                generics,
+                variants
+                    .iter()
+                    .map(|v| match &v.fields {
+                        Some(IdlTypeEnumFields::Named(fields)) => format!(
+                            "    {} {{ {} }},",
+                            v.name,
+                            fields
+                                .iter()
+                                .map(|f| format!(
+                                    "{}: {}",
+                                    f.name,
+                                    f.field_data_type.to_rust_string()
+                                ))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ),
+                        Some(IdlTypeEnumFields::Tuple(types)) => format!(
+                            "    {}({}),",
+                            v.name,
+                            types
+                                .iter()
+                                .map(|t| t.to_rust_string())
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ),
+                        None => format!("    {},", v.name),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ),
+        };
+
+        match self.discriminator() {
+            Some(discriminator) => format!(
+                "pub const {}: [u8; 8] = {:?};\n\n#[derive(Clone, Debug, PartialEq)]\n{}\n\n{}",
+                discriminator_const(&self.name),
+                discriminator,
+                definition,
+                account_borsh_impls(self)
+            ),
+            None => format!(
+                "#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]\n{}",
+                definition
+            ),
+        }
+    }
+}
+
+impl RustConverter for IdlType {
+    fn to_rust_string(&self) -> String {
+        match self {
+            IdlType::Array(idl_type, size) => format!("[{}; {}]", idl_type.to_rust_string(), size),
+            IdlType::Bool => "bool".to_string(),
+            IdlType::Bytes => "Vec<u8>".to_string(),
+            IdlType::Defined(name) => name.clone(),
+            IdlType::I128 => "i128".to_string(),
+            IdlType::I16 => "i16".to_string(),
+            IdlType::I32 => "i32".to_string(),
+            IdlType::I64 => "i64".to_string(),
+            IdlType::I8 => "i8".to_string(),
+            IdlType::Option(idl_type) => format!("Option<{}>", idl_type.to_rust_string()),
+            IdlType::Tuple(idl_types) => match idl_types.len() {
+                1 => format!("({},)", idl_types[0].to_rust_string()),
+                _ => format!(
+                    "({})",
+                    idl_types
+                        .iter()
+                        .map(|t| t.to_rust_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            },
+            IdlType::PublicKey => "Pubkey".to_string(),
+            IdlType::String => "String".to_string(),
+            IdlType::U128 => "u128".to_string(),
+            IdlType::U16 => "u16".to_string(),
+            IdlType::U32 => "u32".to_string(),
+            IdlType::U64 => "u64".to_string(),
+            IdlType::U8 => "u8".to_string(),
+            IdlType::Vec(idl_type) => format!("Vec<{}>", idl_type.to_rust_string()),
+            IdlType::HashMap(key_type, value_type) => format!(
+                "std::collections::HashMap<{}, {}>",
+                key_type.to_rust_string(),
+                value_type.to_rust_string()
+            ),
+            IdlType::BTreeMap(key_type, value_type) => format!(
+                "std::collections::BTreeMap<{}, {}>",
+                key_type.to_rust_string(),
+                value_type.to_rust_string()
+            ),
+            IdlType::HashSet(idl_type) => {
+                format!("std::collections::HashSet<{}>", idl_type.to_rust_string())
+            }
+            IdlType::BTreeSet(idl_type) => {
+                format!("std::collections::BTreeSet<{}>", idl_type.to_rust_string())
+            }
+            IdlType::Generic(name) => name.clone(),
+        }
+    }
+}
+
+/// Builds the `BorshSerialize` and `BorshDeserialize` implementations for an
+/// account, which write and check its discriminator before its data.
+///
+/// Enum variants are written as a `u8` index followed by their fields.
+fn account_borsh_impls(account: &IdlTypeDef) -> String {
+    let name = &account.name;
+    let discrim = discriminator_const(name);
+    let (serialize_body, deserialize_body) = match &account.idl_type {
+        IdlTypeDefType::Struct { fields } => {
+            let members: Vec<&String> = fields.iter().map(|f| &f.name).collect();
+            let serialize = members
+                .iter()
+                .map(|m| format!("        self.{}.serialize(writer)?;", m))
+                .collect::<Vec<String>>()
+                .join("\n");
+            let deserialize = match is_tuple(members.iter().copied()) {
+                true => format!(
+                    "Ok(Self({}))",
+                    vec!["BorshDeserialize::deserialize(buf)?"; members.len()].join(", ")
+                ),
+                false => format!(
+                    "Ok(Self {{ {} }})",
+                    members
+                        .iter()
+                        .map(|m| format!("{}: BorshDeserialize::deserialize(buf)?", m))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            };
+            (serialize, format!("        {}", deserialize))
+        }
+        IdlTypeDefType::Enum { variants } => {
+            let mut serialize_arms = vec![];
+            let mut deserialize_arms = vec![];
+            for (i, v) in variants.iter().enumerate() {
+                let (pattern, bindings, constructor) = match &v.fields {
+                    Some(IdlTypeEnumFields::Named(fields)) => {
+                        let names: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
+                        (
+                            format!("Self::{} {{ {} }}", v.name, names.join(", ")),
+                            names.clone(),
+                            format!(
+                                "Self::{} {{ {} }}",
+                                v.name,
+                                names
+                                    .iter()
+                                    .map(|n| format!("{}: BorshDeserialize::deserialize(buf)?", n))
+                                    .collect::<Vec<String>>()
+                                    .join(", ")
+                            ),
+                        )
+                    }
+                    Some(IdlTypeEnumFields::Tuple(types)) => {
+                        let names: Vec<String> =
+                            (0..types.len()).map(|i| format!("field_{}", i)).collect();
+                        (
+                            format!("Self::{}({})", v.name, names.join(", ")),
+                            names,
+                            format!(
+                                "Self::{}({})",
+                                v.name,
+                                vec!["BorshDeserialize::deserialize(buf)?"; types.len()].join(", ")
+                            ),
+                        )
+                    }
+                    None => (
+                        format!("Self::{}", v.name),
+                        vec![],
+                        format!("Self::{}", v.name),
+                    ),
+                };
+                let mut statements = vec![format!("{}u8.serialize(writer)?;", i)];
+                statements.extend(
+                    bindings
+                        .iter()
+                        .map(|b| format!("{}.serialize(writer)?;", b)),
+                );
+                serialize_arms.push(format!(
+                    "            {} => {{\n{}\n            }}",
+                    pattern,
+                    statements
+                        .iter()
+                        .map(|s| format!("                {}", s))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ));
+                deserialize_arms.push(format!("            {} => {},", i, constructor));
+            }
+            (
+                format!("        match self {{\n{}\n        }}", serialize_arms.join("\n")),
+                format!(
+                    "        let variant: u8 = BorshDeserialize::deserialize(buf)?;\n        Ok(match variant {{\n{}\n            _ => {{\n                return Err(std::io::Error::new(\n                    std::io::ErrorKind::InvalidData,\n                    format!(\"Invalid variant index for `{}`: {{}}\", variant),\n                ))\n            }}\n        }})",
+                    deserialize_arms.join("\n"),
+                    name
+                ),
+            )
+        }
+    };
+    let generics = rust_generics(&account.generics, None);
+    format!(
+        "impl{ser_generics} BorshSerialize for {name}{generics} {{\n    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {{\n        {discrim}.serialize(writer)?;\n{serialize_body}\n        Ok(())\n    }}\n}}\n\nimpl{de_generics} BorshDeserialize for {name}{generics} {{\n    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {{\n        check_discriminator(buf, {discrim}, {name_literal})?;\n{deserialize_body}\n    }}\n}}",
+        ser_generics = rust_generics(&account.generics, Some("BorshSerialize")),
+        de_generics = rust_generics(&account.generics, Some("BorshDeserialize")),
+        name = name,
+        generics = generics,
+        discrim = discrim,
+        serialize_body = serialize_body,
+        deserialize_body = deserialize_body,
+        name_literal = string_literal(name),
+    )
+}
+
+/// Builds the decoder and the address helpers for an account.
+fn account_helpers(account: &IdlTypeDef) -> String {
+    let name = &account.name;
+    let mut helpers = vec![];
+    if account.discriminator().is_some() {
+        helpers.push(format!(
+            "/// Decodes a `{name}` account's data, checking its discriminator.\npub fn decode_{snake_name}{generics}(data: &[u8]) -> std::io::Result<{name}{type_generics}> {{\n    BorshDeserialize::deserialize(&mut &data[..])\n}}",
+            name = name,
+            snake_name = snake_case(name),
+            generics = rust_generics(&account.generics, Some("BorshDeserialize")),
+            type_generics = rust_generics(&account.generics, None),
+        ));
+    }
+    if let Some(find_address) = find_address_helpers(account) {
+        helpers.push(find_address);
+    }
+    helpers.join("\n\n")
+}
+
+/// Builds the helpers deriving an account's program-derived address, returning
+/// the address and its bump.
+///
+/// Records are derived from their table name and primary key, while state
+/// accounts are derived from their declared seeds.
+///
+/// An IDL loaded from JSON may name a primary key or seed that isn't one of
+/// the account's fields. The address can't be derived then, so the helpers
+/// are left out rather than failing to generate the rest of the client.
+fn find_address_helpers(account: &IdlTypeDef) -> Option<String> {
+    let config = account.config.as_ref()?;
+    let name = &account.name;
+    let snake_name = snake_case(name);
+    if let (Some(table_name), Some(primary_key)) = (&config.table_name, &config.primary_key) {
+        let primary_key_ty = field_type(account, primary_key)?;
+        let (param_ty, seed) = match primary_key_ty {
+            IdlType::String => ("&str".to_string(), "primary_key.as_bytes()"),
+            IdlType::PublicKey => ("&Pubkey".to_string(), "primary_key.as_ref()"),
+            ty => (ty.to_rust_string(), "&primary_key.to_le_bytes()"),
+        };
+        let mut helpers = vec![format!(
+            "/// Derives the address of the `{name}` record with the given primary key.\npub fn find_{snake_name}_address(program_id: &Pubkey, primary_key: {param_ty}) -> (Pubkey, u8) {{\n    Pubkey::find_program_address(&[{table}.as_bytes(), {seed}], program_id)\n}}",
+            name = name,
+            snake_name = snake_name,
+            param_ty = param_ty,
+            table = string_literal(table_name),
+            seed = seed,
+        )];
+        if config.autoincrement == Some(true) {
+            helpers.push(format!(
+                "/// Derives the address of the next `{name}` record from the Nautilus Index,\n/// returning it along with the record's primary key.\npub fn find_next_{snake_name}_address(program_id: &Pubkey, index: &NautilusIndex) -> (Pubkey, {ty}) {{\n    let primary_key: {ty} = index.next_count({table}).try_into().unwrap();\n    (find_{snake_name}_address(program_id, primary_key).0, primary_key)\n}}",
+                name = name,
+                snake_name = snake_name,
+                ty = primary_key_ty.to_rust_string(),
+                table = string_literal(table_name),
+            ));
+        }
+        return Some(helpers.join("\n\n"));
+    }
+
+    let mut params = vec!["program_id: &Pubkey".to_string()];
+    let seeds: Vec<String> = config
+        .seeds
+        .iter()
+        .map(|seed| {
+            let (key, ty) = match seed {
+                IdlSeed::Lit { value } => {
+                    return Some(format!("{}.as_bytes()", string_literal(value)))
+                }
+                IdlSeed::Field { key } => (key, field_type(account, key)?),
+                IdlSeed::Param { key, value } => (key, value),
+            };
+            let param = snake_case(key);
+            params.push(format!("{}: &{}", param, ty.to_rust_string()));
+            Some(format!("&{}.try_to_vec().unwrap()", param))
+        })
+        .collect::<Option<_>>()?;
+    // Field seeds of a generic type need the type argument.
+    let generics = match config.seeds.iter().any(|seed| match seed {
+        IdlSeed::Lit { .. } => false,
+        IdlSeed::Field { key } => field_type(account, key).map_or(false, is_generic),
+        IdlSeed::Param { value, .. } => is_generic(value),
+    }) {
+        true => rust_generics(&account.generics, Some("BorshSerialize")),
+        false => String::new(),
+    };
+    Some(format!(
+        "/// Derives the address of the `{}` account.\npub fn find_{}_address{}({}) -> (Pubkey, u8) {{\n    Pubkey::find_program_address(&[{}], program_id)\n}}",
+        name,
+        snake_name,
+        generics,
+        params.join(", "),
+        seeds.join(", "),
+    ))
+}
+
+/// Builds `DecodedAccount` and `decode_account(..)`, which identifies an
+/// account by its discriminator before decoding it.
+///
+/// Generic accounts are left out, since they can't be decoded without knowing
+/// their type arguments.
+fn decode_account_helpers(accounts: &[IdlTypeDef]) -> Option<String> {
+    let accounts: Vec<&IdlTypeDef> = accounts
+        .iter()
+        .filter(|a| a.generics.is_empty() && a.discriminator().is_some())
+        .collect();
+    if accounts.is_empty() {
+        return None;
+    }
+    let variants = accounts
+        .iter()
+        .map(|a| format!("    {}({}),", a.name, a.name))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let arms = accounts
+        .iter()
+        .map(|a| {
+            format!(
+                "        {} => DecodedAccount::{}(decode_{}(data)?),",
+                discriminator_const(&a.name),
+                a.name,
+                snake_case(&a.name)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    Some(format!(
+        "/// Any of this program's accounts.\n#[derive(Clone, Debug, PartialEq)]\npub enum DecodedAccount {{\n{}\n}}\n\n/// Decodes an account's data based on its discriminator, returning `None` if\n/// it doesn't belong to any of this program's accounts.\npub fn decode_account(data: &[u8]) -> std::io::Result<Option<DecodedAccount>> {{\n    let discriminator: [u8; 8] = match data.get(..8) {{\n        Some(discriminator) => discriminator.try_into().unwrap(),\n        None => return Ok(None),\n    }};\n    Ok(Some(match discriminator {{\n{}\n        _ => return Ok(None),\n    }}))\n}}",
+        variants, arms
+    ))
+}
+
+/// Builds the helpers for the Nautilus Index, which holds the current record
+/// count of every table.
+fn index_helpers() -> String {
+    format!(
+        "pub const NAUTILUS_INDEX_DISCRIMINATOR: [u8; 8] = {:?};\n\n/// The Nautilus Index, which holds the current record count of every table.\n#[derive(Clone, Debug, Default, PartialEq)]\npub struct NautilusIndex {{\n    pub index: std::collections::HashMap<String, u32>,\n}}\n\nimpl NautilusIndex {{\n    /// The count the next record added to a table will have.\n    pub fn next_count(&self, table_name: &str) -> u32 {{\n        self.index.get(table_name).map_or(1, |count| count + 1)\n    }}\n}}\n\nimpl BorshSerialize for NautilusIndex {{\n    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {{\n        NAUTILUS_INDEX_DISCRIMINATOR.serialize(writer)?;\n        self.index.serialize(writer)\n    }}\n}}\n\nimpl BorshDeserialize for NautilusIndex {{\n    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {{\n        check_discriminator(buf, NAUTILUS_INDEX_DISCRIMINATOR, \"NautilusIndex\")?;\n        Ok(Self {{ index: BorshDeserialize::deserialize(buf)? }})\n    }}\n}}\n\npub fn find_nautilus_index_address(program_id: &Pubkey) -> (Pubkey, u8) {{\n    Pubkey::find_program_address(&[b\"nautilus_index\", &[0]], program_id)\n}}\n\npub fn decode_nautilus_index(data: &[u8]) -> std::io::Result<NautilusIndex> {{\n    BorshDeserialize::deserialize(&mut &data[..])\n}}",
+        discriminator("nautilus_index")
+    )
+}
+
+/// The address of an instruction account that doesn't need to be provided by
+/// the caller, if there is one.
+fn known_address(account: &IdlInstructionAccount) -> Option<String> {
+    let address = match account.account_type.as_str() {
+        "index" => "find_nautilus_index_address(program_id).0",
+        "systemProgram" => "system_program::ID",
+        "tokenProgram" => "TOKEN_PROGRAM_ID",
+        "associatedTokenProgram" => "ASSOCIATED_TOKEN_PROGRAM_ID",
+        "tokenMetadataProgram" => "TOKEN_METADATA_PROGRAM_ID",
+        "sysvar" => match account.name.as_str() {
+            "clock" => "sysvar::clock::ID",
+            "instructions" => "sysvar::instructions::ID",
+            "rent" => "sysvar::rent::ID",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(address.to_string())
+}
+
+/// The name of the constant holding an account's discriminator.
+fn discriminator_const(type_name: &str) -> String {
+    format!("{}_DISCRIMINATOR", snake_case(type_name).to_uppercase())
+}
+
+/// Whether a struct's fields are a tuple's, which are named by their index.
+fn is_tuple<'a>(mut names: impl Iterator<Item = &'a String>) -> bool {
+    names.all(|n| n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Renders a type's generic parameters, optionally bounded by a trait, ie.
+/// `<T: BorshSerialize>`.
+fn rust_generics(generics: &[String], bound: Option<&str>) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!(
+            "<{}>",
+            generics
+                .iter()
+                .map(|g| match bound {
+                    Some(bound) => format!("{}: {}", g, bound),
+                    None => g.clone(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn indent(code: &str) -> String {
+    code.lines()
+        .map(|line| match line.is_empty() {
+            true => String::new(),
+            false => format!("    {}", line),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
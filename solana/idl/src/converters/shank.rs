@@ -0,0 +1,209 @@
+//! Reads a Shank IDL into the Nautilus IDL, so the Nautilus client generators
+//! and tooling can target any Shank-described program - such as Metaplex's.
+//!
+//! Shank's types, type definitions, instruction args and errors share the
+//! Nautilus IDL's format, so only instructions need converting:
+//! * Composite accounts are flattened.
+//! * Each account's Nautilus account type is inferred from its name, since
+//!   Shank doesn't record what an account is.
+//!
+//! Shank accounts carry no discriminator, so they're imported without a
+//! Nautilus configuration.
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    idl_error::IdlError,
+    idl_instruction::{
+        IdlInstruction, IdlInstructionAccount, IdlInstructionArg, IdlInstructionDiscriminant,
+    },
+    idl_metadata::IdlMetadata,
+    idl_type::IdlType,
+    idl_type_def::IdlTypeDef,
+    Idl,
+};
+
+/// A Shank IDL.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShankIdl {
+    pub version: String,
+    pub name: String,
+    #[serde(default)]
+    pub instructions: Vec<ShankIdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlTypeDef>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+    #[serde(default)]
+    pub errors: Vec<IdlError>,
+    #[serde(default)]
+    pub metadata: ShankIdlMetadata,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShankIdlMetadata {
+    #[serde(default)]
+    pub origin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShankIdlInstruction {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub accounts: Vec<ShankIdlAccountItem>,
+    pub args: Vec<IdlInstructionArg>,
+    pub discriminant: ShankIdlInstructionDiscriminant,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ShankIdlAccountItem {
+    Composite(ShankIdlAccounts),
+    Single(ShankIdlAccount),
+}
+
+/// A group of accounts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShankIdlAccounts {
+    pub name: String,
+    pub accounts: Vec<ShankIdlAccountItem>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShankIdlAccount {
+    pub name: String,
+    pub is_mut: bool,
+    pub is_signer: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShankIdlInstructionDiscriminant {
+    #[serde(rename = "type")]
+    pub discriminant_type: IdlType,
+    pub value: u64,
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Idl {
+    /// Reads a Nautilus IDL from a Shank IDL's JSON.
+    ///
+    /// Fails if the Shank IDL uses a type Nautilus can't represent (ie.
+    /// `COption`) or an instruction discriminant that isn't a `u8`.
+    pub fn from_shank_json(json: &str) -> std::io::Result<Self> {
+        let shank_idl: ShankIdl = serde_json::from_str(json)?;
+        Self::try_from(shank_idl)
+    }
+}
+
+impl TryFrom<ShankIdl> for Idl {
+    type Error = io::Error;
+
+    fn try_from(shank_idl: ShankIdl) -> Result<Self, Self::Error> {
+        let instructions = shank_idl
+            .instructions
+            .iter()
+            .map(instruction)
+            .collect::<io::Result<Vec<_>>>()?;
+        let origin = match shank_idl.metadata.origin.is_empty() {
+            true => "shank".to_string(),
+            false => shank_idl.metadata.origin,
+        };
+        let mut idl = Idl::new(
+            shank_idl.version,
+            shank_idl.name,
+            instructions,
+            shank_idl.accounts,
+            shank_idl.types,
+            IdlMetadata {
+                origin,
+                address: shank_idl.metadata.address,
+            },
+        );
+        idl.errors = shank_idl.errors;
+        Ok(idl)
+    }
+}
+
+fn instruction(instruction: &ShankIdlInstruction) -> io::Result<IdlInstruction> {
+    let discriminant = match instruction.discriminant.discriminant_type {
+        IdlType::U8 => u8::try_from(instruction.discriminant.value).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        invalid_data(format!(
+            "Instruction `{}` doesn't have a `u8` discriminant",
+            instruction.name
+        ))
+    })?;
+
+    let mut shank_accounts = vec![];
+    flatten_accounts(&instruction.accounts, &mut shank_accounts);
+    let accounts = shank_accounts
+        .into_iter()
+        .map(|a| {
+            let desc = match &a.desc {
+                Some(desc) => desc.clone(),
+                None => a.docs.join(" "),
+            };
+            IdlInstructionAccount::new(
+                a.name.clone(),
+                a.is_mut,
+                a.is_signer,
+                account_type(&a.name),
+                desc,
+            )
+        })
+        .collect();
+
+    Ok(IdlInstruction::new(
+        &instruction.name,
+        accounts,
+        instruction.args.clone(),
+        IdlInstructionDiscriminant::new(discriminant),
+    ))
+}
+
+/// Flattens an instruction's accounts, expanding any composite accounts in
+/// place.
+fn flatten_accounts<'a>(items: &'a [ShankIdlAccountItem], accounts: &mut Vec<&'a ShankIdlAccount>) {
+    for item in items {
+        match item {
+            ShankIdlAccountItem::Single(account) => accounts.push(account),
+            ShankIdlAccountItem::Composite(composite) => {
+                flatten_accounts(&composite.accounts, accounts)
+            }
+        }
+    }
+}
+
+/// Infers an instruction account's Nautilus account type from the names
+/// Nautilus and Metaplex programs give well-known accounts.
+fn account_type(name: &str) -> String {
+    let account_type = match name {
+        "feePayer" | "payer" => "feePayer",
+        "systemProgram" => "systemProgram",
+        "tokenProgram" | "splTokenProgram" => "tokenProgram",
+        "associatedTokenProgram" | "ataProgram" | "splAtaProgram" => "associatedTokenProgram",
+        "tokenMetadataProgram" => "tokenMetadataProgram",
+        "rent" | "clock" | "instructions" => "sysvar",
+        _ => "account",
+    };
+    account_type.to_string()
+}
@@ -0,0 +1,242 @@
+//! Converts a JSON IDL to SQL `CREATE TABLE` statements mirroring the
+//! program's records, in either the SQLite or the Postgres dialect.
+//!
+//! Every account with a table name becomes a table with a column per field:
+//! * The primary key (and whether it autoincrements) comes from the record's
+//!   Nautilus configuration.
+//! * Authority columns are indexed, since records are usually looked up by
+//!   who owns them.
+//! * Columns are `NOT NULL` unless they hold an `Option`.
+//!
+//! Values that don't fit a column type natively - integers wider than the
+//! database's, and compound types such as vectors, tuples and defined types -
+//! are stored as text (SQLite) or `NUMERIC`/`JSONB` (Postgres), in the format
+//! `nautilus_idl::decode` writes them.
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use crate::{
+    idl_nautilus_config::IdlTypeDefNautilusConfig,
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType},
+    Idl,
+};
+
+use super::is_record;
+
+/// The SQL dialects tables can be written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqlDialect {
+    Sqlite,
+    Postgres,
+}
+
+impl SqlDialect {
+    fn name(&self) -> &'static str {
+        match self {
+            SqlDialect::Sqlite => "sqlite",
+            SqlDialect::Postgres => "postgres",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            SqlDialect::Sqlite => "SQLite",
+            SqlDialect::Postgres => "Postgres",
+        }
+    }
+}
+
+pub trait SqlIdlWrite {
+    /// Writes `<name>.sqlite.sql` and `<name>.postgres.sql`.
+    fn write_to_sql(&self, dir_path: &str) -> std::io::Result<()>;
+}
+
+pub trait SqlConverter {
+    fn to_sql_string(&self, dialect: SqlDialect) -> String;
+}
+
+impl SqlIdlWrite for Idl {
+    fn write_to_sql(&self, dir_path: &str) -> std::io::Result<()> {
+        if dir_path != "." {
+            fs::create_dir_all(dir_path)?;
+        }
+        for dialect in [SqlDialect::Sqlite, SqlDialect::Postgres] {
+            let sql_path = Path::join(
+                Path::new(dir_path),
+                format!("{}.{}.sql", &self.name, dialect.name()),
+            );
+            let mut file = File::create(sql_path)?;
+            file.write_all(self.to_sql_string(dialect).as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl SqlConverter for Idl {
+    fn to_sql_string(&self, dialect: SqlDialect) -> String {
+        let mut sections = vec![format!(
+            "-- Generated by Nautilus from the `{}` IDL (v{}) for {}. Do not edit by hand.",
+            self.name,
+            self.version,
+            dialect.title()
+        )];
+        sections.extend(
+            self.accounts
+                .iter()
+                .filter(|a| is_record(a))
+                .map(|a| a.to_sql_string(dialect)),
+        );
+        sections.join("\n\n") + "\n"
+    }
+}
+
+impl SqlConverter for IdlTypeDef {
+    /// Renders a record's `CREATE TABLE` statement, followed by an index on
+    /// each of its authorities. Renders nothing for any other type.
+    fn to_sql_string(&self, dialect: SqlDialect) -> String {
+        let config = match &self.config {
+            Some(
+                config @ IdlTypeDefNautilusConfig {
+                    table_name: Some(_),
+                    ..
+                },
+            ) => config,
+            _ => return String::new(),
+        };
+        let table_name = config.table_name.as_deref().unwrap_or_default();
+
+        let columns: Vec<String> = match &self.idl_type {
+            IdlTypeDefType::Struct { fields } => fields
+                .iter()
+                .map(|f| {
+                    let mut lines: Vec<String> =
+                        f.docs.iter().map(|doc| comment(doc, "    ")).collect();
+                    if config.authorities.contains(&f.name) {
+                        lines
+                            .push("    -- Authority: must sign to change this record.".to_string());
+                    }
+                    lines.push(format!(
+                        "    {}",
+                        column(&f.name, &f.field_data_type, config, dialect)
+                    ));
+                    lines.join("\n")
+                })
+                .collect(),
+            // Enum records are stored whole.
+            IdlTypeDefType::Enum { .. } => {
+                vec![format!(
+                    "    {} NOT NULL",
+                    column_definition("value", json_type(dialect))
+                )]
+            }
+        };
+
+        let mut statements: Vec<String> = self.docs.iter().map(|doc| comment(doc, "")).collect();
+        statements.push(format!(
+            "CREATE TABLE IF NOT EXISTS {} (\n{}\n);",
+            quote_identifier(table_name),
+            columns.join(",\n")
+        ));
+        for authority in config.authorities.iter() {
+            statements.push(format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {} ({});",
+                quote_identifier(&format!("{}_{}_idx", table_name, authority)),
+                quote_identifier(table_name),
+                quote_identifier(authority)
+            ));
+        }
+        statements.join("\n")
+    }
+}
+
+/// Renders a field's column definition.
+///
+/// An autoincrementing primary key is always a 64-bit integer, since that's
+/// what both dialects generate keys as.
+fn column(
+    name: &str,
+    idl_type: &IdlType,
+    config: &IdlTypeDefNautilusConfig,
+    dialect: SqlDialect,
+) -> String {
+    let is_primary_key = config.primary_key.as_deref() == Some(name);
+    if is_primary_key && config.autoincrement == Some(true) {
+        let definition = match dialect {
+            SqlDialect::Sqlite => "INTEGER PRIMARY KEY AUTOINCREMENT",
+            SqlDialect::Postgres => "BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY",
+        };
+        return column_definition(name, definition);
+    }
+    let (column_type, nullable) = match idl_type {
+        IdlType::Option(inner) => (column_type(inner, dialect), true),
+        _ => (column_type(idl_type, dialect), false),
+    };
+    match (is_primary_key, nullable) {
+        (true, _) => column_definition(name, &format!("{} PRIMARY KEY", column_type)),
+        (false, true) => column_definition(name, column_type),
+        (false, false) => column_definition(name, &format!("{} NOT NULL", column_type)),
+    }
+}
+
+fn column_definition(name: &str, definition: &str) -> String {
+    format!("{} {}", quote_identifier(name), definition)
+}
+
+/// The column type storing a type's values.
+fn column_type(idl_type: &IdlType, dialect: SqlDialect) -> &'static str {
+    match dialect {
+        SqlDialect::Sqlite => match idl_type {
+            IdlType::Bool
+            | IdlType::U8
+            | IdlType::U16
+            | IdlType::U32
+            | IdlType::I8
+            | IdlType::I16
+            | IdlType::I32
+            | IdlType::I64 => "INTEGER",
+            // SQLite integers are signed 64-bit, so wider ones are kept exact as text.
+            IdlType::U64 | IdlType::U128 | IdlType::I128 => "TEXT",
+            IdlType::String | IdlType::PublicKey => "TEXT",
+            IdlType::Bytes => "BLOB",
+            IdlType::Array(inner, _) if **inner == IdlType::U8 => "BLOB",
+            _ => json_type(dialect),
+        },
+        SqlDialect::Postgres => match idl_type {
+            IdlType::Bool => "BOOLEAN",
+            IdlType::U8 | IdlType::I8 | IdlType::I16 => "SMALLINT",
+            IdlType::U16 | IdlType::I32 => "INTEGER",
+            IdlType::U32 | IdlType::I64 => "BIGINT",
+            IdlType::U64 => "NUMERIC(20, 0)",
+            IdlType::U128 | IdlType::I128 => "NUMERIC(39, 0)",
+            IdlType::String => "TEXT",
+            IdlType::PublicKey => "VARCHAR(44)",
+            IdlType::Bytes => "BYTEA",
+            IdlType::Array(inner, _) if **inner == IdlType::U8 => "BYTEA",
+            _ => json_type(dialect),
+        },
+    }
+}
+
+/// The column type storing compound values as JSON.
+fn json_type(dialect: SqlDialect) -> &'static str {
+    match dialect {
+        SqlDialect::Sqlite => "TEXT",
+        SqlDialect::Postgres => "JSONB",
+    }
+}
+
+/// Quotes an identifier, which is valid in both dialects.
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn comment(doc: &str, indent: &str) -> String {
+    match doc.is_empty() {
+        true => format!("{}--", indent),
+        false => format!("{}-- {}", indent, doc),
+    }
+}
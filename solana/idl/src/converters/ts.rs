@@ -1,4 +1,11 @@
-//! Converts a JSON IDL to TypeScript bindings.
+//! Converts a JSON IDL to a TypeScript client.
+//!
+//! The generated client uses `@coral-xyz/borsh` for (de)serialization and
+//! `@solana/web3.js` for addresses and instructions, and contains:
+//! * A type and a Borsh layout for every account and type.
+//! * Discriminator-checked decode and fetch helpers for every account.
+//! * Program-derived address helpers for every record and state account.
+//! * A builder for every instruction returning a `TransactionInstruction`.
 use std::{
     fs::{self, File},
     io::Write,
@@ -6,12 +13,64 @@ use std::{
 };
 
 use crate::{
-    idl_instruction::IdlInstruction,
+    idl_instruction::{IdlInstruction, IdlInstructionAccount},
+    idl_nautilus_config::IdlSeed,
     idl_type::IdlType,
-    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields},
+    idl_type_def::{discriminator, IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields},
     Idl,
 };
 
+use super::{
+    camel_case, field_type, is_record, pascal_case, snake_case, string_literal, uses_index,
+};
+
+const IMPORTS: &str = r#"import * as borsh from "@coral-xyz/borsh"
+import BN from "bn.js"
+import { Layout } from "buffer-layout"
+import {
+    Connection,
+    PublicKey,
+    SystemProgram,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    SYSVAR_RENT_PUBKEY,
+    TransactionInstruction,
+} from "@solana/web3.js""#;
+
+const HELPERS: &str = r#"export const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+
+/** Prefixes a layout with an account's 8-byte discriminator, the way the Nautilus runtime serializes accounts. */
+class DiscriminatedLayout<T> extends Layout<T> {
+    constructor(readonly discriminator: Buffer, readonly layout: borsh.Layout<T>, property?: string) {
+        super(-1, property)
+    }
+
+    getSpan(b: Buffer, offset = 0): number {
+        return 8 + this.layout.getSpan(b, offset + 8)
+    }
+
+    decode(b: Buffer, offset = 0): T {
+        if (!b.subarray(offset, offset + 8).equals(this.discriminator)) {
+            throw new Error("Invalid account discriminator")
+        }
+        return this.layout.decode(b, offset + 8)
+    }
+
+    encode(src: T, b: Buffer, offset = 0): number {
+        this.discriminator.copy(b, offset)
+        return 8 + this.layout.encode(src, b, offset + 8)
+    }
+}
+
+/** Serializes a single value with a Borsh layout. */
+function encode<T>(layout: borsh.Layout<T>, value: T): Buffer {
+    const buffer = Buffer.alloc(1232) // The maximum size of a transaction
+    const length = layout.encode(value, buffer)
+    return buffer.subarray(0, length)
+}"#;
+
 pub trait TypeScriptIdlWrite {
     fn write_to_ts(&self, dir_path: &str) -> std::io::Result<()>;
 }
@@ -26,7 +85,7 @@ impl TypeScriptIdlWrite for Idl {
             fs::create_dir_all(dir_path)?;
         }
 
-        let ts_idl_path = Path::join(Path::new(dir_path), &format!("{}.ts", &self.name));
+        let ts_idl_path = Path::join(Path::new(dir_path), format!("{}.ts", &self.name));
 
         let mut file = File::create(ts_idl_path)?;
         let typescript_string = self.to_typescript_string();
@@ -38,39 +97,142 @@ impl TypeScriptIdlWrite for Idl {
 
 impl TypeScriptConverter for Idl {
     fn to_typescript_string(&self) -> String {
-        // TODO: Lay down schema and add instructions/configs:
-        let mut all_types = self.accounts.clone();
-        all_types.extend(self.types.clone());
-        let all_types_strings: Vec<String> =
-            all_types.iter().map(|t| t.to_typescript_string()).collect();
-        let res = all_types_strings.join("\n");
-        res
+        let mut sections = vec![
+            format!(
+                "// Generated by Nautilus from the `{}` IDL (v{}). Do not edit by hand.",
+                self.name, self.version
+            ),
+            IMPORTS.to_string(),
+        ];
+        if let Some(address) = &self.metadata.address {
+            sections.push(format!(
+                "export const PROGRAM_ID = new PublicKey({})",
+                string_literal(address)
+            ));
+        }
+        sections.push(HELPERS.to_string());
+
+        let all_types = self.accounts.iter().chain(self.types.iter());
+        sections.extend(all_types.map(|t| t.to_typescript_string()));
+        sections.extend(self.accounts.iter().map(account_helpers));
+        if let Some(decode_account) = decode_account_helpers(&self.accounts) {
+            sections.push(decode_account);
+        }
+        if self.accounts.iter().any(is_record) || uses_index(self) {
+            sections.push(index_helpers());
+        }
+        sections.extend(self.instructions.iter().map(|i| i.to_typescript_string()));
+
+        sections.join("\n\n") + "\n"
     }
 }
 
+/// Builds a `create<Name>Instruction(..)` function.
+///
+/// Accounts with well-known addresses (programs, sysvars and the Nautilus
+/// Index) are filled in automatically, while all others are taken in an
+/// `accounts` object. The instruction data is the instruction's discriminant
+/// followed by its arguments.
 impl TypeScriptConverter for IdlInstruction {
     fn to_typescript_string(&self) -> String {
-        todo!()
+        let mut params = vec!["    programId: PublicKey,".to_string()];
+        let account_params: Vec<String> = self
+            .accounts
+            .iter()
+            .filter(|a| known_address(a).is_none())
+            .map(|a| format!("{}: PublicKey", camel_case(&a.name)))
+            .collect();
+        if !account_params.is_empty() {
+            params.push(format!(
+                "    accounts: {{ {} }},",
+                account_params.join(", ")
+            ));
+        }
+        let arg_params: Vec<String> = self
+            .args
+            .iter()
+            .map(|a| {
+                format!(
+                    "{}: {}",
+                    camel_case(&a.name),
+                    a.arg_type.to_typescript_string()
+                )
+            })
+            .collect();
+        if !arg_params.is_empty() {
+            params.push(format!("    args: {{ {} }},", arg_params.join(", ")));
+        }
+
+        let keys = self
+            .accounts
+            .iter()
+            .map(|a| {
+                let pubkey =
+                    known_address(a).unwrap_or_else(|| format!("accounts.{}", camel_case(&a.name)));
+                format!(
+                    "        {{ pubkey: {}, isSigner: {}, isWritable: {} }},",
+                    pubkey, a.is_signer, a.is_mut
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let mut data_layouts = vec!["borsh.u8(\"instruction\")".to_string()];
+        data_layouts.extend(
+            self.args
+                .iter()
+                .map(|a| layout(&a.arg_type, Some(&camel_case(&a.name)))),
+        );
+        let data_value = match self.args.is_empty() {
+            true => format!("{{ instruction: {} }}", self.discriminant.value),
+            false => format!("{{ instruction: {}, ...args }}", self.discriminant.value),
+        };
+
+        format!(
+            "export function create{}Instruction(\n{}\n): TransactionInstruction {{\n    const keys = [\n{}\n    ]\n    const data = encode(\n        borsh.struct([{}]),\n        {},\n    )\n    return new TransactionInstruction({{ keys, programId, data }})\n}}",
+            pascal_case(&self.name),
+            params.join("\n"),
+            keys,
+            data_layouts.join(", "),
+            data_value,
+        )
     }
 }
 
+/// Builds a type's TypeScript type along with a function returning its Borsh
+/// layout.
+///
+/// Enums are represented the way `borsh.rustEnum` decodes them - an object
+/// with the variant's name as its only key.
 impl TypeScriptConverter for IdlTypeDef {
     fn to_typescript_string(&self) -> String {
-        match &self.idl_type {
+        let (type_str, (layout_fn_str, layout_arg)) = match &self.idl_type {
             IdlTypeDefType::Struct { fields } => {
                 let fields_str = fields
                     .iter()
                     .map(|field| {
                         format!(
-                            "   {}: {};",
+                            "    {}: {}",
                             field.name,
                             field.field_data_type.to_typescript_string()
                         )
                     })
                     .collect::<Vec<String>>()
                     .join("\n");
-
-                format!("type {} = {{\n{}\n}};", self.name, fields_str)
+                let layouts_str = fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "        {},",
+                            layout(&field.field_data_type, Some(&field.name))
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                (
+                    format!(" {{\n{}\n}}", fields_str),
+                    ("borsh.struct", format!("[\n{}\n    ]", layouts_str)),
+                )
             }
             IdlTypeDefType::Enum { variants } => {
                 let variants_str = variants
@@ -81,54 +243,124 @@ impl TypeScriptConverter for IdlTypeDef {
                                 .iter()
                                 .map(|field| {
                                     format!(
-                                        "   {}: {};",
+                                        "{}: {}",
                                         field.name,
                                         field.field_data_type.to_typescript_string()
                                     )
                                 })
                                 .collect::<Vec<String>>()
                                 .join(", "),
+                            Some(IdlTypeEnumFields::Tuple(types)) => types
+                                .iter()
+                                .enumerate()
+                                .map(|(i, ty)| format!("{}: {}", i, ty.to_typescript_string()))
+                                .collect::<Vec<String>>()
+                                .join(", "),
                             None => String::new(),
                         };
-
-                        format!("{} = {{ {} }}", variant.name, fields_str)
+                        match fields_str.is_empty() {
+                            true => format!("    | {{ {}: {{}} }}", variant.name),
+                            false => format!("    | {{ {}: {{ {} }} }}", variant.name, fields_str),
+                        }
                     })
                     .collect::<Vec<String>>()
-                    .join(" | ");
-
-                format!("type {} = {};", self.name, variants_str)
+                    .join("\n");
+                let layouts_str = variants
+                    .iter()
+                    .map(|variant| {
+                        let field_layouts = match &variant.fields {
+                            Some(IdlTypeEnumFields::Named(fields)) => fields
+                                .iter()
+                                .map(|field| layout(&field.field_data_type, Some(&field.name)))
+                                .collect(),
+                            Some(IdlTypeEnumFields::Tuple(types)) => types
+                                .iter()
+                                .enumerate()
+                                .map(|(i, ty)| layout(ty, Some(&i.to_string())))
+                                .collect(),
+                            None => vec![],
+                        };
+                        format!(
+                            "        borsh.struct([{}], {}),",
+                            field_layouts.join(", "),
+                            string_literal(&variant.name)
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                (
+                    format!("\n{}", variants_str),
+                    ("borsh.rustEnum", format!("[\n{}\n    ]", layouts_str)),
+                )
             }
-        }
+        };
+
+        let mut layout_params: Vec<String> = self
+            .generics
+            .iter()
+            .map(|g| format!("{}: borsh.Layout<{}>", generic_layout(g), g))
+            .collect();
+        layout_params.push("property?: string".to_string());
+
+        // Accounts are always prefixed with their discriminator, even when
+        // nested in another type or passed as an instruction argument.
+        let (discriminator_str, layout_str) = match self.discriminator() {
+            Some(discriminator) => (
+                format!(
+                    "export const {} = {}\n\n",
+                    discriminator_const(&self.name),
+                    buffer_literal(&discriminator)
+                ),
+                format!(
+                    "new DiscriminatedLayout({}, {}({}), property)",
+                    discriminator_const(&self.name),
+                    layout_fn_str,
+                    layout_arg
+                ),
+            ),
+            None => (
+                String::new(),
+                format!("{}({}, property)", layout_fn_str, layout_arg),
+            ),
+        };
+
+        format!(
+            "{discriminator_str}export type {name}{generics} ={type_str}\n\nexport function {layout_fn}{generics}({params}): borsh.Layout<{name}{generics}> {{\n    return {layout_str}\n}}",
+            discriminator_str = discriminator_str,
+            name = self.name,
+            generics = typescript_generics(&self.generics),
+            type_str = type_str,
+            layout_fn = layout_fn(&self.name),
+            params = layout_params.join(", "),
+            layout_str = layout_str,
+        )
     }
 }
 
 impl TypeScriptConverter for IdlType {
     fn to_typescript_string(&self) -> String {
         match self {
-            IdlType::Array(idl_type, size) => {
-                format!("[{}; {}]", idl_type.to_typescript_string(), size)
-            }
+            IdlType::Array(idl_type, _) => format!("{}[]", element_type(idl_type)),
             IdlType::Bool => "boolean".to_string(),
-            IdlType::Bytes => "Uint8Array".to_string(),
+            IdlType::Bytes => "Buffer".to_string(),
             IdlType::Defined(name) => name.clone(),
-            IdlType::I128 | IdlType::I16 | IdlType::I32 | IdlType::I64 | IdlType::I8 => {
-                "number".to_string()
-            }
+            IdlType::I16 | IdlType::I32 | IdlType::I8 => "number".to_string(),
+            IdlType::I128 | IdlType::I64 => "BN".to_string(),
             IdlType::Option(idl_type) => format!("{} | null", idl_type.to_typescript_string()),
             IdlType::Tuple(idl_types) => format!(
-                "[{}]",
+                "{{ {} }}",
                 idl_types
                     .iter()
-                    .map(|idl_type| idl_type.to_typescript_string())
+                    .enumerate()
+                    .map(|(i, idl_type)| format!("{}: {}", i, idl_type.to_typescript_string()))
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
             IdlType::PublicKey => "PublicKey".to_string(),
             IdlType::String => "string".to_string(),
-            IdlType::U128 | IdlType::U16 | IdlType::U32 | IdlType::U64 | IdlType::U8 => {
-                "number".to_string()
-            }
-            IdlType::Vec(idl_type) => format!("{}[]", idl_type.to_typescript_string()),
+            IdlType::U16 | IdlType::U32 | IdlType::U8 => "number".to_string(),
+            IdlType::U128 | IdlType::U64 => "BN".to_string(),
+            IdlType::Vec(idl_type) => format!("{}[]", element_type(idl_type)),
             IdlType::HashMap(key_type, value_type) => format!(
                 "Map<{}, {}>",
                 key_type.to_typescript_string(),
@@ -139,8 +371,333 @@ impl TypeScriptConverter for IdlType {
                 key_type.to_typescript_string(),
                 value_type.to_typescript_string()
             ),
-            IdlType::HashSet(idl_type) => format!("Set<{}>", idl_type.to_typescript_string()),
-            IdlType::BTreeSet(idl_type) => format!("Set<{}>", idl_type.to_typescript_string()),
+            // Borsh encodes sets as sequences, which `borsh.vec` decodes to arrays.
+            IdlType::HashSet(idl_type) => format!("{}[]", element_type(idl_type)),
+            IdlType::BTreeSet(idl_type) => format!("{}[]", element_type(idl_type)),
+            IdlType::Generic(name) => name.clone(),
+        }
+    }
+}
+
+/// Renders the TypeScript type of an array's element, wrapping unions in
+/// parentheses.
+fn element_type(idl_type: &IdlType) -> String {
+    let type_str = idl_type.to_typescript_string();
+    match type_str.contains(" | ") {
+        true => format!("({})", type_str),
+        false => type_str,
+    }
+}
+
+/// Renders the Borsh layout for a type, optionally assigning it to a property.
+fn layout(idl_type: &IdlType, property: Option<&str>) -> String {
+    let call = |function: &str, mut args: Vec<String>| {
+        if let Some(property) = property {
+            args.push(string_literal(property));
+        }
+        format!("{}({})", function, args.join(", "))
+    };
+    match idl_type {
+        IdlType::Array(idl_type, size) => call(
+            "borsh.array",
+            vec![layout(idl_type, None), size.to_string()],
+        ),
+        IdlType::Bool => call("borsh.bool", vec![]),
+        IdlType::Bytes => call("borsh.vecU8", vec![]),
+        IdlType::Defined(name) => call(&layout_fn(name), vec![]),
+        IdlType::I128 => call("borsh.i128", vec![]),
+        IdlType::I16 => call("borsh.i16", vec![]),
+        IdlType::I32 => call("borsh.i32", vec![]),
+        IdlType::I64 => call("borsh.i64", vec![]),
+        IdlType::I8 => call("borsh.i8", vec![]),
+        IdlType::Option(idl_type) => call("borsh.option", vec![layout(idl_type, None)]),
+        IdlType::Tuple(idl_types) => call(
+            "borsh.struct",
+            vec![format!(
+                "[{}]",
+                idl_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, idl_type)| layout(idl_type, Some(&i.to_string())))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )],
+        ),
+        IdlType::PublicKey => call("borsh.publicKey", vec![]),
+        IdlType::String => call("borsh.str", vec![]),
+        IdlType::U128 => call("borsh.u128", vec![]),
+        IdlType::U16 => call("borsh.u16", vec![]),
+        IdlType::U32 => call("borsh.u32", vec![]),
+        IdlType::U64 => call("borsh.u64", vec![]),
+        IdlType::U8 => call("borsh.u8", vec![]),
+        IdlType::Vec(idl_type) | IdlType::HashSet(idl_type) | IdlType::BTreeSet(idl_type) => {
+            call("borsh.vec", vec![layout(idl_type, None)])
+        }
+        IdlType::HashMap(key_type, value_type) | IdlType::BTreeMap(key_type, value_type) => call(
+            "borsh.map",
+            vec![layout(key_type, None), layout(value_type, None)],
+        ),
+        IdlType::Generic(name) => match property {
+            Some(property) => format!(
+                "{}.replicate({})",
+                generic_layout(name),
+                string_literal(property)
+            ),
+            None => generic_layout(name),
+        },
+    }
+}
+
+/// The name of the function returning a type's layout, ie. `personLayout`.
+fn layout_fn(type_name: &str) -> String {
+    format!("{}Layout", camel_case(type_name))
+}
+
+/// The name of the parameter holding a generic type parameter's layout.
+fn generic_layout(generic: &str) -> String {
+    format!("{}Layout", camel_case(generic))
+}
+
+/// The name of the constant holding an account's discriminator.
+fn discriminator_const(type_name: &str) -> String {
+    format!("{}_DISCRIMINATOR", snake_case(type_name).to_uppercase())
+}
+
+/// Builds the decode and fetch helpers, and the address helpers for an
+/// account.
+fn account_helpers(account: &IdlTypeDef) -> String {
+    let name = &account.name;
+    let generics = typescript_generics(&account.generics);
+    let generic_params: String = account
+        .generics
+        .iter()
+        .map(|g| format!(", {}: borsh.Layout<{}>", generic_layout(g), g))
+        .collect();
+    let generic_args: String = account
+        .generics
+        .iter()
+        .map(|g| format!(", {}", generic_layout(g)))
+        .collect();
+    let layout_args: Vec<String> = account.generics.iter().map(|g| generic_layout(g)).collect();
+
+    let mut helpers = vec![];
+    if account.discriminator().is_some() {
+        helpers.push(format!(
+            "export function decode{name}{generics}(data: Buffer{generic_params}): {name}{generics} {{\n    if (!data.subarray(0, 8).equals({discrim})) {{\n        throw new Error(\"Account data is not a `{name}`\")\n    }}\n    return {layout_fn}({layout_args}).decode(data)\n}}",
+            name = name,
+            generics = generics,
+            generic_params = generic_params,
+            discrim = discriminator_const(name),
+            layout_fn = layout_fn(name),
+            layout_args = layout_args.join(", "),
+        ));
+        helpers.push(format!(
+            "export async function fetch{name}{generics}(connection: Connection, address: PublicKey{generic_params}): Promise<{name}{generics} | null> {{\n    const info = await connection.getAccountInfo(address)\n    return info === null ? null : decode{name}(info.data{generic_args})\n}}",
+            name = name,
+            generics = generics,
+            generic_params = generic_params,
+            generic_args = generic_args,
+        ));
+    }
+    if let Some(find_address) = find_address_helpers(account) {
+        helpers.push(find_address);
+    }
+    helpers.join("\n\n")
+}
+
+/// Builds the helpers deriving an account's program-derived address.
+///
+/// Records are derived from their table name and primary key, while state
+/// accounts are derived from their declared seeds.
+///
+/// An IDL loaded from JSON may name a primary key or seed that isn't one of
+/// the account's fields. The address can't be derived then, so the helpers
+/// are left out rather than failing to generate the rest of the client.
+fn find_address_helpers(account: &IdlTypeDef) -> Option<String> {
+    let config = account.config.as_ref()?;
+    let name = &account.name;
+    if let (Some(table_name), Some(primary_key)) = (&config.table_name, &config.primary_key) {
+        let primary_key_ty = field_type(account, primary_key)?;
+        let primary_key_seed = match primary_key_ty {
+            IdlType::String => "Buffer.from(primaryKey)".to_string(),
+            ty => format!("encode({}, primaryKey)", layout(ty, None)),
+        };
+        let mut helpers = vec![format!(
+            "export function find{name}Address(programId: PublicKey, primaryKey: {ty}): PublicKey {{\n    return PublicKey.findProgramAddressSync(\n        [Buffer.from({table}), {seed}],\n        programId,\n    )[0]\n}}",
+            name = name,
+            ty = primary_key_ty.to_typescript_string(),
+            table = string_literal(table_name),
+            seed = primary_key_seed,
+        )];
+        if config.autoincrement == Some(true) {
+            let next = match primary_key_ty {
+                IdlType::U64 | IdlType::U128 => "new BN(count + 1)",
+                _ => "count + 1",
+            };
+            helpers.push(format!(
+                "/** Derives the address of the next `{name}` record, based on the record count in the Nautilus Index. */\nexport async function findNext{name}Address(connection: Connection, programId: PublicKey): Promise<[PublicKey, {ty}]> {{\n    const count = (await fetchNautilusIndex(connection, programId))?.get({table}) ?? 0\n    const primaryKey = {next}\n    return [find{name}Address(programId, primaryKey), primaryKey]\n}}",
+                name = name,
+                ty = primary_key_ty.to_typescript_string(),
+                table = string_literal(table_name),
+                next = next,
+            ));
         }
+        return Some(helpers.join("\n\n"));
+    }
+
+    let mut seed_params: Vec<String> = vec![];
+    let seeds: Vec<String> = config
+        .seeds
+        .iter()
+        .map(|seed| match seed {
+            IdlSeed::Lit { value } => Some(format!("Buffer.from({})", string_literal(value))),
+            IdlSeed::Field { key } => {
+                let ty = field_type(account, key)?;
+                seed_params.push(format!(
+                    "{}: {}",
+                    camel_case(key),
+                    ty.to_typescript_string()
+                ));
+                Some(format!(
+                    "encode({}, seeds.{})",
+                    layout(ty, None),
+                    camel_case(key)
+                ))
+            }
+            IdlSeed::Param { key, value } => {
+                seed_params.push(format!(
+                    "{}: {}",
+                    camel_case(key),
+                    value.to_typescript_string()
+                ));
+                Some(format!(
+                    "encode({}, seeds.{})",
+                    layout(value, None),
+                    camel_case(key)
+                ))
+            }
+        })
+        .collect::<Option<_>>()?;
+    let params = match seed_params.is_empty() {
+        true => "programId: PublicKey".to_string(),
+        false => format!(
+            "programId: PublicKey, seeds: {{ {} }}",
+            seed_params.join(", ")
+        ),
+    };
+    // Field seeds of a generic type need the layout of the type argument.
+    let uses_generics = account
+        .generics
+        .iter()
+        .any(|g| seeds.iter().any(|s| s.contains(&generic_layout(g))));
+    let (generics, generic_params) = match uses_generics {
+        true => (
+            typescript_generics(&account.generics),
+            account
+                .generics
+                .iter()
+                .map(|g| format!(", {}: borsh.Layout<{}>", generic_layout(g), g))
+                .collect::<String>(),
+        ),
+        false => (String::new(), String::new()),
+    };
+    Some(format!(
+        "export function find{name}Address{generics}({params}{generic_params}): PublicKey {{\n    return PublicKey.findProgramAddressSync(\n        [{seeds}],\n        programId,\n    )[0]\n}}",
+        name = name,
+        generics = generics,
+        params = params,
+        generic_params = generic_params,
+        seeds = seeds.join(", "),
+    ))
+}
+
+/// Builds `decodeAccount(..)` and `fetchAccount(..)`, which identify an account
+/// by its discriminator before decoding it.
+///
+/// Generic accounts are left out, since they can't be decoded without knowing
+/// their type arguments.
+fn decode_account_helpers(accounts: &[IdlTypeDef]) -> Option<String> {
+    let accounts: Vec<(&IdlTypeDef, [u8; 8])> = accounts
+        .iter()
+        .filter(|a| a.generics.is_empty())
+        .filter_map(|a| a.discriminator().map(|d| (a, d)))
+        .collect();
+    if accounts.is_empty() {
+        return None;
+    }
+    let variants = accounts
+        .iter()
+        .map(|(a, _)| {
+            format!(
+                "    | {{ name: {}, data: {} }}",
+                string_literal(&a.name),
+                a.name
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let branches = accounts
+        .iter()
+        .map(|(a, _)| {
+            format!(
+                "    if (discriminator.equals({})) {{\n        return {{ name: {}, data: decode{}(data) }}\n    }}",
+                discriminator_const(&a.name),
+                string_literal(&a.name),
+                a.name
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    Some(format!(
+        "export type DecodedAccount =\n{}\n\nexport function decodeAccount(data: Buffer): DecodedAccount | null {{\n    const discriminator = data.subarray(0, 8)\n{}\n    return null\n}}\n\nexport async function fetchAccount(connection: Connection, address: PublicKey): Promise<DecodedAccount | null> {{\n    const info = await connection.getAccountInfo(address)\n    return info === null ? null : decodeAccount(info.data)\n}}",
+        variants, branches
+    ))
+}
+
+/// Builds the helpers for the Nautilus Index, which holds the current record
+/// count of every table.
+fn index_helpers() -> String {
+    format!(
+        "export const NAUTILUS_INDEX_DISCRIMINATOR = {}\n\nexport function findNautilusIndexAddress(programId: PublicKey): PublicKey {{\n    return PublicKey.findProgramAddressSync(\n        [Buffer.from(\"nautilus_index\"), Buffer.from([0])],\n        programId,\n    )[0]\n}}\n\nexport async function fetchNautilusIndex(connection: Connection, programId: PublicKey): Promise<Map<string, number> | null> {{\n    const info = await connection.getAccountInfo(findNautilusIndexAddress(programId))\n    if (info === null) {{\n        return null\n    }}\n    if (!info.data.subarray(0, 8).equals(NAUTILUS_INDEX_DISCRIMINATOR)) {{\n        throw new Error(\"Account data is not the Nautilus Index\")\n    }}\n    return borsh.map(borsh.str(), borsh.u32()).decode(info.data, 8)\n}}",
+        buffer_literal(&discriminator("nautilus_index"))
+    )
+}
+
+/// The address of an instruction account that doesn't need to be provided by
+/// the caller, if there is one.
+fn known_address(account: &IdlInstructionAccount) -> Option<String> {
+    let address = match account.account_type.as_str() {
+        "index" => "findNautilusIndexAddress(programId)",
+        "systemProgram" => "SystemProgram.programId",
+        "tokenProgram" => "TOKEN_PROGRAM_ID",
+        "associatedTokenProgram" => "ASSOCIATED_TOKEN_PROGRAM_ID",
+        "tokenMetadataProgram" => "TOKEN_METADATA_PROGRAM_ID",
+        "sysvar" => match account.name.as_str() {
+            "clock" => "SYSVAR_CLOCK_PUBKEY",
+            "instructions" => "SYSVAR_INSTRUCTIONS_PUBKEY",
+            "rent" => "SYSVAR_RENT_PUBKEY",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(address.to_string())
+}
+
+fn buffer_literal(bytes: &[u8]) -> String {
+    format!(
+        "Buffer.from([{}])",
+        bytes
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// Renders a type's generic parameters, ie. `<T, U>`.
+fn typescript_generics(generics: &[String]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", generics.join(", ")),
     }
 }
@@ -0,0 +1,297 @@
+//! Decodes raw account data and instruction data into JSON using nothing but
+//! a program's IDL, so accounts and transactions can be inspected without any
+//! generated client code.
+//!
+//! Values are written the way `serde_json` writes their Rust counterparts,
+//! except:
+//! * `u128` and `i128` are strings, since JSON numbers can't hold them.
+//! * Public keys are base58 strings.
+//! * Maps are arrays of `[key, value]` pairs, since their keys needn't be
+//!   strings.
+//! * Enums are externally tagged - `"Red"`, `{ "Green": [7, "go"] }` or
+//!   `{ "Yellow": { "seconds": 5 } }`.
+use std::io;
+
+use borsh::BorshDeserialize;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    idl_instruction::IdlInstruction,
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeStructField},
+    Idl,
+};
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Decodes an account's data, picking the account type by its 8-byte
+/// discriminator.
+///
+/// Returns `{ "name": <account type>, "data": { <field>: <value>, .. } }`.
+/// Any bytes past the end of the account's data - ie. unused space - are
+/// ignored.
+pub fn decode_account(idl: &Idl, data: &[u8]) -> io::Result<Value> {
+    let discriminator = data
+        .get(..8)
+        .ok_or_else(|| invalid_data("Account data is shorter than a discriminator".to_string()))?;
+    let account = idl
+        .accounts
+        .iter()
+        .find(|a| a.discriminator().map(|d| d.to_vec()) == Some(discriminator.to_vec()))
+        .ok_or_else(|| {
+            invalid_data(format!(
+                "No account in the `{}` IDL has the discriminator {:?}",
+                idl.name, discriminator
+            ))
+        })?;
+    let mut buf = &data[8..];
+    let value = Decoder::new(idl).type_def(account, &mut buf, &account.name)?;
+    Ok(json!({ "name": account.name, "data": value }))
+}
+
+/// Decodes an instruction's data - its 1-byte discriminant followed by its
+/// arguments - and labels the accounts it was called with.
+///
+/// `accounts` are the instruction's account addresses, in order. Returns:
+///
+/// ```json
+/// {
+///   "name": <instruction>,
+///   "args": { <arg>: <value>, .. },
+///   "accounts": [{ "name", "pubkey", "isMut", "isSigner" }, ..]
+/// }
+/// ```
+///
+/// Accounts past the ones the IDL lists have a `null` name.
+pub fn decode_instruction(idl: &Idl, data: &[u8], accounts: &[&str]) -> io::Result<Value> {
+    let (discriminant, mut buf) = data
+        .split_first()
+        .ok_or_else(|| invalid_data("Instruction data is empty".to_string()))?;
+    let instruction = idl
+        .instructions
+        .iter()
+        .find(|i| i.discriminant.value == *discriminant)
+        .ok_or_else(|| {
+            invalid_data(format!(
+                "No instruction in the `{}` IDL has the discriminant {}",
+                idl.name, discriminant
+            ))
+        })?;
+
+    let decoder = Decoder::new(idl);
+    let mut args = Map::new();
+    for arg in instruction.args.iter() {
+        let path = format!("{}.{}", instruction.name, arg.name);
+        args.insert(
+            arg.name.clone(),
+            decoder.value(&arg.arg_type, &mut buf, &path)?,
+        );
+    }
+    if !buf.is_empty() {
+        return Err(invalid_data(format!(
+            "Instruction `{}` has {} bytes of data left over",
+            instruction.name,
+            buf.len()
+        )));
+    }
+
+    Ok(json!({
+        "name": instruction.name,
+        "args": args,
+        "accounts": label_accounts(instruction, accounts)?,
+    }))
+}
+
+/// Pairs each of an instruction's account addresses with the account the IDL
+/// lists at its position.
+fn label_accounts(instruction: &IdlInstruction, accounts: &[&str]) -> io::Result<Vec<Value>> {
+    if accounts.len() < instruction.accounts.len() {
+        return Err(invalid_data(format!(
+            "Instruction `{}` takes {} accounts, but only {} were provided",
+            instruction.name,
+            instruction.accounts.len(),
+            accounts.len()
+        )));
+    }
+    Ok(accounts
+        .iter()
+        .enumerate()
+        .map(|(i, pubkey)| match instruction.accounts.get(i) {
+            Some(account) => json!({
+                "name": account.name,
+                "pubkey": pubkey,
+                "isMut": account.is_mut,
+                "isSigner": account.is_signer,
+            }),
+            None => json!({ "name": null, "pubkey": pubkey }),
+        })
+        .collect())
+}
+
+impl Idl {
+    /// Decodes an account's data. See [`decode_account`].
+    pub fn decode_account(&self, data: &[u8]) -> io::Result<Value> {
+        decode_account(self, data)
+    }
+
+    /// Decodes an instruction's data and labels its accounts. See
+    /// [`decode_instruction`].
+    pub fn decode_instruction(&self, data: &[u8], accounts: &[&str]) -> io::Result<Value> {
+        decode_instruction(self, data, accounts)
+    }
+}
+
+/// Reads Borsh-serialized values, looking up defined types in the IDL.
+///
+/// `path` locates the value being read in any error, ie. `Hero.stats.speed`.
+struct Decoder<'a> {
+    types: Vec<&'a IdlTypeDef>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(idl: &'a Idl) -> Self {
+        Self {
+            types: idl.accounts.iter().chain(idl.types.iter()).collect(),
+        }
+    }
+
+    fn type_def(&self, type_def: &IdlTypeDef, buf: &mut &[u8], path: &str) -> io::Result<Value> {
+        if !type_def.generics.is_empty() {
+            return Err(invalid_data(format!(
+                "`{}` is generic, so its layout isn't known",
+                path
+            )));
+        }
+        match &type_def.idl_type {
+            IdlTypeDefType::Struct { fields } => self.fields(fields, buf, path),
+            IdlTypeDefType::Enum { variants } => {
+                let index = self.read::<u8>(buf, path)?;
+                let variant = variants.get(index as usize).ok_or_else(|| {
+                    invalid_data(format!(
+                        "`{}` has no variant with index {}",
+                        type_def.name, index
+                    ))
+                })?;
+                let path = format!("{}::{}", path, variant.name);
+                let fields = match &variant.fields {
+                    Some(IdlTypeEnumFields::Named(fields)) => self.fields(fields, buf, &path)?,
+                    Some(IdlTypeEnumFields::Tuple(types)) => {
+                        self.tuple(types.iter(), buf, &path)?
+                    }
+                    None => return Ok(Value::String(variant.name.clone())),
+                };
+                Ok(json!({ &variant.name: fields }))
+            }
+        }
+    }
+
+    /// Reads a struct's fields into an object, or an array if they're a tuple
+    /// struct's.
+    fn fields(
+        &self,
+        fields: &[IdlTypeStructField],
+        buf: &mut &[u8],
+        path: &str,
+    ) -> io::Result<Value> {
+        let is_tuple = !fields.is_empty()
+            && fields
+                .iter()
+                .all(|f| f.name.chars().all(|c| c.is_ascii_digit()));
+        if is_tuple {
+            return self.tuple(fields.iter().map(|f| &f.field_data_type), buf, path);
+        }
+        let mut object = Map::new();
+        for field in fields.iter() {
+            let path = format!("{}.{}", path, field.name);
+            object.insert(
+                field.name.clone(),
+                self.value(&field.field_data_type, buf, &path)?,
+            );
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn tuple<'t>(
+        &self,
+        types: impl Iterator<Item = &'t IdlType>,
+        buf: &mut &[u8],
+        path: &str,
+    ) -> io::Result<Value> {
+        types
+            .enumerate()
+            .map(|(i, ty)| self.value(ty, buf, &format!("{}.{}", path, i)))
+            .collect()
+    }
+
+    fn value(&self, ty: &IdlType, buf: &mut &[u8], path: &str) -> io::Result<Value> {
+        Ok(match ty {
+            IdlType::Bool => Value::Bool(self.read(buf, path)?),
+            IdlType::U8 => self.read::<u8>(buf, path)?.into(),
+            IdlType::U16 => self.read::<u16>(buf, path)?.into(),
+            IdlType::U32 => self.read::<u32>(buf, path)?.into(),
+            IdlType::U64 => self.read::<u64>(buf, path)?.into(),
+            IdlType::U128 => self.read::<u128>(buf, path)?.to_string().into(),
+            IdlType::I8 => self.read::<i8>(buf, path)?.into(),
+            IdlType::I16 => self.read::<i16>(buf, path)?.into(),
+            IdlType::I32 => self.read::<i32>(buf, path)?.into(),
+            IdlType::I64 => self.read::<i64>(buf, path)?.into(),
+            IdlType::I128 => self.read::<i128>(buf, path)?.to_string().into(),
+            IdlType::String => Value::String(self.read(buf, path)?),
+            IdlType::PublicKey => {
+                let pubkey: [u8; 32] = self.read(buf, path)?;
+                Value::String(bs58::encode(pubkey).into_string())
+            }
+            IdlType::Bytes => self.read::<Vec<u8>>(buf, path)?.into(),
+            IdlType::Array(ty, len) => (0..*len)
+                .map(|i| self.value(ty, buf, &format!("{}.{}", path, i)))
+                .collect::<io::Result<_>>()?,
+            IdlType::Vec(ty) | IdlType::HashSet(ty) | IdlType::BTreeSet(ty) => {
+                let len = self.read::<u32>(buf, path)?;
+                (0..len)
+                    .map(|i| self.value(ty, buf, &format!("{}.{}", path, i)))
+                    .collect::<io::Result<_>>()?
+            }
+            IdlType::HashMap(key, value) | IdlType::BTreeMap(key, value) => {
+                let len = self.read::<u32>(buf, path)?;
+                (0..len)
+                    .map(|i| {
+                        let path = format!("{}.{}", path, i);
+                        Ok(json!([
+                            self.value(key, buf, &path)?,
+                            self.value(value, buf, &path)?
+                        ]))
+                    })
+                    .collect::<io::Result<_>>()?
+            }
+            IdlType::Option(ty) => match self.read::<u8>(buf, path)? {
+                0 => Value::Null,
+                1 => self.value(ty, buf, path)?,
+                tag => {
+                    return Err(invalid_data(format!(
+                        "`{}` has an invalid option tag {}",
+                        path, tag
+                    )))
+                }
+            },
+            IdlType::Tuple(types) => self.tuple(types.iter(), buf, path)?,
+            IdlType::Defined(name) => {
+                let type_def = self.types.iter().find(|t| &t.name == name).ok_or_else(|| {
+                    invalid_data(format!("`{}` is of undefined type `{}`", path, name))
+                })?;
+                self.type_def(type_def, buf, path)?
+            }
+            IdlType::Generic(name) => {
+                return Err(invalid_data(format!(
+                    "`{}` is of generic type `{}`, so its layout isn't known",
+                    path, name
+                )))
+            }
+        })
+    }
+
+    fn read<T: BorshDeserialize>(&self, buf: &mut &[u8], path: &str) -> io::Result<T> {
+        T::deserialize(buf).map_err(|e| invalid_data(format!("Failed to read `{}`: {}", path, e)))
+    }
+}
@@ -0,0 +1,724 @@
+//! Compares two versions of a program's IDL and classifies every change as
+//! breaking or compatible for deployed clients.
+//!
+//! A change is breaking when a client built against the old IDL could no
+//! longer talk to the program described by the new one, ie.:
+//! * An account's or type's Borsh layout changed - fields or variants were
+//!   added, removed, renamed, reordered or changed type.
+//! * An account's discriminator, seeds, primary key or autoincrement changed,
+//!   so it lives at a different address or can't be found by its discriminator.
+//! * An instruction was removed, its discriminant changed, or its accounts were
+//!   added, removed, reordered or made writable or signers.
+//!
+//! Additions clients don't need to know about - new instructions, types,
+//! accounts, errors and trailing enum variants - and loosened account
+//! requirements are compatible.
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    idl_error::IdlError,
+    idl_instruction::{IdlInstruction, IdlInstructionAccount},
+    idl_nautilus_config::IdlTypeDefNautilusConfig,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields, IdlTypeStructField},
+    Idl,
+};
+
+/// The changes between two versions of an IDL.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlDiff {
+    /// Whether any change is breaking.
+    pub breaking: bool,
+    pub changes: Vec<IdlChange>,
+}
+
+impl IdlDiff {
+    /// The breaking changes.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &IdlChange> {
+        self.changes
+            .iter()
+            .filter(|c| c.severity == IdlChangeSeverity::Breaking)
+    }
+
+    /// Renders the diff as a JSON report.
+    pub fn to_json(&self) -> std::io::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// A single change between two versions of an IDL.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlChange {
+    pub severity: IdlChangeSeverity,
+    pub kind: IdlChangeKind,
+    /// Where the change is, ie. `accounts.Hero.name`,
+    /// `types.Shape.Circle.radius`, `instructions.createHero.accounts.index` or
+    /// `instructions.createHero.args.name`.
+    pub path: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdlChangeSeverity {
+    Breaking,
+    Compatible,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdlChangeKind {
+    ProgramAddressChanged,
+    AccountAdded,
+    AccountRemoved,
+    TypeAdded,
+    TypeRemoved,
+    TypeKindChanged,
+    GenericsChanged,
+    FieldAdded,
+    FieldRemoved,
+    FieldRenamed,
+    FieldReordered,
+    FieldTypeChanged,
+    VariantAdded,
+    VariantRemoved,
+    VariantRenamed,
+    VariantReordered,
+    DiscriminatorChanged,
+    PrimaryKeyChanged,
+    AutoincrementChanged,
+    SeedsChanged,
+    AuthoritiesChanged,
+    DefaultInstructionsChanged,
+    InstructionAdded,
+    InstructionRemoved,
+    DiscriminantChanged,
+    InstructionAccountAdded,
+    InstructionAccountRemoved,
+    InstructionAccountRenamed,
+    InstructionAccountReordered,
+    InstructionAccountTypeChanged,
+    MutabilityChanged,
+    SignerChanged,
+    ErrorAdded,
+    ErrorRemoved,
+    ErrorCodeChanged,
+    ErrorMessageChanged,
+}
+
+/// Compares two versions of an IDL.
+pub fn diff(old: &Idl, new: &Idl) -> IdlDiff {
+    let mut differ = Differ::default();
+    differ.metadata(old, new);
+    differ.type_defs(old, new);
+    differ.instructions(&old.instructions, &new.instructions);
+    differ.errors(&old.errors, &new.errors);
+    IdlDiff {
+        breaking: differ
+            .changes
+            .iter()
+            .any(|c| c.severity == IdlChangeSeverity::Breaking),
+        changes: differ.changes,
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Option<serde_json::Value> {
+    serde_json::to_value(value).ok()
+}
+
+/// Collects the changes between two IDLs.
+#[derive(Default)]
+struct Differ {
+    changes: Vec<IdlChange>,
+}
+
+impl Differ {
+    fn push(
+        &mut self,
+        severity: IdlChangeSeverity,
+        kind: IdlChangeKind,
+        path: &str,
+        message: String,
+        old: Option<serde_json::Value>,
+        new: Option<serde_json::Value>,
+    ) {
+        self.changes.push(IdlChange {
+            severity,
+            kind,
+            path: path.to_string(),
+            message,
+            old,
+            new,
+        });
+    }
+
+    fn breaking(&mut self, kind: IdlChangeKind, path: &str, message: String) {
+        self.push(IdlChangeSeverity::Breaking, kind, path, message, None, None);
+    }
+
+    fn compatible(&mut self, kind: IdlChangeKind, path: &str, message: String) {
+        self.push(
+            IdlChangeSeverity::Compatible,
+            kind,
+            path,
+            message,
+            None,
+            None,
+        );
+    }
+
+    /// Records a breaking change of a value, along with its old and new value.
+    fn changed<T: Serialize>(
+        &mut self,
+        kind: IdlChangeKind,
+        path: &str,
+        message: String,
+        old: &T,
+        new: &T,
+    ) {
+        self.push(
+            IdlChangeSeverity::Breaking,
+            kind,
+            path,
+            message,
+            to_value(old),
+            to_value(new),
+        );
+    }
+
+    fn metadata(&mut self, old: &Idl, new: &Idl) {
+        if let (Some(old_address), Some(new_address)) =
+            (&old.metadata.address, &new.metadata.address)
+        {
+            if old_address != new_address {
+                self.changed(
+                    IdlChangeKind::ProgramAddressChanged,
+                    "metadata.address",
+                    format!(
+                        "The program's address changed from `{}` to `{}`",
+                        old_address, new_address
+                    ),
+                    old_address,
+                    new_address,
+                );
+            }
+        }
+    }
+
+    /// Compares accounts and types, matched by name.
+    ///
+    /// A type that became an account (or vice versa) is compared like any
+    /// other, on top of being reported as an added (or removed) account.
+    fn type_defs(&mut self, old: &Idl, new: &Idl) {
+        let old_type_defs = type_defs(old);
+        let new_type_defs = type_defs(new);
+        for (old_path, old_type_def, old_is_account) in &old_type_defs {
+            match new_type_defs
+                .iter()
+                .find(|(_, t, _)| t.name == old_type_def.name)
+            {
+                Some((new_path, new_type_def, new_is_account)) => {
+                    if *old_is_account && !new_is_account {
+                        self.breaking(
+                            IdlChangeKind::AccountRemoved,
+                            old_path,
+                            format!("`{}` is no longer an account", old_type_def.name),
+                        );
+                    }
+                    if !old_is_account && *new_is_account {
+                        self.compatible(
+                            IdlChangeKind::AccountAdded,
+                            new_path,
+                            format!("`{}` is now an account", new_type_def.name),
+                        );
+                    }
+                    self.type_def(new_path, old_type_def, new_type_def);
+                }
+                None => {
+                    let kind = match old_is_account {
+                        true => IdlChangeKind::AccountRemoved,
+                        false => IdlChangeKind::TypeRemoved,
+                    };
+                    self.breaking(
+                        kind,
+                        old_path,
+                        format!("`{}` was removed", old_type_def.name),
+                    );
+                }
+            }
+        }
+        for (new_path, new_type_def, new_is_account) in &new_type_defs {
+            if !old_type_defs
+                .iter()
+                .any(|(_, t, _)| t.name == new_type_def.name)
+            {
+                let kind = match new_is_account {
+                    true => IdlChangeKind::AccountAdded,
+                    false => IdlChangeKind::TypeAdded,
+                };
+                self.compatible(kind, new_path, format!("`{}` was added", new_type_def.name));
+            }
+        }
+    }
+
+    fn type_def(&mut self, path: &str, old: &IdlTypeDef, new: &IdlTypeDef) {
+        if old.generics != new.generics {
+            self.changed(
+                IdlChangeKind::GenericsChanged,
+                path,
+                format!("The generic parameters of `{}` changed", old.name),
+                &old.generics,
+                &new.generics,
+            );
+        }
+        match (&old.idl_type, &new.idl_type) {
+            (
+                IdlTypeDefType::Struct { fields: old_fields },
+                IdlTypeDefType::Struct { fields: new_fields },
+            ) => self.fields(path, old_fields, new_fields),
+            (
+                IdlTypeDefType::Enum {
+                    variants: old_variants,
+                },
+                IdlTypeDefType::Enum {
+                    variants: new_variants,
+                },
+            ) => {
+                let old_names: Vec<&str> = old_variants.iter().map(|v| v.name.as_str()).collect();
+                let new_names: Vec<&str> = new_variants.iter().map(|v| v.name.as_str()).collect();
+                self.members(path, &old_names, &new_names, MemberKinds::VARIANT, true);
+                for old_variant in old_variants {
+                    if let Some(new_variant) =
+                        new_variants.iter().find(|v| v.name == old_variant.name)
+                    {
+                        self.fields(
+                            &format!("{}.{}", path, old_variant.name),
+                            &variant_fields(&old_variant.fields),
+                            &variant_fields(&new_variant.fields),
+                        );
+                    }
+                }
+            }
+            _ => self.breaking(
+                IdlChangeKind::TypeKindChanged,
+                path,
+                format!("`{}` changed between a struct and an enum", old.name),
+            ),
+        }
+        self.config(path, &old.name, old.config.as_ref(), new.config.as_ref());
+    }
+
+    /// Compares two lists of fields (or instruction args), which are
+    /// serialized in order.
+    fn fields(&mut self, path: &str, old: &[IdlTypeStructField], new: &[IdlTypeStructField]) {
+        let old_names: Vec<&str> = old.iter().map(|f| f.name.as_str()).collect();
+        let new_names: Vec<&str> = new.iter().map(|f| f.name.as_str()).collect();
+        self.members(path, &old_names, &new_names, MemberKinds::FIELD, false);
+        for (i, old_field) in old.iter().enumerate() {
+            // Renamed fields are compared by position.
+            let new_field = new
+                .iter()
+                .find(|f| f.name == old_field.name)
+                .or_else(|| new.get(i).filter(|f| !old_names.contains(&f.name.as_str())));
+            if let Some(new_field) = new_field {
+                if old_field.field_data_type != new_field.field_data_type {
+                    self.changed(
+                        IdlChangeKind::FieldTypeChanged,
+                        &format!("{}.{}", path, new_field.name),
+                        format!("The type of `{}` changed", new_field.name),
+                        &old_field.field_data_type,
+                        &new_field.field_data_type,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Compares two ordered lists of members - fields, variants or instruction
+    /// accounts - by name, reporting additions, removals, renames and
+    /// reorderings.
+    ///
+    /// A member missing from one list is considered renamed if there's a new
+    /// member in its position in the other. Only trailing additions are
+    /// compatible, and only if `trailing_additions_are_compatible`.
+    fn members(
+        &mut self,
+        path: &str,
+        old: &[&str],
+        new: &[&str],
+        kinds: MemberKinds,
+        trailing_additions_are_compatible: bool,
+    ) {
+        let renamed: Vec<usize> = (0..old.len().min(new.len()))
+            .filter(|&i| !new.contains(&old[i]) && !old.contains(&new[i]))
+            .collect();
+        for (i, name) in old.iter().enumerate() {
+            if renamed.contains(&i) {
+                self.changed(
+                    kinds.renamed,
+                    &format!("{}.{}", path, new[i]),
+                    format!("`{}` was renamed to `{}`", name, new[i]),
+                    name,
+                    &new[i],
+                );
+            } else if !new.contains(name) {
+                self.breaking(
+                    kinds.removed,
+                    &format!("{}.{}", path, name),
+                    format!("`{}` was removed", name),
+                );
+            }
+        }
+        for (i, name) in new.iter().enumerate() {
+            if !old.contains(name) && !renamed.contains(&i) {
+                let member_path = format!("{}.{}", path, name);
+                let message = format!("`{}` was added", name);
+                match trailing_additions_are_compatible && i >= old.len() {
+                    true => self.compatible(kinds.added, &member_path, message),
+                    false => self.breaking(kinds.added, &member_path, message),
+                }
+            }
+        }
+        // Members kept on both sides must stay in the same relative order.
+        let old_kept: Vec<&&str> = old.iter().filter(|n| new.contains(n)).collect();
+        let new_kept: Vec<&&str> = new.iter().filter(|n| old.contains(n)).collect();
+        for (i, name) in old_kept.iter().enumerate() {
+            if new_kept[i] != *name {
+                let new_position = new.iter().position(|n| n == *name).unwrap_or_default();
+                let old_position = old.iter().position(|n| n == *name).unwrap_or_default();
+                self.changed(
+                    kinds.reordered,
+                    &format!("{}.{}", path, name),
+                    format!(
+                        "`{}` moved from position {} to {}",
+                        name, old_position, new_position
+                    ),
+                    &old_position,
+                    &new_position,
+                );
+            }
+        }
+    }
+
+    fn config(
+        &mut self,
+        path: &str,
+        name: &str,
+        old: Option<&IdlTypeDefNautilusConfig>,
+        new: Option<&IdlTypeDefNautilusConfig>,
+    ) {
+        let (old, new) = match (old, new) {
+            (Some(old), Some(new)) => (old, new),
+            // Accounts gaining or losing their configuration are reported as
+            // added or removed accounts.
+            _ => return,
+        };
+        if old.table_name != new.table_name || old.discrminator_str != new.discrminator_str {
+            let old_discrim = old.table_name.as_ref().or(old.discrminator_str.as_ref());
+            let new_discrim = new.table_name.as_ref().or(new.discrminator_str.as_ref());
+            self.changed(
+                IdlChangeKind::DiscriminatorChanged,
+                path,
+                format!("The discriminator of `{}` changed", name),
+                &old_discrim,
+                &new_discrim,
+            );
+        }
+        if old.primary_key != new.primary_key {
+            self.changed(
+                IdlChangeKind::PrimaryKeyChanged,
+                path,
+                format!("The primary key of `{}` changed", name),
+                &old.primary_key,
+                &new.primary_key,
+            );
+        }
+        if old.autoincrement != new.autoincrement {
+            self.changed(
+                IdlChangeKind::AutoincrementChanged,
+                path,
+                format!("Autoincrement of `{}` changed", name),
+                &old.autoincrement,
+                &new.autoincrement,
+            );
+        }
+        if old.seeds != new.seeds {
+            self.changed(
+                IdlChangeKind::SeedsChanged,
+                path,
+                format!("The seeds of `{}` changed", name),
+                &old.seeds,
+                &new.seeds,
+            );
+        }
+        if old.authorities != new.authorities {
+            self.changed(
+                IdlChangeKind::AuthoritiesChanged,
+                path,
+                format!("The authorities of `{}` changed", name),
+                &old.authorities,
+                &new.authorities,
+            );
+        }
+        if old.default_instructions != new.default_instructions {
+            // The instructions themselves are compared separately, so this is
+            // only breaking if one was dropped.
+            let severity = match old
+                .default_instructions
+                .iter()
+                .all(|i| new.default_instructions.contains(i))
+            {
+                true => IdlChangeSeverity::Compatible,
+                false => IdlChangeSeverity::Breaking,
+            };
+            self.push(
+                severity,
+                IdlChangeKind::DefaultInstructionsChanged,
+                path,
+                format!("The default instructions of `{}` changed", name),
+                to_value(&old.default_instructions),
+                to_value(&new.default_instructions),
+            );
+        }
+    }
+
+    /// Compares instructions, matched by name.
+    fn instructions(&mut self, old: &[IdlInstruction], new: &[IdlInstruction]) {
+        for old_instruction in old {
+            let path = format!("instructions.{}", old_instruction.name);
+            match new.iter().find(|i| i.name == old_instruction.name) {
+                Some(new_instruction) => self.instruction(&path, old_instruction, new_instruction),
+                None => self.breaking(
+                    IdlChangeKind::InstructionRemoved,
+                    &path,
+                    format!("`{}` was removed", old_instruction.name),
+                ),
+            }
+        }
+        for new_instruction in new {
+            if !old.iter().any(|i| i.name == new_instruction.name) {
+                self.compatible(
+                    IdlChangeKind::InstructionAdded,
+                    &format!("instructions.{}", new_instruction.name),
+                    format!("`{}` was added", new_instruction.name),
+                );
+            }
+        }
+    }
+
+    fn instruction(&mut self, path: &str, old: &IdlInstruction, new: &IdlInstruction) {
+        if old.discriminant.value != new.discriminant.value {
+            self.changed(
+                IdlChangeKind::DiscriminantChanged,
+                path,
+                format!("The discriminant of `{}` changed", old.name),
+                &old.discriminant.value,
+                &new.discriminant.value,
+            );
+        }
+
+        let accounts_path = format!("{}.accounts", path);
+        let old_names: Vec<&str> = old.accounts.iter().map(|a| a.name.as_str()).collect();
+        let new_names: Vec<&str> = new.accounts.iter().map(|a| a.name.as_str()).collect();
+        self.members(
+            &accounts_path,
+            &old_names,
+            &new_names,
+            MemberKinds::INSTRUCTION_ACCOUNT,
+            false,
+        );
+        for (i, old_account) in old.accounts.iter().enumerate() {
+            let new_account = new
+                .accounts
+                .iter()
+                .find(|a| a.name == old_account.name)
+                .or_else(|| {
+                    new.accounts
+                        .get(i)
+                        .filter(|a| !old_names.contains(&a.name.as_str()))
+                });
+            if let Some(new_account) = new_account {
+                self.instruction_account(
+                    &format!("{}.{}", accounts_path, new_account.name),
+                    old_account,
+                    new_account,
+                );
+            }
+        }
+
+        let old_args: Vec<IdlTypeStructField> = old
+            .args
+            .iter()
+            .map(|a| IdlTypeStructField::new(a.name.clone(), a.arg_type.clone()))
+            .collect();
+        let new_args: Vec<IdlTypeStructField> = new
+            .args
+            .iter()
+            .map(|a| IdlTypeStructField::new(a.name.clone(), a.arg_type.clone()))
+            .collect();
+        self.fields(&format!("{}.args", path), &old_args, &new_args);
+    }
+
+    /// Compares an instruction account's requirements. Requiring more of an
+    /// account is breaking, while requiring less is compatible.
+    fn instruction_account(
+        &mut self,
+        path: &str,
+        old: &IdlInstructionAccount,
+        new: &IdlInstructionAccount,
+    ) {
+        if old.is_mut != new.is_mut {
+            let (severity, message) = match new.is_mut {
+                true => (IdlChangeSeverity::Breaking, "is now writable"),
+                false => (IdlChangeSeverity::Compatible, "is no longer writable"),
+            };
+            self.push(
+                severity,
+                IdlChangeKind::MutabilityChanged,
+                path,
+                format!("`{}` {}", new.name, message),
+                to_value(&old.is_mut),
+                to_value(&new.is_mut),
+            );
+        }
+        if old.is_signer != new.is_signer {
+            let (severity, message) = match new.is_signer {
+                true => (IdlChangeSeverity::Breaking, "is now a signer"),
+                false => (IdlChangeSeverity::Compatible, "is no longer a signer"),
+            };
+            self.push(
+                severity,
+                IdlChangeKind::SignerChanged,
+                path,
+                format!("`{}` {}", new.name, message),
+                to_value(&old.is_signer),
+                to_value(&new.is_signer),
+            );
+        }
+        if old.account_type != new.account_type {
+            self.changed(
+                IdlChangeKind::InstructionAccountTypeChanged,
+                path,
+                format!("The account type of `{}` changed", new.name),
+                &old.account_type,
+                &new.account_type,
+            );
+        }
+    }
+
+    /// Compares errors, matched by name.
+    ///
+    /// Clients map error codes to errors, so a code changing is breaking, while
+    /// errors coming and going are compatible.
+    fn errors(&mut self, old: &[IdlError], new: &[IdlError]) {
+        for old_error in old {
+            let path = format!("errors.{}", old_error.name);
+            match new.iter().find(|e| e.name == old_error.name) {
+                Some(new_error) => {
+                    if old_error.code != new_error.code {
+                        self.changed(
+                            IdlChangeKind::ErrorCodeChanged,
+                            &path,
+                            format!("The code of `{}` changed", old_error.name),
+                            &old_error.code,
+                            &new_error.code,
+                        );
+                    }
+                    if old_error.msg != new_error.msg {
+                        self.push(
+                            IdlChangeSeverity::Compatible,
+                            IdlChangeKind::ErrorMessageChanged,
+                            &path,
+                            format!("The message of `{}` changed", old_error.name),
+                            to_value(&old_error.msg),
+                            to_value(&new_error.msg),
+                        );
+                    }
+                }
+                None => self.compatible(
+                    IdlChangeKind::ErrorRemoved,
+                    &path,
+                    format!("`{}` was removed", old_error.name),
+                ),
+            }
+        }
+        for new_error in new {
+            if !old.iter().any(|e| e.name == new_error.name) {
+                self.compatible(
+                    IdlChangeKind::ErrorAdded,
+                    &format!("errors.{}", new_error.name),
+                    format!("`{}` was added", new_error.name),
+                );
+            }
+        }
+    }
+}
+
+/// The kinds of change reported for a kind of ordered member.
+#[derive(Clone, Copy)]
+struct MemberKinds {
+    added: IdlChangeKind,
+    removed: IdlChangeKind,
+    renamed: IdlChangeKind,
+    reordered: IdlChangeKind,
+}
+
+impl MemberKinds {
+    const FIELD: Self = Self {
+        added: IdlChangeKind::FieldAdded,
+        removed: IdlChangeKind::FieldRemoved,
+        renamed: IdlChangeKind::FieldRenamed,
+        reordered: IdlChangeKind::FieldReordered,
+    };
+    const VARIANT: Self = Self {
+        added: IdlChangeKind::VariantAdded,
+        removed: IdlChangeKind::VariantRemoved,
+        renamed: IdlChangeKind::VariantRenamed,
+        reordered: IdlChangeKind::VariantReordered,
+    };
+    const INSTRUCTION_ACCOUNT: Self = Self {
+        added: IdlChangeKind::InstructionAccountAdded,
+        removed: IdlChangeKind::InstructionAccountRemoved,
+        renamed: IdlChangeKind::InstructionAccountRenamed,
+        reordered: IdlChangeKind::InstructionAccountReordered,
+    };
+}
+
+/// Every account and type in an IDL, along with its path and whether it's an
+/// account.
+fn type_defs(idl: &Idl) -> Vec<(String, &IdlTypeDef, bool)> {
+    let accounts = idl
+        .accounts
+        .iter()
+        .map(|t| (format!("accounts.{}", t.name), t, true));
+    let types = idl
+        .types
+        .iter()
+        .map(|t| (format!("types.{}", t.name), t, false));
+    accounts.chain(types).collect()
+}
+
+/// An enum variant's fields, with tuple fields named by their index.
+fn variant_fields(fields: &Option<IdlTypeEnumFields>) -> Vec<IdlTypeStructField> {
+    match fields {
+        Some(IdlTypeEnumFields::Named(fields)) => fields.clone(),
+        Some(IdlTypeEnumFields::Tuple(types)) => types
+            .iter()
+            .enumerate()
+            .map(|(i, t)| IdlTypeStructField::new(i.to_string(), t.clone()))
+            .collect(),
+        None => vec![],
+    }
+}
+
+impl Idl {
+    /// Compares this IDL with a newer version of it.
+    pub fn diff(&self, new: &Idl) -> IdlDiff {
+        diff(self, new)
+    }
+}
@@ -0,0 +1,67 @@
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    idl_type::{array_len, IdlType, IdlTypeContext, IdlTypeError},
+    idl_type_def::docs,
+};
+
+/// A constant declared in the program's crate.
+///
+/// The value is written the way it's declared in Rust, except integers, which
+/// are evaluated - so `MAX_LEN * 2` becomes ie. `64`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlConstant {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(rename = "type")]
+    pub constant_type: IdlType,
+    pub value: String,
+}
+
+impl IdlConstant {
+    pub fn new(name: String, constant_type: IdlType, value: String) -> Self {
+        Self {
+            name,
+            docs: vec![],
+            constant_type,
+            value,
+        }
+    }
+
+    /// Converts a `const` item, evaluating integers against the constants in
+    /// `context`.
+    ///
+    /// Constants are never serialized, so `usize` and `isize` constants are
+    /// described as `u64` and `i64`.
+    pub fn from_const(
+        value: &syn::ItemConst,
+        context: &IdlTypeContext,
+    ) -> Result<Self, IdlTypeError> {
+        let name = value.ident.to_string();
+        let constant_type = match &*value.ty {
+            syn::Type::Path(path) if path.path.is_ident("usize") => IdlType::U64,
+            syn::Type::Path(path) if path.path.is_ident("isize") => IdlType::I64,
+            ty => IdlType::from_type(ty, context).map_err(|e| e.at(name.clone()))?,
+        };
+        let is_unsigned = matches!(
+            constant_type,
+            IdlType::U8 | IdlType::U16 | IdlType::U32 | IdlType::U64 | IdlType::U128
+        );
+        let constant_value = match is_unsigned {
+            true => array_len(&value.expr, context, 0).ok(),
+            false => None,
+        };
+        Ok(Self {
+            name,
+            docs: docs(&value.attrs),
+            constant_type,
+            value: match constant_value {
+                Some(constant_value) => constant_value.to_string(),
+                None => value.expr.to_token_stream().to_string(),
+            },
+        })
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// An error a program can return, identified by its custom error code.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlError {
+    pub code: u32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
+}
+
+impl IdlError {
+    pub fn new(code: u32, name: &str, msg: &str) -> Self {
+        Self {
+            code,
+            name: name.to_string(),
+            msg: Some(msg.to_string()),
+        }
+    }
+}
+
+/// The errors every Nautilus program can return, as declared by
+/// `nautilus::error::NautilusError`.
+pub fn nautilus_errors() -> Vec<IdlError> {
+    vec![
+        IdlError::new(
+            200,
+            "LoadDataFailed",
+            "The inner data of an account could not be loaded. This usually means the account is empty.",
+        ),
+        IdlError::new(
+            201,
+            "DeserializeDataFailed",
+            "The inner data of an account could not be deserialized. This usually means an account type mismatch.",
+        ),
+        IdlError::new(
+            202,
+            "WriteRecordFailed",
+            "Nautilus couldn't write a new record to a table. This usually means an error with the primary key provided.",
+        ),
+        IdlError::new(
+            203,
+            "AccountNotMutable",
+            "The underlying account for a `Mut<T>` declared object was not marked as mutable.",
+        ),
+        IdlError::new(
+            204,
+            "AccountNotSigner",
+            "The underlying account for a `Signer<T>` declared object was not marked as signer.",
+        ),
+        IdlError::new(
+            205,
+            "AccountExists",
+            "The underlying account for a `Create<T>` declared object already exists.",
+        ),
+    ]
+}
@@ -7,6 +7,8 @@ use super::idl_type::IdlType;
 #[serde(rename_all = "camelCase")]
 pub struct IdlInstruction {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     pub accounts: Vec<IdlInstructionAccount>,
     pub args: Vec<IdlInstructionArg>,
     pub discriminant: IdlInstructionDiscriminant,
@@ -21,6 +23,7 @@ impl IdlInstruction {
     ) -> Self {
         Self {
             name: name.to_string(),
+            docs: vec![],
             accounts,
             args,
             discriminant,
@@ -62,13 +65,19 @@ impl IdlInstructionAccount {
 #[serde(rename_all = "camelCase")]
 pub struct IdlInstructionArg {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     #[serde(rename = "type")]
     pub arg_type: IdlType,
 }
 
 impl IdlInstructionArg {
     pub fn new(name: String, arg_type: IdlType) -> Self {
-        Self { name, arg_type }
+        Self {
+            name,
+            docs: vec![],
+            arg_type,
+        }
     }
 }
 
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::idl_type::IdlType;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum IdlSeed {
     Lit { value: String },
@@ -27,13 +27,13 @@ pub struct IdlTypeDefNautilusConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub autoincrement: Option<bool>,
     pub authorities: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub default_instructions: Vec<IdlTypeDefNautilusConfigDefaultInstruction>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub seeds: Vec<IdlSeed>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum IdlTypeDefNautilusConfigDefaultInstruction {
     Create(String),
     Delete(String),
@@ -1,9 +1,14 @@
+use std::collections::HashMap;
+
+use quote::ToTokens;
 use serde::{Deserialize, Serialize};
 
+use crate::idl_type_def::IdlTypeDef;
+
 /// An IDL type enum for converting from Rust types to IDL type.
 ///
 /// Copied from Shank: https://github.com/metaplex-foundation/shank
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum IdlType {
     Array(Box<IdlType>, usize),
@@ -29,26 +34,114 @@ pub enum IdlType {
     BTreeMap(Box<IdlType>, Box<IdlType>),
     HashSet(Box<IdlType>),
     BTreeSet(Box<IdlType>),
+    Generic(String),
+}
+
+/// The type aliases and constants declared in a crate, which the types in it
+/// can refer to.
+#[derive(Clone, Debug, Default)]
+pub struct IdlTypeContext {
+    aliases: HashMap<String, syn::ItemType>,
+    consts: HashMap<String, syn::Expr>,
+}
+
+impl IdlTypeContext {
+    pub fn new<'a>(
+        aliases: impl IntoIterator<Item = &'a syn::ItemType>,
+        consts: impl IntoIterator<Item = &'a syn::ItemConst>,
+    ) -> Self {
+        Self {
+            aliases: aliases
+                .into_iter()
+                .map(|a| (a.ident.to_string(), a.clone()))
+                .collect(),
+            consts: consts
+                .into_iter()
+                .map(|c| (c.ident.to_string(), *c.expr.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// A Rust type that can't be described in the IDL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlTypeError {
+    /// Where the type is used, ie. `Hero.stats`.
+    pub location: Option<String>,
+    /// The type, as written.
+    pub ty: String,
+    pub reason: String,
 }
 
-impl From<&syn::Type> for IdlType {
-    fn from(value: &syn::Type) -> Self {
+impl IdlTypeError {
+    pub(crate) fn new(ty: &impl ToTokens, reason: &str) -> Self {
+        Self {
+            location: None,
+            ty: ty.to_token_stream().to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Records where the type is used, unless it's already known.
+    pub fn at(mut self, location: String) -> Self {
+        self.location.get_or_insert(location);
+        self
+    }
+}
+
+impl std::fmt::Display for IdlTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "{}: ", location)?;
+        }
+        write!(f, "`{}` {}", self.ty, self.reason)
+    }
+}
+
+impl std::error::Error for IdlTypeError {}
+
+impl TryFrom<&syn::Type> for IdlType {
+    type Error = IdlTypeError;
+
+    fn try_from(value: &syn::Type) -> Result<Self, Self::Error> {
+        Self::from_type(value, &IdlTypeContext::default())
+    }
+}
+
+impl IdlType {
+    /// Converts a Rust type into an IDL type, resolving any type aliases and
+    /// constants (ie. array lengths) declared in `context`.
+    ///
+    /// `Box<T>` is serialized exactly like `T`, so it's described as `T`.
+    /// Slices and references are described as what they serialize to.
+    pub fn from_type(value: &syn::Type, context: &IdlTypeContext) -> Result<Self, IdlTypeError> {
+        let convert = |ty: &syn::Type| Self::from_type(ty, context);
         match value {
             syn::Type::Path(type_path) => {
-                let ident = &type_path.path.segments.last().unwrap().ident;
-                let arguments = &type_path.path.segments.last().unwrap().arguments;
-                match ident.to_string().as_str() {
-                    "Vec" => {
-                        if let syn::PathArguments::AngleBracketed(args) = arguments {
-                            if args.args.len() == 1 {
-                                let inner_type = args.args.first().unwrap();
-                                if let syn::GenericArgument::Type(inner_type) = inner_type {
-                                    return IdlType::Vec(Box::new(Self::from(inner_type)));
-                                }
-                            }
-                        }
-                        panic!("Expected Vec<T>.");
-                    }
+                if type_path.qself.is_some() {
+                    return Err(IdlTypeError::new(
+                        value,
+                        "is a qualified path, which isn't supported",
+                    ));
+                }
+                let segment = match type_path.path.segments.last() {
+                    Some(segment) => segment,
+                    None => return Err(IdlTypeError::new(value, "is an empty path")),
+                };
+                let args = type_arguments(value, &segment.arguments)?;
+                let args = args
+                    .iter()
+                    .map(|arg| convert(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let ident = segment.ident.to_string();
+                let expect_args = |count: usize| match args.len() == count {
+                    true => Ok(args.clone()),
+                    false => Err(IdlTypeError::new(
+                        value,
+                        &format!("should have {} type argument(s)", count),
+                    )),
+                };
+                Ok(match ident.as_str() {
                     "bool" => IdlType::Bool,
                     "u8" => IdlType::U8,
                     "u16" => IdlType::U16,
@@ -60,51 +153,288 @@ impl From<&syn::Type> for IdlType {
                     "i32" => IdlType::I32,
                     "i64" => IdlType::I64,
                     "i128" => IdlType::I128,
-                    "String" => IdlType::String,
+                    "String" | "str" => IdlType::String,
                     "Pubkey" => IdlType::PublicKey,
                     "Bytes" => IdlType::Bytes,
-                    _ => IdlType::Defined(ident.to_string()),
-                }
-            }
-            syn::Type::Array(array_type) => {
-                let size = match &array_type.len {
-                    syn::Expr::Lit(lit) => {
-                        if let syn::Lit::Int(int_lit) = &lit.lit {
-                            int_lit.base10_parse().unwrap()
-                        } else {
-                            panic!("Expected array length as an integer literal.");
+                    "f32" | "f64" => {
+                        return Err(IdlTypeError::new(
+                            value,
+                            "is a floating point number, which the IDL can't describe",
+                        ))
+                    }
+                    "usize" | "isize" | "char" => {
+                        return Err(IdlTypeError::new(
+                            value,
+                            "has a platform-dependent or non-Borsh layout; use a fixed-size integer instead",
+                        ))
+                    }
+                    "Box" => expect_args(1)?.remove(0),
+                    "Vec" => IdlType::Vec(Box::new(expect_args(1)?.remove(0))),
+                    "Option" => IdlType::Option(Box::new(expect_args(1)?.remove(0))),
+                    "HashSet" => IdlType::HashSet(Box::new(expect_args(1)?.remove(0))),
+                    "BTreeSet" => IdlType::BTreeSet(Box::new(expect_args(1)?.remove(0))),
+                    "HashMap" | "BTreeMap" => {
+                        let mut args = expect_args(2)?;
+                        let value_type = Box::new(args.remove(1));
+                        let key_type = Box::new(args.remove(0));
+                        match ident.as_str() {
+                            "HashMap" => IdlType::HashMap(key_type, value_type),
+                            _ => IdlType::BTreeMap(key_type, value_type),
                         }
                     }
-                    _ => panic!("Expected array length as an integer literal."),
-                };
-                IdlType::Array(Box::new(Self::from(&*array_type.elem)), size)
+                    _ => match context.aliases.get(&ident) {
+                        Some(alias) => resolve_alias(value, alias, args, context)?,
+                        // Defined types don't track their type arguments.
+                        None => IdlType::Defined(ident),
+                    },
+                })
             }
-            syn::Type::Tuple(tuple_type) => {
-                let types = tuple_type
+            syn::Type::Array(array_type) => {
+                let len = array_len(&array_type.len, context, 0)
+                    .map_err(|reason| IdlTypeError::new(value, &reason))?;
+                Ok(IdlType::Array(Box::new(convert(&array_type.elem)?), len))
+            }
+            syn::Type::Slice(slice_type) => Ok(IdlType::Vec(Box::new(convert(&slice_type.elem)?))),
+            syn::Type::Tuple(tuple_type) => Ok(IdlType::Tuple(
+                tuple_type
                     .elems
                     .iter()
-                    .map(|elem| Self::from(elem))
-                    .collect();
-                IdlType::Tuple(types)
+                    .map(convert)
+                    .collect::<Result<_, _>>()?,
+            )),
+            syn::Type::Reference(type_reference) => match &*type_reference.elem {
+                syn::Type::Slice(slice_type) => match convert(&slice_type.elem)? {
+                    IdlType::U8 => Ok(IdlType::Bytes),
+                    ty => Ok(IdlType::Vec(Box::new(ty))),
+                },
+                elem => convert(elem),
+            },
+            syn::Type::Paren(paren_type) => convert(&paren_type.elem),
+            syn::Type::Group(group_type) => convert(&group_type.elem),
+            _ => Err(IdlTypeError::new(
+                value,
+                "can't be serialized with Borsh, so it can't be described in the IDL",
+            )),
+        }
+    }
+
+    /// Replaces any `Defined` types that are actually one of the given generic
+    /// type parameters with `Generic`.
+    pub fn resolve_generics(self, generics: &[String]) -> Self {
+        let resolve = |ty: Box<IdlType>| Box::new(ty.resolve_generics(generics));
+        match self {
+            IdlType::Defined(name) if generics.contains(&name) => IdlType::Generic(name),
+            IdlType::Array(ty, size) => IdlType::Array(resolve(ty), size),
+            IdlType::Option(ty) => IdlType::Option(resolve(ty)),
+            IdlType::Vec(ty) => IdlType::Vec(resolve(ty)),
+            IdlType::HashSet(ty) => IdlType::HashSet(resolve(ty)),
+            IdlType::BTreeSet(ty) => IdlType::BTreeSet(resolve(ty)),
+            IdlType::HashMap(key, value) => IdlType::HashMap(resolve(key), resolve(value)),
+            IdlType::BTreeMap(key, value) => IdlType::BTreeMap(resolve(key), resolve(value)),
+            IdlType::Tuple(types) => IdlType::Tuple(
+                types
+                    .into_iter()
+                    .map(|ty| ty.resolve_generics(generics))
+                    .collect(),
+            ),
+            ty => ty,
+        }
+    }
+
+    /// Replaces any `Generic` type parameters named in `args` with the type
+    /// they're instantiated with - the reverse of `resolve_generics`.
+    pub fn instantiate_generics(self, args: &HashMap<String, IdlType>) -> Self {
+        let instantiate = |ty: Box<IdlType>| Box::new(ty.instantiate_generics(args));
+        match self {
+            IdlType::Generic(name) => match args.get(&name) {
+                Some(ty) => ty.clone(),
+                None => IdlType::Generic(name),
+            },
+            IdlType::Array(ty, size) => IdlType::Array(instantiate(ty), size),
+            IdlType::Option(ty) => IdlType::Option(instantiate(ty)),
+            IdlType::Vec(ty) => IdlType::Vec(instantiate(ty)),
+            IdlType::HashSet(ty) => IdlType::HashSet(instantiate(ty)),
+            IdlType::BTreeSet(ty) => IdlType::BTreeSet(instantiate(ty)),
+            IdlType::HashMap(key, value) => IdlType::HashMap(instantiate(key), instantiate(value)),
+            IdlType::BTreeMap(key, value) => {
+                IdlType::BTreeMap(instantiate(key), instantiate(value))
             }
-            syn::Type::Reference(type_reference) => {
-                if let syn::Type::Slice(slice_type) = &*type_reference.elem {
-                    if let syn::Type::Path(ref_type_path) = &*slice_type.elem {
-                        let ident = &ref_type_path.path.segments.last().unwrap().ident;
-                        if ident == "u8" {
-                            IdlType::Bytes
-                        } else {
-                            panic!("Expected &[u8].");
-                        }
-                    } else {
-                        panic!("Expected &[u8].");
-                    }
-                } else {
-                    Self::from(&*type_reference.elem)
-                }
+            IdlType::Tuple(types) => IdlType::Tuple(
+                types
+                    .into_iter()
+                    .map(|ty| ty.instantiate_generics(args))
+                    .collect(),
+            ),
+            ty => ty,
+        }
+    }
+
+    /// The number of bytes this type always takes up once serialized with
+    /// Borsh, or `None` if that varies (ie. a `Vec`) or can't be known (ie. a
+    /// generic parameter).
+    ///
+    /// `Defined` types are looked up in `types`.
+    pub fn borsh_size(&self, types: &[IdlTypeDef]) -> Option<usize> {
+        self.borsh_size_within(types, 0)
+    }
+
+    pub(crate) fn borsh_size_within(&self, types: &[IdlTypeDef], depth: usize) -> Option<usize> {
+        // Guards against types that contain themselves, which can't be sized.
+        if depth > 64 {
+            return None;
+        }
+        match self {
+            IdlType::Bool | IdlType::U8 | IdlType::I8 => Some(1),
+            IdlType::U16 | IdlType::I16 => Some(2),
+            IdlType::U32 | IdlType::I32 => Some(4),
+            IdlType::U64 | IdlType::I64 => Some(8),
+            IdlType::U128 | IdlType::I128 => Some(16),
+            IdlType::PublicKey => Some(32),
+            IdlType::Array(ty, len) => ty.borsh_size_within(types, depth + 1)?.checked_mul(*len),
+            IdlType::Tuple(tys) => tys
+                .iter()
+                .map(|ty| ty.borsh_size_within(types, depth + 1))
+                .sum(),
+            IdlType::Defined(name) => types
+                .iter()
+                .find(|t| &t.name == name)?
+                .borsh_size_within(types, depth + 1),
+            _ => None,
+        }
+    }
+
+    /// Replaces any `Defined` types named in `substitutions`, ie. a type
+    /// alias' generic parameters.
+    fn substitute(self, substitutions: &HashMap<String, IdlType>) -> Self {
+        let substitute = |ty: Box<IdlType>| Box::new(ty.substitute(substitutions));
+        match self {
+            IdlType::Defined(name) => match substitutions.get(&name) {
+                Some(ty) => ty.clone(),
+                None => IdlType::Defined(name),
+            },
+            IdlType::Array(ty, size) => IdlType::Array(substitute(ty), size),
+            IdlType::Option(ty) => IdlType::Option(substitute(ty)),
+            IdlType::Vec(ty) => IdlType::Vec(substitute(ty)),
+            IdlType::HashSet(ty) => IdlType::HashSet(substitute(ty)),
+            IdlType::BTreeSet(ty) => IdlType::BTreeSet(substitute(ty)),
+            IdlType::HashMap(key, value) => IdlType::HashMap(substitute(key), substitute(value)),
+            IdlType::BTreeMap(key, value) => IdlType::BTreeMap(substitute(key), substitute(value)),
+            IdlType::Tuple(types) => IdlType::Tuple(
+                types
+                    .into_iter()
+                    .map(|ty| ty.substitute(substitutions))
+                    .collect(),
+            ),
+            ty => ty,
+        }
+    }
+}
+
+/// The type arguments of a path segment, ie. `K` and `V` in `HashMap<K, V>`.
+fn type_arguments<'a>(
+    ty: &syn::Type,
+    arguments: &'a syn::PathArguments,
+) -> Result<Vec<&'a syn::Type>, IdlTypeError> {
+    match arguments {
+        syn::PathArguments::None => Ok(vec![]),
+        syn::PathArguments::AngleBracketed(args) => Ok(args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                // Lifetimes and const arguments don't affect the layout.
+                _ => None,
+            })
+            .collect()),
+        syn::PathArguments::Parenthesized(_) => Err(IdlTypeError::new(
+            ty,
+            "is a function trait, which can't be serialized",
+        )),
+    }
+}
+
+/// Resolves a type alias, substituting the alias' generic parameters with
+/// the type arguments it's used with.
+fn resolve_alias(
+    ty: &syn::Type,
+    alias: &syn::ItemType,
+    args: Vec<IdlType>,
+    context: &IdlTypeContext,
+) -> Result<IdlType, IdlTypeError> {
+    let params: Vec<String> = alias
+        .generics
+        .type_params()
+        .map(|p| p.ident.to_string())
+        .collect();
+    if params.len() != args.len() {
+        return Err(IdlTypeError::new(
+            ty,
+            &format!(
+                "should have {} type argument(s), like its alias `{}`",
+                params.len(),
+                alias.ident
+            ),
+        ));
+    }
+    let substitutions: HashMap<String, IdlType> = params.into_iter().zip(args).collect();
+    Ok(IdlType::from_type(&alias.ty, context)?.substitute(&substitutions))
+}
+
+/// Evaluates an array's length, which can be an integer literal, a constant
+/// or arithmetic on either.
+pub(crate) fn array_len(
+    expr: &syn::Expr,
+    context: &IdlTypeContext,
+    depth: usize,
+) -> Result<usize, String> {
+    // Guards against constants defined in terms of each other.
+    if depth > 32 {
+        return Err("has a length that can't be evaluated".to_string());
+    }
+    let eval = |expr: &syn::Expr| array_len(expr, context, depth + 1);
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int_lit),
+            ..
+        }) => int_lit
+            .base10_parse()
+            .map_err(|_| "has a length that isn't a valid integer".to_string()),
+        syn::Expr::Path(path) => {
+            let name = path
+                .path
+                .segments
+                .last()
+                .map(|s| s.ident.to_string())
+                .unwrap_or_default();
+            match context.consts.get(&name) {
+                Some(value) => eval(value),
+                None => Err(format!(
+                    "has a length of `{}`, which isn't a constant declared in the crate",
+                    name
+                )),
             }
-            syn::Type::Paren(paren_type) => Self::from(&*paren_type.elem),
-            _ => panic!("Unsupported type."),
         }
+        syn::Expr::Binary(binary) => {
+            let (left, right) = (eval(&binary.left)?, eval(&binary.right)?);
+            let result = match binary.op {
+                syn::BinOp::Add(_) => left.checked_add(right),
+                syn::BinOp::Sub(_) => left.checked_sub(right),
+                syn::BinOp::Mul(_) => left.checked_mul(right),
+                syn::BinOp::Div(_) => left.checked_div(right),
+                syn::BinOp::Rem(_) => left.checked_rem(right),
+                syn::BinOp::Shl(_) => u32::try_from(right).ok().and_then(|r| left.checked_shl(r)),
+                syn::BinOp::Shr(_) => u32::try_from(right).ok().and_then(|r| left.checked_shr(r)),
+                _ => return Err("has a length using an unsupported operator".to_string()),
+            };
+            result.ok_or_else(|| "has a length that overflows".to_string())
+        }
+        syn::Expr::Paren(paren) => eval(&paren.expr),
+        syn::Expr::Group(group) => eval(&group.expr),
+        syn::Expr::Cast(cast) => eval(&cast.expr),
+        syn::Expr::Block(block) => match block.block.stmts.as_slice() {
+            [syn::Stmt::Expr(expr)] => eval(expr),
+            _ => Err("has a length that can't be evaluated".to_string()),
+        },
+        _ => Err("has a length that can't be evaluated".to_string()),
     }
 }
@@ -1,16 +1,30 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use super::{idl_nautilus_config::IdlTypeDefNautilusConfig, idl_type::IdlType};
+use super::{
+    idl_nautilus_config::IdlTypeDefNautilusConfig,
+    idl_type::{IdlType, IdlTypeContext, IdlTypeError},
+};
 
 /// An IDL type definition.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IdlTypeDef {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<String>,
     #[serde(rename = "type")]
     pub idl_type: IdlTypeDefType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config: Option<IdlTypeDefNautilusConfig>,
+    /// The size in bytes of an account holding this type - including its
+    /// discriminator - if every field has a fixed Borsh size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
 }
 
 impl IdlTypeDef {
@@ -21,33 +35,258 @@ impl IdlTypeDef {
     ) -> Self {
         Self {
             name,
+            docs: vec![],
+            generics: vec![],
             idl_type,
             config,
+            size: None,
         }
     }
-}
 
-impl From<&syn::ItemStruct> for IdlTypeDef {
-    fn from(value: &syn::ItemStruct) -> Self {
+    /// The 8-byte discriminator prefixed to the data of any account holding
+    /// this type, or `None` if this type isn't an account.
+    ///
+    /// Records are discriminated by their table name and state accounts by
+    /// their discriminator string, matching the on-chain implementation.
+    pub fn discriminator(&self) -> Option<[u8; 8]> {
+        let config = self.config.as_ref()?;
+        let discrim_str = config
+            .table_name
+            .as_ref()
+            .or(config.discrminator_str.as_ref())?;
+        Some(discriminator(discrim_str))
+    }
+
+    /// The number of bytes this type always takes up once serialized with
+    /// Borsh, or `None` if that varies. Generic types are never fixed-size,
+    /// since their parameters aren't known.
+    ///
+    /// An enum is fixed-size if all of its variants hold the same number of
+    /// bytes, which follow its 1-byte variant index.
+    pub fn borsh_size(&self, types: &[IdlTypeDef]) -> Option<usize> {
+        self.borsh_size_within(types, 0)
+    }
+
+    pub(crate) fn borsh_size_within(&self, types: &[IdlTypeDef], depth: usize) -> Option<usize> {
+        if !self.generics.is_empty() {
+            return None;
+        }
+        let fields_size = |fields: &[IdlTypeStructField]| -> Option<usize> {
+            fields
+                .iter()
+                .map(|f| f.field_data_type.borsh_size_within(types, depth + 1))
+                .sum()
+        };
+        match &self.idl_type {
+            IdlTypeDefType::Struct { fields } => fields_size(fields),
+            IdlTypeDefType::Enum { variants } => {
+                let mut sizes = variants.iter().map(|v| match &v.fields {
+                    Some(IdlTypeEnumFields::Named(fields)) => fields_size(fields),
+                    Some(IdlTypeEnumFields::Tuple(tys)) => tys
+                        .iter()
+                        .map(|ty| ty.borsh_size_within(types, depth + 1))
+                        .sum(),
+                    None => Some(0),
+                });
+                let size = sizes.next().unwrap_or(Some(0))?;
+                match sizes.all(|s| s == Some(size)) {
+                    true => Some(1 + size),
+                    false => None,
+                }
+            }
+        }
+    }
+
+    /// One instantiation of this generic type, named `name`, with its type
+    /// parameters replaced by `args` in order - ie. `VaultU64` for
+    /// `Vault<u64>`.
+    ///
+    /// Parameters without an argument are left generic.
+    pub fn instantiate(&self, name: String, args: Vec<IdlType>) -> Self {
+        let args: HashMap<String, IdlType> = self.generics.iter().cloned().zip(args).collect();
+        let instantiate_fields = |fields: &[IdlTypeStructField]| -> Vec<IdlTypeStructField> {
+            fields
+                .iter()
+                .map(|f| IdlTypeStructField {
+                    field_data_type: f.field_data_type.clone().instantiate_generics(&args),
+                    ..f.clone()
+                })
+                .collect()
+        };
+        let idl_type = match &self.idl_type {
+            IdlTypeDefType::Struct { fields } => IdlTypeDefType::Struct {
+                fields: instantiate_fields(fields),
+            },
+            IdlTypeDefType::Enum { variants } => IdlTypeDefType::Enum {
+                variants: variants
+                    .iter()
+                    .map(|v| IdlTypeEnumVariant {
+                        fields: v.fields.as_ref().map(|fields| match fields {
+                            IdlTypeEnumFields::Named(fields) => {
+                                IdlTypeEnumFields::Named(instantiate_fields(fields))
+                            }
+                            IdlTypeEnumFields::Tuple(tys) => IdlTypeEnumFields::Tuple(
+                                tys.iter()
+                                    .map(|ty| ty.clone().instantiate_generics(&args))
+                                    .collect(),
+                            ),
+                        }),
+                        ..v.clone()
+                    })
+                    .collect(),
+            },
+        };
         Self {
-            name: value.ident.to_string(),
+            name,
+            generics: self
+                .generics
+                .iter()
+                .filter(|g| !args.contains_key(*g))
+                .cloned()
+                .collect(),
+            idl_type,
+            size: None,
+            ..self.clone()
+        }
+    }
+
+    /// The size in bytes of an account holding this type: its 8-byte
+    /// discriminator followed by its data. `None` if this type isn't an
+    /// account or its size varies.
+    pub fn account_size(&self, types: &[IdlTypeDef]) -> Option<usize> {
+        self.discriminator()?;
+        Some(8 + self.borsh_size(types)?)
+    }
+}
+
+/// Hashes `global:<discrim_str>` and keeps the first 8 bytes - the same way the
+/// Nautilus runtime builds its discriminators.
+pub fn discriminator(discrim_str: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    let preimage = format!("{}:{}", "global", discrim_str);
+    discriminator.copy_from_slice(&Sha256::digest(preimage.as_bytes())[..8]);
+    discriminator
+}
+
+/// Collects an item's `///` doc comments, one entry per line.
+pub fn docs(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(doc),
+                ..
+            })) => {
+                let doc = doc.value();
+                Some(doc.strip_prefix(' ').unwrap_or(&doc).trim_end().to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The names of a type's generic type parameters.
+fn generic_names(generics: &syn::Generics) -> Vec<String> {
+    generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect()
+}
+
+/// Converts a set of fields into IDL struct fields, where `path` locates the
+/// fields in any error (ie. `Hero` or `Action::Attack`).
+///
+/// Tuple fields are named by their index.
+fn struct_fields(
+    fields: &syn::Fields,
+    generics: &[String],
+    path: &str,
+    context: &IdlTypeContext,
+) -> Result<Vec<IdlTypeStructField>, IdlTypeError> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let name = match &f.ident {
+                Some(ident) => ident.to_string(),
+                None => i.to_string(),
+            };
+            let ty = IdlType::from_type(&f.ty, context)
+                .map_err(|e| e.at(format!("{}.{}", path, name)))?;
+            let mut field = IdlTypeStructField::new(name, ty.resolve_generics(generics));
+            field.docs = docs(&f.attrs);
+            Ok(field)
+        })
+        .collect()
+}
+
+impl IdlTypeDef {
+    /// Converts a struct, resolving any type aliases and constants its fields
+    /// use from `context`.
+    pub fn from_struct(
+        value: &syn::ItemStruct,
+        context: &IdlTypeContext,
+    ) -> Result<Self, IdlTypeError> {
+        let name = value.ident.to_string();
+        let generics = generic_names(&value.generics);
+        Ok(Self {
             idl_type: IdlTypeDefType::Struct {
-                fields: value.fields.iter().map(|f| f.into()).collect(),
+                fields: struct_fields(&value.fields, &generics, &name, context)?,
             },
+            name,
+            docs: docs(&value.attrs),
+            generics,
             config: None,
-        }
+            size: None,
+        })
     }
-}
 
-impl From<&syn::ItemEnum> for IdlTypeDef {
-    fn from(value: &syn::ItemEnum) -> Self {
-        Self {
-            name: value.ident.to_string(),
+    /// Converts an enum, resolving any type aliases and constants its
+    /// variants use from `context`.
+    pub fn from_enum(
+        value: &syn::ItemEnum,
+        context: &IdlTypeContext,
+    ) -> Result<Self, IdlTypeError> {
+        let name = value.ident.to_string();
+        let generics = generic_names(&value.generics);
+        Ok(Self {
             idl_type: IdlTypeDefType::Enum {
-                variants: value.variants.iter().map(|v| v.into()).collect(),
+                variants: value
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        IdlTypeEnumVariant::from_variant(
+                            v,
+                            &generics,
+                            &format!("{}::{}", name, v.ident),
+                            context,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?,
             },
+            name,
+            docs: docs(&value.attrs),
+            generics,
             config: None,
-        }
+            size: None,
+        })
+    }
+}
+
+impl TryFrom<&syn::ItemStruct> for IdlTypeDef {
+    type Error = IdlTypeError;
+
+    fn try_from(value: &syn::ItemStruct) -> Result<Self, Self::Error> {
+        Self::from_struct(value, &IdlTypeContext::default())
+    }
+}
+
+impl TryFrom<&syn::ItemEnum> for IdlTypeDef {
+    type Error = IdlTypeError;
+
+    fn try_from(value: &syn::ItemEnum) -> Result<Self, Self::Error> {
+        Self::from_enum(value, &IdlTypeContext::default())
     }
 }
 
@@ -62,6 +301,8 @@ pub enum IdlTypeDefType {
 #[serde(rename_all = "camelCase")]
 pub struct IdlTypeStructField {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     #[serde(rename = "type")]
     pub field_data_type: IdlType,
 }
@@ -70,28 +311,41 @@ impl IdlTypeStructField {
     pub fn new(name: String, field_data_type: IdlType) -> Self {
         Self {
             name,
+            docs: vec![],
             field_data_type,
         }
     }
 }
 
-impl From<&syn::Field> for IdlTypeStructField {
-    fn from(value: &syn::Field) -> Self {
+impl TryFrom<&syn::Field> for IdlTypeStructField {
+    type Error = IdlTypeError;
+
+    /// Converts a named field; tuple fields are converted with their struct,
+    /// since they're named by their index.
+    fn try_from(value: &syn::Field) -> Result<Self, Self::Error> {
         let name = match &value.ident {
             Some(ident) => ident.to_string(),
-            None => panic!("Expected named field."),
+            None => {
+                return Err(IdlTypeError::new(
+                    &value.ty,
+                    "is a tuple field, so it can only be converted along with its struct",
+                ))
+            }
         };
-        let ty = &value.ty;
-        Self {
+        let field_data_type = IdlType::try_from(&value.ty).map_err(|e| e.at(name.clone()))?;
+        Ok(Self {
             name,
-            field_data_type: ty.into(),
-        }
+            docs: docs(&value.attrs),
+            field_data_type,
+        })
     }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdlTypeEnumVariant {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<IdlTypeEnumFields>,
 }
@@ -100,25 +354,50 @@ pub struct IdlTypeEnumVariant {
 #[serde(untagged)]
 pub enum IdlTypeEnumFields {
     Named(Vec<IdlTypeStructField>),
+    Tuple(Vec<IdlType>),
 }
 
-impl From<&syn::Variant> for IdlTypeEnumVariant {
-    fn from(value: &syn::Variant) -> Self {
+impl IdlTypeEnumVariant {
+    /// Converts an enum's variant, where `generics` are the names of the enum's
+    /// generic type parameters and `path` locates the variant in any error.
+    pub fn from_variant(
+        value: &syn::Variant,
+        generics: &[String],
+        path: &str,
+        context: &IdlTypeContext,
+    ) -> Result<Self, IdlTypeError> {
         let fields = match &value.fields {
-            syn::Fields::Named(named_fields) => {
-                let fields = named_fields
-                    .named
-                    .iter()
-                    .map(|field| field.into())
-                    .collect();
-                Some(IdlTypeEnumFields::Named(fields))
-            }
+            syn::Fields::Named(_) => Some(IdlTypeEnumFields::Named(struct_fields(
+                &value.fields,
+                generics,
+                path,
+                context,
+            )?)),
+            syn::Fields::Unnamed(_) => Some(IdlTypeEnumFields::Tuple(
+                struct_fields(&value.fields, generics, path, context)?
+                    .into_iter()
+                    .map(|field| field.field_data_type)
+                    .collect(),
+            )),
             syn::Fields::Unit => None,
-            syn::Fields::Unnamed(_) => panic!("Expected named fields in enum variant."),
         };
-        Self {
+        Ok(Self {
             name: value.ident.to_string(),
+            docs: docs(&value.attrs),
             fields,
-        }
+        })
+    }
+}
+
+impl TryFrom<&syn::Variant> for IdlTypeEnumVariant {
+    type Error = IdlTypeError;
+
+    fn try_from(value: &syn::Variant) -> Result<Self, Self::Error> {
+        Self::from_variant(
+            value,
+            &[],
+            &value.ident.to_string(),
+            &IdlTypeContext::default(),
+        )
     }
 }
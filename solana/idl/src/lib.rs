@@ -23,14 +23,23 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use self::{idl_instruction::IdlInstruction, idl_metadata::IdlMetadata, idl_type_def::IdlTypeDef};
+use self::{
+    idl_constant::IdlConstant, idl_error::IdlError, idl_instruction::IdlInstruction,
+    idl_metadata::IdlMetadata, idl_type_def::IdlTypeDef,
+};
 
 pub mod converters;
+pub mod decode;
+pub mod diff;
+pub mod idl_constant;
+pub mod idl_error;
 pub mod idl_instruction;
 pub mod idl_metadata;
 pub mod idl_nautilus_config;
 pub mod idl_type;
 pub mod idl_type_def;
+pub mod logs;
+pub mod profile;
 pub mod util;
 
 /// The entire IDL itself.
@@ -39,12 +48,16 @@ pub mod util;
 pub struct Idl {
     pub version: String,
     pub name: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub instructions: Vec<IdlInstruction>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub accounts: Vec<IdlTypeDef>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub types: Vec<IdlTypeDef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constants: Vec<IdlConstant>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<IdlError>,
     pub metadata: IdlMetadata,
 }
 
@@ -63,6 +76,8 @@ impl Idl {
             instructions,
             accounts,
             types,
+            constants: vec![],
+            errors: vec![],
             metadata,
         }
     }
@@ -71,7 +86,7 @@ impl Idl {
         if dir_path != "." {
             fs::create_dir_all(dir_path)?;
         }
-        let idl_path = Path::join(Path::new(dir_path), &format!("{}.json", &self.name));
+        let idl_path = Path::join(Path::new(dir_path), format!("{}.json", &self.name));
         let mut file = File::create(idl_path)?;
         let json_string = serde_json::to_string(&self)?;
         file.write_all(json_string.as_bytes())?;
@@ -0,0 +1,318 @@
+//! Parses a transaction's log messages into the tree of instructions it
+//! invoked, using a program's IDL to name its instructions and errors.
+//!
+//! The runtime logs each invocation as:
+//!
+//! ```text
+//! Program <program id> invoke [<depth>]
+//! Program log: <message>
+//! Program data: <base64> <base64> ..
+//! Program consumption: <units> units remaining
+//! Program <program id> consumed <units> of <limit> compute units
+//! Program return: <program id> <base64>
+//! Program <program id> success
+//! Program <program id> failed: <error>
+//! ```
+//!
+//! Logs from CPIs nest between their caller's `invoke` and `success` or
+//! `failed` lines. Nautilus programs built with the `log-info` feature also log
+//! `Instruction: <name>` as they start processing an instruction, and with
+//! `log-error`, a message describing each `NautilusError` before returning it.
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{
+    converters::camel_case,
+    idl_error::{nautilus_errors, IdlError},
+    Idl,
+};
+
+/// One instruction a transaction invoked - either directly or by CPI.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Invocation {
+    pub program_id: String,
+    /// 1 for the transaction's instructions, 2 for the CPIs they make, etc.
+    pub depth: usize,
+    /// The instruction it logged processing, ie. `Instruction: Transfer`.
+    /// For the IDL's program, the IDL's name for it - ie. `transfer`.
+    pub instruction: Option<String>,
+    /// Its `Program log:` messages, without the prefix.
+    pub logs: Vec<String>,
+    /// The `NautilusError`s it logged.
+    pub logged_errors: Vec<LoggedError>,
+    /// The data it emitted with `sol_log_data`, one entry per call.
+    pub events: Vec<Vec<Vec<u8>>>,
+    pub compute_units: Option<ComputeUnits>,
+    /// The units it had left each time it called `sol_log_compute_units`,
+    /// after how many of its `logs`.
+    pub units_remaining: Vec<(usize, u64)>,
+    pub return_data: Option<Vec<u8>>,
+    pub outcome: Outcome,
+    /// The CPIs it made, in order.
+    pub invocations: Vec<Invocation>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeUnits {
+    pub consumed: u64,
+    /// The units the invocation had left to spend when it started.
+    pub limit: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    Failed(ProgramError),
+    /// The logs end - or were truncated - before the invocation did.
+    Unknown,
+}
+
+impl Default for Outcome {
+    fn default() -> Self {
+        Self::Unknown
+    }
+}
+
+/// Why an invocation failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramError {
+    /// The error as the runtime logged it, ie.
+    /// `custom program error: 0xcc`.
+    pub message: String,
+    /// The custom error code, if it's a custom error.
+    pub code: Option<u32>,
+    /// The error's name and description, if the IDL declares the code.
+    pub name: Option<String>,
+    pub msg: Option<String>,
+}
+
+/// A `NautilusError` a program described in its logs before returning it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoggedError {
+    /// The error's variant, ie. `AccountNotSigner`.
+    pub name: String,
+    pub code: u32,
+    /// The values the message described, ie. the account's address.
+    pub args: Vec<String>,
+}
+
+/// The messages `NautilusError::print` logs: each error's name and code, and
+/// its message split around the values it describes.
+const NAUTILUS_ERROR_LOGS: &[(&str, u32, &[&str])] = &[
+    (
+        "LoadDataFailed",
+        200,
+        &["Failed to load ", " data from account: "],
+    ),
+    (
+        "DeserializeDataFailed",
+        201,
+        &["Failed to deserialize ", " data from account: "],
+    ),
+    (
+        "WriteRecordFailed",
+        202,
+        &["Failed to create a new record for table: "],
+    ),
+    (
+        "AccountNotMutable",
+        203,
+        &["This account was marked with `Mut<T>` but was not passed in as mutable: "],
+    ),
+    (
+        "AccountNotSigner",
+        204,
+        &["This account was marked with `Signer<T>` but was not passed in as signer: "],
+    ),
+    (
+        "AccountExists",
+        205,
+        &["This account was marked with `Create<T>` but it exists already: "],
+    ),
+];
+
+/// Parses `logs` into the transaction's top-level invocations, each holding
+/// the CPIs it made.
+///
+/// Invocations of `program_id` - the IDL's program - have their instructions
+/// named and their custom errors described from the IDL, falling back to
+/// `NautilusError`'s codes. Lines that can't be parsed are ignored.
+pub fn parse_logs(idl: &Idl, program_id: &str, logs: &[String]) -> Vec<Invocation> {
+    let mut roots: Vec<Invocation> = vec![];
+    // The invocations that haven't finished yet, outermost first.
+    let mut stack: Vec<Invocation> = vec![];
+    for line in logs {
+        if let Some((id, depth)) = invoke_line(line) {
+            stack.push(Invocation {
+                program_id: id.to_string(),
+                depth,
+                ..Invocation::default()
+            });
+            continue;
+        }
+        if line == "Log truncated" {
+            // Everything still running is left `Unknown`.
+            break;
+        }
+        let current = match stack.last_mut() {
+            Some(current) => current,
+            None => continue,
+        };
+        if let Some(message) = line.strip_prefix("Program log: ") {
+            if let Some(name) = message.strip_prefix("Instruction: ") {
+                if current.instruction.is_none() {
+                    current.instruction = Some(name.to_string());
+                }
+            }
+            current.logged_errors.extend(logged_error(message));
+            current.logs.push(message.to_string());
+        } else if let Some(data) = line.strip_prefix("Program data: ") {
+            current.events.push(
+                data.split_whitespace()
+                    .filter_map(|field| STANDARD.decode(field).ok())
+                    .collect(),
+            );
+        } else if let Some(units) = line.strip_prefix("Program consumption: ") {
+            if let Some(Ok(units)) = units.strip_suffix(" units remaining").map(str::parse) {
+                current.units_remaining.push((current.logs.len(), units));
+            }
+        } else if let Some(rest) = line.strip_prefix("Program return: ") {
+            current.return_data = rest
+                .split_once(' ')
+                .and_then(|(_, data)| STANDARD.decode(data).ok());
+        } else if let Some(units) = program_line(line, &current.program_id, " consumed ") {
+            current.compute_units = compute_units(units);
+        } else if program_line(line, &current.program_id, " success").is_some() {
+            current.outcome = Outcome::Success;
+            finish(&mut stack, &mut roots);
+        } else if let Some(message) = program_line(line, &current.program_id, " failed: ") {
+            current.outcome = Outcome::Failed(ProgramError::new(message));
+            finish(&mut stack, &mut roots);
+        }
+    }
+    while !stack.is_empty() {
+        finish(&mut stack, &mut roots);
+    }
+    for root in roots.iter_mut() {
+        describe(idl, program_id, root);
+    }
+    roots
+}
+
+impl Idl {
+    pub fn parse_logs(&self, program_id: &str, logs: &[String]) -> Vec<Invocation> {
+        parse_logs(self, program_id, logs)
+    }
+}
+
+impl Invocation {
+    /// This invocation and every CPI under it, depth first.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = &Invocation> + '_> {
+        Box::new(std::iter::once(self).chain(self.invocations.iter().flat_map(|i| i.iter())))
+    }
+
+    /// The innermost failed invocation - where the error was first returned.
+    pub fn failure(&self) -> Option<&Invocation> {
+        self.iter()
+            .filter(|i| matches!(i.outcome, Outcome::Failed(_)))
+            .last()
+    }
+}
+
+impl ProgramError {
+    fn new(message: &str) -> Self {
+        let code = message
+            .strip_prefix("custom program error: 0x")
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+        Self {
+            message: message.to_string(),
+            code,
+            name: None,
+            msg: None,
+        }
+    }
+}
+
+/// `Program <id> invoke [<depth>]`
+fn invoke_line(line: &str) -> Option<(&str, usize)> {
+    let rest = line.strip_prefix("Program ")?;
+    let (id, depth) = rest.split_once(" invoke [")?;
+    Some((id, depth.strip_suffix(']')?.parse().ok()?))
+}
+
+/// The rest of `Program <program_id><marker><rest>`.
+fn program_line<'a>(line: &'a str, program_id: &str, marker: &str) -> Option<&'a str> {
+    line.strip_prefix("Program ")?
+        .strip_prefix(program_id)?
+        .strip_prefix(marker)
+}
+
+/// `<units> of <limit> compute units`
+fn compute_units(units: &str) -> Option<ComputeUnits> {
+    let (consumed, rest) = units.split_once(" of ")?;
+    let limit = rest.strip_suffix(" compute units")?;
+    Some(ComputeUnits {
+        consumed: consumed.parse().ok()?,
+        limit: limit.parse().ok()?,
+    })
+}
+
+/// Moves the innermost invocation to its caller, or to the roots if it's a
+/// top-level one.
+fn finish(stack: &mut Vec<Invocation>, roots: &mut Vec<Invocation>) {
+    if let Some(done) = stack.pop() {
+        match stack.last_mut() {
+            Some(caller) => caller.invocations.push(done),
+            None => roots.push(done),
+        }
+    }
+}
+
+/// Matches a log message against the messages `NautilusError::print` logs,
+/// wherever it appears in the message - so any level prefix is ignored.
+fn logged_error(message: &str) -> Option<LoggedError> {
+    NAUTILUS_ERROR_LOGS.iter().find_map(|(name, code, parts)| {
+        let mut rest = &message[message.find(parts[0])? + parts[0].len()..];
+        let mut args = vec![];
+        for part in &parts[1..] {
+            let (arg, after) = rest.split_once(part)?;
+            args.push(arg.to_string());
+            rest = after;
+        }
+        args.push(rest.to_string());
+        Some(LoggedError {
+            name: name.to_string(),
+            code: *code,
+            args,
+        })
+    })
+}
+
+/// Names the IDL program's instructions and errors, throughout the tree.
+fn describe(idl: &Idl, program_id: &str, invocation: &mut Invocation) {
+    if invocation.program_id == program_id {
+        if let Some(logged) = &invocation.instruction {
+            // Instructions are logged by their entrypoint variant, ie.
+            // `CreatePerson` for the IDL's `createPerson`.
+            let name = camel_case(logged);
+            if let Some(instruction) = idl.instructions.iter().find(|i| i.name == name) {
+                invocation.instruction = Some(instruction.name.clone());
+            }
+        }
+        if let Outcome::Failed(error) = &mut invocation.outcome {
+            let declared = error.code.and_then(|code| {
+                idl.errors
+                    .iter()
+                    .find(|e| e.code == code)
+                    .cloned()
+                    .or_else(|| nautilus_errors().into_iter().find(|e| e.code == code))
+            });
+            if let Some(IdlError { name, msg, .. }) = declared {
+                error.name = Some(name);
+                error.msg = msg;
+            }
+        }
+    }
+    for cpi in invocation.invocations.iter_mut() {
+        describe(idl, program_id, cpi);
+    }
+}
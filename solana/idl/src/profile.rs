@@ -0,0 +1,189 @@
+//! Breaks down the compute units an instruction consumed by phase, from the
+//! logs of a program built with `nautilus`'s `profile-compute-units` feature.
+//!
+//! Each phase logs its name and the units remaining as it begins, and the
+//! units remaining and its name as it ends:
+//!
+//! ```text
+//! Program log: CU begin: <phase>
+//! Program consumption: <units> units remaining
+//! Program consumption: <units> units remaining
+//! Program log: CU end: <phase>
+//! ```
+//!
+//! A phase's units include those of the phases within it - ie. the CPIs its
+//! function makes - and of logging their checkpoints.
+use std::fmt;
+
+use crate::logs::Invocation;
+
+/// The compute units an invocation consumed, by phase.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComputeProfile {
+    /// Every phase that ended, in the order they began - so phases follow the
+    /// phase they're within.
+    pub phases: Vec<Phase>,
+    /// The units the whole invocation consumed, if it logged them.
+    pub consumed: Option<u64>,
+}
+
+/// One phase of an instruction, ie. `Record::load person` or
+/// `cpi::system::create_pda`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Phase {
+    pub name: String,
+    /// How many phases it's within - 0 for the outermost.
+    pub depth: usize,
+    /// The units consumed from its beginning to its end.
+    pub consumed: u64,
+    /// The units consumed by the phases directly within it.
+    pub nested: u64,
+}
+
+impl Phase {
+    /// The units consumed outside of the phases within it.
+    pub fn own(&self) -> u64 {
+        self.consumed.saturating_sub(self.nested)
+    }
+}
+
+impl ComputeProfile {
+    /// Pairs the invocation's `CU begin` and `CU end` logs with the units
+    /// remaining logged right after and right before them. CPIs it made are
+    /// profiled separately.
+    pub fn new(invocation: &Invocation) -> Self {
+        let remaining = &invocation.units_remaining;
+        let mut phases: Vec<Option<Phase>> = vec![];
+        // The phases that haven't ended yet, outermost first, with where they
+        // are in `phases` and the units remaining as they began.
+        let mut open: Vec<(usize, u64)> = vec![];
+        for (i, (logged, units)) in remaining.iter().enumerate() {
+            // The units remaining are logged twice in a row when a phase is
+            // empty, so a phase begins at the first of a run of checkpoints
+            // after the same log, and ends at the last.
+            let first = i == 0 || remaining[i - 1].0 != *logged;
+            let last = remaining
+                .get(i + 1)
+                .map_or(true, |(next, _)| next != logged);
+            let begins = logged
+                .checked_sub(1)
+                .and_then(|previous| invocation.logs[previous].strip_prefix(BEGIN))
+                .filter(|_| first);
+            let ends = invocation
+                .logs
+                .get(*logged)
+                .and_then(|next| next.strip_prefix(END))
+                .filter(|_| last);
+            if let Some(name) = begins {
+                open.push((phases.len(), *units));
+                phases.push(Some(Phase {
+                    name: name.to_string(),
+                    depth: open.len() - 1,
+                    consumed: 0,
+                    nested: 0,
+                }));
+            }
+            if let Some(name) = ends {
+                let matching = open.iter().rposition(|(index, _)| {
+                    phases[*index].as_ref().map_or(false, |p| p.name == name)
+                });
+                let matching = match matching {
+                    Some(matching) => matching,
+                    None => continue,
+                };
+                // Phases within it that never ended are dropped.
+                for (index, _) in open.drain(matching + 1..) {
+                    phases[index] = None;
+                }
+                let (index, began) = open.pop().expect("The phase is open");
+                let consumed = began.saturating_sub(*units);
+                if let Some(phase) = phases[index].as_mut() {
+                    phase.consumed = consumed;
+                }
+                if let Some((parent, _)) = open.last() {
+                    if let Some(parent) = phases[*parent].as_mut() {
+                        parent.nested += consumed;
+                    }
+                }
+            }
+        }
+        for (index, _) in open {
+            phases[index] = None;
+        }
+        Self {
+            phases: phases.into_iter().flatten().collect(),
+            consumed: invocation.compute_units.map(|units| units.consumed),
+        }
+    }
+
+    /// Each phase's own units summed by name, in the order they first began -
+    /// ie. to total a CPI helper called several times.
+    pub fn totals(&self) -> Vec<(&str, u64)> {
+        let mut totals: Vec<(&str, u64)> = vec![];
+        for phase in self.phases.iter() {
+            match totals.iter_mut().find(|(name, _)| *name == phase.name) {
+                Some((_, units)) => *units += phase.own(),
+                None => totals.push((&phase.name, phase.own())),
+            }
+        }
+        totals
+    }
+
+    /// The units consumed outside of any phase - ie. deserializing the
+    /// instruction and logging the outermost phases' checkpoints.
+    pub fn unprofiled(&self) -> Option<u64> {
+        let profiled: u64 = self
+            .phases
+            .iter()
+            .filter(|p| p.depth == 0)
+            .map(|p| p.consumed)
+            .sum();
+        Some(self.consumed?.saturating_sub(profiled))
+    }
+}
+
+impl fmt::Display for ComputeProfile {
+    /// A table of each phase's units and own units, phases indented within the
+    /// phase they're in.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .phases
+            .iter()
+            .map(|p| p.depth * 2 + p.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("unprofiled".len());
+        writeln!(f, "{:width$}  {:>8}  {:>8}", "phase", "units", "own")?;
+        for phase in self.phases.iter() {
+            let name = format!("{}{}", "  ".repeat(phase.depth), phase.name);
+            writeln!(
+                f,
+                "{:width$}  {:>8}  {:>8}",
+                name,
+                phase.consumed,
+                phase.own()
+            )?;
+        }
+        if let Some(unprofiled) = self.unprofiled() {
+            writeln!(
+                f,
+                "{:width$}  {:>8}  {:>8}",
+                "unprofiled", unprofiled, unprofiled
+            )?;
+        }
+        if let Some(consumed) = self.consumed {
+            writeln!(f, "{:width$}  {:>8}", "total", consumed)?;
+        }
+        Ok(())
+    }
+}
+
+impl Invocation {
+    /// The units this invocation consumed, by phase - see `ComputeProfile`.
+    pub fn compute_profile(&self) -> ComputeProfile {
+        ComputeProfile::new(self)
+    }
+}
+
+const BEGIN: &str = "CU begin: ";
+const END: &str = "CU end: ";
@@ -0,0 +1,1263 @@
+// Code generated by Nautilus from the `program-nautilus` IDL (v0.1.0). DO NOT EDIT.
+
+package programnautilus
+
+import (
+	"bytes"
+	"encoding/binary"
+	"fmt"
+	"io"
+	"math/big"
+	"sort"
+
+	"github.com/gagliardetto/solana-go"
+)
+
+// Uint128 is a Borsh `u128`, split into its low and high 64 bits.
+type Uint128 struct {
+	Lo uint64
+	Hi uint64
+}
+
+// BigInt converts the value to a big.Int.
+func (v Uint128) BigInt() *big.Int {
+	hi := new(big.Int).SetUint64(v.Hi)
+	return hi.Lsh(hi, 64).Add(hi, new(big.Int).SetUint64(v.Lo))
+}
+
+// Int128 is a Borsh `i128`, split into its low and high 64 bits.
+type Int128 struct {
+	Lo uint64
+	Hi int64
+}
+
+// BigInt converts the value to a big.Int.
+func (v Int128) BigInt() *big.Int {
+	hi := big.NewInt(v.Hi)
+	return hi.Lsh(hi, 64).Add(hi, new(big.Int).SetUint64(v.Lo))
+}
+
+// BorshEncoder serializes values with Borsh.
+type BorshEncoder struct {
+	buf bytes.Buffer
+}
+
+func NewBorshEncoder() *BorshEncoder {
+	return &BorshEncoder{}
+}
+
+// Bytes returns the data written so far.
+func (e *BorshEncoder) Bytes() []byte {
+	return e.buf.Bytes()
+}
+
+// WriteRaw writes data as is, without a length prefix.
+func (e *BorshEncoder) WriteRaw(data []byte) error {
+	_, err := e.buf.Write(data)
+	return err
+}
+
+func (e *BorshEncoder) writeFixed(v any) error {
+	return binary.Write(&e.buf, binary.LittleEndian, v)
+}
+
+func (e *BorshEncoder) WriteBool(v bool) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU8(v uint8) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU16(v uint16) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU32(v uint32) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU64(v uint64) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU128(v Uint128) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI8(v int8) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI16(v int16) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI32(v int32) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI64(v int64) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI128(v Int128) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteString(v string) error { return e.WriteBytes([]byte(v)) }
+func (e *BorshEncoder) WritePublicKey(v solana.PublicKey) error { return e.WriteRaw(v[:]) }
+
+func (e *BorshEncoder) WriteBytes(v []byte) error {
+	if err := e.WriteU32(uint32(len(v))); err != nil {
+		return err
+	}
+	return e.WriteRaw(v)
+}
+
+// BorshDecoder deserializes values with Borsh.
+type BorshDecoder struct {
+	data []byte
+}
+
+func NewBorshDecoder(data []byte) *BorshDecoder {
+	return &BorshDecoder{data: data}
+}
+
+// Remaining returns the data that hasn't been read yet.
+func (d *BorshDecoder) Remaining() []byte {
+	return d.data
+}
+
+// ReadRaw reads the next n bytes as is.
+func (d *BorshDecoder) ReadRaw(n int) ([]byte, error) {
+	if n < 0 || n > len(d.data) {
+		return nil, io.ErrUnexpectedEOF
+	}
+	data := d.data[:n]
+	d.data = d.data[n:]
+	return data, nil
+}
+
+func readFixed[T any](d *BorshDecoder) (T, error) {
+	var v T
+	data, err := d.ReadRaw(binary.Size(v))
+	if err != nil {
+		return v, err
+	}
+	err = binary.Read(bytes.NewReader(data), binary.LittleEndian, &v)
+	return v, err
+}
+
+func (d *BorshDecoder) ReadU8() (uint8, error) { return readFixed[uint8](d) }
+func (d *BorshDecoder) ReadU16() (uint16, error) { return readFixed[uint16](d) }
+func (d *BorshDecoder) ReadU32() (uint32, error) { return readFixed[uint32](d) }
+func (d *BorshDecoder) ReadU64() (uint64, error) { return readFixed[uint64](d) }
+func (d *BorshDecoder) ReadU128() (Uint128, error) { return readFixed[Uint128](d) }
+func (d *BorshDecoder) ReadI8() (int8, error) { return readFixed[int8](d) }
+func (d *BorshDecoder) ReadI16() (int16, error) { return readFixed[int16](d) }
+func (d *BorshDecoder) ReadI32() (int32, error) { return readFixed[int32](d) }
+func (d *BorshDecoder) ReadI64() (int64, error) { return readFixed[int64](d) }
+func (d *BorshDecoder) ReadI128() (Int128, error) { return readFixed[Int128](d) }
+
+func (d *BorshDecoder) ReadBool() (bool, error) {
+	v, err := d.ReadU8()
+	if err != nil {
+		return false, err
+	}
+	if v > 1 {
+		return false, fmt.Errorf("invalid bool: %d", v)
+	}
+	return v == 1, nil
+}
+
+func (d *BorshDecoder) ReadBytes() ([]byte, error) {
+	n, err := d.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	data, err := d.ReadRaw(int(n))
+	if err != nil {
+		return nil, err
+	}
+	return append([]byte{}, data...), nil
+}
+
+func (d *BorshDecoder) ReadString() (string, error) {
+	data, err := d.ReadBytes()
+	return string(data), err
+}
+
+func (d *BorshDecoder) ReadPublicKey() (solana.PublicKey, error) {
+	var v solana.PublicKey
+	data, err := d.ReadRaw(len(v))
+	if err != nil {
+		return v, err
+	}
+	copy(v[:], data)
+	return v, nil
+}
+
+// checkDiscriminator reads and checks an account's 8-byte discriminator.
+func (d *BorshDecoder) checkDiscriminator(discriminator [8]byte, name string) error {
+	data, err := d.ReadRaw(len(discriminator))
+	if err != nil {
+		return err
+	}
+	if !bytes.Equal(data, discriminator[:]) {
+		return fmt.Errorf("account data is not a `%s`", name)
+	}
+	return nil
+}
+
+func writeItems[T any](e *BorshEncoder, items []T, write func(*BorshEncoder, T) error) error {
+	for _, item := range items {
+		if err := write(e, item); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func readItems[T any](d *BorshDecoder, items []T, read func(*BorshDecoder) (T, error)) error {
+	for i := range items {
+		var err error
+		if items[i], err = read(d); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func vecEncoder[T any](write func(*BorshEncoder, T) error) func(*BorshEncoder, []T) error {
+	return func(e *BorshEncoder, v []T) error {
+		if err := e.WriteU32(uint32(len(v))); err != nil {
+			return err
+		}
+		return writeItems(e, v, write)
+	}
+}
+
+func vecDecoder[T any](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) ([]T, error) {
+	return func(d *BorshDecoder) ([]T, error) {
+		n, err := d.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		v := []T{}
+		for i := uint32(0); i < n; i++ {
+			item, err := read(d)
+			if err != nil {
+				return nil, err
+			}
+			v = append(v, item)
+		}
+		return v, nil
+	}
+}
+
+func optionEncoder[T any](write func(*BorshEncoder, T) error) func(*BorshEncoder, *T) error {
+	return func(e *BorshEncoder, v *T) error {
+		if v == nil {
+			return e.WriteU8(0)
+		}
+		if err := e.WriteU8(1); err != nil {
+			return err
+		}
+		return write(e, *v)
+	}
+}
+
+func optionDecoder[T any](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) (*T, error) {
+	return func(d *BorshDecoder) (*T, error) {
+		tag, err := d.ReadU8()
+		if err != nil {
+			return nil, err
+		}
+		switch tag {
+		case 0:
+			return nil, nil
+		case 1:
+			v, err := read(d)
+			if err != nil {
+				return nil, err
+			}
+			return &v, nil
+		default:
+			return nil, fmt.Errorf("invalid option tag: %d", tag)
+		}
+	}
+}
+
+// mapEncoder writes a map's entries sorted by key, like Borsh does.
+func mapEncoder[K comparable, V any](
+	writeKey func(*BorshEncoder, K) error,
+	writeValue func(*BorshEncoder, V) error,
+	less func(K, K) bool,
+) func(*BorshEncoder, map[K]V) error {
+	return func(e *BorshEncoder, v map[K]V) error {
+		keys := make([]K, 0, len(v))
+		for key := range v {
+			keys = append(keys, key)
+		}
+		sort.Slice(keys, func(i, j int) bool { return less(keys[i], keys[j]) })
+		if err := e.WriteU32(uint32(len(keys))); err != nil {
+			return err
+		}
+		for _, key := range keys {
+			if err := writeKey(e, key); err != nil {
+				return err
+			}
+			if err := writeValue(e, v[key]); err != nil {
+				return err
+			}
+		}
+		return nil
+	}
+}
+
+func mapDecoder[K comparable, V any](
+	readKey func(*BorshDecoder) (K, error),
+	readValue func(*BorshDecoder) (V, error),
+) func(*BorshDecoder) (map[K]V, error) {
+	return func(d *BorshDecoder) (map[K]V, error) {
+		n, err := d.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		v := map[K]V{}
+		for i := uint32(0); i < n; i++ {
+			key, err := readKey(d)
+			if err != nil {
+				return nil, err
+			}
+			if v[key], err = readValue(d); err != nil {
+				return nil, err
+			}
+		}
+		return v, nil
+	}
+}
+
+func setEncoder[T comparable](write func(*BorshEncoder, T) error, less func(T, T) bool) func(*BorshEncoder, map[T]struct{}) error {
+	return mapEncoder(write, func(*BorshEncoder, struct{}) error { return nil }, less)
+}
+
+func setDecoder[T comparable](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) (map[T]struct{}, error) {
+	return mapDecoder(read, func(*BorshDecoder) (struct{}, error) { return struct{}{}, nil })
+}
+
+type ordered interface {
+	~int8 | ~int16 | ~int32 | ~int64 | ~uint8 | ~uint16 | ~uint32 | ~uint64 | ~string
+}
+
+func lessOrdered[T ordered](a, b T) bool { return a < b }
+func lessBool(a, b bool) bool { return !a && b }
+func lessUint128(a, b Uint128) bool { return a.Hi < b.Hi || (a.Hi == b.Hi && a.Lo < b.Lo) }
+func lessInt128(a, b Int128) bool { return a.Hi < b.Hi || (a.Hi == b.Hi && a.Lo < b.Lo) }
+func lessPublicKey(a, b solana.PublicKey) bool { return bytes.Compare(a[:], b[:]) < 0 }
+
+// lessEncoded orders keys without a natural order by their encoded bytes.
+func lessEncoded[T any](write func(*BorshEncoder, T) error) func(T, T) bool {
+	return func(a, b T) bool {
+		encodedA, encodedB := NewBorshEncoder(), NewBorshEncoder()
+		_ = write(encodedA, a)
+		_ = write(encodedB, b)
+		return bytes.Compare(encodedA.Bytes(), encodedB.Bytes()) < 0
+	}
+}
+
+// borshSeed serializes a value to be used as a seed.
+func borshSeed[T any](v T, write func(*BorshEncoder, T) error) ([]byte, error) {
+	e := NewBorshEncoder()
+	err := write(e, v)
+	return e.Bytes(), err
+}
+
+var PersonDiscriminator = [8]byte{38, 10, 62, 51, 134, 223, 96, 156}
+
+type Person struct {
+	Name string
+	Authority solana.PublicKey
+}
+
+func EncodePerson(e *BorshEncoder, v Person) error {
+	if err := e.WriteRaw(PersonDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Name); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.Authority); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodePerson(d *BorshDecoder) (v Person, err error) {
+	if err = d.checkDiscriminator(PersonDiscriminator, "Person"); err != nil {
+		return
+	}
+	if v.Name, err = d.ReadString(); err != nil {
+		return
+	}
+	if v.Authority, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	return
+}
+
+var HomeDiscriminator = [8]byte{207, 164, 88, 52, 87, 254, 75, 93}
+
+type Home struct {
+	HouseNumber uint8
+	Street string
+}
+
+func EncodeHome(e *BorshEncoder, v Home) error {
+	if err := e.WriteRaw(HomeDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteU8(v.HouseNumber); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Street); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeHome(d *BorshDecoder) (v Home, err error) {
+	if err = d.checkDiscriminator(HomeDiscriminator, "Home"); err != nil {
+		return
+	}
+	if v.HouseNumber, err = d.ReadU8(); err != nil {
+		return
+	}
+	if v.Street, err = d.ReadString(); err != nil {
+		return
+	}
+	return
+}
+
+var CarDiscriminator = [8]byte{84, 120, 193, 54, 102, 120, 204, 93}
+
+type Car struct {
+	Make string
+	Model string
+	PurchaseAuthority solana.PublicKey
+	OperatingAuthority solana.PublicKey
+}
+
+func EncodeCar(e *BorshEncoder, v Car) error {
+	if err := e.WriteRaw(CarDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Make); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Model); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.PurchaseAuthority); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.OperatingAuthority); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeCar(d *BorshDecoder) (v Car, err error) {
+	if err = d.checkDiscriminator(CarDiscriminator, "Car"); err != nil {
+		return
+	}
+	if v.Make, err = d.ReadString(); err != nil {
+		return
+	}
+	if v.Model, err = d.ReadString(); err != nil {
+		return
+	}
+	if v.PurchaseAuthority, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	if v.OperatingAuthority, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	return
+}
+
+var CounterDiscriminator = [8]byte{111, 107, 95, 228, 242, 115, 107, 223}
+
+type Counter struct {
+	Field0 solana.PublicKey
+	Field1 uint64
+}
+
+func EncodeCounter(e *BorshEncoder, v Counter) error {
+	if err := e.WriteRaw(CounterDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.Field0); err != nil {
+		return err
+	}
+	if err := e.WriteU64(v.Field1); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeCounter(d *BorshDecoder) (v Counter, err error) {
+	if err = d.checkDiscriminator(CounterDiscriminator, "Counter"); err != nil {
+		return
+	}
+	if v.Field0, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	if v.Field1, err = d.ReadU64(); err != nil {
+		return
+	}
+	return
+}
+
+var OrderBookDiscriminator = [8]byte{202, 74, 178, 111, 127, 113, 177, 217}
+
+type OrderBook struct {
+	Market solana.PublicKey
+	Len uint64
+	Prices [64]uint64
+	Sizes [64]uint64
+}
+
+func EncodeOrderBook(e *BorshEncoder, v OrderBook) error {
+	if err := e.WriteRaw(OrderBookDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.Market); err != nil {
+		return err
+	}
+	if err := e.WriteU64(v.Len); err != nil {
+		return err
+	}
+	if err := func(e *BorshEncoder, v [64]uint64) error { return writeItems(e, v[:], (*BorshEncoder).WriteU64) }(e, v.Prices); err != nil {
+		return err
+	}
+	if err := func(e *BorshEncoder, v [64]uint64) error { return writeItems(e, v[:], (*BorshEncoder).WriteU64) }(e, v.Sizes); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeOrderBook(d *BorshDecoder) (v OrderBook, err error) {
+	if err = d.checkDiscriminator(OrderBookDiscriminator, "OrderBook"); err != nil {
+		return
+	}
+	if v.Market, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	if v.Len, err = d.ReadU64(); err != nil {
+		return
+	}
+	if v.Prices, err = func(d *BorshDecoder) (v [64]uint64, err error) { err = readItems(d, v[:], (*BorshDecoder).ReadU64); return }(d); err != nil {
+		return
+	}
+	if v.Sizes, err = func(d *BorshDecoder) (v [64]uint64, err error) { err = readItems(d, v[:], (*BorshDecoder).ReadU64); return }(d); err != nil {
+		return
+	}
+	return
+}
+
+var VaultU64Discriminator = [8]byte{163, 245, 72, 45, 63, 45, 215, 109}
+
+type VaultU64 struct {
+	Value uint64
+}
+
+func EncodeVaultU64(e *BorshEncoder, v VaultU64) error {
+	if err := e.WriteRaw(VaultU64Discriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteU64(v.Value); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeVaultU64(d *BorshDecoder) (v VaultU64, err error) {
+	if err = d.checkDiscriminator(VaultU64Discriminator, "VaultU64"); err != nil {
+		return
+	}
+	if v.Value, err = d.ReadU64(); err != nil {
+		return
+	}
+	return
+}
+
+var VaultBoolDiscriminator = [8]byte{123, 33, 179, 119, 57, 180, 207, 136}
+
+type VaultBool struct {
+	Value bool
+}
+
+func EncodeVaultBool(e *BorshEncoder, v VaultBool) error {
+	if err := e.WriteRaw(VaultBoolDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteBool(v.Value); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeVaultBool(d *BorshDecoder) (v VaultBool, err error) {
+	if err = d.checkDiscriminator(VaultBoolDiscriminator, "VaultBool"); err != nil {
+		return
+	}
+	if v.Value, err = d.ReadBool(); err != nil {
+		return
+	}
+	return
+}
+
+var LightDiscriminator = [8]byte{2, 254, 244, 177, 101, 93, 194, 220}
+
+// Light is one of LightOff, LightOn, LightBlinking.
+type Light interface {
+	isLight()
+}
+
+type LightOff struct{}
+
+func (LightOff) isLight() {}
+
+type LightOn struct {
+	Brightness uint8
+}
+
+func (LightOn) isLight() {}
+
+type LightBlinking struct {
+	Field0 uint16
+	Field1 uint16
+}
+
+func (LightBlinking) isLight() {}
+
+func EncodeLight(e *BorshEncoder, v Light) error {
+	if err := e.WriteRaw(LightDiscriminator[:]); err != nil {
+		return err
+	}
+	switch v := v.(type) {
+	case LightOff:
+		return e.WriteU8(0)
+	case LightOn:
+		if err := e.WriteU8(1); err != nil {
+			return err
+		}
+		if err := e.WriteU8(v.Brightness); err != nil {
+			return err
+		}
+		return nil
+	case LightBlinking:
+		if err := e.WriteU8(2); err != nil {
+			return err
+		}
+		if err := e.WriteU16(v.Field0); err != nil {
+			return err
+		}
+		if err := e.WriteU16(v.Field1); err != nil {
+			return err
+		}
+		return nil
+	default:
+		return fmt.Errorf("invalid variant for `Light`: %T", v)
+	}
+}
+
+func DecodeLight(d *BorshDecoder) (Light, error) {
+	if err := d.checkDiscriminator(LightDiscriminator, "Light"); err != nil {
+		return nil, err
+	}
+	variant, err := d.ReadU8()
+	if err != nil {
+		return nil, err
+	}
+	switch variant {
+	case 0:
+		return LightOff{}, nil
+	case 1:
+		var v LightOn
+		if v.Brightness, err = d.ReadU8(); err != nil {
+			return nil, err
+		}
+		return v, nil
+	case 2:
+		var v LightBlinking
+		if v.Field0, err = d.ReadU16(); err != nil {
+			return nil, err
+		}
+		if v.Field1, err = d.ReadU16(); err != nil {
+			return nil, err
+		}
+		return v, nil
+	default:
+		return nil, fmt.Errorf("invalid variant index for `Light`: %d", variant)
+	}
+}
+
+// DecodePersonAccount decodes a `Person` account's data, checking its discriminator.
+func DecodePersonAccount(data []byte) (Person, error) {
+	return DecodePerson(NewBorshDecoder(data))
+}
+
+// FindPersonAddress derives the address of the `Person` account.
+func FindPersonAddress(programID solana.PublicKey, authority solana.PublicKey) (solana.PublicKey, uint8, error) {
+	authoritySeed, err := borshSeed(authority, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("person"), authoritySeed}, programID)
+}
+
+// DecodeHomeAccount decodes a `Home` account's data, checking its discriminator.
+func DecodeHomeAccount(data []byte) (Home, error) {
+	return DecodeHome(NewBorshDecoder(data))
+}
+
+// FindHomeAddress derives the address of the `Home` account.
+func FindHomeAddress(programID solana.PublicKey, somePubkey solana.PublicKey) (solana.PublicKey, uint8, error) {
+	somePubkeySeed, err := borshSeed(somePubkey, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("home"), somePubkeySeed}, programID)
+}
+
+// DecodeCarAccount decodes a `Car` account's data, checking its discriminator.
+func DecodeCarAccount(data []byte) (Car, error) {
+	return DecodeCar(NewBorshDecoder(data))
+}
+
+// FindCarAddress derives the address of the `Car` account.
+func FindCarAddress(programID solana.PublicKey, purchaseAuthority solana.PublicKey, operatingAuthority solana.PublicKey) (solana.PublicKey, uint8, error) {
+	purchaseAuthoritySeed, err := borshSeed(purchaseAuthority, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	operatingAuthoritySeed, err := borshSeed(operatingAuthority, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("car"), purchaseAuthoritySeed, operatingAuthoritySeed}, programID)
+}
+
+// DecodeCounterAccount decodes a `Counter` account's data, checking its discriminator.
+func DecodeCounterAccount(data []byte) (Counter, error) {
+	return DecodeCounter(NewBorshDecoder(data))
+}
+
+// FindCounterAddress derives the address of the `Counter` account.
+func FindCounterAddress(programID solana.PublicKey, owner solana.PublicKey) (solana.PublicKey, uint8, error) {
+	ownerSeed, err := borshSeed(owner, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("counter"), ownerSeed}, programID)
+}
+
+// DecodeOrderBookAccount decodes a `OrderBook` account's data, checking its discriminator.
+func DecodeOrderBookAccount(data []byte) (OrderBook, error) {
+	return DecodeOrderBook(NewBorshDecoder(data))
+}
+
+// FindOrderBookAddress derives the address of the `OrderBook` account.
+func FindOrderBookAddress(programID solana.PublicKey, market solana.PublicKey) (solana.PublicKey, uint8, error) {
+	marketSeed, err := borshSeed(market, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("order_book"), marketSeed}, programID)
+}
+
+// DecodeVaultU64Account decodes a `VaultU64` account's data, checking its discriminator.
+func DecodeVaultU64Account(data []byte) (VaultU64, error) {
+	return DecodeVaultU64(NewBorshDecoder(data))
+}
+
+// FindVaultU64Address derives the address of the `VaultU64` account.
+func FindVaultU64Address(programID solana.PublicKey, owner solana.PublicKey) (solana.PublicKey, uint8, error) {
+	ownerSeed, err := borshSeed(owner, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("vault"), ownerSeed}, programID)
+}
+
+// DecodeVaultBoolAccount decodes a `VaultBool` account's data, checking its discriminator.
+func DecodeVaultBoolAccount(data []byte) (VaultBool, error) {
+	return DecodeVaultBool(NewBorshDecoder(data))
+}
+
+// FindVaultBoolAddress derives the address of the `VaultBool` account.
+func FindVaultBoolAddress(programID solana.PublicKey, owner solana.PublicKey) (solana.PublicKey, uint8, error) {
+	ownerSeed, err := borshSeed(owner, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("vault"), ownerSeed}, programID)
+}
+
+// DecodeLightAccount decodes a `Light` account's data, checking its discriminator.
+func DecodeLightAccount(data []byte) (Light, error) {
+	return DecodeLight(NewBorshDecoder(data))
+}
+
+// FindLightAddress derives the address of the `Light` account.
+func FindLightAddress(programID solana.PublicKey, owner solana.PublicKey) (solana.PublicKey, uint8, error) {
+	ownerSeed, err := borshSeed(owner, (*BorshEncoder).WritePublicKey)
+	if err != nil {
+		return solana.PublicKey{}, 0, err
+	}
+	return solana.FindProgramAddress([][]byte{[]byte("light"), ownerSeed}, programID)
+}
+
+// DecodeAccount decodes an account's data based on its discriminator, returning
+// nil if it doesn't belong to any of this program's accounts.
+func DecodeAccount(data []byte) (any, error) {
+	var discriminator [8]byte
+	if len(data) < len(discriminator) {
+		return nil, nil
+	}
+	copy(discriminator[:], data)
+	switch discriminator {
+	case PersonDiscriminator:
+		return DecodePersonAccount(data)
+	case HomeDiscriminator:
+		return DecodeHomeAccount(data)
+	case CarDiscriminator:
+		return DecodeCarAccount(data)
+	case CounterDiscriminator:
+		return DecodeCounterAccount(data)
+	case OrderBookDiscriminator:
+		return DecodeOrderBookAccount(data)
+	case VaultU64Discriminator:
+		return DecodeVaultU64Account(data)
+	case VaultBoolDiscriminator:
+		return DecodeVaultBoolAccount(data)
+	case LightDiscriminator:
+		return DecodeLightAccount(data)
+	default:
+		return nil, nil
+	}
+}
+
+// CreatePersonAccounts are the accounts of a `createPerson` instruction that need to be provided.
+type CreatePersonAccounts struct {
+	NewPerson solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreatePersonArgs are the arguments of a `createPerson` instruction.
+type CreatePersonArgs struct {
+	Name string
+	Authority solana.PublicKey
+}
+
+// NewCreatePersonInstruction creates a `createPerson` instruction.
+func NewCreatePersonInstruction(programID solana.PublicKey, accounts CreatePersonAccounts, args CreatePersonArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(0); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Name); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Authority); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewPerson, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadPersonAccounts are the accounts of a `readPerson` instruction that need to be provided.
+type ReadPersonAccounts struct {
+	Person solana.PublicKey
+}
+
+// NewReadPersonInstruction creates a `readPerson` instruction.
+func NewReadPersonInstruction(programID solana.PublicKey, accounts ReadPersonAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(1); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Person, false, false),
+	}, e.Bytes()), nil
+}
+
+// CheckPersonAccounts are the accounts of a `checkPerson` instruction that need to be provided.
+type CheckPersonAccounts struct {
+	Person solana.PublicKey
+}
+
+// NewCheckPersonInstruction creates a `checkPerson` instruction.
+func NewCheckPersonInstruction(programID solana.PublicKey, accounts CheckPersonAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(2); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Person, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadPersonAuthorityAccounts are the accounts of a `readPersonAuthority` instruction that need to be provided.
+type ReadPersonAuthorityAccounts struct {
+	Person solana.PublicKey
+}
+
+// NewReadPersonAuthorityInstruction creates a `readPersonAuthority` instruction.
+func NewReadPersonAuthorityInstruction(programID solana.PublicKey, accounts ReadPersonAuthorityAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(3); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Person, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateHomeAccounts are the accounts of a `createHome` instruction that need to be provided.
+type CreateHomeAccounts struct {
+	NewHome solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateHomeArgs are the arguments of a `createHome` instruction.
+type CreateHomeArgs struct {
+	HouseNumber uint8
+	Street string
+	SomePubkey solana.PublicKey
+}
+
+// NewCreateHomeInstruction creates a `createHome` instruction.
+func NewCreateHomeInstruction(programID solana.PublicKey, accounts CreateHomeAccounts, args CreateHomeArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(4); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU8(args.HouseNumber); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Street); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.SomePubkey); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewHome, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadHomeAccounts are the accounts of a `readHome` instruction that need to be provided.
+type ReadHomeAccounts struct {
+	Home solana.PublicKey
+}
+
+// NewReadHomeInstruction creates a `readHome` instruction.
+func NewReadHomeInstruction(programID solana.PublicKey, accounts ReadHomeAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(5); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Home, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateCarAccounts are the accounts of a `createCar` instruction that need to be provided.
+type CreateCarAccounts struct {
+	NewCar solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateCarArgs are the arguments of a `createCar` instruction.
+type CreateCarArgs struct {
+	Make string
+	Model string
+	PurchaseAuthority solana.PublicKey
+	OperatingAuthority solana.PublicKey
+}
+
+// NewCreateCarInstruction creates a `createCar` instruction.
+func NewCreateCarInstruction(programID solana.PublicKey, accounts CreateCarAccounts, args CreateCarArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(6); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Make); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Model); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.PurchaseAuthority); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.OperatingAuthority); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewCar, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadCarAccounts are the accounts of a `readCar` instruction that need to be provided.
+type ReadCarAccounts struct {
+	Car solana.PublicKey
+}
+
+// NewReadCarInstruction creates a `readCar` instruction.
+func NewReadCarInstruction(programID solana.PublicKey, accounts ReadCarAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(7); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Car, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateCounterAccounts are the accounts of a `createCounter` instruction that need to be provided.
+type CreateCounterAccounts struct {
+	NewCounter solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateCounterArgs are the arguments of a `createCounter` instruction.
+type CreateCounterArgs struct {
+	Owner solana.PublicKey
+	Count uint64
+}
+
+// NewCreateCounterInstruction creates a `createCounter` instruction.
+func NewCreateCounterInstruction(programID solana.PublicKey, accounts CreateCounterAccounts, args CreateCounterArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(8); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Owner); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Count); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewCounter, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadCounterAccounts are the accounts of a `readCounter` instruction that need to be provided.
+type ReadCounterAccounts struct {
+	Counter solana.PublicKey
+}
+
+// NewReadCounterInstruction creates a `readCounter` instruction.
+func NewReadCounterInstruction(programID solana.PublicKey, accounts ReadCounterAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(9); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Counter, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateLightAccounts are the accounts of a `createLight` instruction that need to be provided.
+type CreateLightAccounts struct {
+	NewLight solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateLightArgs are the arguments of a `createLight` instruction.
+type CreateLightArgs struct {
+	Owner solana.PublicKey
+	Brightness uint8
+	OnMs uint16
+	OffMs uint16
+}
+
+// NewCreateLightInstruction creates a `createLight` instruction.
+func NewCreateLightInstruction(programID solana.PublicKey, accounts CreateLightAccounts, args CreateLightArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(10); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Owner); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU8(args.Brightness); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU16(args.OnMs); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU16(args.OffMs); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewLight, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadLightAccounts are the accounts of a `readLight` instruction that need to be provided.
+type ReadLightAccounts struct {
+	Light solana.PublicKey
+}
+
+// NewReadLightInstruction creates a `readLight` instruction.
+func NewReadLightInstruction(programID solana.PublicKey, accounts ReadLightAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(11); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Light, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateOrderBookAccounts are the accounts of a `createOrderBook` instruction that need to be provided.
+type CreateOrderBookAccounts struct {
+	NewOrderBook solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateOrderBookArgs are the arguments of a `createOrderBook` instruction.
+type CreateOrderBookArgs struct {
+	Market solana.PublicKey
+}
+
+// NewCreateOrderBookInstruction creates a `createOrderBook` instruction.
+func NewCreateOrderBookInstruction(programID solana.PublicKey, accounts CreateOrderBookAccounts, args CreateOrderBookArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(12); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Market); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewOrderBook, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// PlaceOrderAccounts are the accounts of a `placeOrder` instruction that need to be provided.
+type PlaceOrderAccounts struct {
+	OrderBook solana.PublicKey
+}
+
+// PlaceOrderArgs are the arguments of a `placeOrder` instruction.
+type PlaceOrderArgs struct {
+	Price uint64
+	Size uint64
+}
+
+// NewPlaceOrderInstruction creates a `placeOrder` instruction.
+func NewPlaceOrderInstruction(programID solana.PublicKey, accounts PlaceOrderAccounts, args PlaceOrderArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(13); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Price); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Size); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.OrderBook, true, false),
+	}, e.Bytes()), nil
+}
+
+// ReadOrderBookAccounts are the accounts of a `readOrderBook` instruction that need to be provided.
+type ReadOrderBookAccounts struct {
+	OrderBook solana.PublicKey
+}
+
+// NewReadOrderBookInstruction creates a `readOrderBook` instruction.
+func NewReadOrderBookInstruction(programID solana.PublicKey, accounts ReadOrderBookAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(14); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.OrderBook, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateBalanceVaultAccounts are the accounts of a `createBalanceVault` instruction that need to be provided.
+type CreateBalanceVaultAccounts struct {
+	NewVault solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateBalanceVaultArgs are the arguments of a `createBalanceVault` instruction.
+type CreateBalanceVaultArgs struct {
+	Owner solana.PublicKey
+	Balance uint64
+}
+
+// NewCreateBalanceVaultInstruction creates a `createBalanceVault` instruction.
+func NewCreateBalanceVaultInstruction(programID solana.PublicKey, accounts CreateBalanceVaultAccounts, args CreateBalanceVaultArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(15); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Owner); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Balance); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewVault, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadBalanceVaultAccounts are the accounts of a `readBalanceVault` instruction that need to be provided.
+type ReadBalanceVaultAccounts struct {
+	Vault solana.PublicKey
+}
+
+// NewReadBalanceVaultInstruction creates a `readBalanceVault` instruction.
+func NewReadBalanceVaultInstruction(programID solana.PublicKey, accounts ReadBalanceVaultAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(16); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.Vault, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateFlagVaultAccounts are the accounts of a `createFlagVault` instruction that need to be provided.
+type CreateFlagVaultAccounts struct {
+	NewVault solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateFlagVaultArgs are the arguments of a `createFlagVault` instruction.
+type CreateFlagVaultArgs struct {
+	Owner solana.PublicKey
+	Flag bool
+}
+
+// NewCreateFlagVaultInstruction creates a `createFlagVault` instruction.
+func NewCreateFlagVaultInstruction(programID solana.PublicKey, accounts CreateFlagVaultAccounts, args CreateFlagVaultArgs) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(17); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Owner); err != nil {
+		return nil, err
+	}
+	if err := e.WriteBool(args.Flag); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NewVault, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
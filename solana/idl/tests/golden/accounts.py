@@ -0,0 +1,662 @@
+# Generated by Nautilus from the `program-nautilus` IDL (v0.1.0). Do not edit by hand.
+from __future__ import annotations
+
+import typing
+from dataclasses import dataclass, fields
+
+import borsh_construct as borsh
+from construct import Adapter, Array, Construct
+from construct import Bytes as FixedBytes
+from solders.instruction import AccountMeta, Instruction
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.sysvar import CLOCK, INSTRUCTIONS, RENT
+
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+ASSOCIATED_TOKEN_PROGRAM_ID = Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+TOKEN_METADATA_PROGRAM_ID = Pubkey.from_string("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+
+
+class Rpc(typing.Protocol):
+    """Any RPC client that can fetch accounts, such as `solana.rpc.async_api.AsyncClient`."""
+
+    async def get_account_info(self, pubkey: Pubkey) -> typing.Any:
+        ...
+
+
+async def _fetch_account_data(rpc: Rpc, address: Pubkey) -> typing.Optional[bytes]:
+    response = await rpc.get_account_info(address)
+    return None if response.value is None else bytes(response.value.data)
+
+
+class _PublicKey(Adapter):
+    def _decode(self, obj, context, path):
+        return Pubkey.from_bytes(obj)
+
+    def _encode(self, obj, context, path):
+        return bytes(obj)
+
+
+class _Tuple(Adapter):
+    def _decode(self, obj, context, path):
+        return tuple(obj)
+
+    def _encode(self, obj, context, path):
+        return list(obj)
+
+
+class _Dataclass(Adapter):
+    """Converts between a `CStruct` container and a dataclass."""
+
+    def __init__(self, cls, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+
+    def _decode(self, obj, context, path):
+        return self.cls(**{f.name: obj[f.name] for f in fields(self.cls)})
+
+    def _encode(self, obj, context, path):
+        return {f.name: getattr(obj, f.name) for f in fields(self.cls)}
+
+
+class _Enum(Construct):
+    """A `u8` variant index followed by the variant's fields."""
+
+    def __init__(self, variants: typing.List[_Dataclass]):
+        super().__init__()
+        self.variants = variants
+
+    def _parse(self, stream, context, path):
+        index = borsh.U8._parsereport(stream, context, path)
+        return self.variants[index]._parsereport(stream, context, path)
+
+    def _build(self, obj, stream, context, path):
+        index = next(i for i, v in enumerate(self.variants) if isinstance(obj, v.cls))
+        borsh.U8._build(index, stream, context, path)
+        return self.variants[index]._build(obj, stream, context, path)
+
+
+class _Discriminated(Construct):
+    """Prefixes a layout with an account's 8-byte discriminator, the way the Nautilus runtime serializes accounts."""
+
+    def __init__(self, discriminator: bytes, subcon: Construct):
+        super().__init__()
+        self.discriminator = discriminator
+        self.subcon = subcon
+
+    def _parse(self, stream, context, path):
+        if FixedBytes(8)._parsereport(stream, context, path) != self.discriminator:
+            raise ValueError("Invalid account discriminator")
+        return self.subcon._parsereport(stream, context, path)
+
+    def _build(self, obj, stream, context, path):
+        FixedBytes(8)._build(self.discriminator, stream, context, path)
+        return self.subcon._build(obj, stream, context, path)
+
+
+PUBLIC_KEY = _PublicKey(FixedBytes(32))
+
+
+PERSON_DISCRIMINATOR = bytes([38, 10, 62, 51, 134, 223, 96, 156])
+
+
+@dataclass
+class Person:
+    name: str
+    authority: Pubkey
+
+
+def person_layout() -> Construct:
+    return _Discriminated(PERSON_DISCRIMINATOR, _Dataclass(Person, borsh.CStruct("name" / borsh.String, "authority" / PUBLIC_KEY)))
+
+
+HOME_DISCRIMINATOR = bytes([207, 164, 88, 52, 87, 254, 75, 93])
+
+
+@dataclass
+class Home:
+    house_number: int
+    street: str
+
+
+def home_layout() -> Construct:
+    return _Discriminated(HOME_DISCRIMINATOR, _Dataclass(Home, borsh.CStruct("house_number" / borsh.U8, "street" / borsh.String)))
+
+
+CAR_DISCRIMINATOR = bytes([84, 120, 193, 54, 102, 120, 204, 93])
+
+
+@dataclass
+class Car:
+    make: str
+    model: str
+    purchase_authority: Pubkey
+    operating_authority: Pubkey
+
+
+def car_layout() -> Construct:
+    return _Discriminated(CAR_DISCRIMINATOR, _Dataclass(Car, borsh.CStruct("make" / borsh.String, "model" / borsh.String, "purchase_authority" / PUBLIC_KEY, "operating_authority" / PUBLIC_KEY)))
+
+
+COUNTER_DISCRIMINATOR = bytes([111, 107, 95, 228, 242, 115, 107, 223])
+
+
+@dataclass
+class Counter:
+    field_0: Pubkey
+    field_1: int
+
+
+def counter_layout() -> Construct:
+    return _Discriminated(COUNTER_DISCRIMINATOR, _Dataclass(Counter, borsh.CStruct("field_0" / PUBLIC_KEY, "field_1" / borsh.U64)))
+
+
+ORDER_BOOK_DISCRIMINATOR = bytes([202, 74, 178, 111, 127, 113, 177, 217])
+
+
+@dataclass
+class OrderBook:
+    market: Pubkey
+    len: int
+    prices: typing.List[int]
+    sizes: typing.List[int]
+
+
+def order_book_layout() -> Construct:
+    return _Discriminated(ORDER_BOOK_DISCRIMINATOR, _Dataclass(OrderBook, borsh.CStruct("market" / PUBLIC_KEY, "len" / borsh.U64, "prices" / Array(64, borsh.U64), "sizes" / Array(64, borsh.U64))))
+
+
+VAULT_U64_DISCRIMINATOR = bytes([163, 245, 72, 45, 63, 45, 215, 109])
+
+
+@dataclass
+class VaultU64:
+    value: int
+
+
+def vault_u64_layout() -> Construct:
+    return _Discriminated(VAULT_U64_DISCRIMINATOR, _Dataclass(VaultU64, borsh.CStruct("value" / borsh.U64)))
+
+
+VAULT_BOOL_DISCRIMINATOR = bytes([123, 33, 179, 119, 57, 180, 207, 136])
+
+
+@dataclass
+class VaultBool:
+    value: bool
+
+
+def vault_bool_layout() -> Construct:
+    return _Discriminated(VAULT_BOOL_DISCRIMINATOR, _Dataclass(VaultBool, borsh.CStruct("value" / borsh.Bool)))
+
+
+LIGHT_DISCRIMINATOR = bytes([2, 254, 244, 177, 101, 93, 194, 220])
+
+
+@dataclass
+class LightOff:
+    pass
+
+
+@dataclass
+class LightOn:
+    brightness: int
+
+
+@dataclass
+class LightBlinking:
+    field_0: int
+    field_1: int
+
+
+Light = typing.Union[LightOff, LightOn, LightBlinking]
+
+
+def light_layout() -> Construct:
+    return _Discriminated(LIGHT_DISCRIMINATOR, _Enum([
+        _Dataclass(LightOff, borsh.CStruct()),
+        _Dataclass(LightOn, borsh.CStruct("brightness" / borsh.U8)),
+        _Dataclass(LightBlinking, borsh.CStruct("field_0" / borsh.U16, "field_1" / borsh.U16)),
+    ]))
+
+
+def decode_person(data: bytes) -> Person:
+    if data[:8] != PERSON_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Person`")
+    return person_layout().parse(data)
+
+
+async def fetch_person(rpc: Rpc, address: Pubkey) -> typing.Optional[Person]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_person(data)
+
+
+def find_person_address(program_id: Pubkey, *, authority: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["person".encode(), PUBLIC_KEY.build(authority)], program_id)[0]
+
+
+def decode_home(data: bytes) -> Home:
+    if data[:8] != HOME_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Home`")
+    return home_layout().parse(data)
+
+
+async def fetch_home(rpc: Rpc, address: Pubkey) -> typing.Optional[Home]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_home(data)
+
+
+def find_home_address(program_id: Pubkey, *, some_pubkey: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["home".encode(), PUBLIC_KEY.build(some_pubkey)], program_id)[0]
+
+
+def decode_car(data: bytes) -> Car:
+    if data[:8] != CAR_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Car`")
+    return car_layout().parse(data)
+
+
+async def fetch_car(rpc: Rpc, address: Pubkey) -> typing.Optional[Car]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_car(data)
+
+
+def find_car_address(program_id: Pubkey, *, purchase_authority: Pubkey, operating_authority: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["car".encode(), PUBLIC_KEY.build(purchase_authority), PUBLIC_KEY.build(operating_authority)], program_id)[0]
+
+
+def decode_counter(data: bytes) -> Counter:
+    if data[:8] != COUNTER_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Counter`")
+    return counter_layout().parse(data)
+
+
+async def fetch_counter(rpc: Rpc, address: Pubkey) -> typing.Optional[Counter]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_counter(data)
+
+
+def find_counter_address(program_id: Pubkey, *, owner: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["counter".encode(), PUBLIC_KEY.build(owner)], program_id)[0]
+
+
+def decode_order_book(data: bytes) -> OrderBook:
+    if data[:8] != ORDER_BOOK_DISCRIMINATOR:
+        raise ValueError("Account data is not a `OrderBook`")
+    return order_book_layout().parse(data)
+
+
+async def fetch_order_book(rpc: Rpc, address: Pubkey) -> typing.Optional[OrderBook]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_order_book(data)
+
+
+def find_order_book_address(program_id: Pubkey, *, market: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["order_book".encode(), PUBLIC_KEY.build(market)], program_id)[0]
+
+
+def decode_vault_u64(data: bytes) -> VaultU64:
+    if data[:8] != VAULT_U64_DISCRIMINATOR:
+        raise ValueError("Account data is not a `VaultU64`")
+    return vault_u64_layout().parse(data)
+
+
+async def fetch_vault_u64(rpc: Rpc, address: Pubkey) -> typing.Optional[VaultU64]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_vault_u64(data)
+
+
+def find_vault_u64_address(program_id: Pubkey, *, owner: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["vault".encode(), PUBLIC_KEY.build(owner)], program_id)[0]
+
+
+def decode_vault_bool(data: bytes) -> VaultBool:
+    if data[:8] != VAULT_BOOL_DISCRIMINATOR:
+        raise ValueError("Account data is not a `VaultBool`")
+    return vault_bool_layout().parse(data)
+
+
+async def fetch_vault_bool(rpc: Rpc, address: Pubkey) -> typing.Optional[VaultBool]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_vault_bool(data)
+
+
+def find_vault_bool_address(program_id: Pubkey, *, owner: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["vault".encode(), PUBLIC_KEY.build(owner)], program_id)[0]
+
+
+def decode_light(data: bytes) -> Light:
+    if data[:8] != LIGHT_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Light`")
+    return light_layout().parse(data)
+
+
+async def fetch_light(rpc: Rpc, address: Pubkey) -> typing.Optional[Light]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_light(data)
+
+
+def find_light_address(program_id: Pubkey, *, owner: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address(["light".encode(), PUBLIC_KEY.build(owner)], program_id)[0]
+
+
+DecodedAccount = typing.Union[Person, Home, Car, Counter, OrderBook, VaultU64, VaultBool, Light]
+
+_ACCOUNT_DECODERS = {
+    PERSON_DISCRIMINATOR: decode_person,
+    HOME_DISCRIMINATOR: decode_home,
+    CAR_DISCRIMINATOR: decode_car,
+    COUNTER_DISCRIMINATOR: decode_counter,
+    ORDER_BOOK_DISCRIMINATOR: decode_order_book,
+    VAULT_U64_DISCRIMINATOR: decode_vault_u64,
+    VAULT_BOOL_DISCRIMINATOR: decode_vault_bool,
+    LIGHT_DISCRIMINATOR: decode_light,
+}
+
+
+def decode_account(data: bytes) -> typing.Optional[DecodedAccount]:
+    decoder = _ACCOUNT_DECODERS.get(data[:8])
+    return None if decoder is None else decoder(data)
+
+
+async def fetch_account(rpc: Rpc, address: Pubkey) -> typing.Optional[DecodedAccount]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_account(data)
+
+
+class CreatePersonAccounts(typing.TypedDict):
+    new_person: Pubkey
+    fee_payer: Pubkey
+
+
+class CreatePersonArgs(typing.TypedDict):
+    name: str
+    authority: Pubkey
+
+
+def create_create_person_instruction(program_id: Pubkey, accounts: CreatePersonAccounts, args: CreatePersonArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_person"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "name" / borsh.String, "authority" / PUBLIC_KEY).build({"instruction": 0, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadPersonAccounts(typing.TypedDict):
+    person: Pubkey
+
+
+def create_read_person_instruction(program_id: Pubkey, accounts: ReadPersonAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["person"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 1})
+    return Instruction(program_id, data, keys)
+
+
+class CheckPersonAccounts(typing.TypedDict):
+    person: Pubkey
+
+
+def create_check_person_instruction(program_id: Pubkey, accounts: CheckPersonAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["person"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 2})
+    return Instruction(program_id, data, keys)
+
+
+class ReadPersonAuthorityAccounts(typing.TypedDict):
+    person: Pubkey
+
+
+def create_read_person_authority_instruction(program_id: Pubkey, accounts: ReadPersonAuthorityAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["person"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 3})
+    return Instruction(program_id, data, keys)
+
+
+class CreateHomeAccounts(typing.TypedDict):
+    new_home: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateHomeArgs(typing.TypedDict):
+    house_number: int
+    street: str
+    some_pubkey: Pubkey
+
+
+def create_create_home_instruction(program_id: Pubkey, accounts: CreateHomeAccounts, args: CreateHomeArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_home"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "house_number" / borsh.U8, "street" / borsh.String, "some_pubkey" / PUBLIC_KEY).build({"instruction": 4, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadHomeAccounts(typing.TypedDict):
+    home: Pubkey
+
+
+def create_read_home_instruction(program_id: Pubkey, accounts: ReadHomeAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["home"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 5})
+    return Instruction(program_id, data, keys)
+
+
+class CreateCarAccounts(typing.TypedDict):
+    new_car: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateCarArgs(typing.TypedDict):
+    make: str
+    model: str
+    purchase_authority: Pubkey
+    operating_authority: Pubkey
+
+
+def create_create_car_instruction(program_id: Pubkey, accounts: CreateCarAccounts, args: CreateCarArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_car"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "make" / borsh.String, "model" / borsh.String, "purchase_authority" / PUBLIC_KEY, "operating_authority" / PUBLIC_KEY).build({"instruction": 6, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadCarAccounts(typing.TypedDict):
+    car: Pubkey
+
+
+def create_read_car_instruction(program_id: Pubkey, accounts: ReadCarAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["car"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 7})
+    return Instruction(program_id, data, keys)
+
+
+class CreateCounterAccounts(typing.TypedDict):
+    new_counter: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateCounterArgs(typing.TypedDict):
+    owner: Pubkey
+    count: int
+
+
+def create_create_counter_instruction(program_id: Pubkey, accounts: CreateCounterAccounts, args: CreateCounterArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_counter"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "owner" / PUBLIC_KEY, "count" / borsh.U64).build({"instruction": 8, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadCounterAccounts(typing.TypedDict):
+    counter: Pubkey
+
+
+def create_read_counter_instruction(program_id: Pubkey, accounts: ReadCounterAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["counter"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 9})
+    return Instruction(program_id, data, keys)
+
+
+class CreateLightAccounts(typing.TypedDict):
+    new_light: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateLightArgs(typing.TypedDict):
+    owner: Pubkey
+    brightness: int
+    on_ms: int
+    off_ms: int
+
+
+def create_create_light_instruction(program_id: Pubkey, accounts: CreateLightAccounts, args: CreateLightArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_light"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "owner" / PUBLIC_KEY, "brightness" / borsh.U8, "on_ms" / borsh.U16, "off_ms" / borsh.U16).build({"instruction": 10, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadLightAccounts(typing.TypedDict):
+    light: Pubkey
+
+
+def create_read_light_instruction(program_id: Pubkey, accounts: ReadLightAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["light"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 11})
+    return Instruction(program_id, data, keys)
+
+
+class CreateOrderBookAccounts(typing.TypedDict):
+    new_order_book: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateOrderBookArgs(typing.TypedDict):
+    market: Pubkey
+
+
+def create_create_order_book_instruction(program_id: Pubkey, accounts: CreateOrderBookAccounts, args: CreateOrderBookArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_order_book"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "market" / PUBLIC_KEY).build({"instruction": 12, **args})
+    return Instruction(program_id, data, keys)
+
+
+class PlaceOrderAccounts(typing.TypedDict):
+    order_book: Pubkey
+
+
+class PlaceOrderArgs(typing.TypedDict):
+    price: int
+    size: int
+
+
+def create_place_order_instruction(program_id: Pubkey, accounts: PlaceOrderAccounts, args: PlaceOrderArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["order_book"], is_signer=False, is_writable=True),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "price" / borsh.U64, "size" / borsh.U64).build({"instruction": 13, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadOrderBookAccounts(typing.TypedDict):
+    order_book: Pubkey
+
+
+def create_read_order_book_instruction(program_id: Pubkey, accounts: ReadOrderBookAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["order_book"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 14})
+    return Instruction(program_id, data, keys)
+
+
+class CreateBalanceVaultAccounts(typing.TypedDict):
+    new_vault: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateBalanceVaultArgs(typing.TypedDict):
+    owner: Pubkey
+    balance: int
+
+
+def create_create_balance_vault_instruction(program_id: Pubkey, accounts: CreateBalanceVaultAccounts, args: CreateBalanceVaultArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_vault"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "owner" / PUBLIC_KEY, "balance" / borsh.U64).build({"instruction": 15, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadBalanceVaultAccounts(typing.TypedDict):
+    vault: Pubkey
+
+
+def create_read_balance_vault_instruction(program_id: Pubkey, accounts: ReadBalanceVaultAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["vault"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 16})
+    return Instruction(program_id, data, keys)
+
+
+class CreateFlagVaultAccounts(typing.TypedDict):
+    new_vault: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateFlagVaultArgs(typing.TypedDict):
+    owner: Pubkey
+    flag: bool
+
+
+def create_create_flag_vault_instruction(program_id: Pubkey, accounts: CreateFlagVaultAccounts, args: CreateFlagVaultArgs) -> Instruction:
+    keys = [
+        AccountMeta(accounts["new_vault"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "owner" / PUBLIC_KEY, "flag" / borsh.Bool).build({"instruction": 17, **args})
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,765 @@
+//! Generated by Nautilus from the `program-nautilus` IDL (v0.1.0). Do not edit by hand.
+#![allow(dead_code, unused_imports)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{pubkey, pubkey::Pubkey};
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Reads and checks an account's 8-byte discriminator.
+fn check_discriminator(buf: &mut &[u8], discriminator: [u8; 8], name: &str) -> std::io::Result<()> {
+    let data_discriminator: [u8; 8] = BorshDeserialize::deserialize(buf)?;
+    match data_discriminator == discriminator {
+        true => Ok(()),
+        false => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Account data is not a `{}`", name),
+        )),
+    }
+}
+
+pub const PERSON_DISCRIMINATOR: [u8; 8] = [38, 10, 62, 51, 134, 223, 96, 156];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Person {
+    pub name: String,
+    pub authority: Pubkey,
+}
+
+impl BorshSerialize for Person {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        PERSON_DISCRIMINATOR.serialize(writer)?;
+        self.name.serialize(writer)?;
+        self.authority.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Person {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, PERSON_DISCRIMINATOR, "Person")?;
+        Ok(Self { name: BorshDeserialize::deserialize(buf)?, authority: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const HOME_DISCRIMINATOR: [u8; 8] = [207, 164, 88, 52, 87, 254, 75, 93];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Home {
+    pub house_number: u8,
+    pub street: String,
+}
+
+impl BorshSerialize for Home {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        HOME_DISCRIMINATOR.serialize(writer)?;
+        self.house_number.serialize(writer)?;
+        self.street.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Home {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, HOME_DISCRIMINATOR, "Home")?;
+        Ok(Self { house_number: BorshDeserialize::deserialize(buf)?, street: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const CAR_DISCRIMINATOR: [u8; 8] = [84, 120, 193, 54, 102, 120, 204, 93];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Car {
+    pub make: String,
+    pub model: String,
+    pub purchase_authority: Pubkey,
+    pub operating_authority: Pubkey,
+}
+
+impl BorshSerialize for Car {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        CAR_DISCRIMINATOR.serialize(writer)?;
+        self.make.serialize(writer)?;
+        self.model.serialize(writer)?;
+        self.purchase_authority.serialize(writer)?;
+        self.operating_authority.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Car {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, CAR_DISCRIMINATOR, "Car")?;
+        Ok(Self { make: BorshDeserialize::deserialize(buf)?, model: BorshDeserialize::deserialize(buf)?, purchase_authority: BorshDeserialize::deserialize(buf)?, operating_authority: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const COUNTER_DISCRIMINATOR: [u8; 8] = [111, 107, 95, 228, 242, 115, 107, 223];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counter(pub Pubkey, pub u64);
+
+impl BorshSerialize for Counter {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        COUNTER_DISCRIMINATOR.serialize(writer)?;
+        self.0.serialize(writer)?;
+        self.1.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Counter {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, COUNTER_DISCRIMINATOR, "Counter")?;
+        Ok(Self(BorshDeserialize::deserialize(buf)?, BorshDeserialize::deserialize(buf)?))
+    }
+}
+
+pub const ORDER_BOOK_DISCRIMINATOR: [u8; 8] = [202, 74, 178, 111, 127, 113, 177, 217];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBook {
+    pub market: Pubkey,
+    pub len: u64,
+    pub prices: [u64; 64],
+    pub sizes: [u64; 64],
+}
+
+impl BorshSerialize for OrderBook {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        ORDER_BOOK_DISCRIMINATOR.serialize(writer)?;
+        self.market.serialize(writer)?;
+        self.len.serialize(writer)?;
+        self.prices.serialize(writer)?;
+        self.sizes.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for OrderBook {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, ORDER_BOOK_DISCRIMINATOR, "OrderBook")?;
+        Ok(Self { market: BorshDeserialize::deserialize(buf)?, len: BorshDeserialize::deserialize(buf)?, prices: BorshDeserialize::deserialize(buf)?, sizes: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const VAULT_U64_DISCRIMINATOR: [u8; 8] = [163, 245, 72, 45, 63, 45, 215, 109];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultU64 {
+    pub value: u64,
+}
+
+impl BorshSerialize for VaultU64 {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        VAULT_U64_DISCRIMINATOR.serialize(writer)?;
+        self.value.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for VaultU64 {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, VAULT_U64_DISCRIMINATOR, "VaultU64")?;
+        Ok(Self { value: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const VAULT_BOOL_DISCRIMINATOR: [u8; 8] = [123, 33, 179, 119, 57, 180, 207, 136];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultBool {
+    pub value: bool,
+}
+
+impl BorshSerialize for VaultBool {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        VAULT_BOOL_DISCRIMINATOR.serialize(writer)?;
+        self.value.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for VaultBool {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, VAULT_BOOL_DISCRIMINATOR, "VaultBool")?;
+        Ok(Self { value: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const LIGHT_DISCRIMINATOR: [u8; 8] = [2, 254, 244, 177, 101, 93, 194, 220];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Light {
+    Off,
+    On { brightness: u8 },
+    Blinking(u16, u16),
+}
+
+impl BorshSerialize for Light {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        LIGHT_DISCRIMINATOR.serialize(writer)?;
+        match self {
+            Self::Off => {
+                0u8.serialize(writer)?;
+            }
+            Self::On { brightness } => {
+                1u8.serialize(writer)?;
+                brightness.serialize(writer)?;
+            }
+            Self::Blinking(field_0, field_1) => {
+                2u8.serialize(writer)?;
+                field_0.serialize(writer)?;
+                field_1.serialize(writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Light {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, LIGHT_DISCRIMINATOR, "Light")?;
+        let variant: u8 = BorshDeserialize::deserialize(buf)?;
+        Ok(match variant {
+            0 => Self::Off,
+            1 => Self::On { brightness: BorshDeserialize::deserialize(buf)? },
+            2 => Self::Blinking(BorshDeserialize::deserialize(buf)?, BorshDeserialize::deserialize(buf)?),
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid variant index for `Light`: {}", variant),
+                ))
+            }
+        })
+    }
+}
+
+/// Decodes a `Person` account's data, checking its discriminator.
+pub fn decode_person(data: &[u8]) -> std::io::Result<Person> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Person` account.
+pub fn find_person_address(program_id: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["person".as_bytes(), &authority.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `Home` account's data, checking its discriminator.
+pub fn decode_home(data: &[u8]) -> std::io::Result<Home> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Home` account.
+pub fn find_home_address(program_id: &Pubkey, some_pubkey: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["home".as_bytes(), &some_pubkey.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `Car` account's data, checking its discriminator.
+pub fn decode_car(data: &[u8]) -> std::io::Result<Car> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Car` account.
+pub fn find_car_address(program_id: &Pubkey, purchase_authority: &Pubkey, operating_authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["car".as_bytes(), &purchase_authority.try_to_vec().unwrap(), &operating_authority.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `Counter` account's data, checking its discriminator.
+pub fn decode_counter(data: &[u8]) -> std::io::Result<Counter> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Counter` account.
+pub fn find_counter_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["counter".as_bytes(), &owner.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `OrderBook` account's data, checking its discriminator.
+pub fn decode_order_book(data: &[u8]) -> std::io::Result<OrderBook> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `OrderBook` account.
+pub fn find_order_book_address(program_id: &Pubkey, market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["order_book".as_bytes(), &market.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `VaultU64` account's data, checking its discriminator.
+pub fn decode_vault_u64(data: &[u8]) -> std::io::Result<VaultU64> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `VaultU64` account.
+pub fn find_vault_u64_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["vault".as_bytes(), &owner.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `VaultBool` account's data, checking its discriminator.
+pub fn decode_vault_bool(data: &[u8]) -> std::io::Result<VaultBool> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `VaultBool` account.
+pub fn find_vault_bool_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["vault".as_bytes(), &owner.try_to_vec().unwrap()], program_id)
+}
+
+/// Decodes a `Light` account's data, checking its discriminator.
+pub fn decode_light(data: &[u8]) -> std::io::Result<Light> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Light` account.
+pub fn find_light_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["light".as_bytes(), &owner.try_to_vec().unwrap()], program_id)
+}
+
+/// Any of this program's accounts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodedAccount {
+    Person(Person),
+    Home(Home),
+    Car(Car),
+    Counter(Counter),
+    OrderBook(OrderBook),
+    VaultU64(VaultU64),
+    VaultBool(VaultBool),
+    Light(Light),
+}
+
+/// Decodes an account's data based on its discriminator, returning `None` if
+/// it doesn't belong to any of this program's accounts.
+pub fn decode_account(data: &[u8]) -> std::io::Result<Option<DecodedAccount>> {
+    let discriminator: [u8; 8] = match data.get(..8) {
+        Some(discriminator) => discriminator.try_into().unwrap(),
+        None => return Ok(None),
+    };
+    Ok(Some(match discriminator {
+        PERSON_DISCRIMINATOR => DecodedAccount::Person(decode_person(data)?),
+        HOME_DISCRIMINATOR => DecodedAccount::Home(decode_home(data)?),
+        CAR_DISCRIMINATOR => DecodedAccount::Car(decode_car(data)?),
+        COUNTER_DISCRIMINATOR => DecodedAccount::Counter(decode_counter(data)?),
+        ORDER_BOOK_DISCRIMINATOR => DecodedAccount::OrderBook(decode_order_book(data)?),
+        VAULT_U64_DISCRIMINATOR => DecodedAccount::VaultU64(decode_vault_u64(data)?),
+        VAULT_BOOL_DISCRIMINATOR => DecodedAccount::VaultBool(decode_vault_bool(data)?),
+        LIGHT_DISCRIMINATOR => DecodedAccount::Light(decode_light(data)?),
+        _ => return Ok(None),
+    }))
+}
+
+pub mod instruction {
+    use solana_program::{
+        instruction::{AccountMeta, Instruction},
+        system_program, sysvar,
+    };
+
+    use super::*;
+
+    /// The accounts for `createPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreatePersonAccounts {
+        pub new_person: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createPerson`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreatePersonArgs {
+        pub name: String,
+        pub authority: Pubkey,
+    }
+
+    /// Creates a `createPerson` instruction.
+    pub fn create_person(program_id: &Pubkey, accounts: &CreatePersonAccounts, args: &CreatePersonArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_person, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![0];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadPersonAccounts {
+        pub person: Pubkey,
+    }
+
+    /// Creates a `readPerson` instruction.
+    pub fn read_person(program_id: &Pubkey, accounts: &ReadPersonAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.person, false),
+        ];
+        let data = vec![1];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `checkPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CheckPersonAccounts {
+        pub person: Pubkey,
+    }
+
+    /// Creates a `checkPerson` instruction.
+    pub fn check_person(program_id: &Pubkey, accounts: &CheckPersonAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.person, false),
+        ];
+        let data = vec![2];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readPersonAuthority` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadPersonAuthorityAccounts {
+        pub person: Pubkey,
+    }
+
+    /// Creates a `readPersonAuthority` instruction.
+    pub fn read_person_authority(program_id: &Pubkey, accounts: &ReadPersonAuthorityAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.person, false),
+        ];
+        let data = vec![3];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createHome` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateHomeAccounts {
+        pub new_home: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createHome`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateHomeArgs {
+        pub house_number: u8,
+        pub street: String,
+        pub some_pubkey: Pubkey,
+    }
+
+    /// Creates a `createHome` instruction.
+    pub fn create_home(program_id: &Pubkey, accounts: &CreateHomeAccounts, args: &CreateHomeArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_home, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![4];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readHome` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadHomeAccounts {
+        pub home: Pubkey,
+    }
+
+    /// Creates a `readHome` instruction.
+    pub fn read_home(program_id: &Pubkey, accounts: &ReadHomeAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.home, false),
+        ];
+        let data = vec![5];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createCar` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateCarAccounts {
+        pub new_car: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createCar`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateCarArgs {
+        pub make: String,
+        pub model: String,
+        pub purchase_authority: Pubkey,
+        pub operating_authority: Pubkey,
+    }
+
+    /// Creates a `createCar` instruction.
+    pub fn create_car(program_id: &Pubkey, accounts: &CreateCarAccounts, args: &CreateCarArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_car, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![6];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readCar` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadCarAccounts {
+        pub car: Pubkey,
+    }
+
+    /// Creates a `readCar` instruction.
+    pub fn read_car(program_id: &Pubkey, accounts: &ReadCarAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.car, false),
+        ];
+        let data = vec![7];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createCounter` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateCounterAccounts {
+        pub new_counter: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createCounter`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateCounterArgs {
+        pub owner: Pubkey,
+        pub count: u64,
+    }
+
+    /// Creates a `createCounter` instruction.
+    pub fn create_counter(program_id: &Pubkey, accounts: &CreateCounterAccounts, args: &CreateCounterArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_counter, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![8];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readCounter` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadCounterAccounts {
+        pub counter: Pubkey,
+    }
+
+    /// Creates a `readCounter` instruction.
+    pub fn read_counter(program_id: &Pubkey, accounts: &ReadCounterAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.counter, false),
+        ];
+        let data = vec![9];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createLight` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateLightAccounts {
+        pub new_light: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createLight`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateLightArgs {
+        pub owner: Pubkey,
+        pub brightness: u8,
+        pub on_ms: u16,
+        pub off_ms: u16,
+    }
+
+    /// Creates a `createLight` instruction.
+    pub fn create_light(program_id: &Pubkey, accounts: &CreateLightAccounts, args: &CreateLightArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_light, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![10];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readLight` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadLightAccounts {
+        pub light: Pubkey,
+    }
+
+    /// Creates a `readLight` instruction.
+    pub fn read_light(program_id: &Pubkey, accounts: &ReadLightAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.light, false),
+        ];
+        let data = vec![11];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createOrderBook` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateOrderBookAccounts {
+        pub new_order_book: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createOrderBook`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateOrderBookArgs {
+        pub market: Pubkey,
+    }
+
+    /// Creates a `createOrderBook` instruction.
+    pub fn create_order_book(program_id: &Pubkey, accounts: &CreateOrderBookAccounts, args: &CreateOrderBookArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_order_book, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![12];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `placeOrder` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct PlaceOrderAccounts {
+        pub order_book: Pubkey,
+    }
+
+    /// The arguments for `placeOrder`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct PlaceOrderArgs {
+        pub price: u64,
+        pub size: u64,
+    }
+
+    /// Creates a `placeOrder` instruction.
+    pub fn place_order(program_id: &Pubkey, accounts: &PlaceOrderAccounts, args: &PlaceOrderArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.order_book, false),
+        ];
+        let data = {
+            let mut data = vec![13];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readOrderBook` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadOrderBookAccounts {
+        pub order_book: Pubkey,
+    }
+
+    /// Creates a `readOrderBook` instruction.
+    pub fn read_order_book(program_id: &Pubkey, accounts: &ReadOrderBookAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.order_book, false),
+        ];
+        let data = vec![14];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createBalanceVault` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateBalanceVaultAccounts {
+        pub new_vault: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createBalanceVault`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateBalanceVaultArgs {
+        pub owner: Pubkey,
+        pub balance: u64,
+    }
+
+    /// Creates a `createBalanceVault` instruction.
+    pub fn create_balance_vault(program_id: &Pubkey, accounts: &CreateBalanceVaultAccounts, args: &CreateBalanceVaultArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_vault, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![15];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readBalanceVault` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadBalanceVaultAccounts {
+        pub vault: Pubkey,
+    }
+
+    /// Creates a `readBalanceVault` instruction.
+    pub fn read_balance_vault(program_id: &Pubkey, accounts: &ReadBalanceVaultAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(accounts.vault, false),
+        ];
+        let data = vec![16];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createFlagVault` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateFlagVaultAccounts {
+        pub new_vault: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createFlagVault`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateFlagVaultArgs {
+        pub owner: Pubkey,
+        pub flag: bool,
+    }
+
+    /// Creates a `createFlagVault` instruction.
+    pub fn create_flag_vault(program_id: &Pubkey, accounts: &CreateFlagVaultAccounts, args: &CreateFlagVaultArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.new_vault, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![17];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+}
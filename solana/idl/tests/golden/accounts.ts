@@ -0,0 +1,646 @@
+// Generated by Nautilus from the `program-nautilus` IDL (v0.1.0). Do not edit by hand.
+
+import * as borsh from "@coral-xyz/borsh"
+import BN from "bn.js"
+import { Layout } from "buffer-layout"
+import {
+    Connection,
+    PublicKey,
+    SystemProgram,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    SYSVAR_RENT_PUBKEY,
+    TransactionInstruction,
+} from "@solana/web3.js"
+
+export const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+
+/** Prefixes a layout with an account's 8-byte discriminator, the way the Nautilus runtime serializes accounts. */
+class DiscriminatedLayout<T> extends Layout<T> {
+    constructor(readonly discriminator: Buffer, readonly layout: borsh.Layout<T>, property?: string) {
+        super(-1, property)
+    }
+
+    getSpan(b: Buffer, offset = 0): number {
+        return 8 + this.layout.getSpan(b, offset + 8)
+    }
+
+    decode(b: Buffer, offset = 0): T {
+        if (!b.subarray(offset, offset + 8).equals(this.discriminator)) {
+            throw new Error("Invalid account discriminator")
+        }
+        return this.layout.decode(b, offset + 8)
+    }
+
+    encode(src: T, b: Buffer, offset = 0): number {
+        this.discriminator.copy(b, offset)
+        return 8 + this.layout.encode(src, b, offset + 8)
+    }
+}
+
+/** Serializes a single value with a Borsh layout. */
+function encode<T>(layout: borsh.Layout<T>, value: T): Buffer {
+    const buffer = Buffer.alloc(1232) // The maximum size of a transaction
+    const length = layout.encode(value, buffer)
+    return buffer.subarray(0, length)
+}
+
+export const PERSON_DISCRIMINATOR = Buffer.from([38, 10, 62, 51, 134, 223, 96, 156])
+
+export type Person = {
+    name: string
+    authority: PublicKey
+}
+
+export function personLayout(property?: string): borsh.Layout<Person> {
+    return new DiscriminatedLayout(PERSON_DISCRIMINATOR, borsh.struct([
+        borsh.str("name"),
+        borsh.publicKey("authority"),
+    ]), property)
+}
+
+export const HOME_DISCRIMINATOR = Buffer.from([207, 164, 88, 52, 87, 254, 75, 93])
+
+export type Home = {
+    house_number: number
+    street: string
+}
+
+export function homeLayout(property?: string): borsh.Layout<Home> {
+    return new DiscriminatedLayout(HOME_DISCRIMINATOR, borsh.struct([
+        borsh.u8("house_number"),
+        borsh.str("street"),
+    ]), property)
+}
+
+export const CAR_DISCRIMINATOR = Buffer.from([84, 120, 193, 54, 102, 120, 204, 93])
+
+export type Car = {
+    make: string
+    model: string
+    purchase_authority: PublicKey
+    operating_authority: PublicKey
+}
+
+export function carLayout(property?: string): borsh.Layout<Car> {
+    return new DiscriminatedLayout(CAR_DISCRIMINATOR, borsh.struct([
+        borsh.str("make"),
+        borsh.str("model"),
+        borsh.publicKey("purchase_authority"),
+        borsh.publicKey("operating_authority"),
+    ]), property)
+}
+
+export const COUNTER_DISCRIMINATOR = Buffer.from([111, 107, 95, 228, 242, 115, 107, 223])
+
+export type Counter = {
+    0: PublicKey
+    1: BN
+}
+
+export function counterLayout(property?: string): borsh.Layout<Counter> {
+    return new DiscriminatedLayout(COUNTER_DISCRIMINATOR, borsh.struct([
+        borsh.publicKey("0"),
+        borsh.u64("1"),
+    ]), property)
+}
+
+export const ORDER_BOOK_DISCRIMINATOR = Buffer.from([202, 74, 178, 111, 127, 113, 177, 217])
+
+export type OrderBook = {
+    market: PublicKey
+    len: BN
+    prices: BN[]
+    sizes: BN[]
+}
+
+export function orderBookLayout(property?: string): borsh.Layout<OrderBook> {
+    return new DiscriminatedLayout(ORDER_BOOK_DISCRIMINATOR, borsh.struct([
+        borsh.publicKey("market"),
+        borsh.u64("len"),
+        borsh.array(borsh.u64(), 64, "prices"),
+        borsh.array(borsh.u64(), 64, "sizes"),
+    ]), property)
+}
+
+export const VAULT_U64_DISCRIMINATOR = Buffer.from([163, 245, 72, 45, 63, 45, 215, 109])
+
+export type VaultU64 = {
+    value: BN
+}
+
+export function vaultU64Layout(property?: string): borsh.Layout<VaultU64> {
+    return new DiscriminatedLayout(VAULT_U64_DISCRIMINATOR, borsh.struct([
+        borsh.u64("value"),
+    ]), property)
+}
+
+export const VAULT_BOOL_DISCRIMINATOR = Buffer.from([123, 33, 179, 119, 57, 180, 207, 136])
+
+export type VaultBool = {
+    value: boolean
+}
+
+export function vaultBoolLayout(property?: string): borsh.Layout<VaultBool> {
+    return new DiscriminatedLayout(VAULT_BOOL_DISCRIMINATOR, borsh.struct([
+        borsh.bool("value"),
+    ]), property)
+}
+
+export const LIGHT_DISCRIMINATOR = Buffer.from([2, 254, 244, 177, 101, 93, 194, 220])
+
+export type Light =
+    | { Off: {} }
+    | { On: { brightness: number } }
+    | { Blinking: { 0: number, 1: number } }
+
+export function lightLayout(property?: string): borsh.Layout<Light> {
+    return new DiscriminatedLayout(LIGHT_DISCRIMINATOR, borsh.rustEnum([
+        borsh.struct([], "Off"),
+        borsh.struct([borsh.u8("brightness")], "On"),
+        borsh.struct([borsh.u16("0"), borsh.u16("1")], "Blinking"),
+    ]), property)
+}
+
+export function decodePerson(data: Buffer): Person {
+    if (!data.subarray(0, 8).equals(PERSON_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Person`")
+    }
+    return personLayout().decode(data)
+}
+
+export async function fetchPerson(connection: Connection, address: PublicKey): Promise<Person | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodePerson(info.data)
+}
+
+export function findPersonAddress(programId: PublicKey, seeds: { authority: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("person"), encode(borsh.publicKey(), seeds.authority)],
+        programId,
+    )[0]
+}
+
+export function decodeHome(data: Buffer): Home {
+    if (!data.subarray(0, 8).equals(HOME_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Home`")
+    }
+    return homeLayout().decode(data)
+}
+
+export async function fetchHome(connection: Connection, address: PublicKey): Promise<Home | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeHome(info.data)
+}
+
+export function findHomeAddress(programId: PublicKey, seeds: { somePubkey: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("home"), encode(borsh.publicKey(), seeds.somePubkey)],
+        programId,
+    )[0]
+}
+
+export function decodeCar(data: Buffer): Car {
+    if (!data.subarray(0, 8).equals(CAR_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Car`")
+    }
+    return carLayout().decode(data)
+}
+
+export async function fetchCar(connection: Connection, address: PublicKey): Promise<Car | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeCar(info.data)
+}
+
+export function findCarAddress(programId: PublicKey, seeds: { purchaseAuthority: PublicKey, operatingAuthority: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("car"), encode(borsh.publicKey(), seeds.purchaseAuthority), encode(borsh.publicKey(), seeds.operatingAuthority)],
+        programId,
+    )[0]
+}
+
+export function decodeCounter(data: Buffer): Counter {
+    if (!data.subarray(0, 8).equals(COUNTER_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Counter`")
+    }
+    return counterLayout().decode(data)
+}
+
+export async function fetchCounter(connection: Connection, address: PublicKey): Promise<Counter | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeCounter(info.data)
+}
+
+export function findCounterAddress(programId: PublicKey, seeds: { owner: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("counter"), encode(borsh.publicKey(), seeds.owner)],
+        programId,
+    )[0]
+}
+
+export function decodeOrderBook(data: Buffer): OrderBook {
+    if (!data.subarray(0, 8).equals(ORDER_BOOK_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `OrderBook`")
+    }
+    return orderBookLayout().decode(data)
+}
+
+export async function fetchOrderBook(connection: Connection, address: PublicKey): Promise<OrderBook | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeOrderBook(info.data)
+}
+
+export function findOrderBookAddress(programId: PublicKey, seeds: { market: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("order_book"), encode(borsh.publicKey(), seeds.market)],
+        programId,
+    )[0]
+}
+
+export function decodeVaultU64(data: Buffer): VaultU64 {
+    if (!data.subarray(0, 8).equals(VAULT_U64_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `VaultU64`")
+    }
+    return vaultU64Layout().decode(data)
+}
+
+export async function fetchVaultU64(connection: Connection, address: PublicKey): Promise<VaultU64 | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeVaultU64(info.data)
+}
+
+export function findVaultU64Address(programId: PublicKey, seeds: { owner: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), encode(borsh.publicKey(), seeds.owner)],
+        programId,
+    )[0]
+}
+
+export function decodeVaultBool(data: Buffer): VaultBool {
+    if (!data.subarray(0, 8).equals(VAULT_BOOL_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `VaultBool`")
+    }
+    return vaultBoolLayout().decode(data)
+}
+
+export async function fetchVaultBool(connection: Connection, address: PublicKey): Promise<VaultBool | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeVaultBool(info.data)
+}
+
+export function findVaultBoolAddress(programId: PublicKey, seeds: { owner: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), encode(borsh.publicKey(), seeds.owner)],
+        programId,
+    )[0]
+}
+
+export function decodeLight(data: Buffer): Light {
+    if (!data.subarray(0, 8).equals(LIGHT_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Light`")
+    }
+    return lightLayout().decode(data)
+}
+
+export async function fetchLight(connection: Connection, address: PublicKey): Promise<Light | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeLight(info.data)
+}
+
+export function findLightAddress(programId: PublicKey, seeds: { owner: PublicKey }): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("light"), encode(borsh.publicKey(), seeds.owner)],
+        programId,
+    )[0]
+}
+
+export type DecodedAccount =
+    | { name: "Person", data: Person }
+    | { name: "Home", data: Home }
+    | { name: "Car", data: Car }
+    | { name: "Counter", data: Counter }
+    | { name: "OrderBook", data: OrderBook }
+    | { name: "VaultU64", data: VaultU64 }
+    | { name: "VaultBool", data: VaultBool }
+    | { name: "Light", data: Light }
+
+export function decodeAccount(data: Buffer): DecodedAccount | null {
+    const discriminator = data.subarray(0, 8)
+    if (discriminator.equals(PERSON_DISCRIMINATOR)) {
+        return { name: "Person", data: decodePerson(data) }
+    }
+    if (discriminator.equals(HOME_DISCRIMINATOR)) {
+        return { name: "Home", data: decodeHome(data) }
+    }
+    if (discriminator.equals(CAR_DISCRIMINATOR)) {
+        return { name: "Car", data: decodeCar(data) }
+    }
+    if (discriminator.equals(COUNTER_DISCRIMINATOR)) {
+        return { name: "Counter", data: decodeCounter(data) }
+    }
+    if (discriminator.equals(ORDER_BOOK_DISCRIMINATOR)) {
+        return { name: "OrderBook", data: decodeOrderBook(data) }
+    }
+    if (discriminator.equals(VAULT_U64_DISCRIMINATOR)) {
+        return { name: "VaultU64", data: decodeVaultU64(data) }
+    }
+    if (discriminator.equals(VAULT_BOOL_DISCRIMINATOR)) {
+        return { name: "VaultBool", data: decodeVaultBool(data) }
+    }
+    if (discriminator.equals(LIGHT_DISCRIMINATOR)) {
+        return { name: "Light", data: decodeLight(data) }
+    }
+    return null
+}
+
+export async function fetchAccount(connection: Connection, address: PublicKey): Promise<DecodedAccount | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeAccount(info.data)
+}
+
+export function createCreatePersonInstruction(
+    programId: PublicKey,
+    accounts: { newPerson: PublicKey, feePayer: PublicKey },
+    args: { name: string, authority: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newPerson, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.str("name"), borsh.publicKey("authority")]),
+        { instruction: 0, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadPersonInstruction(
+    programId: PublicKey,
+    accounts: { person: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.person, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 1 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCheckPersonInstruction(
+    programId: PublicKey,
+    accounts: { person: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.person, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 2 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadPersonAuthorityInstruction(
+    programId: PublicKey,
+    accounts: { person: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.person, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 3 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateHomeInstruction(
+    programId: PublicKey,
+    accounts: { newHome: PublicKey, feePayer: PublicKey },
+    args: { houseNumber: number, street: string, somePubkey: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newHome, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u8("houseNumber"), borsh.str("street"), borsh.publicKey("somePubkey")]),
+        { instruction: 4, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadHomeInstruction(
+    programId: PublicKey,
+    accounts: { home: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.home, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 5 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateCarInstruction(
+    programId: PublicKey,
+    accounts: { newCar: PublicKey, feePayer: PublicKey },
+    args: { make: string, model: string, purchaseAuthority: PublicKey, operatingAuthority: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newCar, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.str("make"), borsh.str("model"), borsh.publicKey("purchaseAuthority"), borsh.publicKey("operatingAuthority")]),
+        { instruction: 6, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadCarInstruction(
+    programId: PublicKey,
+    accounts: { car: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.car, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 7 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateCounterInstruction(
+    programId: PublicKey,
+    accounts: { newCounter: PublicKey, feePayer: PublicKey },
+    args: { owner: PublicKey, count: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newCounter, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.publicKey("owner"), borsh.u64("count")]),
+        { instruction: 8, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadCounterInstruction(
+    programId: PublicKey,
+    accounts: { counter: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.counter, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 9 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateLightInstruction(
+    programId: PublicKey,
+    accounts: { newLight: PublicKey, feePayer: PublicKey },
+    args: { owner: PublicKey, brightness: number, onMs: number, offMs: number },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newLight, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.publicKey("owner"), borsh.u8("brightness"), borsh.u16("onMs"), borsh.u16("offMs")]),
+        { instruction: 10, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadLightInstruction(
+    programId: PublicKey,
+    accounts: { light: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.light, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 11 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateOrderBookInstruction(
+    programId: PublicKey,
+    accounts: { newOrderBook: PublicKey, feePayer: PublicKey },
+    args: { market: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newOrderBook, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.publicKey("market")]),
+        { instruction: 12, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createPlaceOrderInstruction(
+    programId: PublicKey,
+    accounts: { orderBook: PublicKey },
+    args: { price: BN, size: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.orderBook, isSigner: false, isWritable: true },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("price"), borsh.u64("size")]),
+        { instruction: 13, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadOrderBookInstruction(
+    programId: PublicKey,
+    accounts: { orderBook: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.orderBook, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 14 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateBalanceVaultInstruction(
+    programId: PublicKey,
+    accounts: { newVault: PublicKey, feePayer: PublicKey },
+    args: { owner: PublicKey, balance: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newVault, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.publicKey("owner"), borsh.u64("balance")]),
+        { instruction: 15, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadBalanceVaultInstruction(
+    programId: PublicKey,
+    accounts: { vault: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.vault, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 16 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateFlagVaultInstruction(
+    programId: PublicKey,
+    accounts: { newVault: PublicKey, feePayer: PublicKey },
+    args: { owner: PublicKey, flag: boolean },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.newVault, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.publicKey("owner"), borsh.bool("flag")]),
+        { instruction: 17, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
@@ -0,0 +1,1070 @@
+// Code generated by Nautilus from the `program-nautilus` IDL (v0.1.0). DO NOT EDIT.
+
+package programnautilus
+
+import (
+	"bytes"
+	"encoding/binary"
+	"fmt"
+	"io"
+	"math/big"
+	"sort"
+
+	"github.com/gagliardetto/solana-go"
+)
+
+// Uint128 is a Borsh `u128`, split into its low and high 64 bits.
+type Uint128 struct {
+	Lo uint64
+	Hi uint64
+}
+
+// BigInt converts the value to a big.Int.
+func (v Uint128) BigInt() *big.Int {
+	hi := new(big.Int).SetUint64(v.Hi)
+	return hi.Lsh(hi, 64).Add(hi, new(big.Int).SetUint64(v.Lo))
+}
+
+// Int128 is a Borsh `i128`, split into its low and high 64 bits.
+type Int128 struct {
+	Lo uint64
+	Hi int64
+}
+
+// BigInt converts the value to a big.Int.
+func (v Int128) BigInt() *big.Int {
+	hi := big.NewInt(v.Hi)
+	return hi.Lsh(hi, 64).Add(hi, new(big.Int).SetUint64(v.Lo))
+}
+
+// BorshEncoder serializes values with Borsh.
+type BorshEncoder struct {
+	buf bytes.Buffer
+}
+
+func NewBorshEncoder() *BorshEncoder {
+	return &BorshEncoder{}
+}
+
+// Bytes returns the data written so far.
+func (e *BorshEncoder) Bytes() []byte {
+	return e.buf.Bytes()
+}
+
+// WriteRaw writes data as is, without a length prefix.
+func (e *BorshEncoder) WriteRaw(data []byte) error {
+	_, err := e.buf.Write(data)
+	return err
+}
+
+func (e *BorshEncoder) writeFixed(v any) error {
+	return binary.Write(&e.buf, binary.LittleEndian, v)
+}
+
+func (e *BorshEncoder) WriteBool(v bool) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU8(v uint8) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU16(v uint16) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU32(v uint32) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU64(v uint64) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteU128(v Uint128) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI8(v int8) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI16(v int16) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI32(v int32) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI64(v int64) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteI128(v Int128) error { return e.writeFixed(v) }
+func (e *BorshEncoder) WriteString(v string) error { return e.WriteBytes([]byte(v)) }
+func (e *BorshEncoder) WritePublicKey(v solana.PublicKey) error { return e.WriteRaw(v[:]) }
+
+func (e *BorshEncoder) WriteBytes(v []byte) error {
+	if err := e.WriteU32(uint32(len(v))); err != nil {
+		return err
+	}
+	return e.WriteRaw(v)
+}
+
+// BorshDecoder deserializes values with Borsh.
+type BorshDecoder struct {
+	data []byte
+}
+
+func NewBorshDecoder(data []byte) *BorshDecoder {
+	return &BorshDecoder{data: data}
+}
+
+// Remaining returns the data that hasn't been read yet.
+func (d *BorshDecoder) Remaining() []byte {
+	return d.data
+}
+
+// ReadRaw reads the next n bytes as is.
+func (d *BorshDecoder) ReadRaw(n int) ([]byte, error) {
+	if n < 0 || n > len(d.data) {
+		return nil, io.ErrUnexpectedEOF
+	}
+	data := d.data[:n]
+	d.data = d.data[n:]
+	return data, nil
+}
+
+func readFixed[T any](d *BorshDecoder) (T, error) {
+	var v T
+	data, err := d.ReadRaw(binary.Size(v))
+	if err != nil {
+		return v, err
+	}
+	err = binary.Read(bytes.NewReader(data), binary.LittleEndian, &v)
+	return v, err
+}
+
+func (d *BorshDecoder) ReadU8() (uint8, error) { return readFixed[uint8](d) }
+func (d *BorshDecoder) ReadU16() (uint16, error) { return readFixed[uint16](d) }
+func (d *BorshDecoder) ReadU32() (uint32, error) { return readFixed[uint32](d) }
+func (d *BorshDecoder) ReadU64() (uint64, error) { return readFixed[uint64](d) }
+func (d *BorshDecoder) ReadU128() (Uint128, error) { return readFixed[Uint128](d) }
+func (d *BorshDecoder) ReadI8() (int8, error) { return readFixed[int8](d) }
+func (d *BorshDecoder) ReadI16() (int16, error) { return readFixed[int16](d) }
+func (d *BorshDecoder) ReadI32() (int32, error) { return readFixed[int32](d) }
+func (d *BorshDecoder) ReadI64() (int64, error) { return readFixed[int64](d) }
+func (d *BorshDecoder) ReadI128() (Int128, error) { return readFixed[Int128](d) }
+
+func (d *BorshDecoder) ReadBool() (bool, error) {
+	v, err := d.ReadU8()
+	if err != nil {
+		return false, err
+	}
+	if v > 1 {
+		return false, fmt.Errorf("invalid bool: %d", v)
+	}
+	return v == 1, nil
+}
+
+func (d *BorshDecoder) ReadBytes() ([]byte, error) {
+	n, err := d.ReadU32()
+	if err != nil {
+		return nil, err
+	}
+	data, err := d.ReadRaw(int(n))
+	if err != nil {
+		return nil, err
+	}
+	return append([]byte{}, data...), nil
+}
+
+func (d *BorshDecoder) ReadString() (string, error) {
+	data, err := d.ReadBytes()
+	return string(data), err
+}
+
+func (d *BorshDecoder) ReadPublicKey() (solana.PublicKey, error) {
+	var v solana.PublicKey
+	data, err := d.ReadRaw(len(v))
+	if err != nil {
+		return v, err
+	}
+	copy(v[:], data)
+	return v, nil
+}
+
+// checkDiscriminator reads and checks an account's 8-byte discriminator.
+func (d *BorshDecoder) checkDiscriminator(discriminator [8]byte, name string) error {
+	data, err := d.ReadRaw(len(discriminator))
+	if err != nil {
+		return err
+	}
+	if !bytes.Equal(data, discriminator[:]) {
+		return fmt.Errorf("account data is not a `%s`", name)
+	}
+	return nil
+}
+
+func writeItems[T any](e *BorshEncoder, items []T, write func(*BorshEncoder, T) error) error {
+	for _, item := range items {
+		if err := write(e, item); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func readItems[T any](d *BorshDecoder, items []T, read func(*BorshDecoder) (T, error)) error {
+	for i := range items {
+		var err error
+		if items[i], err = read(d); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+func vecEncoder[T any](write func(*BorshEncoder, T) error) func(*BorshEncoder, []T) error {
+	return func(e *BorshEncoder, v []T) error {
+		if err := e.WriteU32(uint32(len(v))); err != nil {
+			return err
+		}
+		return writeItems(e, v, write)
+	}
+}
+
+func vecDecoder[T any](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) ([]T, error) {
+	return func(d *BorshDecoder) ([]T, error) {
+		n, err := d.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		v := []T{}
+		for i := uint32(0); i < n; i++ {
+			item, err := read(d)
+			if err != nil {
+				return nil, err
+			}
+			v = append(v, item)
+		}
+		return v, nil
+	}
+}
+
+func optionEncoder[T any](write func(*BorshEncoder, T) error) func(*BorshEncoder, *T) error {
+	return func(e *BorshEncoder, v *T) error {
+		if v == nil {
+			return e.WriteU8(0)
+		}
+		if err := e.WriteU8(1); err != nil {
+			return err
+		}
+		return write(e, *v)
+	}
+}
+
+func optionDecoder[T any](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) (*T, error) {
+	return func(d *BorshDecoder) (*T, error) {
+		tag, err := d.ReadU8()
+		if err != nil {
+			return nil, err
+		}
+		switch tag {
+		case 0:
+			return nil, nil
+		case 1:
+			v, err := read(d)
+			if err != nil {
+				return nil, err
+			}
+			return &v, nil
+		default:
+			return nil, fmt.Errorf("invalid option tag: %d", tag)
+		}
+	}
+}
+
+// mapEncoder writes a map's entries sorted by key, like Borsh does.
+func mapEncoder[K comparable, V any](
+	writeKey func(*BorshEncoder, K) error,
+	writeValue func(*BorshEncoder, V) error,
+	less func(K, K) bool,
+) func(*BorshEncoder, map[K]V) error {
+	return func(e *BorshEncoder, v map[K]V) error {
+		keys := make([]K, 0, len(v))
+		for key := range v {
+			keys = append(keys, key)
+		}
+		sort.Slice(keys, func(i, j int) bool { return less(keys[i], keys[j]) })
+		if err := e.WriteU32(uint32(len(keys))); err != nil {
+			return err
+		}
+		for _, key := range keys {
+			if err := writeKey(e, key); err != nil {
+				return err
+			}
+			if err := writeValue(e, v[key]); err != nil {
+				return err
+			}
+		}
+		return nil
+	}
+}
+
+func mapDecoder[K comparable, V any](
+	readKey func(*BorshDecoder) (K, error),
+	readValue func(*BorshDecoder) (V, error),
+) func(*BorshDecoder) (map[K]V, error) {
+	return func(d *BorshDecoder) (map[K]V, error) {
+		n, err := d.ReadU32()
+		if err != nil {
+			return nil, err
+		}
+		v := map[K]V{}
+		for i := uint32(0); i < n; i++ {
+			key, err := readKey(d)
+			if err != nil {
+				return nil, err
+			}
+			if v[key], err = readValue(d); err != nil {
+				return nil, err
+			}
+		}
+		return v, nil
+	}
+}
+
+func setEncoder[T comparable](write func(*BorshEncoder, T) error, less func(T, T) bool) func(*BorshEncoder, map[T]struct{}) error {
+	return mapEncoder(write, func(*BorshEncoder, struct{}) error { return nil }, less)
+}
+
+func setDecoder[T comparable](read func(*BorshDecoder) (T, error)) func(*BorshDecoder) (map[T]struct{}, error) {
+	return mapDecoder(read, func(*BorshDecoder) (struct{}, error) { return struct{}{}, nil })
+}
+
+type ordered interface {
+	~int8 | ~int16 | ~int32 | ~int64 | ~uint8 | ~uint16 | ~uint32 | ~uint64 | ~string
+}
+
+func lessOrdered[T ordered](a, b T) bool { return a < b }
+func lessBool(a, b bool) bool { return !a && b }
+func lessUint128(a, b Uint128) bool { return a.Hi < b.Hi || (a.Hi == b.Hi && a.Lo < b.Lo) }
+func lessInt128(a, b Int128) bool { return a.Hi < b.Hi || (a.Hi == b.Hi && a.Lo < b.Lo) }
+func lessPublicKey(a, b solana.PublicKey) bool { return bytes.Compare(a[:], b[:]) < 0 }
+
+// lessEncoded orders keys without a natural order by their encoded bytes.
+func lessEncoded[T any](write func(*BorshEncoder, T) error) func(T, T) bool {
+	return func(a, b T) bool {
+		encodedA, encodedB := NewBorshEncoder(), NewBorshEncoder()
+		_ = write(encodedA, a)
+		_ = write(encodedB, b)
+		return bytes.Compare(encodedA.Bytes(), encodedB.Bytes()) < 0
+	}
+}
+
+// borshSeed serializes a value to be used as a seed.
+func borshSeed[T any](v T, write func(*BorshEncoder, T) error) ([]byte, error) {
+	e := NewBorshEncoder()
+	err := write(e, v)
+	return e.Bytes(), err
+}
+
+var PersonDiscriminator = [8]byte{38, 10, 62, 51, 134, 223, 96, 156}
+
+type Person struct {
+	Id uint8
+	Name string
+	Authority solana.PublicKey
+}
+
+func EncodePerson(e *BorshEncoder, v Person) error {
+	if err := e.WriteRaw(PersonDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteU8(v.Id); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Name); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.Authority); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodePerson(d *BorshDecoder) (v Person, err error) {
+	if err = d.checkDiscriminator(PersonDiscriminator, "Person"); err != nil {
+		return
+	}
+	if v.Id, err = d.ReadU8(); err != nil {
+		return
+	}
+	if v.Name, err = d.ReadString(); err != nil {
+		return
+	}
+	if v.Authority, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	return
+}
+
+var HomeDiscriminator = [8]byte{207, 164, 88, 52, 87, 254, 75, 93}
+
+type Home struct {
+	Id uint8
+	HouseNumber uint8
+	Street string
+}
+
+func EncodeHome(e *BorshEncoder, v Home) error {
+	if err := e.WriteRaw(HomeDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteU8(v.Id); err != nil {
+		return err
+	}
+	if err := e.WriteU8(v.HouseNumber); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Street); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeHome(d *BorshDecoder) (v Home, err error) {
+	if err = d.checkDiscriminator(HomeDiscriminator, "Home"); err != nil {
+		return
+	}
+	if v.Id, err = d.ReadU8(); err != nil {
+		return
+	}
+	if v.HouseNumber, err = d.ReadU8(); err != nil {
+		return
+	}
+	if v.Street, err = d.ReadString(); err != nil {
+		return
+	}
+	return
+}
+
+var CarDiscriminator = [8]byte{84, 120, 193, 54, 102, 120, 204, 93}
+
+type Car struct {
+	Id uint8
+	Make string
+	Model string
+	PurchaseAuthority solana.PublicKey
+	OperatingAuthority solana.PublicKey
+}
+
+func EncodeCar(e *BorshEncoder, v Car) error {
+	if err := e.WriteRaw(CarDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteU8(v.Id); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Make); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Model); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.PurchaseAuthority); err != nil {
+		return err
+	}
+	if err := e.WritePublicKey(v.OperatingAuthority); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeCar(d *BorshDecoder) (v Car, err error) {
+	if err = d.checkDiscriminator(CarDiscriminator, "Car"); err != nil {
+		return
+	}
+	if v.Id, err = d.ReadU8(); err != nil {
+		return
+	}
+	if v.Make, err = d.ReadString(); err != nil {
+		return
+	}
+	if v.Model, err = d.ReadString(); err != nil {
+		return
+	}
+	if v.PurchaseAuthority, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	if v.OperatingAuthority, err = d.ReadPublicKey(); err != nil {
+		return
+	}
+	return
+}
+
+var TagDiscriminator = [8]byte{62, 126, 95, 189, 228, 237, 42, 150}
+
+type Tag struct {
+	Field0 uint8
+	Field1 string
+}
+
+func EncodeTag(e *BorshEncoder, v Tag) error {
+	if err := e.WriteRaw(TagDiscriminator[:]); err != nil {
+		return err
+	}
+	if err := e.WriteU8(v.Field0); err != nil {
+		return err
+	}
+	if err := e.WriteString(v.Field1); err != nil {
+		return err
+	}
+	return nil
+}
+
+func DecodeTag(d *BorshDecoder) (v Tag, err error) {
+	if err = d.checkDiscriminator(TagDiscriminator, "Tag"); err != nil {
+		return
+	}
+	if v.Field0, err = d.ReadU8(); err != nil {
+		return
+	}
+	if v.Field1, err = d.ReadString(); err != nil {
+		return
+	}
+	return
+}
+
+// DecodePersonAccount decodes a `Person` account's data, checking its discriminator.
+func DecodePersonAccount(data []byte) (Person, error) {
+	return DecodePerson(NewBorshDecoder(data))
+}
+
+// FindPersonAddress derives the address of the `Person` record with the given primary key.
+func FindPersonAddress(programID solana.PublicKey, primaryKey uint8) (solana.PublicKey, uint8, error) {
+	return solana.FindProgramAddress([][]byte{[]byte("person"), {primaryKey}}, programID)
+}
+
+// FindNextPersonAddress derives the address of the next `Person` record from the
+// Nautilus Index, returning it along with the record's primary key.
+func FindNextPersonAddress(programID solana.PublicKey, index NautilusIndex) (solana.PublicKey, uint8, error) {
+	primaryKey := uint8(index.NextCount("person"))
+	address, _, err := FindPersonAddress(programID, primaryKey)
+	return address, primaryKey, err
+}
+
+// DecodeHomeAccount decodes a `Home` account's data, checking its discriminator.
+func DecodeHomeAccount(data []byte) (Home, error) {
+	return DecodeHome(NewBorshDecoder(data))
+}
+
+// FindHomeAddress derives the address of the `Home` record with the given primary key.
+func FindHomeAddress(programID solana.PublicKey, primaryKey uint8) (solana.PublicKey, uint8, error) {
+	return solana.FindProgramAddress([][]byte{[]byte("home"), {primaryKey}}, programID)
+}
+
+// DecodeCarAccount decodes a `Car` account's data, checking its discriminator.
+func DecodeCarAccount(data []byte) (Car, error) {
+	return DecodeCar(NewBorshDecoder(data))
+}
+
+// FindCarAddress derives the address of the `Car` record with the given primary key.
+func FindCarAddress(programID solana.PublicKey, primaryKey uint8) (solana.PublicKey, uint8, error) {
+	return solana.FindProgramAddress([][]byte{[]byte("car"), {primaryKey}}, programID)
+}
+
+// FindNextCarAddress derives the address of the next `Car` record from the
+// Nautilus Index, returning it along with the record's primary key.
+func FindNextCarAddress(programID solana.PublicKey, index NautilusIndex) (solana.PublicKey, uint8, error) {
+	primaryKey := uint8(index.NextCount("car"))
+	address, _, err := FindCarAddress(programID, primaryKey)
+	return address, primaryKey, err
+}
+
+// DecodeTagAccount decodes a `Tag` account's data, checking its discriminator.
+func DecodeTagAccount(data []byte) (Tag, error) {
+	return DecodeTag(NewBorshDecoder(data))
+}
+
+// FindTagAddress derives the address of the `Tag` record with the given primary key.
+func FindTagAddress(programID solana.PublicKey, primaryKey uint8) (solana.PublicKey, uint8, error) {
+	return solana.FindProgramAddress([][]byte{[]byte("tag"), {primaryKey}}, programID)
+}
+
+// FindNextTagAddress derives the address of the next `Tag` record from the
+// Nautilus Index, returning it along with the record's primary key.
+func FindNextTagAddress(programID solana.PublicKey, index NautilusIndex) (solana.PublicKey, uint8, error) {
+	primaryKey := uint8(index.NextCount("tag"))
+	address, _, err := FindTagAddress(programID, primaryKey)
+	return address, primaryKey, err
+}
+
+// DecodeAccount decodes an account's data based on its discriminator, returning
+// nil if it doesn't belong to any of this program's accounts.
+func DecodeAccount(data []byte) (any, error) {
+	var discriminator [8]byte
+	if len(data) < len(discriminator) {
+		return nil, nil
+	}
+	copy(discriminator[:], data)
+	switch discriminator {
+	case PersonDiscriminator:
+		return DecodePersonAccount(data)
+	case HomeDiscriminator:
+		return DecodeHomeAccount(data)
+	case CarDiscriminator:
+		return DecodeCarAccount(data)
+	case TagDiscriminator:
+		return DecodeTagAccount(data)
+	default:
+		return nil, nil
+	}
+}
+
+var NautilusIndexDiscriminator = [8]byte{201, 34, 45, 79, 218, 117, 230, 102}
+
+// NautilusIndex holds the current record count of every table.
+type NautilusIndex map[string]uint32
+
+// NextCount returns the count the next record added to a table will have.
+func (index NautilusIndex) NextCount(tableName string) uint32 {
+	return index[tableName] + 1
+}
+
+func FindNautilusIndexAddress(programID solana.PublicKey) (solana.PublicKey, uint8, error) {
+	return solana.FindProgramAddress([][]byte{[]byte("nautilus_index"), {0}}, programID)
+}
+
+func DecodeNautilusIndexAccount(data []byte) (NautilusIndex, error) {
+	d := NewBorshDecoder(data)
+	if err := d.checkDiscriminator(NautilusIndexDiscriminator, "NautilusIndex"); err != nil {
+		return nil, err
+	}
+	index, err := mapDecoder((*BorshDecoder).ReadString, (*BorshDecoder).ReadU32)(d)
+	return NautilusIndex(index), err
+}
+
+// InitializeAccounts are the accounts of a `initialize` instruction that need to be provided.
+type InitializeAccounts struct {
+	NautilusIndex solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// NewInitializeInstruction creates a `initialize` instruction.
+func NewInitializeInstruction(programID solana.PublicKey, accounts InitializeAccounts) (*solana.GenericInstruction, error) {
+	e := NewBorshEncoder()
+	if err := e.WriteU8(0); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(accounts.NautilusIndex, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreatePersonAccounts are the accounts of a `createPerson` instruction that need to be provided.
+type CreatePersonAccounts struct {
+	NewPerson solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreatePersonArgs are the arguments of a `createPerson` instruction.
+type CreatePersonArgs struct {
+	Name string
+	Authority solana.PublicKey
+}
+
+// NewCreatePersonInstruction creates a `createPerson` instruction.
+func NewCreatePersonInstruction(programID solana.PublicKey, accounts CreatePersonAccounts, args CreatePersonArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(1); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Name); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.Authority); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.NewPerson, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadPersonAccounts are the accounts of a `readPerson` instruction that need to be provided.
+type ReadPersonAccounts struct {
+	Person solana.PublicKey
+}
+
+// NewReadPersonInstruction creates a `readPerson` instruction.
+func NewReadPersonInstruction(programID solana.PublicKey, accounts ReadPersonAccounts) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(2); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, false, false),
+		solana.NewAccountMeta(accounts.Person, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateHomeAccounts are the accounts of a `createHome` instruction that need to be provided.
+type CreateHomeAccounts struct {
+	NewHome solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateHomeArgs are the arguments of a `createHome` instruction.
+type CreateHomeArgs struct {
+	Id uint8
+	HouseNumber uint8
+	Street string
+}
+
+// NewCreateHomeInstruction creates a `createHome` instruction.
+func NewCreateHomeInstruction(programID solana.PublicKey, accounts CreateHomeAccounts, args CreateHomeArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(3); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU8(args.Id); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU8(args.HouseNumber); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Street); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.NewHome, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadHomeAccounts are the accounts of a `readHome` instruction that need to be provided.
+type ReadHomeAccounts struct {
+	Home solana.PublicKey
+}
+
+// NewReadHomeInstruction creates a `readHome` instruction.
+func NewReadHomeInstruction(programID solana.PublicKey, accounts ReadHomeAccounts) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(4); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, false, false),
+		solana.NewAccountMeta(accounts.Home, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateCarAccounts are the accounts of a `createCar` instruction that need to be provided.
+type CreateCarAccounts struct {
+	NewCar solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateCarArgs are the arguments of a `createCar` instruction.
+type CreateCarArgs struct {
+	Make string
+	Model string
+	PurchaseAuthority solana.PublicKey
+	OperatingAuthority solana.PublicKey
+}
+
+// NewCreateCarInstruction creates a `createCar` instruction.
+func NewCreateCarInstruction(programID solana.PublicKey, accounts CreateCarAccounts, args CreateCarArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(5); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Make); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Model); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.PurchaseAuthority); err != nil {
+		return nil, err
+	}
+	if err := e.WritePublicKey(args.OperatingAuthority); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.NewCar, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadCarAccounts are the accounts of a `readCar` instruction that need to be provided.
+type ReadCarAccounts struct {
+	Car solana.PublicKey
+}
+
+// NewReadCarInstruction creates a `readCar` instruction.
+func NewReadCarInstruction(programID solana.PublicKey, accounts ReadCarAccounts) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(6); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, false, false),
+		solana.NewAccountMeta(accounts.Car, false, false),
+	}, e.Bytes()), nil
+}
+
+// CreateTagAccounts are the accounts of a `createTag` instruction that need to be provided.
+type CreateTagAccounts struct {
+	NewTag solana.PublicKey
+	FeePayer solana.PublicKey
+}
+
+// CreateTagArgs are the arguments of a `createTag` instruction.
+type CreateTagArgs struct {
+	Label string
+}
+
+// NewCreateTagInstruction creates a `createTag` instruction.
+func NewCreateTagInstruction(programID solana.PublicKey, accounts CreateTagAccounts, args CreateTagArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(7); err != nil {
+		return nil, err
+	}
+	if err := e.WriteString(args.Label); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.NewTag, true, false),
+		solana.NewAccountMeta(accounts.FeePayer, true, true),
+		solana.NewAccountMeta(solana.SysVarRentPubkey, false, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// ReadTagAccounts are the accounts of a `readTag` instruction that need to be provided.
+type ReadTagAccounts struct {
+	Tag solana.PublicKey
+}
+
+// NewReadTagInstruction creates a `readTag` instruction.
+func NewReadTagInstruction(programID solana.PublicKey, accounts ReadTagAccounts) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(8); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, false, false),
+		solana.NewAccountMeta(accounts.Tag, false, false),
+	}, e.Bytes()), nil
+}
+
+// FundPersonAccounts are the accounts of a `fundPerson` instruction that need to be provided.
+type FundPersonAccounts struct {
+	Payer solana.PublicKey
+	Person solana.PublicKey
+}
+
+// FundPersonArgs are the arguments of a `fundPerson` instruction.
+type FundPersonArgs struct {
+	Amount uint64
+}
+
+// NewFundPersonInstruction creates a `fundPerson` instruction.
+func NewFundPersonInstruction(programID solana.PublicKey, accounts FundPersonAccounts, args FundPersonArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(9); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Amount); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.Payer, true, true),
+		solana.NewAccountMeta(accounts.Person, true, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// TransferFromPersonAccounts are the accounts of a `transferFromPerson` instruction that need to be provided.
+type TransferFromPersonAccounts struct {
+	Person solana.PublicKey
+	Recipient solana.PublicKey
+}
+
+// TransferFromPersonArgs are the arguments of a `transferFromPerson` instruction.
+type TransferFromPersonArgs struct {
+	Amount uint64
+}
+
+// NewTransferFromPersonInstruction creates a `transferFromPerson` instruction.
+func NewTransferFromPersonInstruction(programID solana.PublicKey, accounts TransferFromPersonAccounts, args TransferFromPersonArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(10); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Amount); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.Person, true, false),
+		solana.NewAccountMeta(accounts.Recipient, true, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// FundHomeAccounts are the accounts of a `fundHome` instruction that need to be provided.
+type FundHomeAccounts struct {
+	Home solana.PublicKey
+	Payer solana.PublicKey
+}
+
+// FundHomeArgs are the arguments of a `fundHome` instruction.
+type FundHomeArgs struct {
+	Amount uint64
+}
+
+// NewFundHomeInstruction creates a `fundHome` instruction.
+func NewFundHomeInstruction(programID solana.PublicKey, accounts FundHomeAccounts, args FundHomeArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(11); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Amount); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.Home, true, false),
+		solana.NewAccountMeta(accounts.Payer, true, true),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// TransferFromHomeAccounts are the accounts of a `transferFromHome` instruction that need to be provided.
+type TransferFromHomeAccounts struct {
+	Home solana.PublicKey
+	Recipient solana.PublicKey
+}
+
+// TransferFromHomeArgs are the arguments of a `transferFromHome` instruction.
+type TransferFromHomeArgs struct {
+	Amount uint64
+}
+
+// NewTransferFromHomeInstruction creates a `transferFromHome` instruction.
+func NewTransferFromHomeInstruction(programID solana.PublicKey, accounts TransferFromHomeAccounts, args TransferFromHomeArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(12); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Amount); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.Home, true, false),
+		solana.NewAccountMeta(accounts.Recipient, true, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// FundCarAccounts are the accounts of a `fundCar` instruction that need to be provided.
+type FundCarAccounts struct {
+	Car solana.PublicKey
+	Payer solana.PublicKey
+}
+
+// FundCarArgs are the arguments of a `fundCar` instruction.
+type FundCarArgs struct {
+	Amount uint64
+}
+
+// NewFundCarInstruction creates a `fundCar` instruction.
+func NewFundCarInstruction(programID solana.PublicKey, accounts FundCarAccounts, args FundCarArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(13); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Amount); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.Car, true, false),
+		solana.NewAccountMeta(accounts.Payer, true, true),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
+
+// TransferFromCarAccounts are the accounts of a `transferFromCar` instruction that need to be provided.
+type TransferFromCarAccounts struct {
+	Car solana.PublicKey
+	Recipient solana.PublicKey
+}
+
+// TransferFromCarArgs are the arguments of a `transferFromCar` instruction.
+type TransferFromCarArgs struct {
+	Amount uint64
+}
+
+// NewTransferFromCarInstruction creates a `transferFromCar` instruction.
+func NewTransferFromCarInstruction(programID solana.PublicKey, accounts TransferFromCarAccounts, args TransferFromCarArgs) (*solana.GenericInstruction, error) {
+	indexAddress, _, err := FindNautilusIndexAddress(programID)
+	if err != nil {
+		return nil, err
+	}
+	e := NewBorshEncoder()
+	if err := e.WriteU8(14); err != nil {
+		return nil, err
+	}
+	if err := e.WriteU64(args.Amount); err != nil {
+		return nil, err
+	}
+	return solana.NewInstruction(programID, solana.AccountMetaSlice{
+		solana.NewAccountMeta(indexAddress, true, false),
+		solana.NewAccountMeta(accounts.Car, true, false),
+		solana.NewAccountMeta(accounts.Recipient, true, false),
+		solana.NewAccountMeta(solana.SystemProgramID, false, false),
+	}, e.Bytes()), nil
+}
@@ -0,0 +1,550 @@
+# Generated by Nautilus from the `program-nautilus` IDL (v0.1.0). Do not edit by hand.
+from __future__ import annotations
+
+import typing
+from dataclasses import dataclass, fields
+
+import borsh_construct as borsh
+from construct import Adapter, Array, Construct
+from construct import Bytes as FixedBytes
+from solders.instruction import AccountMeta, Instruction
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.sysvar import CLOCK, INSTRUCTIONS, RENT
+
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+ASSOCIATED_TOKEN_PROGRAM_ID = Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+TOKEN_METADATA_PROGRAM_ID = Pubkey.from_string("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+
+
+class Rpc(typing.Protocol):
+    """Any RPC client that can fetch accounts, such as `solana.rpc.async_api.AsyncClient`."""
+
+    async def get_account_info(self, pubkey: Pubkey) -> typing.Any:
+        ...
+
+
+async def _fetch_account_data(rpc: Rpc, address: Pubkey) -> typing.Optional[bytes]:
+    response = await rpc.get_account_info(address)
+    return None if response.value is None else bytes(response.value.data)
+
+
+class _PublicKey(Adapter):
+    def _decode(self, obj, context, path):
+        return Pubkey.from_bytes(obj)
+
+    def _encode(self, obj, context, path):
+        return bytes(obj)
+
+
+class _Tuple(Adapter):
+    def _decode(self, obj, context, path):
+        return tuple(obj)
+
+    def _encode(self, obj, context, path):
+        return list(obj)
+
+
+class _Dataclass(Adapter):
+    """Converts between a `CStruct` container and a dataclass."""
+
+    def __init__(self, cls, subcon):
+        super().__init__(subcon)
+        self.cls = cls
+
+    def _decode(self, obj, context, path):
+        return self.cls(**{f.name: obj[f.name] for f in fields(self.cls)})
+
+    def _encode(self, obj, context, path):
+        return {f.name: getattr(obj, f.name) for f in fields(self.cls)}
+
+
+class _Enum(Construct):
+    """A `u8` variant index followed by the variant's fields."""
+
+    def __init__(self, variants: typing.List[_Dataclass]):
+        super().__init__()
+        self.variants = variants
+
+    def _parse(self, stream, context, path):
+        index = borsh.U8._parsereport(stream, context, path)
+        return self.variants[index]._parsereport(stream, context, path)
+
+    def _build(self, obj, stream, context, path):
+        index = next(i for i, v in enumerate(self.variants) if isinstance(obj, v.cls))
+        borsh.U8._build(index, stream, context, path)
+        return self.variants[index]._build(obj, stream, context, path)
+
+
+class _Discriminated(Construct):
+    """Prefixes a layout with an account's 8-byte discriminator, the way the Nautilus runtime serializes accounts."""
+
+    def __init__(self, discriminator: bytes, subcon: Construct):
+        super().__init__()
+        self.discriminator = discriminator
+        self.subcon = subcon
+
+    def _parse(self, stream, context, path):
+        if FixedBytes(8)._parsereport(stream, context, path) != self.discriminator:
+            raise ValueError("Invalid account discriminator")
+        return self.subcon._parsereport(stream, context, path)
+
+    def _build(self, obj, stream, context, path):
+        FixedBytes(8)._build(self.discriminator, stream, context, path)
+        return self.subcon._build(obj, stream, context, path)
+
+
+PUBLIC_KEY = _PublicKey(FixedBytes(32))
+
+
+PERSON_DISCRIMINATOR = bytes([38, 10, 62, 51, 134, 223, 96, 156])
+
+
+@dataclass
+class Person:
+    id: int
+    name: str
+    authority: Pubkey
+
+
+def person_layout() -> Construct:
+    return _Discriminated(PERSON_DISCRIMINATOR, _Dataclass(Person, borsh.CStruct("id" / borsh.U8, "name" / borsh.String, "authority" / PUBLIC_KEY)))
+
+
+HOME_DISCRIMINATOR = bytes([207, 164, 88, 52, 87, 254, 75, 93])
+
+
+@dataclass
+class Home:
+    id: int
+    house_number: int
+    street: str
+
+
+def home_layout() -> Construct:
+    return _Discriminated(HOME_DISCRIMINATOR, _Dataclass(Home, borsh.CStruct("id" / borsh.U8, "house_number" / borsh.U8, "street" / borsh.String)))
+
+
+CAR_DISCRIMINATOR = bytes([84, 120, 193, 54, 102, 120, 204, 93])
+
+
+@dataclass
+class Car:
+    id: int
+    make: str
+    model: str
+    purchase_authority: Pubkey
+    operating_authority: Pubkey
+
+
+def car_layout() -> Construct:
+    return _Discriminated(CAR_DISCRIMINATOR, _Dataclass(Car, borsh.CStruct("id" / borsh.U8, "make" / borsh.String, "model" / borsh.String, "purchase_authority" / PUBLIC_KEY, "operating_authority" / PUBLIC_KEY)))
+
+
+TAG_DISCRIMINATOR = bytes([62, 126, 95, 189, 228, 237, 42, 150])
+
+
+@dataclass
+class Tag:
+    field_0: int
+    field_1: str
+
+
+def tag_layout() -> Construct:
+    return _Discriminated(TAG_DISCRIMINATOR, _Dataclass(Tag, borsh.CStruct("field_0" / borsh.U8, "field_1" / borsh.String)))
+
+
+def decode_person(data: bytes) -> Person:
+    if data[:8] != PERSON_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Person`")
+    return person_layout().parse(data)
+
+
+async def fetch_person(rpc: Rpc, address: Pubkey) -> typing.Optional[Person]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_person(data)
+
+
+def find_person_address(program_id: Pubkey, primary_key: int) -> Pubkey:
+    return Pubkey.find_program_address(["person".encode(), borsh.U8.build(primary_key)], program_id)[0]
+
+
+async def find_next_person_address(rpc: Rpc, program_id: Pubkey) -> typing.Tuple[Pubkey, int]:
+    """Derives the address of the next `Person` record, based on the record count in the Nautilus Index."""
+    index = await fetch_nautilus_index(rpc, program_id)
+    primary_key = (index or {}).get("person", 0) + 1
+    return find_person_address(program_id, primary_key), primary_key
+
+
+def decode_home(data: bytes) -> Home:
+    if data[:8] != HOME_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Home`")
+    return home_layout().parse(data)
+
+
+async def fetch_home(rpc: Rpc, address: Pubkey) -> typing.Optional[Home]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_home(data)
+
+
+def find_home_address(program_id: Pubkey, primary_key: int) -> Pubkey:
+    return Pubkey.find_program_address(["home".encode(), borsh.U8.build(primary_key)], program_id)[0]
+
+
+def decode_car(data: bytes) -> Car:
+    if data[:8] != CAR_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Car`")
+    return car_layout().parse(data)
+
+
+async def fetch_car(rpc: Rpc, address: Pubkey) -> typing.Optional[Car]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_car(data)
+
+
+def find_car_address(program_id: Pubkey, primary_key: int) -> Pubkey:
+    return Pubkey.find_program_address(["car".encode(), borsh.U8.build(primary_key)], program_id)[0]
+
+
+async def find_next_car_address(rpc: Rpc, program_id: Pubkey) -> typing.Tuple[Pubkey, int]:
+    """Derives the address of the next `Car` record, based on the record count in the Nautilus Index."""
+    index = await fetch_nautilus_index(rpc, program_id)
+    primary_key = (index or {}).get("car", 0) + 1
+    return find_car_address(program_id, primary_key), primary_key
+
+
+def decode_tag(data: bytes) -> Tag:
+    if data[:8] != TAG_DISCRIMINATOR:
+        raise ValueError("Account data is not a `Tag`")
+    return tag_layout().parse(data)
+
+
+async def fetch_tag(rpc: Rpc, address: Pubkey) -> typing.Optional[Tag]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_tag(data)
+
+
+def find_tag_address(program_id: Pubkey, primary_key: int) -> Pubkey:
+    return Pubkey.find_program_address(["tag".encode(), borsh.U8.build(primary_key)], program_id)[0]
+
+
+async def find_next_tag_address(rpc: Rpc, program_id: Pubkey) -> typing.Tuple[Pubkey, int]:
+    """Derives the address of the next `Tag` record, based on the record count in the Nautilus Index."""
+    index = await fetch_nautilus_index(rpc, program_id)
+    primary_key = (index or {}).get("tag", 0) + 1
+    return find_tag_address(program_id, primary_key), primary_key
+
+
+DecodedAccount = typing.Union[Person, Home, Car, Tag]
+
+_ACCOUNT_DECODERS = {
+    PERSON_DISCRIMINATOR: decode_person,
+    HOME_DISCRIMINATOR: decode_home,
+    CAR_DISCRIMINATOR: decode_car,
+    TAG_DISCRIMINATOR: decode_tag,
+}
+
+
+def decode_account(data: bytes) -> typing.Optional[DecodedAccount]:
+    decoder = _ACCOUNT_DECODERS.get(data[:8])
+    return None if decoder is None else decoder(data)
+
+
+async def fetch_account(rpc: Rpc, address: Pubkey) -> typing.Optional[DecodedAccount]:
+    data = await _fetch_account_data(rpc, address)
+    return None if data is None else decode_account(data)
+
+
+NAUTILUS_INDEX_DISCRIMINATOR = bytes([201, 34, 45, 79, 218, 117, 230, 102])
+
+
+def find_nautilus_index_address(program_id: Pubkey) -> Pubkey:
+    return Pubkey.find_program_address([b"nautilus_index", bytes([0])], program_id)[0]
+
+
+async def fetch_nautilus_index(rpc: Rpc, program_id: Pubkey) -> typing.Optional[typing.Dict[str, int]]:
+    data = await _fetch_account_data(rpc, find_nautilus_index_address(program_id))
+    if data is None:
+        return None
+    if data[:8] != NAUTILUS_INDEX_DISCRIMINATOR:
+        raise ValueError("Account data is not the Nautilus Index")
+    return borsh.HashMap(borsh.String, borsh.U32).parse(data[8:])
+
+
+class InitializeAccounts(typing.TypedDict):
+    nautilus_index: Pubkey
+    fee_payer: Pubkey
+
+
+def create_initialize_instruction(program_id: Pubkey, accounts: InitializeAccounts) -> Instruction:
+    keys = [
+        AccountMeta(accounts["nautilus_index"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 0})
+    return Instruction(program_id, data, keys)
+
+
+class CreatePersonAccounts(typing.TypedDict):
+    new_person: Pubkey
+    fee_payer: Pubkey
+
+
+class CreatePersonArgs(typing.TypedDict):
+    name: str
+    authority: Pubkey
+
+
+def create_create_person_instruction(program_id: Pubkey, accounts: CreatePersonAccounts, args: CreatePersonArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["new_person"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "name" / borsh.String, "authority" / PUBLIC_KEY).build({"instruction": 1, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadPersonAccounts(typing.TypedDict):
+    person: Pubkey
+
+
+def create_read_person_instruction(program_id: Pubkey, accounts: ReadPersonAccounts) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=False),
+        AccountMeta(accounts["person"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 2})
+    return Instruction(program_id, data, keys)
+
+
+class CreateHomeAccounts(typing.TypedDict):
+    new_home: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateHomeArgs(typing.TypedDict):
+    id: int
+    house_number: int
+    street: str
+
+
+def create_create_home_instruction(program_id: Pubkey, accounts: CreateHomeAccounts, args: CreateHomeArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["new_home"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "id" / borsh.U8, "house_number" / borsh.U8, "street" / borsh.String).build({"instruction": 3, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadHomeAccounts(typing.TypedDict):
+    home: Pubkey
+
+
+def create_read_home_instruction(program_id: Pubkey, accounts: ReadHomeAccounts) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=False),
+        AccountMeta(accounts["home"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 4})
+    return Instruction(program_id, data, keys)
+
+
+class CreateCarAccounts(typing.TypedDict):
+    new_car: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateCarArgs(typing.TypedDict):
+    make: str
+    model: str
+    purchase_authority: Pubkey
+    operating_authority: Pubkey
+
+
+def create_create_car_instruction(program_id: Pubkey, accounts: CreateCarAccounts, args: CreateCarArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["new_car"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "make" / borsh.String, "model" / borsh.String, "purchase_authority" / PUBLIC_KEY, "operating_authority" / PUBLIC_KEY).build({"instruction": 5, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadCarAccounts(typing.TypedDict):
+    car: Pubkey
+
+
+def create_read_car_instruction(program_id: Pubkey, accounts: ReadCarAccounts) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=False),
+        AccountMeta(accounts["car"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 6})
+    return Instruction(program_id, data, keys)
+
+
+class CreateTagAccounts(typing.TypedDict):
+    new_tag: Pubkey
+    fee_payer: Pubkey
+
+
+class CreateTagArgs(typing.TypedDict):
+    label: str
+
+
+def create_create_tag_instruction(program_id: Pubkey, accounts: CreateTagAccounts, args: CreateTagArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["new_tag"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["fee_payer"], is_signer=True, is_writable=True),
+        AccountMeta(RENT, is_signer=False, is_writable=False),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "label" / borsh.String).build({"instruction": 7, **args})
+    return Instruction(program_id, data, keys)
+
+
+class ReadTagAccounts(typing.TypedDict):
+    tag: Pubkey
+
+
+def create_read_tag_instruction(program_id: Pubkey, accounts: ReadTagAccounts) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=False),
+        AccountMeta(accounts["tag"], is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8).build({"instruction": 8})
+    return Instruction(program_id, data, keys)
+
+
+class FundPersonAccounts(typing.TypedDict):
+    payer: Pubkey
+    person: Pubkey
+
+
+class FundPersonArgs(typing.TypedDict):
+    amount: int
+
+
+def create_fund_person_instruction(program_id: Pubkey, accounts: FundPersonAccounts, args: FundPersonArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(accounts["person"], is_signer=False, is_writable=True),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "amount" / borsh.U64).build({"instruction": 9, **args})
+    return Instruction(program_id, data, keys)
+
+
+class TransferFromPersonAccounts(typing.TypedDict):
+    person: Pubkey
+    recipient: Pubkey
+
+
+class TransferFromPersonArgs(typing.TypedDict):
+    amount: int
+
+
+def create_transfer_from_person_instruction(program_id: Pubkey, accounts: TransferFromPersonAccounts, args: TransferFromPersonArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["person"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["recipient"], is_signer=False, is_writable=True),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "amount" / borsh.U64).build({"instruction": 10, **args})
+    return Instruction(program_id, data, keys)
+
+
+class FundHomeAccounts(typing.TypedDict):
+    home: Pubkey
+    payer: Pubkey
+
+
+class FundHomeArgs(typing.TypedDict):
+    amount: int
+
+
+def create_fund_home_instruction(program_id: Pubkey, accounts: FundHomeAccounts, args: FundHomeArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["home"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "amount" / borsh.U64).build({"instruction": 11, **args})
+    return Instruction(program_id, data, keys)
+
+
+class TransferFromHomeAccounts(typing.TypedDict):
+    home: Pubkey
+    recipient: Pubkey
+
+
+class TransferFromHomeArgs(typing.TypedDict):
+    amount: int
+
+
+def create_transfer_from_home_instruction(program_id: Pubkey, accounts: TransferFromHomeAccounts, args: TransferFromHomeArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["home"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["recipient"], is_signer=False, is_writable=True),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "amount" / borsh.U64).build({"instruction": 12, **args})
+    return Instruction(program_id, data, keys)
+
+
+class FundCarAccounts(typing.TypedDict):
+    car: Pubkey
+    payer: Pubkey
+
+
+class FundCarArgs(typing.TypedDict):
+    amount: int
+
+
+def create_fund_car_instruction(program_id: Pubkey, accounts: FundCarAccounts, args: FundCarArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["car"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "amount" / borsh.U64).build({"instruction": 13, **args})
+    return Instruction(program_id, data, keys)
+
+
+class TransferFromCarAccounts(typing.TypedDict):
+    car: Pubkey
+    recipient: Pubkey
+
+
+class TransferFromCarArgs(typing.TypedDict):
+    amount: int
+
+
+def create_transfer_from_car_instruction(program_id: Pubkey, accounts: TransferFromCarAccounts, args: TransferFromCarArgs) -> Instruction:
+    keys = [
+        AccountMeta(find_nautilus_index_address(program_id), is_signer=False, is_writable=True),
+        AccountMeta(accounts["car"], is_signer=False, is_writable=True),
+        AccountMeta(accounts["recipient"], is_signer=False, is_writable=True),
+        AccountMeta(SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
+    ]
+    data = borsh.CStruct("instruction" / borsh.U8, "amount" / borsh.U64).build({"instruction": 14, **args})
+    return Instruction(program_id, data, keys)
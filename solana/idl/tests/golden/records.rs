@@ -0,0 +1,639 @@
+//! Generated by Nautilus from the `program-nautilus` IDL (v0.1.0). Do not edit by hand.
+#![allow(dead_code, unused_imports)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{pubkey, pubkey::Pubkey};
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Reads and checks an account's 8-byte discriminator.
+fn check_discriminator(buf: &mut &[u8], discriminator: [u8; 8], name: &str) -> std::io::Result<()> {
+    let data_discriminator: [u8; 8] = BorshDeserialize::deserialize(buf)?;
+    match data_discriminator == discriminator {
+        true => Ok(()),
+        false => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Account data is not a `{}`", name),
+        )),
+    }
+}
+
+pub const PERSON_DISCRIMINATOR: [u8; 8] = [38, 10, 62, 51, 134, 223, 96, 156];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Person {
+    pub id: u8,
+    pub name: String,
+    pub authority: Pubkey,
+}
+
+impl BorshSerialize for Person {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        PERSON_DISCRIMINATOR.serialize(writer)?;
+        self.id.serialize(writer)?;
+        self.name.serialize(writer)?;
+        self.authority.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Person {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, PERSON_DISCRIMINATOR, "Person")?;
+        Ok(Self { id: BorshDeserialize::deserialize(buf)?, name: BorshDeserialize::deserialize(buf)?, authority: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const HOME_DISCRIMINATOR: [u8; 8] = [207, 164, 88, 52, 87, 254, 75, 93];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Home {
+    pub id: u8,
+    pub house_number: u8,
+    pub street: String,
+}
+
+impl BorshSerialize for Home {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        HOME_DISCRIMINATOR.serialize(writer)?;
+        self.id.serialize(writer)?;
+        self.house_number.serialize(writer)?;
+        self.street.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Home {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, HOME_DISCRIMINATOR, "Home")?;
+        Ok(Self { id: BorshDeserialize::deserialize(buf)?, house_number: BorshDeserialize::deserialize(buf)?, street: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const CAR_DISCRIMINATOR: [u8; 8] = [84, 120, 193, 54, 102, 120, 204, 93];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Car {
+    pub id: u8,
+    pub make: String,
+    pub model: String,
+    pub purchase_authority: Pubkey,
+    pub operating_authority: Pubkey,
+}
+
+impl BorshSerialize for Car {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        CAR_DISCRIMINATOR.serialize(writer)?;
+        self.id.serialize(writer)?;
+        self.make.serialize(writer)?;
+        self.model.serialize(writer)?;
+        self.purchase_authority.serialize(writer)?;
+        self.operating_authority.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Car {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, CAR_DISCRIMINATOR, "Car")?;
+        Ok(Self { id: BorshDeserialize::deserialize(buf)?, make: BorshDeserialize::deserialize(buf)?, model: BorshDeserialize::deserialize(buf)?, purchase_authority: BorshDeserialize::deserialize(buf)?, operating_authority: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub const TAG_DISCRIMINATOR: [u8; 8] = [62, 126, 95, 189, 228, 237, 42, 150];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tag(pub u8, pub String);
+
+impl BorshSerialize for Tag {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        TAG_DISCRIMINATOR.serialize(writer)?;
+        self.0.serialize(writer)?;
+        self.1.serialize(writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Tag {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, TAG_DISCRIMINATOR, "Tag")?;
+        Ok(Self(BorshDeserialize::deserialize(buf)?, BorshDeserialize::deserialize(buf)?))
+    }
+}
+
+/// Decodes a `Person` account's data, checking its discriminator.
+pub fn decode_person(data: &[u8]) -> std::io::Result<Person> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Person` record with the given primary key.
+pub fn find_person_address(program_id: &Pubkey, primary_key: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["person".as_bytes(), &primary_key.to_le_bytes()], program_id)
+}
+
+/// Derives the address of the next `Person` record from the Nautilus Index,
+/// returning it along with the record's primary key.
+pub fn find_next_person_address(program_id: &Pubkey, index: &NautilusIndex) -> (Pubkey, u8) {
+    let primary_key: u8 = index.next_count("person").try_into().unwrap();
+    (find_person_address(program_id, primary_key).0, primary_key)
+}
+
+/// Decodes a `Home` account's data, checking its discriminator.
+pub fn decode_home(data: &[u8]) -> std::io::Result<Home> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Home` record with the given primary key.
+pub fn find_home_address(program_id: &Pubkey, primary_key: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["home".as_bytes(), &primary_key.to_le_bytes()], program_id)
+}
+
+/// Decodes a `Car` account's data, checking its discriminator.
+pub fn decode_car(data: &[u8]) -> std::io::Result<Car> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Car` record with the given primary key.
+pub fn find_car_address(program_id: &Pubkey, primary_key: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["car".as_bytes(), &primary_key.to_le_bytes()], program_id)
+}
+
+/// Derives the address of the next `Car` record from the Nautilus Index,
+/// returning it along with the record's primary key.
+pub fn find_next_car_address(program_id: &Pubkey, index: &NautilusIndex) -> (Pubkey, u8) {
+    let primary_key: u8 = index.next_count("car").try_into().unwrap();
+    (find_car_address(program_id, primary_key).0, primary_key)
+}
+
+/// Decodes a `Tag` account's data, checking its discriminator.
+pub fn decode_tag(data: &[u8]) -> std::io::Result<Tag> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+/// Derives the address of the `Tag` record with the given primary key.
+pub fn find_tag_address(program_id: &Pubkey, primary_key: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["tag".as_bytes(), &primary_key.to_le_bytes()], program_id)
+}
+
+/// Derives the address of the next `Tag` record from the Nautilus Index,
+/// returning it along with the record's primary key.
+pub fn find_next_tag_address(program_id: &Pubkey, index: &NautilusIndex) -> (Pubkey, u8) {
+    let primary_key: u8 = index.next_count("tag").try_into().unwrap();
+    (find_tag_address(program_id, primary_key).0, primary_key)
+}
+
+/// Any of this program's accounts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodedAccount {
+    Person(Person),
+    Home(Home),
+    Car(Car),
+    Tag(Tag),
+}
+
+/// Decodes an account's data based on its discriminator, returning `None` if
+/// it doesn't belong to any of this program's accounts.
+pub fn decode_account(data: &[u8]) -> std::io::Result<Option<DecodedAccount>> {
+    let discriminator: [u8; 8] = match data.get(..8) {
+        Some(discriminator) => discriminator.try_into().unwrap(),
+        None => return Ok(None),
+    };
+    Ok(Some(match discriminator {
+        PERSON_DISCRIMINATOR => DecodedAccount::Person(decode_person(data)?),
+        HOME_DISCRIMINATOR => DecodedAccount::Home(decode_home(data)?),
+        CAR_DISCRIMINATOR => DecodedAccount::Car(decode_car(data)?),
+        TAG_DISCRIMINATOR => DecodedAccount::Tag(decode_tag(data)?),
+        _ => return Ok(None),
+    }))
+}
+
+pub const NAUTILUS_INDEX_DISCRIMINATOR: [u8; 8] = [201, 34, 45, 79, 218, 117, 230, 102];
+
+/// The Nautilus Index, which holds the current record count of every table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NautilusIndex {
+    pub index: std::collections::HashMap<String, u32>,
+}
+
+impl NautilusIndex {
+    /// The count the next record added to a table will have.
+    pub fn next_count(&self, table_name: &str) -> u32 {
+        self.index.get(table_name).map_or(1, |count| count + 1)
+    }
+}
+
+impl BorshSerialize for NautilusIndex {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        NAUTILUS_INDEX_DISCRIMINATOR.serialize(writer)?;
+        self.index.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for NautilusIndex {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        check_discriminator(buf, NAUTILUS_INDEX_DISCRIMINATOR, "NautilusIndex")?;
+        Ok(Self { index: BorshDeserialize::deserialize(buf)? })
+    }
+}
+
+pub fn find_nautilus_index_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nautilus_index", &[0]], program_id)
+}
+
+pub fn decode_nautilus_index(data: &[u8]) -> std::io::Result<NautilusIndex> {
+    BorshDeserialize::deserialize(&mut &data[..])
+}
+
+pub mod instruction {
+    use solana_program::{
+        instruction::{AccountMeta, Instruction},
+        system_program, sysvar,
+    };
+
+    use super::*;
+
+    /// The accounts for `initialize` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct InitializeAccounts {
+        pub nautilus_index: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// Creates a `initialize` instruction.
+    pub fn initialize(program_id: &Pubkey, accounts: &InitializeAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(accounts.nautilus_index, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = vec![0];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreatePersonAccounts {
+        pub new_person: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createPerson`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreatePersonArgs {
+        pub name: String,
+        pub authority: Pubkey,
+    }
+
+    /// Creates a `createPerson` instruction.
+    pub fn create_person(program_id: &Pubkey, accounts: &CreatePersonAccounts, args: &CreatePersonArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.new_person, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![1];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadPersonAccounts {
+        pub person: Pubkey,
+    }
+
+    /// Creates a `readPerson` instruction.
+    pub fn read_person(program_id: &Pubkey, accounts: &ReadPersonAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new_readonly(accounts.person, false),
+        ];
+        let data = vec![2];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createHome` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateHomeAccounts {
+        pub new_home: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createHome`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateHomeArgs {
+        pub id: u8,
+        pub house_number: u8,
+        pub street: String,
+    }
+
+    /// Creates a `createHome` instruction.
+    pub fn create_home(program_id: &Pubkey, accounts: &CreateHomeAccounts, args: &CreateHomeArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.new_home, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![3];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readHome` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadHomeAccounts {
+        pub home: Pubkey,
+    }
+
+    /// Creates a `readHome` instruction.
+    pub fn read_home(program_id: &Pubkey, accounts: &ReadHomeAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new_readonly(accounts.home, false),
+        ];
+        let data = vec![4];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createCar` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateCarAccounts {
+        pub new_car: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createCar`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateCarArgs {
+        pub make: String,
+        pub model: String,
+        pub purchase_authority: Pubkey,
+        pub operating_authority: Pubkey,
+    }
+
+    /// Creates a `createCar` instruction.
+    pub fn create_car(program_id: &Pubkey, accounts: &CreateCarAccounts, args: &CreateCarArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.new_car, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![5];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readCar` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadCarAccounts {
+        pub car: Pubkey,
+    }
+
+    /// Creates a `readCar` instruction.
+    pub fn read_car(program_id: &Pubkey, accounts: &ReadCarAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new_readonly(accounts.car, false),
+        ];
+        let data = vec![6];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `createTag` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct CreateTagAccounts {
+        pub new_tag: Pubkey,
+        pub fee_payer: Pubkey,
+    }
+
+    /// The arguments for `createTag`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct CreateTagArgs {
+        pub label: String,
+    }
+
+    /// Creates a `createTag` instruction.
+    pub fn create_tag(program_id: &Pubkey, accounts: &CreateTagAccounts, args: &CreateTagArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.new_tag, false),
+            AccountMeta::new(accounts.fee_payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![7];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `readTag` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ReadTagAccounts {
+        pub tag: Pubkey,
+    }
+
+    /// Creates a `readTag` instruction.
+    pub fn read_tag(program_id: &Pubkey, accounts: &ReadTagAccounts) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new_readonly(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new_readonly(accounts.tag, false),
+        ];
+        let data = vec![8];
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `fundPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FundPersonAccounts {
+        pub payer: Pubkey,
+        pub person: Pubkey,
+    }
+
+    /// The arguments for `fundPerson`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct FundPersonArgs {
+        pub amount: u64,
+    }
+
+    /// Creates a `fundPerson` instruction.
+    pub fn fund_person(program_id: &Pubkey, accounts: &FundPersonAccounts, args: &FundPersonArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.payer, true),
+            AccountMeta::new(accounts.person, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![9];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `transferFromPerson` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct TransferFromPersonAccounts {
+        pub person: Pubkey,
+        pub recipient: Pubkey,
+    }
+
+    /// The arguments for `transferFromPerson`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct TransferFromPersonArgs {
+        pub amount: u64,
+    }
+
+    /// Creates a `transferFromPerson` instruction.
+    pub fn transfer_from_person(program_id: &Pubkey, accounts: &TransferFromPersonAccounts, args: &TransferFromPersonArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.person, false),
+            AccountMeta::new(accounts.recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![10];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `fundHome` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FundHomeAccounts {
+        pub home: Pubkey,
+        pub payer: Pubkey,
+    }
+
+    /// The arguments for `fundHome`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct FundHomeArgs {
+        pub amount: u64,
+    }
+
+    /// Creates a `fundHome` instruction.
+    pub fn fund_home(program_id: &Pubkey, accounts: &FundHomeAccounts, args: &FundHomeArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.home, false),
+            AccountMeta::new(accounts.payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![11];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `transferFromHome` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct TransferFromHomeAccounts {
+        pub home: Pubkey,
+        pub recipient: Pubkey,
+    }
+
+    /// The arguments for `transferFromHome`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct TransferFromHomeArgs {
+        pub amount: u64,
+    }
+
+    /// Creates a `transferFromHome` instruction.
+    pub fn transfer_from_home(program_id: &Pubkey, accounts: &TransferFromHomeAccounts, args: &TransferFromHomeArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.home, false),
+            AccountMeta::new(accounts.recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![12];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `fundCar` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FundCarAccounts {
+        pub car: Pubkey,
+        pub payer: Pubkey,
+    }
+
+    /// The arguments for `fundCar`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct FundCarArgs {
+        pub amount: u64,
+    }
+
+    /// Creates a `fundCar` instruction.
+    pub fn fund_car(program_id: &Pubkey, accounts: &FundCarAccounts, args: &FundCarArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.car, false),
+            AccountMeta::new(accounts.payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![13];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+
+    /// The accounts for `transferFromCar` that need to be provided.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct TransferFromCarAccounts {
+        pub car: Pubkey,
+        pub recipient: Pubkey,
+    }
+
+    /// The arguments for `transferFromCar`.
+    #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+    pub struct TransferFromCarArgs {
+        pub amount: u64,
+    }
+
+    /// Creates a `transferFromCar` instruction.
+    pub fn transfer_from_car(program_id: &Pubkey, accounts: &TransferFromCarAccounts, args: &TransferFromCarArgs) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(find_nautilus_index_address(program_id).0, false),
+            AccountMeta::new(accounts.car, false),
+            AccountMeta::new(accounts.recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        let data = {
+            let mut data = vec![14];
+            args.serialize(&mut data).unwrap();
+            data
+        };
+        Instruction::new_with_bytes(*program_id, &data, account_metas)
+    }
+}
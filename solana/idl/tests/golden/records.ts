@@ -0,0 +1,520 @@
+// Generated by Nautilus from the `program-nautilus` IDL (v0.1.0). Do not edit by hand.
+
+import * as borsh from "@coral-xyz/borsh"
+import BN from "bn.js"
+import { Layout } from "buffer-layout"
+import {
+    Connection,
+    PublicKey,
+    SystemProgram,
+    SYSVAR_CLOCK_PUBKEY,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    SYSVAR_RENT_PUBKEY,
+    TransactionInstruction,
+} from "@solana/web3.js"
+
+export const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
+
+/** Prefixes a layout with an account's 8-byte discriminator, the way the Nautilus runtime serializes accounts. */
+class DiscriminatedLayout<T> extends Layout<T> {
+    constructor(readonly discriminator: Buffer, readonly layout: borsh.Layout<T>, property?: string) {
+        super(-1, property)
+    }
+
+    getSpan(b: Buffer, offset = 0): number {
+        return 8 + this.layout.getSpan(b, offset + 8)
+    }
+
+    decode(b: Buffer, offset = 0): T {
+        if (!b.subarray(offset, offset + 8).equals(this.discriminator)) {
+            throw new Error("Invalid account discriminator")
+        }
+        return this.layout.decode(b, offset + 8)
+    }
+
+    encode(src: T, b: Buffer, offset = 0): number {
+        this.discriminator.copy(b, offset)
+        return 8 + this.layout.encode(src, b, offset + 8)
+    }
+}
+
+/** Serializes a single value with a Borsh layout. */
+function encode<T>(layout: borsh.Layout<T>, value: T): Buffer {
+    const buffer = Buffer.alloc(1232) // The maximum size of a transaction
+    const length = layout.encode(value, buffer)
+    return buffer.subarray(0, length)
+}
+
+export const PERSON_DISCRIMINATOR = Buffer.from([38, 10, 62, 51, 134, 223, 96, 156])
+
+export type Person = {
+    id: number
+    name: string
+    authority: PublicKey
+}
+
+export function personLayout(property?: string): borsh.Layout<Person> {
+    return new DiscriminatedLayout(PERSON_DISCRIMINATOR, borsh.struct([
+        borsh.u8("id"),
+        borsh.str("name"),
+        borsh.publicKey("authority"),
+    ]), property)
+}
+
+export const HOME_DISCRIMINATOR = Buffer.from([207, 164, 88, 52, 87, 254, 75, 93])
+
+export type Home = {
+    id: number
+    house_number: number
+    street: string
+}
+
+export function homeLayout(property?: string): borsh.Layout<Home> {
+    return new DiscriminatedLayout(HOME_DISCRIMINATOR, borsh.struct([
+        borsh.u8("id"),
+        borsh.u8("house_number"),
+        borsh.str("street"),
+    ]), property)
+}
+
+export const CAR_DISCRIMINATOR = Buffer.from([84, 120, 193, 54, 102, 120, 204, 93])
+
+export type Car = {
+    id: number
+    make: string
+    model: string
+    purchase_authority: PublicKey
+    operating_authority: PublicKey
+}
+
+export function carLayout(property?: string): borsh.Layout<Car> {
+    return new DiscriminatedLayout(CAR_DISCRIMINATOR, borsh.struct([
+        borsh.u8("id"),
+        borsh.str("make"),
+        borsh.str("model"),
+        borsh.publicKey("purchase_authority"),
+        borsh.publicKey("operating_authority"),
+    ]), property)
+}
+
+export const TAG_DISCRIMINATOR = Buffer.from([62, 126, 95, 189, 228, 237, 42, 150])
+
+export type Tag = {
+    0: number
+    1: string
+}
+
+export function tagLayout(property?: string): borsh.Layout<Tag> {
+    return new DiscriminatedLayout(TAG_DISCRIMINATOR, borsh.struct([
+        borsh.u8("0"),
+        borsh.str("1"),
+    ]), property)
+}
+
+export function decodePerson(data: Buffer): Person {
+    if (!data.subarray(0, 8).equals(PERSON_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Person`")
+    }
+    return personLayout().decode(data)
+}
+
+export async function fetchPerson(connection: Connection, address: PublicKey): Promise<Person | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodePerson(info.data)
+}
+
+export function findPersonAddress(programId: PublicKey, primaryKey: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("person"), encode(borsh.u8(), primaryKey)],
+        programId,
+    )[0]
+}
+
+/** Derives the address of the next `Person` record, based on the record count in the Nautilus Index. */
+export async function findNextPersonAddress(connection: Connection, programId: PublicKey): Promise<[PublicKey, number]> {
+    const count = (await fetchNautilusIndex(connection, programId))?.get("person") ?? 0
+    const primaryKey = count + 1
+    return [findPersonAddress(programId, primaryKey), primaryKey]
+}
+
+export function decodeHome(data: Buffer): Home {
+    if (!data.subarray(0, 8).equals(HOME_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Home`")
+    }
+    return homeLayout().decode(data)
+}
+
+export async function fetchHome(connection: Connection, address: PublicKey): Promise<Home | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeHome(info.data)
+}
+
+export function findHomeAddress(programId: PublicKey, primaryKey: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("home"), encode(borsh.u8(), primaryKey)],
+        programId,
+    )[0]
+}
+
+export function decodeCar(data: Buffer): Car {
+    if (!data.subarray(0, 8).equals(CAR_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Car`")
+    }
+    return carLayout().decode(data)
+}
+
+export async function fetchCar(connection: Connection, address: PublicKey): Promise<Car | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeCar(info.data)
+}
+
+export function findCarAddress(programId: PublicKey, primaryKey: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("car"), encode(borsh.u8(), primaryKey)],
+        programId,
+    )[0]
+}
+
+/** Derives the address of the next `Car` record, based on the record count in the Nautilus Index. */
+export async function findNextCarAddress(connection: Connection, programId: PublicKey): Promise<[PublicKey, number]> {
+    const count = (await fetchNautilusIndex(connection, programId))?.get("car") ?? 0
+    const primaryKey = count + 1
+    return [findCarAddress(programId, primaryKey), primaryKey]
+}
+
+export function decodeTag(data: Buffer): Tag {
+    if (!data.subarray(0, 8).equals(TAG_DISCRIMINATOR)) {
+        throw new Error("Account data is not a `Tag`")
+    }
+    return tagLayout().decode(data)
+}
+
+export async function fetchTag(connection: Connection, address: PublicKey): Promise<Tag | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeTag(info.data)
+}
+
+export function findTagAddress(programId: PublicKey, primaryKey: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("tag"), encode(borsh.u8(), primaryKey)],
+        programId,
+    )[0]
+}
+
+/** Derives the address of the next `Tag` record, based on the record count in the Nautilus Index. */
+export async function findNextTagAddress(connection: Connection, programId: PublicKey): Promise<[PublicKey, number]> {
+    const count = (await fetchNautilusIndex(connection, programId))?.get("tag") ?? 0
+    const primaryKey = count + 1
+    return [findTagAddress(programId, primaryKey), primaryKey]
+}
+
+export type DecodedAccount =
+    | { name: "Person", data: Person }
+    | { name: "Home", data: Home }
+    | { name: "Car", data: Car }
+    | { name: "Tag", data: Tag }
+
+export function decodeAccount(data: Buffer): DecodedAccount | null {
+    const discriminator = data.subarray(0, 8)
+    if (discriminator.equals(PERSON_DISCRIMINATOR)) {
+        return { name: "Person", data: decodePerson(data) }
+    }
+    if (discriminator.equals(HOME_DISCRIMINATOR)) {
+        return { name: "Home", data: decodeHome(data) }
+    }
+    if (discriminator.equals(CAR_DISCRIMINATOR)) {
+        return { name: "Car", data: decodeCar(data) }
+    }
+    if (discriminator.equals(TAG_DISCRIMINATOR)) {
+        return { name: "Tag", data: decodeTag(data) }
+    }
+    return null
+}
+
+export async function fetchAccount(connection: Connection, address: PublicKey): Promise<DecodedAccount | null> {
+    const info = await connection.getAccountInfo(address)
+    return info === null ? null : decodeAccount(info.data)
+}
+
+export const NAUTILUS_INDEX_DISCRIMINATOR = Buffer.from([201, 34, 45, 79, 218, 117, 230, 102])
+
+export function findNautilusIndexAddress(programId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("nautilus_index"), Buffer.from([0])],
+        programId,
+    )[0]
+}
+
+export async function fetchNautilusIndex(connection: Connection, programId: PublicKey): Promise<Map<string, number> | null> {
+    const info = await connection.getAccountInfo(findNautilusIndexAddress(programId))
+    if (info === null) {
+        return null
+    }
+    if (!info.data.subarray(0, 8).equals(NAUTILUS_INDEX_DISCRIMINATOR)) {
+        throw new Error("Account data is not the Nautilus Index")
+    }
+    return borsh.map(borsh.str(), borsh.u32()).decode(info.data, 8)
+}
+
+export function createInitializeInstruction(
+    programId: PublicKey,
+    accounts: { nautilusIndex: PublicKey, feePayer: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: accounts.nautilusIndex, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 0 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreatePersonInstruction(
+    programId: PublicKey,
+    accounts: { newPerson: PublicKey, feePayer: PublicKey },
+    args: { name: string, authority: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.newPerson, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.str("name"), borsh.publicKey("authority")]),
+        { instruction: 1, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadPersonInstruction(
+    programId: PublicKey,
+    accounts: { person: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: false },
+        { pubkey: accounts.person, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 2 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateHomeInstruction(
+    programId: PublicKey,
+    accounts: { newHome: PublicKey, feePayer: PublicKey },
+    args: { id: number, houseNumber: number, street: string },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.newHome, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u8("id"), borsh.u8("houseNumber"), borsh.str("street")]),
+        { instruction: 3, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadHomeInstruction(
+    programId: PublicKey,
+    accounts: { home: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: false },
+        { pubkey: accounts.home, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 4 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateCarInstruction(
+    programId: PublicKey,
+    accounts: { newCar: PublicKey, feePayer: PublicKey },
+    args: { make: string, model: string, purchaseAuthority: PublicKey, operatingAuthority: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.newCar, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.str("make"), borsh.str("model"), borsh.publicKey("purchaseAuthority"), borsh.publicKey("operatingAuthority")]),
+        { instruction: 5, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadCarInstruction(
+    programId: PublicKey,
+    accounts: { car: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: false },
+        { pubkey: accounts.car, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 6 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createCreateTagInstruction(
+    programId: PublicKey,
+    accounts: { newTag: PublicKey, feePayer: PublicKey },
+    args: { label: string },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.newTag, isSigner: false, isWritable: true },
+        { pubkey: accounts.feePayer, isSigner: true, isWritable: true },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.str("label")]),
+        { instruction: 7, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createReadTagInstruction(
+    programId: PublicKey,
+    accounts: { tag: PublicKey },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: false },
+        { pubkey: accounts.tag, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction")]),
+        { instruction: 8 },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createFundPersonInstruction(
+    programId: PublicKey,
+    accounts: { payer: PublicKey, person: PublicKey },
+    args: { amount: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.payer, isSigner: true, isWritable: true },
+        { pubkey: accounts.person, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("amount")]),
+        { instruction: 9, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createTransferFromPersonInstruction(
+    programId: PublicKey,
+    accounts: { person: PublicKey, recipient: PublicKey },
+    args: { amount: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.person, isSigner: false, isWritable: true },
+        { pubkey: accounts.recipient, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("amount")]),
+        { instruction: 10, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createFundHomeInstruction(
+    programId: PublicKey,
+    accounts: { home: PublicKey, payer: PublicKey },
+    args: { amount: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.home, isSigner: false, isWritable: true },
+        { pubkey: accounts.payer, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("amount")]),
+        { instruction: 11, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createTransferFromHomeInstruction(
+    programId: PublicKey,
+    accounts: { home: PublicKey, recipient: PublicKey },
+    args: { amount: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.home, isSigner: false, isWritable: true },
+        { pubkey: accounts.recipient, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("amount")]),
+        { instruction: 12, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createFundCarInstruction(
+    programId: PublicKey,
+    accounts: { car: PublicKey, payer: PublicKey },
+    args: { amount: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.car, isSigner: false, isWritable: true },
+        { pubkey: accounts.payer, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("amount")]),
+        { instruction: 13, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
+
+export function createTransferFromCarInstruction(
+    programId: PublicKey,
+    accounts: { car: PublicKey, recipient: PublicKey },
+    args: { amount: BN },
+): TransactionInstruction {
+    const keys = [
+        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },
+        { pubkey: accounts.car, isSigner: false, isWritable: true },
+        { pubkey: accounts.recipient, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]
+    const data = encode(
+        borsh.struct([borsh.u8("instruction"), borsh.u64("amount")]),
+        { instruction: 14, ...args },
+    )
+    return new TransactionInstruction({ keys, programId, data })
+}
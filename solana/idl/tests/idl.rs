@@ -1,43 +1,172 @@
-use nautilus_idl::*;
+use nautilus_idl::{
+    idl_instruction::{
+        IdlInstruction, IdlInstructionAccount, IdlInstructionArg, IdlInstructionDiscriminant,
+    },
+    idl_metadata::IdlMetadata,
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeStructField},
+    util::load_idl_from_json,
+    Idl,
+};
+
+fn struct_type_def(name: &str, fields: Vec<(&str, IdlType)>) -> IdlTypeDef {
+    IdlTypeDef::new(
+        name.to_string(),
+        IdlTypeDefType::Struct {
+            fields: fields
+                .into_iter()
+                .map(|(name, ty)| IdlTypeStructField::new(name.to_string(), ty))
+                .collect(),
+        },
+        None,
+    )
+}
+
+fn account(name: &str, is_mut: bool, is_signer: bool, desc: &str) -> IdlInstructionAccount {
+    IdlInstructionAccount::new(
+        name.to_string(),
+        is_mut,
+        is_signer,
+        "account".to_string(),
+        desc.to_string(),
+    )
+}
+
+/// Loads one of the IDLs in `tests/golden`, built by `nautilus idl build` from
+/// the programs in `tests/programs`.
+fn golden_idl(name: &str) -> Idl {
+    load_idl_from_json(&format!(
+        "{}/tests/golden/{}.json",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+/// Compares a client generated from a golden IDL with the one checked in next
+/// to it. Run with `NAUTILUS_BLESS=1` to rewrite the checked-in client instead,
+/// after changing a converter on purpose.
+fn assert_golden(file_name: &str, generated: &str) {
+    let path = format!("{}/tests/golden/{}", env!("CARGO_MANIFEST_DIR"), file_name);
+    if std::env::var_os("NAUTILUS_BLESS").is_some() {
+        std::fs::write(&path, generated).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert!(
+        generated == expected,
+        "`{}` doesn't match the generated client - rerun with `NAUTILUS_BLESS=1` if the change is intended.\n{}",
+        file_name,
+        first_difference(&expected, generated)
+    );
+}
+
+/// Describes the first line where `generated` differs from `expected`.
+fn first_difference(expected: &str, generated: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut generated_lines = generated.lines();
+    for line in 1.. {
+        match (expected_lines.next(), generated_lines.next()) {
+            (None, None) => break,
+            (e, g) if e != g => {
+                return format!("line {}:\n  expected: {:?}\n  generated: {:?}", line, e, g)
+            }
+            _ => {}
+        }
+    }
+    "(only line endings differ)".to_string()
+}
+
+/// An IDL, as it might be loaded from JSON, whose record's primary key and
+/// account's seed name fields neither has.
+fn missing_keys_idl() -> Idl {
+    use nautilus_idl::idl_nautilus_config::{IdlSeed, IdlTypeDefNautilusConfig};
+
+    let mut hero = struct_type_def("Hero", vec![("name", IdlType::String)]);
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: None,
+        table_name: Some("hero".to_string()),
+        primary_key: Some("id".to_string()),
+        autoincrement: Some(false),
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    let mut ledger = struct_type_def("Ledger", vec![("total", IdlType::U64)]);
+    ledger.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some("ledger".to_string()),
+        table_name: None,
+        primary_key: None,
+        autoincrement: None,
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![IdlSeed::Field {
+            key: "owner".to_string(),
+        }],
+    });
+    Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus_idl_test".to_string(),
+        vec![],
+        vec![hero, ledger],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    )
+}
+
+/// An IDL shaped like a Shank one, where `createHero` takes a single
+/// `CreateHeroArgs` struct - the name clients would give its arguments.
+fn clashing_idl() -> Idl {
+    let create_hero = IdlInstruction::new(
+        "createHero",
+        vec![
+            account("newHero", true, false, "new_hero"),
+            account("feePayer", true, true, "The transaction fee payer"),
+        ],
+        vec![IdlInstructionArg::new(
+            "createHeroArgs".to_string(),
+            IdlType::Defined("CreateHeroArgs".to_string()),
+        )],
+        IdlInstructionDiscriminant::new(0),
+    );
+    Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus_idl_test".to_string(),
+        vec![create_hero],
+        vec![],
+        vec![struct_type_def(
+            "CreateHeroArgs",
+            vec![("name", IdlType::String)],
+        )],
+        IdlMetadata::new_with_no_id(),
+    )
+}
 
 #[test]
 fn idl() {
-    let (name, version) = parse_cargo_toml("Cargo.toml").unwrap();
     let metadata = IdlMetadata::new("some-program-id");
 
-    let types = vec![IdlType::new(
+    let types = vec![struct_type_def(
         "CustomArgs",
-        IdlTypeType::new(
-            "struct",
-            vec![
-                IdlTypeTypeField::new("string1", "string"),
-                IdlTypeTypeField::new("string2", "string"),
-            ],
-        ),
+        vec![("string1", IdlType::String), ("string2", IdlType::String)],
     )];
 
     let accounts = vec![
-        IdlAccount::new(
+        struct_type_def(
             "Hero",
-            IdlTypeType::new(
-                "struct",
-                vec![
-                    IdlTypeTypeField::new("id", "u8"),
-                    IdlTypeTypeField::new("name", "string"),
-                    IdlTypeTypeField::new("authority", "publicKey"),
-                ],
-            ),
+            vec![
+                ("id", IdlType::U8),
+                ("name", IdlType::String),
+                ("authority", IdlType::PublicKey),
+            ],
         ),
-        IdlAccount::new(
+        struct_type_def(
             "Villain",
-            IdlTypeType::new(
-                "struct",
-                vec![
-                    IdlTypeTypeField::new("id", "u8"),
-                    IdlTypeTypeField::new("name", "string"),
-                    IdlTypeTypeField::new("authority", "publicKey"),
-                ],
-            ),
+            vec![
+                ("id", IdlType::U8),
+                ("name", IdlType::String),
+                ("authority", IdlType::PublicKey),
+            ],
         ),
     ];
 
@@ -45,44 +174,34 @@ fn idl() {
         IdlInstruction::new(
             "CreateHero",
             vec![
-                IdlInstructionAccount::new(
-                    "autoincAccount",
-                    true,
-                    false,
-                    "The autoincrement account.",
-                ),
-                IdlInstructionAccount::new("newAccount", true, false, "The account to be created."),
-                IdlInstructionAccount::new(
+                account("autoincAccount", true, false, "The autoincrement account."),
+                account("newAccount", true, false, "The account to be created."),
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
-                IdlInstructionAccount::new("systemProgram", false, false, "The System Program"),
+                account("feePayer", true, true, "Fee payer"),
+                account("systemProgram", false, false, "The System Program"),
             ],
             vec![IdlInstructionArg::new(
-                "hero",
-                IdlInstructionArgType::new("Hero"),
+                "hero".to_string(),
+                IdlType::Defined("Hero".to_string()),
             )],
             IdlInstructionDiscriminant::new(0),
         ),
         IdlInstruction::new(
             "DeleteHero",
             vec![
-                IdlInstructionAccount::new(
-                    "targetAccount",
-                    true,
-                    false,
-                    "The account to be deleted.",
-                ),
-                IdlInstructionAccount::new(
+                account("targetAccount", true, false, "The account to be deleted."),
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
+                account("feePayer", true, true, "Fee payer"),
             ],
             vec![],
             IdlInstructionDiscriminant::new(1),
@@ -90,68 +209,53 @@ fn idl() {
         IdlInstruction::new(
             "UpdateHero",
             vec![
-                IdlInstructionAccount::new(
-                    "targetAccount",
-                    true,
-                    false,
-                    "The account to be updated.",
-                ),
-                IdlInstructionAccount::new(
+                account("targetAccount", true, false, "The account to be updated."),
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
-                IdlInstructionAccount::new("systemProgram", false, false, "The System Program"),
+                account("feePayer", true, true, "Fee payer"),
+                account("systemProgram", false, false, "The System Program"),
             ],
             vec![IdlInstructionArg::new(
-                "hero",
-                IdlInstructionArgType::new("Hero"),
+                "hero".to_string(),
+                IdlType::Defined("Hero".to_string()),
             )],
             IdlInstructionDiscriminant::new(2),
         ),
         IdlInstruction::new(
             "CreateVillain",
             vec![
-                IdlInstructionAccount::new(
-                    "autoincAccount",
-                    true,
-                    false,
-                    "The autoincrement account.",
-                ),
-                IdlInstructionAccount::new("newAccount", true, false, "The account to be created."),
-                IdlInstructionAccount::new(
+                account("autoincAccount", true, false, "The autoincrement account."),
+                account("newAccount", true, false, "The account to be created."),
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
-                IdlInstructionAccount::new("systemProgram", false, false, "The System Program"),
+                account("feePayer", true, true, "Fee payer"),
+                account("systemProgram", false, false, "The System Program"),
             ],
             vec![IdlInstructionArg::new(
-                "villain",
-                IdlInstructionArgType::new("Villain"),
+                "villain".to_string(),
+                IdlType::Defined("Villain".to_string()),
             )],
             IdlInstructionDiscriminant::new(3),
         ),
         IdlInstruction::new(
             "DeleteVillain",
             vec![
-                IdlInstructionAccount::new(
-                    "targetAccount",
-                    true,
-                    false,
-                    "The account to be deleted.",
-                ),
-                IdlInstructionAccount::new(
+                account("targetAccount", true, false, "The account to be deleted."),
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
+                account("feePayer", true, true, "Fee payer"),
             ],
             vec![],
             IdlInstructionDiscriminant::new(4),
@@ -159,54 +263,1843 @@ fn idl() {
         IdlInstruction::new(
             "UpdateVillain",
             vec![
-                IdlInstructionAccount::new(
-                    "targetAccount",
-                    true,
-                    false,
-                    "The account to be updated.",
-                ),
-                IdlInstructionAccount::new(
+                account("targetAccount", true, false, "The account to be updated."),
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
-                IdlInstructionAccount::new("systemProgram", false, false, "The System Program"),
+                account("feePayer", true, true, "Fee payer"),
+                account("systemProgram", false, false, "The System Program"),
             ],
             vec![IdlInstructionArg::new(
-                "villain",
-                IdlInstructionArgType::new("Villain"),
+                "villain".to_string(),
+                IdlType::Defined("Villain".to_string()),
             )],
             IdlInstructionDiscriminant::new(5),
         ),
         IdlInstruction::new(
             "CustomInstruction",
             vec![
-                IdlInstructionAccount::new(
+                account(
                     "targetAccount",
                     true,
                     false,
                     "The account to be used as a test.",
                 ),
-                IdlInstructionAccount::new(
+                account(
                     "authority",
                     true,
                     true,
                     "One of the authorities specified for this account.",
                 ),
-                IdlInstructionAccount::new("feePayer", true, true, "Fee payer"),
-                IdlInstructionAccount::new("systemProgram", false, false, "The System Program"),
+                account("feePayer", true, true, "Fee payer"),
+                account("systemProgram", false, false, "The System Program"),
             ],
             vec![IdlInstructionArg::new(
-                "customArgs",
-                IdlInstructionArgType::new("CustomArgs"),
+                "customArgs".to_string(),
+                IdlType::Defined("CustomArgs".to_string()),
             )],
             IdlInstructionDiscriminant::new(6),
         ),
     ];
 
-    let idl = Idl::new(&version, &name, instructions, accounts, types, metadata);
+    let idl = Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus_idl_test".to_string(),
+        instructions,
+        accounts,
+        types,
+        metadata,
+    );
+
+    idl.write_to_json("./target/idl").unwrap();
+
+    let loaded = load_idl_from_json("./target/idl/nautilus_idl_test.json").unwrap();
+    assert_eq!(loaded.name, idl.name);
+    assert_eq!(loaded.instructions.len(), 7);
+    assert_eq!(loaded.accounts.len(), 2);
+    assert_eq!(loaded.types.len(), 1);
+    assert_eq!(loaded.metadata.address.as_deref(), Some("some-program-id"));
+}
+
+#[test]
+fn type_defs_from_tuple_structs_enums_and_generics() {
+    use nautilus_idl::idl_type_def::IdlTypeEnumFields;
+
+    let pair: syn::ItemStruct = syn::parse_quote! { struct Pair(u8, String); };
+    let pair = IdlTypeDef::try_from(&pair).unwrap();
+    match &pair.idl_type {
+        IdlTypeDefType::Struct { fields } => {
+            assert_eq!(fields[0].name, "0");
+            assert_eq!(fields[1].name, "1");
+        }
+        _ => panic!("Expected a struct."),
+    }
+    // A tuple field has no name of its own outside of its struct.
+    let tuple_struct: syn::ItemStruct = syn::parse_quote! { struct Id(u8); };
+    let tuple_field = tuple_struct.fields.iter().next().unwrap();
+    let err = IdlTypeStructField::try_from(tuple_field).unwrap_err();
+    assert!(err.to_string().contains("tuple field"));
+
+    let vault: syn::ItemStruct = syn::parse_quote! { struct Vault<T> { amount: T, items: Vec<T> } };
+    let vault = IdlTypeDef::try_from(&vault).unwrap();
+    assert_eq!(vault.generics, vec!["T".to_string()]);
+    match &vault.idl_type {
+        IdlTypeDefType::Struct { fields } => {
+            assert!(matches!(&fields[0].field_data_type, IdlType::Generic(t) if t == "T"));
+            assert!(
+                matches!(&fields[1].field_data_type, IdlType::Vec(inner) if matches!(**inner, IdlType::Generic(_)))
+            );
+        }
+        _ => panic!("Expected a struct."),
+    }
+
+    let light: syn::ItemEnum = syn::parse_quote! {
+        enum Light { Red, Yellow { seconds: u8 }, Green(u64, String) }
+    };
+    let light = IdlTypeDef::try_from(&light).unwrap();
+    match &light.idl_type {
+        IdlTypeDefType::Enum { variants } => {
+            assert!(variants[0].fields.is_none());
+            assert!(
+                matches!(&variants[1].fields, Some(IdlTypeEnumFields::Named(f)) if f[0].name == "seconds")
+            );
+            assert!(
+                matches!(&variants[2].fields, Some(IdlTypeEnumFields::Tuple(t)) if t.len() == 2)
+            );
+        }
+        _ => panic!("Expected an enum."),
+    }
+
+    let json = serde_json::to_string(&light).unwrap();
+    let reloaded: IdlTypeDef = serde_json::from_str(&json).unwrap();
+    assert!(
+        matches!(&reloaded.idl_type, IdlTypeDefType::Enum { variants } if matches!(&variants[2].fields, Some(IdlTypeEnumFields::Tuple(_))))
+    );
+}
+
+#[test]
+fn generic_type_defs_are_instantiated() {
+    use nautilus_idl::idl_type_def::IdlTypeEnumFields;
+
+    let slot: syn::ItemEnum = syn::parse_quote! {
+        enum Slot<K, V> { Empty, Held { key: K, values: Vec<V> }, Pair(K, Option<V>) }
+    };
+    let slot = IdlTypeDef::try_from(&slot).unwrap();
+
+    let instance = slot.instantiate(
+        "SlotU8PublicKey".to_string(),
+        vec![IdlType::U8, IdlType::PublicKey],
+    );
+
+    assert_eq!(instance.name, "SlotU8PublicKey");
+    assert!(instance.generics.is_empty());
+    match &instance.idl_type {
+        IdlTypeDefType::Enum { variants } => {
+            assert!(variants[0].fields.is_none());
+            match &variants[1].fields {
+                Some(IdlTypeEnumFields::Named(fields)) => {
+                    assert_eq!(fields[0].field_data_type, IdlType::U8);
+                    assert_eq!(
+                        fields[1].field_data_type,
+                        IdlType::Vec(Box::new(IdlType::PublicKey))
+                    );
+                }
+                _ => panic!("Expected named fields."),
+            }
+            match &variants[2].fields {
+                Some(IdlTypeEnumFields::Tuple(tys)) => assert_eq!(
+                    tys,
+                    &[IdlType::U8, IdlType::Option(Box::new(IdlType::PublicKey))]
+                ),
+                _ => panic!("Expected tuple fields."),
+            }
+        }
+        _ => panic!("Expected an enum."),
+    }
+    // Without an argument for `V`, it's left generic.
+    let partial = slot.instantiate("SlotU8".to_string(), vec![IdlType::U8]);
+    assert_eq!(partial.generics, vec!["V".to_string()]);
+    assert_eq!(partial.borsh_size(&[]), None);
+}
+
+#[test]
+fn types_from_collections_aliases_and_constants() {
+    use nautilus_idl::idl_type::IdlTypeContext;
+
+    let aliases: Vec<syn::ItemType> = vec![
+        syn::parse_quote! { type Scores = HashMap<Pubkey, u64>; },
+        syn::parse_quote! { type Pair<T> = (T, T); },
+    ];
+    let consts: Vec<syn::ItemConst> = vec![
+        syn::parse_quote! { const NAME_LEN: usize = 32; },
+        syn::parse_quote! { const SLOTS: u8 = NAME_LEN as u8 / 4; },
+    ];
+    let context = IdlTypeContext::new(&aliases, &consts);
+    let convert = |ty: syn::Type| IdlType::from_type(&ty, &context);
+    let boxed = |ty: IdlType| Box::new(ty);
+
+    assert_eq!(
+        convert(syn::parse_quote! { Option<Box<u8>> }).unwrap(),
+        IdlType::Option(boxed(IdlType::U8))
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { std::collections::BTreeMap<String, Vec<u16>> }).unwrap(),
+        IdlType::BTreeMap(
+            boxed(IdlType::String),
+            boxed(IdlType::Vec(boxed(IdlType::U16)))
+        )
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { HashSet<i32> }).unwrap(),
+        IdlType::HashSet(boxed(IdlType::I32))
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { BTreeSet<Pubkey> }).unwrap(),
+        IdlType::BTreeSet(boxed(IdlType::PublicKey))
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { Scores }).unwrap(),
+        IdlType::HashMap(boxed(IdlType::PublicKey), boxed(IdlType::U64))
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { Pair<Hero> }).unwrap(),
+        IdlType::Tuple(vec![
+            IdlType::Defined("Hero".to_string()),
+            IdlType::Defined("Hero".to_string())
+        ])
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { [u8; NAME_LEN] }).unwrap(),
+        IdlType::Array(boxed(IdlType::U8), 32)
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { [u8; SLOTS * 2 + 1] }).unwrap(),
+        IdlType::Array(boxed(IdlType::U8), 17)
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { [u32] }).unwrap(),
+        IdlType::Vec(boxed(IdlType::U32))
+    );
+    assert_eq!(
+        convert(syn::parse_quote! { &'a [u8] }).unwrap(),
+        IdlType::Bytes
+    );
+
+    let err = convert(syn::parse_quote! { [u8; UNKNOWN] }).unwrap_err();
+    assert!(err.reason.contains("UNKNOWN"));
+    assert!(convert(syn::parse_quote! { f64 }).is_err());
+    assert!(convert(syn::parse_quote! { Box<dyn Fn()> }).is_err());
+    assert!(convert(syn::parse_quote! { Pair<u8, u8> }).is_err());
+
+    let hero: syn::ItemStruct = syn::parse_quote! {
+        struct Hero { name: String, speed: f32 }
+    };
+    let err = IdlTypeDef::from_struct(&hero, &context).unwrap_err();
+    assert_eq!(err.location.as_deref(), Some("Hero.speed"));
+    assert!(err.to_string().starts_with("Hero.speed: `f32`"));
+
+    let action: syn::ItemEnum = syn::parse_quote! {
+        enum Action { Rest, Attack(u8, *const u8) }
+    };
+    let err = IdlTypeDef::from_enum(&action, &context).unwrap_err();
+    assert_eq!(err.location.as_deref(), Some("Action::Attack.1"));
+}
+
+#[test]
+fn docs_constants_and_account_sizes() {
+    use nautilus_idl::{
+        idl_constant::IdlConstant, idl_nautilus_config::IdlTypeDefNautilusConfig,
+        idl_type::IdlTypeContext,
+    };
+
+    let consts: Vec<syn::ItemConst> = vec![
+        syn::parse_quote! {
+            /// The length of a hero's name.
+            pub const NAME_LEN: usize = 16;
+        },
+        syn::parse_quote! { pub const MAX_HP: u32 = NAME_LEN as u32 * 10; },
+        syn::parse_quote! { pub const SEED: &str = "hero"; },
+    ];
+    let context = IdlTypeContext::new(&[], &consts);
+    let constants: Vec<IdlConstant> = consts
+        .iter()
+        .map(|c| IdlConstant::from_const(c, &context).unwrap())
+        .collect();
+    assert_eq!(constants[0].constant_type, IdlType::U64);
+    assert_eq!(constants[0].value, "16");
+    assert_eq!(constants[0].docs, vec!["The length of a hero's name."]);
+    assert_eq!(constants[1].value, "160");
+    assert_eq!(constants[2].constant_type, IdlType::String);
+    assert_eq!(constants[2].value, "\"hero\"");
+
+    let hero: syn::ItemStruct = syn::parse_quote! {
+        /// A hero.
+        ///
+        /// Heroes fight.
+        struct Hero {
+            /// The hero's name.
+            name: [u8; NAME_LEN],
+            class: Class,
+            hp: u32,
+        }
+    };
+    let class: syn::ItemEnum = syn::parse_quote! {
+        enum Class {
+            /// Fights up close.
+            Knight(u16),
+            Mage { mana: u16 },
+        }
+    };
+    let mut hero = IdlTypeDef::from_struct(&hero, &context).unwrap();
+    let class = IdlTypeDef::from_enum(&class, &context).unwrap();
+    assert_eq!(hero.docs, vec!["A hero.", "", "Heroes fight."]);
+    match &hero.idl_type {
+        IdlTypeDefType::Struct { fields } => {
+            assert_eq!(fields[0].docs, vec!["The hero's name."]);
+            assert!(fields[1].docs.is_empty());
+        }
+        _ => panic!("Expected a struct."),
+    }
+    match &class.idl_type {
+        IdlTypeDefType::Enum { variants } => {
+            assert_eq!(variants[0].docs, vec!["Fights up close."])
+        }
+        _ => panic!("Expected an enum."),
+    }
+
+    let types = vec![hero.clone(), class.clone()];
+    assert_eq!(class.borsh_size(&types), Some(3));
+    assert_eq!(hero.borsh_size(&types), Some(16 + 3 + 4));
+    // Only accounts have a discriminator.
+    assert_eq!(hero.account_size(&types), None);
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some("hero".to_string()),
+        table_name: None,
+        primary_key: None,
+        autoincrement: None,
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    assert_eq!(hero.account_size(&types), Some(8 + 16 + 3 + 4));
+
+    let uneven: syn::ItemEnum = syn::parse_quote! { enum Uneven { A(u8), B(u16) } };
+    let uneven = IdlTypeDef::from_enum(&uneven, &context).unwrap();
+    assert_eq!(uneven.borsh_size(&[]), None);
+    let named: syn::ItemStruct = syn::parse_quote! { struct Named { name: String } };
+    let named = IdlTypeDef::from_struct(&named, &context).unwrap();
+    assert_eq!(named.borsh_size(&[]), None);
+
+    hero.size = hero.account_size(&types);
+    let mut idl = Idl::new(
+        "0.1.0".to_string(),
+        "heroes".to_string(),
+        vec![],
+        vec![hero],
+        vec![class],
+        IdlMetadata::new_with_no_id(),
+    );
+    idl.constants = constants;
+    let restored = Idl::from_anchor_json(&idl.to_anchor_json().unwrap()).unwrap();
+    assert_eq!(restored.accounts[0].size, Some(31));
+    assert_eq!(restored.accounts[0].docs, idl.accounts[0].docs);
+    assert_eq!(restored.constants, idl.constants);
+    assert_eq!(
+        serde_json::to_value(&restored.types).unwrap(),
+        serde_json::to_value(&idl.types).unwrap()
+    );
+}
+
+#[test]
+fn typescript_client() {
+    use nautilus_idl::{
+        converters::ts::TypeScriptConverter, idl_nautilus_config::IdlTypeDefNautilusConfig,
+    };
+
+    let mut hero = struct_type_def("Hero", vec![("id", IdlType::U8), ("name", IdlType::String)]);
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: None,
+        table_name: Some("hero".to_string()),
+        primary_key: Some("id".to_string()),
+        autoincrement: Some(true),
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+
+    let create_hero = IdlInstruction::new(
+        "createHero",
+        vec![
+            IdlInstructionAccount::new(
+                "index".to_string(),
+                true,
+                false,
+                "index".to_string(),
+                "The Nautilus Index for this program".to_string(),
+            ),
+            account("new_hero", true, false, "new_hero"),
+            IdlInstructionAccount::new(
+                "systemProgram".to_string(),
+                false,
+                false,
+                "systemProgram".to_string(),
+                "The System Program".to_string(),
+            ),
+        ],
+        vec![IdlInstructionArg::new("name".to_string(), IdlType::String)],
+        IdlInstructionDiscriminant::new(1),
+    );
+
+    let idl = Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus_idl_test".to_string(),
+        vec![create_hero],
+        vec![hero],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    );
+    let ts = idl.to_typescript_string();
+
+    assert!(ts.contains("export type Hero = {\n    id: number\n    name: string\n}"));
+    assert!(ts.contains("export function heroLayout(property?: string): borsh.Layout<Hero>"));
+    assert!(ts.contains("return new DiscriminatedLayout(HERO_DISCRIMINATOR, borsh.struct(["));
+    assert!(ts.contains("export function decodeHero(data: Buffer): Hero"));
+    assert!(ts.contains("[Buffer.from(\"hero\"), encode(borsh.u8(), primaryKey)]"));
+    assert!(ts.contains("export async function findNextHeroAddress("));
+    assert!(ts.contains("export async function fetchNautilusIndex("));
+    assert!(!ts.contains("export const PROGRAM_ID"));
+    assert!(ts.contains(
+        "    accounts: { newHero: PublicKey },\n    args: { name: string },\n): TransactionInstruction {\n    const keys = [\n        { pubkey: findNautilusIndexAddress(programId), isSigner: false, isWritable: true },\n        { pubkey: accounts.newHero, isSigner: false, isWritable: true },\n        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },\n    ]"
+    ));
+    assert!(ts.contains(
+        "borsh.struct([borsh.u8(\"instruction\"), borsh.str(\"name\")]),\n        { instruction: 1, ...args },"
+    ));
+}
+
+#[test]
+fn typescript_client_golden_files() {
+    use nautilus_idl::converters::ts::TypeScriptConverter;
+
+    assert_golden(
+        "accounts.ts",
+        &golden_idl("accounts").to_typescript_string(),
+    );
+    assert_golden("records.ts", &golden_idl("records").to_typescript_string());
+}
+
+#[test]
+fn typescript_client_skips_addresses_it_cant_derive() {
+    use nautilus_idl::converters::ts::TypeScriptConverter;
+
+    let ts = missing_keys_idl().to_typescript_string();
+
+    assert!(ts.contains("export function decodeHero("));
+    assert!(ts.contains("export function decodeLedger("));
+    assert!(!ts.contains("export function findHeroAddress("));
+    assert!(!ts.contains("export function findLedgerAddress("));
+}
+
+#[test]
+fn python_client() {
+    use nautilus_idl::converters::py::PythonConverter;
+
+    let light: syn::ItemEnum = syn::parse_quote! {
+        enum Light { Red, Green(u64, String) }
+    };
+    let pair: syn::ItemStruct = syn::parse_quote! { struct Pair(u8, Pubkey); };
+
+    let create_pair = IdlInstruction::new(
+        "createPair",
+        vec![
+            account("new_pair", true, false, "new_pair"),
+            account("feePayer", true, true, "The transaction fee payer"),
+        ],
+        vec![IdlInstructionArg::new(
+            "pair".to_string(),
+            IdlType::Defined("Pair".to_string()),
+        )],
+        IdlInstructionDiscriminant::new(0),
+    );
+
+    let idl = Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus_idl_test".to_string(),
+        vec![create_pair],
+        vec![],
+        vec![
+            IdlTypeDef::try_from(&pair).unwrap(),
+            IdlTypeDef::try_from(&light).unwrap(),
+        ],
+        IdlMetadata::new("11111111111111111111111111111111"),
+    );
+    let py = idl.to_python_string();
+
+    assert!(py.contains("PROGRAM_ID = Pubkey.from_string(\"11111111111111111111111111111111\")"));
+    assert!(py.contains("@dataclass\nclass Pair:\n    field_0: int\n    field_1: Pubkey\n"));
+    assert!(py.contains(
+        "return _Dataclass(Pair, borsh.CStruct(\"field_0\" / borsh.U8, \"field_1\" / PUBLIC_KEY))"
+    ));
+    assert!(py.contains("@dataclass\nclass LightRed:\n    pass\n"));
+    assert!(py.contains("Light = typing.Union[LightRed, LightGreen]"));
+    assert!(py.contains("class CreatePairAccounts(typing.TypedDict):\n    new_pair: Pubkey\n    fee_payer: Pubkey\n"));
+    assert!(py.contains(
+        "data = borsh.CStruct(\"instruction\" / borsh.U8, \"pair\" / pair_layout()).build({\"instruction\": 0, **args})"
+    ));
+}
+
+#[test]
+fn python_client_golden_files() {
+    use nautilus_idl::converters::py::PythonConverter;
+
+    assert_golden("accounts.py", &golden_idl("accounts").to_python_string());
+    assert_golden("records.py", &golden_idl("records").to_python_string());
+}
+
+#[test]
+fn python_client_skips_addresses_it_cant_derive() {
+    use nautilus_idl::converters::py::PythonConverter;
+
+    let py = missing_keys_idl().to_python_string();
+
+    assert!(py.contains("def decode_hero("));
+    assert!(py.contains("def decode_ledger("));
+    assert!(!py.contains("def find_hero_address("));
+    assert!(!py.contains("def find_ledger_address("));
+}
+
+#[test]
+fn python_client_struct_names_dont_clash_with_types() {
+    use nautilus_idl::converters::py::PythonConverter;
+
+    let py = clashing_idl().to_python_string();
+
+    assert_eq!(py.matches("class CreateHeroArgs:").count(), 1);
+    assert!(py.contains("class CreateHeroInstructionArgs(typing.TypedDict):\n    create_hero_args: CreateHeroArgs\n"));
+    assert!(py.contains("args: CreateHeroInstructionArgs"));
+    assert!(py.contains("class CreateHeroAccounts(typing.TypedDict):"));
+}
+
+#[test]
+fn rust_client() {
+    use nautilus_idl::{
+        converters::rust::RustConverter,
+        idl_nautilus_config::{IdlSeed, IdlTypeDefNautilusConfig},
+    };
+
+    let light: syn::ItemEnum = syn::parse_quote! {
+        enum Light { Red, Yellow { seconds: u8 }, Green(u64, String) }
+    };
+    let mut light = IdlTypeDef::try_from(&light).unwrap();
+    light.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some("light".to_string()),
+        table_name: None,
+        primary_key: None,
+        autoincrement: None,
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![
+            IdlSeed::Lit {
+                value: "light".to_string(),
+            },
+            IdlSeed::Param {
+                key: "owner".to_string(),
+                value: IdlType::PublicKey,
+            },
+        ],
+    });
+
+    let create_light = IdlInstruction::new(
+        "createLight",
+        vec![
+            account("new_light", true, false, "new_light"),
+            account("feePayer", true, true, "The transaction fee payer"),
+            IdlInstructionAccount::new(
+                "systemProgram".to_string(),
+                false,
+                false,
+                "systemProgram".to_string(),
+                "The System Program".to_string(),
+            ),
+        ],
+        vec![IdlInstructionArg::new(
+            "light".to_string(),
+            IdlType::Defined("Light".to_string()),
+        )],
+        IdlInstructionDiscriminant::new(2),
+    );
+
+    let idl = Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus_idl_test".to_string(),
+        vec![create_light],
+        vec![light],
+        vec![],
+        IdlMetadata::new("11111111111111111111111111111111"),
+    );
+    let rs = idl.to_rust_string();
+
+    assert!(rs
+        .contains("pub const PROGRAM_ID: Pubkey = pubkey!(\"11111111111111111111111111111111\");"));
+    assert!(rs.contains(
+        "pub enum Light {\n    Red,\n    Yellow { seconds: u8 },\n    Green(u64, String),\n}"
+    ));
+    assert!(rs.contains("        LIGHT_DISCRIMINATOR.serialize(writer)?;\n        match self {"));
+    assert!(rs.contains("            2 => Self::Green(BorshDeserialize::deserialize(buf)?, BorshDeserialize::deserialize(buf)?),"));
+    assert!(rs.contains("pub fn decode_light(data: &[u8]) -> std::io::Result<Light>"));
+    assert!(rs.contains(
+        "pub fn find_light_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {\n    Pubkey::find_program_address(&[\"light\".as_bytes(), &owner.try_to_vec().unwrap()], program_id)"
+    ));
+    assert!(
+        rs.contains("        LIGHT_DISCRIMINATOR => DecodedAccount::Light(decode_light(data)?),")
+    );
+    assert!(!rs.contains("pub struct NautilusIndex"));
+    assert!(rs.contains(
+        "    pub struct CreateLightAccounts {\n        pub new_light: Pubkey,\n        pub fee_payer: Pubkey,\n    }"
+    ));
+    assert!(rs.contains(
+        "    pub fn create_light(program_id: &Pubkey, accounts: &CreateLightAccounts, args: &CreateLightArgs) -> Instruction {"
+    ));
+    assert!(rs.contains("            AccountMeta::new_readonly(system_program::ID, false),"));
+    assert!(rs.contains(
+        "            let mut data = vec![2];\n            args.serialize(&mut data).unwrap();"
+    ));
+}
+
+#[test]
+fn rust_client_golden_files() {
+    use nautilus_idl::converters::rust::RustConverter;
+
+    assert_golden("accounts.rs", &golden_idl("accounts").to_rust_string());
+    assert_golden("records.rs", &golden_idl("records").to_rust_string());
+}
+
+#[test]
+fn rust_client_skips_addresses_it_cant_derive() {
+    use nautilus_idl::converters::rust::RustConverter;
+
+    let rust = missing_keys_idl().to_rust_string();
+
+    assert!(rust.contains("pub fn decode_hero("));
+    assert!(rust.contains("pub fn decode_ledger("));
+    assert!(!rust.contains("pub fn find_hero_address("));
+    assert!(!rust.contains("pub fn find_ledger_address("));
+}
+
+#[test]
+fn rust_client_struct_names_dont_clash_with_types() {
+    use nautilus_idl::converters::rust::RustConverter;
+
+    let rust = clashing_idl().to_rust_string();
+
+    assert_eq!(rust.matches("pub struct CreateHeroArgs {").count(), 1);
+    assert!(rust.contains(
+        "pub struct CreateHeroInstructionArgs {\n        pub create_hero_args: CreateHeroArgs,\n    }"
+    ));
+    assert!(rust.contains("args: &CreateHeroInstructionArgs"));
+    assert!(rust.contains("pub struct CreateHeroAccounts {"));
+}
+
+#[test]
+fn go_client() {
+    use nautilus_idl::{
+        converters::go::GoConverter, idl_nautilus_config::IdlTypeDefNautilusConfig,
+    };
+
+    let mut hero = struct_type_def(
+        "Hero",
+        vec![
+            ("id", IdlType::U16),
+            (
+                "stats",
+                IdlType::HashMap(Box::new(IdlType::String), Box::new(IdlType::U64)),
+            ),
+            ("sidekick", IdlType::Option(Box::new(IdlType::PublicKey))),
+            ("badges", IdlType::Array(Box::new(IdlType::U8), 4)),
+        ],
+    );
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: None,
+        table_name: Some("hero".to_string()),
+        primary_key: Some("id".to_string()),
+        autoincrement: Some(true),
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+
+    let create_hero = IdlInstruction::new(
+        "createHero",
+        vec![
+            IdlInstructionAccount::new(
+                "index".to_string(),
+                true,
+                false,
+                "index".to_string(),
+                "The Nautilus Index for this program".to_string(),
+            ),
+            account("new_hero", true, false, "new_hero"),
+            IdlInstructionAccount::new(
+                "systemProgram".to_string(),
+                false,
+                false,
+                "systemProgram".to_string(),
+                "The System Program".to_string(),
+            ),
+        ],
+        vec![IdlInstructionArg::new("name".to_string(), IdlType::String)],
+        IdlInstructionDiscriminant::new(1),
+    );
+
+    let idl = Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus-idl-test".to_string(),
+        vec![create_hero],
+        vec![hero],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    );
+    let go = idl.to_go_string();
+
+    assert!(go.contains("\n\npackage nautilusidltest\n"));
+    assert!(!go.contains("var ProgramID"));
+    assert!(go.contains(
+        "type Hero struct {\n\tId uint16\n\tStats map[string]uint64\n\tSidekick *solana.PublicKey\n\tBadges [4]uint8\n}"
+    ));
+    assert!(go.contains("\tif err := e.WriteRaw(HeroDiscriminator[:]); err != nil {"));
+    assert!(go.contains(
+        "\tif err := mapEncoder((*BorshEncoder).WriteString, (*BorshEncoder).WriteU64, lessOrdered[string])(e, v.Stats); err != nil {"
+    ));
+    assert!(go.contains(
+        "\tif v.Sidekick, err = optionDecoder((*BorshDecoder).ReadPublicKey)(d); err != nil {"
+    ));
+    assert!(go.contains("\tif v.Id, err = d.ReadU16(); err != nil {"));
+    assert!(go.contains("func DecodeHeroAccount(data []byte) (Hero, error)"));
+    assert!(go.contains("\tprimaryKeySeed, err := borshSeed(primaryKey, (*BorshEncoder).WriteU16)"));
+    assert!(go.contains(
+        "\treturn solana.FindProgramAddress([][]byte{[]byte(\"hero\"), primaryKeySeed}, programID)"
+    ));
+    assert!(go.contains("\tprimaryKey := uint16(index.NextCount(\"hero\"))"));
+    assert!(go.contains("type CreateHeroAccounts struct {\n\tNewHero solana.PublicKey\n}"));
+    assert!(go.contains(
+        "func NewCreateHeroInstruction(programID solana.PublicKey, accounts CreateHeroAccounts, args CreateHeroArgs) (*solana.GenericInstruction, error) {"
+    ));
+    assert!(go.contains(
+        "\t\tsolana.NewAccountMeta(indexAddress, true, false),\n\t\tsolana.NewAccountMeta(accounts.NewHero, true, false),\n\t\tsolana.NewAccountMeta(solana.SystemProgramID, false, false),"
+    ));
+}
+
+#[test]
+fn go_client_golden_files() {
+    use nautilus_idl::converters::go::GoConverter;
+
+    assert_golden("accounts.go", &golden_idl("accounts").to_go_string());
+    assert_golden("records.go", &golden_idl("records").to_go_string());
+}
+
+#[test]
+fn go_client_skips_addresses_it_cant_derive() {
+    use nautilus_idl::converters::go::GoConverter;
+
+    let go = missing_keys_idl().to_go_string();
+
+    assert!(go.contains("func DecodeHeroAccount("));
+    assert!(go.contains("func DecodeLedgerAccount("));
+    assert!(!go.contains("func FindHeroAddress("));
+    assert!(!go.contains("func FindLedgerAddress("));
+}
+
+#[test]
+fn go_client_struct_names_dont_clash_with_types() {
+    use nautilus_idl::converters::go::GoConverter;
+
+    let go = clashing_idl().to_go_string();
+
+    assert_eq!(go.matches("type CreateHeroArgs struct {").count(), 1);
+    assert!(
+        go.contains("type CreateHeroInstructionArgs struct {\n\tCreateHeroArgs CreateHeroArgs\n}")
+    );
+    assert!(go.contains("args CreateHeroInstructionArgs)"));
+    assert!(go.contains("type CreateHeroAccounts struct {"));
+}
+
+#[test]
+fn anchor_roundtrip() {
+    use nautilus_idl::{
+        idl_error::IdlError,
+        idl_nautilus_config::{
+            IdlSeed, IdlTypeDefNautilusConfig, IdlTypeDefNautilusConfigDefaultInstruction,
+        },
+        idl_type_def::discriminator,
+    };
+
+    let mut hero = struct_type_def(
+        "Hero",
+        vec![
+            ("id", IdlType::U32),
+            (
+                "stats",
+                IdlType::HashMap(Box::new(IdlType::String), Box::new(IdlType::U64)),
+            ),
+            ("tags", IdlType::BTreeSet(Box::new(IdlType::String))),
+        ],
+    );
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: None,
+        table_name: Some("hero".to_string()),
+        primary_key: Some("id".to_string()),
+        autoincrement: Some(true),
+        authorities: vec!["owner".to_string()],
+        default_instructions: vec![IdlTypeDefNautilusConfigDefaultInstruction::Create(
+            "Hero".to_string(),
+        )],
+        seeds: vec![],
+    });
+    let mut vault = struct_type_def(
+        "Vault",
+        vec![(
+            "position",
+            IdlType::Tuple(vec![IdlType::Generic("T".to_string()), IdlType::I64]),
+        )],
+    );
+    vault.generics = vec!["T".to_string()];
+    vault.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some("vault".to_string()),
+        table_name: None,
+        primary_key: None,
+        autoincrement: None,
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![
+            IdlSeed::Lit {
+                value: "vault".to_string(),
+            },
+            IdlSeed::Param {
+                key: "owner".to_string(),
+                value: IdlType::PublicKey,
+            },
+        ],
+    });
+
+    let create_hero = IdlInstruction::new(
+        "createHero",
+        vec![
+            IdlInstructionAccount::new(
+                "index".to_string(),
+                true,
+                false,
+                "index".to_string(),
+                "The Nautilus Index for this program".to_string(),
+            ),
+            account("new_hero", true, false, "new_hero"),
+            IdlInstructionAccount::new(
+                "systemProgram".to_string(),
+                false,
+                false,
+                "systemProgram".to_string(),
+                "The System Program".to_string(),
+            ),
+        ],
+        vec![IdlInstructionArg::new(
+            "position".to_string(),
+            IdlType::Tuple(vec![IdlType::U8, IdlType::String]),
+        )],
+        IdlInstructionDiscriminant::new(1),
+    );
+
+    let mut idl = Idl::new(
+        env!("CARGO_PKG_VERSION").to_string(),
+        "nautilus-idl-test".to_string(),
+        vec![create_hero],
+        vec![hero, vault],
+        vec![struct_type_def(
+            "Point",
+            vec![("0", IdlType::I32), ("1", IdlType::I32)],
+        )],
+        IdlMetadata::new("some-program-id"),
+    );
+    idl.errors = vec![IdlError::new(200, "LoadDataFailed", "Failed to load data")];
+
+    let anchor_json: serde_json::Value =
+        serde_json::from_str(&idl.to_anchor_json().unwrap()).unwrap();
+    assert_eq!(anchor_json["address"], "some-program-id");
+    assert_eq!(anchor_json["metadata"]["spec"], "0.1.0");
+    assert_eq!(
+        anchor_json["accounts"][0],
+        serde_json::json!({ "name": "Hero", "discriminator": discriminator("hero") })
+    );
+    assert_eq!(
+        anchor_json["accounts"][1]["discriminator"],
+        serde_json::json!(discriminator("vault"))
+    );
+    let ix = &anchor_json["instructions"][0];
+    assert_eq!(ix["discriminator"], serde_json::json!([1]));
+    assert_eq!(
+        ix["accounts"][0]["pda"]["seeds"][0],
+        serde_json::json!({ "kind": "const", "value": b"nautilus_index" })
+    );
+    assert_eq!(
+        ix["accounts"][2]["address"],
+        "11111111111111111111111111111111"
+    );
+    assert_eq!(ix["accounts"][1]["writable"], true);
+    assert_eq!(
+        ix["args"][0]["type"],
+        serde_json::json!({ "defined": { "name": "TupleU8String" } })
+    );
+    let types = anchor_json["types"].as_array().unwrap();
+    assert_eq!(
+        types[0]["type"]["fields"][1]["type"],
+        serde_json::json!({ "vec": { "defined": { "name": "TupleStringU64" } } })
+    );
+    assert_eq!(
+        types[0]["type"]["fields"][2]["type"],
+        serde_json::json!({ "vec": "string" })
+    );
+    assert_eq!(
+        types[1]["type"]["fields"][0]["type"],
+        serde_json::json!({ "defined": {
+            "name": "TupleTI64",
+            "generics": [{ "kind": "type", "type": { "generic": "T" } }]
+        } })
+    );
+    assert_eq!(
+        types[2]["type"]["fields"],
+        serde_json::json!(["i32", "i32"])
+    );
+    assert!(types.iter().any(|t| t["name"] == "TupleTI64"
+        && t["generics"] == serde_json::json!([{ "kind": "type", "name": "T" }])));
+    assert_eq!(anchor_json["errors"][0]["code"], 200);
+    let extension = &anchor_json["metadata"]["nautilus"];
+    assert_eq!(extension["configs"]["Hero"]["tableName"], "hero");
+    assert_eq!(
+        extension["accountTypes"]["createHero"],
+        serde_json::json!(["index", "account", "systemProgram"])
+    );
+
+    let restored = Idl::from_anchor_json(&anchor_json.to_string()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&restored).unwrap(),
+        serde_json::to_value(&idl).unwrap()
+    );
+}
+
+#[test]
+fn anchor_import() {
+    let anchor_json = serde_json::json!({
+        "address": "Counter111111111111111111111111111111111111",
+        "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [{
+            "name": "increment",
+            "discriminator": [3],
+            "accounts": [
+                {
+                    "name": "accounts",
+                    "accounts": [
+                        { "name": "counter", "writable": true },
+                        { "name": "authority", "signer": true, "docs": ["The counter's authority"] }
+                    ]
+                },
+                { "name": "system_program", "address": "11111111111111111111111111111111" }
+            ],
+            "args": [{ "name": "by", "type": { "option": "u64" } }]
+        }],
+        "accounts": [{
+            "name": "Counter",
+            "discriminator": nautilus_idl::idl_type_def::discriminator("counter")
+        }],
+        "errors": [{ "code": 6000, "name": "Overflow" }],
+        "types": [{
+            "name": "Counter",
+            "type": {
+                "kind": "struct",
+                "fields": [
+                    { "name": "count", "type": "u64" },
+                    { "name": "authority", "type": "pubkey" },
+                    { "name": "history", "type": { "array": ["u8", 16] } }
+                ]
+            }
+        }]
+    });
+    let idl = Idl::from_anchor_json(&anchor_json.to_string()).unwrap();
+
+    assert_eq!(idl.metadata.origin, "anchor");
+    assert_eq!(
+        idl.metadata.address.as_deref(),
+        Some("Counter111111111111111111111111111111111111")
+    );
+    let increment = &idl.instructions[0];
+    assert_eq!(increment.discriminant.value, 3);
+    let accounts: Vec<(&str, &str, bool, bool)> = increment
+        .accounts
+        .iter()
+        .map(|a| {
+            (
+                a.name.as_str(),
+                a.account_type.as_str(),
+                a.is_mut,
+                a.is_signer,
+            )
+        })
+        .collect();
+    assert_eq!(
+        accounts,
+        vec![
+            ("counter", "account", true, false),
+            ("authority", "account", false, true),
+            ("system_program", "systemProgram", false, false),
+        ]
+    );
+    assert_eq!(increment.accounts[1].desc, "The counter's authority");
+    assert!(matches!(
+        &increment.args[0].arg_type,
+        IdlType::Option(t) if matches!(**t, IdlType::U64)
+    ));
+    assert_eq!(idl.accounts.len(), 1);
+    assert!(idl.types.is_empty());
+    assert_eq!(
+        idl.accounts[0]
+            .config
+            .as_ref()
+            .and_then(|c| c.discrminator_str.as_deref()),
+        Some("counter")
+    );
+    assert_eq!(idl.errors[0].code, 6000);
+
+    // Anchor's own 8-byte instruction discriminators can't be represented.
+    let mut anchor_discriminators = anchor_json.clone();
+    anchor_discriminators["instructions"][0]["discriminator"] =
+        serde_json::json!([11, 18, 104, 9, 104, 174, 59, 33]);
+    let err = Idl::from_anchor_json(&anchor_discriminators.to_string()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut floats = anchor_json;
+    floats["types"][0]["type"]["fields"][0]["type"] = serde_json::json!("f64");
+    let err = Idl::from_anchor_json(&floats.to_string()).unwrap_err();
+    assert!(err.to_string().contains("types.Counter.count"));
+}
+
+#[test]
+fn shank_import() {
+    let shank_json = include_str!("../../../js/idl/program_shank.json");
+    let idl = Idl::from_shank_json(shank_json).unwrap();
+
+    assert_eq!(idl.metadata.origin, "shank");
+    assert_eq!(
+        idl.metadata.address.as_deref(),
+        Some("45A6jtRE6Tr71EpRATyWF8FYUNP7LEZ7NFd3Xb9LJ4TR")
+    );
+    let create_person = &idl.instructions[0];
+    assert_eq!(create_person.name, "CreatePerson");
+    assert_eq!(create_person.discriminant.value, 0);
+    let accounts: Vec<(&str, &str, bool, bool)> = create_person
+        .accounts
+        .iter()
+        .map(|a| {
+            (
+                a.name.as_str(),
+                a.account_type.as_str(),
+                a.is_mut,
+                a.is_signer,
+            )
+        })
+        .collect();
+    assert_eq!(
+        accounts,
+        vec![
+            ("autoincAccount", "account", true, false),
+            ("newAccount", "account", true, false),
+            ("authority", "account", false, true),
+            ("feePayer", "feePayer", true, true),
+            ("systemProgram", "systemProgram", false, false),
+        ]
+    );
+    assert_eq!(
+        create_person.accounts[2].desc,
+        "Record authority".to_string()
+    );
+    assert!(matches!(
+        &create_person.args[0].arg_type,
+        IdlType::Defined(name) if name == "CreatePersonArgs"
+    ));
+    assert_eq!(idl.accounts[0].name, "Person");
+    assert!(idl.accounts[0].config.is_none());
+    assert_eq!(idl.types.len(), 2);
+    match &idl.types[1].idl_type {
+        IdlTypeDefType::Struct { fields } => assert!(matches!(
+            &fields[2].field_data_type,
+            IdlType::Option(t) if matches!(**t, IdlType::PublicKey)
+        )),
+        IdlTypeDefType::Enum { .. } => panic!("Expected a struct."),
+    }
+
+    let nested = serde_json::json!({
+        "version": "0.1.0",
+        "name": "nested",
+        "instructions": [{
+            "name": "Mint",
+            "accounts": [
+                {
+                    "name": "mintAccounts",
+                    "accounts": [
+                        { "name": "mint", "isMut": true, "isSigner": true, "docs": ["The new mint"] },
+                        { "name": "tokenProgram", "isMut": false, "isSigner": false }
+                    ]
+                },
+                { "name": "rent", "isMut": false, "isSigner": false, "optional": true }
+            ],
+            "args": [{ "name": "amount", "type": { "hashMap": ["string", "u64"] } }],
+            "discriminant": { "type": "u8", "value": 7 }
+        }],
+        "errors": [{ "code": 0, "name": "InvalidMint", "msg": "Invalid mint" }],
+        "metadata": { "origin": "shank" }
+    });
+    let idl = Idl::from_shank_json(&nested.to_string()).unwrap();
+    let account_types: Vec<&str> = idl.instructions[0]
+        .accounts
+        .iter()
+        .map(|a| a.account_type.as_str())
+        .collect();
+    assert_eq!(account_types, vec!["account", "tokenProgram", "sysvar"]);
+    assert_eq!(idl.instructions[0].accounts[0].desc, "The new mint");
+    assert!(matches!(
+        idl.instructions[0].args[0].arg_type,
+        IdlType::HashMap(..)
+    ));
+    assert_eq!(idl.errors[0].msg.as_deref(), Some("Invalid mint"));
+
+    let mut u32_discriminant = nested.clone();
+    u32_discriminant["instructions"][0]["discriminant"] =
+        serde_json::json!({ "type": "u32", "value": 7 });
+    let err = Idl::from_shank_json(&u32_discriminant.to_string()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut c_option = nested;
+    c_option["instructions"][0]["args"][0]["type"] = serde_json::json!({ "cOption": "u64" });
+    assert!(Idl::from_shank_json(&c_option.to_string()).is_err());
+}
+
+#[test]
+fn idl_diff() {
+    use nautilus_idl::{
+        diff::{IdlChangeKind, IdlChangeSeverity},
+        idl_error::IdlError,
+        idl_nautilus_config::IdlTypeDefNautilusConfig,
+        idl_type_def::{IdlTypeEnumFields, IdlTypeEnumVariant},
+    };
+
+    let build = |hero_fields: Vec<(&str, IdlType)>,
+                 variants: Vec<&str>,
+                 primary_key: &str,
+                 owner_is_signer: bool,
+                 payer_is_mut: bool,
+                 discriminant: u8,
+                 extra_instruction: bool| {
+        let mut hero = struct_type_def("Hero", hero_fields);
+        hero.config = Some(IdlTypeDefNautilusConfig {
+            discrminator_str: None,
+            table_name: Some("hero".to_string()),
+            primary_key: Some(primary_key.to_string()),
+            autoincrement: Some(true),
+            authorities: vec![],
+            default_instructions: vec![],
+            seeds: vec![],
+        });
+        let class = IdlTypeDef::new(
+            "Class".to_string(),
+            IdlTypeDefType::Enum {
+                variants: variants
+                    .into_iter()
+                    .map(|name| IdlTypeEnumVariant {
+                        name: name.to_string(),
+                        docs: vec![],
+                        fields: Some(IdlTypeEnumFields::Tuple(vec![IdlType::U8])),
+                    })
+                    .collect(),
+            },
+            None,
+        );
+        let mut instructions = vec![IdlInstruction::new(
+            "createHero",
+            vec![
+                account("owner", false, owner_is_signer, "owner"),
+                account("payer", payer_is_mut, true, "payer"),
+            ],
+            vec![IdlInstructionArg::new("name".to_string(), IdlType::String)],
+            IdlInstructionDiscriminant::new(discriminant),
+        )];
+        if extra_instruction {
+            instructions.push(IdlInstruction::new(
+                "retireHero",
+                vec![],
+                vec![],
+                IdlInstructionDiscriminant::new(9),
+            ));
+        }
+        let mut idl = Idl::new(
+            "0.1.0".to_string(),
+            "heroes".to_string(),
+            instructions,
+            vec![hero],
+            vec![class],
+            IdlMetadata::new_with_no_id(),
+        );
+        idl.errors = vec![IdlError::new(200, "LoadDataFailed", "Failed to load")];
+        idl
+    };
+
+    let old = build(
+        vec![
+            ("id", IdlType::U32),
+            ("name", IdlType::String),
+            ("level", IdlType::U8),
+            ("xp", IdlType::U64),
+        ],
+        vec!["Warrior", "Mage"],
+        "id",
+        false,
+        true,
+        0,
+        false,
+    );
+    assert!(!old.diff(&old).breaking);
+    assert!(old.diff(&old).changes.is_empty());
+
+    // Appending an enum variant, loosening an account's requirements and
+    // adding an instruction doesn't break anyone.
+    let compatible = build(
+        vec![
+            ("id", IdlType::U32),
+            ("name", IdlType::String),
+            ("level", IdlType::U8),
+            ("xp", IdlType::U64),
+        ],
+        vec!["Warrior", "Mage", "Rogue"],
+        "id",
+        false,
+        false,
+        0,
+        true,
+    );
+    let diff = old.diff(&compatible);
+    assert!(!diff.breaking);
+    let changes: Vec<(IdlChangeKind, &str)> = diff
+        .changes
+        .iter()
+        .map(|c| (c.kind, c.path.as_str()))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (IdlChangeKind::VariantAdded, "types.Class.Rogue"),
+            (
+                IdlChangeKind::MutabilityChanged,
+                "instructions.createHero.accounts.payer"
+            ),
+            (IdlChangeKind::InstructionAdded, "instructions.retireHero"),
+        ]
+    );
+
+    let breaking = build(
+        vec![
+            ("id", IdlType::U32),
+            ("title", IdlType::String),
+            ("xp", IdlType::U64),
+            ("level", IdlType::U16),
+        ],
+        vec!["Mage", "Warrior"],
+        "title",
+        true,
+        true,
+        1,
+        false,
+    );
+    let diff = old.diff(&breaking);
+    assert!(diff.breaking);
+    assert!(diff
+        .changes
+        .iter()
+        .all(|c| c.severity == IdlChangeSeverity::Breaking));
+    let changes: Vec<(IdlChangeKind, &str)> = diff
+        .changes
+        .iter()
+        .map(|c| (c.kind, c.path.as_str()))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (IdlChangeKind::FieldRenamed, "accounts.Hero.title"),
+            (IdlChangeKind::FieldReordered, "accounts.Hero.level"),
+            (IdlChangeKind::FieldReordered, "accounts.Hero.xp"),
+            (IdlChangeKind::FieldTypeChanged, "accounts.Hero.level"),
+            (IdlChangeKind::PrimaryKeyChanged, "accounts.Hero"),
+            (IdlChangeKind::VariantReordered, "types.Class.Warrior"),
+            (IdlChangeKind::VariantReordered, "types.Class.Mage"),
+            (
+                IdlChangeKind::DiscriminantChanged,
+                "instructions.createHero"
+            ),
+            (
+                IdlChangeKind::SignerChanged,
+                "instructions.createHero.accounts.owner"
+            ),
+        ]
+    );
+    let level_type = &diff.changes[3];
+    assert_eq!(level_type.old, Some(serde_json::json!("u8")));
+    assert_eq!(level_type.new, Some(serde_json::json!("u16")));
+
+    let report: serde_json::Value = serde_json::from_str(&diff.to_json().unwrap()).unwrap();
+    assert_eq!(report["breaking"], true);
+    assert_eq!(report["changes"][0]["kind"], "fieldRenamed");
+    assert_eq!(report["changes"][0]["severity"], "breaking");
+    assert_eq!(report["changes"][0]["old"], "name");
+}
+
+#[test]
+fn decode_accounts_and_instructions() {
+    use nautilus_idl::idl_nautilus_config::IdlTypeDefNautilusConfig;
+
+    let hero: syn::ItemStruct = syn::parse_quote! {
+        struct Hero {
+            id: u8,
+            name: String,
+            owner: Pubkey,
+            power: u128,
+            class: Class,
+            guild: Option<String>,
+            stats: BTreeMap<u8, i16>,
+            position: (i32, i32),
+        }
+    };
+    let class: syn::ItemEnum = syn::parse_quote! {
+        enum Class { Knight, Mage { mana: u16 }, Rogue(u8) }
+    };
+    let mut hero = IdlTypeDef::try_from(&hero).unwrap();
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some("hero".to_string()),
+        table_name: None,
+        primary_key: None,
+        autoincrement: None,
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    let class = IdlTypeDef::try_from(&class).unwrap();
+    let instructions = vec![IdlInstruction::new(
+        "renameHero",
+        vec![
+            account("hero", true, false, ""),
+            account("owner", false, true, ""),
+        ],
+        vec![
+            IdlInstructionArg::new("name".to_string(), IdlType::String),
+            IdlInstructionArg::new("class".to_string(), IdlType::Defined("Class".to_string())),
+        ],
+        IdlInstructionDiscriminant::new(3),
+    )];
+    let idl = Idl::new(
+        "0.1.0".to_string(),
+        "heroes".to_string(),
+        instructions,
+        vec![hero.clone()],
+        vec![class],
+        IdlMetadata::new_with_no_id(),
+    );
+
+    let mut data = hero.discriminator().unwrap().to_vec();
+    data.push(7);
+    data.extend(4u32.to_le_bytes());
+    data.extend(b"Lyra");
+    data.extend([0u8; 32]);
+    data.extend(u128::MAX.to_le_bytes());
+    data.extend([1, 9, 0]); // Class::Mage { mana: 9 }
+    data.push(0); // No guild.
+    data.extend(1u32.to_le_bytes());
+    data.extend([2, 0xff, 0xff]); // { 2: -1 }
+    data.extend((-3i32).to_le_bytes());
+    data.extend(4i32.to_le_bytes());
+    data.extend([0; 16]); // Unused space.
+
+    let decoded = idl.decode_account(&data).unwrap();
+    assert_eq!(
+        decoded,
+        serde_json::json!({
+            "name": "Hero",
+            "data": {
+                "id": 7,
+                "name": "Lyra",
+                "owner": "11111111111111111111111111111111",
+                "power": u128::MAX.to_string(),
+                "class": { "Mage": { "mana": 9 } },
+                "guild": null,
+                "stats": [[2, -1]],
+                "position": [-3, 4],
+            }
+        })
+    );
+    assert!(idl.decode_account(&data[..20]).is_err());
+    assert!(idl.decode_account(&[0; 16]).is_err());
+
+    let mut data = vec![3];
+    data.extend(2u32.to_le_bytes());
+    data.extend(b"Io");
+    data.extend([2, 5]); // Class::Rogue(5)
+    let accounts = ["Hero1111", "Owner111", "Extra111"];
+    let decoded = idl.decode_instruction(&data, &accounts).unwrap();
+    assert_eq!(
+        decoded,
+        serde_json::json!({
+            "name": "renameHero",
+            "args": { "name": "Io", "class": { "Rogue": [5] } },
+            "accounts": [
+                { "name": "hero", "pubkey": "Hero1111", "isMut": true, "isSigner": false },
+                { "name": "owner", "pubkey": "Owner111", "isMut": false, "isSigner": true },
+                { "name": null, "pubkey": "Extra111" },
+            ]
+        })
+    );
+
+    data[5] = b'\xff'; // Invalid UTF-8.
+    assert!(idl.decode_instruction(&data, &accounts).is_err());
+    data.push(0);
+    assert!(idl.decode_instruction(&data, &accounts).is_err());
+    assert!(idl.decode_instruction(&[3], &accounts[..1]).is_err());
+    assert!(idl.decode_instruction(&[9], &accounts).is_err());
+}
+
+#[test]
+fn sql_tables_and_json_schemas() {
+    use nautilus_idl::{
+        converters::{
+            json_schema::JsonSchemaConverter,
+            sql::{SqlConverter, SqlDialect},
+        },
+        idl_nautilus_config::IdlTypeDefNautilusConfig,
+    };
+
+    let hero: syn::ItemStruct = syn::parse_quote! {
+        /// A hero of the realm.
+        struct Hero {
+            id: u32,
+            /// Shown on the leaderboard.
+            name: String,
+            owner: Pubkey,
+            gold: u64,
+            guild: Option<String>,
+            class: Class,
+            sigil: [u8; 4],
+        }
+    };
+    let class: syn::ItemEnum = syn::parse_quote! {
+        enum Class { Knight, Mage { mana: u16 }, Rogue(u8) }
+    };
+    let mut hero = IdlTypeDef::try_from(&hero).unwrap();
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: None,
+        table_name: Some("hero".to_string()),
+        primary_key: Some("id".to_string()),
+        autoincrement: Some(true),
+        authorities: vec!["owner".to_string()],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    let class = IdlTypeDef::try_from(&class).unwrap();
+    let idl = Idl::new(
+        "0.1.0".to_string(),
+        "heroes".to_string(),
+        vec![],
+        vec![hero],
+        vec![class],
+        IdlMetadata::new_with_no_id(),
+    );
+
+    assert_eq!(
+        idl.to_sql_string(SqlDialect::Sqlite),
+        r#"-- Generated by Nautilus from the `heroes` IDL (v0.1.0) for SQLite. Do not edit by hand.
+
+-- A hero of the realm.
+CREATE TABLE IF NOT EXISTS "hero" (
+    "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+    -- Shown on the leaderboard.
+    "name" TEXT NOT NULL,
+    -- Authority: must sign to change this record.
+    "owner" TEXT NOT NULL,
+    "gold" TEXT NOT NULL,
+    "guild" TEXT,
+    "class" TEXT NOT NULL,
+    "sigil" BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS "hero_owner_idx" ON "hero" ("owner");
+"#
+    );
+    let postgres = idl.to_sql_string(SqlDialect::Postgres);
+    assert!(postgres.contains(r#""id" BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,"#));
+    assert!(postgres.contains(r#""owner" VARCHAR(44) NOT NULL,"#));
+    assert!(postgres.contains(r#""gold" NUMERIC(20, 0) NOT NULL,"#));
+    assert!(postgres.contains(r#""class" JSONB NOT NULL,"#));
+    assert!(postgres.contains(r#""sigil" BYTEA NOT NULL"#));
+
+    let schema = idl.to_json_schema("Hero").unwrap();
+    assert_eq!(schema["$ref"], "#/$defs/Hero");
+    let hero = &schema["$defs"]["Hero"];
+    assert_eq!(hero["description"], "A hero of the realm.");
+    assert_eq!(hero["additionalProperties"], false);
+    assert_eq!(hero["required"].as_array().unwrap().len(), 7);
+    assert_eq!(
+        hero["properties"]["id"],
+        serde_json::json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX })
+    );
+    assert_eq!(
+        hero["properties"]["name"]["description"],
+        "Shown on the leaderboard."
+    );
+    assert_eq!(
+        hero["properties"]["guild"],
+        serde_json::json!({ "anyOf": [{ "type": "null" }, { "type": "string" }] })
+    );
+    assert_eq!(
+        hero["properties"]["class"],
+        serde_json::json!({ "$ref": "#/$defs/Class" })
+    );
+    assert_eq!(hero["properties"]["sigil"]["maxItems"], 4);
+    assert_eq!(
+        schema["$defs"]["Class"]["oneOf"][0],
+        serde_json::json!({ "const": "Knight" })
+    );
+    assert_eq!(
+        schema["$defs"]["Class"]["oneOf"][2]["properties"]["Rogue"]["prefixItems"],
+        serde_json::json!([{ "type": "integer", "minimum": 0, "maximum": 255 }])
+    );
+    assert!(idl.to_json_schema("Villain").is_none());
+}
+
+#[test]
+fn markdown_and_html_docs() {
+    use nautilus_idl::{
+        converters::docs::DocsConverter, idl_error::IdlError,
+        idl_nautilus_config::IdlTypeDefNautilusConfig,
+    };
+
+    let hero: syn::ItemStruct = syn::parse_quote! {
+        /// A hero of the realm.
+        struct Hero {
+            id: u32,
+            level: u8,
+            /// Shown on the `leaderboard`.
+            name: String,
+            owner: Pubkey,
+        }
+    };
+    let class: syn::ItemEnum = syn::parse_quote! {
+        enum Class { Knight, Mage { mana: u16 }, Rogue(u8) }
+    };
+    let mut hero = IdlTypeDef::try_from(&hero).unwrap();
+    hero.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: None,
+        table_name: Some("hero".to_string()),
+        primary_key: Some("id".to_string()),
+        autoincrement: Some(true),
+        authorities: vec!["owner".to_string()],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    let class = IdlTypeDef::try_from(&class).unwrap();
+    let mut rename_hero = IdlInstruction::new(
+        "renameHero",
+        vec![
+            account("hero", true, false, "The hero to rename"),
+            account("owner", false, true, "The hero's owner"),
+        ],
+        vec![
+            IdlInstructionArg::new("name".to_string(), IdlType::String),
+            IdlInstructionArg::new("class".to_string(), IdlType::Defined("Class".to_string())),
+        ],
+        IdlInstructionDiscriminant::new(3),
+    );
+    rename_hero.docs = vec!["Renames a hero.".to_string()];
+    let mut idl = Idl::new(
+        "0.1.0".to_string(),
+        "heroes".to_string(),
+        vec![rename_hero],
+        vec![hero],
+        vec![class],
+        IdlMetadata::new_with_no_id(),
+    );
+    idl.errors = vec![IdlError::new(6000, "Fallen", "The hero is <fallen>")];
+
+    let markdown = idl.to_markdown_string();
+    for expected in [
+        "| `3` | [`renameHero`](#renamehero) | 2 | 2 |",
+        "### renameHero\n\nRenames a hero.\n\nDiscriminant: `3`",
+        "| 0 | `hero` | yes | no | The hero to rename |",
+        "| 1 | `owner` | no | yes | The hero's owner |",
+        "| 1 | `name` | `String` | variable |  |",
+        "| — | `class` | [`Class`](#class) | variable |  |",
+        "- Primary key: `id` (autoincrement)",
+        "- Authorities: `owner`",
+        "- Address: program-derived from `[\"hero\", id: u32]`",
+        "| 0 | discriminator | `[u8; 8]` | 8 | Identifies the account's type. |",
+        "| 12 | `level` | `u8` | 1 |  |",
+        "| 13 | `name` | `String` | variable | Shown on the `leaderboard`. |",
+        "| — | `owner` | `Pubkey` | 32 |  |",
+        "| 1 | `Mage` | `{ mana: u16 }` |  |",
+        "| `6000 (0x1770)` | `Fallen` | The hero is <fallen> |",
+    ] {
+        assert!(markdown.contains(expected), "missing {:?}", expected);
+    }
+
+    let html = idl.to_html_string();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h3 id=\"renamehero\">renameHero</h3>"));
+    assert!(html.contains("<td><a href=\"#class\"><code>Class</code></a></td>"));
+    assert!(html.contains("<td>Shown on the <code>leaderboard</code>.</td>"));
+    assert!(html.contains("<td>The hero is &lt;fallen&gt;</td>"));
+}
+
+#[test]
+fn transaction_logs() {
+    use nautilus_idl::{
+        idl_error::{nautilus_errors, IdlError},
+        logs::{ComputeUnits, LoggedError, Outcome},
+    };
+
+    let program = "Nautiius1111111111111111111111111111111111";
+    let token = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+    let mut idl = Idl::new(
+        "0.1.0".to_string(),
+        "heroes".to_string(),
+        vec![IdlInstruction::new(
+            "createHero",
+            vec![],
+            vec![],
+            IdlInstructionDiscriminant::new(0),
+        )],
+        vec![],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    );
+    idl.errors = nautilus_errors();
+    idl.errors
+        .push(IdlError::new(6000, "Fallen", "The hero has fallen"));
+    let lines = |lines: &[&str]| -> Vec<String> { lines.iter().map(|l| l.to_string()).collect() };
+
+    let logs = lines(&[
+        &format!("Program {} invoke [1]", program),
+        "Program log: Instruction: CreateHero",
+        &format!("Program {} invoke [2]", token),
+        "Program log: Instruction: MintTo",
+        &format!("Program {} consumed 4500 of 190000 compute units", token),
+        &format!("Program {} success", token),
+        "Program data: aGVybw== Ym9ybg==",
+        &format!("Program return: {} AQI=", program),
+        &format!("Program {} consumed 12000 of 200000 compute units", program),
+        &format!("Program {} success", program),
+        &format!("Program {} invoke [1]", program),
+        "Program log: Instruction: CreateHero",
+        "Program log: ❌ This account was marked with `Signer<T>` but was not passed in as signer: Payer111",
+        &format!("Program {} consumed 800 of 188000 compute units", program),
+        &format!("Program {} failed: custom program error: 0xcc", program),
+    ]);
+    let invocations = idl.parse_logs(program, &logs);
+    assert_eq!(invocations.len(), 2);
+
+    let created = &invocations[0];
+    assert_eq!(created.instruction.as_deref(), Some("createHero"));
+    assert_eq!(created.outcome, Outcome::Success);
+    assert_eq!(
+        created.compute_units,
+        Some(ComputeUnits {
+            consumed: 12000,
+            limit: 200000
+        })
+    );
+    assert_eq!(
+        created.events,
+        vec![vec![b"hero".to_vec(), b"born".to_vec()]]
+    );
+    assert_eq!(created.return_data, Some(vec![1, 2]));
+    let mint_to = &created.invocations[0];
+    assert_eq!((mint_to.program_id.as_str(), mint_to.depth), (token, 2));
+    // Only the IDL's program has its instructions renamed.
+    assert_eq!(mint_to.instruction.as_deref(), Some("MintTo"));
+    assert_eq!(created.iter().count(), 2);
+    assert!(created.failure().is_none());
+
+    let failed = &invocations[1];
+    assert_eq!(
+        failed.logged_errors,
+        vec![LoggedError {
+            name: "AccountNotSigner".to_string(),
+            code: 204,
+            args: vec!["Payer111".to_string()],
+        }]
+    );
+    match &failed.failure().unwrap().outcome {
+        Outcome::Failed(error) => {
+            assert_eq!(error.message, "custom program error: 0xcc");
+            assert_eq!(error.code, Some(204));
+            assert_eq!(error.name.as_deref(), Some("AccountNotSigner"));
+        }
+        outcome => panic!("Expected a failure, found {:?}", outcome),
+    }
+
+    // User errors come from the IDL, and other programs' errors aren't
+    // described with it.
+    let logs = lines(&[
+        &format!("Program {} invoke [1]", program),
+        "Program log: Failed to load hero data from account: Hero111",
+        &format!("Program {} invoke [2]", token),
+        &format!("Program {} failed: custom program error: 0x1", token),
+        &format!("Program {} failed: custom program error: 0x1770", program),
+    ]);
+    let invocation = &idl.parse_logs(program, &logs)[0];
+    assert_eq!(
+        invocation.logged_errors[0].args,
+        vec!["hero".to_string(), "Hero111".to_string()]
+    );
+    let errors: Vec<_> = invocation
+        .iter()
+        .map(|i| match &i.outcome {
+            Outcome::Failed(error) => (error.code, error.name.clone()),
+            outcome => panic!("Expected a failure, found {:?}", outcome),
+        })
+        .collect();
+    assert_eq!(
+        errors,
+        vec![(Some(6000), Some("Fallen".to_string())), (Some(1), None)]
+    );
+
+    // Truncated logs leave unfinished invocations' outcomes unknown.
+    let logs = lines(&[
+        &format!("Program {} invoke [1]", program),
+        "Program log: Instruction: CreateHero",
+        "Log truncated",
+    ]);
+    assert_eq!(idl.parse_logs(program, &logs)[0].outcome, Outcome::Unknown);
+}
+
+#[test]
+fn compute_profile() {
+    use nautilus_idl::profile::Phase;
+
+    let program = "Nautiius1111111111111111111111111111111111";
+    let system = "11111111111111111111111111111111";
+    let idl = Idl::new(
+        "0.1.0".to_string(),
+        "heroes".to_string(),
+        vec![],
+        vec![],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    );
+    let logs: Vec<String> = [
+        &format!("Program {} invoke [1]", program),
+        "Program log: Instruction: CreateHero",
+        "Program log: CU begin: accounts",
+        "Program consumption: 199000 units remaining",
+        "Program consumption: 198900 units remaining",
+        "Program log: CU end: accounts",
+        "Program log: CU begin: Create::new new_hero",
+        "Program consumption: 198500 units remaining",
+        "Program consumption: 198400 units remaining",
+        "Program log: CU end: Create::new new_hero",
+        "Program log: CU begin: create_hero",
+        "Program consumption: 198000 units remaining",
+        "Program log: CU begin: cpi::system::create_pda",
+        "Program consumption: 197500 units remaining",
+        &format!("Program {} invoke [2]", system),
+        &format!("Program {} success", system),
+        "Program consumption: 195000 units remaining",
+        "Program log: CU end: cpi::system::create_pda",
+        "Program log: CU begin: cpi::system::transfer",
+        "Program consumption: 194500 units remaining",
+        "Program consumption: 194000 units remaining",
+        "Program log: CU end: cpi::system::transfer",
+        "Program log: CU begin: cpi::system::transfer",
+        "Program consumption: 193500 units remaining",
+        "Program consumption: 193000 units remaining",
+        "Program log: CU end: cpi::system::transfer",
+        "Program consumption: 192000 units remaining",
+        "Program log: CU end: create_hero",
+        &format!("Program {} consumed 9000 of 200000 compute units", program),
+        &format!("Program {} success", program),
+    ]
+    .iter()
+    .map(|l| l.to_string())
+    .collect();
+    let invocation = &idl.parse_logs(program, &logs)[0];
+    assert_eq!(invocation.units_remaining.len(), 12);
+    assert_eq!(invocation.units_remaining[0], (2, 199000));
+
+    let profile = invocation.compute_profile();
+    let phase = |name: &str, depth, consumed, nested| Phase {
+        name: name.to_string(),
+        depth,
+        consumed,
+        nested,
+    };
+    assert_eq!(
+        profile.phases,
+        vec![
+            phase("accounts", 0, 100, 0),
+            phase("Create::new new_hero", 0, 100, 0),
+            phase("create_hero", 0, 6000, 3500),
+            phase("cpi::system::create_pda", 1, 2500, 0),
+            phase("cpi::system::transfer", 1, 500, 0),
+            phase("cpi::system::transfer", 1, 500, 0),
+        ]
+    );
+    assert_eq!(profile.phases[2].own(), 2500);
+    assert_eq!(profile.unprofiled(), Some(2800));
+    assert_eq!(
+        profile.totals(),
+        vec![
+            ("accounts", 100),
+            ("Create::new new_hero", 100),
+            ("create_hero", 2500),
+            ("cpi::system::create_pda", 2500),
+            ("cpi::system::transfer", 1000),
+        ]
+    );
+    assert_eq!(
+        profile.to_string(),
+        "\
+phase                         units       own
+accounts                        100       100
+Create::new new_hero            100       100
+create_hero                    6000      2500
+  cpi::system::create_pda      2500      2500
+  cpi::system::transfer         500       500
+  cpi::system::transfer         500       500
+unprofiled                     2800      2800
+total                          9000
+"
+    );
 
-    idl.write_to_json("./target/idl");
+    // A phase that never ends - ie. the program panicked - is left out, and
+    // programs built without profiling have no phases.
+    let logs: Vec<String> = [
+        &format!("Program {} invoke [1]", program),
+        "Program log: CU begin: accounts",
+        "Program consumption: 199000 units remaining",
+        "Program log: CU begin: Record::load hero",
+        "Program consumption: 198000 units remaining",
+        "Program consumption: 197000 units remaining",
+        "Program log: CU end: Record::load hero",
+        &format!("Program {} failed: Program failed to complete", program),
+    ]
+    .iter()
+    .map(|l| l.to_string())
+    .collect();
+    let profile = idl.parse_logs(program, &logs)[0].compute_profile();
+    assert_eq!(profile.phases, vec![phase("Record::load hero", 1, 1000, 0)]);
+    assert_eq!(profile.unprofiled(), None);
+    let logs = vec![format!("Program {} invoke [1]", program)];
+    assert!(idl.parse_logs(program, &logs)[0]
+        .compute_profile()
+        .phases
+        .is_empty());
 }
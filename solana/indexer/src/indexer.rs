@@ -0,0 +1,285 @@
+//! Upserts decoded records into SQLite.
+use std::io;
+
+use nautilus_idl::{
+    converters::sql::{SqlConverter, SqlDialect},
+    idl_type::IdlType,
+    idl_type_def::IdlTypeDefType,
+    Idl,
+};
+use rusqlite::{params, types::Value as SqlValue, Connection, OptionalExtension};
+use serde_json::Value;
+use solana_program::pubkey::Pubkey;
+
+use crate::source::{AccountSource, AccountUpdate};
+
+/// Tracks which row, if any, each of the program's accounts is mirrored to,
+/// and the slot it was last updated at - so rows can be deleted when their
+/// accounts close, and stale updates ignored.
+const ACCOUNTS_TABLE: &str = "_nautilus_accounts";
+
+/// What applying updates did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexStats {
+    /// Records inserted or updated.
+    pub upserted: usize,
+    /// Records removed, since their accounts closed or stopped being records.
+    pub deleted: usize,
+    /// Updates older than one already applied to the same account.
+    pub stale: usize,
+}
+
+impl std::ops::AddAssign for IndexStats {
+    fn add_assign(&mut self, other: Self) {
+        self.upserted += other.upserted;
+        self.deleted += other.deleted;
+        self.stale += other.stale;
+    }
+}
+
+/// A table the indexer writes to.
+struct Table {
+    discriminator: [u8; 8],
+    name: String,
+    /// Each column, its type and whether it's an autoincremented primary key.
+    /// Enum records have a single `value` column and no type.
+    columns: Vec<(String, Option<IdlType>, bool)>,
+}
+
+/// Mirrors a program's tables into a SQLite database.
+pub struct Indexer {
+    idl: Idl,
+    program_id: Pubkey,
+    connection: Connection,
+    tables: Vec<Table>,
+}
+
+impl Indexer {
+    /// Creates any of the IDL's tables missing from the database - using the
+    /// same `CREATE TABLE` statements as `nautilus idl sql` - ready to index
+    /// `program_id`'s accounts.
+    pub fn new(idl: Idl, program_id: Pubkey, connection: Connection) -> io::Result<Self> {
+        connection
+            .execute_batch(&idl.to_sql_string(SqlDialect::Sqlite))
+            .map_err(sqlite_error)?;
+        connection
+            .execute_batch(&format!(
+                "CREATE TABLE IF NOT EXISTS \"{}\" (
+    \"address\" TEXT PRIMARY KEY,
+    \"table_name\" TEXT,
+    \"row_id\" INTEGER,
+    \"slot\" INTEGER NOT NULL
+);",
+                ACCOUNTS_TABLE
+            ))
+            .map_err(sqlite_error)?;
+        let tables = idl
+            .accounts
+            .iter()
+            .filter_map(|account| {
+                let config = account.config.as_ref()?;
+                let name = config.table_name.clone()?;
+                let columns = match &account.idl_type {
+                    IdlTypeDefType::Struct { fields } => fields
+                        .iter()
+                        .map(|f| {
+                            let autoincrement = config.primary_key.as_ref() == Some(&f.name)
+                                && config.autoincrement == Some(true);
+                            (
+                                f.name.clone(),
+                                Some(f.field_data_type.clone()),
+                                autoincrement,
+                            )
+                        })
+                        .collect(),
+                    IdlTypeDefType::Enum { .. } => vec![("value".to_string(), None, false)],
+                };
+                Some(Table {
+                    discriminator: account.discriminator()?,
+                    name,
+                    columns,
+                })
+            })
+            .collect();
+        Ok(Self {
+            idl,
+            program_id,
+            connection,
+            tables,
+        })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// Applies updates from `source` until it's exhausted, calling
+    /// `on_batch` after each batch.
+    pub fn run(
+        &mut self,
+        source: &mut (impl AccountSource + ?Sized),
+        mut on_batch: impl FnMut(IndexStats),
+    ) -> io::Result<IndexStats> {
+        let mut total = IndexStats::default();
+        while let Some(batch) = source.next_batch()? {
+            let stats = self.apply(&batch)?;
+            on_batch(stats);
+            total += stats;
+        }
+        Ok(total)
+    }
+
+    /// Applies a batch of updates in one transaction.
+    ///
+    /// An account's row is replaced whenever it changes. Accounts that close,
+    /// leave the program, or no longer hold a record have their rows deleted.
+    pub fn apply(&mut self, updates: &[AccountUpdate]) -> io::Result<IndexStats> {
+        let transaction = self.connection.transaction().map_err(sqlite_error)?;
+        let mut stats = IndexStats::default();
+        for update in updates {
+            let address = update.pubkey.to_string();
+            let existing: Option<(Option<String>, Option<i64>, i64)> = transaction
+                .query_row(
+                    &format!(
+                        "SELECT \"table_name\", \"row_id\", \"slot\" FROM \"{}\" WHERE \"address\" = ?1",
+                        ACCOUNTS_TABLE
+                    ),
+                    params![address],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+                )
+                .optional()
+                .map_err(sqlite_error)?;
+            if let Some((_, _, slot)) = existing {
+                if (update.slot as i64) < slot {
+                    stats.stale += 1;
+                    continue;
+                }
+            }
+            let old_row = existing.and_then(|(table, row_id, _)| Some((table?, row_id?)));
+
+            let record = match update.owner == self.program_id && update.lamports > 0 {
+                true => record(&self.idl, &self.tables, update),
+                false => None,
+            };
+            if let Some((table, row_id)) = &old_row {
+                transaction
+                    .execute(
+                        &format!("DELETE FROM {} WHERE rowid = ?1", quote(table)),
+                        params![row_id],
+                    )
+                    .map_err(sqlite_error)?;
+                if record.is_none() {
+                    stats.deleted += 1;
+                }
+            }
+            let (table_name, row_id) = match record {
+                Some((table, values)) => {
+                    let columns: Vec<String> = table.columns.iter().map(|c| quote(&c.0)).collect();
+                    let placeholders: Vec<String> =
+                        (1..=columns.len()).map(|i| format!("?{}", i)).collect();
+                    transaction
+                        .execute(
+                            &format!(
+                                "INSERT OR REPLACE INTO {} ({}) VALUES ({})",
+                                quote(&table.name),
+                                columns.join(", "),
+                                placeholders.join(", ")
+                            ),
+                            rusqlite::params_from_iter(values),
+                        )
+                        .map_err(sqlite_error)?;
+                    stats.upserted += 1;
+                    (
+                        Some(table.name.clone()),
+                        Some(transaction.last_insert_rowid()),
+                    )
+                }
+                None => (None, None),
+            };
+            transaction
+                .execute(
+                    &format!(
+                        "INSERT OR REPLACE INTO \"{}\" (\"address\", \"table_name\", \"row_id\", \"slot\") VALUES (?1, ?2, ?3, ?4)",
+                        ACCOUNTS_TABLE
+                    ),
+                    params![address, table_name, row_id, update.slot as i64],
+                )
+                .map_err(sqlite_error)?;
+        }
+        transaction.commit().map_err(sqlite_error)?;
+        Ok(stats)
+    }
+}
+
+/// The table an account's record belongs in and its columns' values, or `None`
+/// if it doesn't hold a record.
+fn record<'a>(
+    idl: &Idl,
+    tables: &'a [Table],
+    update: &AccountUpdate,
+) -> Option<(&'a Table, Vec<SqlValue>)> {
+    let discriminator = update.data.get(..8)?;
+    let table = tables.iter().find(|t| t.discriminator == discriminator)?;
+    // Accounts that don't decode - ie. from an older version of the
+    // program - aren't records this IDL can describe.
+    let mut decoded = idl.decode_account(&update.data).ok()?;
+    let data = decoded.get_mut("data")?.take();
+    let values = table
+        .columns
+        .iter()
+        .map(|(name, ty, autoincrement)| match ty {
+            Some(ty) => {
+                let value = data.get(name).cloned().unwrap_or(Value::Null);
+                match autoincrement {
+                    true => value.as_i64().map_or(SqlValue::Null, SqlValue::Integer),
+                    false => column_value(value, ty),
+                }
+            }
+            None => SqlValue::Text(data.to_string()),
+        })
+        .collect();
+    Some((table, values))
+}
+
+/// Converts a decoded value to the column type `nautilus idl sql` gives its
+/// type.
+fn column_value(value: Value, ty: &IdlType) -> SqlValue {
+    match (value, ty) {
+        (Value::Null, _) => SqlValue::Null,
+        (value, IdlType::Option(inner)) => column_value(value, inner),
+        (Value::Bool(b), IdlType::Bool) => SqlValue::Integer(b as i64),
+        (
+            Value::Number(n),
+            IdlType::U8
+            | IdlType::U16
+            | IdlType::U32
+            | IdlType::I8
+            | IdlType::I16
+            | IdlType::I32
+            | IdlType::I64,
+        ) => n.as_i64().map_or(SqlValue::Null, SqlValue::Integer),
+        // Kept exact as text, like the decoder writes `u128`s.
+        (Value::Number(n), IdlType::U64) => SqlValue::Text(n.to_string()),
+        (Value::String(s), _) => SqlValue::Text(s),
+        (Value::Array(bytes), IdlType::Bytes) => SqlValue::Blob(blob(&bytes)),
+        (Value::Array(bytes), IdlType::Array(inner, _)) if **inner == IdlType::U8 => {
+            SqlValue::Blob(blob(&bytes))
+        }
+        (value, _) => SqlValue::Text(value.to_string()),
+    }
+}
+
+fn blob(bytes: &[Value]) -> Vec<u8> {
+    bytes
+        .iter()
+        .filter_map(|b| b.as_u64().map(|b| b as u8))
+        .collect()
+}
+
+fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn sqlite_error(error: rusqlite::Error) -> io::Error {
+    io::Error::other(error)
+}
@@ -0,0 +1,35 @@
+//
+//
+// ----------------------------------------------------------------
+//                        Nautilus Indexer
+// ----------------------------------------------------------------
+//
+// Mirrors a Nautilus program's tables into SQLite, so they can be queried
+// off-chain with real SQL.
+//
+// Account updates come from an `AccountSource`:
+// * `RpcPoller` polls a cluster's `getProgramAccounts`.
+// * `ChannelSource` receives updates pushed by a Geyser plugin or any other
+//   stream.
+// * `ReplaySource` reads updates recorded in a file.
+//
+// Every account with a table's discriminator is decoded with the IDL and
+// upserted into the tables `nautilus idl sql` generates. Rows are deleted
+// when their accounts close.
+//
+//
+use std::io;
+
+pub use self::{
+    indexer::{IndexStats, Indexer},
+    rpc::RpcPoller,
+    source::{write_replay, AccountSource, AccountUpdate, ChannelSource, ReplaySource},
+};
+
+pub mod indexer;
+pub mod rpc;
+pub mod source;
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
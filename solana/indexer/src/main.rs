@@ -0,0 +1,78 @@
+//
+//
+// ----------------------------------------------------------------
+//                        Nautilus Indexer
+// ----------------------------------------------------------------
+//
+//
+use std::{str::FromStr, time::Duration};
+
+use clap::Parser;
+use nautilus_indexer::{AccountSource, IndexStats, Indexer, ReplaySource, RpcPoller};
+use rusqlite::Connection;
+use solana_program::pubkey::Pubkey;
+
+/// 🗄️  Mirrors a Nautilus program's tables into SQLite
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// The program's IDL
+    #[arg(short, long)]
+    idl: String,
+    /// The SQLite database to write to, created if it doesn't exist
+    #[arg(short, long)]
+    db: String,
+    /// The program's address, if the IDL doesn't have it
+    #[arg(short, long)]
+    program_id: Option<String>,
+    /// Polls the program's accounts from this RPC
+    #[arg(long, conflicts_with = "replay", required_unless_present = "replay")]
+    rpc: Option<String>,
+    /// Seconds between polls
+    #[arg(long, default_value_t = 10)]
+    interval: u64,
+    /// Replays account updates recorded in this file
+    #[arg(long)]
+    replay: Option<String>,
+}
+
+fn main() -> std::io::Result<()> {
+    let cli = Cli::parse();
+    let idl = nautilus_idl::util::load_idl_from_json(&cli.idl)?;
+    let program_id = cli
+        .program_id
+        .as_deref()
+        .or(idl.metadata.address.as_deref())
+        .ok_or_else(|| invalid_input("The IDL has no address, so pass `--program-id`"))?;
+    let program_id = Pubkey::from_str(program_id)
+        .map_err(|_| invalid_input(&format!("`{}` isn't an address", program_id)))?;
+    let connection = Connection::open(&cli.db).map_err(std::io::Error::other)?;
+    let mut indexer = Indexer::new(idl, program_id, connection)?;
+
+    let mut source: Box<dyn AccountSource> = match (&cli.rpc, &cli.replay) {
+        (Some(url), _) => Box::new(RpcPoller::new(
+            url,
+            program_id,
+            Duration::from_secs(cli.interval),
+        )),
+        (None, Some(replay)) => Box::new(ReplaySource::open(replay)?),
+        (None, None) => unreachable!("clap requires one of `--rpc` and `--replay`"),
+    };
+    let total = indexer.run(&mut *source, |stats| {
+        if stats != IndexStats::default() {
+            println!(
+                " 🗄️  {} upserted, {} deleted, {} stale",
+                stats.upserted, stats.deleted, stats.stale
+            );
+        }
+    })?;
+    println!(
+        " ✅  Done: {} upserted, {} deleted, {} stale",
+        total.upserted, total.deleted, total.stale
+    );
+    Ok(())
+}
+
+fn invalid_input(message: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message)
+}
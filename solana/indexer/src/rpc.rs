@@ -0,0 +1,152 @@
+//! Polls a cluster's RPC for the program's accounts.
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    io,
+    str::FromStr,
+    thread,
+    time::Duration,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use serde_json::json;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    invalid_data,
+    source::{AccountSource, AccountUpdate},
+};
+
+#[derive(Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct WithContext<T> {
+    context: RpcContext,
+    value: T,
+}
+
+#[derive(Deserialize)]
+struct RpcContext {
+    slot: u64,
+}
+
+#[derive(Deserialize)]
+struct KeyedAccount {
+    pubkey: String,
+    account: RpcAccount,
+}
+
+#[derive(Deserialize)]
+struct RpcAccount {
+    lamports: u64,
+    owner: String,
+    /// `[<base64 data>, "base64"]`
+    data: (String, String),
+}
+
+/// Fetches every account the program owns with `getProgramAccounts`, every
+/// `interval`.
+///
+/// Each batch holds the accounts that changed since the last poll. Accounts
+/// that have disappeared since are reported closed.
+pub struct RpcPoller {
+    url: String,
+    program_id: Pubkey,
+    interval: Duration,
+    polled: bool,
+    /// A hash of each account's owner, lamports and data as of the last poll.
+    seen: HashMap<Pubkey, u64>,
+}
+
+impl RpcPoller {
+    pub fn new(url: &str, program_id: Pubkey, interval: Duration) -> Self {
+        Self {
+            url: url.to_string(),
+            program_id,
+            interval,
+            polled: false,
+            seen: HashMap::new(),
+        }
+    }
+
+    fn get_program_accounts(&self) -> io::Result<(u64, Vec<AccountUpdate>)> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getProgramAccounts",
+            "params": [
+                self.program_id.to_string(),
+                { "encoding": "base64", "commitment": "confirmed", "withContext": true },
+            ],
+        });
+        let response: RpcResponse<WithContext<Vec<KeyedAccount>>> = ureq::post(&self.url)
+            .send_json(request)
+            .map_err(|e| io::Error::other(e.to_string()))?
+            .into_json()?;
+        let result = match (response.result, response.error) {
+            (Some(result), _) => result,
+            (None, error) => {
+                return Err(invalid_data(format!(
+                    "`getProgramAccounts` failed: {}",
+                    error.unwrap_or_default()
+                )))
+            }
+        };
+        let slot = result.context.slot;
+        let updates = result
+            .value
+            .into_iter()
+            .map(|keyed| {
+                let pubkey = |s: &str| {
+                    Pubkey::from_str(s)
+                        .map_err(|_| invalid_data(format!("`{}` isn't an address", s)))
+                };
+                Ok(AccountUpdate {
+                    slot,
+                    pubkey: pubkey(&keyed.pubkey)?,
+                    owner: pubkey(&keyed.account.owner)?,
+                    lamports: keyed.account.lamports,
+                    data: STANDARD.decode(&keyed.account.data.0).map_err(|e| {
+                        invalid_data(format!("{}'s data isn't base64: {}", keyed.pubkey, e))
+                    })?,
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        Ok((slot, updates))
+    }
+}
+
+impl AccountSource for RpcPoller {
+    /// Never exhausted - polls until it fails.
+    fn next_batch(&mut self) -> io::Result<Option<Vec<AccountUpdate>>> {
+        if self.polled {
+            thread::sleep(self.interval);
+        }
+        self.polled = true;
+        let (slot, accounts) = self.get_program_accounts()?;
+        let mut seen = HashMap::with_capacity(accounts.len());
+        let mut batch = vec![];
+        for update in accounts {
+            let mut hasher = DefaultHasher::new();
+            (&update.owner, update.lamports, &update.data).hash(&mut hasher);
+            let hash = hasher.finish();
+            seen.insert(update.pubkey, hash);
+            if self.seen.get(&update.pubkey) != Some(&hash) {
+                batch.push(update);
+            }
+        }
+        batch.extend(
+            self.seen
+                .keys()
+                .filter(|pubkey| !seen.contains_key(pubkey))
+                .map(|pubkey| AccountUpdate::closed(slot, *pubkey)),
+        );
+        self.seen = seen;
+        Ok(Some(batch))
+    }
+}
@@ -0,0 +1,175 @@
+//! Where the indexer hears about account changes.
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    str::FromStr,
+    sync::mpsc::Receiver,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::invalid_data;
+
+/// An account's state as of a slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdate {
+    pub slot: u64,
+    pub pubkey: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl AccountUpdate {
+    /// The update for an account closed at `slot`, which has no lamports,
+    /// data or program owning it.
+    pub fn closed(slot: u64, pubkey: Pubkey) -> Self {
+        Self {
+            slot,
+            pubkey,
+            owner: solana_program::system_program::ID,
+            lamports: 0,
+            data: vec![],
+        }
+    }
+}
+
+/// A stream of account updates, delivered in batches.
+pub trait AccountSource {
+    /// The next batch of updates, blocking until there is one. `None` once
+    /// the source has nothing more to give.
+    fn next_batch(&mut self) -> io::Result<Option<Vec<AccountUpdate>>>;
+}
+
+/// Updates pushed into a channel - ie. by a Geyser plugin or a websocket
+/// subscription running on another thread.
+///
+/// Each batch is every update waiting in the channel. The source is exhausted
+/// once every sender is dropped.
+pub struct ChannelSource {
+    receiver: Receiver<AccountUpdate>,
+}
+
+impl ChannelSource {
+    pub fn new(receiver: Receiver<AccountUpdate>) -> Self {
+        Self { receiver }
+    }
+}
+
+impl AccountSource for ChannelSource {
+    fn next_batch(&mut self) -> io::Result<Option<Vec<AccountUpdate>>> {
+        let first = match self.receiver.recv() {
+            Ok(update) => update,
+            Err(_) => return Ok(None),
+        };
+        let mut batch = vec![first];
+        while let Ok(update) = self.receiver.try_recv() {
+            batch.push(update);
+        }
+        Ok(Some(batch))
+    }
+}
+
+/// One line of a replay file.
+#[derive(Deserialize, Serialize)]
+struct ReplayLine {
+    slot: u64,
+    pubkey: String,
+    owner: String,
+    lamports: u64,
+    /// Base64-encoded, as the RPC returns it.
+    data: String,
+}
+
+/// Updates recorded in a file, one JSON object per line:
+///
+/// ```json
+/// { "slot": 42, "pubkey": "<base58>", "owner": "<base58>", "lamports": 1000, "data": "<base64>" }
+/// ```
+///
+/// Each slot's updates are one batch.
+pub struct ReplaySource {
+    lines: std::iter::Peekable<std::iter::Enumerate<io::Lines<BufReader<File>>>>,
+}
+
+impl ReplaySource {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            lines: BufReader::new(File::open(path)?)
+                .lines()
+                .enumerate()
+                .peekable(),
+        })
+    }
+
+    fn next_update(&mut self) -> io::Result<Option<AccountUpdate>> {
+        for (number, line) in self.lines.by_ref() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            return parse_replay_line(&line)
+                .map(Some)
+                .map_err(|e| invalid_data(format!("Line {} of the replay: {}", number + 1, e)));
+        }
+        Ok(None)
+    }
+}
+
+impl AccountSource for ReplaySource {
+    fn next_batch(&mut self) -> io::Result<Option<Vec<AccountUpdate>>> {
+        let first = match self.next_update()? {
+            Some(update) => update,
+            None => return Ok(None),
+        };
+        let mut batch = vec![first];
+        // Peeks at the next line's slot without consuming it.
+        while let Some((_, Ok(line))) = self.lines.peek() {
+            let same_slot = serde_json::from_str::<ReplayLine>(line)
+                .is_ok_and(|next| next.slot == batch[0].slot);
+            if !same_slot {
+                break;
+            }
+            match self.next_update()? {
+                Some(update) => batch.push(update),
+                None => break,
+            }
+        }
+        Ok(Some(batch))
+    }
+}
+
+fn parse_replay_line(line: &str) -> io::Result<AccountUpdate> {
+    let line: ReplayLine = serde_json::from_str(line)?;
+    let pubkey = |s: &str| {
+        Pubkey::from_str(s).map_err(|_| invalid_data(format!("`{}` isn't an address", s)))
+    };
+    Ok(AccountUpdate {
+        slot: line.slot,
+        pubkey: pubkey(&line.pubkey)?,
+        owner: pubkey(&line.owner)?,
+        lamports: line.lamports,
+        data: STANDARD
+            .decode(&line.data)
+            .map_err(|e| invalid_data(format!("The account's data isn't base64: {}", e)))?,
+    })
+}
+
+/// Appends updates to a replay file, so a run can be replayed later.
+pub fn write_replay(writer: &mut impl Write, updates: &[AccountUpdate]) -> io::Result<()> {
+    for update in updates {
+        let line = ReplayLine {
+            slot: update.slot,
+            pubkey: update.pubkey.to_string(),
+            owner: update.owner.to_string(),
+            lamports: update.lamports,
+            data: STANDARD.encode(&update.data),
+        };
+        serde_json::to_writer(&mut *writer, &line)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
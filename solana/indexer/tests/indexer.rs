@@ -0,0 +1,332 @@
+use std::sync::mpsc;
+
+use borsh::BorshSerialize;
+use nautilus_idl::{
+    idl_metadata::IdlMetadata,
+    idl_nautilus_config::IdlTypeDefNautilusConfig,
+    idl_type::IdlType,
+    idl_type_def::{discriminator, IdlTypeDef, IdlTypeDefType, IdlTypeStructField},
+    Idl,
+};
+use nautilus_indexer::{
+    write_replay, AccountSource, AccountUpdate, ChannelSource, IndexStats, Indexer, ReplaySource,
+};
+use rusqlite::Connection;
+use solana_program::pubkey::Pubkey;
+
+fn table(
+    name: &str,
+    fields: Vec<(&str, IdlType)>,
+    primary_key: &str,
+    autoincrement: bool,
+) -> IdlTypeDef {
+    let mut type_def = IdlTypeDef::new(
+        name.to_string(),
+        IdlTypeDefType::Struct {
+            fields: fields
+                .into_iter()
+                .map(|(name, ty)| IdlTypeStructField::new(name.to_string(), ty))
+                .collect(),
+        },
+        None,
+    );
+    let table_name = name.to_lowercase();
+    type_def.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some(table_name.clone()),
+        table_name: Some(table_name),
+        primary_key: Some(primary_key.to_string()),
+        autoincrement: Some(autoincrement),
+        authorities: vec![],
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    type_def
+}
+
+fn idl() -> Idl {
+    Idl::new(
+        "0.1.0".to_string(),
+        "garage".to_string(),
+        vec![],
+        vec![
+            table(
+                "Person",
+                vec![
+                    ("id", IdlType::U8),
+                    ("name", IdlType::String),
+                    ("authority", IdlType::PublicKey),
+                ],
+                "id",
+                true,
+            ),
+            table(
+                "Car",
+                vec![
+                    ("vin", IdlType::U64),
+                    ("year", IdlType::U16),
+                    ("electric", IdlType::Bool),
+                    ("owner", IdlType::Option(Box::new(IdlType::String))),
+                    ("tags", IdlType::Vec(Box::new(IdlType::String))),
+                ],
+                "vin",
+                false,
+            ),
+        ],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    )
+}
+
+fn update(
+    slot: u64,
+    pubkey: Pubkey,
+    owner: Pubkey,
+    table_name: &str,
+    data: impl BorshSerialize,
+) -> AccountUpdate {
+    let mut bytes = discriminator(table_name).to_vec();
+    bytes.extend(data.try_to_vec().unwrap());
+    AccountUpdate {
+        slot,
+        pubkey,
+        owner,
+        lamports: 1_000_000,
+        data: bytes,
+    }
+}
+
+fn people(indexer: &Indexer) -> Vec<(i64, String)> {
+    let mut statement = indexer
+        .connection()
+        .prepare("SELECT id, name FROM person ORDER BY id")
+        .unwrap();
+    let rows = statement
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    rows.map(Result::unwrap).collect()
+}
+
+#[test]
+fn mirrors_records_and_deletes_closed_accounts() {
+    let program_id = Pubkey::new_unique();
+    let mut indexer =
+        Indexer::new(idl(), program_id, Connection::open_in_memory().unwrap()).unwrap();
+    let (alice, bob, car) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let authority = Pubkey::new_unique();
+
+    let (sender, receiver) = mpsc::channel();
+    for update in [
+        update(
+            1,
+            alice,
+            program_id,
+            "person",
+            (1u8, "Alice".to_string(), authority),
+        ),
+        update(
+            1,
+            bob,
+            program_id,
+            "person",
+            (2u8, "Bob".to_string(), authority),
+        ),
+        update(
+            2,
+            car,
+            program_id,
+            "car",
+            (
+                u64::MAX,
+                2020u16,
+                true,
+                Some("Alice".to_string()),
+                vec!["red".to_string()],
+            ),
+        ),
+        // Another program's account, and one of this program's that isn't a
+        // record, are ignored.
+        update(
+            2,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            "person",
+            (3u8, "Eve".to_string(), authority),
+        ),
+        update(2, Pubkey::new_unique(), program_id, "nautilus_index", 0u8),
+    ] {
+        sender.send(update).unwrap();
+    }
+    drop(sender);
+    let stats = indexer
+        .run(&mut ChannelSource::new(receiver), |_| {})
+        .unwrap();
+    assert_eq!(
+        stats,
+        IndexStats {
+            upserted: 3,
+            deleted: 0,
+            stale: 0
+        }
+    );
+    assert_eq!(
+        people(&indexer),
+        vec![(1, "Alice".to_string()), (2, "Bob".to_string())]
+    );
+    let car_row: (String, i64, i64, Option<String>, String) = indexer
+        .connection()
+        .query_row(
+            "SELECT vin, year, electric, owner, tags FROM car",
+            [],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        car_row,
+        (
+            u64::MAX.to_string(),
+            2020,
+            1,
+            Some("Alice".to_string()),
+            "[\"red\"]".to_string()
+        )
+    );
+
+    // Changes replace the row, closed accounts delete it, and updates older
+    // than the last one applied are ignored.
+    let stats = indexer
+        .apply(&[
+            update(
+                5,
+                alice,
+                program_id,
+                "person",
+                (1u8, "Alicia".to_string(), authority),
+            ),
+            AccountUpdate::closed(5, bob),
+            update(
+                4,
+                alice,
+                program_id,
+                "person",
+                (1u8, "Old Alice".to_string(), authority),
+            ),
+            update(
+                3,
+                bob,
+                program_id,
+                "person",
+                (2u8, "Bob".to_string(), authority),
+            ),
+        ])
+        .unwrap();
+    assert_eq!(
+        stats,
+        IndexStats {
+            upserted: 1,
+            deleted: 1,
+            stale: 2
+        }
+    );
+    assert_eq!(people(&indexer), vec![(1, "Alicia".to_string())]);
+
+    // An account that stops holding a record loses its row too.
+    let mut garbage = discriminator("car").to_vec();
+    garbage.push(7);
+    let stats = indexer
+        .apply(&[AccountUpdate {
+            slot: 6,
+            pubkey: car,
+            owner: program_id,
+            lamports: 1,
+            data: garbage,
+        }])
+        .unwrap();
+    assert_eq!(stats.deleted, 1);
+    let cars: i64 = indexer
+        .connection()
+        .query_row("SELECT COUNT(*) FROM car", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(cars, 0);
+}
+
+#[test]
+fn replays_recorded_updates_slot_by_slot() {
+    let program_id = Pubkey::new_unique();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let authority = Pubkey::new_unique();
+    let updates = [
+        update(
+            1,
+            alice,
+            program_id,
+            "person",
+            (1u8, "Alice".to_string(), authority),
+        ),
+        update(
+            1,
+            bob,
+            program_id,
+            "person",
+            (2u8, "Bob".to_string(), authority),
+        ),
+        update(
+            2,
+            alice,
+            program_id,
+            "person",
+            (1u8, "Alicia".to_string(), authority),
+        ),
+        AccountUpdate::closed(3, bob),
+    ];
+    let path = std::env::temp_dir().join(format!("nautilus-indexer-{}.jsonl", program_id));
+    let mut file = std::fs::File::create(&path).unwrap();
+    write_replay(&mut file, &updates).unwrap();
+    drop(file);
+
+    let mut source = ReplaySource::open(&path).unwrap();
+    let mut batches = vec![];
+    while let Some(batch) = source.next_batch().unwrap() {
+        batches.push(batch);
+    }
+    assert_eq!(
+        batches,
+        vec![
+            updates[..2].to_vec(),
+            updates[2..3].to_vec(),
+            updates[3..].to_vec()
+        ]
+    );
+
+    let mut indexer =
+        Indexer::new(idl(), program_id, Connection::open_in_memory().unwrap()).unwrap();
+    let mut per_batch = vec![];
+    indexer
+        .run(&mut ReplaySource::open(&path).unwrap(), |stats| {
+            per_batch.push(stats)
+        })
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(per_batch.len(), 3);
+    assert_eq!(people(&indexer), vec![(1, "Alicia".to_string())]);
+
+    std::fs::write(&path, "{ \"slot\": 1 }\n").unwrap();
+    let error = ReplaySource::open(&path)
+        .unwrap()
+        .next_batch()
+        .unwrap_err()
+        .to_string();
+    std::fs::remove_file(&path).unwrap();
+    assert!(error.starts_with("Line 1 of the replay: "), "{}", error);
+}
@@ -0,0 +1,138 @@
+//! Builds calls to a table's default `create`, `update` and `delete`
+//! instructions, filling their accounts and arguments from a record.
+use std::io;
+
+use nautilus_idl::idl_instruction::IdlInstruction;
+use serde_json::{Map, Value};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::{
+    invalid_input,
+    table::{index_address, Table},
+    value,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum DefaultInstruction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl DefaultInstruction {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+
+    fn statement(self) -> &'static str {
+        match self {
+            Self::Create => "insert into",
+            Self::Update => "update",
+            Self::Delete => "delete from",
+        }
+    }
+}
+
+/// Builds a call to the table's `kind` instruction on the record at
+/// `address`, whose columns hold `row`.
+pub(crate) fn build(
+    table: &Table,
+    kind: DefaultInstruction,
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    address: &Pubkey,
+    row: &Map<String, Value>,
+) -> io::Result<Instruction> {
+    let instruction = find(table, kind)?;
+    let mut accounts = Vec::with_capacity(instruction.accounts.len());
+    let mut record_used = false;
+    for account in &instruction.accounts {
+        let pubkey = match account.account_type.as_str() {
+            "index" => index_address(program_id),
+            "feePayer" => *payer,
+            "systemProgram" => system_program::ID,
+            "sysvar" => match account.name.as_str() {
+                "rent" => sysvar::rent::ID,
+                "clock" => sysvar::clock::ID,
+                name => return Err(unfillable(instruction, name)),
+            },
+            "account" if table.authorities.contains(&account.name) => {
+                let authority = row.get(&account.name).unwrap_or(&Value::Null);
+                pubkey_value(authority).ok_or_else(|| {
+                    invalid_input(format!(
+                        "`{}.{}` must hold an address to sign `{}`",
+                        table.name, account.name, instruction.name
+                    ))
+                })?
+            }
+            // The record itself.
+            "account" if !record_used => {
+                record_used = true;
+                *address
+            }
+            _ => return Err(unfillable(instruction, &account.name)),
+        };
+        accounts.push(AccountMeta {
+            pubkey,
+            is_signer: account.is_signer,
+            is_writable: account.is_mut,
+        });
+    }
+
+    let mut data = vec![instruction.discriminant.value];
+    for arg in &instruction.args {
+        let arg_value = row.get(&arg.name).ok_or_else(|| {
+            invalid_input(format!(
+                "`{}` needs a value for `{}`",
+                instruction.name, arg.name
+            ))
+        })?;
+        data.extend(value::encode(
+            table.idl,
+            arg_value,
+            &arg.arg_type,
+            &format!("{}.{}", table.name, arg.name),
+        )?);
+    }
+    Ok(Instruction::new_with_bytes(*program_id, &data, accounts))
+}
+
+/// The instruction named after the kind and the table's record, ie.
+/// `createPerson`.
+fn find<'a>(table: &Table<'a>, kind: DefaultInstruction) -> io::Result<&'a IdlInstruction> {
+    let name = format!("{}{}", kind.prefix(), table.type_def.name);
+    table
+        .idl
+        .instructions
+        .iter()
+        .find(|i| i.name == name)
+        .ok_or_else(|| {
+            invalid_input(format!(
+                "The `{}` IDL has no `{}` instruction to {} `{}`",
+                table.idl.name,
+                name,
+                kind.statement(),
+                table.name
+            ))
+        })
+}
+
+fn unfillable(instruction: &IdlInstruction, account: &str) -> io::Error {
+    invalid_input(format!(
+        "Can't tell which account to pass as `{}` to `{}`",
+        account, instruction.name
+    ))
+}
+
+fn pubkey_value(value: &Value) -> Option<Pubkey> {
+    let bytes = bs58::decode(value.as_str()?).into_vec().ok()?;
+    Pubkey::try_from(bytes.as_slice()).ok()
+}
@@ -0,0 +1,46 @@
+//
+//
+// ----------------------------------------------------------------
+//                          Nautilus SQL
+// ----------------------------------------------------------------
+//
+// Runs SQL statements against a Nautilus program's tables, using its IDL.
+//
+// * `SELECT` reads records: by address when the statement pins down the
+//   primary key, otherwise by scanning the program's accounts for the table's
+//   discriminator and any column values `memcmp` can match.
+// * `INSERT`, `UPDATE` and `DELETE` build the instructions the table's default
+//   `create`, `update` and `delete` instructions expect.
+//
+// Accounts are read through the `Rpc` trait, so statements can run against a
+// cluster or a local stand-in like `LocalRpc`.
+//
+//
+use std::io;
+
+use nautilus_idl::Idl;
+use solana_program::pubkey::Pubkey;
+
+pub use self::{
+    predicate::{Comparison, Predicate},
+    query::{Delete, Insert, Lookup, Operation, OrderBy, Row, Select, Update},
+    rpc::{Account, LocalRpc, Rpc, RpcFilter},
+    table::Table,
+};
+
+mod instruction;
+pub mod predicate;
+pub mod query;
+pub mod rpc;
+pub mod table;
+mod value;
+
+/// Parses `sql` - one or more `;`-separated statements - into the operations
+/// they perform on the tables of `idl`'s program, deployed at `program_id`.
+pub fn parse<'a>(idl: &'a Idl, program_id: &Pubkey, sql: &str) -> io::Result<Vec<Operation<'a>>> {
+    query::parse(idl, program_id, sql)
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
@@ -0,0 +1,222 @@
+//! `WHERE` clauses, checked against a table's columns.
+use std::{cmp::Ordering, io};
+
+use nautilus_idl::idl_type::IdlType;
+use serde_json::{Map, Value};
+use sqlparser::ast::{BinaryOperator, Expr, UnaryOperator};
+
+use crate::{invalid_input, table::Table, value};
+
+/// A condition on a table's records.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Predicate {
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+    Compare {
+        column: String,
+        op: Comparison,
+        value: Value,
+    },
+    IsNull {
+        column: String,
+        negated: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl Comparison {
+    fn from_operator(op: &BinaryOperator) -> Option<Self> {
+        Some(match op {
+            BinaryOperator::Eq => Self::Eq,
+            BinaryOperator::NotEq => Self::NotEq,
+            BinaryOperator::Lt => Self::Lt,
+            BinaryOperator::LtEq => Self::LtEq,
+            BinaryOperator::Gt => Self::Gt,
+            BinaryOperator::GtEq => Self::GtEq,
+            _ => return None,
+        })
+    }
+
+    /// The comparison with its operands swapped, ie. `a < b` is `b > a`.
+    fn flip(self) -> Self {
+        match self {
+            Self::Lt => Self::Gt,
+            Self::LtEq => Self::GtEq,
+            Self::Gt => Self::Lt,
+            Self::GtEq => Self::LtEq,
+            op => op,
+        }
+    }
+
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering == Ordering::Equal,
+            Self::NotEq => ordering != Ordering::Equal,
+            Self::Lt => ordering == Ordering::Less,
+            Self::LtEq => ordering != Ordering::Greater,
+            Self::Gt => ordering == Ordering::Greater,
+            Self::GtEq => ordering != Ordering::Less,
+        }
+    }
+}
+
+impl Predicate {
+    /// Reads a `WHERE` clause on `table`, checking its columns exist and its
+    /// values fit them.
+    pub fn parse(table: &Table, expr: &Expr) -> io::Result<Self> {
+        match expr {
+            Expr::BinaryOp { left, op, right } => match op {
+                BinaryOperator::And => Ok(Self::And(
+                    Box::new(Self::parse(table, left)?),
+                    Box::new(Self::parse(table, right)?),
+                )),
+                BinaryOperator::Or => Ok(Self::Or(
+                    Box::new(Self::parse(table, left)?),
+                    Box::new(Self::parse(table, right)?),
+                )),
+                op => {
+                    let comparison = Comparison::from_operator(op).ok_or_else(|| {
+                        invalid_input(format!("The `{}` operator isn't supported", op))
+                    })?;
+                    // Either side can be the column.
+                    let (column, op, literal) = match column_name(left) {
+                        Some(column) => (column, comparison, right),
+                        None => match column_name(right) {
+                            Some(column) => (column, comparison.flip(), left),
+                            None => {
+                                return Err(invalid_input(format!(
+                                    "`{}` must compare a column to a value",
+                                    expr
+                                )))
+                            }
+                        },
+                    };
+                    let value = value::literal(literal)?;
+                    if value.is_null() {
+                        return Err(invalid_input(format!(
+                            "`{}` is never true - use `IS NULL` or `IS NOT NULL` instead",
+                            expr
+                        )));
+                    }
+                    let ty = &table.column(&column)?.field_data_type;
+                    if !value::is_comparable(table.idl, ty) || !is_orderable(ty, op) {
+                        return Err(invalid_input(format!(
+                            "`{}.{}` can't be compared with `{}`",
+                            table.name,
+                            column,
+                            comparison_symbol(op)
+                        )));
+                    }
+                    // Checks the value fits the column.
+                    value::encode(table.idl, &value, ty, &format!("{}.{}", table.name, column))?;
+                    Ok(Self::Compare { column, op, value })
+                }
+            },
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr,
+            } => Ok(Self::Not(Box::new(Self::parse(table, expr)?))),
+            Expr::Nested(expr) => Self::parse(table, expr),
+            Expr::IsNull(column) => Self::is_null(table, column, false),
+            Expr::IsNotNull(column) => Self::is_null(table, column, true),
+            _ => Err(invalid_input(format!(
+                "`{}` isn't a supported condition",
+                expr
+            ))),
+        }
+    }
+
+    fn is_null(table: &Table, expr: &Expr, negated: bool) -> io::Result<Self> {
+        let column = column_name(expr)
+            .ok_or_else(|| invalid_input(format!("`{}` must be a column", expr)))?;
+        table.column(&column)?;
+        Ok(Self::IsNull { column, negated })
+    }
+
+    /// Whether a record - its columns' decoded values - satisfies the
+    /// predicate. Comparisons with `NULL` are unknown, and so don't match.
+    pub fn matches(&self, table: &Table, row: &Map<String, Value>) -> bool {
+        self.eval(table, row) == Some(true)
+    }
+
+    /// Evaluates the predicate with SQL's three-valued logic, where `None` is
+    /// unknown.
+    fn eval(&self, table: &Table, row: &Map<String, Value>) -> Option<bool> {
+        match self {
+            Self::And(a, b) => match (a.eval(table, row), b.eval(table, row)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            Self::Or(a, b) => match (a.eval(table, row), b.eval(table, row)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            Self::Not(p) => p.eval(table, row).map(|b| !b),
+            Self::Compare { column, op, value } => {
+                let ty = &table.column(column).ok()?.field_data_type;
+                let ordering = value::compare(row.get(column)?, value, ty)?;
+                Some(op.holds(ordering))
+            }
+            Self::IsNull { column, negated } => {
+                let is_null = row.get(column).map_or(true, Value::is_null);
+                Some(is_null != *negated)
+            }
+        }
+    }
+
+    /// The conditions `AND`ed together at the top of the predicate - each of
+    /// which a matching record satisfies.
+    pub fn conjuncts(&self) -> Vec<&Predicate> {
+        match self {
+            Self::And(a, b) => {
+                let mut conjuncts = a.conjuncts();
+                conjuncts.extend(b.conjuncts());
+                conjuncts
+            }
+            p => vec![p],
+        }
+    }
+}
+
+fn column_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Identifier(ident) => Some(ident.value.clone()),
+        // `table.column`
+        Expr::CompoundIdentifier(idents) if idents.len() == 2 => Some(idents[1].value.clone()),
+        Expr::Nested(expr) => column_name(expr),
+        _ => None,
+    }
+}
+
+/// Public keys and enums only have a meaningful order for `=` and `<>`.
+fn is_orderable(ty: &IdlType, op: Comparison) -> bool {
+    match ty {
+        _ if matches!(op, Comparison::Eq | Comparison::NotEq) => true,
+        IdlType::Option(ty) => is_orderable(ty, op),
+        IdlType::PublicKey | IdlType::Defined(_) => false,
+        _ => true,
+    }
+}
+
+fn comparison_symbol(op: Comparison) -> &'static str {
+    match op {
+        Comparison::Eq => "=",
+        Comparison::NotEq => "<>",
+        Comparison::Lt => "<",
+        Comparison::LtEq => "<=",
+        Comparison::Gt => ">",
+        Comparison::GtEq => ">=",
+    }
+}
@@ -0,0 +1,587 @@
+//! Turns SQL statements into operations on a program's tables: reads for
+//! `SELECT`, and instructions for `INSERT`, `UPDATE` and `DELETE`.
+use std::{cmp::Ordering, collections::HashMap, io};
+
+use borsh::BorshDeserialize;
+use nautilus_idl::Idl;
+use serde_json::{Map, Value};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use sqlparser::{
+    ast::{
+        Expr, ObjectName, OrderByExpr, Query, Select as SqlSelect, SelectItem, SetExpr, Statement,
+        TableFactor, TableWithJoins,
+    },
+    dialect::GenericDialect,
+    parser::Parser,
+};
+
+use crate::{
+    instruction::{self, DefaultInstruction},
+    invalid_input,
+    predicate::{Comparison, Predicate},
+    rpc::{Rpc, RpcFilter},
+    table::{index_address, index_discriminator, Table},
+    value,
+};
+
+/// What a statement does to a table.
+#[derive(Clone, Debug)]
+pub enum Operation<'a> {
+    Select(Select<'a>),
+    Insert(Insert<'a>),
+    Update(Update<'a>),
+    Delete(Delete<'a>),
+}
+
+/// A record read by a statement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Row {
+    pub address: Pubkey,
+    /// The record's columns, or the ones selected, in order.
+    pub values: Map<String, Value>,
+}
+
+/// How a statement finds the records it reads.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lookup {
+    /// The statement pins down the primary key, so at most one record - at
+    /// this address - can match.
+    Address(Pubkey),
+    /// Every record the program owns that passes these filters could match.
+    Scan(Vec<RpcFilter>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderBy {
+    pub column: String,
+    pub descending: bool,
+}
+
+/// `SELECT <columns> FROM <table> [WHERE ..] [ORDER BY ..] [LIMIT ..]`
+#[derive(Clone, Debug)]
+pub struct Select<'a> {
+    pub table: Table<'a>,
+    pub program_id: Pubkey,
+    /// `None` for `*`.
+    pub columns: Option<Vec<String>>,
+    pub predicate: Option<Predicate>,
+    pub order_by: Vec<OrderBy>,
+    pub limit: Option<usize>,
+}
+
+impl<'a> Select<'a> {
+    pub fn lookup(&self) -> io::Result<Lookup> {
+        lookup(&self.table, &self.program_id, self.predicate.as_ref())
+    }
+
+    /// Reads the selected records.
+    pub fn fetch(&self, rpc: &impl Rpc) -> io::Result<Vec<Row>> {
+        let mut rows = fetch(&self.table, &self.program_id, self.predicate.as_ref(), rpc)?;
+        if !self.order_by.is_empty() {
+            rows.sort_by(|a, b| self.order(a, b));
+        }
+        if let Some(limit) = self.limit {
+            rows.truncate(limit);
+        }
+        if let Some(columns) = &self.columns {
+            for row in rows.iter_mut() {
+                row.values = columns
+                    .iter()
+                    .map(|c| (c.clone(), row.values.remove(c).unwrap_or(Value::Null)))
+                    .collect();
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Orders rows by the `ORDER BY` columns, with `NULL`s last.
+    fn order(&self, a: &Row, b: &Row) -> Ordering {
+        for OrderBy { column, descending } in &self.order_by {
+            let ty = match self.table.column(column) {
+                Ok(column) => &column.field_data_type,
+                Err(_) => continue,
+            };
+            let (a, b) = (
+                a.values.get(column).unwrap_or(&Value::Null),
+                b.values.get(column).unwrap_or(&Value::Null),
+            );
+            let ordering = match (a.is_null(), b.is_null()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => {
+                    let ordering = value::compare(a, b, ty).unwrap_or(Ordering::Equal);
+                    match descending {
+                        true => ordering.reverse(),
+                        false => ordering,
+                    }
+                }
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+/// `INSERT INTO <table> [(<columns>)] VALUES (..), ..`
+#[derive(Clone, Debug)]
+pub struct Insert<'a> {
+    pub table: Table<'a>,
+    pub program_id: Pubkey,
+    /// Each new record's columns. Autoincremented primary keys are left out,
+    /// since the program assigns them.
+    pub rows: Vec<Map<String, Value>>,
+}
+
+impl<'a> Insert<'a> {
+    /// Builds a `create` instruction for each new record, paid for by
+    /// `payer`.
+    ///
+    /// Autoincremented records are addressed by the keys the program will
+    /// give them, read from its `NautilusIndex` - so the instructions must
+    /// land in order, with no other inserts into the table in between.
+    pub fn instructions(&self, rpc: &impl Rpc, payer: &Pubkey) -> io::Result<Vec<Instruction>> {
+        let mut next_key = match self.table.autoincrement {
+            true => Some(self.record_count(rpc)? + 1),
+            false => None,
+        };
+        self.rows
+            .iter()
+            .map(|row| {
+                let mut row = row.clone();
+                if let Some(key) = next_key.as_mut() {
+                    row.insert(self.table.primary_key.to_string(), (*key).into());
+                    *key += 1;
+                }
+                let primary_key = row.get(self.table.primary_key).ok_or_else(|| {
+                    invalid_input(format!(
+                        "`{}` needs a value for its primary key `{}`",
+                        self.table.name, self.table.primary_key
+                    ))
+                })?;
+                let address = self.table.address(&self.program_id, primary_key)?;
+                instruction::build(
+                    &self.table,
+                    DefaultInstruction::Create,
+                    &self.program_id,
+                    payer,
+                    &address,
+                    &row,
+                )
+            })
+            .collect()
+    }
+
+    /// How many records the program's `NautilusIndex` has counted in the
+    /// table.
+    fn record_count(&self, rpc: &impl Rpc) -> io::Result<u32> {
+        let address = index_address(&self.program_id);
+        let account = rpc.get_account(&address)?.ok_or_else(|| {
+            invalid_input(format!(
+                "`{}` autoincrements its primary key, but the program's index at {} hasn't been \
+                 initialized",
+                self.table.name, address
+            ))
+        })?;
+        if account.owner != self.program_id || !account.data.starts_with(&index_discriminator()) {
+            return Err(invalid_input(format!(
+                "{} isn't the program's index",
+                address
+            )));
+        }
+        let index = HashMap::<String, u32>::deserialize(&mut &account.data[8..])?;
+        Ok(index.get(self.table.name).copied().unwrap_or(0))
+    }
+}
+
+/// `UPDATE <table> SET <column> = <value>, .. [WHERE ..]`
+#[derive(Clone, Debug)]
+pub struct Update<'a> {
+    pub table: Table<'a>,
+    pub program_id: Pubkey,
+    /// The columns to set, and their new values.
+    pub values: Map<String, Value>,
+    pub predicate: Option<Predicate>,
+}
+
+impl<'a> Update<'a> {
+    pub fn lookup(&self) -> io::Result<Lookup> {
+        lookup(&self.table, &self.program_id, self.predicate.as_ref())
+    }
+
+    /// Reads the matching records, and builds an `update` instruction for
+    /// each - signed by the records' authorities.
+    pub fn instructions(&self, rpc: &impl Rpc, payer: &Pubkey) -> io::Result<Vec<Instruction>> {
+        fetch(&self.table, &self.program_id, self.predicate.as_ref(), rpc)?
+            .into_iter()
+            .map(|mut row| {
+                row.values.extend(self.values.clone());
+                instruction::build(
+                    &self.table,
+                    DefaultInstruction::Update,
+                    &self.program_id,
+                    payer,
+                    &row.address,
+                    &row.values,
+                )
+            })
+            .collect()
+    }
+}
+
+/// `DELETE FROM <table> [WHERE ..]`
+#[derive(Clone, Debug)]
+pub struct Delete<'a> {
+    pub table: Table<'a>,
+    pub program_id: Pubkey,
+    pub predicate: Option<Predicate>,
+}
+
+impl<'a> Delete<'a> {
+    pub fn lookup(&self) -> io::Result<Lookup> {
+        lookup(&self.table, &self.program_id, self.predicate.as_ref())
+    }
+
+    /// Reads the matching records, and builds a `delete` instruction for
+    /// each - signed by the records' authorities, and refunding `payer`.
+    pub fn instructions(&self, rpc: &impl Rpc, payer: &Pubkey) -> io::Result<Vec<Instruction>> {
+        fetch(&self.table, &self.program_id, self.predicate.as_ref(), rpc)?
+            .into_iter()
+            .map(|row| {
+                instruction::build(
+                    &self.table,
+                    DefaultInstruction::Delete,
+                    &self.program_id,
+                    payer,
+                    &row.address,
+                    &row.values,
+                )
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn parse<'a>(
+    idl: &'a Idl,
+    program_id: &Pubkey,
+    sql: &str,
+) -> io::Result<Vec<Operation<'a>>> {
+    let statements = Parser::parse_sql(&GenericDialect {}, sql)
+        .map_err(|e| invalid_input(format!("Invalid SQL: {}", e)))?;
+    statements
+        .iter()
+        .map(|statement| parse_statement(idl, program_id, statement))
+        .collect()
+}
+
+fn parse_statement<'a>(
+    idl: &'a Idl,
+    program_id: &Pubkey,
+    statement: &Statement,
+) -> io::Result<Operation<'a>> {
+    let program_id = *program_id;
+    match statement {
+        Statement::Query(query) => parse_select(idl, program_id, query).map(Operation::Select),
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            ..
+        } => {
+            let table = Table::resolve(idl, &object_name(table_name)?)?;
+            let rows = parse_insert_rows(&table, columns, source)?;
+            Ok(Operation::Insert(Insert {
+                table,
+                program_id,
+                rows,
+            }))
+        }
+        Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+        } => {
+            if from.is_some() || returning.is_some() {
+                return Err(unsupported("`UPDATE .. FROM` or `RETURNING`"));
+            }
+            let table = Table::resolve(idl, &table_with_joins(table)?)?;
+            let mut values = Map::new();
+            for assignment in assignments {
+                let column = match assignment.id.as_slice() {
+                    [column] | [_, column] => column.value.clone(),
+                    _ => return Err(unsupported("nested columns")),
+                };
+                if column == table.primary_key {
+                    return Err(invalid_input(
+                        "You cannot change a primary key with an UPDATE operation".to_string(),
+                    ));
+                }
+                let value = column_value(&table, &column, &assignment.value)?;
+                values.insert(column, value);
+            }
+            Ok(Operation::Update(Update {
+                table,
+                program_id,
+                values,
+                predicate: where_clause(&table, selection.as_ref())?,
+            }))
+        }
+        Statement::Delete {
+            table_name,
+            using,
+            selection,
+            returning,
+        } => {
+            if using.is_some() || returning.is_some() {
+                return Err(unsupported("`DELETE .. USING` or `RETURNING`"));
+            }
+            let table = Table::resolve(idl, &table_factor(table_name)?)?;
+            Ok(Operation::Delete(Delete {
+                table,
+                program_id,
+                predicate: where_clause(&table, selection.as_ref())?,
+            }))
+        }
+        statement => Err(invalid_input(format!(
+            "Only `SELECT`, `INSERT`, `UPDATE` and `DELETE` statements are supported, but found \
+             `{}`",
+            statement
+        ))),
+    }
+}
+
+fn parse_select<'a>(idl: &'a Idl, program_id: Pubkey, query: &Query) -> io::Result<Select<'a>> {
+    if query.with.is_some() {
+        return Err(unsupported("`WITH`"));
+    }
+    if query.offset.is_some() || query.fetch.is_some() {
+        return Err(unsupported("`OFFSET` or `FETCH`"));
+    }
+    let select: &SqlSelect = match query.body.as_ref() {
+        SetExpr::Select(select) => select,
+        _ => return Err(unsupported("`UNION`s, `VALUES` or subqueries")),
+    };
+    if select.distinct {
+        return Err(unsupported("`DISTINCT`"));
+    }
+    if !select.group_by.is_empty() || select.having.is_some() {
+        return Err(unsupported("`GROUP BY` or `HAVING`"));
+    }
+    let table = match select.from.as_slice() {
+        [from] => Table::resolve(idl, &table_with_joins(from)?)?,
+        _ => return Err(unsupported("selecting from more or less than one table")),
+    };
+
+    let mut columns = Some(vec![]);
+    for item in &select.projection {
+        match item {
+            SelectItem::Wildcard(_) | SelectItem::QualifiedWildcard(..) => columns = None,
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                let column = column_name(expr)?;
+                table.column(&column)?;
+                if let Some(columns) = columns.as_mut() {
+                    columns.push(column);
+                }
+            }
+        }
+    }
+
+    let order_by = query
+        .order_by
+        .iter()
+        .map(|OrderByExpr { expr, asc, .. }| {
+            let column = column_name(expr)?;
+            table.column(&column)?;
+            Ok(OrderBy {
+                column,
+                descending: *asc == Some(false),
+            })
+        })
+        .collect::<io::Result<_>>()?;
+
+    let limit = query
+        .limit
+        .as_ref()
+        .map(|limit| {
+            value::literal(limit)?
+                .as_u64()
+                .map(|n| n as usize)
+                .ok_or_else(|| invalid_input(format!("`LIMIT {}` isn't a row count", limit)))
+        })
+        .transpose()?;
+
+    Ok(Select {
+        table,
+        program_id,
+        columns,
+        predicate: where_clause(&table, select.selection.as_ref())?,
+        order_by,
+        limit,
+    })
+}
+
+fn parse_insert_rows(
+    table: &Table,
+    columns: &[sqlparser::ast::Ident],
+    source: &Query,
+) -> io::Result<Vec<Map<String, Value>>> {
+    let rows = match source.body.as_ref() {
+        SetExpr::Values(values) => &values.rows,
+        _ => return Err(unsupported("`INSERT .. SELECT`")),
+    };
+    // Without named columns, values fill the columns the statement can set.
+    let columns: Vec<String> = match columns.is_empty() {
+        true => table
+            .columns()
+            .iter()
+            .map(|c| c.name.clone())
+            .filter(|c| !(table.autoincrement && c == table.primary_key))
+            .collect(),
+        false => columns.iter().map(|c| c.value.clone()).collect(),
+    };
+    if table.autoincrement && columns.iter().any(|c| c == table.primary_key) {
+        return Err(invalid_input(
+            "You cannot provide a value for the primary key if autoincrement is enabled"
+                .to_string(),
+        ));
+    }
+    rows.iter()
+        .map(|values| {
+            if values.len() != columns.len() {
+                return Err(invalid_input(format!(
+                    "`{}` expects {} values per row, but found {}",
+                    table.name,
+                    columns.len(),
+                    values.len()
+                )));
+            }
+            columns
+                .iter()
+                .zip(values)
+                .map(|(column, expr)| Ok((column.clone(), column_value(table, column, expr)?)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Reads a literal given to a column, checking it fits.
+fn column_value(table: &Table, column: &str, expr: &Expr) -> io::Result<Value> {
+    let ty = &table.column(column)?.field_data_type;
+    let value = value::literal(expr)?;
+    value::encode(table.idl, &value, ty, &format!("{}.{}", table.name, column))?;
+    Ok(value)
+}
+
+fn where_clause(table: &Table, selection: Option<&Expr>) -> io::Result<Option<Predicate>> {
+    selection
+        .map(|expr| Predicate::parse(table, expr))
+        .transpose()
+}
+
+/// Narrows a statement's reads as much as the RPC allows: to one address if
+/// the primary key is pinned down, otherwise to the table's discriminator and
+/// any columns that must equal a value at a fixed offset.
+fn lookup(table: &Table, program_id: &Pubkey, predicate: Option<&Predicate>) -> io::Result<Lookup> {
+    let conjuncts = predicate.map(Predicate::conjuncts).unwrap_or_default();
+    let equalities = conjuncts.iter().filter_map(|p| match p {
+        Predicate::Compare {
+            column,
+            op: Comparison::Eq,
+            value,
+        } => Some((column, value)),
+        _ => None,
+    });
+    let mut filters = vec![RpcFilter::Memcmp {
+        offset: 0,
+        bytes: table.discriminator().to_vec(),
+    }];
+    for (column, value) in equalities {
+        if column == table.primary_key {
+            return Ok(Lookup::Address(table.address(program_id, value)?));
+        }
+        if let Some(offset) = table.offset(column) {
+            let ty = &table.column(column)?.field_data_type;
+            let path = format!("{}.{}", table.name, column);
+            filters.push(RpcFilter::Memcmp {
+                offset,
+                bytes: value::encode(table.idl, value, ty, &path)?,
+            });
+        }
+    }
+    Ok(Lookup::Scan(filters))
+}
+
+/// Reads the table's records matching `predicate`.
+fn fetch(
+    table: &Table,
+    program_id: &Pubkey,
+    predicate: Option<&Predicate>,
+    rpc: &impl Rpc,
+) -> io::Result<Vec<Row>> {
+    let accounts = match lookup(table, program_id, predicate)? {
+        Lookup::Address(address) => match rpc.get_account(&address)? {
+            Some(account) => vec![(address, account)],
+            None => vec![],
+        },
+        Lookup::Scan(filters) => rpc.get_program_accounts(program_id, &filters)?,
+    };
+    let discriminator = table.discriminator();
+    let mut rows = vec![];
+    for (address, account) in accounts {
+        if &account.owner != program_id || !account.data.starts_with(&discriminator) {
+            continue;
+        }
+        let values = match table.idl.decode_account(&account.data)? {
+            Value::Object(mut decoded) => match decoded.remove("data") {
+                Some(Value::Object(values)) => values,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if predicate.map_or(true, |p| p.matches(table, &values)) {
+            rows.push(Row { address, values });
+        }
+    }
+    Ok(rows)
+}
+
+fn object_name(name: &ObjectName) -> io::Result<String> {
+    name.0
+        .last()
+        .map(|ident| ident.value.clone())
+        .ok_or_else(|| invalid_input("Missing table name".to_string()))
+}
+
+fn table_factor(factor: &TableFactor) -> io::Result<String> {
+    match factor {
+        TableFactor::Table { name, args, .. } if args.is_none() => object_name(name),
+        _ => Err(unsupported("subqueries or table functions")),
+    }
+}
+
+fn table_with_joins(table: &TableWithJoins) -> io::Result<String> {
+    if !table.joins.is_empty() {
+        return Err(unsupported("joins"));
+    }
+    table_factor(&table.relation)
+}
+
+fn column_name(expr: &Expr) -> io::Result<String> {
+    match expr {
+        Expr::Identifier(ident) => Ok(ident.value.clone()),
+        Expr::CompoundIdentifier(idents) if idents.len() == 2 => Ok(idents[1].value.clone()),
+        Expr::Nested(expr) => column_name(expr),
+        expr => Err(invalid_input(format!(
+            "Only columns can be selected or ordered by, but found `{}`",
+            expr
+        ))),
+    }
+}
+
+fn unsupported(what: &str) -> io::Error {
+    invalid_input(format!("Nautilus SQL doesn't support {}", what))
+}
@@ -0,0 +1,85 @@
+//! How statements read accounts: a cluster's RPC, or a local stand-in.
+use std::{collections::BTreeMap, io};
+
+use solana_program::pubkey::Pubkey;
+
+/// The parts of an account statements read.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Account {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// Narrows the accounts `getProgramAccounts` returns, like the RPC's filters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RpcFilter {
+    /// The account's data holds `bytes` at `offset`.
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+impl RpcFilter {
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            Self::Memcmp { offset, bytes } => {
+                data.get(*offset..offset + bytes.len()) == Some(bytes.as_slice())
+            }
+        }
+    }
+}
+
+/// The RPC methods statements need.
+///
+/// Implement this over an RPC client to run statements against a cluster.
+pub trait Rpc {
+    /// `getAccountInfo`: the account at `address`, if it exists.
+    fn get_account(&self, address: &Pubkey) -> io::Result<Option<Account>>;
+
+    /// `getProgramAccounts`: the accounts `program_id` owns that match every
+    /// filter.
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[RpcFilter],
+    ) -> io::Result<Vec<(Pubkey, Account)>>;
+}
+
+/// An in-memory set of accounts, for running statements without a cluster.
+#[derive(Clone, Debug, Default)]
+pub struct LocalRpc {
+    accounts: BTreeMap<Pubkey, Account>,
+}
+
+impl LocalRpc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_account(&mut self, address: Pubkey, account: Account) {
+        self.accounts.insert(address, account);
+    }
+
+    pub fn account(&self, address: &Pubkey) -> Option<&Account> {
+        self.accounts.get(address)
+    }
+}
+
+impl Rpc for LocalRpc {
+    fn get_account(&self, address: &Pubkey) -> io::Result<Option<Account>> {
+        Ok(self.accounts.get(address).cloned())
+    }
+
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[RpcFilter],
+    ) -> io::Result<Vec<(Pubkey, Account)>> {
+        Ok(self
+            .accounts
+            .iter()
+            .filter(|(_, account)| {
+                &account.owner == program_id && filters.iter().all(|f| f.matches(&account.data))
+            })
+            .map(|(address, account)| (*address, account.clone()))
+            .collect())
+    }
+}
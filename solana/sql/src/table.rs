@@ -0,0 +1,135 @@
+//! Resolves SQL tables to the IDL's record accounts.
+use std::io;
+
+use nautilus_idl::{
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeStructField},
+    Idl,
+};
+use serde_json::Value;
+use solana_program::pubkey::Pubkey;
+
+use crate::{invalid_input, value};
+
+/// The table name of the `NautilusIndex`, which counts each table's records to
+/// autoincrement their primary keys.
+const INDEX_TABLE_NAME: &str = "nautilus_index";
+
+/// A Nautilus table: a record account the IDL gives a table name.
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    pub idl: &'a Idl,
+    pub type_def: &'a IdlTypeDef,
+    pub name: &'a str,
+    pub primary_key: &'a str,
+    pub autoincrement: bool,
+    /// The columns holding the addresses that must sign to change a record.
+    pub authorities: &'a [String],
+}
+
+impl<'a> Table<'a> {
+    /// Finds the table named `name` in `idl`.
+    pub fn resolve(idl: &'a Idl, name: &str) -> io::Result<Self> {
+        let (type_def, config) = idl
+            .accounts
+            .iter()
+            .find_map(|a| {
+                let config = a.config.as_ref()?;
+                (config.table_name.as_deref() == Some(name)).then(|| (a, config))
+            })
+            .ok_or_else(|| {
+                invalid_input(format!("The `{}` IDL has no `{}` table", idl.name, name))
+            })?;
+        if !matches!(type_def.idl_type, IdlTypeDefType::Struct { .. }) {
+            return Err(invalid_input(format!(
+                "`{}` is an enum, so it has no columns",
+                name
+            )));
+        }
+        let primary_key = config
+            .primary_key
+            .as_deref()
+            .ok_or_else(|| invalid_input(format!("`{}` has no primary key", name)))?;
+        Ok(Self {
+            idl,
+            type_def,
+            name: config.table_name.as_deref().unwrap_or_default(),
+            primary_key,
+            autoincrement: config.autoincrement.unwrap_or(false),
+            authorities: &config.authorities,
+        })
+    }
+
+    /// The table's columns - its record's fields - in order.
+    pub fn columns(&self) -> &'a [IdlTypeStructField] {
+        match &self.type_def.idl_type {
+            IdlTypeDefType::Struct { fields } => fields,
+            IdlTypeDefType::Enum { .. } => &[],
+        }
+    }
+
+    pub fn column(&self, name: &str) -> io::Result<&'a IdlTypeStructField> {
+        self.columns()
+            .iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| invalid_input(format!("`{}` has no column `{}`", self.name, name)))
+    }
+
+    /// Where a column's value starts in a record's account data, or `None` if
+    /// a variable-size column comes before it.
+    pub fn offset(&self, column: &str) -> Option<usize> {
+        let types = self.types();
+        let mut offset = 8; // The discriminator.
+        for c in self.columns() {
+            if c.name == column {
+                return Some(offset);
+            }
+            offset += c.field_data_type.borsh_size(&types)?;
+        }
+        None
+    }
+
+    pub fn discriminator(&self) -> [u8; 8] {
+        nautilus_idl::idl_type_def::discriminator(self.name)
+    }
+
+    /// The address of the record whose primary key is `primary_key`. Records
+    /// are derived from their table name and primary key.
+    pub fn address(&self, program_id: &Pubkey, primary_key: &Value) -> io::Result<Pubkey> {
+        let ty = &self.column(self.primary_key)?.field_data_type;
+        let path = format!("{}.{}", self.name, self.primary_key);
+        let seed = match ty {
+            // The string's bytes, without Borsh's length prefix.
+            IdlType::String => value::encode(self.idl, primary_key, ty, &path)?.split_off(4),
+            IdlType::U8 | IdlType::U16 | IdlType::U32 | IdlType::U64 | IdlType::PublicKey => {
+                value::encode(self.idl, primary_key, ty, &path)?
+            }
+            _ => {
+                return Err(invalid_input(format!(
+                    "`{}` can't be used as a primary key",
+                    path
+                )))
+            }
+        };
+        Ok(Pubkey::find_program_address(&[self.name.as_bytes(), &seed], program_id).0)
+    }
+
+    pub(crate) fn types(&self) -> Vec<IdlTypeDef> {
+        self.idl
+            .accounts
+            .iter()
+            .chain(self.idl.types.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// The address of the program's `NautilusIndex`.
+pub fn index_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[INDEX_TABLE_NAME.as_bytes(), &[0]], program_id).0
+}
+
+/// The discriminator of the program's `NautilusIndex`.
+pub(crate) fn index_discriminator() -> [u8; 8] {
+    nautilus_idl::idl_type_def::discriminator(INDEX_TABLE_NAME)
+}
@@ -0,0 +1,221 @@
+//! SQL literals, as the JSON values the IDL's decoder reads records into.
+use std::{cmp::Ordering, io};
+
+use nautilus_idl::{
+    idl_type::IdlType,
+    idl_type_def::{IdlTypeDefType, IdlTypeEnumFields},
+    Idl,
+};
+use serde_json::Value;
+use sqlparser::ast::{Expr, UnaryOperator, Value as SqlValue};
+
+use crate::invalid_input;
+
+/// Reads a literal: a number, string, boolean or `NULL`.
+///
+/// Integers too big for JSON numbers are kept as strings, the same way the
+/// decoder reads `u128`s and `i128`s.
+pub(crate) fn literal(expr: &Expr) -> io::Result<Value> {
+    match expr {
+        Expr::Value(SqlValue::Number(number, _)) => number_literal(number),
+        Expr::Value(
+            SqlValue::SingleQuotedString(s)
+            | SqlValue::DoubleQuotedString(s)
+            | SqlValue::NationalStringLiteral(s),
+        ) => Ok(Value::String(s.clone())),
+        Expr::Value(SqlValue::Boolean(b)) => Ok(Value::Bool(*b)),
+        Expr::Value(SqlValue::Null) => Ok(Value::Null),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => match expr.as_ref() {
+            Expr::Value(SqlValue::Number(number, _)) => number_literal(&format!("-{}", number)),
+            _ => Err(not_a_literal(expr)),
+        },
+        Expr::Nested(expr) => literal(expr),
+        _ => Err(not_a_literal(expr)),
+    }
+}
+
+fn number_literal(number: &str) -> io::Result<Value> {
+    if let Ok(n) = number.parse::<u64>() {
+        Ok(n.into())
+    } else if let Ok(n) = number.parse::<i64>() {
+        Ok(n.into())
+    } else if number.parse::<i128>().is_ok() || number.parse::<u128>().is_ok() {
+        Ok(Value::String(number.to_string()))
+    } else {
+        Err(invalid_input(format!(
+            "`{}` isn't an integer - Nautilus tables only hold integers",
+            number
+        )))
+    }
+}
+
+fn not_a_literal(expr: &Expr) -> io::Error {
+    invalid_input(format!(
+        "Only literal values are supported, but found `{}`",
+        expr
+    ))
+}
+
+/// Borsh-serializes `value` as a `ty`, the way a program reads it from
+/// instruction or account data. `path` locates the value in any error.
+pub(crate) fn encode(idl: &Idl, value: &Value, ty: &IdlType, path: &str) -> io::Result<Vec<u8>> {
+    macro_rules! int {
+        ($int:ty) => {
+            integer(value)
+                .and_then(|n| <$int>::try_from(n).ok())
+                .map(|n| n.to_le_bytes().to_vec())
+                .ok_or_else(|| mismatch(value, ty, path))
+        };
+    }
+    match ty {
+        IdlType::Bool => value
+            .as_bool()
+            .map(|b| vec![b as u8])
+            .ok_or_else(|| mismatch(value, ty, path)),
+        IdlType::U8 => int!(u8),
+        IdlType::U16 => int!(u16),
+        IdlType::U32 => int!(u32),
+        IdlType::U64 => int!(u64),
+        IdlType::U128 => unsigned(value)
+            .map(|n| n.to_le_bytes().to_vec())
+            .ok_or_else(|| mismatch(value, ty, path)),
+        IdlType::I8 => int!(i8),
+        IdlType::I16 => int!(i16),
+        IdlType::I32 => int!(i32),
+        IdlType::I64 => int!(i64),
+        IdlType::I128 => int!(i128),
+        IdlType::String => {
+            let s = value.as_str().ok_or_else(|| mismatch(value, ty, path))?;
+            let mut bytes = (s.len() as u32).to_le_bytes().to_vec();
+            bytes.extend(s.as_bytes());
+            Ok(bytes)
+        }
+        IdlType::PublicKey => value
+            .as_str()
+            .and_then(|s| bs58::decode(s).into_vec().ok())
+            .filter(|bytes| bytes.len() == 32)
+            .ok_or_else(|| mismatch(value, ty, path)),
+        IdlType::Option(inner) => match value {
+            Value::Null => Ok(vec![0]),
+            value => {
+                let mut bytes = vec![1];
+                bytes.extend(encode(idl, value, inner, path)?);
+                Ok(bytes)
+            }
+        },
+        IdlType::Defined(name) => {
+            // Enums without fields are read into their variant's name.
+            let variants = idl
+                .accounts
+                .iter()
+                .chain(idl.types.iter())
+                .find(|t| &t.name == name)
+                .and_then(|t| match &t.idl_type {
+                    IdlTypeDefType::Enum { variants } => Some(variants),
+                    IdlTypeDefType::Struct { .. } => None,
+                });
+            variants
+                .and_then(|variants| {
+                    variants
+                        .iter()
+                        .position(|v| Some(v.name.as_str()) == value.as_str())
+                        .filter(|i| variants[*i].fields.is_none())
+                })
+                .map(|i| vec![i as u8])
+                .ok_or_else(|| mismatch(value, ty, path))
+        }
+        _ => Err(invalid_input(format!(
+            "`{}` is a `{}`, which SQL values can't be given as",
+            path,
+            serde_json::to_string(ty).unwrap_or_default()
+        ))),
+    }
+}
+
+fn mismatch(value: &Value, ty: &IdlType, path: &str) -> io::Error {
+    invalid_input(format!(
+        "`{}` is a `{}`, but was given {}",
+        path,
+        serde_json::to_string(ty).unwrap_or_default(),
+        value
+    ))
+}
+
+fn integer(value: &Value) -> Option<i128> {
+    match value {
+        Value::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from)),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn unsigned(value: &Value) -> Option<u128> {
+    match value {
+        Value::Number(n) => n.as_u64().map(u128::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn is_integer(ty: &IdlType) -> bool {
+    matches!(
+        ty,
+        IdlType::U8
+            | IdlType::U16
+            | IdlType::U32
+            | IdlType::U64
+            | IdlType::I8
+            | IdlType::I16
+            | IdlType::I32
+            | IdlType::I64
+            | IdlType::I128
+    )
+}
+
+/// Orders two values of a column of type `ty`, or `None` if SQL can't - ie.
+/// either is `NULL`.
+pub(crate) fn compare(a: &Value, b: &Value, ty: &IdlType) -> Option<Ordering> {
+    match ty {
+        IdlType::Option(ty) => compare(a, b, ty),
+        IdlType::U128 => Some(unsigned(a)?.cmp(&unsigned(b)?)),
+        ty if is_integer(ty) => Some(integer(a)?.cmp(&integer(b)?)),
+        _ => match (a, b) {
+            (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            _ => None,
+        },
+    }
+}
+
+/// Whether SQL can compare values of a column of type `ty`.
+pub(crate) fn is_comparable(idl: &Idl, ty: &IdlType) -> bool {
+    match ty {
+        IdlType::Option(ty) => is_comparable(idl, ty),
+        IdlType::Bool | IdlType::U128 | IdlType::String | IdlType::PublicKey => true,
+        IdlType::Defined(name) => is_unit_enum(idl, name),
+        ty => is_integer(ty),
+    }
+}
+
+/// Whether a defined type is an enum without fields, whose values are its
+/// variants' names.
+pub(crate) fn is_unit_enum(idl: &Idl, name: &str) -> bool {
+    idl.accounts
+        .iter()
+        .chain(idl.types.iter())
+        .find(|t| t.name == name)
+        .map_or(false, |t| match &t.idl_type {
+            IdlTypeDefType::Enum { variants } => variants.iter().all(|v| match &v.fields {
+                None => true,
+                Some(IdlTypeEnumFields::Named(fields)) => fields.is_empty(),
+                Some(IdlTypeEnumFields::Tuple(types)) => types.is_empty(),
+            }),
+            IdlTypeDefType::Struct { .. } => false,
+        })
+}
@@ -0,0 +1,555 @@
+use std::collections::HashMap;
+
+use borsh::BorshSerialize;
+use nautilus_idl::{
+    idl_instruction::{
+        IdlInstruction, IdlInstructionAccount, IdlInstructionArg, IdlInstructionDiscriminant,
+    },
+    idl_metadata::IdlMetadata,
+    idl_nautilus_config::IdlTypeDefNautilusConfig,
+    idl_type::IdlType,
+    idl_type_def::{discriminator, IdlTypeDef, IdlTypeDefType, IdlTypeStructField},
+    Idl,
+};
+use nautilus_sql::{table::index_address, Account, LocalRpc, Lookup, Operation, RpcFilter};
+use serde_json::json;
+use solana_program::{pubkey::Pubkey, system_program, sysvar};
+
+fn table(
+    name: &str,
+    fields: Vec<(&str, IdlType)>,
+    primary_key: &str,
+    autoincrement: bool,
+    authorities: &[&str],
+) -> IdlTypeDef {
+    let mut type_def = IdlTypeDef::new(
+        name.to_string(),
+        IdlTypeDefType::Struct {
+            fields: fields
+                .into_iter()
+                .map(|(name, ty)| IdlTypeStructField::new(name.to_string(), ty))
+                .collect(),
+        },
+        None,
+    );
+    let table_name = name.to_lowercase();
+    type_def.config = Some(IdlTypeDefNautilusConfig {
+        discrminator_str: Some(table_name.clone()),
+        table_name: Some(table_name),
+        primary_key: Some(primary_key.to_string()),
+        autoincrement: Some(autoincrement),
+        authorities: authorities.iter().map(|a| a.to_string()).collect(),
+        default_instructions: vec![],
+        seeds: vec![],
+    });
+    type_def
+}
+
+fn instruction(
+    name: &str,
+    discriminant: u8,
+    accounts: Vec<(&str, bool, bool, &str)>,
+    args: Vec<(&str, IdlType)>,
+) -> IdlInstruction {
+    IdlInstruction::new(
+        name,
+        accounts
+            .into_iter()
+            .map(|(name, is_mut, is_signer, ty)| {
+                IdlInstructionAccount::new(
+                    name.to_string(),
+                    is_mut,
+                    is_signer,
+                    ty.to_string(),
+                    String::new(),
+                )
+            })
+            .collect(),
+        args.into_iter()
+            .map(|(name, ty)| IdlInstructionArg::new(name.to_string(), ty))
+            .collect(),
+        IdlInstructionDiscriminant::new(discriminant),
+    )
+}
+
+/// A program with an autoincremented `person` table, whose records an
+/// authority owns, and a `car` table keyed by VIN.
+fn idl() -> Idl {
+    let person_args = || vec![("name", IdlType::String), ("authority", IdlType::PublicKey)];
+    Idl::new(
+        "0.1.0".to_string(),
+        "people".to_string(),
+        vec![
+            instruction(
+                "createPerson",
+                0,
+                vec![
+                    ("index", true, false, "index"),
+                    ("new_person", true, false, "account"),
+                    ("fee_payer", true, true, "feePayer"),
+                    ("rent", false, false, "sysvar"),
+                    ("system_program", false, false, "systemProgram"),
+                ],
+                person_args(),
+            ),
+            instruction(
+                "updatePerson",
+                1,
+                vec![
+                    ("person", true, false, "account"),
+                    ("authority", false, true, "account"),
+                    ("fee_payer", true, true, "feePayer"),
+                    ("system_program", false, false, "systemProgram"),
+                ],
+                person_args(),
+            ),
+            instruction(
+                "deletePerson",
+                2,
+                vec![
+                    ("person", true, false, "account"),
+                    ("authority", false, true, "account"),
+                    ("fee_payer", true, true, "feePayer"),
+                ],
+                vec![],
+            ),
+            instruction(
+                "createCar",
+                3,
+                vec![
+                    ("new_car", true, false, "account"),
+                    ("fee_payer", true, true, "feePayer"),
+                    ("system_program", false, false, "systemProgram"),
+                ],
+                vec![
+                    ("vin", IdlType::U64),
+                    ("year", IdlType::U16),
+                    ("make", IdlType::String),
+                    ("owner", IdlType::Option(Box::new(IdlType::String))),
+                ],
+            ),
+        ],
+        vec![
+            table(
+                "Person",
+                vec![
+                    ("id", IdlType::U8),
+                    ("name", IdlType::String),
+                    ("authority", IdlType::PublicKey),
+                ],
+                "id",
+                true,
+                &["authority"],
+            ),
+            table(
+                "Car",
+                vec![
+                    ("vin", IdlType::U64),
+                    ("year", IdlType::U16),
+                    ("make", IdlType::String),
+                    ("owner", IdlType::Option(Box::new(IdlType::String))),
+                ],
+                "vin",
+                false,
+                &[],
+            ),
+        ],
+        vec![],
+        IdlMetadata::new_with_no_id(),
+    )
+}
+
+struct Setup {
+    idl: Idl,
+    program_id: Pubkey,
+    rpc: LocalRpc,
+    alice: Pubkey,
+    bob: Pubkey,
+}
+
+fn record(program_id: &Pubkey, table_name: &str, data: impl BorshSerialize) -> Account {
+    let mut bytes = discriminator(table_name).to_vec();
+    bytes.extend(data.try_to_vec().unwrap());
+    Account {
+        owner: *program_id,
+        data: bytes,
+    }
+}
+
+fn address(program_id: &Pubkey, table_name: &str, primary_key: &[u8]) -> Pubkey {
+    Pubkey::find_program_address(&[table_name.as_bytes(), primary_key], program_id).0
+}
+
+fn setup() -> Setup {
+    let program_id = Pubkey::new_unique();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut rpc = LocalRpc::new();
+    let people = [(1u8, "Alice", alice), (2, "Bob", bob), (3, "Carol", alice)];
+    for (id, name, authority) in people {
+        rpc.add_account(
+            address(&program_id, "person", &[id]),
+            record(&program_id, "person", (id, name.to_string(), authority)),
+        );
+    }
+    let cars = [
+        (100u64, 2020u16, "Volvo", Some("Alice")),
+        (200, 1999, "Saab", None),
+        (300, 2020, "Saab", Some("Bob")),
+    ];
+    for (vin, year, make, owner) in cars {
+        rpc.add_account(
+            address(&program_id, "car", &vin.to_le_bytes()),
+            record(
+                &program_id,
+                "car",
+                (vin, year, make.to_string(), owner.map(str::to_string)),
+            ),
+        );
+    }
+    let mut index = HashMap::new();
+    index.insert("person".to_string(), 3u32);
+    rpc.add_account(
+        index_address(&program_id),
+        record(&program_id, "nautilus_index", index),
+    );
+    // Another program's account with the same discriminator is ignored.
+    rpc.add_account(
+        Pubkey::new_unique(),
+        record(
+            &Pubkey::new_unique(),
+            "person",
+            (9u8, "Mallory".to_string(), bob),
+        ),
+    );
+    Setup {
+        idl: idl(),
+        program_id,
+        rpc,
+        alice,
+        bob,
+    }
+}
+
+fn one<'a>(s: &'a Setup, sql: &str) -> Operation<'a> {
+    let mut operations = nautilus_sql::parse(&s.idl, &s.program_id, sql).unwrap();
+    assert_eq!(operations.len(), 1);
+    operations.remove(0)
+}
+
+fn select(s: &Setup, sql: &str) -> Vec<serde_json::Value> {
+    match one(s, sql) {
+        Operation::Select(select) => select
+            .fetch(&s.rpc)
+            .unwrap()
+            .into_iter()
+            .map(|row| serde_json::Value::Object(row.values))
+            .collect(),
+        operation => panic!("Expected a select, found {:?}", operation),
+    }
+}
+
+fn error(s: &Setup, sql: &str) -> String {
+    nautilus_sql::parse(&s.idl, &s.program_id, sql)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn select_scans_and_filters() {
+    let s = setup();
+    let alice = s.alice.to_string();
+    assert_eq!(
+        select(&s, "SELECT * FROM person ORDER BY id"),
+        vec![
+            json!({ "id": 1, "name": "Alice", "authority": alice }),
+            json!({ "id": 2, "name": "Bob", "authority": s.bob.to_string() }),
+            json!({ "id": 3, "name": "Carol", "authority": alice }),
+        ]
+    );
+    assert_eq!(
+        select(
+            &s,
+            &format!(
+                "SELECT name FROM person WHERE authority = '{}' ORDER BY name DESC",
+                alice
+            )
+        ),
+        vec![json!({ "name": "Carol" }), json!({ "name": "Alice" })]
+    );
+    assert_eq!(
+        select(
+            &s,
+            "SELECT make, vin FROM car WHERE year >= 2000 AND NOT make = 'Volvo' OR vin < 150 \
+             ORDER BY vin DESC"
+        ),
+        vec![
+            json!({ "make": "Saab", "vin": 300 }),
+            json!({ "make": "Volvo", "vin": 100 }),
+        ]
+    );
+    assert_eq!(
+        select(&s, "SELECT vin FROM car WHERE owner IS NULL"),
+        vec![json!({ "vin": 200 })]
+    );
+    // `NULL`s sort last, and never satisfy comparisons.
+    assert_eq!(
+        select(&s, "SELECT vin FROM car ORDER BY owner, year DESC LIMIT 2"),
+        vec![json!({ "vin": 100 }), json!({ "vin": 300 })]
+    );
+    assert_eq!(
+        select(&s, "SELECT vin FROM car WHERE owner <> 'Bob'"),
+        vec![json!({ "vin": 100 })]
+    );
+}
+
+#[test]
+fn select_narrows_reads() {
+    let s = setup();
+    // Pinning down the primary key reads the record by its address.
+    let lookup = |sql: &str| match one(&s, sql) {
+        Operation::Select(select) => select.lookup().unwrap(),
+        operation => panic!("Expected a select, found {:?}", operation),
+    };
+    assert_eq!(
+        lookup("SELECT * FROM car WHERE make = 'Saab' AND 300 = vin"),
+        Lookup::Address(address(&s.program_id, "car", &300u64.to_le_bytes()))
+    );
+    assert_eq!(
+        select(&s, "SELECT make FROM car WHERE vin = 300 AND year = 2020"),
+        vec![json!({ "make": "Saab" })]
+    );
+    assert!(select(&s, "SELECT * FROM car WHERE vin = 300 AND year = 1999").is_empty());
+    assert!(select(&s, "SELECT * FROM car WHERE vin = 400").is_empty());
+
+    // Otherwise columns at fixed offsets are matched by the RPC - up to and
+    // including the first variable-size one.
+    assert_eq!(
+        lookup("SELECT * FROM car WHERE year = 2020 AND make = 'Saab' AND owner = 'Bob'"),
+        Lookup::Scan(vec![
+            RpcFilter::Memcmp {
+                offset: 0,
+                bytes: discriminator("car").to_vec(),
+            },
+            RpcFilter::Memcmp {
+                offset: 16,
+                bytes: 2020u16.to_le_bytes().to_vec(),
+            },
+            RpcFilter::Memcmp {
+                offset: 18,
+                bytes: "Saab".to_string().try_to_vec().unwrap(),
+            },
+        ])
+    );
+    assert_eq!(
+        lookup("SELECT * FROM car WHERE vin = 1 OR year = 2020"),
+        Lookup::Scan(vec![RpcFilter::Memcmp {
+            offset: 0,
+            bytes: discriminator("car").to_vec(),
+        }])
+    );
+}
+
+#[test]
+fn insert_builds_create_instructions() {
+    let s = setup();
+    let payer = Pubkey::new_unique();
+    let insert = match one(
+        &s,
+        &format!(
+            "INSERT INTO person (name, authority) VALUES ('Dave', '{}'), ('Erin', '{}')",
+            s.bob, s.alice
+        ),
+    ) {
+        Operation::Insert(insert) => insert,
+        operation => panic!("Expected an insert, found {:?}", operation),
+    };
+    let instructions = insert.instructions(&s.rpc, &payer).unwrap();
+    assert_eq!(instructions.len(), 2);
+    // The index has counted 3 people, so they're the 4th and 5th.
+    let dave = &instructions[0];
+    assert_eq!(dave.program_id, s.program_id);
+    let accounts: Vec<_> = dave
+        .accounts
+        .iter()
+        .map(|a| (a.pubkey, a.is_writable, a.is_signer))
+        .collect();
+    assert_eq!(
+        accounts,
+        vec![
+            (index_address(&s.program_id), true, false),
+            (address(&s.program_id, "person", &[4]), true, false),
+            (payer, true, true),
+            (sysvar::rent::ID, false, false),
+            (system_program::ID, false, false),
+        ]
+    );
+    let mut data = vec![0];
+    data.extend(("Dave".to_string(), s.bob).try_to_vec().unwrap());
+    assert_eq!(dave.data, data);
+    assert_eq!(
+        instructions[1].accounts[1].pubkey,
+        address(&s.program_id, "person", &[5])
+    );
+
+    // Without named columns, values fill the columns in order.
+    let insert = match one(&s, "INSERT INTO car VALUES (400, 2023, 'Polestar', NULL)") {
+        Operation::Insert(insert) => insert,
+        operation => panic!("Expected an insert, found {:?}", operation),
+    };
+    let instructions = insert.instructions(&s.rpc, &payer).unwrap();
+    assert_eq!(
+        instructions[0].accounts[0].pubkey,
+        address(&s.program_id, "car", &400u64.to_le_bytes())
+    );
+    let mut data = vec![3];
+    data.extend(
+        (400u64, 2023u16, "Polestar".to_string(), None::<String>)
+            .try_to_vec()
+            .unwrap(),
+    );
+    assert_eq!(instructions[0].data, data);
+
+    // Autoincrement needs the program's index.
+    let mut rpc = LocalRpc::new();
+    rpc.add_account(
+        Pubkey::new_unique(),
+        s.rpc
+            .account(&index_address(&s.program_id))
+            .unwrap()
+            .clone(),
+    );
+    let insert = match one(
+        &s,
+        &format!(
+            "INSERT INTO person (name, authority) VALUES ('Dave', '{}')",
+            s.bob
+        ),
+    ) {
+        Operation::Insert(insert) => insert,
+        operation => panic!("Expected an insert, found {:?}", operation),
+    };
+    assert!(insert
+        .instructions(&rpc, &payer)
+        .unwrap_err()
+        .to_string()
+        .contains("hasn't been initialized"));
+}
+
+#[test]
+fn update_and_delete_matching_records() {
+    let s = setup();
+    let payer = Pubkey::new_unique();
+    let instructions = match one(
+        &s,
+        &format!(
+            "UPDATE person SET name = 'Alicia' WHERE authority = '{}' AND id < 3",
+            s.alice
+        ),
+    ) {
+        Operation::Update(update) => update.instructions(&s.rpc, &payer).unwrap(),
+        operation => panic!("Expected an update, found {:?}", operation),
+    };
+    assert_eq!(instructions.len(), 1);
+    let update = &instructions[0];
+    let accounts: Vec<_> = update
+        .accounts
+        .iter()
+        .map(|a| (a.pubkey, a.is_writable, a.is_signer))
+        .collect();
+    assert_eq!(
+        accounts,
+        vec![
+            (address(&s.program_id, "person", &[1]), true, false),
+            (s.alice, false, true),
+            (payer, true, true),
+            (system_program::ID, false, false),
+        ]
+    );
+    let mut data = vec![1];
+    data.extend(("Alicia".to_string(), s.alice).try_to_vec().unwrap());
+    assert_eq!(update.data, data);
+
+    let instructions = match one(&s, "DELETE FROM person WHERE id = 2 OR id = 3") {
+        Operation::Delete(delete) => delete.instructions(&s.rpc, &payer).unwrap(),
+        operation => panic!("Expected a delete, found {:?}", operation),
+    };
+    let mut signers: Vec<_> = instructions
+        .iter()
+        .map(|i| (i.data.clone(), i.accounts[0].pubkey, i.accounts[1].pubkey))
+        .collect();
+    signers.sort();
+    let mut expected = vec![
+        (vec![2], address(&s.program_id, "person", &[2]), s.bob),
+        (vec![2], address(&s.program_id, "person", &[3]), s.alice),
+    ];
+    expected.sort();
+    assert_eq!(signers, expected);
+
+    // `car` has no default `update` or `delete` instructions.
+    let delete = match one(&s, "DELETE FROM car WHERE vin = 100") {
+        Operation::Delete(delete) => delete,
+        operation => panic!("Expected a delete, found {:?}", operation),
+    };
+    assert_eq!(
+        delete.instructions(&s.rpc, &payer).unwrap_err().to_string(),
+        "The `people` IDL has no `deleteCar` instruction to delete from `car`"
+    );
+}
+
+#[test]
+fn invalid_statements() {
+    let s = setup();
+    let cases = [
+        ("SELECT * FROM ship", "The `people` IDL has no `ship` table"),
+        ("SELECT wheels FROM car", "`car` has no column `wheels`"),
+        (
+            "SELECT * FROM car WHERE owner = NULL",
+            "`owner = NULL` is never true - use `IS NULL` or `IS NOT NULL` instead",
+        ),
+        (
+            "SELECT * FROM car WHERE year = 'old'",
+            "`car.year` is a `\"u16\"`, but was given \"old\"",
+        ),
+        (
+            "SELECT * FROM car WHERE year = 70000",
+            "`car.year` is a `\"u16\"`, but was given 70000",
+        ),
+        (
+            "SELECT * FROM person WHERE authority > 'x'",
+            "`person.authority` can't be compared with `>`",
+        ),
+        (
+            "SELECT * FROM car WHERE make LIKE 'S%'",
+            "`make LIKE 'S%'` isn't a supported condition",
+        ),
+        (
+            "SELECT DISTINCT make FROM car",
+            "Nautilus SQL doesn't support `DISTINCT`",
+        ),
+        (
+            "SELECT * FROM car JOIN person ON car.owner = person.name",
+            "Nautilus SQL doesn't support joins",
+        ),
+        (
+            "INSERT INTO person (id, name, authority) VALUES (4, 'Dave', 'x')",
+            "You cannot provide a value for the primary key if autoincrement is enabled",
+        ),
+        (
+            "INSERT INTO car VALUES (400, 2023)",
+            "`car` expects 4 values per row, but found 2",
+        ),
+        (
+            "UPDATE car SET vin = 5 WHERE vin = 100",
+            "You cannot change a primary key with an UPDATE operation",
+        ),
+        (
+            "DROP TABLE car",
+            "Only `SELECT`, `INSERT`, `UPDATE` and `DELETE` statements are supported, but found \
+             `DROP TABLE car`",
+        ),
+    ];
+    for (sql, message) in cases {
+        assert_eq!(error(&s, sql), message, "{}", sql);
+    }
+    assert!(error(&s, "SELEC * FROM car").starts_with("Invalid SQL: "));
+}
@@ -1,7 +1,7 @@
 //! Cross-Program invocations to the Associated Token Program
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program::invoke};
 
-use crate::{AssociatedTokenAccount, NautilusAccountInfo, NautilusSigner};
+use crate::{profile::Phase, AssociatedTokenAccount, NautilusAccountInfo, NautilusSigner};
 
 /// Creates an associated token account.
 #[allow(clippy::boxed_local)]
@@ -14,6 +14,7 @@ pub fn create_associated_token_account<'a>(
     token_program: Box<AccountInfo<'a>>,
     associated_token_program: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::associated_token::create_associated_token_account");
     invoke(
         &spl_associated_token_account::instruction::create_associated_token_account(
             payer.key(),
@@ -42,6 +43,7 @@ pub fn recover_nested<'a>(
     token_program: Box<AccountInfo<'a>>,
     associated_token_program: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::associated_token::recover_nested");
     invoke(
         &spl_associated_token_account::instruction::recover_nested(
             wallet.key(),
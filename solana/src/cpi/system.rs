@@ -7,10 +7,11 @@ use solana_program::{
     system_instruction,
 };
 
-use crate::{NautilusAccountInfo, NautilusMut, NautilusSigner};
+use crate::{profile::Phase, NautilusAccountInfo, NautilusMut, NautilusSigner};
 
 /// Allocate space for an account.
 pub fn allocate<'a>(new_account: impl NautilusSigner<'a>) -> ProgramResult {
+    let _phase = Phase::begin("cpi::system::allocate");
     invoke(
         &system_instruction::allocate(new_account.key(), new_account.size()?),
         &[*new_account.account_info()],
@@ -19,6 +20,7 @@ pub fn allocate<'a>(new_account: impl NautilusSigner<'a>) -> ProgramResult {
 
 /// Assign ownership of an account from the system program.
 pub fn assign<'a>(new_account: impl NautilusSigner<'a>, owner: &Pubkey) -> ProgramResult {
+    let _phase = Phase::begin("cpi::system::assign");
     invoke(
         &system_instruction::assign(new_account.key(), owner),
         &[*new_account.account_info()],
@@ -31,6 +33,7 @@ pub fn create_account<'a>(
     owner: &Pubkey,
     payer: impl NautilusSigner<'a>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::system::create_account");
     invoke(
         &system_instruction::create_account(
             payer.key(),
@@ -53,6 +56,7 @@ pub fn create_pda<'a, T: BorshSerialize>(
     data: Box<T>,
     signer_seeds: Vec<&[u8]>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::system::create_pda");
     invoke_signed(
         &system_instruction::create_account(
             payer.key(),
@@ -74,6 +78,7 @@ pub fn transfer<'a>(
     to: impl NautilusMut<'a>,
     amount: u64,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::system::transfer");
     invoke(
         &solana_program::system_instruction::transfer(from.key(), to.key(), amount),
         &[*from.account_info(), *to.account_info()],
@@ -4,7 +4,7 @@ use solana_program::{
 };
 use spl_token::instruction::AuthorityType;
 
-use crate::{NautilusAccountInfo, NautilusMut, NautilusSigner};
+use crate::{profile::Phase, NautilusAccountInfo, NautilusMut, NautilusSigner};
 
 /// Approves a delegate.  A delegate is given the authority over tokens on
 /// behalf of the source account's owner.
@@ -16,6 +16,7 @@ pub fn approve<'a>(
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
     amount: u64,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::approve");
     let mut accounts = vec![
         *source_account.account_info(),
         *delegate.account_info(),
@@ -55,6 +56,7 @@ pub fn approve_checked<'a>(
     amount: u64,
     decimals: u8,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::approve_checked");
     let mut accounts = vec![
         *source_account.account_info(),
         *delegate.account_info(),
@@ -88,6 +90,7 @@ pub fn burn<'a>(
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
     amount: u64,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::burn");
     let mut accounts = vec![
         *token_account.account_info(),
         *mint.account_info(),
@@ -126,6 +129,7 @@ pub fn burn_checked<'a>(
     amount: u64,
     decimals: u8,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::burn_checked");
     let mut accounts = vec![
         *token_account.account_info(),
         *mint.account_info(),
@@ -158,6 +162,7 @@ pub fn close_account<'a>(
     authority: impl NautilusSigner<'a>,
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::close_account");
     let mut accounts = vec![
         *token_account.account_info(),
         *destination.account_info(),
@@ -188,6 +193,7 @@ pub fn freeze_account<'a>(
     freeze_authority: impl NautilusSigner<'a>,
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::freeze_account");
     let mut accounts = vec![
         *token_account.account_info(),
         *mint.account_info(),
@@ -222,6 +228,7 @@ pub fn initialize_account<'a>(
     authority: impl NautilusAccountInfo<'a>,
     rent: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_account");
     invoke(
         &spl_token::instruction::initialize_account(
             token_program_id,
@@ -250,6 +257,7 @@ pub fn initialize_account2<'a>(
     authority: &Pubkey,
     rent: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_account2");
     invoke(
         &spl_token::instruction::initialize_account2(
             token_program_id,
@@ -272,6 +280,7 @@ pub fn initialize_account3<'a>(
     mint: impl NautilusAccountInfo<'a>,
     authority: &Pubkey,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_account3");
     invoke(
         &spl_token::instruction::initialize_account3(
             token_program_id,
@@ -291,6 +300,7 @@ pub fn initialize_immutable_owner<'a>(
     token_program_id: &Pubkey,
     new_token_account: impl NautilusMut<'a>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_immutable_owner");
     invoke(
         &spl_token::instruction::initialize_immutable_owner(
             token_program_id,
@@ -311,6 +321,7 @@ pub fn initialize_mint<'a>(
     decimals: u8,
     rent: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_mint");
     invoke(
         &spl_token::instruction::initialize_mint(
             token_program_id,
@@ -331,6 +342,7 @@ pub fn initialize_mint2<'a>(
     freeze_authority: Option<&Pubkey>,
     decimals: u8,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_mint2");
     invoke(
         &spl_token::instruction::initialize_mint2(
             token_program_id,
@@ -357,6 +369,7 @@ pub fn initialize_multisig<'a>(
     m: u8,
     rent: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_multisig");
     let mut accounts = vec![*multisig_account.account_info(), *rent];
     let signer_pubkeys = append_multisig_accounts_and_return_keys(&mut accounts, multisigs);
     invoke(
@@ -382,6 +395,7 @@ pub fn initialize_multisig2<'a>(
     multisigs: Vec<impl NautilusAccountInfo<'a>>,
     m: u8,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::initialize_multisig2");
     let mut accounts = vec![*multisig_account.account_info()];
     let signer_pubkeys = append_multisig_accounts_and_return_keys(&mut accounts, multisigs);
     invoke(
@@ -405,6 +419,7 @@ pub fn mint_to<'a>(
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
     amount: u64,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::mint_to");
     let mut accounts = vec![
         *mint.account_info(),
         *recipient.account_info(),
@@ -442,6 +457,7 @@ pub fn mint_to_checked<'a>(
     amount: u64,
     decimals: u8,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::mint_to_checked");
     let mut accounts = vec![
         *mint.account_info(),
         *recipient.account_info(),
@@ -472,6 +488,7 @@ pub fn revoke<'a>(
     source_owner: impl NautilusSigner<'a>,
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::revoke");
     let mut accounts = vec![*source_account.account_info(), *source_owner.account_info()];
     let signer_pubkeys = match multisigs {
         Some(sigs) => append_multisig_accounts_and_return_keys(&mut accounts, sigs),
@@ -497,6 +514,7 @@ pub fn set_authority<'a>(
     current_authority: impl NautilusSigner<'a>,
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::set_authority");
     let mut accounts = vec![
         *mint_or_account.account_info(),
         *current_authority.account_info(),
@@ -527,6 +545,7 @@ pub fn sync_native<'a>(
     token_program_id: &Pubkey,
     token_account: impl NautilusMut<'a>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::sync_native");
     invoke(
         &spl_token::instruction::sync_native(token_program_id, token_account.key())?,
         &[*token_account.account_info()],
@@ -541,6 +560,7 @@ pub fn thaw_account<'a>(
     freeze_authority: impl NautilusSigner<'a>,
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::thaw_account");
     let mut accounts = vec![
         *token_account.account_info(),
         *mint.account_info(),
@@ -572,6 +592,7 @@ pub fn transfer<'a>(
     multisigs: Option<Vec<impl NautilusSigner<'a>>>,
     amount: u64,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::transfer");
     let mut accounts = vec![
         *from.account_info(),
         *to.account_info(),
@@ -613,6 +634,7 @@ pub fn transfer_checked<'a>(
     amount: u64,
     decimals: u8,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token::transfer_checked");
     let mut accounts = vec![
         *mint.account_info(),
         *from.account_info(),
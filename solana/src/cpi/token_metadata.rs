@@ -4,7 +4,7 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke, pubkey::Pubkey,
 };
 
-use crate::{Create, Metadata, NautilusAccountInfo, NautilusMut, NautilusSigner};
+use crate::{profile::Phase, Create, Metadata, NautilusAccountInfo, NautilusMut, NautilusSigner};
 
 /// Creates a Metadata account with the Token Metadata Program.
 #[allow(clippy::boxed_local)]
@@ -21,6 +21,7 @@ pub fn create_metadata_v3<'a>(
     payer: impl NautilusSigner<'a>,
     rent: Box<AccountInfo<'a>>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token_metadata::create_metadata_v3");
     invoke(
         &mpl_token_metadata::instruction::create_metadata_accounts_v3(
             *token_metadata_program_id,
@@ -64,6 +65,7 @@ pub fn create_master_edition_v3<'a>(
     rent: Box<AccountInfo<'a>>,
     max_supply: Option<u64>,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token_metadata::create_master_edition_v3");
     invoke(
         &mpl_token_metadata::instruction::create_master_edition_v3(
             *token_metadata_program_id,
@@ -105,6 +107,7 @@ pub fn mint_edition_from_master_edition<'a>(
     rent: Box<AccountInfo<'a>>,
     edition_val: u64,
 ) -> ProgramResult {
+    let _phase = Phase::begin("cpi::token_metadata::mint_edition_from_master_edition");
     invoke(
         &mpl_token_metadata::instruction::mint_new_edition_from_master_edition_via_token(
             *token_metadata_program_id,
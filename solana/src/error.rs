@@ -4,9 +4,10 @@ use solana_program::{
     decode_error::DecodeError,
     program_error::{PrintProgramError, ProgramError},
 };
-use splogger::{error, Splog};
 use thiserror::Error;
 
+use crate::{log_debug, log_error};
+
 /// Custom errors for Nautilus functionality. Convertible to
 /// `solana_program::program_error::ProgramError`.
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
@@ -81,45 +82,41 @@ impl ToPrimitive for NautilusError {
 }
 
 impl PrintProgramError for NautilusError {
+    /// Describes the error once, with the `log-error` feature - see
+    /// `nautilus::log`.
     fn print<E>(&self)
     where
         E: 'static + std::error::Error + DecodeError<E> + PrintProgramError + FromPrimitive,
     {
         match self {
             Self::LoadDataFailed(state_type, pubkey) => {
-                error!(
-                    "Failed to load {} data from account: {}",
-                    state_type, pubkey
-                );
-                error!("Could not borrow account data");
-                error!(
+                log_error!(
                     "Failed to load {} data from account: {}",
-                    state_type, pubkey
+                    state_type,
+                    pubkey
                 );
+                log_debug!("Could not borrow account data");
             }
             Self::DeserializeDataFailed(state_type, pubkey) => {
-                error!(
-                    "Failed to deserialize {} data from account: {}",
-                    state_type, pubkey
-                );
-                error!("Could not deserialize");
-                error!(
+                log_error!(
                     "Failed to deserialize {} data from account: {}",
-                    state_type, pubkey
+                    state_type,
+                    pubkey
                 );
+                log_debug!("Could not deserialize");
             }
             Self::WriteRecordFailed(table_name) => {
-                error!("Failed to create a new record for table: {}", table_name);
+                log_error!("Failed to create a new record for table: {}", table_name);
             }
-            Self::AccountNotMutable(pubkey) => error!(
+            Self::AccountNotMutable(pubkey) => log_error!(
                 "This account was marked with `Mut<T>` but was not passed in as mutable: {}",
                 pubkey
             ),
-            Self::AccountNotSigner(pubkey) => error!(
+            Self::AccountNotSigner(pubkey) => log_error!(
                 "This account was marked with `Signer<T>` but was not passed in as signer: {}",
                 pubkey
             ),
-            Self::AccountExists(pubkey) => error!(
+            Self::AccountExists(pubkey) => log_error!(
                 "This account was marked with `Create<T>` but it exists already: {}",
                 pubkey
             ),
@@ -9,9 +9,14 @@ extern crate self as nautilus;
 
 pub mod cpi;
 pub mod error;
+pub mod log;
 pub mod objects;
+pub mod profile;
 pub mod properties;
+#[cfg(not(target_os = "solana"))]
+pub mod testing;
 
+pub use bytemuck;
 pub use mpl_token_metadata;
 pub use solana_program;
 pub use spl_associated_token_account;
@@ -33,7 +38,8 @@ pub use solana_program::{
 };
 
 pub use objects::{
-    accounts::*,
+    accounts::{zero_copy::*, *},
+    lazy::*,
     records::{index::*, *},
     tokens::{associated_token::*, metadata::*, mint::*, nft::*, token::*, *},
     wallets::*,
@@ -0,0 +1,77 @@
+//! The framework's own logging, leveled at compile time with features:
+//!
+//! * `log-error`: describes each `NautilusError` as it's returned.
+//! * `log-info`: also logs `Instruction: <name>` as each instruction starts.
+//! * `log-debug`: also logs the details behind each error.
+//!
+//! Without any of them the framework logs nothing, so its logs cost no compute
+//! units. Programs' own `splogger` calls aren't affected.
+//!
+//! A disabled macro still type-checks its arguments, so values only logged
+//! don't become unused.
+
+/// Logs with `splogger::error!` when the `log-error` feature is enabled.
+#[cfg(feature = "log-error")]
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)*) => {{
+        #[allow(unused_imports)]
+        use $crate::splogger::Splog;
+        $crate::splogger::error!($($arg)*)
+    }};
+}
+
+/// Logs with `splogger::error!` when the `log-error` feature is enabled.
+#[cfg(not(feature = "log-error"))]
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)*) => {{
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    }};
+}
+
+/// Logs with `splogger::info!` when the `log-info` feature is enabled.
+#[cfg(feature = "log-info")]
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)*) => {{
+        #[allow(unused_imports)]
+        use $crate::splogger::Splog;
+        $crate::splogger::info!($($arg)*)
+    }};
+}
+
+/// Logs with `splogger::info!` when the `log-info` feature is enabled.
+#[cfg(not(feature = "log-info"))]
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)*) => {{
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    }};
+}
+
+/// Logs with `splogger::info!` when the `log-debug` feature is enabled.
+#[cfg(feature = "log-debug")]
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {{
+        #[allow(unused_imports)]
+        use $crate::splogger::Splog;
+        $crate::splogger::info!($($arg)*)
+    }};
+}
+
+/// Logs with `splogger::info!` when the `log-debug` feature is enabled.
+#[cfg(not(feature = "log-debug"))]
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {{
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    }};
+}
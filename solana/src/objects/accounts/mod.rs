@@ -2,17 +2,20 @@
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
-    error::NautilusError, Mut, NautilusAccount, NautilusAccountData, NautilusAccountInfo,
-    NautilusMut, NautilusTransferLamports,
+    data::discriminator, objects::lazy::check_account_data, Lazy, Mut, NautilusAccount,
+    NautilusAccountData, NautilusAccountInfo, NautilusMut, NautilusTransferLamports,
 };
 
+pub mod zero_copy;
+
 /// The struct that allows you to create a plain-old program-derived address
 /// (PDA) account.
 ///
 /// A user wraps their data type `T` with `Account<'_, T>` in order to combine
 /// the data stored within the account and its underlying AccountInfo.
 ///
-/// The `account_info` field represents the PDA itself.
+/// The `account_info` field represents the PDA itself. Its `data` is
+/// deserialized when it's first accessed - see `Lazy<T>`.
 #[derive(Clone)]
 pub struct Account<'a, T>
 where
@@ -20,7 +23,7 @@ where
 {
     pub program_id: &'a Pubkey,
     pub account_info: Box<AccountInfo<'a>>,
-    pub data: Box<T>,
+    pub data: Lazy<'a, T>,
 }
 
 impl<'a, T> Account<'a, T>
@@ -32,39 +35,35 @@ where
     pub fn new(program_id: &'a Pubkey, account_info: Box<AccountInfo<'a>>) -> Self {
         Self {
             program_id,
+            data: Lazy::with_value(
+                account_info.clone(),
+                T::DISCRIMINATOR_STR,
+                |data| T::try_from_slice(data).ok(),
+                Box::<T>::default(),
+            ),
             account_info,
-            data: Box::<T>::default(),
         }
     }
 
-    /// Instantiate a new PDA and load the account inner data from on-chain.
+    /// Instantiate a PDA, checking it's owned by the program and holds a `T`.
+    /// The account inner data is loaded from on-chain when it's first
+    /// accessed.
     pub fn load(
         program_id: &'a Pubkey,
         account_info: Box<AccountInfo<'a>>,
     ) -> Result<Self, ProgramError> {
-        let data = match T::try_from_slice(match &account_info.try_borrow_data() {
-            Ok(acct_data) => acct_data,
-            Err(_) => {
-                return Err(NautilusError::LoadDataFailed(
-                    T::DISCRIMINATOR_STR.to_string(),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        }) {
-            Ok(state_data) => Box::new(state_data),
-            Err(_) => {
-                return Err(NautilusError::DeserializeDataFailed(
-                    T::DISCRIMINATOR_STR.to_string(),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        };
+        check_account_data(
+            &account_info,
+            T::DISCRIMINATOR_STR,
+            &[*program_id],
+            |data| data.starts_with(&discriminator(T::DISCRIMINATOR_STR)),
+        )?;
         Ok(Self {
             program_id,
+            data: Lazy::new(account_info.clone(), T::DISCRIMINATOR_STR, |data| {
+                T::try_from_slice(data).ok()
+            }),
             account_info,
-            data,
         })
     }
 }
@@ -102,7 +101,7 @@ where
     }
 
     fn span(&self) -> Result<usize, ProgramError> {
-        Ok(self.data.try_to_vec()?.len())
+        Ok(Lazy::try_get(&self.data)?.try_to_vec()?.len())
     }
 }
 
@@ -111,15 +110,15 @@ where
     T: NautilusAccountData,
 {
     fn discriminator(&self) -> [u8; 8] {
-        self.data.discriminator()
+        discriminator(T::DISCRIMINATOR_STR)
     }
 
     fn check_authorities(&self, accounts: Vec<AccountInfo>) -> Result<(), ProgramError> {
-        self.data.check_authorities(accounts)
+        Lazy::try_get(&self.data)?.check_authorities(accounts)
     }
 
-    fn count_authorities(&self) -> u8 {
-        self.data.count_authorities()
+    fn count_authorities(&self) -> Result<u8, ProgramError> {
+        Ok(Lazy::try_get(&self.data)?.count_authorities())
     }
 }
 
@@ -0,0 +1,176 @@
+//! The `ZeroCopyAccount<T>` Nautilus object and all associated trait
+//! implementations.
+use std::{
+    cell::{Ref, RefMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::NautilusError, objects::lazy::check_account_data, Mut, NautilusAccountInfo, NautilusMut,
+    NautilusTransferLamports, NautilusZeroCopyData,
+};
+
+/// The struct that allows you to create a program-derived address (PDA)
+/// account whose data is read and written in place, rather than deserialized.
+///
+/// A user wraps their `#[zero_copy]` data type `T` with
+/// `ZeroCopyAccount<'_, T>`. The account holds `T`'s 8-byte discriminator
+/// followed by `T` itself, and `data()` and `data_mut()` return views directly
+/// into the account's data - so large accounts cost nothing to load.
+///
+/// The `account_info` field represents the PDA itself.
+#[derive(Clone)]
+pub struct ZeroCopyAccount<'a, T>
+where
+    T: NautilusZeroCopyData,
+{
+    pub program_id: &'a Pubkey,
+    pub account_info: Box<AccountInfo<'a>>,
+    data: PhantomData<T>,
+}
+
+impl<'a, T> ZeroCopyAccount<'a, T>
+where
+    T: NautilusZeroCopyData,
+{
+    /// The size of the account's data: the discriminator followed by `T`.
+    pub const SPAN: usize = 8 + size_of::<T>();
+
+    /// Instantiate a new PDA without checking the account's data.
+    pub fn new(program_id: &'a Pubkey, account_info: Box<AccountInfo<'a>>) -> Self {
+        Self {
+            program_id,
+            account_info,
+            data: PhantomData,
+        }
+    }
+
+    /// Instantiate a PDA, checking that it's owned by the program and its data
+    /// holds a `T` - without copying it.
+    pub fn load(
+        program_id: &'a Pubkey,
+        account_info: Box<AccountInfo<'a>>,
+    ) -> Result<Self, ProgramError> {
+        check_account_data(
+            &account_info,
+            T::DISCRIMINATOR_STR,
+            &[*program_id],
+            Self::holds_data,
+        )?;
+        Ok(Self::new(program_id, account_info))
+    }
+
+    /// Borrows the account's data as a `T`.
+    ///
+    /// Fails if the data is already borrowed mutably, or doesn't start with
+    /// `T`'s discriminator.
+    pub fn data(&self) -> Result<Ref<'_, T>, ProgramError> {
+        let data = self
+            .account_info
+            .try_borrow_data()
+            .map_err(|_| self.load_data_failed())?;
+        self.check(&data)?;
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..Self::SPAN])
+        }))
+    }
+
+    /// Borrows the account's data mutably as a `T`. Writes go straight to the
+    /// account.
+    ///
+    /// Fails if the data is already borrowed, or doesn't start with `T`'s
+    /// discriminator.
+    pub fn data_mut(&self) -> Result<RefMut<'_, T>, ProgramError> {
+        let data = self
+            .account_info
+            .try_borrow_mut_data()
+            .map_err(|_| self.load_data_failed())?;
+        self.check(&data)?;
+        Ok(RefMut::map(data, |data| {
+            bytemuck::from_bytes_mut(&mut data[8..Self::SPAN])
+        }))
+    }
+
+    /// Whether the data is long enough to hold a `T`, starts with its
+    /// discriminator, and is aligned for it.
+    fn holds_data(data: &[u8]) -> bool {
+        data.len() >= Self::SPAN
+            && data[..8] == T::DISCRIMINATOR
+            && bytemuck::try_from_bytes::<T>(&data[8..Self::SPAN]).is_ok()
+    }
+
+    fn check(&self, data: &[u8]) -> Result<(), ProgramError> {
+        if !Self::holds_data(data) {
+            return Err(NautilusError::DeserializeDataFailed(
+                T::DISCRIMINATOR_STR.to_string(),
+                self.account_info.key.to_string(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    fn load_data_failed(&self) -> ProgramError {
+        NautilusError::LoadDataFailed(
+            T::DISCRIMINATOR_STR.to_string(),
+            self.account_info.key.to_string(),
+        )
+        .into()
+    }
+}
+
+impl<'a, T> NautilusAccountInfo<'a> for ZeroCopyAccount<'a, T>
+where
+    T: NautilusZeroCopyData,
+{
+    fn account_info(&self) -> Box<AccountInfo<'a>> {
+        self.account_info.clone()
+    }
+
+    fn key(&self) -> &'a Pubkey {
+        self.account_info.key
+    }
+
+    fn is_signer(&self) -> bool {
+        self.account_info.is_signer
+    }
+
+    fn is_writable(&self) -> bool {
+        self.account_info.is_writable
+    }
+
+    fn lamports(&self) -> u64 {
+        self.account_info.lamports()
+    }
+
+    fn mut_lamports(&self) -> Result<std::cell::RefMut<'_, &'a mut u64>, ProgramError> {
+        self.account_info.try_borrow_mut_lamports()
+    }
+
+    fn owner(&self) -> &'a Pubkey {
+        self.account_info.owner
+    }
+
+    fn span(&self) -> Result<usize, ProgramError> {
+        Ok(Self::SPAN)
+    }
+}
+
+impl<'a, T> NautilusTransferLamports<'a> for Mut<ZeroCopyAccount<'a, T>>
+where
+    T: NautilusZeroCopyData,
+{
+    fn transfer_lamports(
+        &self,
+        to: impl NautilusMut<'a>,
+        amount: u64,
+    ) -> solana_program::entrypoint::ProgramResult {
+        let from = self.account_info();
+        **from.try_borrow_mut_lamports()? -= amount;
+        **to.mut_lamports()? += amount;
+        Ok(())
+    }
+}
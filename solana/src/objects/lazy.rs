@@ -0,0 +1,151 @@
+//! Account data that's deserialized when it's first accessed.
+use std::ops::{Deref, DerefMut};
+
+use once_cell::unsync::OnceCell;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::NautilusError;
+
+/// The inner data of a Nautilus object, deserialized from its account the first
+/// time it's accessed and cached after that.
+///
+/// Loading an object only checks that its account holds the right type of
+/// data - its owner, and its discriminator or size - so an instruction that
+/// only needs an account's key or lamports never pays to deserialize it.
+///
+/// `Lazy::try_get(..)` and `Lazy::try_get_mut(..)` return a
+/// `DeserializeDataFailed` error if the data can't be deserialized, and are
+/// what Nautilus itself uses. For convenience, `Lazy<T>` also dereferences to
+/// `T`, but that panics instead - aborting the program without an error code.
+/// Like `Rc`'s, its own functions are associated functions - ie.
+/// `Lazy::try_get(&account.data)` - so they never shadow `T`'s methods.
+#[derive(Clone)]
+pub struct Lazy<'a, T> {
+    account_info: Box<AccountInfo<'a>>,
+    state_type: &'static str,
+    deserialize: fn(&[u8]) -> Option<T>,
+    value: OnceCell<Box<T>>,
+}
+
+impl<'a, T> Lazy<'a, T> {
+    /// Defers deserializing the account's data with `deserialize` until it's
+    /// first accessed. `state_type` names the data in errors.
+    pub fn new(
+        account_info: Box<AccountInfo<'a>>,
+        state_type: &'static str,
+        deserialize: fn(&[u8]) -> Option<T>,
+    ) -> Self {
+        Self {
+            account_info,
+            state_type,
+            deserialize,
+            value: OnceCell::new(),
+        }
+    }
+
+    /// Like `new`, but with the data already in hand - ie. the default data of
+    /// an account that's yet to be created.
+    pub fn with_value(
+        account_info: Box<AccountInfo<'a>>,
+        state_type: &'static str,
+        deserialize: fn(&[u8]) -> Option<T>,
+        value: Box<T>,
+    ) -> Self {
+        Self {
+            account_info,
+            state_type,
+            deserialize,
+            value: OnceCell::from(value),
+        }
+    }
+
+    /// Returns the data, deserializing it if it hasn't been yet.
+    pub fn try_get(this: &Self) -> Result<&T, ProgramError> {
+        this.value
+            .get_or_try_init(|| this.load())
+            .map(|value| &**value)
+    }
+
+    /// Returns the data mutably, deserializing it if it hasn't been yet.
+    pub fn try_get_mut(this: &mut Self) -> Result<&mut T, ProgramError> {
+        Self::try_get(this)?;
+        Ok(this.value.get_mut().expect("The data was just loaded"))
+    }
+
+    /// Replaces the data without deserializing the account's, ie. with the data
+    /// an account is about to be created with.
+    pub fn set(this: &mut Self, value: Box<T>) {
+        this.value = OnceCell::from(value);
+    }
+
+    /// Whether the data has been deserialized (or set) yet.
+    pub fn is_loaded(this: &Self) -> bool {
+        this.value.get().is_some()
+    }
+
+    fn load(&self) -> Result<Box<T>, ProgramError> {
+        let data = self.account_info.try_borrow_data().map_err(|_| {
+            NautilusError::LoadDataFailed(
+                self.state_type.to_string(),
+                self.account_info.key.to_string(),
+            )
+        })?;
+        match (self.deserialize)(&data) {
+            Some(value) => Ok(Box::new(value)),
+            None => Err(NautilusError::DeserializeDataFailed(
+                self.state_type.to_string(),
+                self.account_info.key.to_string(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl<'a, T> Deref for Lazy<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match Self::try_get(self) {
+            Ok(value) => value,
+            Err(e) => panic!(
+                "Failed to deserialize {} data from account {}: {}",
+                self.state_type, self.account_info.key, e
+            ),
+        }
+    }
+}
+
+impl<'a, T> DerefMut for Lazy<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        let (state_type, key) = (self.state_type, self.account_info.key);
+        match Self::try_get_mut(self) {
+            Ok(value) => value,
+            Err(e) => panic!(
+                "Failed to deserialize {} data from account {}: {}",
+                state_type, key, e
+            ),
+        }
+    }
+}
+
+/// Checks up front, without deserializing it, that an account holds
+/// `state_type` data: that it's owned by one of `owners` and `check` accepts
+/// its data - ie. that it starts with the right discriminator.
+pub(crate) fn check_account_data(
+    account_info: &AccountInfo,
+    state_type: &str,
+    owners: &[Pubkey],
+    check: impl FnOnce(&[u8]) -> bool,
+) -> Result<(), ProgramError> {
+    let data = account_info.try_borrow_data().map_err(|_| {
+        NautilusError::LoadDataFailed(state_type.to_string(), account_info.key.to_string())
+    })?;
+    match owners.contains(account_info.owner) && check(&data) {
+        true => Ok(()),
+        false => Err(NautilusError::DeserializeDataFailed(
+            state_type.to_string(),
+            account_info.key.to_string(),
+        )
+        .into()),
+    }
+}
@@ -1,6 +1,7 @@
 //! Submodule containing all Nautilus objects and their associated trait
 //! implementations.
 pub mod accounts;
+pub mod lazy;
 pub mod records;
 pub mod tokens;
 pub mod wallets;
@@ -218,24 +218,24 @@ impl<'a> NautilusRecord<'a> for NautilusIndex<'a> {
         self.data.discriminator()
     }
 
-    fn seeds(&self) -> Vec<Vec<u8>> {
-        self.data.seeds()
+    fn seeds(&self) -> Result<Vec<Vec<u8>>, ProgramError> {
+        Ok(self.data.seeds())
     }
 
-    fn pda(&self) -> (Pubkey, u8) {
-        self.data.pda(self.program_id)
+    fn pda(&self) -> Result<(Pubkey, u8), ProgramError> {
+        Ok(self.data.pda(self.program_id))
     }
 
-    fn primary_key(&self) -> Vec<u8> {
-        self.data.primary_key()
+    fn primary_key(&self) -> Result<Vec<u8>, ProgramError> {
+        Ok(self.data.primary_key())
     }
 
     fn check_authorities(&self, accounts: Vec<AccountInfo>) -> Result<(), ProgramError> {
         self.data.check_authorities(accounts)
     }
 
-    fn count_authorities(&self) -> u8 {
-        self.data.count_authorities()
+    fn count_authorities(&self) -> Result<u8, ProgramError> {
+        Ok(self.data.count_authorities())
     }
 }
 
@@ -265,14 +265,14 @@ impl<'a> Create<'a, NautilusIndex<'a>> {
             index: std::collections::HashMap::new(),
         };
         let data_pointer = Box::new(data);
-        let (pda, bump) = self.pda();
+        let (pda, bump) = self.pda()?;
         assert_eq!(
             &pda,
             self.key(),
             "Derived PDA does not match data for account {:#?}",
             self.key()
         );
-        let mut signer_seeds_vec = self.seeds();
+        let mut signer_seeds_vec = self.seeds()?;
         signer_seeds_vec.push(vec![bump]);
         let signer_seeds: Vec<&[u8]> = signer_seeds_vec.iter().map(AsRef::as_ref).collect();
         cpi::system::create_pda(
@@ -293,14 +293,14 @@ impl<'a> Create<'a, NautilusIndex<'a>> {
             index: std::collections::HashMap::new(),
         };
         let data_pointer = Box::new(data);
-        let (pda, bump) = self.pda();
+        let (pda, bump) = self.pda()?;
         assert_eq!(
             &pda,
             self.key(),
             "Derived PDA does not match data for account {:#?}",
             self.key()
         );
-        let mut signer_seeds_vec = self.seeds();
+        let mut signer_seeds_vec = self.seeds()?;
         signer_seeds_vec.push(vec![bump]);
         let signer_seeds: Vec<&[u8]> = signer_seeds_vec.iter().map(AsRef::as_ref).collect();
         cpi::system::create_pda(
@@ -320,15 +320,15 @@ impl<'a> NautilusRecord<'a> for Create<'a, NautilusIndex<'a>> {
         self.self_account.discriminator()
     }
 
-    fn seeds(&self) -> Vec<Vec<u8>> {
+    fn seeds(&self) -> Result<Vec<Vec<u8>>, ProgramError> {
         self.self_account.seeds()
     }
 
-    fn pda(&self) -> (Pubkey, u8) {
+    fn pda(&self) -> Result<(Pubkey, u8), ProgramError> {
         self.self_account.pda()
     }
 
-    fn primary_key(&self) -> Vec<u8> {
+    fn primary_key(&self) -> Result<Vec<u8>, ProgramError> {
         self.self_account.primary_key()
     }
 
@@ -336,7 +336,7 @@ impl<'a> NautilusRecord<'a> for Create<'a, NautilusIndex<'a>> {
         self.self_account.check_authorities(accounts)
     }
 
-    fn count_authorities(&self) -> u8 {
+    fn count_authorities(&self) -> Result<u8, ProgramError> {
         self.self_account.count_authorities()
     }
 }
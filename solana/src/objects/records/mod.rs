@@ -5,8 +5,9 @@ use solana_program::{
 };
 
 use crate::{
-    cpi, error::NautilusError, Create, Mut, NautilusAccountInfo, NautilusIndex, NautilusMut,
-    NautilusRecord, NautilusRecordData, NautilusSigner, NautilusTransferLamports, Signer, Wallet,
+    cpi, data::discriminator, objects::lazy::check_account_data, Create, Lazy, Mut,
+    NautilusAccountInfo, NautilusIndex, NautilusMut, NautilusRecord, NautilusRecordData,
+    NautilusSigner, NautilusTransferLamports, Signer, Wallet,
 };
 
 pub mod index;
@@ -24,6 +25,9 @@ pub mod index;
 ///
 /// For more information on the `NautilusIndex<'_>` see the docs for that
 /// struct.
+///
+/// The record's `data` is deserialized when it's first accessed - see
+/// `Lazy<T>`.
 #[derive(Clone)]
 pub struct Record<'a, T>
 where
@@ -32,7 +36,7 @@ where
     pub program_id: &'a Pubkey,
     pub account_info: Box<AccountInfo<'a>>,
     pub index: NautilusIndex<'a>,
-    pub data: Box<T>,
+    pub data: Lazy<'a, T>,
 }
 
 impl<'a, T> Record<'a, T>
@@ -49,41 +53,34 @@ where
         Self {
             program_id,
             index,
+            data: Lazy::with_value(
+                account_info.clone(),
+                T::TABLE_NAME,
+                |data| T::try_from_slice(data).ok(),
+                Box::<T>::default(),
+            ),
             account_info,
-            data: Box::<T>::default(),
         }
     }
 
-    /// Instantiate a new record and load the account inner data from on-chain.
+    /// Instantiate a record, checking it's owned by the program and holds a
+    /// `T`. The account inner data is loaded from on-chain when it's first
+    /// accessed.
     pub fn load(
         program_id: &'a Pubkey,
         account_info: Box<AccountInfo<'a>>,
         index: NautilusIndex<'a>,
     ) -> Result<Self, ProgramError> {
-        let data = match T::try_from_slice(match &account_info.try_borrow_data() {
-            Ok(acct_data) => acct_data,
-            Err(_) => {
-                return Err(NautilusError::LoadDataFailed(
-                    T::TABLE_NAME.to_string(),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        }) {
-            Ok(state_data) => Box::new(state_data),
-            Err(_) => {
-                return Err(NautilusError::DeserializeDataFailed(
-                    T::TABLE_NAME.to_string(),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        };
+        check_account_data(&account_info, T::TABLE_NAME, &[*program_id], |data| {
+            data.starts_with(&discriminator(T::TABLE_NAME))
+        })?;
         Ok(Self {
             program_id,
             index,
+            data: Lazy::new(account_info.clone(), T::TABLE_NAME, |data| {
+                T::try_from_slice(data).ok()
+            }),
             account_info,
-            data,
         })
     }
 }
@@ -121,7 +118,7 @@ where
     }
 
     fn span(&self) -> Result<usize, ProgramError> {
-        Ok(self.data.try_to_vec()?.len())
+        Ok(Lazy::try_get(&self.data)?.try_to_vec()?.len())
     }
 }
 
@@ -130,27 +127,27 @@ where
     T: NautilusRecordData,
 {
     fn discriminator(&self) -> [u8; 8] {
-        self.data.discriminator()
+        discriminator(T::TABLE_NAME)
     }
 
-    fn seeds(&self) -> Vec<Vec<u8>> {
-        self.data.seeds()
+    fn seeds(&self) -> Result<Vec<Vec<u8>>, ProgramError> {
+        Ok(Lazy::try_get(&self.data)?.seeds())
     }
 
-    fn pda(&self) -> (Pubkey, u8) {
-        self.data.pda(self.program_id)
+    fn pda(&self) -> Result<(Pubkey, u8), ProgramError> {
+        Ok(Lazy::try_get(&self.data)?.pda(self.program_id))
     }
 
-    fn primary_key(&self) -> Vec<u8> {
-        self.data.primary_key()
+    fn primary_key(&self) -> Result<Vec<u8>, ProgramError> {
+        Ok(Lazy::try_get(&self.data)?.primary_key())
     }
 
     fn check_authorities(&self, accounts: Vec<AccountInfo>) -> Result<(), ProgramError> {
-        self.data.check_authorities(accounts)
+        Lazy::try_get(&self.data)?.check_authorities(accounts)
     }
 
-    fn count_authorities(&self) -> u8 {
-        self.data.count_authorities()
+    fn count_authorities(&self) -> Result<u8, ProgramError> {
+        Ok(Lazy::try_get(&self.data)?.count_authorities())
     }
 }
 
@@ -189,21 +186,21 @@ where
             account_info: self.fee_payer.to_owned(),
             system_program: self.system_program.to_owned(),
         })?;
-        let (pda, bump) = self.pda();
+        let (pda, bump) = self.pda()?;
         assert_eq!(
             &pda,
             self.key(),
             "Derived PDA does not match data for account {:#?}",
             self.key()
         );
-        let mut signer_seeds_vec = self.seeds();
+        let mut signer_seeds_vec = self.seeds()?;
         signer_seeds_vec.push(vec![bump]);
         let signer_seeds: Vec<&[u8]> = signer_seeds_vec.iter().map(AsRef::as_ref).collect();
         cpi::system::create_pda(
             self.self_account.clone(),
             self.self_account.program_id,
             payer,
-            self.self_account.data.clone(),
+            Box::new(Lazy::try_get(&self.self_account.data)?.clone()),
             signer_seeds,
         )
     }
@@ -211,21 +208,21 @@ where
     /// This function is the same as `create_record(&mut self, ..)` but allows
     /// you to specify a rent payer.
     pub fn create_record_with_payer(&mut self, payer: impl NautilusSigner<'a>) -> ProgramResult {
-        let (pda, bump) = self.pda();
+        let (pda, bump) = self.pda()?;
         assert_eq!(
             &pda,
             self.key(),
             "Derived PDA does not match data for account {:#?}",
             self.key()
         );
-        let mut signer_seeds_vec = self.seeds();
+        let mut signer_seeds_vec = self.seeds()?;
         signer_seeds_vec.push(vec![bump]);
         let signer_seeds: Vec<&[u8]> = signer_seeds_vec.iter().map(AsRef::as_ref).collect();
         cpi::system::create_pda(
             self.self_account.clone(),
             self.self_account.program_id,
             payer,
-            self.self_account.data.clone(),
+            Box::new(Lazy::try_get(&self.self_account.data)?.clone()),
             signer_seeds,
         )
     }
@@ -239,15 +236,15 @@ where
         self.self_account.discriminator()
     }
 
-    fn seeds(&self) -> Vec<Vec<u8>> {
+    fn seeds(&self) -> Result<Vec<Vec<u8>>, ProgramError> {
         self.self_account.seeds()
     }
 
-    fn pda(&self) -> (Pubkey, u8) {
+    fn pda(&self) -> Result<(Pubkey, u8), ProgramError> {
         self.self_account.pda()
     }
 
-    fn primary_key(&self) -> Vec<u8> {
+    fn primary_key(&self) -> Result<Vec<u8>, ProgramError> {
         self.self_account.primary_key()
     }
 
@@ -255,7 +252,7 @@ where
         self.self_account.check_authorities(accounts)
     }
 
-    fn count_authorities(&self) -> u8 {
+    fn count_authorities(&self) -> Result<u8, ProgramError> {
         self.self_account.count_authorities()
     }
 }
@@ -7,8 +7,12 @@ use solana_program::{
 pub use spl_token::state::Account as AssociatedTokenAccountState;
 
 use crate::{
-    cpi, error::NautilusError, Create, Mint, Mut, NautilusAccountInfo, NautilusMut, NautilusSigner,
-    Signer, Wallet,
+    cpi,
+    objects::{
+        lazy::check_account_data,
+        tokens::{is_token_state, unpack_token_state, TOKEN_PROGRAM_IDS},
+    },
+    Create, Lazy, Mint, Mut, NautilusAccountInfo, NautilusMut, NautilusSigner, Signer, Wallet,
 };
 
 /// The Nautilus object representing an associated token account.
@@ -18,12 +22,15 @@ use crate::{
 ///
 /// We also include the read-only Token Program and Associated Token Program for
 /// any CPI operations necessary, since we do not own this account.
+///
+/// The token account's `data` is unpacked when it's first accessed - see
+/// `Lazy<T>`.
 #[derive(Clone)]
 pub struct AssociatedTokenAccount<'a> {
     pub account_info: Box<AccountInfo<'a>>,
     pub token_program: Box<AccountInfo<'a>>,
     pub associated_token_program: Box<AccountInfo<'a>>,
-    pub data: AssociatedTokenAccountState,
+    pub data: Lazy<'a, AssociatedTokenAccountState>,
 }
 
 impl<'a> AssociatedTokenAccount<'a> {
@@ -35,45 +42,42 @@ impl<'a> AssociatedTokenAccount<'a> {
         associated_token_program: Box<AccountInfo<'a>>,
     ) -> Self {
         Self {
+            data: Lazy::with_value(
+                account_info.clone(),
+                "associated_token_account",
+                unpack_token_state::<AssociatedTokenAccountState>,
+                Box::default(),
+            ),
             account_info,
             token_program,
             associated_token_program,
-            data: AssociatedTokenAccountState::default(),
         }
     }
 
-    /// Instantiate a new `AssociatedTokenAccount` and load the account inner
-    /// data from on-chain.
+    /// Instantiate an `AssociatedTokenAccount`, checking it's owned by the
+    /// Token Program or Token-2022 and holds a token account. The account
+    /// inner data is loaded from on-chain when it's first accessed.
     pub fn load(
         account_info: Box<AccountInfo<'a>>,
         token_program: Box<AccountInfo<'a>>,
         associated_token_program: Box<AccountInfo<'a>>,
     ) -> Result<Self, ProgramError> {
-        let data =
-            match AssociatedTokenAccountState::unpack(match &account_info.try_borrow_data() {
-                Ok(acct_data) => acct_data,
-                Err(_) => {
-                    return Err(NautilusError::LoadDataFailed(
-                        String::from("associated_token_account"),
-                        account_info.key.to_string(),
-                    )
-                    .into())
-                }
-            }) {
-                Ok(state_data) => state_data,
-                Err(_) => {
-                    return Err(NautilusError::DeserializeDataFailed(
-                        String::from("associated_token_account"),
-                        account_info.key.to_string(),
-                    )
-                    .into())
-                }
-            };
+        let owner = account_info.owner;
+        check_account_data(
+            &account_info,
+            "associated_token_account",
+            &TOKEN_PROGRAM_IDS,
+            |data| is_token_state::<spl_token_2022::state::Account>(owner, data),
+        )?;
         Ok(Self {
+            data: Lazy::new(
+                account_info.clone(),
+                "associated_token_account",
+                unpack_token_state::<AssociatedTokenAccountState>,
+            ),
             account_info,
             token_program,
             associated_token_program,
-            data,
         })
     }
 }
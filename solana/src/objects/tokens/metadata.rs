@@ -1,4 +1,5 @@
 //! The `Metadata<T>` Nautilus object and all associated trait implementations.
+use mpl_token_metadata::state::Key;
 pub use mpl_token_metadata::state::{Metadata as MetadataState, TokenMetadataAccount};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
@@ -6,7 +7,8 @@ use solana_program::{
 };
 
 use crate::{
-    cpi, error::NautilusError, Create, Mint, NautilusAccountInfo, NautilusSigner, Signer, Wallet,
+    cpi, objects::lazy::check_account_data, Create, Lazy, Mint, NautilusAccountInfo,
+    NautilusSigner, Signer, Wallet,
 };
 
 /// The Nautilus object representing a token metadata account.
@@ -16,11 +18,14 @@ use crate::{
 ///
 /// We also include the read-only Token Metadata Program for any CPI operations
 /// necessary, since we do not own this account.
+///
+/// The metadata's `data` is deserialized when it's first accessed - see
+/// `Lazy<T>`.
 #[derive(Clone)]
 pub struct Metadata<'a> {
     pub account_info: Box<AccountInfo<'a>>,
     pub token_metadata_program: Box<AccountInfo<'a>>,
-    pub data: MetadataState,
+    pub data: Lazy<'a, MetadataState>,
 }
 
 impl<'a> Metadata<'a> {
@@ -31,41 +36,36 @@ impl<'a> Metadata<'a> {
         token_metadata_program: Box<AccountInfo<'a>>,
     ) -> Self {
         Self {
+            data: Lazy::with_value(
+                account_info.clone(),
+                "token_metadata",
+                |data| MetadataState::safe_deserialize(data).ok(),
+                Box::default(),
+            ),
             account_info,
             token_metadata_program,
-            data: MetadataState::default(),
         }
     }
 
-    /// Instantiate a new `Metadata` and load the account inner data from
-    /// on-chain.
+    /// Instantiate a `Metadata`, checking it's owned by the Token Metadata
+    /// Program and its key marks it as metadata. The account inner data is
+    /// loaded from on-chain when it's first accessed.
     pub fn load(
         account_info: Box<AccountInfo<'a>>,
         token_metadata_program: Box<AccountInfo<'a>>,
     ) -> Result<Self, ProgramError> {
-        let data = match MetadataState::safe_deserialize(match &account_info.try_borrow_data() {
-            Ok(acct_data) => acct_data,
-            Err(_) => {
-                return Err(NautilusError::LoadDataFailed(
-                    String::from("token_metadata"),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        }) {
-            Ok(state_data) => state_data,
-            Err(_) => {
-                return Err(NautilusError::DeserializeDataFailed(
-                    String::from("token_metadata"),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        };
+        check_account_data(
+            &account_info,
+            "token_metadata",
+            &[mpl_token_metadata::ID],
+            |data| data.first() == Some(&(Key::MetadataV1 as u8)),
+        )?;
         Ok(Self {
+            data: Lazy::new(account_info.clone(), "token_metadata", |data| {
+                MetadataState::safe_deserialize(data).ok()
+            }),
             account_info,
             token_metadata_program,
-            data,
         })
     }
 }
@@ -7,8 +7,12 @@ use spl_token::instruction::AuthorityType;
 pub use spl_token::state::Mint as MintState;
 
 use crate::{
-    cpi, error::NautilusError, Create, Mut, NautilusAccountInfo, NautilusMut, NautilusSigner,
-    Signer, Wallet,
+    cpi,
+    objects::{
+        lazy::check_account_data,
+        tokens::{is_token_state, unpack_token_state, TOKEN_PROGRAM_IDS},
+    },
+    Create, Lazy, Mut, NautilusAccountInfo, NautilusMut, NautilusSigner, Signer, Wallet,
 };
 
 /// The Nautilus object representing a mint account.
@@ -18,11 +22,13 @@ use crate::{
 ///
 /// We also include the read-only Token Program for any CPI operations
 /// necessary, since we do not own this account.
+///
+/// The mint's `data` is unpacked when it's first accessed - see `Lazy<T>`.
 #[derive(Clone)]
 pub struct Mint<'a> {
     pub account_info: Box<AccountInfo<'a>>,
     pub token_program: Box<AccountInfo<'a>>,
-    pub data: MintState,
+    pub data: Lazy<'a, MintState>,
 }
 
 impl<'a> Mint<'a> {
@@ -32,40 +38,36 @@ impl<'a> Mint<'a> {
     /// on-chain.
     pub fn new(account_info: Box<AccountInfo<'a>>, token_program: Box<AccountInfo<'a>>) -> Self {
         Self {
+            data: Lazy::with_value(
+                account_info.clone(),
+                "token_mint",
+                unpack_token_state::<MintState>,
+                Box::default(),
+            ),
             account_info,
             token_program,
-            data: MintState::default(),
         }
     }
 
-    /// Instantiate a new `Mint` and load the account inner data from on-chain.
+    /// Instantiate a `Mint`, checking it's owned by the Token Program or
+    /// Token-2022 and holds a mint. The account inner data is loaded from
+    /// on-chain when it's first accessed.
     pub fn load(
         account_info: Box<AccountInfo<'a>>,
         token_program: Box<AccountInfo<'a>>,
     ) -> Result<Self, ProgramError> {
-        let data = match MintState::unpack(match &account_info.try_borrow_data() {
-            Ok(acct_data) => acct_data,
-            Err(_) => {
-                return Err(NautilusError::LoadDataFailed(
-                    String::from("token_mint"),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        }) {
-            Ok(state_data) => state_data,
-            Err(_) => {
-                return Err(NautilusError::DeserializeDataFailed(
-                    String::from("token_mint"),
-                    account_info.key.to_string(),
-                )
-                .into())
-            }
-        };
+        let owner = account_info.owner;
+        check_account_data(&account_info, "token_mint", &TOKEN_PROGRAM_IDS, |data| {
+            is_token_state::<spl_token_2022::state::Mint>(owner, data)
+        })?;
         Ok(Self {
+            data: Lazy::new(
+                account_info.clone(),
+                "token_mint",
+                unpack_token_state::<MintState>,
+            ),
             account_info,
             token_program,
-            data,
         })
     }
 
@@ -6,3 +6,34 @@ pub mod metadata;
 pub mod mint;
 pub mod nft;
 pub mod token;
+
+use solana_program::{
+    program_pack::{IsInitialized, Pack},
+    pubkey::Pubkey,
+};
+use spl_token_2022::{
+    extension::BaseState,
+    state::{Account, Multisig},
+};
+
+/// The programs whose mints and token accounts Nautilus objects can load: the
+/// Token Program and Token-2022, which shares its account layouts.
+pub(crate) const TOKEN_PROGRAM_IDS: [Pubkey; 2] = [spl_token::ID, spl_token_2022::ID];
+
+/// Checks, without unpacking it, that an account owned by `owner` holds `S`
+/// state: exactly `S` or - for Token-2022 - `S` followed by its extensions,
+/// which are marked with `S`'s account type right after a token account's
+/// length.
+pub(crate) fn is_token_state<S: BaseState>(owner: &Pubkey, data: &[u8]) -> bool {
+    data.len() == S::LEN
+        || (spl_token_2022::check_id(owner)
+            && data.len() > Account::LEN
+            && data.len() != Multisig::LEN
+            && data[Account::LEN] == S::ACCOUNT_TYPE as u8)
+}
+
+/// Unpacks the `S` state an account starts with, ignoring any Token-2022
+/// extensions after it.
+pub(crate) fn unpack_token_state<S: Pack + IsInitialized>(data: &[u8]) -> Option<S> {
+    S::unpack(data.get(..S::LEN)?).ok()
+}
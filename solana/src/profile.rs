@@ -0,0 +1,59 @@
+//! Compute-unit profiling, enabled with the `profile-compute-units` feature.
+//!
+//! A program built with the feature logs the compute units it has left as each
+//! phase of an instruction begins and ends:
+//!
+//! ```text
+//! Program log: CU begin: Record::load person
+//! Program consumption: 196850 units remaining
+//! Program consumption: 195112 units remaining
+//! Program log: CU end: Record::load person
+//! ```
+//!
+//! The `#[nautilus]` processor profiles loading its accounts, initializing each
+//! object and calling the instruction's function, and each helper in
+//! `nautilus::cpi` profiles itself. `nautilus_idl::profile` turns the logs into
+//! a per-phase breakdown.
+//!
+//! Without the feature, phases compile to nothing.
+#[cfg(feature = "profile-compute-units")]
+use solana_program::{log::sol_log_compute_units, msg};
+
+/// A phase being profiled, which ends when it's dropped - so phases cut short
+/// by an early return still end.
+#[must_use]
+pub struct Phase {
+    #[cfg(feature = "profile-compute-units")]
+    name: &'static str,
+}
+
+impl Phase {
+    /// Logs the phase beginning, then the compute units remaining - so logging
+    /// the name isn't counted against the phase.
+    #[inline(always)]
+    #[cfg_attr(not(feature = "profile-compute-units"), allow(unused_variables))]
+    pub fn begin(name: &'static str) -> Self {
+        #[cfg(feature = "profile-compute-units")]
+        {
+            msg!("CU begin: {}", name);
+            sol_log_compute_units();
+        }
+        Self {
+            #[cfg(feature = "profile-compute-units")]
+            name,
+        }
+    }
+
+    /// Ends the phase.
+    #[inline(always)]
+    pub fn end(self) {}
+}
+
+#[cfg(feature = "profile-compute-units")]
+impl Drop for Phase {
+    /// Logs the compute units remaining, then the phase ending.
+    fn drop(&mut self) {
+        sol_log_compute_units();
+        msg!("CU end: {}", self.name);
+    }
+}
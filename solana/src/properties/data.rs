@@ -26,6 +26,36 @@ pub trait NautilusAccountData: BorshDeserialize + BorshSerialize + Clone + Defau
     fn count_authorities(&self) -> u8;
 }
 
+/// The trait that represents account data for zero-copy Nautilus accounts,
+/// derived with `#[derive(nautilus::State)]` and `#[zero_copy]`.
+///
+/// The data is read and written in place through `bytemuck`, so the struct
+/// must be `#[repr(C)]` with no padding - which also makes its in-memory
+/// layout the same as its Borsh layout, so the IDL describes it as-is.
+///
+/// Unlike `NautilusAccountData`, the discriminator is stored as the first 8
+/// bytes of the account and checked whenever the account is loaded.
+pub trait NautilusZeroCopyData: bytemuck::Pod {
+    const DISCRIMINATOR_STR: &'static str;
+
+    /// The 8-bit discriminator prefixed to the account's data, computed when
+    /// the type is derived.
+    const DISCRIMINATOR: [u8; 8];
+}
+
+/// Names one instantiation of a generic `Table` or `State` type, ie.
+/// `Vault<u64>`.
+///
+/// The name - the type's name in lowercase followed by its type arguments, ie.
+/// `vault<u64>` - is used as the instantiation's table name or discriminator
+/// string, so `Vault<u8>` and `Vault<u64>` can't be loaded as one another.
+///
+/// The `#[nautilus]` macro implements this for every instantiation used in the
+/// program's instructions.
+pub trait NautilusDataInstance {
+    const NAME: &'static str;
+}
+
 /// The trait that represents account data for record-based, SQL-friendly
 /// Nautilus accounts.
 ///
@@ -89,7 +119,7 @@ pub trait NautilusAccount<'a>: NautilusAccountInfo<'a> {
     fn check_authorities(&self, accounts: Vec<AccountInfo>) -> Result<(), ProgramError>;
 
     /// Counts the data's declared authorities.
-    fn count_authorities(&self) -> u8;
+    fn count_authorities(&self) -> Result<u8, ProgramError>;
 }
 
 /// This trait provides methods only accessible to Nautilus Tables.
@@ -105,23 +135,23 @@ pub trait NautilusRecord<'a>: NautilusAccountInfo<'a> {
     fn discriminator(&self) -> [u8; 8];
 
     /// The seeds used to derive the program-derived address of this account.
-    fn seeds(&self) -> Vec<Vec<u8>>;
+    fn seeds(&self) -> Result<Vec<Vec<u8>>, ProgramError>;
 
     /// Returns the program-derived address and bump for an account.
-    fn pda(&self) -> (Pubkey, u8);
+    fn pda(&self) -> Result<(Pubkey, u8), ProgramError>;
 
     /// Returns the primary key of a record.
-    fn primary_key(&self) -> Vec<u8>;
+    fn primary_key(&self) -> Result<Vec<u8>, ProgramError>;
 
     /// Checks authorities against the data's declared authorities.
     fn check_authorities(&self, accounts: Vec<AccountInfo>) -> Result<(), ProgramError>;
 
     /// Counts the data's declared authorities.
-    fn count_authorities(&self) -> u8;
+    fn count_authorities(&self) -> Result<u8, ProgramError>;
 }
 
 /// Helper function to return the 8-bit discriminator of an account data type.
-fn discriminator(discrim_str: &str) -> [u8; 8] {
+pub(crate) fn discriminator(discrim_str: &str) -> [u8; 8] {
     let mut discriminator = [0u8; 8];
     let preimage = format!("{}:{}", "global", discrim_str);
     discriminator.copy_from_slice(&solana_program::hash::hash(preimage.as_bytes()).to_bytes()[..8]); // First 8 bytes
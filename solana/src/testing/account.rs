@@ -0,0 +1,99 @@
+//! The accounts held by a `TestHarness`.
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo, bpf_loader_upgradeable, pubkey::Pubkey, rent::Rent, system_program,
+    sysvar,
+};
+
+/// The loader owning the System Program.
+pub const NATIVE_LOADER_ID: Pubkey =
+    solana_program::pubkey!("NativeLoader1111111111111111111111111111111");
+
+/// An account in a `TestHarness`'s account store.
+///
+/// Any address the store doesn't hold is an empty, System Program-owned account
+/// with no lamports - the same as an address that was never used on-chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+    pub rent_epoch: u64,
+}
+
+impl Default for TestAccount {
+    fn default() -> Self {
+        Self {
+            lamports: 0,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+}
+
+impl TestAccount {
+    pub fn new(lamports: u64, data: Vec<u8>, owner: Pubkey) -> Self {
+        Self {
+            lamports,
+            data,
+            owner,
+            ..Default::default()
+        }
+    }
+
+    /// A system account (a `Wallet`) holding `lamports`.
+    pub fn wallet(lamports: u64) -> Self {
+        Self::new(lamports, vec![], system_program::ID)
+    }
+
+    /// An account holding `data`, funded to be rent-exempt.
+    pub fn rent_exempt(data: Vec<u8>, owner: Pubkey) -> Self {
+        Self::new(Rent::default().minimum_balance(data.len()), data, owner)
+    }
+
+    /// A deployed program's account.
+    pub fn program() -> Self {
+        Self {
+            executable: true,
+            ..Self::new(1, vec![], bpf_loader_upgradeable::ID)
+        }
+    }
+
+    /// A native program's account, ie. the System Program's.
+    pub fn native_program() -> Self {
+        Self {
+            executable: true,
+            ..Self::new(1, vec![], NATIVE_LOADER_ID)
+        }
+    }
+
+    /// A sysvar's account, holding its serialized value.
+    pub fn sysvar<S: sysvar::Sysvar>(value: &S) -> Self {
+        let mut account = Self::new(1, vec![0; S::size_of()], sysvar::ID);
+        let key = Pubkey::default();
+        let mut info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut account.lamports,
+            &mut account.data,
+            &account.owner,
+            false,
+            0,
+        );
+        value
+            .to_account_info(&mut info)
+            .expect("A sysvar's account fits its value");
+        account
+    }
+
+    /// Deserializes the account's data - ie. a Nautilus record or state
+    /// account, whose Borsh implementation reads its discriminator first.
+    /// Any unused space after the data is ignored.
+    pub fn deserialize<T: BorshDeserialize>(&self) -> std::io::Result<T> {
+        T::deserialize(&mut &self.data[..])
+    }
+}
@@ -0,0 +1,296 @@
+//! Submodule for testing Nautilus programs natively, in the test process,
+//! without deploying them to a validator.
+//!
+//! A `TestHarness` runs a program's generated `process_instruction` against an
+//! in-memory account store, emulating the parts of the Solana runtime a
+//! Nautilus program relies on:
+//! * Signer and writable flags, taken from the instruction's `AccountMeta`s.
+//! * The runtime's checks on how a program may change accounts.
+//! * Cross-program invocations to the System Program, and to any other program
+//!   loaded into the harness or stood in for by a `CpiHook` - such as native
+//!   builds of the SPL Token, Token-2022, Associated Token Account and Token
+//!   Metadata programs, loaded with `TestHarness::add_spl_programs`.
+//! * The Rent and Clock sysvars, and program logs.
+//!
+//! ```ignore
+//! use nautilus::testing::TestHarness;
+//!
+//! let program_id = Pubkey::new_unique();
+//! let mut harness = TestHarness::new(program_id, program_nautilus::process_instruction);
+//! let payer = harness.add_wallet(1_000_000_000);
+//!
+//! let instruction = harness.instruction(NautilusEntrypoint::Transfer(100), accounts);
+//! let processed = harness.process(&instruction).unwrap();
+//! assert!(processed.messages().contains(&"Transferring 100 ..."));
+//! assert_eq!(harness.account(&payer).unwrap().lamports, 999_999_900);
+//! ```
+use std::collections::HashMap;
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program, sysvar,
+};
+use thiserror::Error;
+
+pub use self::account::*;
+
+pub mod account;
+mod runtime;
+pub mod spl;
+mod system;
+
+/// A program's processor, ie. the `process_instruction` function the
+/// `#[nautilus]` macro generates.
+pub type ProcessInstruction = fn(&'static Pubkey, &[AccountInfo], &[u8]) -> ProgramResult;
+
+/// Stands in for programs that aren't loaded into a `TestHarness`, ie. to mock
+/// an on-chain program the program under test invokes.
+///
+/// A hook is given every instruction - top-level, or invoked - for a program
+/// the harness doesn't know, and either processes it against its accounts,
+/// returning `Some` result, or returns `None` to leave it to the next hook.
+pub type CpiHook = fn(&Instruction, &[AccountInfo]) -> Option<ProgramResult>;
+
+#[derive(Clone, Copy)]
+struct LoadedProgram {
+    id: &'static Pubkey,
+    process_instruction: ProcessInstruction,
+}
+
+/// Why a `TestHarness` failed to process an instruction.
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum TestError {
+    /// The instruction - or a cross-program invocation - called a program
+    /// that isn't loaded into the harness.
+    #[error("program {0} is not loaded into the test harness")]
+    ProgramNotFound(Pubkey),
+    /// The program returned an error.
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+    /// The program panicked, with the given message.
+    #[error("program panicked: {0}")]
+    Panicked(String),
+    /// The program broke one of the runtime's rules, ie. by modifying an
+    /// account it doesn't own.
+    #[error("{0}")]
+    Runtime(String),
+}
+
+/// The logs a program writes with `msg!`, without the runtime's
+/// `Program log: ` prefix.
+fn messages(logs: &[String]) -> Vec<&str> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program log: "))
+        .collect()
+}
+
+/// The result of a transaction that succeeded.
+#[derive(Clone, Debug)]
+pub struct Processed {
+    /// The transaction's logs, as the runtime writes them.
+    pub logs: Vec<String>,
+    /// The data the last instruction returned, and the program that set it.
+    pub return_data: Option<(Pubkey, Vec<u8>)>,
+}
+
+impl Processed {
+    pub fn messages(&self) -> Vec<&str> {
+        messages(&self.logs)
+    }
+}
+
+/// The result of a transaction that failed. No account is changed.
+#[derive(Clone, Debug)]
+pub struct Failed {
+    /// The index of the instruction that failed.
+    pub instruction: usize,
+    pub error: TestError,
+    /// The transaction's logs, as the runtime writes them, up to the failure.
+    pub logs: Vec<String>,
+}
+
+impl Failed {
+    pub fn messages(&self) -> Vec<&str> {
+        messages(&self.logs)
+    }
+}
+
+impl std::fmt::Display for Failed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Instruction {} failed: {}", self.instruction, self.error)?;
+        for log in self.logs.iter() {
+            writeln!(f, "    {}", log)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Failed {}
+
+/// Runs Nautilus programs in the test process against an in-memory account
+/// store.
+///
+/// Cloning a harness forks its account store, ie. to run instructions from the
+/// same starting state over and over.
+#[derive(Clone)]
+pub struct TestHarness {
+    program_id: Pubkey,
+    programs: HashMap<Pubkey, LoadedProgram>,
+    hooks: Vec<CpiHook>,
+    accounts: HashMap<Pubkey, TestAccount>,
+    rent: Rent,
+    clock: Clock,
+}
+
+impl TestHarness {
+    /// Creates a harness with `process_instruction` loaded as `program_id`,
+    /// alongside the System Program and the Rent and Clock sysvars.
+    pub fn new(program_id: Pubkey, process_instruction: ProcessInstruction) -> Self {
+        runtime::install_syscall_stubs();
+        let mut harness = Self {
+            program_id,
+            programs: HashMap::new(),
+            hooks: vec![],
+            accounts: HashMap::new(),
+            rent: Rent::default(),
+            clock: Clock::default(),
+        };
+        harness.add_program(program_id, process_instruction);
+        harness.add_account(system_program::ID, TestAccount::native_program());
+        harness.add_account(sysvar::rent::ID, TestAccount::sysvar(&harness.rent));
+        harness.set_clock(Clock::default());
+        harness
+    }
+
+    /// The ID of the program under test - the one the harness was created
+    /// with.
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// Builds an instruction for the program under test, ie. from one of the
+    /// `NautilusEntrypoint` variants the `#[nautilus]` macro generates.
+    pub fn instruction(
+        &self,
+        entry: impl BorshSerialize,
+        accounts: Vec<AccountMeta>,
+    ) -> Instruction {
+        let data = entry
+            .try_to_vec()
+            .expect("Failed to serialize the instruction's data");
+        Instruction::new_with_bytes(self.program_id, &data, accounts)
+    }
+
+    /// Adds a new wallet holding `lamports`, ie. to pay for the accounts a test
+    /// creates, returning its address.
+    pub fn add_wallet(&mut self, lamports: u64) -> Pubkey {
+        let wallet = Pubkey::new_unique();
+        self.add_account(wallet, TestAccount::wallet(lamports));
+        wallet
+    }
+
+    /// Loads another program, ie. one the program under test invokes.
+    pub fn add_program(&mut self, program_id: Pubkey, process_instruction: ProcessInstruction) {
+        // Processors take the program's ID for the life of the program.
+        let id: &'static Pubkey = Box::leak(Box::new(program_id));
+        self.programs.insert(
+            program_id,
+            LoadedProgram {
+                id,
+                process_instruction,
+            },
+        );
+        self.add_account(program_id, TestAccount::program());
+    }
+
+    /// Adds a hook to stand in for programs the harness hasn't loaded. Hooks
+    /// are tried in the order they were added.
+    pub fn add_cpi_hook(&mut self, hook: CpiHook) {
+        self.hooks.push(hook);
+    }
+
+    /// Stands in for the SPL Token, Token-2022, Associated Token Account and
+    /// Token Metadata programs with native builds of them.
+    pub fn add_spl_programs(&mut self) {
+        self.add_cpi_hook(spl::process);
+        for program_id in spl::PROGRAM_IDS {
+            self.add_account(program_id, TestAccount::program());
+        }
+    }
+
+    pub fn add_account(&mut self, key: Pubkey, account: TestAccount) {
+        self.accounts.insert(key, account);
+    }
+
+    /// The account at `key`, or `None` if it doesn't exist - ie. it has no
+    /// lamports.
+    pub fn account(&self, key: &Pubkey) -> Option<&TestAccount> {
+        self.accounts.get(key)
+    }
+
+    /// Every account in the store, in no particular order.
+    pub fn accounts(&self) -> impl Iterator<Item = (&Pubkey, &TestAccount)> {
+        self.accounts.iter()
+    }
+
+    pub fn rent(&self) -> &Rent {
+        &self.rent
+    }
+
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Sets the clock programs see, ie. to advance time.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.add_account(sysvar::clock::ID, TestAccount::sysvar(&clock));
+        self.clock = clock;
+    }
+
+    /// Processes a single instruction as its own transaction.
+    pub fn process(&mut self, instruction: &Instruction) -> Result<Processed, Failed> {
+        self.process_transaction(std::slice::from_ref(instruction))
+    }
+
+    /// Processes instructions in order, as one transaction: if any fails,
+    /// none of their changes are kept.
+    ///
+    /// Signatures aren't checked - an account signs an instruction if the
+    /// instruction's `AccountMeta` for it says so.
+    pub fn process_transaction(
+        &mut self,
+        instructions: &[Instruction],
+    ) -> Result<Processed, Failed> {
+        let mut accounts = self.accounts.clone();
+        let mut logs = vec![];
+        let mut return_data = None;
+        for (i, instruction) in instructions.iter().enumerate() {
+            let outcome = runtime::process_instruction(
+                &self.programs,
+                &self.hooks,
+                self.rent,
+                self.clock.clone(),
+                instruction,
+                &mut accounts,
+            );
+            logs.extend(outcome.logs);
+            if let Err(error) = outcome.result {
+                return Err(Failed {
+                    instruction: i,
+                    error,
+                    logs,
+                });
+            }
+            return_data = outcome.return_data;
+        }
+        self.accounts = accounts;
+        Ok(Processed { logs, return_data })
+    }
+}
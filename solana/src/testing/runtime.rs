@@ -0,0 +1,513 @@
+//! The in-process runtime behind a `TestHarness`.
+//!
+//! Instructions run against the same serialized input the on-chain loader
+//! builds, so `AccountInfo::realloc` and duplicate accounts behave as they do
+//! on-chain. Syscalls - logging, sysvars and cross-program invocations - are
+//! routed to the instruction being processed on the current thread, so tests
+//! can run in parallel.
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Once,
+};
+
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{
+        deserialize, ProgramResult, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER, SUCCESS,
+    },
+    instruction::Instruction,
+    program_error::ProgramError,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+};
+
+use super::{system, CpiHook, LoadedProgram, TestAccount, TestError};
+
+/// The state of an account at the last point the runtime checked it: the
+/// start of the instruction, or the end of a cross-program invocation.
+struct AccountState {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+/// The instruction being processed on this thread.
+struct Invocation {
+    programs: HashMap<Pubkey, LoadedProgram>,
+    hooks: Vec<CpiHook>,
+    /// The executable accounts the instruction lists, which invocations may
+    /// reference without passing along, as on-chain.
+    executables: HashMap<Pubkey, TestAccount>,
+    rent: Rent,
+    clock: Clock,
+    logs: Vec<String>,
+    /// The programs currently executing, innermost last.
+    stack: Vec<Pubkey>,
+    return_data: Option<(Pubkey, Vec<u8>)>,
+    snapshot: HashMap<Pubkey, AccountState>,
+    /// The error a failed cross-program invocation aborted the instruction
+    /// with. Callers can't recover from these, as on-chain.
+    failure: Option<TestError>,
+}
+
+thread_local! {
+    static INVOCATION: RefCell<Option<Invocation>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on the instruction being processed on this thread, if any.
+fn with_invocation<R>(f: impl FnOnce(&mut Invocation) -> R) -> Option<R> {
+    INVOCATION.with(|invocation| invocation.borrow_mut().as_mut().map(f))
+}
+
+fn log(message: String) {
+    with_invocation(|invocation| invocation.logs.push(message));
+}
+
+/// The result of processing one instruction.
+pub(super) struct Outcome {
+    pub result: Result<(), TestError>,
+    pub logs: Vec<String>,
+    pub return_data: Option<(Pubkey, Vec<u8>)>,
+}
+
+static INSTALL_STUBS: Once = Once::new();
+
+/// Routes syscalls made outside of an on-chain runtime to the `TestHarness`.
+/// Outside of an instruction, they behave like `solana_program`'s defaults.
+pub(super) fn install_syscall_stubs() {
+    INSTALL_STUBS.call_once(|| {
+        set_syscall_stubs(Box::new(TestSyscallStubs));
+    });
+}
+
+struct TestSyscallStubs;
+
+impl SyscallStubs for TestSyscallStubs {
+    fn sol_log(&self, message: &str) {
+        if with_invocation(|invocation| invocation.logs.push(format!("Program log: {}", message)))
+            .is_none()
+        {
+            println!("{}", message);
+        }
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = with_invocation(|invocation| invocation.clock.clone()).unwrap_or_default();
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let rent = with_invocation(|invocation| invocation.rent).unwrap_or_default();
+        unsafe { *(var_addr as *mut Rent) = rent };
+        SUCCESS
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        with_invocation(|invocation| invocation.return_data.clone()).flatten()
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        with_invocation(|invocation| {
+            let program_id = *invocation.stack.last().expect("A program is executing");
+            invocation.return_data = match data.is_empty() {
+                true => None,
+                false => Some((program_id, data.to_vec())),
+            };
+        });
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        with_invocation(|invocation| invocation.stack.len() as u64).unwrap_or_default()
+    }
+}
+
+/// Processes a top-level instruction against `accounts`, which are only
+/// updated if it succeeds.
+pub(super) fn process_instruction(
+    programs: &HashMap<Pubkey, LoadedProgram>,
+    hooks: &[CpiHook],
+    rent: Rent,
+    clock: Clock,
+    instruction: &Instruction,
+    accounts: &mut HashMap<Pubkey, TestAccount>,
+) -> Outcome {
+    let program_id = instruction.program_id;
+    let executables = instruction
+        .accounts
+        .iter()
+        .filter_map(|meta| accounts.get(&meta.pubkey).map(|a| (meta.pubkey, a)))
+        .filter(|(_, account)| account.executable)
+        .map(|(key, account)| (key, account.clone()))
+        .collect();
+    let mut input = serialize_input(instruction, accounts);
+    let (_, account_infos, _) = unsafe { deserialize(input.as_mut_ptr() as *mut u8) };
+    let lamports_before = total_lamports(&account_infos);
+    INVOCATION.with(|invocation| {
+        *invocation.borrow_mut() = Some(Invocation {
+            programs: programs.clone(),
+            hooks: hooks.to_vec(),
+            executables,
+            rent,
+            clock,
+            logs: vec![format!("Program {} invoke [1]", program_id)],
+            stack: vec![program_id],
+            return_data: None,
+            snapshot: snapshot(&account_infos),
+            failure: None,
+        })
+    });
+
+    let result = catch_unwind(AssertUnwindSafe(|| dispatch(instruction, &account_infos)));
+    let mut invocation = INVOCATION
+        .with(|invocation| invocation.borrow_mut().take())
+        .expect("The instruction is still being processed");
+
+    let result = match (invocation.failure.take(), result) {
+        (Some(failure), _) => Err(failure),
+        (None, Ok(result)) => result,
+        (None, Err(panic)) => Err(TestError::Panicked(panic_message(panic))),
+    }
+    .and_then(|()| verify(&program_id, &account_infos, &invocation.snapshot))
+    .and_then(
+        |()| match total_lamports(&account_infos) == lamports_before {
+            true => Ok(()),
+            false => Err(TestError::Runtime(
+                "the sum of the accounts' lamports changed".to_string(),
+            )),
+        },
+    );
+
+    match &result {
+        Ok(()) => {
+            invocation
+                .logs
+                .push(format!("Program {} success", program_id));
+            for info in unique(&account_infos) {
+                // Accounts left without lamports are purged, as on-chain.
+                match info.lamports() {
+                    0 => accounts.remove(info.key),
+                    lamports => accounts.insert(
+                        *info.key,
+                        TestAccount {
+                            lamports,
+                            data: info.data.borrow().to_vec(),
+                            owner: *info.owner,
+                            executable: info.executable,
+                            rent_epoch: info.rent_epoch,
+                        },
+                    ),
+                };
+            }
+        }
+        Err(error) => invocation
+            .logs
+            .push(format!("Program {} failed: {}", program_id, error)),
+    }
+    Outcome {
+        result,
+        logs: invocation.logs,
+        return_data: invocation.return_data,
+    }
+}
+
+/// Runs the program `instruction` calls: a program loaded into the harness,
+/// the emulated System Program, or the first CPI hook that stands in for it.
+fn dispatch(instruction: &Instruction, account_infos: &[AccountInfo]) -> Result<(), TestError> {
+    let (program, hooks) = with_invocation(|invocation| {
+        (
+            invocation.programs.get(&instruction.program_id).copied(),
+            invocation.hooks.clone(),
+        )
+    })
+    .expect("Programs run while processing an instruction");
+    let result = if let Some(program) = program {
+        (program.process_instruction)(program.id, account_infos, &instruction.data)
+    } else if system_program::check_id(&instruction.program_id) {
+        system::process(instruction, account_infos)
+    } else {
+        hooks
+            .iter()
+            .find_map(|hook| hook(instruction, account_infos))
+            .ok_or(TestError::ProgramNotFound(instruction.program_id))?
+    };
+    result.map_err(TestError::Program)
+}
+
+/// Emulates a cross-program invocation: checks the caller only escalates
+/// privileges of addresses it signs for, then runs the callee against the
+/// caller's accounts.
+fn invoke(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let (caller, depth) = with_invocation(|invocation| {
+        (
+            *invocation.stack.last().expect("A program is executing"),
+            invocation.stack.len() + 1,
+        )
+    })
+    .expect("Cross-program invocations are made while processing an instruction");
+    log(format!(
+        "Program {} invoke [{}]",
+        instruction.program_id, depth
+    ));
+
+    let result = callee_accounts(&caller, instruction, account_infos, signers_seeds).and_then(
+        |callee_infos| {
+            verify_with_snapshot(&caller, account_infos)?;
+            with_invocation(|invocation| invocation.stack.push(instruction.program_id));
+            let result = dispatch(instruction, &callee_infos);
+            with_invocation(|invocation| invocation.stack.pop());
+            result?;
+            verify_with_snapshot(&instruction.program_id, &callee_infos)
+        },
+    );
+
+    match result {
+        Ok(()) => {
+            let states = snapshot(account_infos);
+            with_invocation(|invocation| {
+                invocation.snapshot.extend(states);
+                invocation
+                    .logs
+                    .push(format!("Program {} success", instruction.program_id));
+            });
+            Ok(())
+        }
+        Err(error) => {
+            let program_error = match &error {
+                TestError::Program(program_error) => program_error.clone(),
+                _ => ProgramError::InvalidArgument,
+            };
+            with_invocation(|invocation| {
+                invocation.logs.push(format!(
+                    "Program {} failed: {}",
+                    instruction.program_id, error
+                ));
+                invocation.failure.get_or_insert(error);
+            });
+            Err(program_error)
+        }
+    }
+}
+
+/// The callee's accounts, in the order its instruction lists them, with the
+/// privileges it grants them.
+fn callee_accounts<'a>(
+    caller: &Pubkey,
+    instruction: &Instruction,
+    account_infos: &[AccountInfo<'a>],
+    signers_seeds: &[&[&[u8]]],
+) -> Result<Vec<AccountInfo<'a>>, TestError> {
+    instruction
+        .accounts
+        .iter()
+        .map(|meta| {
+            let mut info = account_infos
+                .iter()
+                .find(|info| info.key == &meta.pubkey)
+                .cloned()
+                .or_else(|| executable_account(&meta.pubkey))
+                .ok_or_else(|| {
+                    TestError::Runtime(format!(
+                        "account {} is missing from the invocation's accounts",
+                        meta.pubkey
+                    ))
+                })?;
+            if meta.is_writable && !info.is_writable {
+                return Err(TestError::Runtime(format!(
+                    "{}'s writable privilege was escalated",
+                    meta.pubkey
+                )));
+            }
+            let signed_by_caller = signers_seeds.iter().any(|seeds| {
+                Pubkey::create_program_address(seeds, caller).ok() == Some(meta.pubkey)
+            });
+            if meta.is_signer && !info.is_signer && !signed_by_caller {
+                return Err(TestError::Runtime(format!(
+                    "{}'s signer privilege was escalated",
+                    meta.pubkey
+                )));
+            }
+            info.is_signer = meta.is_signer;
+            info.is_writable = meta.is_writable;
+            Ok(info)
+        })
+        .collect()
+}
+
+/// An executable account the instruction lists but the caller didn't pass
+/// along. It's read-only and small, so it's leaked for the invocation to
+/// borrow.
+fn executable_account<'a>(key: &Pubkey) -> Option<AccountInfo<'a>> {
+    let account = with_invocation(|invocation| invocation.executables.get(key).cloned())??;
+    Some(AccountInfo::new(
+        Box::leak(Box::new(*key)),
+        false,
+        false,
+        Box::leak(Box::new(account.lamports)),
+        Box::leak(account.data.into_boxed_slice()),
+        Box::leak(Box::new(account.owner)),
+        true,
+        account.rent_epoch,
+    ))
+}
+
+/// Lays out an instruction's accounts and data the way the on-chain loader
+/// does for `entrypoint::deserialize`, backed by 8-byte aligned memory.
+fn serialize_input(instruction: &Instruction, accounts: &HashMap<Pubkey, TestAccount>) -> Vec<u64> {
+    let metas = &instruction.accounts;
+    let mut input: Vec<u8> = vec![];
+    input.extend((metas.len() as u64).to_le_bytes());
+    for (i, meta) in metas.iter().enumerate() {
+        if let Some(original) = metas[..i].iter().position(|m| m.pubkey == meta.pubkey) {
+            input.push(original as u8);
+            input.extend([0; 7]);
+            continue;
+        }
+        // A repeated account has the combined privileges of all of its uses.
+        let uses = metas.iter().filter(|m| m.pubkey == meta.pubkey);
+        let is_signer = uses.clone().any(|m| m.is_signer);
+        let is_writable = uses.clone().any(|m| m.is_writable);
+        let account = accounts.get(&meta.pubkey).cloned().unwrap_or_default();
+
+        input.extend([
+            NON_DUP_MARKER,
+            is_signer as u8,
+            is_writable as u8,
+            account.executable as u8,
+        ]);
+        input.extend([0; 4]); // Original data length, filled in by `deserialize`.
+        input.extend(meta.pubkey.as_ref());
+        input.extend(account.owner.as_ref());
+        input.extend(account.lamports.to_le_bytes());
+        input.extend((account.data.len() as u64).to_le_bytes());
+        input.extend(&account.data);
+        input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        input.resize((input.len() + 7) / 8 * 8, 0);
+        input.extend(account.rent_epoch.to_le_bytes());
+    }
+    input.extend((instruction.data.len() as u64).to_le_bytes());
+    input.extend(&instruction.data);
+    input.extend(instruction.program_id.as_ref());
+
+    let mut aligned = vec![0u64; (input.len() + 7) / 8];
+    unsafe {
+        std::ptr::copy_nonoverlapping(input.as_ptr(), aligned.as_mut_ptr() as *mut u8, input.len())
+    };
+    aligned
+}
+
+/// Each account once, skipping repeats.
+fn unique<'b, 'a>(account_infos: &'b [AccountInfo<'a>]) -> Vec<&'b AccountInfo<'a>> {
+    let mut unique: Vec<&AccountInfo> = vec![];
+    for info in account_infos.iter() {
+        if !unique.iter().any(|u| u.key == info.key) {
+            unique.push(info);
+        }
+    }
+    unique
+}
+
+fn total_lamports(account_infos: &[AccountInfo]) -> u128 {
+    unique(account_infos)
+        .iter()
+        .map(|info| info.lamports() as u128)
+        .sum()
+}
+
+fn snapshot(account_infos: &[AccountInfo]) -> HashMap<Pubkey, AccountState> {
+    unique(account_infos)
+        .into_iter()
+        .map(|info| {
+            (
+                *info.key,
+                AccountState {
+                    lamports: info.lamports(),
+                    data: info.data.borrow().to_vec(),
+                    owner: *info.owner,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Checks that `program_id` only changed accounts the way the runtime allows
+/// since they were last checked: it may only change the data and owner of,
+/// and debit, writable accounts it owns, and only credit writable accounts.
+fn verify(
+    program_id: &Pubkey,
+    account_infos: &[AccountInfo],
+    snapshot: &HashMap<Pubkey, AccountState>,
+) -> Result<(), TestError> {
+    for info in unique(account_infos) {
+        let before = match snapshot.get(info.key) {
+            Some(before) => before,
+            None => continue,
+        };
+        let lamports = info.lamports();
+        let data_changed = before.data[..] != info.data.borrow()[..];
+        let owner_changed = before.owner != *info.owner;
+        if !(data_changed || owner_changed || lamports != before.lamports) {
+            continue;
+        }
+        if !info.is_writable || info.executable {
+            return Err(TestError::Runtime(format!(
+                "read-only account {} was modified",
+                info.key
+            )));
+        }
+        let owned = before.owner == *program_id;
+        let violation = if owner_changed && !owned {
+            Some("changed the owner of")
+        } else if data_changed && !owned {
+            Some("modified the data of")
+        } else if lamports < before.lamports && !owned {
+            Some("debited")
+        } else {
+            None
+        };
+        if let Some(violation) = violation {
+            return Err(TestError::Runtime(format!(
+                "program {} {} account {}, which it doesn't own",
+                program_id, violation, info.key
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks `program_id`'s changes since the instruction's last check.
+fn verify_with_snapshot(
+    program_id: &Pubkey,
+    account_infos: &[AccountInfo],
+) -> Result<(), TestError> {
+    with_invocation(|invocation| verify(program_id, account_infos, &invocation.snapshot))
+        .unwrap_or(Ok(()))
+}
+
+fn panic_message(panic: Box<dyn std::any::Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => match panic.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
@@ -0,0 +1,56 @@
+//! Native builds of the SPL Token, Token-2022, Associated Token Account and
+//! Token Metadata programs, to stand in for them under a `TestHarness`.
+//!
+//! These are the programs' own processors, so they behave exactly as they do
+//! on-chain - including their own cross-program invocations, which the harness
+//! routes back through the System Program and this hook.
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, pubkey::Pubkey,
+};
+
+/// The programs `process` stands in for.
+pub const PROGRAM_IDS: [Pubkey; 4] = [
+    spl_token::ID,
+    spl_token_2022::ID,
+    spl_associated_token_account::ID,
+    mpl_token_metadata::ID,
+];
+
+/// A `CpiHook` that processes instructions for the SPL Token, Token-2022,
+/// Associated Token Account and Token Metadata programs.
+pub fn process<'x>(
+    instruction: &Instruction,
+    accounts: &[AccountInfo<'x>],
+) -> Option<ProgramResult> {
+    let program_id = &instruction.program_id;
+    let data = &instruction.data;
+    if spl_token::check_id(program_id) {
+        Some(spl_token::processor::Processor::process(
+            program_id, accounts, data,
+        ))
+    } else if spl_token_2022::check_id(program_id) {
+        Some(spl_token_2022::processor::Processor::process(
+            program_id, accounts, data,
+        ))
+    } else if spl_associated_token_account::check_id(program_id) {
+        Some(
+            spl_associated_token_account::processor::process_instruction(
+                program_id, accounts, data,
+            ),
+        )
+    } else if mpl_token_metadata::check_id(program_id) {
+        // The Token Metadata processor borrows the accounts for as long as
+        // their data (`&'a [AccountInfo<'a>]`), as the loader's input buffer
+        // does on-chain. `AccountInfo` is invariant over its lifetime, so `'x`
+        // can't be shortened to the borrow's without a transmute. The data
+        // outlives the call and the processor doesn't replace any of its
+        // references, so narrowing it is sound.
+        let accounts =
+            unsafe { std::mem::transmute::<&[AccountInfo<'x>], &[AccountInfo<'_>]>(accounts) };
+        Some(mpl_token_metadata::processor::process_instruction(
+            program_id, accounts, data,
+        ))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,118 @@
+//! Emulates the System Program for cross-program invocations made under a
+//! `TestHarness`.
+//!
+//! Only the instructions Nautilus programs use are supported: creating,
+//! allocating and assigning accounts, and transferring lamports.
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    msg,
+    program_error::ProgramError,
+    program_utils::limited_deserialize,
+    pubkey::Pubkey,
+    system_instruction::{SystemError, SystemInstruction, MAX_PERMITTED_DATA_LENGTH},
+    system_program,
+};
+
+/// Processes a System Program instruction, where `accounts` are the
+/// instruction's accounts in order.
+pub(super) fn process(instruction: &Instruction, accounts: &[AccountInfo]) -> ProgramResult {
+    let system_instruction: SystemInstruction =
+        limited_deserialize(&instruction.data, MAX_PERMITTED_DATA_LENGTH)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let account = |index: usize| {
+        accounts
+            .get(index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+    match system_instruction {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let (from, to) = (account(0)?, account(1)?);
+            if to.lamports() > 0 || !to.data_is_empty() || !system_program::check_id(to.owner) {
+                msg!("Create Account: account {} already in use", to.key);
+                return Err(system_error(SystemError::AccountAlreadyInUse));
+            }
+            allocate(to, space)?;
+            assign(to, &owner)?;
+            transfer(from, to, lamports)
+        }
+        SystemInstruction::Assign { owner } => assign(account(0)?, &owner),
+        SystemInstruction::Transfer { lamports } => transfer(account(0)?, account(1)?, lamports),
+        SystemInstruction::Allocate { space } => allocate(account(0)?, space),
+        _ => {
+            msg!("This System Program instruction isn't supported by the test harness");
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+}
+
+fn system_error(error: SystemError) -> ProgramError {
+    ProgramError::Custom(error as u32)
+}
+
+fn signed(account: &AccountInfo) -> ProgramResult {
+    match account.is_signer {
+        true => Ok(()),
+        false => {
+            msg!("Account {} must sign", account.key);
+            Err(ProgramError::MissingRequiredSignature)
+        }
+    }
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    signed(account)?;
+    if !account.data_is_empty() || !system_program::check_id(account.owner) {
+        msg!("Allocate: account {} already in use", account.key);
+        return Err(system_error(SystemError::AccountAlreadyInUse));
+    }
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        msg!(
+            "Allocate: requested {}, max allowed {}",
+            space,
+            MAX_PERMITTED_DATA_LENGTH
+        );
+        return Err(system_error(SystemError::InvalidAccountDataLength));
+    }
+    account.realloc(space as usize, true)
+}
+
+fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner == owner {
+        return Ok(());
+    }
+    signed(account)?;
+    if !system_program::check_id(account.owner) {
+        msg!(
+            "Assign: account {} must be owned by the System Program",
+            account.key
+        );
+        return Err(ProgramError::IllegalOwner);
+    }
+    account.assign(owner);
+    Ok(())
+}
+
+fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    signed(from)?;
+    if !from.data_is_empty() {
+        msg!("Transfer: `from` must not carry data");
+        return Err(ProgramError::InvalidArgument);
+    }
+    if from.lamports() < lamports {
+        msg!(
+            "Transfer: insufficient lamports {}, need {}",
+            from.lamports(),
+            lamports
+        );
+        return Err(system_error(SystemError::ResultWithNegativeLamports));
+    }
+    **from.try_borrow_mut_lamports()? -= lamports;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
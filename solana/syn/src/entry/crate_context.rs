@@ -0,0 +1,162 @@
+//! Walks the user's entire crate, following `mod` declarations across files.
+use std::path::{Path, PathBuf};
+
+use nautilus_idl::idl_type::IdlTypeContext;
+use syn::{
+    Attribute, Item, ItemConst, ItemEnum, ItemMod, ItemStruct, ItemType, Lit, Meta, NestedMeta,
+};
+
+/// The parsed contents of the user's crate.
+///
+/// Starting at the crate root (usually `src/lib.rs`), every module - inline or
+/// declared in another file with `mod foo;` - is visited, and all of the
+/// structs, enums, type aliases, constants and inline modules found along the
+/// way are collected.
+///
+/// * `structs`: Every struct defined in the crate.
+/// * `enums`: Every enum defined in the crate.
+/// * `type_aliases`: Every type alias (`type Foo = ..;`) defined in the crate.
+/// * `consts`: Every constant defined in the crate.
+/// * `modules`: Every inline module (`mod foo { .. }`) defined in the crate.
+#[derive(Debug, Default)]
+pub struct CrateContext {
+    structs: Vec<ItemStruct>,
+    enums: Vec<ItemEnum>,
+    type_aliases: Vec<ItemType>,
+    consts: Vec<ItemConst>,
+    modules: Vec<ItemMod>,
+}
+
+impl CrateContext {
+    /// Parses the crate whose root file is located at `root`.
+    ///
+    /// Module files are resolved the same way `rustc` resolves them:
+    /// `#[path = ".."]` if provided, otherwise `foo.rs` or `foo/mod.rs`.
+    /// Modules gated behind `#[cfg(test)]` are skipped.
+    pub fn parse(root: &Path) -> Result<Self, String> {
+        let mut crate_context = Self::default();
+        let mod_dir = root.parent().map(Path::to_path_buf).unwrap_or_default();
+        crate_context.parse_file(root, &mod_dir)?;
+        Ok(crate_context)
+    }
+
+    pub fn structs(&self) -> impl Iterator<Item = &ItemStruct> {
+        self.structs.iter()
+    }
+
+    pub fn enums(&self) -> impl Iterator<Item = &ItemEnum> {
+        self.enums.iter()
+    }
+
+    pub fn consts(&self) -> impl Iterator<Item = &ItemConst> {
+        self.consts.iter()
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = &ItemMod> {
+        self.modules.iter()
+    }
+
+    /// The crate's type aliases and constants, which the IDL resolves the
+    /// crate's types against.
+    pub fn idl_type_context(&self) -> IdlTypeContext {
+        IdlTypeContext::new(&self.type_aliases, &self.consts)
+    }
+
+    /// Parses a single source file, where `mod_dir` is the directory its child
+    /// modules live in.
+    fn parse_file(&mut self, path: &Path, mod_dir: &Path) -> Result<(), String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read `{}`: {}", path.display(), e))?;
+        let file = syn::parse_file(&source)
+            .map_err(|e| format!("Failed to parse `{}`: {}", path.display(), e))?;
+        self.parse_items(file.items, path, mod_dir)
+    }
+
+    fn parse_items(&mut self, items: Vec<Item>, file: &Path, mod_dir: &Path) -> Result<(), String> {
+        for item in items {
+            match item {
+                Item::Struct(item_struct) => self.structs.push(item_struct),
+                Item::Enum(item_enum) => self.enums.push(item_enum),
+                Item::Type(item_type) => self.type_aliases.push(item_type),
+                Item::Const(item_const) => self.consts.push(item_const),
+                Item::Mod(item_mod) if !is_cfg_test(&item_mod.attrs) => {
+                    self.parse_module(item_mod, file, mod_dir)?
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_module(
+        &mut self,
+        item_mod: ItemMod,
+        file: &Path,
+        mod_dir: &Path,
+    ) -> Result<(), String> {
+        let name = item_mod.ident.to_string();
+        match &item_mod.content {
+            Some((_, items)) => {
+                let items = items.clone();
+                self.modules.push(item_mod);
+                self.parse_items(items, file, &mod_dir.join(name))
+            }
+            None => {
+                let (path, child_mod_dir) = match path_attribute(&item_mod.attrs) {
+                    // Files loaded with `#[path]` are treated like `mod.rs` files.
+                    Some(path) => {
+                        let path = file.parent().unwrap_or(mod_dir).join(path);
+                        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+                        (path, dir)
+                    }
+                    None => resolve_module_file(mod_dir, &name).ok_or_else(|| {
+                        format!(
+                            "Failed to find the file for module `{}` declared in `{}`. Expected `{}` or `{}`.",
+                            name,
+                            file.display(),
+                            mod_dir.join(format!("{}.rs", name)).display(),
+                            mod_dir.join(&name).join("mod.rs").display(),
+                        )
+                    })?,
+                };
+                self.parse_file(&path, &child_mod_dir)
+            }
+        }
+    }
+}
+
+/// Finds the file for `mod name;` declared in a module whose children live in
+/// `mod_dir`, returning the file along with the directory its own children
+/// live in.
+fn resolve_module_file(mod_dir: &Path, name: &str) -> Option<(PathBuf, PathBuf)> {
+    let file = mod_dir.join(format!("{}.rs", name));
+    if file.is_file() {
+        return Some((file, mod_dir.join(name)));
+    }
+    let file = mod_dir.join(name).join("mod.rs");
+    if file.is_file() {
+        return Some((file, mod_dir.join(name)));
+    }
+    None
+}
+
+/// Reads the value of a `#[path = ".."]` attribute, if one is present.
+fn path_attribute(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match attr.parse_meta() {
+        Ok(Meta::NameValue(meta)) if meta.path.is_ident("path") => match meta.lit {
+            Lit::Str(lit_str) => Some(lit_str.value()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Checks for `#[cfg(test)]`.
+fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| match attr.parse_meta() {
+        Ok(Meta::List(meta_list)) if meta_list.path.is_ident("cfg") => meta_list.nested.iter().any(
+            |nested| matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("test")),
+        ),
+        _ => false,
+    })
+}
@@ -1,12 +1,13 @@
 //! A `syn`-powered enum that dissolves to the required components to create the
 //! program's entrypoint, processor, and IDL.
-use nautilus_idl::idl_instruction::IdlInstruction;
+use nautilus_idl::idl_type_def::docs;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Ident, ItemFn};
+use syn::{Ident, ItemFn, Type};
 
 use crate::{
-    entry::entry_variant::NautilusEntrypointEnumVariant, entry::parser::parse_function,
+    entry::entry_variant::{CallContext, NautilusEntrypointEnumVariant},
+    entry::parser::{data_instance_name, parse_function},
     object::NautilusObject,
 };
 
@@ -27,23 +28,55 @@ impl NautilusEntrypointEnum {
     /// This action will simply convert the user's declared functions into
     /// `NautilusEntrypointEnumVariant` instances, which dissolve to
     /// the required components for building out the generated program.
-    pub fn new(nautilus_objects: Vec<NautilusObject>, declared_functions: Vec<ItemFn>) -> Self {
+    ///
+    /// Fails if any of the functions' signatures can't be parsed.
+    pub fn new(
+        nautilus_objects: Vec<NautilusObject>,
+        declared_functions: Vec<ItemFn>,
+    ) -> syn::Result<Self> {
         let variants = declared_functions
             .into_iter()
             .enumerate()
             .map(|(i, f)| {
+                let function_docs = docs(&f.attrs);
                 let (variant_ident, variant_args, call_ident, call_context) =
-                    parse_function(&nautilus_objects, f);
-                NautilusEntrypointEnumVariant::new(
+                    parse_function(&nautilus_objects, f)?;
+                Ok(NautilusEntrypointEnumVariant::new(
                     i.try_into().unwrap(),
                     variant_ident,
                     variant_args,
                     call_ident,
                     call_context,
-                )
+                    function_docs,
+                ))
             })
-            .collect();
-        Self { variants }
+            .collect::<syn::Result<_>>()?;
+        Ok(Self { variants })
+    }
+
+    /// Every instantiation of a generic `Table` or `State` type used in the
+    /// instructions, ie. `Vault<u64>` in `Account<'a, Vault<u64>>`, with its
+    /// name - see `parser::data_instance_name`.
+    ///
+    /// Each is listed once, in the order it's first used.
+    pub fn data_instances(&self) -> Vec<(String, Type)> {
+        let mut instances: Vec<(String, Type)> = vec![];
+        let data_tys = self
+            .variants
+            .iter()
+            .flat_map(|v| v.call_context.iter())
+            .filter_map(|ctx| match ctx {
+                CallContext::Nautilus(obj) => obj.entry_config.as_ref()?.data_ty.as_ref(),
+                CallContext::Arg(_) => None,
+            });
+        for data_ty in data_tys {
+            if let Some(name) = data_instance_name(data_ty) {
+                if !instances.iter().any(|(n, _)| n == &name) {
+                    instances.push((name, data_ty.clone()));
+                }
+            }
+        }
+        instances
     }
 
     pub fn enum_ident() -> Ident {
@@ -51,37 +84,29 @@ impl NautilusEntrypointEnum {
     }
 }
 
-impl From<&NautilusEntrypointEnum> for (TokenStream, TokenStream, Vec<IdlInstruction>) {
+impl From<&NautilusEntrypointEnum> for (TokenStream, TokenStream) {
     /// Maps each `NautilusEntrypointEnumVariant` into the proper components and
     /// dissolves itself into the required components for building out the
     /// generated program.
     ///
-    /// Consider the `fold` operation on the `variants` field, which returns
-    /// (`variants`, `match_arms`, `idl_instructions`):
+    /// Consider the `unzip` operation on the `variants` field, which returns
+    /// (`variants`, `match_arms`):
     /// * `variants`: The variants of the instruction enum for the program.
     /// * `match_arms`: The match arms of the processor which will process
     ///   whichever instruction enum variant (and its arguments) is provided to
     ///   the program.
-    /// * `idl_instructions`: The IDL instructions derived from the declared
-    ///   functions - to be used in generating the IDL.
     ///
     /// Consider the return type of the function itself - defined at the trait
-    /// level: (`TokenStream`, `TokenStream`, `Vec<IdlInstruction>`):
+    /// level: (`TokenStream`, `TokenStream`):
     /// * `TokenStream` (first): The instruction enum.
     /// * `TokenStream` (second): The processor.
-    /// * `Vec<IdlInstruction>`: The list of IDL instructions.
+    ///
+    /// The IDL instructions are built separately - see
+    /// `From<&NautilusEntrypointEnum> for Vec<IdlInstruction>`.
     fn from(value: &NautilusEntrypointEnum) -> Self {
         let enum_name = NautilusEntrypointEnum::enum_ident();
-        let (variants, match_arms, idl_instructions) = value.variants.iter().fold(
-            (Vec::new(), Vec::new(), Vec::new()),
-            |(mut variants, mut match_arms, mut idl_instructions), v| {
-                let (a, b, c): (TokenStream, TokenStream, IdlInstruction) = v.into();
-                variants.push(a);
-                match_arms.push(b);
-                idl_instructions.push(c);
-                (variants, match_arms, idl_instructions)
-            },
-        );
+        let (variants, match_arms): (Vec<TokenStream>, Vec<TokenStream>) =
+            value.variants.iter().map(|v| v.into()).unzip();
         (
             quote! {
                 #[derive(borsh::BorshDeserialize, borsh::BorshSerialize)]
@@ -104,7 +129,6 @@ impl From<&NautilusEntrypointEnum> for (TokenStream, TokenStream, Vec<IdlInstruc
 
                 entrypoint!(process_instruction);
             },
-            idl_instructions,
         )
     }
 }
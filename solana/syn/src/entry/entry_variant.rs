@@ -1,14 +1,13 @@
 //! A `syn`-powered struct that dissolves to the required components to create
 //! the variants of the program's instruction enum and it's associated processor
 //! match arm initialization logic.
-use nautilus_idl::idl_instruction::IdlInstruction;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Ident, Type};
 
 use crate::{
     entry::required_account::{to_ident_pointer, RequiredAccountSubtype},
-    object::{parser::NautilusObjectConfig, source::source_nautilus_names, NautilusObject},
+    object::{source::source_nautilus_names, NautilusObject, NautilusObjectType},
 };
 
 use super::{
@@ -47,6 +46,9 @@ pub struct NautilusEntrypointEnumVariant {
     /// for `CallContext`, but essentially it's information about whether or
     /// not the parameter is a Nautilus object or an instruction argument.
     pub call_context: Vec<CallContext>,
+    /// The `///` doc comments on the user's declared function, which document
+    /// the instruction in the IDL.
+    pub docs: Vec<String>,
 }
 
 /// "Call context" for each declared parameter in the user's defined function
@@ -74,6 +76,7 @@ impl NautilusEntrypointEnumVariant {
         variant_args: Vec<(Ident, Type)>,
         call_ident: Ident,
         call_context: Vec<CallContext>,
+        docs: Vec<String>,
     ) -> Self {
         let required_accounts = RequiredAccount::condense(
             call_context
@@ -100,6 +103,7 @@ impl NautilusEntrypointEnumVariant {
             required_accounts,
             call_ident,
             call_context,
+            docs,
         }
     }
 
@@ -128,7 +132,10 @@ impl NautilusEntrypointEnumVariant {
                     RequiredAccountSubtype::MintAuthority => mint_authority_ident(&r.ident),
                 },
                 RequiredAccountType::IndexAccount => {
-                    index_init = quote! { let nautilus_index = NautilusIndex::load(program_id, index_pointer)?; }; // TODO
+                    index_init = profile_phase(
+                        "NautilusIndex::load nautilus_index",
+                        quote! { let nautilus_index = NautilusIndex::load(program_id, index_pointer)?; },
+                    ); // TODO
                     r.ident.clone()
                 }
                 _ => r.ident.clone(),
@@ -155,16 +162,20 @@ impl NautilusEntrypointEnumVariant {
                             let (obj_type, arg_ty, is_custom) = match source_nautilus_names().contains(&obj.ident.to_string()) {
                                 true => (obj.ident.clone(), quote!(), false),
                                 false => {
-                                    let ty = &obj.ident;
+                                    let ty = match &config.data_ty {
+                                        Some(data_ty) => quote! { #data_ty },
+                                        None => {
+                                            let ident = &obj.ident;
+                                            quote! { #ident }
+                                        }
+                                    };
                                     (
-                                        match &obj.object_config {
-                                            Some(t) => match t {
-                                                NautilusObjectConfig::RecordConfig { .. } => Ident::new("Record", Span::call_site()),
-                                                NautilusObjectConfig::AccountConfig { .. } => Ident::new("Account", Span::call_site()),
-                                            },
-                                            None => panic!("Object {} did not match any source Nautilus objects and was not annotated with a Nautilus #[derive(..)] macro", &obj.ident.to_string()),
+                                        match &obj.object_type {
+                                            NautilusObjectType::Record => Ident::new("Record", Span::call_site()),
+                                            NautilusObjectType::Account => Ident::new("Account", Span::call_site()),
+                                            NautilusObjectType::ZeroCopyAccount => Ident::new("ZeroCopyAccount", Span::call_site()),
                                         },
-                                        quote! { #ty },
+                                        ty,
                                         true,
                                     )
                                 },
@@ -184,6 +195,7 @@ impl NautilusEntrypointEnumVariant {
                                         let t: TokenStream = r.into();
                                         t
                                     });
+                                    let phase = format!("Create::new {}", arg_ident);
                                     let create_obj_init = match is_custom {
                                         true => quote! {
                                             let mut #arg_ident = Create::new(
@@ -198,24 +210,21 @@ impl NautilusEntrypointEnumVariant {
                                             )?;
                                         },
                                     };
-                                    object_inits.push(create_obj_init);
+                                    object_inits.push(profile_phase(&phase, create_obj_init));
                                 },
                                 None => {
-                                    if config.is_signer {
-                                        object_inits.push(
-                                            quote! { let #arg_ident = Signer::new(#obj_type::load(#(#read_call_idents,)*)?)?; },
-                                        );
+                                    let phase = format!("{}::load {}", obj_type, arg_ident);
+                                    let load_obj_init = if config.is_signer {
+                                        quote! { let #arg_ident = Signer::new(#obj_type::load(#(#read_call_idents,)*)?)?; }
                                     } else if config.is_mut {
-                                        object_inits.push(
-                                            quote! { let #arg_ident = Mut::new(#obj_type::load(#(#read_call_idents,)*)?)?; },
-                                        );
+                                        quote! { let #arg_ident = Mut::new(#obj_type::load(#(#read_call_idents,)*)?)?; }
                                     } else {
-                                        object_inits.push(match is_custom {
-                                                true => quote! { let #arg_ident = #obj_type::< #arg_ty >::load(#(#read_call_idents,)*)?; },
-                                                false => quote! { let #arg_ident = #obj_type::load(#(#read_call_idents,)*)?; },
-                                            }
-                                        );
-                                    }
+                                        match is_custom {
+                                            true => quote! { let #arg_ident = #obj_type::< #arg_ty >::load(#(#read_call_idents,)*)?; },
+                                            false => quote! { let #arg_ident = #obj_type::load(#(#read_call_idents,)*)?; },
+                                        }
+                                    };
+                                    object_inits.push(profile_phase(&phase, load_obj_init));
                                 },
                             };
                             call_args.push(quote! { #arg_ident })
@@ -229,20 +238,39 @@ impl NautilusEntrypointEnumVariant {
             });
         }
         let call_ident = &self.call_ident;
+        let call_phase = call_ident.to_string();
+        // With the `profile-compute-units` feature, loading the accounts,
+        // initializing each object and calling the user's function are each
+        // profiled - see `nautilus::profile`.
         quote::quote! {
             {
-                splogger::info!("Instruction: {}", #instruction_name);
+                nautilus::log_info!("Instruction: {}", #instruction_name);
                 let accounts_iter = &mut accounts.iter();
+                let nautilus_phase = nautilus::profile::Phase::begin("accounts");
                 #(#all_accounts)*
+                nautilus_phase.end();
                 #index_init
                 #(#object_inits)*
-                #call_ident(#(#call_args,)*)
+                let nautilus_phase = nautilus::profile::Phase::begin(#call_phase);
+                let result = #call_ident(#(#call_args,)*);
+                nautilus_phase.end();
+                result
             }
         }
     }
 }
 
-impl From<&NautilusEntrypointEnumVariant> for (TokenStream, TokenStream, IdlInstruction) {
+/// Wraps an object's initialization in a profiled phase - see
+/// `nautilus::profile`.
+fn profile_phase(phase: &str, init: TokenStream) -> TokenStream {
+    quote! {
+        let nautilus_phase = nautilus::profile::Phase::begin(#phase);
+        #init
+        nautilus_phase.end();
+    }
+}
+
+impl From<&NautilusEntrypointEnumVariant> for (TokenStream, TokenStream) {
     /// Dissolves the `NautilusEntrypointEnumVariant` into the proper components
     /// for building out the generated program.
     ///
@@ -251,14 +279,15 @@ impl From<&NautilusEntrypointEnumVariant> for (TokenStream, TokenStream, IdlInst
     /// aggregates each generated component.
     ///
     /// Consider the return type of the function itself - defined at the trait
-    /// level: (`TokenStream`, `TokenStream`, `IdlInstruction`):
+    /// level: (`TokenStream`, `TokenStream`):
     /// * `TokenStream` (first): The identifier and associated arguments for the
     ///   program instruction enum variant for this particular declared
     ///   function.
     /// * `TokenStream` (second): The processor match arm for this particular
     ///   declared function.
-    /// * `IdlInstruction`: The IDL instruction derived from this particular
-    ///   declared function.
+    ///
+    /// The IDL instruction for this variant is built separately - see
+    /// `From<&NautilusEntrypointEnumVariant> for IdlInstruction`.
     fn from(value: &NautilusEntrypointEnumVariant) -> Self {
         let variant_ident = &value.variant_ident;
         let enum_ident = NautilusEntrypointEnum::enum_ident();
@@ -268,7 +297,6 @@ impl From<&NautilusEntrypointEnumVariant> for (TokenStream, TokenStream, IdlInst
         (
             quote! { #variant_ident(#(#arg_types,)*), },
             quote! { #enum_ident::#variant_ident(#(#arg_names,)*) => #match_arm_logic, },
-            value.into(),
         )
     }
 }
@@ -2,42 +2,420 @@
 //! configuration structs,
 // such as `NautilusEntrypointEnum` and `NautilusEntrypointEnumVariant`,
 // into IDL components.
+//
+// This module is also the library entry point for building a program's IDL,
+// which is done outside of macro expansion - by the Nautilus CLI or a program's
+// `build.rs`.
+use std::path::Path;
+
 use nautilus_idl::{
+    converters::{
+        anchor::AnchorIdlWrite, docs::DocsIdlWrite, go::GoIdlWrite,
+        json_schema::JsonSchemaIdlWrite, py::PythonIdlWrite, rust::RustIdlWrite, sql::SqlIdlWrite,
+        ts::TypeScriptIdlWrite,
+    },
+    idl_error::nautilus_errors,
     idl_instruction::{
         IdlInstruction, IdlInstructionAccount, IdlInstructionArg, IdlInstructionDiscriminant,
     },
+    idl_metadata::IdlMetadata,
     idl_nautilus_config::{
         IdlSeed, IdlTypeDefNautilusConfig, IdlTypeDefNautilusConfigDefaultInstruction,
     },
-    idl_type_def::IdlTypeDef,
+    idl_type::{IdlType, IdlTypeContext, IdlTypeError},
+    idl_type_def::{IdlTypeDef, IdlTypeDefType, IdlTypeEnumFields},
+    Idl,
 };
 
 use crate::object::{
-    default_instructions::DefaultInstruction, parser::NautilusObjectConfig, seeds::Seed,
+    default_instructions::DefaultInstruction,
+    parser::{member_name, NautilusObjectConfig},
+    seeds::Seed,
     NautilusObject, NautilusObjectRawType,
 };
 
-use super::{entry_variant::NautilusEntrypointEnumVariant, required_account::RequiredAccount};
+use super::{
+    crate_context::CrateContext,
+    entry_enum::NautilusEntrypointEnum,
+    entry_variant::NautilusEntrypointEnumVariant,
+    parser::{
+        data_instance_idl_name, parse_crate_context, parse_manifest, parse_nautilus_module,
+        type_to_string,
+    },
+    required_account::RequiredAccount,
+};
 
-/// Converts the `NautilusEntrypointEnumVariant` into an IDL instruction.
+/// Builds the IDL for the Nautilus program whose `Cargo.toml` is located in
+/// `manifest_dir`.
 ///
-/// This will use the configurations from the variant to build the necessary
-/// instruction in the IDL - including all required accounts.
-impl From<&NautilusEntrypointEnumVariant> for IdlInstruction {
-    fn from(value: &NautilusEntrypointEnumVariant) -> Self {
-        let mut name = value.variant_ident.to_string();
-        name.replace_range(..1, &name[..1].to_lowercase());
-        IdlInstruction {
-            name,
-            accounts: value.required_accounts.iter().map(|a| a.into()).collect(),
-            args: value
-                .variant_args
+/// Using information extracted from the user's manifest (`Cargo.toml`), the
+/// entirety of their crate, and the functions declared in their `#[nautilus]`
+/// module, this function builds the same `NautilusEntrypointEnum` the macro
+/// does and dissolves it into IDL instructions.
+///
+/// This reads everything it needs from disk, so it can be called by the
+/// Nautilus CLI (`nautilus idl build`) or from a program's `build.rs` rather
+/// than during macro expansion.
+///
+/// Fails if the manifest or crate can't be parsed, if the crate has no
+/// `#[nautilus]` module, or with every type it uses that can't be described in
+/// the IDL.
+pub fn build_idl(manifest_dir: &Path) -> std::io::Result<Idl> {
+    let (crate_version, crate_name) = parse_manifest(manifest_dir)?;
+    let crate_context = CrateContext::parse(&manifest_dir.join("src/lib.rs")).map_err(|e| {
+        invalid_data(format!(
+            "Failed to parse your crate. Are you sure you've built your program with `--lib` ? {}",
+            e
+        ))
+    })?;
+    let parsed_crate = parse_crate_context(&crate_context).map_err(type_errors)?;
+    let declared_functions = parse_nautilus_module(&crate_context)?;
+
+    let nautilus_enum =
+        NautilusEntrypointEnum::new(parsed_crate.nautilus_objects, declared_functions)
+            .map_err(|e| invalid_data(e.to_string()))?;
+    let idl_type_context = crate_context.idl_type_context();
+    let idl_instructions =
+        idl_instructions(&nautilus_enum, &idl_type_context).map_err(type_errors)?;
+    let idl_accounts = instantiate_generic_accounts(
+        parsed_crate.idl_accounts,
+        &parsed_crate.idl_types,
+        &nautilus_enum.data_instances(),
+        &idl_type_context,
+    )
+    .map_err(type_errors)?;
+
+    let mut idl = Idl::new(
+        crate_version,
+        crate_name,
+        idl_instructions,
+        idl_accounts,
+        parsed_crate.idl_types,
+        IdlMetadata::new_with_no_id(),
+    );
+    idl.constants = parsed_crate.idl_constants;
+    idl.errors = nautilus_errors();
+    check_defined_types(&idl).map_err(invalid_data)?;
+    Ok(idl)
+}
+
+/// Replaces each generic account with one concrete account for every
+/// instantiation of it the instructions use, ie. `VaultU64` for `Vault<u64>`,
+/// discriminated the way the `#[nautilus]` macro does.
+///
+/// A client can only decode an account whose layout is fully known, so a
+/// generic account that's never instantiated is left out.
+fn instantiate_generic_accounts(
+    accounts: Vec<IdlTypeDef>,
+    types: &[IdlTypeDef],
+    instances: &[(String, syn::Type)],
+    context: &IdlTypeContext,
+) -> Result<Vec<IdlTypeDef>, Vec<IdlTypeError>> {
+    let mut concrete_accounts = vec![];
+    let mut errors = vec![];
+    for account in accounts {
+        if account.generics.is_empty() {
+            concrete_accounts.push(account);
+            continue;
+        }
+        for (instance_name, data_ty) in instances
+            .iter()
+            .filter(|(_, data_ty)| type_to_string(data_ty).as_ref() == Some(&account.name))
+        {
+            match instantiate_account(&account, instance_name, data_ty, context) {
+                Ok(instance) => concrete_accounts.push(instance),
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let all_types: Vec<IdlTypeDef> = concrete_accounts
+        .iter()
+        .chain(types.iter())
+        .cloned()
+        .collect();
+    for account in concrete_accounts.iter_mut() {
+        account.size = account.account_size(&all_types);
+    }
+    Ok(concrete_accounts)
+}
+
+/// One instantiation of a generic account, named after its type arguments and
+/// with `instance_name` as its table name or discriminator string.
+fn instantiate_account(
+    account: &IdlTypeDef,
+    instance_name: &str,
+    data_ty: &syn::Type,
+    context: &IdlTypeContext,
+) -> Result<IdlTypeDef, IdlTypeError> {
+    let args = match data_ty {
+        syn::Type::Path(syn::TypePath { path, .. }) => match path.segments.last() {
+            Some(syn::PathSegment {
+                arguments: syn::PathArguments::AngleBracketed(args),
+                ..
+            }) => args
+                .args
                 .iter()
-                .map(|(ident, ty)| IdlInstructionArg::new(ident.to_string(), ty.into()))
-                .collect(),
-            discriminant: IdlInstructionDiscriminant::new(value.discriminant),
+                .filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(ty) => Some(IdlType::from_type(ty, context)),
+                    _ => None,
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.at(account.name.clone()))?,
+            _ => vec![],
+        },
+        _ => vec![],
+    };
+    let name = data_instance_idl_name(data_ty).unwrap_or_else(|| account.name.clone());
+    let mut instance = account.instantiate(name, args);
+    if let Some(config) = instance.config.as_mut() {
+        match config.table_name {
+            Some(_) => config.table_name = Some(instance_name.to_string()),
+            None => config.discrminator_str = Some(instance_name.to_string()),
+        }
+    }
+    Ok(instance)
+}
+
+fn invalid_data(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+/// Lists every type that can't be described in the IDL in a single error.
+fn type_errors(errors: Vec<IdlTypeError>) -> std::io::Error {
+    let errors: Vec<String> = errors.iter().map(|e| format!("  {}", e)).collect();
+    invalid_data(format!(
+        "The following types can't be described in the IDL:\n{}",
+        errors.join("\n")
+    ))
+}
+
+/// Makes sure every `IdlType::Defined` referenced in the IDL is defined in the
+/// user's crate.
+///
+/// Only the user's crate is parsed, so a type imported from another crate
+/// can't be described in the IDL. Rather than emitting an IDL clients can't
+/// decode, this fails listing each unresolved type and where it's used.
+fn check_defined_types(idl: &Idl) -> Result<(), String> {
+    let defined: Vec<&str> = idl
+        .accounts
+        .iter()
+        .chain(idl.types.iter())
+        .map(|t| t.name.as_str())
+        .collect();
+    let mut unresolved: Vec<String> = vec![];
+    let mut check = |ty: &IdlType, used_in: String| {
+        let mut names = vec![];
+        collect_defined_types(ty, &mut names);
+        for name in names {
+            if !defined.contains(&name.as_str()) {
+                unresolved.push(format!("`{}` (used in {})", name, used_in));
+            }
+        }
+    };
+    for type_def in idl.accounts.iter().chain(idl.types.iter()) {
+        match &type_def.idl_type {
+            IdlTypeDefType::Struct { fields } => fields.iter().for_each(|f| {
+                check(
+                    &f.field_data_type,
+                    format!("`{}.{}`", type_def.name, f.name),
+                )
+            }),
+            IdlTypeDefType::Enum { variants } => variants.iter().for_each(|v| match &v.fields {
+                Some(IdlTypeEnumFields::Named(fields)) => fields.iter().for_each(|f| {
+                    check(
+                        &f.field_data_type,
+                        format!("`{}::{}.{}`", type_def.name, v.name, f.name),
+                    )
+                }),
+                Some(IdlTypeEnumFields::Tuple(types)) => {
+                    types.iter().enumerate().for_each(|(i, ty)| {
+                        check(ty, format!("`{}::{}.{}`", type_def.name, v.name, i))
+                    })
+                }
+                None => (),
+            }),
+        }
+    }
+    for instruction in idl.instructions.iter() {
+        for arg in instruction.args.iter() {
+            check(
+                &arg.arg_type,
+                format!("instruction `{}` argument `{}`", instruction.name, arg.name),
+            )
         }
     }
+    match unresolved.is_empty() {
+        true => Ok(()),
+        false => Err(format!(
+            "The following types aren't defined in your crate, so they can't be added to the IDL: {}. Types defined in other crates can't be resolved - define them (or a copy of them) in your program's crate.",
+            unresolved.join(", ")
+        )),
+    }
+}
+
+/// Collects the names of all `IdlType::Defined` types within `ty`.
+fn collect_defined_types(ty: &IdlType, names: &mut Vec<String>) {
+    match ty {
+        IdlType::Defined(name) => names.push(name.clone()),
+        IdlType::Array(inner, _)
+        | IdlType::Option(inner)
+        | IdlType::Vec(inner)
+        | IdlType::HashSet(inner)
+        | IdlType::BTreeSet(inner) => collect_defined_types(inner, names),
+        IdlType::HashMap(key, value) | IdlType::BTreeMap(key, value) => {
+            collect_defined_types(key, names);
+            collect_defined_types(value, names);
+        }
+        IdlType::Tuple(types) => types.iter().for_each(|t| collect_defined_types(t, names)),
+        _ => (),
+    }
+}
+
+/// Builds the IDL for the Nautilus program whose `Cargo.toml` is located in
+/// `manifest_dir`, and writes it - along with its Anchor IDL counterpart and its
+/// TypeScript, Python and Rust bindings - to `out_dir`.
+pub fn write_idl(manifest_dir: &Path, out_dir: &str) -> std::io::Result<Idl> {
+    let idl = build_idl(manifest_dir)?;
+    idl.write_to_json(out_dir)?;
+    idl.write_to_anchor_json(out_dir)?;
+    idl.write_to_py(out_dir)?;
+    idl.write_to_rs(out_dir)?;
+    idl.write_to_ts(out_dir)?;
+    Ok(idl)
+}
+
+/// Builds the IDL for the Nautilus program whose `Cargo.toml` is located in
+/// `manifest_dir`, and writes its Go bindings to `out_dir`.
+pub fn write_go_bindings(manifest_dir: &Path, out_dir: &str) -> std::io::Result<Idl> {
+    let idl = build_idl(manifest_dir)?;
+    idl.write_to_go(out_dir)?;
+    Ok(idl)
+}
+
+/// Builds the IDL for the Nautilus program whose `Cargo.toml` is located in
+/// `manifest_dir`, and writes the SQLite and Postgres DDL for its tables to
+/// `out_dir`.
+pub fn write_sql(manifest_dir: &Path, out_dir: &str) -> std::io::Result<Idl> {
+    let idl = build_idl(manifest_dir)?;
+    idl.write_to_sql(out_dir)?;
+    Ok(idl)
+}
+
+/// Builds the IDL for the Nautilus program whose `Cargo.toml` is located in
+/// `manifest_dir`, and writes a JSON Schema for each of its accounts and types
+/// to `out_dir`.
+pub fn write_json_schemas(manifest_dir: &Path, out_dir: &str) -> std::io::Result<Idl> {
+    let idl = build_idl(manifest_dir)?;
+    idl.write_to_json_schema(out_dir)?;
+    Ok(idl)
+}
+
+/// Builds the IDL for the Nautilus program whose `Cargo.toml` is located in
+/// `manifest_dir`, and writes its Markdown and HTML reference documentation
+/// to `out_dir`.
+pub fn write_docs(manifest_dir: &Path, out_dir: &str) -> std::io::Result<Idl> {
+    let idl = build_idl(manifest_dir)?;
+    idl.write_to_docs(out_dir)?;
+    Ok(idl)
+}
+
+/// Helper for building the IDL from a program's `build.rs`.
+///
+/// Writes the IDL and its bindings to `target/idl` in the program's root and
+/// tells Cargo to only re-run the build script when the program's source or
+/// manifest changes.
+///
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     nautilus_syn::entry::idl::build_script().unwrap();
+/// }
+/// ```
+pub fn build_script() -> std::io::Result<Idl> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("`CARGO_MANIFEST_DIR` is not set. Is this being called from a `build.rs` ?");
+    let manifest_dir = Path::new(&manifest_dir);
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    write_idl(
+        manifest_dir,
+        &manifest_dir.join("target/idl").to_string_lossy(),
+    )
+}
+
+/// Converts each `NautilusEntrypointEnumVariant` of the `NautilusEntrypointEnum`
+/// into its IDL instruction, failing with every argument type that can't be
+/// described in the IDL.
+fn idl_instructions(
+    value: &NautilusEntrypointEnum,
+    context: &IdlTypeContext,
+) -> Result<Vec<IdlInstruction>, Vec<IdlTypeError>> {
+    let mut instructions = vec![];
+    let mut errors = vec![];
+    for variant in value.variants.iter() {
+        match idl_instruction(variant, context) {
+            Ok(instruction) => instructions.push(instruction),
+            Err(e) => errors.push(e),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(instructions),
+        false => Err(errors),
+    }
+}
+
+/// Converts the `NautilusEntrypointEnumVariant` into an IDL instruction.
+///
+/// This will use the configurations from the variant to build the necessary
+/// instruction in the IDL - including all required accounts.
+fn idl_instruction(
+    value: &NautilusEntrypointEnumVariant,
+    context: &IdlTypeContext,
+) -> Result<IdlInstruction, IdlTypeError> {
+    let mut name = value.variant_ident.to_string();
+    name.replace_range(..1, &name[..1].to_lowercase());
+    let args = value
+        .variant_args
+        .iter()
+        .map(|(ident, ty)| {
+            let arg_type = IdlType::from_type(ty, context)
+                .map_err(|e| e.at(format!("instruction `{}` argument `{}`", name, ident)))?;
+            let mut arg = IdlInstructionArg::new(ident.to_string(), arg_type);
+            arg.docs = arg_docs(&value.docs, &arg.name);
+            Ok(arg)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(IdlInstruction {
+        docs: value.docs.clone(),
+        accounts: value.required_accounts.iter().map(|a| a.into()).collect(),
+        args,
+        discriminant: IdlInstructionDiscriminant::new(value.discriminant),
+        name,
+    })
+}
+
+/// Finds an instruction argument's docs within its function's docs, where
+/// arguments are documented the way rustdoc suggests:
+///
+/// ```ignore
+/// /// # Arguments
+/// /// * `amount` - The number of tokens to mint.
+/// ```
+fn arg_docs(function_docs: &[String], arg_name: &str) -> Vec<String> {
+    let arg = format!("`{}`", arg_name);
+    function_docs
+        .iter()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            let line = line.strip_prefix("* ").or(line.strip_prefix("- "))?;
+            let doc = line.strip_prefix(&arg)?.trim_start();
+            let doc = doc.strip_prefix('-').or(doc.strip_prefix(':'))?.trim();
+            (!doc.is_empty()).then(|| doc.to_string())
+        })
+        .collect()
 }
 
 /// Straightforward conversion from a `RequiredAccount` into its IDL
@@ -54,20 +432,21 @@ impl From<&RequiredAccount> for IdlInstructionAccount {
     }
 }
 
-/// Straightforward conversion from a `NautilusObject` into its IDL type
-/// definition.
-impl From<&NautilusObject> for IdlTypeDef {
-    fn from(value: &NautilusObject) -> Self {
-        let mut default_type_def: IdlTypeDef = match &value.raw_type {
-            NautilusObjectRawType::Struct(raw) => raw.into(),
-            NautilusObjectRawType::Enum(raw) => raw.into(),
-        };
-        match &value.object_config {
-            Some(config) => default_type_def.config = Some(config.into()),
-            None => (),
-        }
-        default_type_def
+/// Converts a `NautilusObject` into its IDL type definition, resolving the
+/// types it uses from `context`.
+pub fn idl_type_def(
+    value: &NautilusObject,
+    context: &IdlTypeContext,
+) -> Result<IdlTypeDef, IdlTypeError> {
+    let mut default_type_def = match &value.raw_type {
+        NautilusObjectRawType::Struct(raw) => IdlTypeDef::from_struct(raw, context)?,
+        NautilusObjectRawType::Enum(raw) => IdlTypeDef::from_enum(raw, context)?,
+    };
+    if let Some(config) = &value.object_config {
+        default_type_def.config =
+            Some(idl_config(config, context).map_err(|e| e.at(default_type_def.name.clone()))?);
     }
+    Ok(default_type_def)
 }
 
 /// Converts the object configurations for a `NautilusObject` into IDL
@@ -76,64 +455,65 @@ impl From<&NautilusObject> for IdlTypeDef {
 /// These configurations are additional (and mostly optional) configs for the
 /// client to use to perform certain actions such as SQL queries and
 /// autoincrement.
-impl From<&NautilusObjectConfig> for IdlTypeDefNautilusConfig {
-    fn from(value: &NautilusObjectConfig) -> Self {
-        match value {
-            NautilusObjectConfig::RecordConfig {
-                table_name,
-                data_fields: _, // Unused in additional config.
-                autoincrement_enabled,
-                primary_key_ident,
-                primary_key_ty: _, // Unused, points to field name instead.
-                authorities,
-                default_instructions,
-            } => Self {
-                discrminator_str: None,
-                table_name: Some(table_name.clone()),
-                primary_key: Some(primary_key_ident.to_string()),
-                autoincrement: Some(*autoincrement_enabled),
-                authorities: authorities.iter().map(|a| a.to_string()).collect(),
-                default_instructions: default_instructions
-                    .iter()
-                    .map(|s| s.clone().into())
-                    .collect(),
-                seeds: vec![],
-            },
-            NautilusObjectConfig::AccountConfig {
-                discrminator_str,
-                data_fields: _, // Unused in additional config.
-                authorities,
-                seeds,
-            } => Self {
-                discrminator_str: Some(discrminator_str.clone()),
-                table_name: None,
-                primary_key: None,
-                autoincrement: None,
-                authorities: authorities.iter().map(|a| a.to_string()).collect(),
-                default_instructions: vec![],
-                seeds: seeds.iter().map(|s| s.into()).collect(),
-            },
-        }
-    }
+fn idl_config(
+    value: &NautilusObjectConfig,
+    context: &IdlTypeContext,
+) -> Result<IdlTypeDefNautilusConfig, IdlTypeError> {
+    Ok(match value {
+        NautilusObjectConfig::RecordConfig {
+            table_name,
+            autoincrement_enabled,
+            primary_key,
+            primary_key_ty: _, // Unused, points to field name instead.
+            authorities,
+            default_instructions,
+        } => IdlTypeDefNautilusConfig {
+            discrminator_str: None,
+            table_name: Some(table_name.clone()),
+            primary_key: Some(member_name(primary_key)),
+            autoincrement: Some(*autoincrement_enabled),
+            authorities: authorities.iter().map(member_name).collect(),
+            default_instructions: default_instructions
+                .iter()
+                .map(|s| s.clone().into())
+                .collect(),
+            seeds: vec![],
+        },
+        NautilusObjectConfig::AccountConfig {
+            discrminator_str,
+            authorities,
+            seeds,
+        } => IdlTypeDefNautilusConfig {
+            discrminator_str: Some(discrminator_str.clone()),
+            table_name: None,
+            primary_key: None,
+            autoincrement: None,
+            authorities: authorities.iter().map(member_name).collect(),
+            default_instructions: vec![],
+            seeds: seeds
+                .iter()
+                .map(|s| idl_seed(s, context))
+                .collect::<Result<_, _>>()?,
+        },
+    })
 }
 
 /// Converts a `Seed` from the `syn` crate into an `IdlSeed` from the `idl`
 /// crate.
-impl From<&Seed> for IdlSeed {
-    fn from(value: &Seed) -> Self {
-        match value {
-            Seed::Lit { value } => IdlSeed::Lit {
-                value: value.clone(),
-            },
-            Seed::Field { ident } => IdlSeed::Field {
-                key: ident.to_string(),
-            },
-            Seed::Param { ident, ty } => IdlSeed::Param {
-                key: ident.to_string(),
-                value: ty.into(),
-            },
-        }
-    }
+fn idl_seed(value: &Seed, context: &IdlTypeContext) -> Result<IdlSeed, IdlTypeError> {
+    Ok(match value {
+        Seed::Lit { value } => IdlSeed::Lit {
+            value: value.clone(),
+        },
+        Seed::Field { ident } => IdlSeed::Field {
+            key: ident.to_string(),
+        },
+        Seed::Param { ident, ty } => IdlSeed::Param {
+            key: ident.to_string(),
+            value: IdlType::from_type(ty, context)
+                .map_err(|e| e.at(format!("seed parameter `{}`", ident)))?,
+        },
+    })
 }
 
 /// Converts a `DefaultInstruction` from the `syn` crate into an
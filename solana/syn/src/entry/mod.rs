@@ -1,23 +1,19 @@
-//! Builds the entrypoint, processor, and IDL for a Nautilus program.
+//! Builds the entrypoint and processor for a Nautilus program, and - separately
+//! from macro expansion - its IDL.
+pub mod crate_context;
 pub mod entry_enum;
 pub mod entry_variant;
 pub mod idl;
 pub mod parser;
 pub mod required_account;
 
-use nautilus_idl::{
-    converters::{py::PythonIdlWrite, ts::TypeScriptIdlWrite},
-    idl_metadata::IdlMetadata,
-    Idl,
-};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{parse::Parse, Item, ItemFn, ItemMod};
 
-use self::{
-    entry_enum::NautilusEntrypointEnum,
-    parser::{is_use_super_star, parse_crate_context, parse_manifest},
-};
+use crate::object::source::source_nautilus_objects;
+
+use self::{entry_enum::NautilusEntrypointEnum, parser::split_module_items};
 
 /// The struct containing the parsed contents required to convert the user's
 /// annotated module into the proper program configurations.
@@ -28,103 +24,75 @@ use self::{
 /// * `declared_functions`: The user's declared functions as-is.
 /// * `processor`: The program's processor, built into a function
 ///   `process_instruction`.
+/// * `data_instances`: The `NautilusDataInstance` implementations naming each
+///   instantiation of a generic `Table` or `State` type the functions use.
 #[derive(Debug)]
 pub struct NautilusEntrypoint {
     pub leftover_content: Vec<Item>,
     pub instruction_enum: TokenStream,
     pub declared_functions: Vec<ItemFn>,
     pub processor: TokenStream,
+    pub data_instances: TokenStream,
 }
 
-impl From<ItemMod> for NautilusEntrypoint {
+impl TryFrom<ItemMod> for NautilusEntrypoint {
+    type Error = syn::Error;
+
     /// Converts the user's annotated module into the `NautilusEntrypoint`
     /// struct.
     ///
-    /// All of the work to build out the entrypoint, processor, and IDL for a
+    /// All of the work to build out the entrypoint and processor for a
     /// Nautilus program is done here. During this conversion, the module
     /// (`ItemMod`) is broken down into components, and the functions declared
     /// by the user are extracted and used to build out various child
     /// structs such as `NautilusEntrypointEnum` and
     /// `NautilusEntrypointEnumVariant`.
     ///
-    /// Using information extracted from the user's manifest (`Cargo.toml`) and
-    /// the entirety of their crate, this function builds the
-    /// `NautilusEntrypointEnum`, which basically dissolves to the required
-    /// components.
+    /// This conversion only generates code: it doesn't read the user's
+    /// manifest or crate, and it doesn't write the IDL. Nautilus objects are
+    /// resolved from Nautilus's own source objects and the user's function
+    /// signatures. The IDL is built separately with `idl::build_idl`.
+    ///
+    /// Fails if any of the declared functions' signatures can't be parsed.
     ///
     /// For more specific information see the documentation for
     /// `NautilusEntrypointEnum` and `NautilusEntrypointEnumVariant`.
-    fn from(value: ItemMod) -> Self {
-        let mut declared_functions = vec![];
+    fn try_from(value: ItemMod) -> syn::Result<Self> {
+        let (leftover_content, declared_functions) = split_module_items(value);
 
-        let leftover_content: Vec<Item> = value
-            .content
-            .clone()
-            .unwrap()
-            .1
+        let nautilus_enum =
+            &NautilusEntrypointEnum::new(source_nautilus_objects(), declared_functions.clone())?;
+        let (instruction_enum, processor) = nautilus_enum.into();
+        let data_instances = nautilus_enum
+            .data_instances()
             .into_iter()
-            .filter_map(|item| match is_use_super_star(&item) {
-                true => None,
-                false => match item {
-                    Item::Fn(input_fn) => {
-                        declared_functions.push(input_fn);
-                        None
+            .map(|(name, data_ty)| {
+                quote! {
+                    impl nautilus::NautilusDataInstance for #data_ty {
+                        const NAME: &'static str = #name;
                     }
-                    _ => Some(item),
-                },
+                }
             })
             .collect();
 
-        let (crate_version, crate_name) = parse_manifest();
-        let (nautilus_objects, idl_accounts, idl_types) = parse_crate_context();
-
-        let nautilus_enum =
-            &NautilusEntrypointEnum::new(nautilus_objects, declared_functions.clone());
-        let (instruction_enum, processor, idl_instructions) = nautilus_enum.into();
-
-        let idl = Idl::new(
-            crate_version,
-            crate_name,
-            idl_instructions,
-            idl_accounts,
-            idl_types,
-            IdlMetadata::new_with_no_id(),
-        );
-        match idl.write_to_json("./target/idl") {
-            Ok(()) => (),
-            Err(e) => println!("[ERROR]: Error writing IDL to JSON file: {:#?}", e),
-        };
-        match idl.write_to_py("./target/idl") {
-            Ok(()) => (),
-            Err(e) => println!(
-                "[ERROR]: Error writing Python bindings to .py file: {:#?}",
-                e
-            ),
-        };
-        match idl.write_to_ts("./target/idl") {
-            Ok(()) => (),
-            Err(e) => println!(
-                "[ERROR]: Error writing TypeScript bindings to .ts file: {:#?}",
-                e
-            ),
-        };
-
-        Self {
+        Ok(Self {
             leftover_content,
             instruction_enum,
             declared_functions,
             processor,
-        }
+            data_instances,
+        })
     }
 }
 
 impl Parse for NautilusEntrypoint {
     /// Parses the user's defined module into a `syn::ItemMod`, which is an
-    /// already pre-fabricated function, and calls Into<NautilusEntrypoint>
-    /// to fire the `from(value: ItemMod)` in the trait implementation `impl
-    /// From<ItemMod> for NautilusEntrypoint`, which does all the magic.
+    /// already pre-fabricated function, and calls TryInto<NautilusEntrypoint>
+    /// to fire the `try_from(value: ItemMod)` in the trait implementation
+    /// `impl TryFrom<ItemMod> for NautilusEntrypoint`, which does all the
+    /// magic.
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        Ok(ItemMod::parse(input)?.into())
+        ItemMod::parse(input)?.try_into()
     }
 }
 
@@ -144,10 +112,12 @@ impl From<&NautilusEntrypoint> for TokenStream {
         let instruction_enum = &ast.instruction_enum;
         let declared_functions = &ast.declared_functions;
         let processor = &ast.processor;
+        let data_instances = &ast.data_instances;
 
         quote! {
             #instruction_enum
             #processor
+            #data_instances
             #(#declared_functions)*
             #(#leftover_content)*
         }
@@ -1,104 +1,296 @@
 //! Parses information about the user's entire crate.
+use std::path::{Path, PathBuf};
+
 use cargo_toml::Manifest;
 use convert_case::{Case::Pascal, Casing};
-use nautilus_idl::idl_type_def::IdlTypeDef;
+use nautilus_idl::{idl_constant::IdlConstant, idl_type::IdlTypeError, idl_type_def::IdlTypeDef};
 use proc_macro2::Span;
 use quote::quote;
-use shank_macro_impl::krate::CrateContext;
-use syn::{FnArg, Ident, Item, ItemFn, Pat, PathArguments, Type, TypePath, UseTree};
+use syn::{
+    Attribute, FnArg, Ident, Item, ItemFn, ItemMod, Pat, PathArguments, Type, TypePath, UseTree,
+    Visibility,
+};
 use syn::{Meta, NestedMeta};
 
-use crate::object::source::source_nautilus_objects;
+use crate::object::source::{source_nautilus_names, source_nautilus_objects};
 use crate::object::ObjectEntryConfig;
 use crate::object::{NautilusObject, NautilusObjectType};
 
-use super::entry_variant::CallContext;
+use super::{crate_context::CrateContext, entry_variant::CallContext, idl::idl_type_def};
+
+/// Parses metadata from the user's `Cargo.toml`, located in `manifest_dir`.
+///
+/// If the package inherits any of its fields from a workspace (ie.
+/// `version.workspace = true`), the workspace's `Cargo.toml` is located and
+/// those fields are resolved from its `[workspace.package]` table.
+///
+/// Fails if the manifest can't be read or doesn't describe a package with a
+/// version.
+pub fn parse_manifest(manifest_dir: &Path) -> std::io::Result<(String, String)> {
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    let content = std::fs::read(&manifest_path).map_err(|e| {
+        std::io::Error::new(
+            e.kind(),
+            format!(
+                "Failed to detect `Cargo.toml` in `{}`: {}",
+                manifest_dir.display(),
+                e
+            ),
+        )
+    })?;
+    let mut manifest = Manifest::from_slice(&content).map_err(|e| {
+        invalid_manifest(format!(
+            "Failed to parse `Cargo.toml`. Is your Cargo.toml file structured properly ? {}",
+            e
+        ))
+    })?;
+    let workspace = find_workspace_manifest(manifest_dir, &manifest);
+    manifest
+        .complete_from_path_and_workspace(
+            &manifest_path,
+            workspace.as_ref().map(|(ws, ws_dir)| (ws, ws_dir.as_path())),
+        )
+        .map_err(|e| invalid_manifest(format!("Failed to resolve workspace inheritance in `Cargo.toml`. Does your workspace's `Cargo.toml` define `[workspace.package]` ? {}", e)))?;
+    let package = manifest.package.ok_or_else(|| {
+        invalid_manifest(
+            "Failed to find `[package]` in `Cargo.toml`. Is your Cargo.toml file structured properly ?"
+                .to_string(),
+        )
+    })?;
+    let crate_version = package.version.get().map_err(|_| {
+        invalid_manifest(
+            "Failed to parse crate version from `Cargo.toml`. Did you provide one ?".to_string(),
+        )
+    })?;
+    Ok((String::from(crate_version), package.name))
+}
 
-/// Parses metadata from the user's `Cargo.toml`
-pub fn parse_manifest() -> (String, String) {
-    let manifest = Manifest::from_path("Cargo.toml")
-        .expect("Failed to detect `Cargo.toml`. Is your Cargo.toml file structured properly ?");
-    let package = manifest
-        .package
-        .expect("Failed to parse `Cargo.toml`. Is your Cargo.toml file structured properly ?");
-    let crate_version = package
-        .version
-        .get()
-        .expect("Failed to parse crate version from `Cargo.toml`. Did you provide one ?");
-    (String::from(crate_version), package.name)
+fn invalid_manifest(message: String) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
 }
 
-/// Uses Metaplex's `shank_macro_impl` to parse all of the contents of the
-/// user's crate.
+/// Locates the `Cargo.toml` of the workspace the user's package belongs to.
 ///
-/// It uses this information to build the rest of the IDL (accounts and types),
-/// and return all defined Nautilus objects annotated with a Nautilus derive
-/// macro.
+/// Uses `package.workspace` if it's set, otherwise walks up from
+/// `manifest_dir` until it finds a manifest with a `[workspace]` table.
+/// Manifests of other (non-workspace) packages along the way are skipped.
+fn find_workspace_manifest(
+    manifest_dir: &Path,
+    manifest: &Manifest,
+) -> Option<(Manifest, PathBuf)> {
+    if manifest.workspace.is_some() {
+        return None;
+    }
+    let manifest_dir = manifest_dir
+        .canonicalize()
+        .unwrap_or_else(|_| manifest_dir.to_path_buf());
+    let read_workspace = |dir: &Path| {
+        let content = std::fs::read(dir.join("Cargo.toml")).ok()?;
+        let ws = Manifest::from_slice(&content).ok()?;
+        ws.workspace.is_some().then(|| (ws, dir.to_path_buf()))
+    };
+    match manifest.package.as_ref().and_then(|p| p.workspace.as_ref()) {
+        Some(ws_path) => read_workspace(&manifest_dir.join(ws_path)),
+        None => manifest_dir.ancestors().skip(1).find_map(read_workspace),
+    }
+}
+
+/// Everything found in the user's crate by `parse_crate_context`.
 ///
-/// Consider the return type: (`Vec<NautilusObject>`, `Vec<IdlTypeDef>`,
-/// `Vec<IdlTypeDef>`):
-/// * `Vec<NautilusObject>`: All Nautilus objects defined in the crate using
+/// * `nautilus_objects`: All Nautilus objects defined in the crate using
 ///   Nautilus derive macros.
-/// * `Vec<IdlTypeDef>` (first): All accounts for the IDL (Nautilus objects).
-/// * `Vec<IdlTypeDef>` (second): All types for the IDL (non-Nautilus objects
-///   defined in the crate).
-pub fn parse_crate_context() -> (Vec<NautilusObject>, Vec<IdlTypeDef>, Vec<IdlTypeDef>) {
-    let root = std::env::current_dir().unwrap().join("src/lib.rs");
-    let crate_context = CrateContext::parse(root).expect(
-        "Failed to detect `src/lib.rs`. Are you sure you've built your program with `--lib` ?",
-    );
+/// * `idl_accounts`: All accounts for the IDL (Nautilus objects), with their
+///   size when it's fixed.
+/// * `idl_types`: All types for the IDL (non-Nautilus objects defined in the
+///   crate).
+/// * `idl_constants`: All `pub const` items for the IDL.
+pub struct ParsedCrate {
+    pub nautilus_objects: Vec<NautilusObject>,
+    pub idl_accounts: Vec<IdlTypeDef>,
+    pub idl_types: Vec<IdlTypeDef>,
+    pub idl_constants: Vec<IdlConstant>,
+}
 
+/// Parses all of the contents of the user's crate, using the crate's
+/// `CrateContext`.
+///
+/// It uses this information to build the rest of the IDL (accounts, types and
+/// constants, along with their doc comments), and return all defined Nautilus
+/// objects annotated with a Nautilus derive macro.
+///
+/// Fails with every type used in the crate that can't be described in the IDL.
+pub fn parse_crate_context(crate_context: &CrateContext) -> Result<ParsedCrate, Vec<IdlTypeError>> {
+    let idl_type_context = crate_context.idl_type_context();
     let mut idl_accounts: Vec<IdlTypeDef> = vec![];
     let mut idl_types: Vec<IdlTypeDef> = vec![];
+    let mut errors: Vec<IdlTypeError> = vec![];
+    let mut push = |type_def: Result<IdlTypeDef, IdlTypeError>, type_defs: &mut Vec<IdlTypeDef>| {
+        match type_def {
+            Ok(type_def) => type_defs.push(type_def),
+            Err(e) => errors.push(e),
+        }
+    };
 
     let mut nautilus_objects: Vec<NautilusObject> = crate_context
         .structs()
-        .filter_map(|s| {
-            if let Some(attr) = s.attrs.iter().find(|attr| attr.path.is_ident("derive")) {
-                if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
-                    let matched_macro =
-                        meta_list
-                            .nested
-                            .iter()
-                            .find_map(|nested_meta| match nested_meta {
-                                NestedMeta::Meta(Meta::Path(path)) => {
-                                    if path.is_ident("Table") {
-                                        Some(NautilusObjectType::Record)
-                                    } else if path.is_ident("State") {
-                                        Some(NautilusObjectType::Account)
-                                    } else {
-                                        None
-                                    }
-                                }
-                                _ => None,
-                            });
-
-                    if let Some(nautilus_ty) = matched_macro {
-                        let nautilus_obj: NautilusObject =
-                            NautilusObject::from_item_struct(s.clone(), nautilus_ty);
-                        let i = &nautilus_obj;
-                        idl_accounts.push(i.into());
-                        return Some(nautilus_obj);
-                    }
-                }
+        .filter_map(|s| match derived_nautilus_type(&s.attrs) {
+            Some(nautilus_ty) => {
+                let nautilus_obj = NautilusObject::from_item_struct(s.clone(), nautilus_ty);
+                push(
+                    idl_type_def(&nautilus_obj, &idl_type_context),
+                    &mut idl_accounts,
+                );
+                Some(nautilus_obj)
+            }
+            None => {
+                push(
+                    IdlTypeDef::from_struct(s, &idl_type_context),
+                    &mut idl_types,
+                );
+                None
             }
-            idl_types.push(s.into());
-            None
         })
         .collect();
 
     nautilus_objects.extend(source_nautilus_objects());
 
-    crate_context.enums().for_each(|e| idl_types.push(e.into()));
+    crate_context
+        .enums()
+        .for_each(|e| match derived_nautilus_type(&e.attrs) {
+            Some(nautilus_ty) => {
+                let nautilus_obj = NautilusObject::from_item_enum(e.clone(), nautilus_ty);
+                push(
+                    idl_type_def(&nautilus_obj, &idl_type_context),
+                    &mut idl_accounts,
+                );
+                nautilus_objects.push(nautilus_obj);
+            }
+            None => push(IdlTypeDef::from_enum(e, &idl_type_context), &mut idl_types),
+        });
+
+    // Constants are never serialized, so any the IDL can't describe are left
+    // out rather than failing the build.
+    let idl_constants = crate_context
+        .consts()
+        .filter(|c| matches!(c.vis, Visibility::Public(_)))
+        .filter_map(|c| IdlConstant::from_const(c, &idl_type_context).ok())
+        .collect();
+
+    let all_types: Vec<IdlTypeDef> = idl_accounts
+        .iter()
+        .chain(idl_types.iter())
+        .cloned()
+        .collect();
+    for account in idl_accounts.iter_mut() {
+        account.size = account.account_size(&all_types);
+    }
+
+    match errors.is_empty() {
+        true => Ok(ParsedCrate {
+            nautilus_objects,
+            idl_accounts,
+            idl_types,
+            idl_constants,
+        }),
+        false => Err(errors),
+    }
+}
+
+/// Checks a type's attributes for `#[derive(Table)]` or `#[derive(State)]`,
+/// returning the type of Nautilus object it's derived as.
+fn derived_nautilus_type(attrs: &[Attribute]) -> Option<NautilusObjectType> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .find_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => {
+                meta_list
+                    .nested
+                    .iter()
+                    .find_map(|nested_meta| match nested_meta {
+                        NestedMeta::Meta(Meta::Path(path)) => {
+                            if path.is_ident("Table") {
+                                Some(NautilusObjectType::Record)
+                            } else if path.is_ident("State") {
+                                Some(NautilusObjectType::Account)
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    })
+            }
+            _ => None,
+        })
+}
+
+/// Locates the module annotated with `#[nautilus]` in the user's crate and
+/// returns the functions declared inside of it.
+///
+/// These are the same functions the `#[nautilus]` macro turns into the
+/// program's instructions, so they can be used to build the IDL without
+/// expanding the macro.
+pub fn parse_nautilus_module(crate_context: &CrateContext) -> std::io::Result<Vec<ItemFn>> {
+    let nautilus_module = crate_context
+        .modules()
+        .find(|item_mod| {
+            item_mod.attrs.iter().any(|attr| {
+                attr.path
+                    .segments
+                    .last()
+                    .map_or(false, |segment| segment.ident == "nautilus")
+            })
+        })
+        .cloned()
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Failed to find a module annotated with `#[nautilus]` in your crate.",
+            )
+        })?;
+    Ok(split_module_items(nautilus_module).1)
+}
 
-    (nautilus_objects, idl_accounts, idl_types)
+/// Splits the contents of the user's annotated module into the functions they
+/// declared and any leftover content.
+///
+/// Consider the return type: (`Vec<Item>`, `Vec<ItemFn>`):
+/// * `Vec<Item>`: Any declarations in the module that aren't named functions,
+///   minus `use super::*;`.
+/// * `Vec<ItemFn>`: The user's declared functions as-is.
+pub fn split_module_items(module: ItemMod) -> (Vec<Item>, Vec<ItemFn>) {
+    let mut declared_functions = vec![];
+    let leftover_content: Vec<Item> = module
+        .content
+        .unwrap()
+        .1
+        .into_iter()
+        .filter_map(|item| match is_use_super_star(&item) {
+            true => None,
+            false => match item {
+                Item::Fn(input_fn) => {
+                    declared_functions.push(input_fn);
+                    None
+                }
+                _ => Some(item),
+            },
+        })
+        .collect();
+    (leftover_content, declared_functions)
 }
 
+/// An instruction parsed from a user's defined function - see
+/// `parse_function`.
+pub type ParsedFunction = (Ident, Vec<(Ident, Type)>, Ident, Vec<CallContext>);
+
 /// Parses all required information from a user's defined function.
 ///
-/// All Nautilus objects - both from the source crate itself and the user's
-/// crate - are provided as a parameter in order to decipher whether or not a
-/// function's parameter is a Nautilus object.
+/// Known Nautilus objects - from the source crate itself and, when building the
+/// IDL, the user's crate - are provided as a parameter in order to decipher
+/// whether or not a function's parameter is a Nautilus object. A user-defined
+/// type that isn't in this list but is wrapped in `Record<'_, T>` or
+/// `Account<'_, T>` is inferred from the function signature alone, which is how
+/// the `#[nautilus]` macro resolves objects without parsing the user's crate.
 ///
 /// Consider the return type: (`Ident`, `Vec<(Ident, Type)>`, `Ident`,
 /// `Vec<CallContext>`):
@@ -113,10 +305,13 @@ pub fn parse_crate_context() -> (Vec<NautilusObject>, Vec<IdlTypeDef>, Vec<IdlTy
 ///
 /// You can see these return values are directly used to build a
 /// `NautilusEntrypointEnumVariant`.
+///
+/// Fails if a parameter isn't a plain `name: Type` pair, or its type can't be
+/// parsed.
 pub fn parse_function(
     nautilus_objects: &Vec<NautilusObject>,
     function: ItemFn,
-) -> (Ident, Vec<(Ident, Type)>, Ident, Vec<CallContext>) {
+) -> syn::Result<ParsedFunction> {
     let variant_ident = Ident::new(
         &function.sig.ident.to_string().to_case(Pascal),
         Span::call_site(),
@@ -130,32 +325,73 @@ pub fn parse_function(
         .map(|input| match input {
             FnArg::Typed(arg) => match *arg.pat {
                 Pat::Ident(ref pat_ident) => {
-                    let (type_string, is_create, is_signer, is_mut) = parse_type(&arg.ty);
-                    for obj in nautilus_objects {
-                        if obj.ident == &type_string {
-                            let mut nautilus_obj = obj.clone();
+                    let (type_string, is_create, is_signer, is_mut) = parse_type(&arg.ty)?;
+                    // User-defined objects are only Nautilus objects when wrapped in
+                    // `Record<'_, T>`, `Account<'_, T>` or `ZeroCopyAccount<'_, T>` -
+                    // otherwise they're plain instruction arguments.
+                    let object_type = parse_object_type(&arg.ty);
+                    let matched_obj = match &object_type {
+                        Some((ident, _, nautilus_ty)) => Some(
+                            nautilus_objects
+                                .iter()
+                                .find(|obj| &obj.ident == ident)
+                                .cloned()
+                                .unwrap_or_else(|| {
+                                    NautilusObject::from_signature(
+                                        ident.clone(),
+                                        nautilus_ty.clone(),
+                                    )
+                                }),
+                        ),
+                        None => nautilus_objects
+                            .iter()
+                            .find(|obj| {
+                                obj.ident == &type_string
+                                    && source_nautilus_names().contains(&type_string)
+                            })
+                            .cloned(),
+                    };
+                    match matched_obj {
+                        Some(mut nautilus_obj) => {
                             nautilus_obj.entry_config = Some(ObjectEntryConfig {
                                 arg_ident: pat_ident.ident.clone(),
+                                data_ty: object_type.map(|(_, data_ty, _)| data_ty),
                                 is_create,
                                 is_signer,
                                 is_mut,
                             });
-                            return CallContext::Nautilus(nautilus_obj);
+                            Ok(CallContext::Nautilus(nautilus_obj))
+                        }
+                        None => {
+                            variant_args.push((pat_ident.ident.clone(), *arg.ty.clone()));
+                            Ok(CallContext::Arg(pat_ident.ident.clone()))
                         }
                     }
-                    variant_args.push((pat_ident.ident.clone(), *arg.ty.clone()));
-                    return CallContext::Arg(pat_ident.ident.clone());
                 }
-                _ => panic!("Error parsing function."),
+                ref pat => Err(syn::Error::new_spanned(
+                    pat,
+                    format!(
+                        "Error parsing function `{}`: parameters must be named, ie. `name: Type`.",
+                        call_ident
+                    ),
+                )),
             },
-            _ => panic!("Error parsing function."),
+            receiver => Err(syn::Error::new_spanned(
+                receiver,
+                format!(
+                    "Error parsing function `{}`: instructions can't take `self`.",
+                    call_ident
+                ),
+            )),
         })
-        .collect();
-    (variant_ident, variant_args, call_ident, call_context)
+        .collect::<syn::Result<_>>()?;
+    Ok((variant_ident, variant_args, call_ident, call_context))
 }
 
 /// Parses the type of a parameter of a user's defined function signature.
-pub fn parse_type(ty: &Type) -> (String, bool, bool, bool) {
+///
+/// Fails if a wrapper such as `Mut<..>` is missing the type it wraps.
+pub fn parse_type(ty: &Type) -> syn::Result<(String, bool, bool, bool)> {
     let mut is_create = false;
     let mut is_signer = false;
     let mut is_mut = false;
@@ -174,7 +410,10 @@ pub fn parse_type(ty: &Type) -> (String, bool, bool, bool) {
             } else if segment.ident == "Mut" {
                 is_mut = true;
                 (child_type, is_pda) = derive_child_type(&segment.arguments)
-            } else if segment.ident == "Record" || segment.ident == "Account" {
+            } else if segment.ident == "Record"
+                || segment.ident == "Account"
+                || segment.ident == "ZeroCopyAccount"
+            {
                 is_pda = true;
                 (child_type, _) = derive_child_type(&segment.arguments)
             }
@@ -188,14 +427,136 @@ pub fn parse_type(ty: &Type) -> (String, bool, bool, bool) {
         if let Some(t) = &child_type {
             format!("{}", quote! { #t })
         } else {
-            panic!("Could not parse provided type: {:#?}", ty);
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!("Could not parse provided type: `{}`", quote! { #ty }),
+            ));
         }
     } else {
         let mut new_t = ty.clone();
         remove_lifetimes_from_type(&mut new_t);
         format!("{}", quote! { #new_t })
     };
-    (type_name, is_create, is_signer, is_mut)
+    Ok((type_name, is_create, is_signer, is_mut))
+}
+
+/// Determines whether a parameter's type wraps a user-defined type in
+/// `Record<'_, T>`, `Account<'_, T>` or `ZeroCopyAccount<'_, T>`, looking
+/// through any `Create<'_, T>`, `Signer<T>` or `Mut<T>` wrappers.
+///
+/// Returns the identifier of `T`, `T` itself as written (including any generic
+/// arguments, ie. `Vault<u64>`) and the type of Nautilus object it represents.
+pub fn parse_object_type(ty: &Type) -> Option<(Ident, Type, NautilusObjectType)> {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            let nautilus_ty = if segment.ident == "Record" {
+                NautilusObjectType::Record
+            } else if segment.ident == "Account" {
+                NautilusObjectType::Account
+            } else if segment.ident == "ZeroCopyAccount" {
+                NautilusObjectType::ZeroCopyAccount
+            } else if segment.ident == "Create"
+                || segment.ident == "Signer"
+                || segment.ident == "Mut"
+            {
+                return first_type_argument(&segment.arguments).and_then(parse_object_type);
+            } else {
+                return None;
+            };
+            if let Some(data_ty @ Type::Path(TypePath { path, .. })) =
+                first_type_argument(&segment.arguments)
+            {
+                return path
+                    .segments
+                    .last()
+                    .map(|inner| (inner.ident.clone(), data_ty.clone(), nautilus_ty));
+            }
+        }
+    }
+    None
+}
+
+/// The name of one instantiation of a generic `Table` or `State` type `T`, as
+/// written in a function signature - ie. `vault<u64>` for `Vault<u64>` - or
+/// `None` if `T` has no type or const arguments.
+///
+/// The name is `T`'s name in lowercase followed by its arguments, without
+/// whitespace or paths, so `Vault<std::string::String>` and `Vault<String>`
+/// are named alike. It's the instantiation's table name or discriminator
+/// string, given to it by the `NautilusDataInstance` implementations
+/// `#[nautilus]` generates.
+pub fn data_instance_name(data_ty: &Type) -> Option<String> {
+    let (ident, args) = data_instance_arguments(data_ty)?;
+    Some(format!(
+        "{}<{}>",
+        ident.to_string().to_lowercase(),
+        args.join(",")
+    ))
+}
+
+/// The name the IDL gives one instantiation of a generic `Table` or `State`
+/// type `T`: `T`'s name followed by its arguments in Pascal case, ie.
+/// `VaultU64` for `Vault<u64>` - or `None` if `T` isn't generic.
+pub fn data_instance_idl_name(data_ty: &Type) -> Option<String> {
+    let (ident, args) = data_instance_arguments(data_ty)?;
+    let args: String = args
+        .iter()
+        .flat_map(|arg| arg.split(|c: char| !c.is_alphanumeric() && c != '_'))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_case(Pascal))
+        .collect();
+    Some(format!("{}{}", ident, args))
+}
+
+/// The identifier of `T` and its type and const arguments, without whitespace
+/// or paths - or `None` if it has none.
+fn data_instance_arguments(data_ty: &Type) -> Option<(&Ident, Vec<String>)> {
+    let segment = match data_ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last()?,
+        _ => return None,
+    };
+    let args: Vec<String> = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(without_paths(&quote! { #ty })),
+                syn::GenericArgument::Const(expr) => Some(without_paths(&quote! { #expr })),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    (!args.is_empty()).then(|| (&segment.ident, args))
+}
+
+/// Renders tokens without whitespace, dropping the path leading up to each
+/// name, ie. `Option<String>` for `Option < std :: string :: String >`.
+fn without_paths(tokens: &proc_macro2::TokenStream) -> String {
+    let tokens: String = tokens
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let mut parts: Vec<&str> = tokens.split("::").collect();
+    let last = parts.pop().unwrap_or_default();
+    parts
+        .into_iter()
+        .map(|part| part.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_'))
+        .chain(std::iter::once(last))
+        .collect()
+}
+
+/// Returns the first type argument of a compound object with angle-bracket
+/// generic arguments, ie: `T` in `Object<'_, T>`.
+fn first_type_argument(arguments: &PathArguments) -> Option<&Type> {
+    if let PathArguments::AngleBracketed(args) = arguments {
+        return args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+    }
+    None
 }
 
 /// Derives the child type of a compound object with angle-bracket generic
@@ -208,7 +569,10 @@ fn derive_child_type(arguments: &PathArguments) -> (Option<Type>, bool) {
                 remove_lifetimes_from_type(&mut new_ty);
                 if let Type::Path(TypePath { path, .. }) = &new_ty {
                     if let Some(segment) = path.segments.first() {
-                        if segment.ident == "Record" || segment.ident == "Account" {
+                        if segment.ident == "Record"
+                            || segment.ident == "Account"
+                            || segment.ident == "ZeroCopyAccount"
+                        {
                             return derive_child_type(&segment.arguments);
                         }
                     }
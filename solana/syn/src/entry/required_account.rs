@@ -259,7 +259,7 @@ impl RequiredAccount {
             match nautilus_ty {
                 Some(t) => match t {
                     NautilusObjectType::Record => ObjectType::Record(is_mut, vec![]), // TODO: PDA authorities not supported yet
-                    NautilusObjectType::Account => ObjectType::Account(is_mut, vec![]), // TODO: PDA authorities not supported yet
+                    NautilusObjectType::Account | NautilusObjectType::ZeroCopyAccount => ObjectType::Account(is_mut, vec![]), // TODO: PDA authorities not supported yet
                 },
                 None => panic!("Could not match object type: {}. Was it annotated with a Nautilus #[derive(..)] macro?", ty_name)
             }
@@ -1,40 +1,221 @@
 //! Spawns the tokens for the required trait implementations for an annotated
-//! struct.
+//! struct or enum.
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{token::Colon, Fields, FnArg, Ident, Pat, PatIdent, PatType, Type};
+use syn::{parse_quote, Fields, FnArg, Generics, Ident, Index, Member, Type, WherePredicate};
 
-use super::seeds::Seed;
+use super::{seeds::Seed, NautilusObjectRawType};
 
-/// Generates tokens to implement `Clone` on a struct.
-pub fn impl_clone(ident: &Ident, fields: &Fields) -> TokenStream {
-    let clone_constructors = fields.iter().map(|f| {
-        let ident = &f.ident.as_ref().unwrap();
-        quote! { #ident: ::core::clone::Clone::clone(&self.#ident) }
+/// A single field of a struct or enum variant.
+///
+/// * `member`: How the field is accessed - its identifier, or its index if
+///   it's a tuple field.
+/// * `binding`: An identifier to bind the field's value to, such as in a
+///   function signature or a pattern.
+/// * `ty`: The field's type.
+struct DataField {
+    member: Member,
+    binding: Ident,
+    ty: Type,
+}
+
+impl DataField {
+    /// Binds the field in a pattern: `name` or `0: field_0`. Named fields use
+    /// the shorthand, which rustc otherwise warns about in the user's crate.
+    fn pattern(&self) -> TokenStream {
+        let (member, binding) = (&self.member, &self.binding);
+        match member {
+            Member::Named(_) => quote! { #binding },
+            Member::Unnamed(_) => quote! { #member: #binding },
+        }
+    }
+}
+
+/// Resolves the members and bindings for a set of fields.
+///
+/// Tuple fields are bound to `field_0`, `field_1`, etc., and accessed by their
+/// index. Since `Self { 0: .. }` is valid Rust, every constructor and pattern
+/// can be written the same way regardless of the kind of fields.
+fn data_fields(fields: &Fields) -> Vec<DataField> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => DataField {
+                member: Member::Named(ident.clone()),
+                binding: ident.clone(),
+                ty: f.ty.clone(),
+            },
+            None => DataField {
+                member: Member::Unnamed(Index::from(i)),
+                binding: Ident::new(&format!("field_{}", i), Span::call_site()),
+                ty: f.ty.clone(),
+            },
+        })
+        .collect()
+}
+
+/// Builds the predicates for a `where` clause: any predicates the user
+/// declared, plus `bound` on the type of every field of the object.
+///
+/// Bounding the field types (rather than the type parameters) mirrors what the
+/// Borsh derive macros do, and works for both generic and non-generic objects.
+fn where_predicates(raw_type: &NautilusObjectRawType, bound: TokenStream) -> Vec<WherePredicate> {
+    let mut predicates: Vec<WherePredicate> = match &raw_type.generics().where_clause {
+        Some(where_clause) => where_clause.predicates.iter().cloned().collect(),
+        None => vec![],
+    };
+    raw_type.all_fields().into_iter().for_each(|f| {
+        let ty = &f.ty;
+        predicates.push(parse_quote! { #ty: #bound });
     });
+    predicates
+}
+
+/// The object's generics with a leading `'a` lifetime, for implementing traits
+/// on wrappers such as `Account<'a, T>` and `Create<'a, T>`.
+fn generics_with_lifetime(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote! { 'a });
+    generics
+}
+
+/// Whether the object has type or const parameters - lifetimes don't change
+/// its data.
+fn is_generic(generics: &Generics) -> bool {
+    generics.type_params().next().is_some() || generics.const_params().next().is_some()
+}
+
+/// The predicates for implementing `NautilusAccountData` or
+/// `NautilusRecordData`: `where_predicates` with `nautilus_data_bound`, plus -
+/// for a generic object - `NautilusDataInstance` on the object itself, which
+/// names each of its instantiations.
+fn data_where_predicates(raw_type: &NautilusObjectRawType) -> Vec<WherePredicate> {
+    let mut predicates = where_predicates(raw_type, nautilus_data_bound());
+    if is_generic(raw_type.generics()) {
+        let ident = raw_type.ident();
+        let (_, ty_generics, _) = raw_type.generics().split_for_impl();
+        predicates.push(parse_quote! { #ident #ty_generics: nautilus::NautilusDataInstance });
+    }
+    predicates
+}
+
+/// The object's table name or discriminator string.
+///
+/// Every instantiation of a generic object needs its own, so theirs come from
+/// `NautilusDataInstance`, which the `#[nautilus]` macro implements for each
+/// instantiation the program uses, ie. `vault<u64>` for `Vault<u64>`.
+fn data_name(raw_type: &NautilusObjectRawType, name: &str) -> TokenStream {
+    match is_generic(raw_type.generics()) {
+        true => quote! { <Self as nautilus::NautilusDataInstance>::NAME },
+        false => quote! { #name },
+    }
+}
+
+/// The bound required by `NautilusAccountData` and `NautilusRecordData` on
+/// every field.
+fn nautilus_data_bound() -> TokenStream {
     quote! {
-        impl ::core::clone::Clone for #ident {
-            #[inline]
-            fn clone(&self) -> Self {
+        nautilus::borsh::ser::BorshSerialize
+            + nautilus::borsh::de::BorshDeserialize
+            + ::core::clone::Clone
+            + ::core::default::Default
+    }
+}
+
+/// Generates tokens to implement `Clone` on a struct or enum.
+pub fn impl_clone(raw_type: &NautilusObjectRawType) -> TokenStream {
+    let ident = raw_type.ident();
+    let (impl_generics, ty_generics, _) = raw_type.generics().split_for_impl();
+    let where_predicates = where_predicates(raw_type, quote! { ::core::clone::Clone });
+    let clone_body = match raw_type {
+        NautilusObjectRawType::Struct(item_struct) => {
+            let clone_constructors = data_fields(&item_struct.fields).into_iter().map(|f| {
+                let member = &f.member;
+                quote! { #member: ::core::clone::Clone::clone(&self.#member) }
+            });
+            quote! {
                 Self {
                     #(#clone_constructors,)*
                 }
             }
         }
+        NautilusObjectRawType::Enum(item_enum) => {
+            let clone_arms = item_enum.variants.iter().map(|v| {
+                let variant = &v.ident;
+                let fields = data_fields(&v.fields);
+                let patterns = fields.iter().map(DataField::pattern);
+                let clone_constructors = fields.iter().map(|f| {
+                    let (member, binding) = (&f.member, &f.binding);
+                    quote! { #member: ::core::clone::Clone::clone(#binding) }
+                });
+                quote! {
+                    Self::#variant { #(#patterns,)* } => Self::#variant {
+                        #(#clone_constructors,)*
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#clone_arms,)*
+                }
+            }
+        }
+    };
+    quote! {
+        impl #impl_generics ::core::clone::Clone for #ident #ty_generics
+        where
+            #(#where_predicates,)*
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                #clone_body
+            }
+        }
     }
 }
 
-/// Generates tokens to implement `Default` on a struct.
-pub fn impl_default(ident: &Ident, fields: &Fields) -> TokenStream {
+/// Generates tokens to implement `Default` on a struct or enum.
+///
+/// For an enum, the default is its first variant with all of its fields set to
+/// their defaults.
+pub fn impl_default(raw_type: &NautilusObjectRawType) -> TokenStream {
+    let ident = raw_type.ident();
+    let (impl_generics, ty_generics, _) = raw_type.generics().split_for_impl();
+    let (constructor, fields) = match raw_type {
+        NautilusObjectRawType::Struct(item_struct) => (quote! { Self }, &item_struct.fields),
+        NautilusObjectRawType::Enum(item_enum) => {
+            let first_variant = item_enum.variants.first().unwrap_or_else(|| {
+                panic!(
+                    "Enum {} must have at least one variant to be used as a Nautilus object.",
+                    ident
+                )
+            });
+            let variant = &first_variant.ident;
+            (quote! { Self::#variant }, &first_variant.fields)
+        }
+    };
+    let fields = data_fields(fields);
+    let field_types = fields.iter().map(|f| &f.ty);
     let fields_default = fields.iter().map(|f| {
-        let i = &f.ident.clone().unwrap();
-        quote! { #i: ::core::default::Default::default() }
+        let member = &f.member;
+        quote! { #member: ::core::default::Default::default() }
     });
+    let user_predicates = raw_type
+        .generics()
+        .where_clause
+        .as_ref()
+        .map(|w| w.predicates.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
     quote! {
-        impl ::core::default::Default for #ident {
+        impl #impl_generics ::core::default::Default for #ident #ty_generics
+        where
+            #(#user_predicates,)*
+            #(#field_types: ::core::default::Default,)*
+        {
             #[inline]
             fn default() -> Self {
-                Self {
+                #constructor {
                     #(#fields_default,)*
                 }
             }
@@ -43,26 +224,95 @@ pub fn impl_default(ident: &Ident, fields: &Fields) -> TokenStream {
 }
 
 /// Generates tokens to implement `BorshDeserialize` and `BorshSerialize` on a
-/// struct.
-pub fn impl_borsh(ident: &Ident, fields: &Fields) -> TokenStream {
-    let borsh_ser_where = fields.iter().map(|f| {
-        let field_ty = f.ty.clone();
-        quote::quote! { #field_ty: nautilus::borsh::ser::BorshSerialize }
-    });
-    let borsh_ser_impl = fields.iter().map(|f| {
-        let field_name = f.ident.clone();
-        quote::quote! { nautilus::borsh::BorshSerialize::serialize(&self.#field_name, writer)? }
-    });
-    let borsh_deser_where = fields.iter().map(|f| {
-        let field_ty = f.ty.clone();
-        quote::quote! { #field_ty: nautilus::borsh::de::BorshDeserialize }
-    });
-    let borsh_deser_impl = fields.iter().map(|f| {
-        let field_name = f.ident.clone();
-        quote::quote! { #field_name: nautilus::borsh::BorshDeserialize::deserialize(buf)? }
-    });
+/// struct or enum.
+///
+/// The account's discriminator is always serialized first. For an enum, it's
+/// followed by the variant's index as a `u8` and then the variant's fields -
+/// the same layout Borsh uses for enums.
+///
+/// Serializing takes the discriminator from `NautilusAccountData` or
+/// `NautilusRecordData`, so it's bound the same way they are.
+pub fn impl_borsh(raw_type: &NautilusObjectRawType) -> TokenStream {
+    let ident = raw_type.ident();
+    let (impl_generics, ty_generics, _) = raw_type.generics().split_for_impl();
+    let borsh_ser_where = data_where_predicates(raw_type);
+    let borsh_deser_where =
+        where_predicates(raw_type, quote! { nautilus::borsh::de::BorshDeserialize });
+    let (borsh_ser_impl, borsh_deser_impl) = match raw_type {
+        NautilusObjectRawType::Struct(item_struct) => {
+            let fields = data_fields(&item_struct.fields);
+            let ser_fields = fields.iter().map(|f| {
+                let member = &f.member;
+                quote! { nautilus::borsh::BorshSerialize::serialize(&self.#member, writer)? }
+            });
+            let deser_fields = fields.iter().map(|f| {
+                let member = &f.member;
+                quote! { #member: nautilus::borsh::BorshDeserialize::deserialize(buf)? }
+            });
+            (
+                quote! { #(#ser_fields;)* },
+                quote! {
+                    Ok(Self {
+                        #(#deser_fields,)*
+                    })
+                },
+            )
+        }
+        NautilusObjectRawType::Enum(item_enum) => {
+            if item_enum.variants.len() > u8::MAX as usize + 1 {
+                panic!("Enum {} has too many variants to be serialized.", ident);
+            }
+            let ser_arms = item_enum.variants.iter().enumerate().map(|(i, v)| {
+                let variant = &v.ident;
+                let variant_index = i as u8;
+                let fields = data_fields(&v.fields);
+                let patterns = fields.iter().map(DataField::pattern);
+                let ser_fields = fields.iter().map(|f| {
+                    let binding = &f.binding;
+                    quote! { nautilus::borsh::BorshSerialize::serialize(#binding, writer)? }
+                });
+                quote! {
+                    Self::#variant { #(#patterns,)* } => {
+                        nautilus::borsh::BorshSerialize::serialize(&#variant_index, writer)?;
+                        #(#ser_fields;)*
+                    }
+                }
+            });
+            let deser_arms = item_enum.variants.iter().enumerate().map(|(i, v)| {
+                let variant = &v.ident;
+                let variant_index = i as u8;
+                let deser_fields = data_fields(&v.fields).into_iter().map(|f| {
+                    let member = &f.member;
+                    quote! { #member: nautilus::borsh::BorshDeserialize::deserialize(buf)? }
+                });
+                quote! {
+                    #variant_index => Ok(Self::#variant {
+                        #(#deser_fields,)*
+                    })
+                }
+            });
+            let ident_str = ident.to_string();
+            (
+                quote! {
+                    match self {
+                        #(#ser_arms,)*
+                    }
+                },
+                quote! {
+                    let variant_index: u8 = nautilus::borsh::BorshDeserialize::deserialize(buf)?;
+                    match variant_index {
+                        #(#deser_arms,)*
+                        _ => Err(nautilus::borsh::maybestd::io::Error::new(
+                            nautilus::borsh::maybestd::io::ErrorKind::InvalidInput,
+                            format!("Unexpected variant index for {}: {}", #ident_str, variant_index),
+                        )),
+                    }
+                },
+            )
+        }
+    };
     quote::quote! {
-        impl nautilus::borsh::ser::BorshSerialize for #ident
+        impl #impl_generics nautilus::borsh::ser::BorshSerialize for #ident #ty_generics
         where
             #(#borsh_ser_where,)*
         {
@@ -71,11 +321,11 @@ pub fn impl_borsh(ident: &Ident, fields: &Fields) -> TokenStream {
                 writer: &mut W,
             ) -> ::core::result::Result<(), nautilus::borsh::maybestd::io::Error> {
                 borsh::BorshSerialize::serialize(&self.discriminator(), writer)?; // Serialize the discriminator first
-                #(#borsh_ser_impl;)*
+                #borsh_ser_impl
                 Ok(())
             }
         }
-        impl nautilus::borsh::de::BorshDeserialize for #ident
+        impl #impl_generics nautilus::borsh::de::BorshDeserialize for #ident #ty_generics
         where
             #(#borsh_deser_where,)*
         {
@@ -83,9 +333,7 @@ pub fn impl_borsh(ident: &Ident, fields: &Fields) -> TokenStream {
                 buf: &mut &[u8],
             ) -> ::core::result::Result<Self, nautilus::borsh::maybestd::io::Error> {
                 let _discrim: [u8; 8] = borsh::BorshDeserialize::deserialize(buf)?; // Skip the first 8 bytes for discriminator
-                Ok(Self {
-                    #(#borsh_deser_impl,)*
-                })
+                #borsh_deser_impl
             }
         }
     }
@@ -93,54 +341,78 @@ pub fn impl_borsh(ident: &Ident, fields: &Fields) -> TokenStream {
 
 /// Generates tokens to implement `NautilusRecordData` on a struct.
 pub fn impl_nautilus_record_data(
-    ident: &Ident,
-    fields: &Fields,
-    table_name: &String,
+    raw_type: &NautilusObjectRawType,
+    table_name: &str,
     autoincrement: bool,
-    primary_key_ident: &Ident,
+    primary_key: &Member,
     primary_key_ty: &Type,
 ) -> TokenStream {
+    let ident = raw_type.ident();
+    let fields = match raw_type {
+        NautilusObjectRawType::Struct(item_struct) => &item_struct.fields,
+        NautilusObjectRawType::Enum(_) => panic!("Records can only be derived for structs."),
+    };
     let nautilus_create_obj_trait_ident = &Ident::new(
         &("NautilusCreate".to_owned() + &ident.to_string()),
         Span::call_site(),
     );
 
-    let tokens_primary_key_seed = build_tokens_primary_key_seed(primary_key_ident, primary_key_ty);
+    let generics = raw_type.generics();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let generics_with_lifetime = generics_with_lifetime(generics);
+    let (lifetime_impl_generics, lifetime_ty_generics, _) = generics_with_lifetime.split_for_impl();
+    let data_where = data_where_predicates(raw_type);
+    let table_name = data_name(raw_type, table_name);
+
+    let tokens_primary_key_seed = build_tokens_primary_key_seed(primary_key, primary_key_ty);
 
-    let (data_new_fn_args, data_new_call_args) =
-        get_new_fn_args_for_record(fields, autoincrement, primary_key_ident);
+    let (data_new_fn_args, data_new_call_args, data_constructor) =
+        get_new_fn_args_for_record(fields, autoincrement, primary_key);
 
     let data_new_fn = match autoincrement {
-        true => quote! {
-            pub fn new<'a>(
-                mut nautilus_index: NautilusIndex<'a>,
-                fee_payer: impl NautilusSigner<'a>,
-                #(#data_new_fn_args,)*
-            ) -> Result<Box<Self>, ProgramError> {
-                let #primary_key_ident = nautilus_index.add_record(
-                    Self::TABLE_NAME,
-                    fee_payer,
-                )?.try_into().unwrap();
-                Ok(Box::new(Self{ #primary_key_ident, #(#data_new_call_args,)* }))
+        true => {
+            let primary_key_binding = data_fields(fields)
+                .into_iter()
+                .find(|f| &f.member == primary_key)
+                .map(|f| f.binding)
+                .unwrap();
+            quote! {
+                pub fn new<'a>(
+                    mut nautilus_index: NautilusIndex<'a>,
+                    fee_payer: impl NautilusSigner<'a>,
+                    #(#data_new_fn_args,)*
+                ) -> Result<Box<Self>, ProgramError> {
+                    let #primary_key_binding = nautilus_index.add_record(
+                        Self::TABLE_NAME,
+                        fee_payer,
+                    )?.try_into().unwrap();
+                    Ok(Box::new(#data_constructor))
+                }
             }
-        },
+        }
         false => quote! {
             pub fn new<'a>(
                 _nautilus_index: NautilusIndex<'a>,
                 fee_payer: impl NautilusSigner<'a>,
                 #(#data_new_fn_args,)*
             ) -> Result<Box<Self>, ProgramError> {
-                Ok(Box::new(Self{ #(#data_new_call_args,)* }))
+                Ok(Box::new(#data_constructor))
             }
         },
     };
 
     quote! {
-        impl #ident {
+        impl #impl_generics #ident #ty_generics
+        where
+            #(#data_where,)*
+        {
             #data_new_fn
         }
 
-        impl NautilusRecordData for #ident {
+        impl #impl_generics NautilusRecordData for #ident #ty_generics
+        where
+            #(#data_where,)*
+        {
             const TABLE_NAME: &'static str = #table_name;
 
             const AUTO_INCREMENT: bool = #autoincrement;
@@ -158,44 +430,54 @@ pub fn impl_nautilus_record_data(
             }
         }
 
-        pub trait #nautilus_create_obj_trait_ident<'a> {
+        pub trait #nautilus_create_obj_trait_ident #lifetime_impl_generics {
             fn create(&mut self, #(#data_new_fn_args,)*) -> ProgramResult;
             fn create_with_payer(&mut self, #(#data_new_fn_args,)* payer: impl NautilusSigner<'a>) -> ProgramResult;
         }
 
-        impl<'a> #nautilus_create_obj_trait_ident<'a> for Create<'a, Record<'a, #ident>> {
+        impl #lifetime_impl_generics #nautilus_create_obj_trait_ident #lifetime_ty_generics for Create<'a, Record<'a, #ident #ty_generics>>
+        where
+            #ident #ty_generics: 'a,
+            #(#data_where,)*
+        {
             fn create(&mut self, #(#data_new_fn_args,)*) -> ProgramResult {
                 let rent_payer = Signer::new(Wallet {
                     account_info: self.fee_payer.to_owned(),
                     system_program: self.system_program.to_owned(),
                 })?;
-                self.self_account.data = #ident ::new(
-                    self.self_account.index.clone(),
-                    rent_payer,
-                    #(#data_new_call_args,)*
-                )?;
+                nautilus::Lazy::set(
+                    &mut self.self_account.data,
+                    <#ident #ty_generics>::new(
+                        self.self_account.index.clone(),
+                        rent_payer,
+                        #(#data_new_call_args,)*
+                    )?,
+                );
                 self.create_record()
             }
 
             fn create_with_payer(&mut self, #(#data_new_fn_args,)* payer: impl NautilusSigner<'a>) -> ProgramResult {
-                self.self_account.data = #ident ::new(
-                    self.self_account.index.clone(),
-                    payer.clone(),
-                    #(#data_new_call_args,)*
-                )?;
+                nautilus::Lazy::set(
+                    &mut self.self_account.data,
+                    <#ident #ty_generics>::new(
+                        self.self_account.index.clone(),
+                        payer.clone(),
+                        #(#data_new_call_args,)*
+                    )?,
+                );
                 self.create_record_with_payer(payer)
             }
         }
     }
 }
 
-/// Generates tokens to implement `NautilusAccountData` on a struct.
+/// Generates tokens to implement `NautilusAccountData` on a struct or enum.
 pub fn impl_nautilus_account_data(
-    ident: &Ident,
-    fields: &Fields,
-    discrminator_str: &String,
+    raw_type: &NautilusObjectRawType,
+    discrminator_str: &str,
     seeds: &Vec<Seed>,
 ) -> TokenStream {
+    let ident = raw_type.ident();
     let nautilus_inner_trait_ident = &Ident::new(
         &("NautilusInner".to_owned() + &ident.to_string()),
         Span::call_site(),
@@ -205,14 +487,22 @@ pub fn impl_nautilus_account_data(
         Span::call_site(),
     );
 
-    let (data_new_fn_args, data_new_call_args) = get_new_fn_args_for_account(fields);
+    let generics = raw_type.generics();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let generics_with_lifetime = generics_with_lifetime(generics);
+    let (lifetime_impl_generics, lifetime_ty_generics, _) = generics_with_lifetime.split_for_impl();
+    let data_where = data_where_predicates(raw_type);
+    let discrminator_str = data_name(raw_type, discrminator_str);
+
+    let (data_new_fn_args, data_new_call_args, data_constructor) =
+        get_new_fn_args_for_account(raw_type);
 
     let data_new_fn = quote! {
         pub fn new<'a>(
             fee_payer: impl NautilusSigner<'a>,
             #(#data_new_fn_args,)*
         ) -> Result<Box<Self>, ProgramError> {
-            Ok(Box::new(Self{ #(#data_new_call_args,)* }))
+            Ok(Box::new(#data_constructor))
         }
     };
 
@@ -267,14 +557,14 @@ pub fn impl_nautilus_account_data(
     // For seeds on `Account<T>`.
     let seeds_fn_outer = quote! {
         fn seeds(#seeds_args) -> Result<Vec<Vec<u8>>, ProgramError> {
-            self.data.seeds(#seeds_caller)
+            nautilus::Lazy::try_get(&self.data)?.seeds(#seeds_caller)
         }
     };
     // For PDA on `Account<T>`.
     let pda_fn_outer = quote! {
         fn pda(#pda_args_outer) -> Result<(Pubkey, u8), ProgramError> {
             let program_id = self.program_id;
-            self.data.pda(#pda_caller)
+            nautilus::Lazy::try_get(&self.data)?.pda(#pda_caller)
         }
     };
     // For seeds on `Create<Account<T>>`.
@@ -291,13 +581,19 @@ pub fn impl_nautilus_account_data(
     };
 
     quote! {
-        impl #ident {
+        impl #impl_generics #ident #ty_generics
+        where
+            #(#data_where,)*
+        {
             #data_new_fn
             #seeds_fn
             #pda_fn
         }
 
-        impl NautilusAccountData for #ident {
+        impl #impl_generics NautilusAccountData for #ident #ty_generics
+        where
+            #(#data_where,)*
+        {
             const DISCRIMINATOR_STR: &'static str = #discrminator_str;
 
             fn check_authorities(&self, accounts: Vec<AccountInfo>) -> Result<(), ProgramError> {
@@ -309,36 +605,50 @@ pub fn impl_nautilus_account_data(
             }
         }
 
-        pub trait #nautilus_inner_trait_ident<'a> {
+        pub trait #nautilus_inner_trait_ident #lifetime_impl_generics {
             fn seeds(#seeds_args) -> Result<Vec<Vec<u8>>, ProgramError>;
             fn pda(#pda_args_outer) -> Result<(Pubkey, u8), ProgramError>;
         }
 
-        impl<'a> #nautilus_inner_trait_ident<'a> for Account<'a, #ident> {
+        impl #lifetime_impl_generics #nautilus_inner_trait_ident #lifetime_ty_generics for Account<'a, #ident #ty_generics>
+        where
+            #(#data_where,)*
+        {
             #seeds_fn_outer
             #pda_fn_outer
         }
 
-        impl<'a> #nautilus_inner_trait_ident<'a> for Create<'a, Account<'a, #ident>> {
+        impl #lifetime_impl_generics #nautilus_inner_trait_ident #lifetime_ty_generics for Create<'a, Account<'a, #ident #ty_generics>>
+        where
+            #ident #ty_generics: 'a,
+            #(#data_where,)*
+        {
             #seeds_fn_outer_2
             #pda_fn_outer_2
         }
 
-        pub trait #nautilus_create_obj_trait_ident<'a> {
+        pub trait #nautilus_create_obj_trait_ident #lifetime_impl_generics {
             fn create(#create_args) -> ProgramResult;
             fn create_with_payer(#create_with_payer_args) -> ProgramResult;
         }
 
-        impl<'a> #nautilus_create_obj_trait_ident<'a> for Create<'a, Account<'a, #ident>> {
+        impl #lifetime_impl_generics #nautilus_create_obj_trait_ident #lifetime_ty_generics for Create<'a, Account<'a, #ident #ty_generics>>
+        where
+            #ident #ty_generics: 'a,
+            #(#data_where,)*
+        {
             fn create(#create_args) -> ProgramResult {
                 let payer = Signer::new(Wallet {
                     account_info: self.fee_payer.to_owned(),
                     system_program: self.system_program.to_owned(),
                 })?;
-                self.self_account.data = #ident ::new(
-                    payer.clone(),
-                    #(#data_new_call_args,)*
-                )?;
+                nautilus::Lazy::set(
+                    &mut self.self_account.data,
+                    <#ident #ty_generics>::new(
+                        payer.clone(),
+                        #(#data_new_call_args,)*
+                    )?,
+                );
                 let (pda, bump) = self.pda(#pda_caller_outer)?;
                 assert_eq!(
                     &pda,
@@ -353,16 +663,19 @@ pub fn impl_nautilus_account_data(
                     self.self_account.clone(),
                     self.self_account.program_id,
                     payer,
-                    self.self_account.data.clone(),
+                    Box::new(nautilus::Lazy::try_get(&self.self_account.data)?.clone()),
                     signer_seeds,
                 )
             }
 
             fn create_with_payer(#create_with_payer_args) -> ProgramResult {
-                self.self_account.data = #ident ::new(
-                    payer.clone(),
-                    #(#data_new_call_args,)*
-                )?;
+                nautilus::Lazy::set(
+                    &mut self.self_account.data,
+                    <#ident #ty_generics>::new(
+                        payer.clone(),
+                        #(#data_new_call_args,)*
+                    )?,
+                );
                 let (pda, bump) = self.pda(#pda_caller_outer)?;
                 assert_eq!(
                     &pda,
@@ -377,7 +690,192 @@ pub fn impl_nautilus_account_data(
                     self.self_account.clone(),
                     self.self_account.program_id,
                     payer,
-                    self.self_account.data.clone(),
+                    Box::new(nautilus::Lazy::try_get(&self.self_account.data)?.clone()),
+                    signer_seeds,
+                )
+            }
+        }
+    }
+}
+
+/// Generates tokens for a `#[zero_copy]` account's data type: `Clone`, `Copy`,
+/// `bytemuck::Zeroable` and `bytemuck::Pod`, `NautilusZeroCopyData`, and the
+/// same `seeds(..)`, `pda(..)` and `create(..)` functions as
+/// `impl_nautilus_account_data` - for `ZeroCopyAccount<T>`.
+///
+/// `Pod` is only sound for a `#[repr(C)]` struct of `Pod` fields without
+/// padding, so the generated code asserts both at compile time. Without
+/// padding, the struct's layout is also its Borsh layout, which is what the IDL
+/// describes.
+///
+/// A new account is created holding just its discriminator, with the rest of
+/// its data zeroed - so seeds can't refer to its fields.
+pub fn impl_nautilus_zero_copy_data(
+    raw_type: &NautilusObjectRawType,
+    discrminator_str: &String,
+    seeds: &Vec<Seed>,
+) -> TokenStream {
+    let ident = raw_type.ident();
+    let item_struct = match raw_type {
+        NautilusObjectRawType::Struct(item_struct) => item_struct,
+        NautilusObjectRawType::Enum(_) => panic!(
+            "`#[zero_copy]` can't be used on an enum ({}). Zero-copy accounts must be `#[repr(C)]` structs.",
+            ident
+        ),
+    };
+    // Its discriminator is computed when it's derived, so every instantiation
+    // would share one.
+    if is_generic(&item_struct.generics) {
+        panic!(
+            "`#[zero_copy]` can't be used on a generic struct ({}).",
+            ident
+        );
+    }
+    if !is_repr_c(&item_struct.attrs) {
+        panic!(
+            "`#[zero_copy]` requires `#[repr(C)]` on {}, so its layout is fixed.",
+            ident
+        );
+    }
+    if seeds.iter().any(|s| matches!(s, Seed::Field { .. })) {
+        panic!(
+            "The seeds of `#[zero_copy]` account {} can't refer to its fields, since its data is zeroed when it's created.",
+            ident
+        );
+    }
+    let nautilus_inner_trait_ident = &Ident::new(
+        &("NautilusInner".to_owned() + &ident.to_string()),
+        Span::call_site(),
+    );
+    let nautilus_create_obj_trait_ident = &Ident::new(
+        &("NautilusCreate".to_owned() + &ident.to_string()),
+        Span::call_site(),
+    );
+
+    let field_tys: Vec<&Type> = item_struct.fields.iter().map(|f| &f.ty).collect();
+    let discriminator = nautilus_idl::idl_type_def::discriminator(discrminator_str);
+
+    let (seeds_inner, seeds_params_tuple) = build_seeds_components_for_account(seeds);
+    let (seeds_args, seeds_caller, seeds_args_outer, create_args, create_with_payer_args) =
+        match &seeds_params_tuple {
+            Some(tuple) => (
+                quote! { seeds: #tuple },
+                quote! { seeds, },
+                quote! { &self, seeds: #tuple },
+                quote! { &mut self, seeds: #tuple },
+                quote! { &mut self, seeds: #tuple, payer: impl nautilus::NautilusSigner<'a> },
+            ),
+            None => (
+                quote!(),
+                quote!(),
+                quote! { &self },
+                quote! { &mut self },
+                quote! { &mut self, payer: impl nautilus::NautilusSigner<'a> },
+            ),
+        };
+
+    quote! {
+        impl ::core::clone::Clone for #ident {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl ::core::marker::Copy for #ident {}
+
+        const _: fn() = || {
+            fn assert_pod<T: nautilus::bytemuck::Pod>() {}
+            #(assert_pod::<#field_tys>();)*
+        };
+
+        const _: () = assert!(
+            ::core::mem::size_of::<#ident>() == 0 #(+ ::core::mem::size_of::<#field_tys>())*,
+            "A `#[zero_copy]` struct can't have padding. Reorder its fields or add explicit padding fields."
+        );
+
+        unsafe impl nautilus::bytemuck::Zeroable for #ident {}
+
+        unsafe impl nautilus::bytemuck::Pod for #ident {}
+
+        impl nautilus::NautilusZeroCopyData for #ident {
+            const DISCRIMINATOR_STR: &'static str = #discrminator_str;
+            const DISCRIMINATOR: [u8; 8] = [#(#discriminator,)*];
+        }
+
+        impl #ident {
+            pub fn seeds(#seeds_args) -> Result<Vec<Vec<u8>>, nautilus::ProgramError> {
+                Ok(#seeds_inner)
+            }
+
+            pub fn pda(
+                program_id: &nautilus::Pubkey,
+                #seeds_args
+            ) -> Result<(nautilus::Pubkey, u8), nautilus::ProgramError> {
+                let seeds_vec = Self::seeds(#seeds_caller)?;
+                let seeds: Vec<&[u8]> = seeds_vec.iter().map(AsRef::as_ref).collect();
+                Ok(nautilus::Pubkey::find_program_address(&seeds, program_id))
+            }
+        }
+
+        pub trait #nautilus_inner_trait_ident<'a> {
+            fn seeds(#seeds_args_outer) -> Result<Vec<Vec<u8>>, nautilus::ProgramError>;
+            fn pda(#seeds_args_outer) -> Result<(nautilus::Pubkey, u8), nautilus::ProgramError>;
+        }
+
+        impl<'a> #nautilus_inner_trait_ident<'a> for nautilus::ZeroCopyAccount<'a, #ident> {
+            fn seeds(#seeds_args_outer) -> Result<Vec<Vec<u8>>, nautilus::ProgramError> {
+                #ident::seeds(#seeds_caller)
+            }
+
+            fn pda(#seeds_args_outer) -> Result<(nautilus::Pubkey, u8), nautilus::ProgramError> {
+                #ident::pda(self.program_id, #seeds_caller)
+            }
+        }
+
+        impl<'a> #nautilus_inner_trait_ident<'a> for nautilus::Create<'a, nautilus::ZeroCopyAccount<'a, #ident>> {
+            fn seeds(#seeds_args_outer) -> Result<Vec<Vec<u8>>, nautilus::ProgramError> {
+                #ident::seeds(#seeds_caller)
+            }
+
+            fn pda(#seeds_args_outer) -> Result<(nautilus::Pubkey, u8), nautilus::ProgramError> {
+                #ident::pda(self.self_account.program_id, #seeds_caller)
+            }
+        }
+
+        pub trait #nautilus_create_obj_trait_ident<'a> {
+            fn create(#create_args) -> nautilus::ProgramResult;
+            fn create_with_payer(#create_with_payer_args) -> nautilus::ProgramResult;
+        }
+
+        impl<'a> #nautilus_create_obj_trait_ident<'a> for nautilus::Create<'a, nautilus::ZeroCopyAccount<'a, #ident>> {
+            fn create(#create_args) -> nautilus::ProgramResult {
+                let payer = nautilus::Signer::new(nautilus::Wallet {
+                    account_info: self.fee_payer.to_owned(),
+                    system_program: self.system_program.to_owned(),
+                })?;
+                self.create_with_payer(#seeds_caller payer)
+            }
+
+            fn create_with_payer(#create_with_payer_args) -> nautilus::ProgramResult {
+                let program_id = self.self_account.program_id;
+                let mut signer_seeds_vec = #ident::seeds(#seeds_caller)?;
+                let (pda, bump) = {
+                    let seeds: Vec<&[u8]> = signer_seeds_vec.iter().map(AsRef::as_ref).collect();
+                    nautilus::Pubkey::find_program_address(&seeds, program_id)
+                };
+                assert_eq!(
+                    &pda,
+                    self.self_account.account_info.key,
+                    "Derived PDA does not match data for account {:#?}",
+                    self.self_account.account_info.key
+                );
+                signer_seeds_vec.push(vec![bump]);
+                let signer_seeds: Vec<&[u8]> = signer_seeds_vec.iter().map(AsRef::as_ref).collect();
+                nautilus::cpi::system::create_pda(
+                    self.self_account.clone(),
+                    program_id,
+                    payer,
+                    Box::new(<#ident as nautilus::NautilusZeroCopyData>::DISCRIMINATOR),
                     signer_seeds,
                 )
             }
@@ -385,9 +883,25 @@ pub fn impl_nautilus_account_data(
     }
 }
 
+/// Whether a type is marked `#[repr(C)]`, alone or alongside other
+/// representation hints.
+fn is_repr_c(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .any(|attr| {
+            match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("C"))
+            }),
+            _ => false,
+        }
+        })
+}
+
 /// Helper function to generate tokens for writing the function that returns the
 /// data type's primary key.
-fn build_tokens_primary_key_seed(key: &syn::Ident, ty: &syn::Type) -> TokenStream {
+fn build_tokens_primary_key_seed(key: &Member, ty: &syn::Type) -> TokenStream {
     match quote::quote!(#ty).to_string().as_str() {
         "String" => quote::quote! {
             self.#key.as_bytes().to_vec()
@@ -409,60 +923,72 @@ fn build_tokens_primary_key_seed(key: &syn::Ident, ty: &syn::Type) -> TokenStrea
 
 /// Helper function that parses the fields of a struct to determine the function
 /// signature for a `new(..) -> Self` function to create a record.
+///
+/// Consider the return type: (`Vec<FnArg>`, `Vec<Ident>`, `TokenStream`):
+/// * `Vec<FnArg>`: The function's parameters.
+/// * `Vec<Ident>`: The arguments to pass when calling the function.
+/// * `TokenStream`: The expression constructing `Self` from the parameters.
 fn get_new_fn_args_for_record(
     fields: &Fields,
     autoincrement: bool,
-    primary_key_ident: &Ident,
-) -> (Vec<FnArg>, Vec<Ident>) {
+    primary_key: &Member,
+) -> (Vec<FnArg>, Vec<Ident>, TokenStream) {
+    let fields = data_fields(fields);
     let mut data_new_fn_args: Vec<FnArg> = vec![];
     let mut data_new_call_args: Vec<Ident> = vec![];
-    fields.iter().for_each(|f| match &f.ident {
-        Some(ident) => {
-            if !(autoincrement && ident == primary_key_ident) {
-                data_new_call_args.push(ident.clone());
-                data_new_fn_args.push(FnArg::Typed(PatType {
-                    attrs: vec![],
-                    pat: Box::new(Pat::Ident(PatIdent {
-                        attrs: vec![],
-                        by_ref: None,
-                        mutability: None,
-                        ident: ident.clone(),
-                        subpat: None,
-                    })),
-                    colon_token: Colon::default(),
-                    ty: Box::new(f.ty.clone()),
-                }))
-            }
+    fields.iter().for_each(|f| {
+        if !(autoincrement && &f.member == primary_key) {
+            let (binding, ty) = (&f.binding, &f.ty);
+            data_new_call_args.push(binding.clone());
+            data_new_fn_args.push(parse_quote! { #binding: #ty });
         }
-        None => (),
     });
-    (data_new_fn_args, data_new_call_args)
+    let members = fields.iter().map(|f| &f.member);
+    let bindings = fields.iter().map(|f| &f.binding);
+    let data_constructor = quote! { Self { #(#members: #bindings,)* } };
+    (data_new_fn_args, data_new_call_args, data_constructor)
 }
 
 /// Helper function that parses the fields of a struct to determine the function
 /// signature for a `new(..) -> Self` function to create an account.
-fn get_new_fn_args_for_account(fields: &Fields) -> (Vec<FnArg>, Vec<Ident>) {
-    let mut data_new_fn_args: Vec<FnArg> = vec![];
-    let mut data_new_call_args: Vec<Ident> = vec![];
-    fields.iter().for_each(|f| match &f.ident {
-        Some(ident) => {
-            data_new_call_args.push(ident.clone());
-            data_new_fn_args.push(FnArg::Typed(PatType {
-                attrs: vec![],
-                pat: Box::new(Pat::Ident(PatIdent {
-                    attrs: vec![],
-                    by_ref: None,
-                    mutability: None,
-                    ident: ident.clone(),
-                    subpat: None,
-                })),
-                colon_token: Colon::default(),
-                ty: Box::new(f.ty.clone()),
-            }))
-        }
-        None => (),
-    });
-    (data_new_fn_args, data_new_call_args)
+///
+/// An enum's variant can't be known ahead of time, so for an enum the function
+/// simply takes the value itself as `data`.
+///
+/// Consider the return type: (`Vec<FnArg>`, `Vec<Ident>`, `TokenStream`):
+/// * `Vec<FnArg>`: The function's parameters.
+/// * `Vec<Ident>`: The arguments to pass when calling the function.
+/// * `TokenStream`: The expression constructing `Self` from the parameters.
+fn get_new_fn_args_for_account(
+    raw_type: &NautilusObjectRawType,
+) -> (Vec<FnArg>, Vec<Ident>, TokenStream) {
+    match raw_type {
+        NautilusObjectRawType::Struct(item_struct) => {
+            let fields = data_fields(&item_struct.fields);
+            let data_new_fn_args = fields
+                .iter()
+                .map(|f| {
+                    let (binding, ty) = (&f.binding, &f.ty);
+                    parse_quote! { #binding: #ty }
+                })
+                .collect();
+            let data_new_call_args = fields.iter().map(|f| f.binding.clone()).collect();
+            let members = fields.iter().map(|f| &f.member);
+            let bindings = fields.iter().map(|f| &f.binding);
+            let data_constructor = quote! { Self { #(#members: #bindings,)* } };
+            (data_new_fn_args, data_new_call_args, data_constructor)
+        }
+        NautilusObjectRawType::Enum(item_enum) => {
+            let ident = &item_enum.ident;
+            let (_, ty_generics, _) = item_enum.generics.split_for_impl();
+            let data = Ident::new("data", Span::call_site());
+            (
+                vec![parse_quote! { #data: #ident #ty_generics }],
+                vec![data.clone()],
+                quote! { #data },
+            )
+        }
+    }
 }
 
 /// Builds the various arguments for the account's seeds.
@@ -7,15 +7,16 @@ pub mod source;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{Ident, ItemEnum, ItemStruct};
+use syn::{parse_quote, Attribute, Field, Generics, Ident, ItemEnum, ItemStruct, Type};
 
 use crate::entry::required_account::RequiredAccount;
 
 use self::{
     data::{
-        impl_borsh, impl_clone, impl_default, impl_nautilus_account_data, impl_nautilus_record_data,
+        impl_borsh, impl_clone, impl_default, impl_nautilus_account_data,
+        impl_nautilus_record_data, impl_nautilus_zero_copy_data,
     },
-    parser::{parse_item_struct, NautilusObjectConfig},
+    parser::{parse_item_enum, parse_item_struct, NautilusObjectConfig},
 };
 
 /// The struct containing the parsed contents of a user's struct, annotated with
@@ -23,6 +24,8 @@ use self::{
 ///
 /// * `ident`: The struct's identifier.
 /// * `raw_Type`: The raw type of the object (is it a struct or enum?).
+/// * `object_type`: The type of Nautilus object (is it a `Record` or an
+///   `Account`?).
 /// * `entry_config`: Account-specific configurations required for building the
 ///   program's entrypoint, such as `is_signer`.
 /// * `object_config`: The object's configurations. these will be specific to
@@ -31,15 +34,19 @@ use self::{
 pub struct NautilusObject {
     pub ident: Ident,
     pub raw_type: NautilusObjectRawType,
+    pub object_type: NautilusObjectType,
     pub entry_config: Option<ObjectEntryConfig>,
     pub object_config: Option<NautilusObjectConfig>,
 }
 
 /// The type of Nautilus object.
+///
+/// A `#[derive(State)]` struct marked `#[zero_copy]` is a `ZeroCopyAccount`.
 #[derive(Clone, Debug)]
 pub enum NautilusObjectType {
     Record,
     Account,
+    ZeroCopyAccount,
 }
 
 /// Represent a "raw type" of an object (struct or enum?).
@@ -49,10 +56,42 @@ pub enum NautilusObjectRawType {
     Enum(ItemEnum),
 }
 
+impl NautilusObjectRawType {
+    pub fn ident(&self) -> &Ident {
+        match self {
+            Self::Struct(item_struct) => &item_struct.ident,
+            Self::Enum(item_enum) => &item_enum.ident,
+        }
+    }
+
+    pub fn generics(&self) -> &Generics {
+        match self {
+            Self::Struct(item_struct) => &item_struct.generics,
+            Self::Enum(item_enum) => &item_enum.generics,
+        }
+    }
+
+    /// Every field of the object - for an enum, the fields of all of its
+    /// variants.
+    pub fn all_fields(&self) -> Vec<&Field> {
+        match self {
+            Self::Struct(item_struct) => item_struct.fields.iter().collect(),
+            Self::Enum(item_enum) => item_enum
+                .variants
+                .iter()
+                .flat_map(|v| v.fields.iter())
+                .collect(),
+        }
+    }
+}
+
 /// Entrypoint configurations for underlying accounts.
 #[derive(Clone, Debug)]
 pub struct ObjectEntryConfig {
     pub arg_ident: Ident,
+    /// The data type `T` of a user-defined `Record<'_, T>` or `Account<'_, T>`
+    /// as written in the function signature, including any generic arguments.
+    pub data_ty: Option<Type>,
     pub is_create: bool,
     pub is_signer: bool,
     pub is_mut: bool,
@@ -74,15 +113,62 @@ impl NautilusObject {
     /// entrypoint `#[nautilus]` macro.
     pub fn from_item_struct(value: ItemStruct, nautilus_ty: NautilusObjectType) -> Self {
         let ident = value.ident.clone();
-        let object_config = parse_item_struct(&value, nautilus_ty);
+        let nautilus_ty = match nautilus_ty {
+            NautilusObjectType::Account if is_zero_copy(&value.attrs) => {
+                NautilusObjectType::ZeroCopyAccount
+            }
+            nautilus_ty => nautilus_ty,
+        };
+        let object_config = parse_item_struct(&value, nautilus_ty.clone());
         Self {
             ident,
             raw_type: NautilusObjectRawType::Struct(value.clone()),
+            object_type: nautilus_ty,
             entry_config: None,
             object_config,
         }
     }
 
+    /// Converts the user's annotated enum into the `NautilusObject` struct.
+    ///
+    /// Only `#[derive(nautilus::State)]` supports enums, which is useful for
+    /// storing something like a state machine in an account.
+    pub fn from_item_enum(value: ItemEnum, nautilus_ty: NautilusObjectType) -> Self {
+        let ident = value.ident.clone();
+        if is_zero_copy(&value.attrs) {
+            panic!(
+                "`#[zero_copy]` can't be used on an enum ({}). Zero-copy accounts must be `#[repr(C)]` structs.",
+                ident
+            );
+        }
+        let object_config = parse_item_enum(&value, nautilus_ty.clone());
+        Self {
+            ident,
+            raw_type: NautilusObjectRawType::Enum(value),
+            object_type: nautilus_ty,
+            entry_config: None,
+            object_config,
+        }
+    }
+
+    /// Builds a `NautilusObject` for a user-defined type referenced in a
+    /// function signature, ie. `T` in `Record<'_, T>` or `Account<'_, T>`.
+    ///
+    /// The `#[nautilus]` macro doesn't parse the user's crate, so the type's
+    /// definition isn't available here. The raw type is left as an empty
+    /// struct and no `object_config` is derived, which is fine since
+    /// generating the processor only requires the object's identifier and
+    /// type.
+    pub fn from_signature(ident: Ident, nautilus_ty: NautilusObjectType) -> Self {
+        Self {
+            raw_type: NautilusObjectRawType::Struct(parse_quote! { struct #ident; }),
+            ident,
+            object_type: nautilus_ty,
+            entry_config: None,
+            object_config: None,
+        }
+    }
+
     /// Resolve the required accounts for this object type based on its
     /// configurations and object type (`Record`, `Account`, `Mint`, etc.).
     pub fn get_required_accounts(&self) -> (Vec<RequiredAccount>, Option<Vec<RequiredAccount>>) {
@@ -92,10 +178,7 @@ impl NautilusObject {
                 RequiredAccount::derive_object_type(
                     &self.ident.to_string(),
                     config.is_mut,
-                    self.object_config.as_ref().map(|config| match config {
-                        NautilusObjectConfig::RecordConfig { .. } => NautilusObjectType::Record,
-                        NautilusObjectConfig::AccountConfig { .. } => NautilusObjectType::Account,
-                    })
+                    Some(self.object_type.clone()),
                 ),
                 config.is_create,
                 config.is_signer,
@@ -120,6 +203,7 @@ impl From<&NautilusObject> for TokenStream {
     /// generate tokens for implementing traits.
     fn from(ast: &NautilusObject) -> Self {
         let ident = &ast.ident;
+        let raw_type = &ast.raw_type;
         let object_config = match &ast.object_config {
             Some(object_config) => object_config,
             None => panic!(
@@ -130,23 +214,20 @@ impl From<&NautilusObject> for TokenStream {
         match object_config {
             NautilusObjectConfig::RecordConfig {
                 table_name,
-                data_fields,
                 autoincrement_enabled,
-                primary_key_ident,
+                primary_key,
                 primary_key_ty,
                 authorities: _,          // TODO: Add authority function creation
                 default_instructions: _, // TODO: Add default instructions to
             } => {
-                let fields = &data_fields;
-                let impl_clone = impl_clone(ident, fields);
-                let impl_default = impl_default(ident, fields);
-                let impl_borsh = impl_borsh(ident, fields);
+                let impl_clone = impl_clone(raw_type);
+                let impl_default = impl_default(raw_type);
+                let impl_borsh = impl_borsh(raw_type);
                 let impl_nautilus_record_data = impl_nautilus_record_data(
-                    ident,
-                    fields,
+                    raw_type,
                     &table_name,
                     *autoincrement_enabled,
-                    &primary_key_ident,
+                    &primary_key,
                     &primary_key_ty,
                 );
                 quote! {
@@ -159,16 +240,17 @@ impl From<&NautilusObject> for TokenStream {
             }
             NautilusObjectConfig::AccountConfig {
                 discrminator_str,
-                data_fields,
                 authorities: _, // TODO: Add authority function creation
                 seeds,
             } => {
-                let fields = &data_fields;
-                let impl_clone = impl_clone(ident, fields);
-                let impl_default = impl_default(ident, fields);
-                let impl_borsh = impl_borsh(ident, fields);
+                if let NautilusObjectType::ZeroCopyAccount = ast.object_type {
+                    return impl_nautilus_zero_copy_data(raw_type, discrminator_str, seeds);
+                }
+                let impl_clone = impl_clone(raw_type);
+                let impl_default = impl_default(raw_type);
+                let impl_borsh = impl_borsh(raw_type);
                 let impl_nautilus_account_data =
-                    impl_nautilus_account_data(ident, fields, &discrminator_str, seeds);
+                    impl_nautilus_account_data(raw_type, &discrminator_str, seeds);
                 quote! {
                     #impl_clone
                     #impl_default
@@ -180,3 +262,8 @@ impl From<&NautilusObject> for TokenStream {
         }
     }
 }
+
+/// Whether a type is marked `#[zero_copy]`.
+fn is_zero_copy(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path.is_ident("zero_copy"))
+}
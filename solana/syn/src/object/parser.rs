@@ -1,6 +1,5 @@
-//! Parses a user's defined struct.
-use proc_macro2::TokenStream;
-use syn::{Fields, Ident, ItemStruct, Type};
+//! Parses a user's defined struct or enum.
+use syn::{ItemEnum, ItemStruct, Member, Type};
 
 use crate::object::seeds::SeedParser;
 
@@ -11,23 +10,24 @@ use super::{
 };
 
 /// Object configurations for either a `Record<T>` or `Account<T>`.
+///
+/// The object's fields (or variants) aren't stored here - they're read from
+/// the object's raw type instead.
 #[derive(Clone, Debug)]
 pub enum NautilusObjectConfig {
     /// Object configurations for a `Record<T>`.
     RecordConfig {
         table_name: String,
-        data_fields: Fields,
         autoincrement_enabled: bool,
-        primary_key_ident: Ident,
+        primary_key: Member,
         primary_key_ty: Type,
-        authorities: Vec<Ident>,
+        authorities: Vec<Member>,
         default_instructions: Vec<DefaultInstruction>,
     },
-    /// Object configurations for an `Account<T>`.
+    /// Object configurations for an `Account<T>` or `ZeroCopyAccount<T>`.
     AccountConfig {
         discrminator_str: String,
-        data_fields: Fields,
-        authorities: Vec<Ident>,
+        authorities: Vec<Member>,
         seeds: Vec<Seed>,
     },
 }
@@ -40,68 +40,65 @@ pub struct NautilusAccountFieldAttributes {
 
 /// Parse out a `syn::ItemStruct` according to whichever type of Nautilus object
 /// is attempting to be created from the macro.
+///
+/// Fields are referred to by their `syn::Member`, so named and tuple structs
+/// are both supported.
 pub fn parse_item_struct(
     item_struct: &ItemStruct,
     nautilus_ty: NautilusObjectType,
 ) -> Option<NautilusObjectConfig> {
     let ident_string = item_struct.ident.to_string();
     let discrminator_str = ident_string.clone().to_lowercase();
-    let data_fields = item_struct.fields.clone();
 
     match nautilus_ty {
         NautilusObjectType::Record => {
             let default_instructions =
                 parse_top_level_attributes_for_record(&ident_string, &item_struct.attrs);
 
-            let mut primary_key_ident_opt: Option<(Ident, Type)> = None;
+            let mut primary_key_opt: Option<(Member, Type)> = None;
             let mut autoincrement_enabled: bool = true;
-            let mut authorities: Vec<Ident> = vec![];
-            let mut _optionized_struct_fields: Vec<(Ident, TokenStream, TokenStream)> = vec![];
+            let mut authorities: Vec<Member> = vec![];
 
-            for f in data_fields.iter() {
-                let parsed_attributes = parse_field_attributes(&f);
+            for (i, f) in item_struct.fields.iter().enumerate() {
+                let parsed_attributes = parse_field_attributes(f);
                 if !parsed_attributes.autoincrement_enabled {
                     autoincrement_enabled = parsed_attributes.autoincrement_enabled;
                 }
                 if parsed_attributes.is_primary_key {
-                    primary_key_ident_opt = Some((f.ident.clone().unwrap(), f.ty.clone()));
+                    primary_key_opt = Some((field_member(i, f), f.ty.clone()));
                 }
                 if parsed_attributes.is_authority {
-                    authorities.push(f.ident.clone().unwrap());
+                    authorities.push(field_member(i, f));
                 }
             }
 
-            let (primary_key_ident, primary_key_ty) = match primary_key_ident_opt {
-                Some((ident, ty)) => (ident, ty),
+            let (primary_key, primary_key_ty) = match primary_key_opt {
+                Some((member, ty)) => (member, ty),
                 None => return None,
             };
 
             Some(NautilusObjectConfig::RecordConfig {
                 table_name: discrminator_str,
-                data_fields,
                 autoincrement_enabled,
-                primary_key_ident,
+                primary_key,
                 primary_key_ty,
                 authorities,
                 default_instructions,
             })
         }
-        NautilusObjectType::Account => {
+        NautilusObjectType::Account | NautilusObjectType::ZeroCopyAccount => {
             let seeds = parse_top_level_attributes_for_account(&item_struct.attrs);
 
-            let mut authorities: Vec<Ident> = vec![];
-            let mut _optionized_struct_fields: Vec<(Ident, TokenStream, TokenStream)> = vec![];
-
-            for f in data_fields.iter() {
-                let parsed_attributes = parse_field_attributes(&f);
-                if parsed_attributes.is_authority {
-                    authorities.push(f.ident.clone().unwrap());
-                }
-            }
+            let authorities: Vec<Member> = item_struct
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| parse_field_attributes(f).is_authority)
+                .map(|(i, f)| field_member(i, f))
+                .collect();
 
             Some(NautilusObjectConfig::AccountConfig {
                 discrminator_str,
-                data_fields,
                 authorities,
                 seeds,
             })
@@ -109,6 +106,46 @@ pub fn parse_item_struct(
     }
 }
 
+/// Parse out a `syn::ItemEnum` according to whichever type of Nautilus object
+/// is attempting to be created from the macro.
+///
+/// Only accounts can be enums - a record requires a `#[primary_key]` field,
+/// which an enum can't guarantee.
+pub fn parse_item_enum(
+    item_enum: &ItemEnum,
+    nautilus_ty: NautilusObjectType,
+) -> Option<NautilusObjectConfig> {
+    match nautilus_ty {
+        NautilusObjectType::Record => panic!(
+            "`#[derive(Table)]` can't be used on an enum ({}). Records require a `#[primary_key]` field - use a struct instead.",
+            item_enum.ident
+        ),
+        NautilusObjectType::Account | NautilusObjectType::ZeroCopyAccount => Some(NautilusObjectConfig::AccountConfig {
+            discrminator_str: item_enum.ident.to_string().to_lowercase(),
+            authorities: vec![],
+            seeds: parse_top_level_attributes_for_account(&item_enum.attrs),
+        }),
+    }
+}
+
+/// The `syn::Member` used to access a field: its name, or its index if it's
+/// a tuple field.
+pub fn field_member(index: usize, field: &syn::Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    }
+}
+
+/// The name of a field as it appears in the IDL: its identifier, or its index
+/// if it's a tuple field.
+pub fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
 /// Parses the field attributes of the struct, such as `#[authority]` and
 /// `#[primary_key(..)]`.
 pub fn parse_field_attributes(field: &syn::Field) -> NautilusAccountFieldAttributes {
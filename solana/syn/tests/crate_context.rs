@@ -0,0 +1,84 @@
+//! Reads programs' manifests and sources the way the IDL build does, against
+//! the fixtures in `tests/fixtures`.
+use std::path::{Path, PathBuf};
+
+use nautilus_syn::entry::{crate_context::CrateContext, parser::parse_manifest};
+
+fn fixture(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+#[test]
+fn manifests_inherit_from_the_workspace_above_them() {
+    let (version, name) = parse_manifest(&fixture("workspace/programs/vaults")).unwrap();
+
+    assert_eq!(version, "1.2.3");
+    assert_eq!(name, "vaults");
+}
+
+#[test]
+fn manifests_inherit_from_the_workspace_they_point_to() {
+    let (version, name) = parse_manifest(&fixture("detached/program")).unwrap();
+
+    assert_eq!(version, "4.5.6");
+    assert_eq!(name, "detached");
+}
+
+#[test]
+fn manifests_without_inheritance_are_read_as_is() {
+    let (version, name) = parse_manifest(&fixture("workspace/programs/unresolved")).unwrap();
+
+    assert_eq!(version, "0.1.0");
+    assert_eq!(name, "unresolved");
+}
+
+#[test]
+fn workspace_manifests_arent_packages() {
+    let error = parse_manifest(&fixture("workspace")).unwrap_err();
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("`[package]`"));
+}
+
+#[test]
+fn crates_are_read_through_their_modules() {
+    let crate_context =
+        CrateContext::parse(&fixture("workspace/programs/vaults/src/lib.rs")).unwrap();
+
+    // `mod state;` is read from `state.rs`, `mod records;` from
+    // `records/mod.rs` and its `mod receipt;` from `records/receipt.rs`.
+    let structs: Vec<String> = crate_context
+        .structs()
+        .map(|s| s.ident.to_string())
+        .collect();
+    assert_eq!(structs, ["Receipt", "Deposit", "Vault", "Locker"]);
+    // `#[path = "shared/limits.rs"] mod limits;`
+    let consts: Vec<String> = crate_context
+        .consts()
+        .map(|c| c.ident.to_string())
+        .collect();
+    assert_eq!(consts, ["MAX_DEPOSIT"]);
+    // Only inline modules are kept, and `#[cfg(test)] mod tests;` - which has
+    // no file - is skipped rather than failing.
+    let modules: Vec<String> = crate_context
+        .modules()
+        .map(|m| m.ident.to_string())
+        .collect();
+    assert_eq!(modules, ["program_nautilus"]);
+}
+
+#[test]
+fn missing_module_files_are_errors() {
+    let dir = std::env::temp_dir().join(format!("nautilus-syn-missing-mod-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("lib.rs"), "mod missing;\n").unwrap();
+
+    let error = CrateContext::parse(&dir.join("lib.rs")).unwrap_err();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(error.contains("Failed to find the file for module `missing`"));
+    assert!(error.contains("missing.rs"));
+    assert!(error.contains("mod.rs"));
+}
@@ -0,0 +1,18 @@
+//! A program whose enum wraps a type from another crate in a tuple variant.
+use geometry::Radius;
+use nautilus::*;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub enum Shape {
+    Point,
+    Circle(Radius),
+}
+
+#[nautilus]
+mod program_nautilus {
+    use super::*;
+
+    fn draw(shape: Shape) -> ProgramResult {
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+//! A program whose objects are spread across its modules.
+use nautilus::*;
+
+mod records;
+mod state;
+
+#[path = "shared/limits.rs"]
+mod limits;
+
+// There's no `tests.rs`: test-only modules are never read.
+#[cfg(test)]
+mod tests;
+
+pub use limits::*;
+use records::{Deposit, Receipt};
+use state::Vault;
+
+#[nautilus]
+mod program_nautilus {
+    use super::*;
+
+    fn create_vault<'a>(mut new_vault: Create<'a, Account<'a, Vault>>, owner: Pubkey) -> ProgramResult {
+        new_vault.create(owner)
+    }
+
+    fn create_deposit<'a>(
+        mut new_deposit: Create<'a, Record<'a, Deposit>>,
+        receipt: Receipt,
+    ) -> ProgramResult {
+        new_deposit.create(receipt.amount)
+    }
+}
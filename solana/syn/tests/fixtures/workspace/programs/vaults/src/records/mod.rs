@@ -0,0 +1,12 @@
+use nautilus::*;
+
+mod receipt;
+
+pub use receipt::Receipt;
+
+#[derive(Table)]
+pub struct Deposit {
+    #[primary_key(autoincrement = true)]
+    pub id: u32,
+    pub amount: u64,
+}
@@ -0,0 +1,6 @@
+use nautilus::*;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Default)]
+pub struct Receipt {
+    pub amount: u64,
+}
@@ -0,0 +1,2 @@
+/// The most a single deposit can hold.
+pub const MAX_DEPOSIT: u64 = 1_000;
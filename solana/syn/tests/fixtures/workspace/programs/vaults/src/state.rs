@@ -0,0 +1,19 @@
+use nautilus::*;
+
+#[derive(State)]
+#[seeds(
+    "vault",                // Literal seed
+    owner,                  // Self-referencing seed
+)]
+pub struct Vault {
+    #[authority]
+    pub owner: Pubkey,
+}
+
+/// Never used by an instruction, so the IDL can't describe any instantiation
+/// of it.
+#[derive(State)]
+#[seeds("locker")]
+pub struct Locker<T> {
+    pub contents: T,
+}
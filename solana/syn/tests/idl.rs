@@ -0,0 +1,99 @@
+//! Builds IDLs straight from programs' sources, without expanding the
+//! `#[nautilus]` macro.
+use std::path::{Path, PathBuf};
+
+use nautilus_idl::{
+    idl_nautilus_config::IdlSeed, idl_type_def::IdlTypeDef, util::load_idl_from_json, Idl,
+};
+use nautilus_syn::entry::idl::build_idl;
+
+/// A program in `tests/fixtures/workspace`.
+fn fixture_dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/workspace/programs")
+        .join(name)
+}
+
+/// A crate in `tests/programs`.
+fn program_dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/programs")
+        .join(name)
+}
+
+/// The IDL checked in for a program, which the clients in `nautilus-idl`'s
+/// golden tests are generated from.
+fn golden_idl(name: &str) -> Idl {
+    load_idl_from_json(&format!(
+        "{}/../idl/tests/golden/{}.json",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+#[test]
+fn builds_the_idl_of_a_test_program() {
+    let idl = build_idl(&program_dir("accounts")).unwrap();
+
+    // `Idl` isn't `PartialEq`, but its debug output covers every field.
+    assert_eq!(
+        format!("{:#?}", idl),
+        format!("{:#?}", golden_idl("accounts"))
+    );
+}
+
+#[test]
+fn missing_manifests_are_errors() {
+    let dir = program_dir("does-not-exist");
+
+    let error = build_idl(&dir).unwrap_err();
+
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+    assert!(error.to_string().contains("Failed to detect `Cargo.toml`"));
+}
+
+#[test]
+fn builds_the_idl_of_a_program_split_across_modules() {
+    let idl = build_idl(&fixture_dir("vaults")).unwrap();
+
+    assert_eq!(idl.name, "vaults");
+    assert_eq!(idl.version, "1.2.3");
+    let names = |type_defs: &[IdlTypeDef]| -> Vec<String> {
+        type_defs.iter().map(|t| t.name.clone()).collect()
+    };
+    // `Locker<T>` is generic and never instantiated, so it's left out.
+    assert_eq!(names(&idl.accounts), ["Deposit", "Vault"]);
+    assert_eq!(names(&idl.types), ["Receipt"]);
+    let constants: Vec<&str> = idl.constants.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(constants, ["MAX_DEPOSIT"]);
+    let instructions: Vec<&str> = idl.instructions.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(instructions, ["createVault", "createDeposit"]);
+    // The objects' configs are read from their own modules.
+    let deposit = idl.accounts[0].config.as_ref().unwrap();
+    assert_eq!(deposit.table_name.as_deref(), Some("deposit"));
+    assert_eq!(deposit.primary_key.as_deref(), Some("id"));
+    let vault = idl.accounts[1].config.as_ref().unwrap();
+    assert_eq!(vault.discrminator_str.as_deref(), Some("vault"));
+    assert_eq!(
+        vault.seeds,
+        [
+            IdlSeed::Lit {
+                value: "vault".to_string()
+            },
+            IdlSeed::Field {
+                key: "owner".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn types_from_other_crates_in_tuple_variants_are_errors() {
+    let error = build_idl(&fixture_dir("unresolved")).unwrap_err();
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error
+        .to_string()
+        .contains("`Radius` (used in `Shape::Circle.0`)"));
+}
@@ -16,18 +16,30 @@ mod program_nautilus {
         //
         new_person.create(name, authority)?;
         //
-        new_person.self_account.print();
+        new_person.self_account.print()?;
         Ok(())
     }
 
     fn read_person<'a>(person: Account<'a, Person>) -> ProgramResult {
-        person.print();
+        person.print()?;
         //
         // /* Business Logic */
         //
         Ok(())
     }
 
+    fn check_person<'a>(person: Account<'a, Person>) -> ProgramResult {
+        // Only the key is used, so the data is never deserialized.
+        info!(" * Person exists: {}", person.key());
+        Ok(())
+    }
+
+    fn read_person_authority<'a>(person: Account<'a, Person>) -> ProgramResult {
+        let data = Lazy::try_get(&person.data)?;
+        info!(" * Authority: {}", data.authority);
+        Ok(())
+    }
+
     fn create_home<'a>(
         mut new_home: Create<'a, Account<'a, Home>>,
         house_number: u8,
@@ -40,12 +52,12 @@ mod program_nautilus {
         //
         new_home.create(house_number, street, (some_pubkey,))?; // Seed parameter required
                                                                 //
-        new_home.self_account.print();
+        new_home.self_account.print()?;
         Ok(())
     }
 
     fn read_home<'a>(home: Account<'a, Home>) -> ProgramResult {
-        home.print();
+        home.print()?;
         //
         // /* Business Logic */
         //
@@ -65,15 +77,152 @@ mod program_nautilus {
         //
         new_car.create(make, model, purchase_authority, operating_authority)?;
         //
-        new_car.self_account.print();
+        new_car.self_account.print()?;
         Ok(())
     }
 
     fn read_car<'a>(car: Account<'a, Car>) -> ProgramResult {
-        car.print();
+        car.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_counter<'a>(
+        mut new_counter: Create<'a, Account<'a, Counter>>,
+        owner: Pubkey,
+        count: u64,
+    ) -> ProgramResult {
+        info!(" * New Counter:       {}", &new_counter.key());
+        //
+        // /* Business Logic */
+        //
+        new_counter.create(owner, count, (owner,))?;
+        //
+        new_counter.self_account.print()?;
+        Ok(())
+    }
+
+    fn read_counter<'a>(counter: Account<'a, Counter>) -> ProgramResult {
+        counter.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_light<'a>(
+        mut new_light: Create<'a, Account<'a, Light>>,
+        owner: Pubkey,
+        brightness: u8,
+        on_ms: u16,
+        off_ms: u16,
+    ) -> ProgramResult {
+        info!(" * New Light:         {}", &new_light.key());
+        //
+        // /* Business Logic */
+        //
+        let light = match (brightness, on_ms) {
+            (0, _) => Light::Off,
+            (brightness, 0) => Light::On { brightness },
+            _ => Light::Blinking(on_ms, off_ms),
+        };
+        new_light.create(light, (owner,))?;
+        //
+        new_light.self_account.print()?;
+        Ok(())
+    }
+
+    fn read_light<'a>(light: Account<'a, Light>) -> ProgramResult {
+        light.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_order_book<'a>(
+        mut new_order_book: Create<'a, ZeroCopyAccount<'a, OrderBook>>,
+        market: Pubkey,
+    ) -> ProgramResult {
+        info!(" * New Order Book: {}", &new_order_book.key());
+        //
+        // /* Business Logic */
+        //
+        new_order_book.create((market,))?; // Seed parameter required
+        new_order_book.self_account.data_mut()?.market = market;
+        //
+        new_order_book.self_account.print()?;
+        Ok(())
+    }
+
+    fn place_order<'a>(
+        order_book: Mut<ZeroCopyAccount<'a, OrderBook>>,
+        price: u64,
+        size: u64,
+    ) -> ProgramResult {
         //
         // /* Business Logic */
         //
+        {
+            let mut data = order_book.self_account.data_mut()?;
+            let index = data.len as usize;
+            if index == data.prices.len() {
+                return Err(ProgramError::InvalidArgument);
+            }
+            data.prices[index] = price;
+            data.sizes[index] = size;
+            data.len += 1;
+        }
+        //
+        order_book.self_account.print()?;
+        Ok(())
+    }
+
+    fn read_order_book<'a>(order_book: ZeroCopyAccount<'a, OrderBook>) -> ProgramResult {
+        order_book.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_balance_vault<'a>(
+        mut new_vault: Create<'a, Account<'a, Vault<u64>>>,
+        owner: Pubkey,
+        balance: u64,
+    ) -> ProgramResult {
+        info!(" * New Balance Vault: {}", &new_vault.key());
+        //
+        // /* Business Logic */
+        //
+        new_vault.create(balance, (owner,))?;
+        //
+        new_vault.self_account.print()?;
+        Ok(())
+    }
+
+    fn read_balance_vault<'a>(vault: Account<'a, Vault<u64>>) -> ProgramResult {
+        vault.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_flag_vault<'a>(
+        mut new_vault: Create<'a, Account<'a, Vault<bool>>>,
+        owner: Pubkey,
+        flag: bool,
+    ) -> ProgramResult {
+        info!(" * New Flag Vault:    {}", &new_vault.key());
+        //
+        // /* Business Logic */
+        //
+        new_vault.create(flag, (owner,))?;
+        //
+        new_vault.self_account.print()?;
         Ok(())
     }
 }
@@ -114,34 +263,131 @@ struct Car {
     operating_authority: Pubkey,
 }
 
+#[derive(State)]
+#[seeds(
+    "counter",              // Literal seed
+    owner: Pubkey,          // Parameter seed
+)]
+pub struct Counter(pub Pubkey, pub u64);
+
+#[derive(State, Debug, PartialEq)]
+#[seeds(
+    "light",                // Literal seed
+    owner: Pubkey,          // Parameter seed
+)]
+pub enum Light {
+    Off,
+    On { brightness: u8 },
+    Blinking(u16, u16),
+}
+
+#[derive(State)]
+#[zero_copy] // Read and written in place, rather than deserialized
+#[seeds(
+    "order_book",           // Literal seed
+    market: Pubkey,         // Parameter seed
+)]
+#[repr(C)]
+pub struct OrderBook {
+    pub market: Pubkey,
+    pub len: u64,
+    pub prices: [u64; 64],
+    pub sizes: [u64; 64],
+}
+
+#[derive(State)]
+#[seeds(
+    "vault",                // Literal seed
+    owner: Pubkey,          // Parameter seed
+)]
+pub struct Vault<T> {
+    pub value: T,
+}
+
 //
 
 pub trait TestPrint {
-    fn print(&self);
+    fn print(&self) -> ProgramResult;
 }
 
 impl TestPrint for Account<'_, Person> {
-    fn print(&self) {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
         info!(" * Person: {}", self.key());
-        info!("      Name:           {}", self.data.name);
-        info!("      Authority:      {}", self.data.authority);
+        info!("      Name:           {}", data.name);
+        info!("      Authority:      {}", data.authority);
+        Ok(())
     }
 }
 
 impl TestPrint for Account<'_, Home> {
-    fn print(&self) {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
         info!(" * Home: {}", self.key());
-        info!("      House Number:   {}", self.data.house_number);
-        info!("      Street:         {}", self.data.street);
+        info!("      House Number:   {}", data.house_number);
+        info!("      Street:         {}", data.street);
+        Ok(())
     }
 }
 
 impl TestPrint for Account<'_, Car> {
-    fn print(&self) {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
         info!(" * Car: {}", self.key());
-        info!("      Make:           {}", self.data.make);
-        info!("      Model:          {}", self.data.model);
-        info!("      Purchase Auth:  {}", self.data.purchase_authority);
-        info!("      Operating Auth: {}", self.data.operating_authority);
+        info!("      Make:           {}", data.make);
+        info!("      Model:          {}", data.model);
+        info!("      Purchase Auth:  {}", data.purchase_authority);
+        info!("      Operating Auth: {}", data.operating_authority);
+        Ok(())
+    }
+}
+
+impl TestPrint for Account<'_, Counter> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!(" * Counter: {}", self.key());
+        info!("      Owner:          {}", data.0);
+        info!("      Count:          {}", data.1);
+        Ok(())
+    }
+}
+
+impl TestPrint for Account<'_, Light> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!(" * Light: {}", self.key());
+        info!("      State:          {:?}", data);
+        Ok(())
+    }
+}
+
+impl TestPrint for ZeroCopyAccount<'_, OrderBook> {
+    fn print(&self) -> ProgramResult {
+        let data = self.data()?;
+        info!(" * Order Book: {}", self.key());
+        info!("      Market:         {}", data.market);
+        info!("      Orders:         {}", data.len);
+        for i in 0..data.len as usize {
+            info!("      {} @ {}", data.sizes[i], data.prices[i]);
+        }
+        Ok(())
+    }
+}
+
+impl TestPrint for Account<'_, Vault<u64>> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!(" * Balance Vault: {}", self.key());
+        info!("      Balance:        {}", data.value);
+        Ok(())
+    }
+}
+
+impl TestPrint for Account<'_, Vault<bool>> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!(" * Flag Vault: {}", self.key());
+        info!("      Flag:           {}", data.value);
+        Ok(())
     }
 }
@@ -0,0 +1,397 @@
+//! Runs the accounts program's people, counters, lights, zero-copy order book
+//! and generic vaults natively with `nautilus::testing`.
+use nautilus::{
+    borsh::BorshDeserialize,
+    error::NautilusError,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+    },
+    sysvar,
+    testing::{TestAccount, TestError, TestHarness},
+    NautilusAccountData, NautilusZeroCopyData, Pubkey, ZeroCopyAccount,
+};
+use program_nautilus::{Counter, Light, NautilusEntrypoint, OrderBook, Vault};
+
+fn setup() -> (TestHarness, Pubkey) {
+    let mut harness = TestHarness::new(Pubkey::new_unique(), program_nautilus::process_instruction);
+    let payer = harness.add_wallet(LAMPORTS_PER_SOL);
+    (harness, payer)
+}
+
+fn create_person(harness: &TestHarness, payer: Pubkey, authority: Pubkey) -> (Instruction, Pubkey) {
+    let (person, _) =
+        Pubkey::find_program_address(&[b"person", authority.as_ref()], &harness.program_id());
+    let ix = harness.instruction(
+        NautilusEntrypoint::CreatePerson("Joe".to_string(), authority),
+        vec![
+            AccountMeta::new(person, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(nautilus::system_program::ID, false),
+        ],
+    );
+    (ix, person)
+}
+
+fn check_person(harness: &TestHarness, person: Pubkey) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::CheckPerson(),
+        vec![AccountMeta::new_readonly(person, false)],
+    )
+}
+
+fn read_person_authority(harness: &TestHarness, person: Pubkey) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::ReadPersonAuthority(),
+        vec![AccountMeta::new_readonly(person, false)],
+    )
+}
+
+/// The accounts of an instruction creating an account at `address`.
+fn create_accounts(address: Pubkey, payer: Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(address, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new_readonly(nautilus::system_program::ID, false),
+    ]
+}
+
+/// Decodes an account's data. The data types' Borsh implementations read past
+/// the discriminator themselves.
+fn account_data<T: BorshDeserialize>(harness: &TestHarness, address: &Pubkey) -> T {
+    T::try_from_slice(&harness.account(address).unwrap().data).unwrap()
+}
+
+fn create_order_book(
+    harness: &TestHarness,
+    order_book: Pubkey,
+    payer: Pubkey,
+    market: Pubkey,
+) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::CreateOrderBook(market),
+        vec![
+            AccountMeta::new(order_book, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(nautilus::system_program::ID, false),
+        ],
+    )
+}
+
+fn place_order(harness: &TestHarness, order_book: Pubkey, price: u64, size: u64) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::PlaceOrder(price, size),
+        vec![AccountMeta::new(order_book, false)],
+    )
+}
+
+fn read_order_book(harness: &TestHarness, order_book: Pubkey) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::ReadOrderBook(),
+        vec![AccountMeta::new_readonly(order_book, false)],
+    )
+}
+
+/// The order book's data, read straight from its account's bytes.
+fn order_book_data(harness: &TestHarness, order_book: &Pubkey) -> OrderBook {
+    let data = &harness.account(order_book).unwrap().data;
+    assert_eq!(data[..8], OrderBook::DISCRIMINATOR);
+    *nautilus::bytemuck::from_bytes(&data[8..])
+}
+
+fn vault_address(harness: &TestHarness, owner: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault", owner.as_ref()], &harness.program_id()).0
+}
+
+fn read_balance_vault(harness: &TestHarness, vault: Pubkey) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::ReadBalanceVault(),
+        vec![AccountMeta::new_readonly(vault, false)],
+    )
+}
+
+fn person_data_failed(person: &Pubkey) -> TestError {
+    TestError::Program(
+        NautilusError::DeserializeDataFailed("person".to_string(), person.to_string()).into(),
+    )
+}
+
+#[test]
+fn read_an_account_lazily() {
+    let (mut harness, payer) = setup();
+    let authority = Pubkey::new_unique();
+    let (create, person) = create_person(&harness, payer, authority);
+    harness.process(&create).unwrap();
+
+    let processed = harness
+        .process(&read_person_authority(&harness, person))
+        .unwrap();
+
+    let authority_line = format!(" * Authority: {}", authority);
+    assert!(processed.messages().contains(&authority_line.as_str()));
+}
+
+#[test]
+fn accounts_are_validated_up_front() {
+    let (mut harness, payer) = setup();
+    let (create, person) = create_person(&harness, payer, Pubkey::new_unique());
+    harness.process(&create).unwrap();
+    let mut account = harness.account(&person).unwrap().clone();
+    account.owner = Pubkey::new_unique();
+    harness.add_account(person, account);
+
+    // The data is never read, but the wrong owner still fails the instruction.
+    let failed = harness
+        .process(&check_person(&harness, person))
+        .unwrap_err();
+
+    assert_eq!(failed.error, person_data_failed(&person));
+}
+
+#[test]
+fn account_data_is_deserialized_only_when_accessed() {
+    let (mut harness, payer) = setup();
+    let (create, person) = create_person(&harness, payer, Pubkey::new_unique());
+    harness.process(&create).unwrap();
+    // Keep the discriminator but drop the data after it.
+    let mut account = harness.account(&person).unwrap().clone();
+    account.data.truncate(8);
+    harness.add_account(person, account);
+
+    harness.process(&check_person(&harness, person)).unwrap();
+    let failed = harness
+        .process(&read_person_authority(&harness, person))
+        .unwrap_err();
+
+    assert_eq!(failed.error, person_data_failed(&person));
+}
+
+#[test]
+fn create_and_read_a_tuple_struct_account() {
+    let (mut harness, payer) = setup();
+    let owner = Pubkey::new_unique();
+    let (counter, _) =
+        Pubkey::find_program_address(&[b"counter", owner.as_ref()], &harness.program_id());
+    harness
+        .process(&harness.instruction(
+            NautilusEntrypoint::CreateCounter(owner, 42),
+            create_accounts(counter, payer),
+        ))
+        .unwrap();
+
+    let processed = harness
+        .process(&harness.instruction(
+            NautilusEntrypoint::ReadCounter(),
+            vec![AccountMeta::new_readonly(counter, false)],
+        ))
+        .unwrap();
+
+    let data: Counter = account_data(&harness, &counter);
+    assert_eq!((data.0, data.1), (owner, 42));
+    assert!(processed.messages().contains(&"      Count:          42"));
+}
+
+#[test]
+fn create_and_read_enum_accounts() {
+    let (mut harness, payer) = setup();
+    for (args, light) in [
+        ((0, 0, 0), Light::Off),
+        ((80, 0, 0), Light::On { brightness: 80 }),
+        ((80, 500, 250), Light::Blinking(500, 250)),
+    ] {
+        let owner = Pubkey::new_unique();
+        let (address, _) =
+            Pubkey::find_program_address(&[b"light", owner.as_ref()], &harness.program_id());
+        harness
+            .process(&harness.instruction(
+                NautilusEntrypoint::CreateLight(owner, args.0, args.1, args.2),
+                create_accounts(address, payer),
+            ))
+            .unwrap();
+
+        let processed = harness
+            .process(&harness.instruction(
+                NautilusEntrypoint::ReadLight(),
+                vec![AccountMeta::new_readonly(address, false)],
+            ))
+            .unwrap();
+
+        assert_eq!(account_data::<Light>(&harness, &address), light);
+        let state_line = format!("      State:          {:?}", light);
+        assert!(processed.messages().contains(&state_line.as_str()));
+    }
+}
+
+#[test]
+fn generic_accounts_are_discriminated_by_their_type_arguments() {
+    let (mut harness, payer) = setup();
+    let (balance_owner, flag_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let balance_vault = vault_address(&harness, balance_owner);
+    let flag_vault = vault_address(&harness, flag_owner);
+    harness
+        .process(&harness.instruction(
+            NautilusEntrypoint::CreateBalanceVault(balance_owner, 42),
+            create_accounts(balance_vault, payer),
+        ))
+        .unwrap();
+    harness
+        .process(&harness.instruction(
+            NautilusEntrypoint::CreateFlagVault(flag_owner, true),
+            create_accounts(flag_vault, payer),
+        ))
+        .unwrap();
+
+    let processed = harness
+        .process(&read_balance_vault(&harness, balance_vault))
+        .unwrap();
+    // A `Vault<bool>` can't be loaded as a `Vault<u64>`.
+    let failed = harness
+        .process(&read_balance_vault(&harness, flag_vault))
+        .unwrap_err();
+
+    assert_eq!(Vault::<u64>::DISCRIMINATOR_STR, "vault<u64>");
+    assert_eq!(Vault::<bool>::DISCRIMINATOR_STR, "vault<bool>");
+    assert_eq!(
+        account_data::<Vault<u64>>(&harness, &balance_vault).value,
+        42
+    );
+    assert!(account_data::<Vault<bool>>(&harness, &flag_vault).value);
+    assert!(processed.messages().contains(&"      Balance:        42"));
+    assert_eq!(
+        failed.error,
+        TestError::Program(
+            NautilusError::DeserializeDataFailed("vault<u64>".to_string(), flag_vault.to_string())
+                .into()
+        )
+    );
+}
+
+#[test]
+fn create_a_zero_copy_account() {
+    let (mut harness, payer) = setup();
+    let market = Pubkey::new_unique();
+    let (order_book, _) = OrderBook::pda(&harness.program_id(), (market,)).unwrap();
+
+    harness
+        .process(&create_order_book(&harness, order_book, payer, market))
+        .unwrap();
+
+    let span = ZeroCopyAccount::<OrderBook>::SPAN;
+    let account = harness.account(&order_book).unwrap();
+    assert_eq!(span, 8 + 32 + 8 + 64 * 8 * 2);
+    assert_eq!(account.data.len(), span);
+    assert_eq!(account.owner, harness.program_id());
+    assert_eq!(account.lamports, harness.rent().minimum_balance(span));
+    let data = order_book_data(&harness, &order_book);
+    assert_eq!(data.market, market);
+    assert_eq!(data.len, 0);
+}
+
+#[test]
+fn write_a_zero_copy_account_in_place() {
+    let (mut harness, payer) = setup();
+    let market = Pubkey::new_unique();
+    let (order_book, _) = OrderBook::pda(&harness.program_id(), (market,)).unwrap();
+    harness
+        .process(&create_order_book(&harness, order_book, payer, market))
+        .unwrap();
+
+    harness
+        .process(&place_order(&harness, order_book, 100, 5))
+        .unwrap();
+    harness
+        .process(&place_order(&harness, order_book, 101, 7))
+        .unwrap();
+    let processed = harness
+        .process(&read_order_book(&harness, order_book))
+        .unwrap();
+
+    let data = order_book_data(&harness, &order_book);
+    assert_eq!(data.len, 2);
+    assert_eq!(data.prices[..2], [100, 101]);
+    assert_eq!(data.sizes[..2], [5, 7]);
+    assert!(processed.messages().contains(&"      7 @ 101"));
+}
+
+#[test]
+fn zero_copy_accounts_check_their_discriminator() {
+    let (mut harness, _) = setup();
+    let order_book = Pubkey::new_unique();
+    let mut data = vec![0; ZeroCopyAccount::<OrderBook>::SPAN];
+    data[..8].copy_from_slice(&[1; 8]);
+    harness.add_account(
+        order_book,
+        TestAccount::rent_exempt(data, harness.program_id()),
+    );
+
+    let failed = harness
+        .process(&read_order_book(&harness, order_book))
+        .unwrap_err();
+
+    assert_eq!(
+        failed.error,
+        TestError::Program(
+            NautilusError::DeserializeDataFailed(
+                OrderBook::DISCRIMINATOR_STR.to_string(),
+                order_book.to_string()
+            )
+            .into()
+        )
+    );
+}
+
+#[test]
+fn zero_copy_accounts_check_their_owner() {
+    let (mut harness, _) = setup();
+    let order_book = Pubkey::new_unique();
+    let mut data = vec![0; ZeroCopyAccount::<OrderBook>::SPAN];
+    data[..8].copy_from_slice(&OrderBook::DISCRIMINATOR);
+    harness.add_account(
+        order_book,
+        TestAccount::rent_exempt(data, Pubkey::new_unique()),
+    );
+
+    let failed = harness
+        .process(&read_order_book(&harness, order_book))
+        .unwrap_err();
+
+    assert_eq!(
+        failed.error,
+        TestError::Program(
+            NautilusError::DeserializeDataFailed(
+                OrderBook::DISCRIMINATOR_STR.to_string(),
+                order_book.to_string()
+            )
+            .into()
+        )
+    );
+}
+
+#[test]
+fn zero_copy_accounts_check_their_size() {
+    let (mut harness, _) = setup();
+    let order_book = Pubkey::new_unique();
+    let data = OrderBook::DISCRIMINATOR.to_vec();
+    harness.add_account(
+        order_book,
+        TestAccount::rent_exempt(data, harness.program_id()),
+    );
+
+    let failed = harness
+        .process(&read_order_book(&harness, order_book))
+        .unwrap_err();
+
+    assert_eq!(
+        failed.error,
+        TestError::Program(
+            NautilusError::DeserializeDataFailed(
+                OrderBook::DISCRIMINATOR_STR.to_string(),
+                order_book.to_string()
+            )
+            .into()
+        )
+    );
+}
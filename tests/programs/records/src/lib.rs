@@ -28,12 +28,12 @@ mod program_nautilus {
         //
         new_person.create(name, authority)?;
         //
-        new_person.self_account.print();
+        new_person.self_account.print()?;
         Ok(())
     }
 
     fn read_person<'a>(person: Record<'a, Person>) -> ProgramResult {
-        person.print();
+        person.print()?;
         //
         // /* Business Logic */
         //
@@ -52,12 +52,12 @@ mod program_nautilus {
         //
         new_home.create(id, house_number, street)?;
         //
-        new_home.self_account.print();
+        new_home.self_account.print()?;
         Ok(())
     }
 
     fn read_home<'a>(home: Record<'a, Home>) -> ProgramResult {
-        home.print();
+        home.print()?;
         //
         // /* Business Logic */
         //
@@ -77,18 +77,73 @@ mod program_nautilus {
         //
         new_car.create(make, model, purchase_authority, operating_authority)?;
         //
-        new_car.self_account.print();
+        new_car.self_account.print()?;
         Ok(())
     }
 
     fn read_car<'a>(car: Record<'a, Car>) -> ProgramResult {
-        car.print();
+        car.print()?;
         //
         // /* Business Logic */
         //
         Ok(())
     }
 
+    fn create_tag<'a>(mut new_tag: Create<'a, Record<'a, Tag>>, label: String) -> ProgramResult {
+        info!("-- New Tag: {}", &new_tag.key());
+        //
+        // /* Business Logic */
+        //
+        new_tag.create(label)?;
+        //
+        new_tag.self_account.print()?;
+        Ok(())
+    }
+
+    fn read_tag<'a>(tag: Record<'a, Tag>) -> ProgramResult {
+        tag.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_number_setting<'a>(
+        mut new_setting: Create<'a, Record<'a, Setting<u32>>>,
+        value: u32,
+    ) -> ProgramResult {
+        info!("-- New Number Setting: {}", &new_setting.key());
+        //
+        // /* Business Logic */
+        //
+        new_setting.create(value)?;
+        //
+        new_setting.self_account.print()?;
+        Ok(())
+    }
+
+    fn read_number_setting<'a>(setting: Record<'a, Setting<u32>>) -> ProgramResult {
+        setting.print()?;
+        //
+        // /* Business Logic */
+        //
+        Ok(())
+    }
+
+    fn create_label_setting<'a>(
+        mut new_setting: Create<'a, Record<'a, Setting<String>>>,
+        value: String,
+    ) -> ProgramResult {
+        info!("-- New Label Setting: {}", &new_setting.key());
+        //
+        // /* Business Logic */
+        //
+        new_setting.create(value)?;
+        //
+        new_setting.self_account.print()?;
+        Ok(())
+    }
+
     fn fund_person<'a>(
         person: Mut<Record<'a, Person>>,
         payer: Signer<Wallet<'a>>,
@@ -168,37 +223,83 @@ struct Car {
     operating_authority: Pubkey,
 }
 
+#[derive(Table)]
+pub struct Tag(#[primary_key(autoincrement = true)] pub u8, pub String);
+
+#[derive(Table)]
+pub struct Setting<T> {
+    #[primary_key(autoincrement = true)]
+    pub id: u8,
+    pub value: T,
+}
+
 //
 
 pub trait TestPrint {
-    fn print(&self);
+    fn print(&self) -> ProgramResult;
 }
 
 impl TestPrint for Record<'_, Person> {
-    fn print(&self) {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
         info!("-- Person: {}", self.key());
-        info!("      ID:             {}", self.data.id);
-        info!("      Name:           {}", self.data.name);
-        info!("      Authority:      {}", self.data.authority);
+        info!("      ID:             {}", data.id);
+        info!("      Name:           {}", data.name);
+        info!("      Authority:      {}", data.authority);
+        Ok(())
     }
 }
 
 impl TestPrint for Record<'_, Home> {
-    fn print(&self) {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
         info!("-- Home: {}", self.key());
-        info!("      ID:             {}", self.data.id);
-        info!("      House Number:   {}", self.data.house_number);
-        info!("      Street:         {}", self.data.street);
+        info!("      ID:             {}", data.id);
+        info!("      House Number:   {}", data.house_number);
+        info!("      Street:         {}", data.street);
+        Ok(())
     }
 }
 
 impl TestPrint for Record<'_, Car> {
-    fn print(&self) {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
         info!("-- Car: {}", self.key());
-        info!("      ID:             {}", self.data.id);
-        info!("      Make:           {}", self.data.make);
-        info!("      Model:          {}", self.data.model);
-        info!("      Purchase Auth:  {}", self.data.purchase_authority);
-        info!("      Operating Auth: {}", self.data.operating_authority);
+        info!("      ID:             {}", data.id);
+        info!("      Make:           {}", data.make);
+        info!("      Model:          {}", data.model);
+        info!("      Purchase Auth:  {}", data.purchase_authority);
+        info!("      Operating Auth: {}", data.operating_authority);
+        Ok(())
+    }
+}
+
+impl TestPrint for Record<'_, Tag> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!("-- Tag: {}", self.key());
+        info!("      ID:             {}", data.0);
+        info!("      Label:          {}", data.1);
+        Ok(())
+    }
+}
+
+impl TestPrint for Record<'_, Setting<u32>> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!("-- Number Setting: {}", self.key());
+        info!("      ID:             {}", data.id);
+        info!("      Value:          {}", data.value);
+        Ok(())
+    }
+}
+
+impl TestPrint for Record<'_, Setting<String>> {
+    fn print(&self) -> ProgramResult {
+        let data = Lazy::try_get(&self.data)?;
+        info!("-- Label Setting: {}", self.key());
+        info!("      ID:             {}", data.id);
+        info!("      Value:          {}", data.value);
+        Ok(())
     }
 }
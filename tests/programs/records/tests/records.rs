@@ -0,0 +1,170 @@
+//! Runs the records program's tuple struct and generic tables natively with
+//! `nautilus::testing`.
+use nautilus::{
+    borsh::BorshDeserialize,
+    error::NautilusError,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+    },
+    sysvar,
+    testing::{TestError, TestHarness},
+    NautilusRecordData, Pubkey,
+};
+use program_nautilus::{NautilusEntrypoint, Setting, Tag};
+
+struct Setup {
+    harness: TestHarness,
+    payer: Pubkey,
+    index: Pubkey,
+}
+
+/// Sets up the harness with the Nautilus Index already initialized.
+fn setup() -> Setup {
+    let program_id = Pubkey::new_unique();
+    let mut harness = TestHarness::new(program_id, program_nautilus::process_instruction);
+    let payer = harness.add_wallet(LAMPORTS_PER_SOL);
+    let (index, _) = Pubkey::find_program_address(&[b"nautilus_index", &[0]], &program_id);
+    harness
+        .process(&harness.instruction(
+            NautilusEntrypoint::Initialize(),
+            vec![
+                AccountMeta::new(index, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(sysvar::rent::ID, false),
+                AccountMeta::new_readonly(nautilus::system_program::ID, false),
+            ],
+        ))
+        .unwrap();
+    Setup {
+        harness,
+        payer,
+        index,
+    }
+}
+
+/// The address of the tag with the given (autoincremented) ID.
+fn tag_address(s: &Setup, id: u8) -> Pubkey {
+    Pubkey::find_program_address(&[b"tag", &[id]], &s.harness.program_id()).0
+}
+
+fn create_tag(s: &Setup, id: u8, label: &str) -> Instruction {
+    s.harness.instruction(
+        NautilusEntrypoint::CreateTag(label.to_string()),
+        vec![
+            AccountMeta::new(s.index, false),
+            AccountMeta::new(tag_address(s, id), false),
+            AccountMeta::new(s.payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(nautilus::system_program::ID, false),
+        ],
+    )
+}
+
+fn read_tag(s: &Setup, id: u8) -> Instruction {
+    s.harness.instruction(
+        NautilusEntrypoint::ReadTag(),
+        vec![
+            AccountMeta::new_readonly(s.index, false),
+            AccountMeta::new_readonly(tag_address(s, id), false),
+        ],
+    )
+}
+
+/// The address of the record with the given (autoincremented) ID in a table.
+fn record_address(s: &Setup, table_name: &str, id: u8) -> Pubkey {
+    Pubkey::find_program_address(&[table_name.as_bytes(), &[id]], &s.harness.program_id()).0
+}
+
+fn create_record(s: &Setup, entry: NautilusEntrypoint, address: Pubkey) -> Instruction {
+    s.harness.instruction(
+        entry,
+        vec![
+            AccountMeta::new(s.index, false),
+            AccountMeta::new(address, false),
+            AccountMeta::new(s.payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(nautilus::system_program::ID, false),
+        ],
+    )
+}
+
+fn read_number_setting(s: &Setup, address: Pubkey) -> Instruction {
+    s.harness.instruction(
+        NautilusEntrypoint::ReadNumberSetting(),
+        vec![
+            AccountMeta::new_readonly(s.index, false),
+            AccountMeta::new_readonly(address, false),
+        ],
+    )
+}
+
+/// Decodes a tag's account data. `Tag`'s Borsh implementation reads past the
+/// discriminator itself.
+fn tag(s: &Setup, id: u8) -> Tag {
+    Tag::try_from_slice(&s.harness.account(&tag_address(s, id)).unwrap().data).unwrap()
+}
+
+#[test]
+fn create_and_read_tuple_struct_records() {
+    let mut s = setup();
+
+    s.harness.process(&create_tag(&s, 1, "urgent")).unwrap();
+    s.harness.process(&create_tag(&s, 2, "later")).unwrap();
+    let processed = s.harness.process(&read_tag(&s, 2)).unwrap();
+
+    let (first, second) = (tag(&s, 1), tag(&s, 2));
+    assert_eq!((first.0, first.1.as_str()), (1, "urgent"));
+    assert_eq!((second.0, second.1.as_str()), (2, "later"));
+    assert!(processed
+        .messages()
+        .contains(&"      Label:          later"));
+}
+
+#[test]
+fn generic_tables_are_kept_apart_by_their_type_arguments() {
+    let mut s = setup();
+    let number = record_address(&s, Setting::<u32>::TABLE_NAME, 1);
+    let label = record_address(&s, Setting::<String>::TABLE_NAME, 1);
+
+    // Each instantiation is its own table, with its own IDs.
+    s.harness
+        .process(&create_record(
+            &s,
+            NautilusEntrypoint::CreateNumberSetting(7),
+            number,
+        ))
+        .unwrap();
+    s.harness
+        .process(&create_record(
+            &s,
+            NautilusEntrypoint::CreateLabelSetting("dark".to_string()),
+            label,
+        ))
+        .unwrap();
+    let processed = s.harness.process(&read_number_setting(&s, number)).unwrap();
+    // A `Setting<String>` can't be loaded as a `Setting<u32>`.
+    let failed = s
+        .harness
+        .process(&read_number_setting(&s, label))
+        .unwrap_err();
+
+    assert_eq!(Setting::<u32>::TABLE_NAME, "setting<u32>");
+    assert_eq!(Setting::<String>::TABLE_NAME, "setting<String>");
+    let data = |address: &Pubkey| s.harness.account(address).unwrap().data.clone();
+    let number_setting = Setting::<u32>::try_from_slice(&data(&number)).unwrap();
+    let label_setting = Setting::<String>::try_from_slice(&data(&label)).unwrap();
+    assert_eq!((number_setting.id, number_setting.value), (1, 7));
+    assert_eq!(
+        (label_setting.id, label_setting.value.as_str()),
+        (1, "dark")
+    );
+    assert!(processed.messages().contains(&"      Value:          7"));
+    assert_eq!(
+        failed.error,
+        TestError::Program(
+            NautilusError::DeserializeDataFailed("setting<u32>".to_string(), label.to_string())
+                .into()
+        )
+    );
+}
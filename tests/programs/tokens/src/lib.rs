@@ -19,7 +19,7 @@ mod program_nautilus {
         //
         new_mint.create(decimals, mint_authority.clone(), Some(mint_authority))?;
         //
-        print_mint_data(&new_mint.self_account.data, "Create");
+        print_mint_data(&new_mint.self_account.data, "Create")?;
         Ok(())
     }
 
@@ -41,7 +41,7 @@ mod program_nautilus {
             rent_payer,
         )?;
         //
-        print_mint_data(&new_mint.self_account.data, "Create with payer");
+        print_mint_data(&new_mint.self_account.data, "Create with payer")?;
         Ok(())
     }
 
@@ -51,16 +51,16 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
         amount: u64,
     ) -> ProgramResult {
-        print_associated_token_data(&to.self_account.data, "To acct pre-mint");
+        print_associated_token_data(&to.self_account.data, "To acct pre-mint")?;
         info!(" * Mint Public Key: {}", &mint.key());
-        print_mint_data(&mint.self_account.data, "MintTo");
+        print_mint_data(&mint.self_account.data, "MintTo")?;
         info!("Minting {} tokens to: {}", amount, to.key());
         //
         // /* Business Logic */
         //
         mint.mint_to(to.clone(), authority, amount)?; // Cloning so we can ref later
                                                       //
-        print_associated_token_data(&to.self_account.data, "To acct post-mint");
+        print_associated_token_data(&to.self_account.data, "To acct post-mint")?;
         Ok(())
     }
 
@@ -69,19 +69,19 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
     ) -> ProgramResult {
         info!(" * Mint Public Key: {}", &mint.key());
-        print_mint_data(&mint.self_account.data, "Mint pre-disabling");
+        print_mint_data(&mint.self_account.data, "Mint pre-disabling")?;
         //
         // /* Business Logic */
         //
         mint.set_authority(None, AuthorityType::MintTokens, authority)?;
         //
-        print_mint_data(&mint.self_account.data, "Mint post-disabling");
+        print_mint_data(&mint.self_account.data, "Mint post-disabling")?;
         Ok(())
     }
 
     fn read_mint(mint: Mint) -> ProgramResult {
         info!(" * Mint Public Key: {}", &mint.key());
-        print_mint_data(&mint.data, "Read");
+        print_mint_data(&mint.data, "Read")?;
         //
         // /* Business Logic */
         //
@@ -112,7 +112,7 @@ mod program_nautilus {
             mint_authority,
         )?;
         //
-        print_metadata_data(&new_metadata.self_account.data, "Create");
+        print_metadata_data(&new_metadata.self_account.data, "Create")?;
         Ok(())
     }
 
@@ -141,13 +141,13 @@ mod program_nautilus {
             rent_payer,
         )?;
         //
-        print_metadata_data(&new_metadata.self_account.data, "Create with payer");
+        print_metadata_data(&new_metadata.self_account.data, "Create with payer")?;
         Ok(())
     }
 
     fn read_metadata(metadata: Metadata) -> ProgramResult {
         info!(" * Metadata Public Key: {}", &metadata.key());
-        print_metadata_data(&metadata.data, "Read");
+        print_metadata_data(&metadata.data, "Read")?;
         //
         // /* Business Logic */
         //
@@ -172,7 +172,7 @@ mod program_nautilus {
         //
         new_associated_token.create(mint, owner)?;
         //
-        print_associated_token_data(&new_associated_token.self_account.data, "Create");
+        print_associated_token_data(&new_associated_token.self_account.data, "Create")?;
         Ok(())
     }
 
@@ -194,7 +194,7 @@ mod program_nautilus {
         //
         new_associated_token.create_with_payer(mint, owner, rent_payer)?;
         //
-        print_associated_token_data(&new_associated_token.self_account.data, "Create with payer");
+        print_associated_token_data(&new_associated_token.self_account.data, "Create with payer")?;
         Ok(())
     }
 
@@ -203,7 +203,7 @@ mod program_nautilus {
             " * AssociatedTokenAccount Public Key: {}",
             &associated_token.key()
         );
-        print_associated_token_data(&associated_token.data, "Read");
+        print_associated_token_data(&associated_token.data, "Read")?;
         //
         // /* Business Logic */
         //
@@ -216,14 +216,14 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
         amount: u64,
     ) -> ProgramResult {
-        print_associated_token_data(&from.self_account.data, "From acct pre-burn");
+        print_associated_token_data(&from.self_account.data, "From acct pre-burn")?;
         info!("Burning {} tokens from: {} ", amount, from.key(),);
         //
         // /* Business Logic */
         //
         from.burn(mint, authority, amount)?; // Cloning so we can ref later
                                              //
-        print_associated_token_data(&from.self_account.data, "From acct post-burn");
+        print_associated_token_data(&from.self_account.data, "From acct post-burn")?;
         Ok(())
     }
 
@@ -233,8 +233,8 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
         amount: u64,
     ) -> ProgramResult {
-        print_associated_token_data(&from.self_account.data, "From acct pre-transfer");
-        print_associated_token_data(&to.self_account.data, "To acct pre-transfer");
+        print_associated_token_data(&from.self_account.data, "From acct pre-transfer")?;
+        print_associated_token_data(&to.self_account.data, "To acct pre-transfer")?;
         info!(
             "Transferring {} tokens from: {} to: {}",
             amount,
@@ -246,8 +246,8 @@ mod program_nautilus {
         //
         from.transfer(to.clone(), authority, amount)?; // Cloning so we can ref later
                                                        //
-        print_associated_token_data(&from.self_account.data, "From acct post-transfer");
-        print_associated_token_data(&to.self_account.data, "To acct post-transfer");
+        print_associated_token_data(&from.self_account.data, "From acct post-transfer")?;
+        print_associated_token_data(&to.self_account.data, "To acct post-transfer")?;
         Ok(())
     }
 
@@ -257,13 +257,13 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
     ) -> ProgramResult {
         info!(" * AssociatedTokenAccount Public Key: {}", &account.key());
-        print_associated_token_data(&account.self_account.data, "Freeze (pre)");
+        print_associated_token_data(&account.self_account.data, "Freeze (pre)")?;
         //
         // /* Business Logic */
         //
         account.freeze(mint, authority)?; // Cloning so we can ref later
                                           //
-        print_associated_token_data(&account.self_account.data, "Freeze (post)");
+        print_associated_token_data(&account.self_account.data, "Freeze (post)")?;
         Ok(())
     }
 
@@ -273,13 +273,13 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
     ) -> ProgramResult {
         info!(" * AssociatedTokenAccount Public Key: {}", &account.key());
-        print_associated_token_data(&account.self_account.data, "Thaw (pre)");
+        print_associated_token_data(&account.self_account.data, "Thaw (pre)")?;
         //
         // /* Business Logic */
         //
         account.thaw(mint, authority)?; // Cloning so we can ref later
                                         //
-        print_associated_token_data(&account.self_account.data, "Thaw (post)");
+        print_associated_token_data(&account.self_account.data, "Thaw (post)")?;
         Ok(())
     }
 
@@ -307,8 +307,8 @@ mod program_nautilus {
             Some(mint_authority),
         )?;
         //
-        print_mint_data(&new_token.self_account.mint.data, "Create");
-        print_metadata_data(&new_token.self_account.metadata.data, "Create");
+        print_mint_data(&new_token.self_account.mint.data, "Create")?;
+        print_metadata_data(&new_token.self_account.metadata.data, "Create")?;
         Ok(())
     }
 
@@ -337,8 +337,8 @@ mod program_nautilus {
             rent_payer,
         )?;
         //
-        print_mint_data(&new_token.self_account.mint.data, "Create with payer");
-        print_metadata_data(&new_token.self_account.metadata.data, "Create with payer");
+        print_mint_data(&new_token.self_account.mint.data, "Create with payer")?;
+        print_metadata_data(&new_token.self_account.metadata.data, "Create with payer")?;
         Ok(())
     }
 
@@ -348,16 +348,16 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
         amount: u64,
     ) -> ProgramResult {
-        print_associated_token_data(&to.self_account.data, "To acct pre-mint");
+        print_associated_token_data(&to.self_account.data, "To acct pre-mint")?;
         info!(" * Token Public Key: {}", &token.key());
-        print_mint_data(&token.self_account.mint.data, "MintTo");
+        print_mint_data(&token.self_account.mint.data, "MintTo")?;
         info!("Minting {} tokens to: {}", amount, to.key());
         //
         // /* Business Logic */
         //
         token.mint_to(to.clone(), authority, amount)?; // Cloning so we can ref later
                                                        //
-        print_associated_token_data(&to.self_account.data, "To acct post-mint");
+        print_associated_token_data(&to.self_account.data, "To acct post-mint")?;
         Ok(())
     }
 
@@ -366,20 +366,20 @@ mod program_nautilus {
         authority: Signer<Wallet<'a>>,
     ) -> ProgramResult {
         info!(" * Mint Public Key: {}", &token.key());
-        print_mint_data(&token.self_account.mint.data, "Token mint pre-disabling");
+        print_mint_data(&token.self_account.mint.data, "Token mint pre-disabling")?;
         //
         // /* Business Logic */
         //
         token.set_authority(None, AuthorityType::MintTokens, authority)?;
         //
-        print_mint_data(&token.self_account.mint.data, "token mint post-disabling");
+        print_mint_data(&token.self_account.mint.data, "token mint post-disabling")?;
         Ok(())
     }
 
     fn read_token(token: Token) -> ProgramResult {
         info!(" * Token Public Key: {}", &token.key());
-        print_mint_data(&token.mint.data, "Read");
-        print_metadata_data(&token.metadata.data, "Read");
+        print_mint_data(&token.mint.data, "Read")?;
+        print_metadata_data(&token.metadata.data, "Read")?;
         //
         // /* Business Logic */
         //
@@ -408,8 +408,8 @@ mod program_nautilus {
             Some(mint_authority),
         )?;
         //
-        print_mint_data(&new_nft.self_account.mint.data, "Create");
-        print_metadata_data(&new_nft.self_account.metadata.data, "Create");
+        print_mint_data(&new_nft.self_account.mint.data, "Create")?;
+        print_metadata_data(&new_nft.self_account.metadata.data, "Create")?;
         Ok(())
     }
 
@@ -436,8 +436,8 @@ mod program_nautilus {
             rent_payer,
         )?;
         //
-        print_mint_data(&new_nft.self_account.mint.data, "Create with payer");
-        print_metadata_data(&new_nft.self_account.metadata.data, "Create with payer");
+        print_mint_data(&new_nft.self_account.mint.data, "Create with payer")?;
+        print_metadata_data(&new_nft.self_account.metadata.data, "Create with payer")?;
         Ok(())
     }
 
@@ -446,23 +446,23 @@ mod program_nautilus {
         to: Mut<AssociatedTokenAccount<'a>>,
         authority: Signer<Wallet<'a>>,
     ) -> ProgramResult {
-        print_associated_token_data(&to.self_account.data, "To acct pre-mint");
+        print_associated_token_data(&to.self_account.data, "To acct pre-mint")?;
         info!(" * NFT Public Key: {}", &nft.key());
-        print_mint_data(&nft.self_account.mint.data, "MintTo");
+        print_mint_data(&nft.self_account.mint.data, "MintTo")?;
         info!("Minting NFT to: {}", to.key());
         //
         // /* Business Logic */
         //
         nft.mint_to(to.clone(), authority)?; // Cloning so we can ref later
                                              //
-        print_associated_token_data(&to.self_account.data, "To acct post-mint");
+        print_associated_token_data(&to.self_account.data, "To acct post-mint")?;
         Ok(())
     }
 
     fn read_nft(nft: Nft) -> ProgramResult {
         info!(" * NFT Public Key: {}", &nft.key());
-        print_mint_data(&nft.mint.data, "Read");
-        print_metadata_data(&nft.metadata.data, "Read");
+        print_mint_data(&nft.mint.data, "Read")?;
+        print_metadata_data(&nft.metadata.data, "Read")?;
         //
         // /* Business Logic */
         //
@@ -470,16 +470,19 @@ mod program_nautilus {
     }
 }
 
-fn print_mint_data(data: &MintState, desc: &str) {
+fn print_mint_data(data: &Lazy<MintState>, desc: &str) -> ProgramResult {
+    let data = Lazy::try_get(data)?;
     info!(" * Mint Data for: {}:", desc);
     info!("      Mint Authority:         {:#?}", data.mint_authority);
     info!("      Supply:                 {}", data.supply);
     info!("      Decimals:               {}", data.decimals);
     info!("      Is Initialized:         {}", data.is_initialized);
     info!("      Freeze Authority:       {:#?}", data.freeze_authority);
+    Ok(())
 }
 
-fn print_metadata_data(data: &MetadataState, desc: &str) {
+fn print_metadata_data(data: &Lazy<MetadataState>, desc: &str) -> ProgramResult {
+    let data = Lazy::try_get(data)?;
     info!(" * Metadata Data for: {}:", desc);
     info!("      Mint:                   {:#?}", data.mint);
     info!(
@@ -491,9 +494,14 @@ fn print_metadata_data(data: &MetadataState, desc: &str) {
     info!("      Title:                  {}", data.data.name);
     info!("      Symbol:                 {}", data.data.symbol);
     info!("      URI:                    {}", data.data.uri);
+    Ok(())
 }
 
-fn print_associated_token_data(data: &AssociatedTokenAccountState, desc: &str) {
+fn print_associated_token_data(
+    data: &Lazy<AssociatedTokenAccountState>,
+    desc: &str,
+) -> ProgramResult {
+    let data = Lazy::try_get(data)?;
     info!(" * Associated Token Data for: {}:", desc);
     info!("      Mint:                   {:#?}", data.mint);
     info!("      Owner:                  {:#?}", data.owner);
@@ -502,4 +510,5 @@ fn print_associated_token_data(data: &AssociatedTokenAccountState, desc: &str) {
     info!("      Is Native:              {:#?}", data.is_native);
     info!("      Delegated Amount:       {}", data.delegated_amount);
     info!("      Close Authority:        {:#?}", data.close_authority);
+    Ok(())
 }
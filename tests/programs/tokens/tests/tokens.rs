@@ -0,0 +1,297 @@
+//! Runs the tokens program natively with `nautilus::testing`, against the SPL
+//! programs' native builds.
+use nautilus::{
+    error::NautilusError,
+    mpl_token_metadata::{self, state::TokenMetadataAccount},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        program_pack::Pack,
+    },
+    spl_associated_token_account::{self, get_associated_token_address},
+    spl_token,
+    spl_token_2022::{
+        self,
+        extension::ExtensionType,
+        instruction::{initialize_mint2, initialize_mint_close_authority},
+    },
+    system_program, sysvar,
+    testing::{TestAccount, TestError, TestHarness},
+    Pubkey,
+};
+use program_nautilus::NautilusEntrypoint;
+
+struct Setup {
+    harness: TestHarness,
+    authority: Pubkey,
+    mint: Pubkey,
+}
+
+fn setup() -> Setup {
+    let mut harness = TestHarness::new(Pubkey::new_unique(), program_nautilus::process_instruction);
+    harness.add_spl_programs();
+    let authority = harness.add_wallet(LAMPORTS_PER_SOL);
+    Setup {
+        harness,
+        authority,
+        mint: Pubkey::new_unique(),
+    }
+}
+
+fn create_mint(s: &Setup) -> Instruction {
+    s.harness.instruction(
+        NautilusEntrypoint::CreateMint(6),
+        vec![
+            AccountMeta::new(s.authority, true),
+            AccountMeta::new(s.mint, true),
+            AccountMeta::new(s.authority, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+    )
+}
+
+fn create_associated_token(s: &Setup, owner: Pubkey) -> Instruction {
+    s.harness.instruction(
+        NautilusEntrypoint::CreateAssociatedToken(),
+        vec![
+            AccountMeta::new_readonly(s.mint, false),
+            AccountMeta::new(get_associated_token_address(&owner, &s.mint), false),
+            AccountMeta::new_readonly(owner, false),
+            AccountMeta::new(s.authority, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ],
+    )
+}
+
+/// The accounts of the instructions acting on token accounts, which the
+/// authority signs.
+fn token_accounts(s: &Setup, first: AccountMeta, second: AccountMeta) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(s.authority, true),
+        first,
+        second,
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+    ]
+}
+
+fn read_mint(s: &Setup, mint: Pubkey, token_program: Pubkey) -> Instruction {
+    s.harness.instruction(
+        NautilusEntrypoint::ReadMint(),
+        vec![
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+    )
+}
+
+fn token_account(harness: &TestHarness, address: &Pubkey) -> spl_token::state::Account {
+    spl_token::state::Account::unpack(&harness.account(address).unwrap().data).unwrap()
+}
+
+#[test]
+fn mint_burn_freeze_and_thaw_tokens() {
+    let mut s = setup();
+    let ata = get_associated_token_address(&s.authority, &s.mint);
+    let ixs = [
+        create_mint(&s),
+        create_associated_token(&s, s.authority),
+        s.harness.instruction(
+            NautilusEntrypoint::MintMintTo(1_000),
+            token_accounts(
+                &s,
+                AccountMeta::new(s.mint, false),
+                AccountMeta::new(ata, false),
+            ),
+        ),
+        s.harness.instruction(
+            NautilusEntrypoint::BurnTokens(400),
+            // Burning changes the mint's supply.
+            token_accounts(
+                &s,
+                AccountMeta::new(ata, false),
+                AccountMeta::new(s.mint, false),
+            ),
+        ),
+    ];
+    s.harness.process_transaction(&ixs).unwrap();
+
+    let mint = spl_token::state::Mint::unpack(&s.harness.account(&s.mint).unwrap().data).unwrap();
+    assert_eq!(mint.decimals, 6);
+    assert_eq!(mint.supply, 600);
+    assert_eq!(s.harness.account(&ata).unwrap().owner, spl_token::ID);
+    let token = token_account(&s.harness, &ata);
+    assert_eq!(
+        (token.mint, token.owner, token.amount),
+        (s.mint, s.authority, 600)
+    );
+
+    let freeze_accounts = || {
+        vec![
+            AccountMeta::new(ata, false),
+            AccountMeta::new(s.authority, true),
+            AccountMeta::new_readonly(s.mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        ]
+    };
+    let freeze = s
+        .harness
+        .instruction(NautilusEntrypoint::FreezeAccount(), freeze_accounts());
+    s.harness.process(&freeze).unwrap();
+    assert!(token_account(&s.harness, &ata).is_frozen());
+
+    let thaw = s
+        .harness
+        .instruction(NautilusEntrypoint::ThawAccount(), freeze_accounts());
+    s.harness.process(&thaw).unwrap();
+    assert!(!token_account(&s.harness, &ata).is_frozen());
+}
+
+#[test]
+fn transfers_need_enough_tokens() {
+    let mut s = setup();
+    let recipient = Pubkey::new_unique();
+    let (from, to) = (
+        get_associated_token_address(&s.authority, &s.mint),
+        get_associated_token_address(&recipient, &s.mint),
+    );
+    let transfer = |s: &Setup, amount| {
+        s.harness.instruction(
+            NautilusEntrypoint::TransferTokens(amount),
+            token_accounts(
+                s,
+                AccountMeta::new(from, false),
+                AccountMeta::new(to, false),
+            ),
+        )
+    };
+    let ixs = [
+        create_mint(&s),
+        create_associated_token(&s, s.authority),
+        create_associated_token(&s, recipient),
+        s.harness.instruction(
+            NautilusEntrypoint::MintMintTo(100),
+            token_accounts(
+                &s,
+                AccountMeta::new(s.mint, false),
+                AccountMeta::new(from, false),
+            ),
+        ),
+        transfer(&s, 60),
+    ];
+    s.harness.process_transaction(&ixs).unwrap();
+    assert_eq!(token_account(&s.harness, &from).amount, 40);
+    assert_eq!(token_account(&s.harness, &to).amount, 60);
+
+    let failed = s.harness.process(&transfer(&s, 60)).unwrap_err();
+    assert!(failed.logs.contains(&format!(
+        "Program {} failed: {}",
+        spl_token::ID,
+        failed.error
+    )));
+    assert_eq!(token_account(&s.harness, &from).amount, 40);
+}
+
+#[test]
+fn create_token_with_metadata() {
+    let mut s = setup();
+    let (metadata, _) = mpl_token_metadata::pda::find_metadata_account(&s.mint);
+    let create_token = s.harness.instruction(
+        NautilusEntrypoint::CreateToken(
+            9,
+            "Nautilus".to_string(),
+            "NAUT".to_string(),
+            "https://example.com/naut.json".to_string(),
+        ),
+        vec![
+            AccountMeta::new(s.authority, true),
+            AccountMeta::new(s.mint, true),
+            AccountMeta::new(metadata, false),
+            AccountMeta::new(s.authority, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        ],
+    );
+
+    let processed = s.harness.process(&create_token).unwrap();
+
+    let account = s.harness.account(&metadata).unwrap();
+    assert_eq!(account.owner, mpl_token_metadata::ID);
+    let state = mpl_token_metadata::state::Metadata::safe_deserialize(&account.data).unwrap();
+    assert_eq!(state.mint, s.mint);
+    assert_eq!(state.update_authority, s.authority);
+    assert_eq!(state.data.name.trim_end_matches('\0'), "Nautilus");
+    assert_eq!(state.data.symbol.trim_end_matches('\0'), "NAUT");
+    assert!(processed
+        .logs
+        .contains(&format!("Program {} invoke [2]", mpl_token_metadata::ID)));
+}
+
+#[test]
+fn mints_must_be_owned_by_the_token_program() {
+    let mut s = setup();
+    s.harness.process(&create_mint(&s)).unwrap();
+    s.harness
+        .process(&read_mint(&s, s.mint, spl_token::ID))
+        .unwrap();
+
+    // A copy of the mint owned by another program, passed in as the token
+    // program alongside it.
+    let fake_program = Pubkey::new_unique();
+    let fake_mint = Pubkey::new_unique();
+    let data = s.harness.account(&s.mint).unwrap().data.clone();
+    s.harness
+        .add_account(fake_mint, TestAccount::rent_exempt(data, fake_program));
+    let failed = s
+        .harness
+        .process(&read_mint(&s, fake_mint, fake_program))
+        .unwrap_err();
+
+    assert_eq!(
+        failed.error,
+        TestError::Program(
+            NautilusError::DeserializeDataFailed("token_mint".to_string(), fake_mint.to_string())
+                .into()
+        )
+    );
+}
+
+#[test]
+fn mints_can_be_owned_by_token_2022() {
+    let mut s = setup();
+    // A Token-2022 mint with an extension after its base state.
+    let len = ExtensionType::get_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::MintCloseAuthority,
+    ]);
+    s.harness.add_account(
+        s.mint,
+        TestAccount::rent_exempt(vec![0; len], spl_token_2022::ID),
+    );
+    s.harness
+        .process_transaction(&[
+            initialize_mint_close_authority(&spl_token_2022::ID, &s.mint, Some(&s.authority))
+                .unwrap(),
+            initialize_mint2(&spl_token_2022::ID, &s.mint, &s.authority, None, 9).unwrap(),
+        ])
+        .unwrap();
+
+    let processed = s
+        .harness
+        .process(&read_mint(&s, s.mint, spl_token_2022::ID))
+        .unwrap();
+
+    assert!(processed
+        .logs
+        .iter()
+        .any(|log| log.ends_with("Decimals:               9")));
+}
@@ -0,0 +1,120 @@
+//! Runs the wallets program natively with `nautilus::testing`.
+use nautilus::{
+    error::NautilusError,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+    },
+    sysvar,
+    testing::{TestError, TestHarness},
+    Pubkey,
+};
+use program_nautilus::NautilusEntrypoint;
+
+fn setup() -> (TestHarness, Pubkey) {
+    let mut harness = TestHarness::new(Pubkey::new_unique(), program_nautilus::process_instruction);
+    let payer = harness.add_wallet(LAMPORTS_PER_SOL);
+    (harness, payer)
+}
+
+fn create_wallet(harness: &TestHarness, new_wallet: Pubkey, payer: Pubkey) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::Create(),
+        vec![
+            AccountMeta::new(new_wallet, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(nautilus::system_program::ID, false),
+        ],
+    )
+}
+
+fn transfer(harness: &TestHarness, from: Pubkey, to: Pubkey, amount: u64) -> Instruction {
+    harness.instruction(
+        NautilusEntrypoint::Transfer(amount),
+        vec![
+            AccountMeta::new(from, true),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(nautilus::system_program::ID, false),
+        ],
+    )
+}
+
+#[test]
+fn create_wallet_through_the_system_program() {
+    let (mut harness, payer) = setup();
+    let new_wallet = Pubkey::new_unique();
+
+    let processed = harness
+        .process(&create_wallet(&harness, new_wallet, payer))
+        .unwrap();
+
+    let rent = harness.rent().minimum_balance(0);
+    let wallet = harness.account(&new_wallet).unwrap();
+    assert_eq!(wallet.lamports, rent);
+    assert_eq!(wallet.owner, nautilus::system_program::ID);
+    assert_eq!(
+        harness.account(&payer).unwrap().lamports,
+        LAMPORTS_PER_SOL - rent
+    );
+    assert!(processed.logs.contains(&format!(
+        "Program {} invoke [2]",
+        nautilus::system_program::ID
+    )));
+    assert!(processed
+        .messages()
+        .contains(&" * Wallet info for: Create acct post-create:"));
+}
+
+#[test]
+fn transfer_between_wallets() {
+    let (mut harness, payer) = setup();
+    let recipient = Pubkey::new_unique();
+
+    let processed = harness
+        .process(&transfer(&harness, payer, recipient, 1_000))
+        .unwrap();
+
+    assert_eq!(
+        harness.account(&payer).unwrap().lamports,
+        LAMPORTS_PER_SOL - 1_000
+    );
+    assert_eq!(harness.account(&recipient).unwrap().lamports, 1_000);
+    assert!(processed
+        .messages()
+        .contains(&format!("Transferring 1000 From: {} to: {}", payer, recipient).as_str()));
+}
+
+#[test]
+fn transfer_requires_a_signer() {
+    let (mut harness, payer) = setup();
+    let recipient = Pubkey::new_unique();
+    let mut ix = transfer(&harness, payer, recipient, 1_000);
+    ix.accounts[0].is_signer = false;
+
+    let failed = harness.process(&ix).unwrap_err();
+
+    assert_eq!(
+        failed.error,
+        TestError::Program(NautilusError::AccountNotSigner(payer.to_string()).into())
+    );
+    assert_eq!(harness.account(&payer).unwrap().lamports, LAMPORTS_PER_SOL);
+    assert!(harness.account(&recipient).is_none());
+}
+
+#[test]
+fn failed_transactions_keep_no_changes() {
+    let (mut harness, payer) = setup();
+    let recipient = Pubkey::new_unique();
+
+    let failed = harness
+        .process_transaction(&[
+            transfer(&harness, payer, recipient, 1_000),
+            transfer(&harness, payer, recipient, 2 * LAMPORTS_PER_SOL),
+        ])
+        .unwrap_err();
+
+    assert_eq!(failed.instruction, 1);
+    assert_eq!(harness.account(&payer).unwrap().lamports, LAMPORTS_PER_SOL);
+    assert!(harness.account(&recipient).is_none());
+}